@@ -3,7 +3,10 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{quote, quote_spanned};
 use syn::ext::IdentExt;
-use syn::{Fields, FieldsNamed, ItemStruct, Lit, LitStr, Meta, MetaNameValue, Token};
+use syn::punctuated::Punctuated;
+use syn::{
+    Fields, FieldsNamed, ItemStruct, Lit, LitStr, Meta, MetaNameValue, NestedMeta, Token, Type,
+};
 
 /// Wrapper type that implements a custom [`syn::parse::Parse`]
 pub(crate) struct ItemStructNamed {
@@ -95,9 +98,10 @@ pub(crate) struct Arg {
     pub(crate) value: ArgValue,
 }
 
+#[derive(Clone)]
 pub(crate) enum ArgValue {
     Lit(Lit),
-    Array(Vec<String>),
+    Array(Vec<LitStr>),
 }
 
 impl IntoIterator for MacroArguments {
@@ -127,9 +131,9 @@ impl TryFrom<ArgValue> for bool {
         }
     }
 }
-impl TryFrom<ArgValue> for Vec<String> {
+impl TryFrom<ArgValue> for Vec<LitStr> {
     type Error = Error;
-    fn try_from(value: ArgValue) -> std::result::Result<Vec<String>, Self::Error> {
+    fn try_from(value: ArgValue) -> std::result::Result<Vec<LitStr>, Self::Error> {
         match value {
             ArgValue::Array(val) => Ok(val),
             _ => Err("expected LitBool, got something else".into()),
@@ -157,6 +161,12 @@ impl syn::parse::Parse for Arg {
         let key = input.parse::<Ident>()?;
         let span = key.span();
         let key = key.to_string();
+
+        // a bare flag (e.g. `readonly`) is shorthand for `readonly = true`
+        if !input.peek(Token![=]) {
+            let value = ArgValue::Lit(Lit::Bool(syn::LitBool::new(true, span)));
+            return Ok(Self { key, span, value });
+        }
         input.parse::<Token![=]>()?;
         let value = input.parse()?;
         Ok(Self { key, span, value })
@@ -171,7 +181,7 @@ impl syn::parse::Parse for ArgValue {
             let mut values = Vec::new();
             syn::bracketed!(content in input);
             while content.peek(Lit) {
-                values.push(content.parse::<LitStr>()?.value());
+                values.push(content.parse::<LitStr>()?);
                 if content.peek(Token![,]) {
                     content.parse::<Token![,]>()?;
                 }
@@ -186,3 +196,348 @@ impl syn::parse::Parse for ArgValue {
         }
     }
 }
+
+/// Build a "did you mean" hint for an invalid argument `key`
+///
+/// Returns an empty string if none of the `valid` keys are close enough.
+pub(crate) fn suggest(key: &str, valid: &[&str]) -> String {
+    valid
+        .iter()
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!(" (did you mean `{}`?)", candidate))
+        .unwrap_or_default()
+}
+
+/// The Levenshtein distance between `a` and `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = current;
+        }
+    }
+    row[b.len()]
+}
+
+/// Check whether the struct has a field called `name`
+pub(crate) fn has_field(fields: &FieldsNamed, name: &str) -> bool {
+    fields
+        .named
+        .iter()
+        .any(|field| matches!(&field.ident, Some(ident) if ident == name))
+}
+
+/// Check whether the struct's `name` field is an `Option<T>`
+pub(crate) fn is_option_field(fields: &FieldsNamed, name: &str) -> bool {
+    fields
+        .named
+        .iter()
+        .find(|field| matches!(&field.ident, Some(ident) if ident == name))
+        .map(|field| is_option(&field.ty))
+        .unwrap_or(false)
+}
+
+/// Check whether `ty` is an `Option<T>`
+pub(crate) fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Check that each name in `names` is a field on the struct
+///
+/// The error is spanned to the offending string literal.
+pub(crate) fn validate_field_names(
+    item: &ItemStructNamed,
+    key: &str,
+    names: &[LitStr],
+) -> Result<()> {
+    for name in names {
+        if !has_field(&item.fields, &name.value()) {
+            return Err((
+                format!(
+                    "`{}` lists `{}`, but `{}` has no field with that name",
+                    key,
+                    name.value(),
+                    item.item.ident
+                ),
+                Some(name.span()),
+            )
+                .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the struct has each of the `required` fields
+///
+/// The error is spanned to the struct name.
+pub(crate) fn require_fields(
+    item: &ItemStructNamed,
+    required: &[&str],
+    reason: &str,
+) -> Result<()> {
+    let missing: Vec<String> = required
+        .iter()
+        .filter(|name| !has_field(&item.fields, name))
+        .map(|name| format!("`{}`", name))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    Err((
+        format!(
+            "`{}` is missing the {} field(s): {}",
+            item.item.ident,
+            reason,
+            missing.join(", ")
+        ),
+        Some(item.item.ident.span()),
+    )
+        .into())
+}
+
+/// ORM methods which don't write to the database
+///
+/// These are sent to the read replica (if one is configured), unless the
+/// `#[odoo_orm]` macro sets `readonly = false`.
+pub(crate) const READONLY_METHODS: [&str; 19] = [
+    "check_access_rights",
+    "check_access_rule",
+    "check_field_access_rights",
+    "copy_data",
+    "default_get",
+    "exists",
+    "fields_get",
+    "get_external_id",
+    "get_metadata",
+    "get_xml_id",
+    "name_get",
+    "name_search",
+    "read",
+    "read_group",
+    "search",
+    "search_count",
+    "search_read",
+    "web_read",
+    "web_search_read",
+];
+
+/// ORM methods which write record values
+///
+/// The client sets the current company in the context of these methods (see
+/// `OdooKwargsMethod::SETS_COMPANY`).
+pub(crate) const COMPANY_METHODS: [&str; 2] = ["create", "write"];
+
+/// Field names which are auto-filled from `client.auth` on authed clients
+const AUTH_FIELDS: [&str; 5] = ["database", "db", "uid", "login", "password"];
+
+/// A method's entry in the `odoo_api::registry`
+pub(crate) struct RegistryEntry<'a> {
+    pub service: &'a str,
+    pub method: &'a str,
+    pub name: &'a str,
+    pub auth: bool,
+    pub args: Vec<String>,
+    pub kwargs: Vec<String>,
+}
+
+impl RegistryEntry<'_> {
+    /// The client method arguments, skipping the auto-filled credentials if
+    /// the method requires auth
+    pub(crate) fn client_args(fields: &FieldsNamed, auth: bool) -> Vec<String> {
+        fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.as_ref().map(|ident| ident.to_string()))
+            .filter(|name| !auth || !AUTH_FIELDS.contains(&name.as_str()))
+            .collect()
+    }
+
+    /// Output the `inventory::submit!` for this method (only with the
+    /// `registry` feature)
+    pub(crate) fn submit(&self) -> TokenStream2 {
+        let Self {
+            service,
+            method,
+            name,
+            auth,
+            args,
+            kwargs,
+        } = self;
+        quote! {
+            #[cfg(feature = "registry")]
+            odoo_api::registry::inventory::submit! {
+                odoo_api::registry::MethodInfo {
+                    service: #service,
+                    method: #method,
+                    name: #name,
+                    auth: #auth,
+                    args: &[#(#args),*],
+                    kwargs: &[#(#kwargs),*],
+                }
+            }
+        }
+    }
+}
+
+/// Field names which hold credentials
+///
+/// These are redacted from the `Debug` output, and (via [`secret_fields!`](crate::secret_fields))
+/// from the request bodies logged by `odoo_api`'s tracing.
+pub(crate) const SECRET_FIELDS: [&str; 6] = [
+    "password",
+    "passwd",
+    "new_passwd",
+    "new_password",
+    "admin_password",
+    "user_password",
+];
+
+/// Replace a derived `Debug` with one that redacts the credential fields
+///
+/// If the struct has a [`SECRET_FIELDS`] field and `#[derive(Debug)]`, `Debug`
+/// is removed from the derive list, and the returned impl prints `"***"` for
+/// those fields instead. Otherwise, the struct is left as-is.
+pub(crate) fn redact_debug(input: &mut ItemStructNamed) -> Result<TokenStream2> {
+    let is_secret = |field: &&syn::Field| matches!(&field.ident, Some(ident) if SECRET_FIELDS.iter().any(|name| ident == name));
+    if !input.fields.named.iter().any(|field| is_secret(&field)) {
+        return Ok(quote!());
+    }
+
+    // strip `Debug` from the `#[derive(...)]` list(s)
+    let mut derives_debug = false;
+    let mut attrs = Vec::new();
+    for attr in input.item.attrs.drain(..) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) if attr.path.is_ident("derive") => list,
+            _ => {
+                attrs.push(attr);
+                continue;
+            }
+        };
+        let mut derives: Punctuated<NestedMeta, Token![,]> = Punctuated::new();
+        for nested in list.nested {
+            match &nested {
+                // `Debug`, or a path like `std::fmt::Debug`
+                NestedMeta::Meta(Meta::Path(path)) if matches!(path.segments.last(), Some(segment) if segment.ident == "Debug") => {
+                    derives_debug = true
+                }
+                _ => derives.push(nested),
+            }
+        }
+        if !derives.is_empty() {
+            attrs.push(syn::parse_quote!(#[derive(#derives)]));
+        }
+    }
+    input.item.attrs = attrs;
+    if !derives_debug {
+        return Ok(quote!());
+    }
+
+    let ident = &input.item.ident;
+    let name = ident.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.item.generics.split_for_impl();
+    let fields = input.fields.named.iter().map(|field| {
+        let ident = field.ident.as_ref().unwrap();
+        let name = ident.to_string();
+        if is_secret(&field) {
+            quote!(.field(#name, &"***"))
+        } else {
+            quote!(.field(#name, &self.#ident))
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Debug for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct(#name)
+                    #(#fields)*
+                    .finish()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn suggestions() {
+        let valid = ["method", "name", "args", "kwargs"];
+        assert_eq!(suggest("arg", &valid), " (did you mean `args`?)");
+        assert_eq!(suggest("kwarg", &valid), " (did you mean `kwargs`?)");
+        assert_eq!(suggest("methd", &valid), " (did you mean `method`?)");
+        assert_eq!(suggest("service", &valid), "");
+    }
+
+    #[test]
+    fn redact_debug_derive() {
+        let mut input: ItemStructNamed = syn::parse2(quote! {
+            #[derive(Debug, Serialize)]
+            pub struct Login {
+                pub db: String,
+                pub password: String,
+            }
+        })
+        .unwrap();
+        let output = redact_debug(&mut input).unwrap().to_string();
+
+        assert_eq!(
+            input.item.attrs[0].tokens.to_string(),
+            quote!((Serialize)).to_string()
+        );
+        assert!(output.contains("impl :: std :: fmt :: Debug for Login"));
+        assert!(output.contains(". field (\"db\" , & self . db)"));
+        assert!(output.contains(". field (\"password\" , & \"***\")"));
+
+        // structs without credentials are left as-is
+        let mut input: ItemStructNamed = syn::parse2(quote! {
+            #[derive(Debug)]
+            pub struct Version {}
+        })
+        .unwrap();
+        assert!(redact_debug(&mut input).unwrap().is_empty());
+        assert_eq!(input.item.attrs.len(), 1);
+    }
+
+    #[test]
+    fn bare_flag() {
+        let args: MacroArguments = syn::parse2(quote!(method = "read", readonly)).unwrap();
+        let args: Vec<Arg> = args.into_iter().collect();
+        assert_eq!(args[1].key, "readonly");
+        assert!(matches!(bool::try_from(args[1].value.clone()), Ok(true)));
+
+        let args: MacroArguments = syn::parse2(quote!(readonly = false, method = "read")).unwrap();
+        let args: Vec<Arg> = args.into_iter().collect();
+        assert!(matches!(bool::try_from(args[0].value.clone()), Ok(false)));
+        assert_eq!(args[1].key, "method");
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("args", "args"), 0);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
@@ -7,6 +7,7 @@ use proc_macro::TokenStream;
 mod common;
 mod error;
 mod odoo_api;
+mod odoo_model;
 mod odoo_orm;
 mod odoo_web;
 mod serialize_tuple;
@@ -24,6 +25,23 @@ use syn::parse_macro_input;
 ///  - Service: The Odoo "service" for this method
 ///  - Method: The method name
 ///  - Auth: Whether authentication is required, optional, or ignored
+///     - `auth = true`: Implement for `OdooClient<Authed>`, auto-filling the
+///       `database`, `uid`, and `password` fields
+///     - `auth = false`: Implement for any `OdooClient`; all fields are arguments
+///     - `auth = "optional"`: Both of the above; the `OdooClient<NotAuthed>`
+///       method has a `_manual` suffix (e.g., `execute_manual()`)
+///     - Omitted: Don't implement any `OdooClient` method
+///  - Readonly: Whether the method can be sent to the read replica (see
+///    `OdooClient::with_read_replica()`). Set with `readonly = true`, or just
+///    `readonly`. Defaults to `false`
+///  - MapError: A function to map error responses to method-specific errors
+///    (see `JsonRpcParams::map_error`), e.g. `map_error = "list_error"`. It's
+///    called as `list_error(&self, &JsonRpcErrorData)`
+///
+/// If the struct derives `Debug` and has a credential field (`password`, `passwd`,
+/// `new_passwd`, `new_password`, `admin_password`, or `user_password`), the
+/// derived impl is replaced with one that prints `"***"` for those fields. The `#[odoo_orm]` and `#[odoo_web]` macros
+/// do the same.
 ///
 /// For example, consider the following:
 /// ```ignore
@@ -57,7 +75,7 @@ use syn::parse_macro_input;
 ///
 /// // Which JSON-RPC service and method does `Execute` belong to?
 /// impl OdooApiMethod for Execute {
-///     fn describe(&self) -> (&'static str, &'static str) {
+///     fn describe(&self) -> (&str, &str) {
 ///         ("object", "execute")
 ///     }
 /// }
@@ -66,23 +84,31 @@ use syn::parse_macro_input;
 /// // Note that we specified "yes" for auth, so this impl is bound to `Authed`
 /// // clients only
 /// impl<I: RequestImpl> OdooClient<Authed, I> {
-///     pub fn execute(&self, model: &str, method: &str, args: Vec<Value>) -> OdooRequest<Execute, I> {
+///     pub fn execute(
+///         &self,
+///         model: impl Into<String>,
+///         method: impl Into<String>,
+///         args: Vec<Value>
+///     ) -> OdooRequest<Execute, I> {
 ///         let execute = Execute {
 ///             // Auth info is pulled from the Client
 ///             database: self.auth.database.clone(),
 ///             uid: self.auth.uid,
 ///             password: self.auth.password.clone(),
 ///
-///             // Strings are passed as &str then converted to Strings
+///             // Strings are accepted as `impl Into<String>` (`&str`, `String`,
+///             // `Cow<str>`, etc), so owned strings aren't cloned
 ///             model: model.into(),
 ///             method: method.into(),
 ///             args
 ///         };
 ///
-///         // Finally, build the request
+///         // Finally, build the request. The endpoint is derived from the
+///         // client URL each time, so it follows `with_url()`
+///         let endpoint = self.build_endpoint(execute.endpoint());
 ///         self.build_request(
 ///             execute,
-///             &self.url_jsonrpc
+///             &endpoint
 ///         )
 ///     }
 /// }
@@ -103,6 +129,22 @@ pub fn odoo_web(args: TokenStream, input: TokenStream) -> TokenStream {
     parse_result(odoo_web::odoo_web(args, input))
 }
 
+/// Implement traits for an ORM method struct
+///
+/// Arguments:
+///  - `method = "..."`: The ORM method name (e.g., `"read_group"`)
+///  - `name = "..."`: The `OdooClient` method name, if it differs from `method`
+///  - `args = [...]`, `kwargs = [...]`: The fields sent as positional and
+///    keyword arguments
+///  - `readonly = bool`: Whether the method can be sent to the read replica.
+///    This defaults to `true` for the well-known read methods (`read`,
+///    `search_read`, `read_group`, etc), and `false` otherwise. A bare
+///    `readonly` is the same as `readonly = true`
+///
+/// The `OdooClient<Authed>` method auto-fills the `database`, `uid`, and
+/// `password` fields. A `_manual` variant (e.g., `read_manual()`) is also
+/// implemented for any `OdooClient`, taking `db`, `uid`, and `password` before
+/// the usual arguments.
 #[proc_macro_attribute]
 pub fn odoo_orm(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args);
@@ -111,7 +153,69 @@ pub fn odoo_orm(args: TokenStream, input: TokenStream) -> TokenStream {
     parse_result(odoo_orm::odoo_orm(args, input))
 }
 
-#[proc_macro_derive(SerializeTuple)]
+/// Serialize a struct with named fields as a tuple (i.e., a JSON list)
+///
+/// Fields are serialized positionally, in the order they're declared.
+///
+/// Arguments (via `#[serialize_tuple(...)]`):
+///  - `skip_trailing_none`: Omit trailing `Option` fields that are `None`,
+///    rather than serializing them as `null`. A `None` followed by a `Some`
+///    is still serialized as `null`, so that the positions line up. This is
+///    useful for Python methods with default args (e.g., `def method(a, b=None)`)
+///
+/// Example:
+/// ```ignore
+/// #[derive(SerializeTuple)]
+/// #[serialize_tuple(skip_trailing_none)]
+/// pub struct CreateDatabase {
+///     pub db_name: String,
+///     pub country_code: Option<String>,
+///     pub phone: Option<String>,
+/// }
+/// ```
+#[proc_macro_derive(SerializeTuple, attributes(serialize_tuple))]
 pub fn serialize_tuple(input: TokenStream) -> TokenStream {
     parse_result(serialize_tuple::serialize_tuple(input))
 }
+
+/// Implement `OdooModel` and `Deserialize` for a struct mapped to an Odoo model
+///
+/// The struct must have named fields, and can't be generic. Each field is read
+/// from the Odoo field of the same name, and `Option` fields treat `false` (or
+/// a missing key) as `None`.
+///
+/// Arguments (via `#[odoo(...)]`):
+///  - `model = "..."` (struct, required): The Odoo model name
+///  - `field = "..."` (field): The Odoo field name, if it differs from the
+///    Rust field name
+///  - `many2one` (field): Deserialize a many2one value (`[id, display_name]`
+///    or `false`), see `odoo_api::model::many2one`
+///
+/// Example:
+/// ```ignore
+/// #[derive(OdooModel)]
+/// #[odoo(model = "res.partner")]
+/// pub struct Partner {
+///     pub id: OdooId,
+///     pub name: String,
+///     pub email: Option<String>,
+///
+///     #[odoo(field = "parent_id", many2one)]
+///     pub parent: Option<(OdooId, String)>,
+/// }
+/// ```
+#[proc_macro_derive(OdooModel, attributes(odoo))]
+pub fn odoo_model(input: TokenStream) -> TokenStream {
+    parse_result(odoo_model::odoo_model(input))
+}
+
+/// Expand to the list of credential field names, as a `[&str; N]` array
+///
+/// This is used internally by `odoo_api` to redact logged request bodies, so
+/// that it shares one list with the redacted `Debug` impls.
+#[doc(hidden)]
+#[proc_macro]
+pub fn secret_fields(_input: TokenStream) -> TokenStream {
+    let fields = common::SECRET_FIELDS;
+    quote::quote!([#(#fields),*]).into()
+}
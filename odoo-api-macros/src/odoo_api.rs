@@ -2,9 +2,44 @@ use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
 use syn::{FieldsNamed, Ident, Type};
 
-use crate::common::{ItemStructNamed, MacroArguments};
+use crate::common::{
+    has_field, redact_debug, suggest, ArgValue, ItemStructNamed, MacroArguments, RegistryEntry,
+};
 use crate::{Error, Result};
 
+/// The `auth` argument
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AuthMode {
+    /// `auth = true`: implement for `OdooClient<Authed>`, and auto-fill the
+    /// database/uid/password fields
+    Required,
+
+    /// `auth = false`: implement for any `OdooClient<S: AuthState>`
+    NotRequired,
+
+    /// `auth = "optional"`: implement for `OdooClient<Authed>` (as above), and
+    /// for `OdooClient<NotAuthed>` with a `_manual` suffix, where the
+    /// database/uid/password fields are passed as arguments
+    Optional,
+}
+
+impl TryFrom<ArgValue> for AuthMode {
+    type Error = Error;
+    fn try_from(value: ArgValue) -> Result<Self> {
+        if let Ok(auth) = bool::try_from(value.clone()) {
+            return Ok(if auth {
+                Self::Required
+            } else {
+                Self::NotRequired
+            });
+        }
+        match String::try_from(value)?.as_str() {
+            "optional" => Ok(Self::Optional),
+            _ => Err("expected `true`, `false`, or `\"optional\"`".into()),
+        }
+    }
+}
+
 struct OdooApiArgs {
     /// The JSON-RPC "service"
     service: String,
@@ -14,10 +49,16 @@ struct OdooApiArgs {
 
     /// Is authentication required, optional, should we skip generating the
     /// OdooClient impl?
-    auth: Option<bool>,
+    auth: Option<AuthMode>,
 
     /// Optionally specify a name for the OdooClient impl
     name: Option<String>,
+
+    /// Whether the method can be sent to a read replica
+    readonly: bool,
+
+    /// Optionally specify a function to map error responses (see `JsonRpcParams::map_error`)
+    map_error: Option<syn::Path>,
 }
 
 impl TryFrom<MacroArguments> for OdooApiArgs {
@@ -28,6 +69,8 @@ impl TryFrom<MacroArguments> for OdooApiArgs {
         let mut method = None;
         let mut auth = None;
         let mut name = None;
+        let mut readonly = false;
+        let mut map_error = None;
 
         for arg in value.into_iter() {
             match (arg.key.as_str(), arg.value, arg.span) {
@@ -50,7 +93,7 @@ impl TryFrom<MacroArguments> for OdooApiArgs {
                 ("auth", val, span) => {
                     auth = Some(val.try_into().map_err(|_| {
                         (
-                            "invalid value, expected String (e.g., `auth = false`)",
+                            "invalid value, expected `true`, `false`, or `\"optional\"` (e.g., `auth = false`)",
                             Some(span),
                         )
                     })?);
@@ -63,11 +106,34 @@ impl TryFrom<MacroArguments> for OdooApiArgs {
                         )
                     })?);
                 }
+                ("readonly", val, span) => {
+                    readonly = val.try_into().map_err(|_| {
+                        (
+                            "invalid value, expected bool (e.g., `readonly = true`)",
+                            Some(span),
+                        )
+                    })?;
+                }
+                ("map_error", val, span) => {
+                    let path: String = val.try_into().map_err(|_| {
+                        (
+                            "invalid value, expected String (e.g., `map_error = \"list_error\"`)",
+                            Some(span),
+                        )
+                    })?;
+                    map_error = Some(syn::parse_str(&path).map_err(|_| {
+                        ("invalid value, expected a function path", Some(span))
+                    })?);
+                }
 
                 (key, _val, span) => Err((
                     format!(
-                        "Invalid argument `{}`. Valid arguments are: service, method, auth, name",
-                        key
+                        "Invalid argument `{}`{}. Valid arguments are: service, method, auth, name, readonly, map_error",
+                        key,
+                        suggest(
+                            key,
+                            &["service", "method", "auth", "name", "readonly", "map_error"]
+                        )
                     ),
                     Some(span),
                 ))?,
@@ -81,13 +147,32 @@ impl TryFrom<MacroArguments> for OdooApiArgs {
                 .ok_or("The \"method\" key is required (e.g., `method = \"execute\"`)")?,
             auth,
             name,
+            readonly,
+            map_error,
         })
     }
 }
 
-pub(crate) fn odoo_api(args: MacroArguments, input: ItemStructNamed) -> Result<TokenStream2> {
+pub(crate) fn odoo_api(args: MacroArguments, mut input: ItemStructNamed) -> Result<TokenStream2> {
     let args: OdooApiArgs = args.try_into()?;
 
+    // the authed client impl auto-fills the credentials, so they must exist
+    if matches!(args.auth, Some(AuthMode::Required | AuthMode::Optional)) {
+        let fields = &input.fields;
+        let has_db = has_field(fields, "database") || has_field(fields, "db");
+        if !has_db || !has_field(fields, "password") {
+            return Err((
+                format!(
+                    "`{}` requires auth, but is missing a `database` (or `db`) and `password` \
+                     field. Add the fields, or use `auth = false`",
+                    input.item.ident
+                ),
+                Some(input.item.ident.span()),
+            )
+                .into());
+        }
+    }
+
     // fetch the struct name (and some variations)
     let name_struct = input.item.ident.to_string();
     let name_response = format!("{}Response", &name_struct);
@@ -108,27 +193,52 @@ pub(crate) fn odoo_api(args: MacroArguments, input: ItemStructNamed) -> Result<T
     );
 
     // build the TokenStreams
-    let out_params = impl_params(&ident_struct, &ident_response)?;
+    let out_debug = redact_debug(&mut input)?;
+    let out_params = impl_params(&ident_struct, &ident_response, &args)?;
     let out_method = impl_method(&ident_struct, &args)?;
     let out_client = impl_client(&ident_struct, &ident_call, &args, &input.fields, &doc_call)?;
+    let out_kwargs = impl_kwargs(&ident_struct, &input.fields)?;
+    let out_registry = impl_registry(&args, &name_call, &input.fields);
 
     // output the result!
     Ok(quote!(
         #input
+        #out_debug
         #out_params
         #out_method
         #out_client
+        #out_kwargs
+        #out_registry
     ))
 }
 
 /// Output the [`JsonRpcParams`](odoo_api::jsonrpc::JsonRpcParams) impl
-fn impl_params(ident_struct: &Ident, ident_response: &Ident) -> Result<TokenStream2> {
+fn impl_params(
+    ident_struct: &Ident,
+    ident_response: &Ident,
+    args: &OdooApiArgs,
+) -> Result<TokenStream2> {
+    let readonly = args.readonly;
+    let map_error = args.map_error.as_ref().map(|path| {
+        quote! {
+            #[cfg(feature = "client")]
+            fn map_error(
+                &self,
+                error: &odoo_api::jsonrpc::response::JsonRpcErrorData,
+            ) -> Option<odoo_api::client::error::ParseResponseError> {
+                #path(self, error)
+            }
+        }
+    });
     Ok(quote! {
         impl odoo_api::jsonrpc::JsonRpcParams for #ident_struct {
             type Container<T> = odoo_api::jsonrpc::OdooApiContainer <Self>;
             type Response = #ident_response;
+            const IS_READONLY: bool = #readonly;
 
             fn build(self, id: odoo_api::jsonrpc::JsonRpcId) -> odoo_api::jsonrpc::JsonRpcRequest<Self> { self._build(id) }
+
+            #map_error
         }
     })
 }
@@ -139,7 +249,7 @@ fn impl_method(ident_struct: &Ident, args: &OdooApiArgs) -> Result<TokenStream2>
     let method = &args.method;
     Ok(quote! {
         impl odoo_api::jsonrpc::OdooApiMethod for #ident_struct {
-            fn describe(&self) -> (&'static str, &'static str) {
+            fn describe(&self) -> (&str, &str) {
                 (#service, #method)
             }
             fn endpoint(&self) -> &'static str {
@@ -149,7 +259,7 @@ fn impl_method(ident_struct: &Ident, args: &OdooApiArgs) -> Result<TokenStream2>
     })
 }
 
-/// Output the OdooClient impl
+/// Output the OdooClient impl(s)
 fn impl_client(
     ident_struct: &Ident,
     ident_call: &Ident,
@@ -157,22 +267,76 @@ fn impl_client(
     fields: &FieldsNamed,
     doc: &str,
 ) -> Result<TokenStream2> {
-    if args.auth.is_none() {
+    let authed = quote!(odoo_api::client::Authed);
+    match args.auth {
         // The `auth` key wasn't passed, so we'll just skip the OdooClient impl
-        return Ok(quote!());
-    }
-
-    let auth = args.auth.unwrap();
+        None => Ok(quote!()),
 
-    // parse the `auth` argument options
-    let (auth_generic, auth_type) = if auth {
         // no generic, we're implementing for the concrete `Authed` type
-        (quote!(), quote!(odoo_api::client::Authed))
-    } else {
+        Some(AuthMode::Required) => impl_client_for(
+            ident_struct,
+            ident_call,
+            fields,
+            doc,
+            quote!(),
+            authed,
+            true,
+        ),
+
         // auth not required, so we'll implement for any `impl AuthState`
-        (quote!(S: odoo_api::client::AuthState), quote!(S))
-    };
+        Some(AuthMode::NotRequired) => impl_client_for(
+            ident_struct,
+            ident_call,
+            fields,
+            doc,
+            quote!(S: odoo_api::client::AuthState),
+            quote!(S),
+            false,
+        ),
 
+        // auth is optional, so we'll implement for `Authed` (with auto-filled
+        // credentials), and `NotAuthed` (with explicit credentials)
+        Some(AuthMode::Optional) => {
+            let ident_manual = Ident::new(&format!("{}_manual", ident_call), Span::call_site());
+            let out_authed = impl_client_for(
+                ident_struct,
+                ident_call,
+                fields,
+                doc,
+                quote!(),
+                authed,
+                true,
+            )?;
+            let out_manual = impl_client_for(
+                ident_struct,
+                &ident_manual,
+                fields,
+                doc,
+                quote!(),
+                quote!(odoo_api::client::NotAuthed),
+                false,
+            )?;
+            Ok(quote! {
+                #out_authed
+                #out_manual
+            })
+        }
+    }
+}
+
+/// Output a single OdooClient impl
+///
+/// If `autofill` is set, the database/uid/login/password fields are fetched
+/// from the `client.auth` struct, otherwise they're passed as arguments.
+fn impl_client_for(
+    ident_struct: &Ident,
+    ident_call: &Ident,
+    fields: &FieldsNamed,
+    doc: &str,
+    auth_generic: TokenStream2,
+    auth_type: TokenStream2,
+    autofill: bool,
+) -> Result<TokenStream2> {
     // parse fields
     let mut field_assigns = Vec::new();
     let mut field_arguments = Vec::new();
@@ -185,7 +349,7 @@ fn impl_client(
         };
         let name = ident.to_string();
         let path = ty.clone().into_token_stream().to_string();
-        match (name.as_str(), path.as_str(), auth) {
+        match (name.as_str(), path.as_str(), autofill) {
             // special cases (data fetched from the `client.auth` struct)
             ("database", "String", true) => {
                 field_assigns.push(quote!(database: self.auth.database.clone()));
@@ -203,11 +367,11 @@ fn impl_client(
                 field_assigns.push(quote!(password: self.auth.password.clone()));
             }
 
-            // strings are passed by ref
-            //TODO: Into<String> more suitable?
+            // strings accept `&str`, `String`, `Cow<str>`, etc (owned strings
+            // are moved, rather than cloned)
             (_, "String", _) => {
                 field_assigns.push(quote!(#ident: #ident.into()));
-                field_arguments.push(quote!(#ident: &str));
+                field_arguments.push(quote!(#ident: impl Into<String>));
             }
 
             // all other fields are passed as-is
@@ -218,17 +382,25 @@ fn impl_client(
         }
     }
 
+    // methods with a `kwargs` field can carry a context, so the client-wide
+    // context is applied to those
+    let build_request = if has_kwargs(fields) {
+        quote!(build_kwargs_request)
+    } else {
+        quote!(build_request)
+    };
+
     Ok(quote! {
-        #[cfg(not(feature = "types-only"))]
+        #[cfg(feature = "client")]
         impl<I: odoo_api::client::RequestImpl, #auth_generic> odoo_api::client::OdooClient<#auth_type, I> {
             #[doc=#doc]
-            pub fn #ident_call(&mut self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
+            pub fn #ident_call(&self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
                 let #ident_call = #ident_struct {
                     #(#field_assigns),*
                 };
 
                 let endpoint = self.build_endpoint(#ident_call.endpoint());
-                self.build_request(
+                self.#build_request(
                     #ident_call,
                     &endpoint
                 )
@@ -236,3 +408,174 @@ fn impl_client(
         }
     })
 }
+
+/// Register the client method (only if one is generated)
+fn impl_registry(args: &OdooApiArgs, name_call: &str, fields: &FieldsNamed) -> TokenStream2 {
+    let auth = match args.auth {
+        None => return quote!(),
+        Some(auth) => auth != AuthMode::NotRequired,
+    };
+    RegistryEntry {
+        service: &args.service,
+        method: &args.method,
+        name: name_call,
+        auth,
+        args: RegistryEntry::client_args(fields, auth),
+        kwargs: Vec::new(),
+    }
+    .submit()
+}
+
+/// Output the OdooKwargsMethod impl (only for structs with a `kwargs` field)
+fn impl_kwargs(ident_struct: &Ident, fields: &FieldsNamed) -> Result<TokenStream2> {
+    if !has_kwargs(fields) {
+        return Ok(quote!());
+    }
+
+    Ok(quote! {
+        impl odoo_api::jsonrpc::OdooKwargsMethod for #ident_struct {}
+    })
+}
+
+/// Check whether the struct has a `kwargs` field
+fn has_kwargs(fields: &FieldsNamed) -> bool {
+    fields
+        .named
+        .iter()
+        .any(|field| matches!(&field.ident, Some(ident) if ident == "kwargs"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Expand the macro, and return the generated client impls as strings
+    fn expand(args: TokenStream2) -> Vec<String> {
+        let args: MacroArguments = syn::parse2(args).unwrap();
+        let input: ItemStructNamed = syn::parse2(quote! {
+            /// Check if a database exists
+            #[derive(Debug)]
+            pub struct DbExist {
+                pub database: String,
+                pub uid: OdooId,
+                pub password: String,
+                pub model: String,
+                pub limit: Option<u32>,
+            }
+        })
+        .unwrap();
+        let output = odoo_api(args, input).unwrap().to_string();
+
+        // each client impl starts with the `#[cfg(feature = "client")]` attr
+        output
+            .split("# [cfg (feature = \"client\")]")
+            .skip(1)
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn auth_required() {
+        let impls = expand(quote!(service = "object", method = "db_exist", auth = true));
+        assert_eq!(impls.len(), 1);
+        assert!(impls[0].contains("OdooClient < odoo_api :: client :: Authed , I >"));
+        assert!(impls[0].contains(
+            "pub fn db_exist (& self , model : impl Into < String > , limit : Option < u32 >)"
+        ));
+        assert!(impls[0].contains("database : self . auth . database . clone ()"));
+        assert!(impls[0].contains("uid : self . auth . uid"));
+    }
+
+    #[test]
+    fn auth_not_required() {
+        let impls = expand(quote!(
+            service = "object",
+            method = "db_exist",
+            auth = false
+        ));
+        assert_eq!(impls.len(), 1);
+        assert!(impls[0].contains(
+            "S : odoo_api :: client :: AuthState > odoo_api :: client :: OdooClient < S , I >"
+        ));
+        assert!(impls[0].contains(
+            "pub fn db_exist (& self , database : impl Into < String > , uid : OdooId , password : impl Into < String > , model : impl Into < String > , limit : Option < u32 >)"
+        ));
+        assert!(!impls[0].contains("self . auth"));
+    }
+
+    #[test]
+    fn auth_optional() {
+        let impls = expand(quote!(
+            service = "object",
+            method = "db_exist",
+            auth = "optional"
+        ));
+        assert_eq!(impls.len(), 2);
+
+        // the authed impl auto-fills the credentials
+        assert!(impls[0].contains("OdooClient < odoo_api :: client :: Authed , I >"));
+        assert!(impls[0].contains(
+            "pub fn db_exist (& self , model : impl Into < String > , limit : Option < u32 >)"
+        ));
+        assert!(impls[0].contains("database : self . auth . database . clone ()"));
+
+        // the not-authed impl takes them as arguments
+        assert!(impls[1].contains("OdooClient < odoo_api :: client :: NotAuthed , I >"));
+        assert!(impls[1].contains(
+            "pub fn db_exist_manual (& self , database : impl Into < String > , uid : OdooId , password : impl Into < String > , model : impl Into < String > , limit : Option < u32 >)"
+        ));
+        assert!(!impls[1].contains("self . auth"));
+    }
+
+    #[test]
+    fn auth_omitted() {
+        let impls = expand(quote!(service = "object", method = "db_exist"));
+        assert!(impls.is_empty());
+    }
+
+    #[test]
+    fn auth_invalid() {
+        let args: MacroArguments = syn::parse2(quote!(
+            service = "object",
+            method = "db_exist",
+            auth = "maybe"
+        ))
+        .unwrap();
+        assert!(OdooApiArgs::try_from(args).is_err());
+    }
+
+    #[test]
+    fn auth_missing_credentials() {
+        let args: MacroArguments =
+            syn::parse2(quote!(service = "object", method = "execute", auth = true)).unwrap();
+        let input: ItemStructNamed = syn::parse2(quote! {
+            pub struct Execute {
+                pub database: String,
+                pub model: String,
+            }
+        })
+        .unwrap();
+        match odoo_api(args, input) {
+            Err(Error::MacroError((message, Some(_)))) => assert_eq!(
+                message,
+                "`Execute` requires auth, but is missing a `database` (or `db`) and `password` \
+                 field. Add the fields, or use `auth = false`"
+            ),
+            _ => panic!("expected a spanned error"),
+        }
+    }
+
+    #[test]
+    fn typo_argument() {
+        let args: MacroArguments =
+            syn::parse2(quote!(service = "object", methods = "execute")).unwrap();
+        match OdooApiArgs::try_from(args) {
+            Err(Error::MacroError((message, Some(_)))) => assert_eq!(
+                message,
+                "Invalid argument `methods` (did you mean `method`?). Valid arguments are: \
+                 service, method, auth, name, readonly, map_error"
+            ),
+            _ => panic!("expected a spanned error"),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Field, Fields, Lit, Meta, NestedMeta};
+
+use crate::common::is_option;
+use crate::Result;
+
+/// The `#[odoo(...)]` arguments on a struct or field
+#[derive(Default)]
+struct OdooArgs {
+    /// The Odoo model name (struct only)
+    model: Option<String>,
+
+    /// The Odoo field name, if it differs from the Rust field name (field only)
+    field: Option<String>,
+
+    /// Whether the field is a many2one (field only)
+    many2one: bool,
+}
+
+impl OdooArgs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut args = Self::default();
+        for attr in attrs {
+            if !attr.path.is_ident("odoo") {
+                continue;
+            }
+            let list = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list,
+                _ => {
+                    return Err((
+                        "Expected a list of arguments (e.g., `#[odoo(field = \"parent_id\")]`)",
+                        Some(attr.span()),
+                    )
+                        .into())
+                }
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(value)) => {
+                        let lit = match &value.lit {
+                            Lit::Str(lit) => lit.value(),
+                            _ => {
+                                return Err(
+                                    ("Expected a string literal", Some(value.lit.span())).into()
+                                )
+                            }
+                        };
+                        if value.path.is_ident("model") {
+                            args.model = Some(lit);
+                        } else if value.path.is_ident("field") {
+                            args.field = Some(lit);
+                        } else {
+                            return Err((
+                                "Invalid argument. Valid arguments are: model, field, many2one",
+                                Some(value.path.span()),
+                            )
+                                .into());
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("many2one") => {
+                        args.many2one = true;
+                    }
+                    other => {
+                        return Err((
+                            "Invalid argument. Valid arguments are: model, field, many2one",
+                            Some(other.span()),
+                        )
+                            .into())
+                    }
+                }
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+pub(crate) fn odoo_model(input: TokenStream) -> Result<TokenStream2> {
+    let input: DeriveInput = syn::parse(input).map_err(|e| e.to_compile_error())?;
+    let args = OdooArgs::parse(&input.attrs)?;
+
+    let model = match args.model {
+        Some(model) => model,
+        None => {
+            return Err((
+                "Missing the model name (e.g., `#[odoo(model = \"res.partner\")]`)",
+                Some(Span::call_site()),
+            )
+                .into())
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err((
+            "This macro doesn't support generic structs",
+            Some(input.generics.span()),
+        )
+            .into());
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+            _ => {
+                return Err((
+                    "This macro must be applied to a struct with named fields",
+                    Some(Span::call_site()),
+                )
+                    .into())
+            }
+        },
+        _ => {
+            return Err((
+                "This macro must be applied to a struct with named fields",
+                Some(Span::call_site()),
+            )
+                .into())
+        }
+    };
+
+    let mut names = Vec::new();
+    let mut record_fields = Vec::new();
+    for field in &fields {
+        let (name, record_field) = record_field(field)?;
+        names.push(name);
+        record_fields.push(record_field);
+    }
+    let idents = fields.iter().map(|f| f.ident.as_ref().unwrap());
+    let idents2 = idents.clone();
+
+    let ident = &input.ident;
+    Ok(quote! {
+        impl odoo_api::model::OdooModel for #ident {
+            const MODEL: &'static str = #model;
+            const FIELDS: &'static [&'static str] = &[#(#names),*];
+        }
+
+        impl<'de> odoo_api::model::__serde::Deserialize<'de> for #ident {
+            fn deserialize<__D>(deserializer: __D) -> ::std::result::Result<Self, __D::Error>
+            where
+                __D: odoo_api::model::__serde::Deserializer<'de>,
+            {
+                // the fields are deserialized with their Odoo names and helpers,
+                // then moved into `Self`
+                #[derive(odoo_api::model::__serde::Deserialize)]
+                #[serde(crate = "odoo_api::model::__serde")]
+                struct __OdooRecord {
+                    #(#record_fields),*
+                }
+
+                let record = <__OdooRecord as odoo_api::model::__serde::Deserialize>::deserialize(
+                    deserializer,
+                )?;
+                Ok(Self {
+                    #(#idents: record.#idents2),*
+                })
+            }
+        }
+    })
+}
+
+/// Build the Odoo field name, and the `__OdooRecord` field for `field`
+fn record_field(field: &Field) -> Result<(String, TokenStream2)> {
+    let args = OdooArgs::parse(&field.attrs)?;
+    if args.model.is_some() {
+        return Err(("`model` is only valid on the struct", Some(field.span())).into());
+    }
+
+    let ident = field.ident.as_ref().unwrap();
+    let ty = &field.ty;
+    let name = args.field.unwrap_or_else(|| ident.unraw().to_string());
+
+    // `false` is Odoo's empty value, and a missing `Option` field is `None`
+    let optional = is_option(ty);
+    let with = match (args.many2one, optional) {
+        (true, _) => quote!(deserialize_with = "odoo_api::model::many2one",),
+        (false, true) => quote!(deserialize_with = "odoo_api::serde_helpers::false_as_none",),
+        (false, false) => quote!(),
+    };
+    let default = if optional { quote!(default,) } else { quote!() };
+
+    let record_field = quote! {
+        #[serde(rename = #name, #with #default)]
+        #ident: #ty
+    };
+    Ok((name, record_field))
+}
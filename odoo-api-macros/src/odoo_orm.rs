@@ -1,11 +1,13 @@
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
-use syn::{FieldsNamed, Ident, Type};
+use syn::{FieldsNamed, GenericArgument, Ident, LitStr, PathArguments, Type};
 
-use crate::common::{ItemStructNamed, MacroArguments};
+use crate::common::{
+    is_option_field, redact_debug, require_fields, suggest, validate_field_names, ItemStructNamed,
+    MacroArguments, RegistryEntry, COMPANY_METHODS, READONLY_METHODS,
+};
 use crate::{Error, Result};
 
-#[derive(Debug)]
 struct OdooOrmArgs {
     /// The ORM "method" (e.g. 'write', 'read_group', etc)
     method: String,
@@ -14,10 +16,14 @@ struct OdooOrmArgs {
     name: Option<String>,
 
     /// A list of the positional arguments
-    args: Vec<String>,
+    args: Vec<LitStr>,
 
     /// A list of the keyword arguments
-    kwargs: Vec<String>,
+    kwargs: Vec<LitStr>,
+
+    /// Whether the method can be sent to a read replica (defaults to whether
+    /// `method` is one of the [`READONLY_METHODS`])
+    readonly: Option<bool>,
 }
 
 impl TryFrom<MacroArguments> for OdooOrmArgs {
@@ -30,6 +36,7 @@ impl TryFrom<MacroArguments> for OdooOrmArgs {
         let mut name = None;
         let mut args = None;
         let mut kwargs = None;
+        let mut readonly = None;
 
         for arg in value.into_iter() {
             match (arg.key.as_str(), arg.value, arg.span) {
@@ -61,11 +68,20 @@ impl TryFrom<MacroArguments> for OdooOrmArgs {
                         Some(span)
                     ))?);
                 }
+                ("readonly", val, span) => {
+                    readonly = Some(val.try_into().map_err(|_| {
+                        (
+                            "invalid value, expected bool (e.g., `readonly = false`)",
+                            Some(span),
+                        )
+                    })?);
+                }
 
                 (key, _val, span) => Err((
                     format!(
-                        "Invalid argument `{}`. Valid arguments are: method, name, args, kwargs",
-                        key
+                        "Invalid argument `{}`{}. Valid arguments are: method, name, args, kwargs, readonly",
+                        key,
+                        suggest(key, &["method", "name", "args", "kwargs", "readonly"])
                     ),
                     Some(span),
                 ))?,
@@ -83,13 +99,24 @@ impl TryFrom<MacroArguments> for OdooOrmArgs {
             kwargs: kwargs.ok_or(
                 "The \"kwargs\" key is required, even if you only pass an empty array (e.g., `kwargs = []`)"
             )?,
+            readonly,
         })
     }
 }
 
-pub(crate) fn odoo_orm(args: MacroArguments, input: ItemStructNamed) -> Result<TokenStream2> {
+pub(crate) fn odoo_orm(args: MacroArguments, mut input: ItemStructNamed) -> Result<TokenStream2> {
     let args: OdooOrmArgs = args.try_into()?;
 
+    // the generated Serialize impl reads these fields directly, so catch
+    // mistakes here (rather than with a confusing type error later)
+    require_fields(
+        &input,
+        &["database", "uid", "password", "model"],
+        "required ORM",
+    )?;
+    validate_field_names(&input, "args", &args.args)?;
+    validate_field_names(&input, "kwargs", &args.kwargs)?;
+
     // fetch the struct name (and some variations)
     let name_struct = input.item.ident.to_string();
     let name_response = format!("{}Response", &name_struct);
@@ -110,36 +137,89 @@ pub(crate) fn odoo_orm(args: MacroArguments, input: ItemStructNamed) -> Result<T
     );
 
     // build the TokenStreams
-    let out_params = impl_params(&ident_struct, &ident_response)?;
-    let out_method = impl_method(&ident_struct, &args)?;
-    let out_client = impl_client(&ident_struct, &ident_call, &input.fields, &doc_call)?;
-    let out_serialize = impl_serialize(&ident_struct, &args)?;
+    let out_debug = redact_debug(&mut input)?;
+    let readonly = args
+        .readonly
+        .unwrap_or_else(|| READONLY_METHODS.contains(&args.method.as_str()));
+    let out_params = impl_params(&ident_struct, &ident_response, readonly)?;
+    let out_method = impl_method(&ident_struct, &input.fields, &args)?;
+    let out_client = impl_client(&ident_struct, &ident_call, &input.fields, &args, &doc_call)?;
+    let out_serialize = impl_serialize(&ident_struct)?;
+    let out_registry = RegistryEntry {
+        service: "orm",
+        method: &args.method,
+        name: &name_call,
+        auth: true,
+        args: args.args.iter().map(LitStr::value).collect(),
+        kwargs: args.kwargs.iter().map(LitStr::value).collect(),
+    }
+    .submit();
 
     // output the result!
     Ok(quote!(
         #input
+        #out_debug
         #out_params
         #out_method
         #out_client
         #out_serialize
+        #out_registry
     ))
 }
 
 /// Output the [`JsonRpcParams`](odoo_api::jsonrpc::JsonRpcParams) impl
-pub(crate) fn impl_params(ident_struct: &Ident, ident_response: &Ident) -> Result<TokenStream2> {
+pub(crate) fn impl_params(
+    ident_struct: &Ident,
+    ident_response: &Ident,
+    readonly: bool,
+) -> Result<TokenStream2> {
     Ok(quote! {
         impl odoo_api::jsonrpc::JsonRpcParams for #ident_struct {
             type Container<T> = odoo_api::jsonrpc::OdooOrmContainer <Self>;
             type Response = #ident_response;
+            const IS_READONLY: bool = #readonly;
 
             fn build(self, id: odoo_api::jsonrpc::JsonRpcId) -> odoo_api::jsonrpc::JsonRpcRequest<Self> { self._build(id) }
         }
     })
 }
 
-/// Output the OdooApiMethod impl
-fn impl_method(ident_struct: &Ident, args: &OdooOrmArgs) -> Result<TokenStream2> {
+/// Output the OdooOrmMethod impl
+///
+/// `to_execute_kw()` builds the positional args and kwargs, and is also used by
+/// the generated Serialize impl (see [`impl_serialize`]).
+fn impl_method(
+    ident_struct: &Ident,
+    fields: &FieldsNamed,
+    args: &OdooOrmArgs,
+) -> Result<TokenStream2> {
     let method = &args.method;
+    let ident_args: Vec<Ident> = args
+        .args
+        .iter()
+        .map(|x| Ident::new(&x.value(), x.span()))
+        .collect();
+    // `Option` kwargs are only sent when they're `Some`, so that Odoo falls
+    // back to the Python default (e.g., `operator="ilike"`) rather than `None`
+    let insert_kwargs = args.kwargs.iter().map(|lit| {
+        let ident = Ident::new(&lit.value(), lit.span());
+        if is_option_field(fields, &lit.value()) {
+            quote!(
+                if let Some(value) = &self.#ident {
+                    kwargs.insert(#lit.into(), ::serde_json::json!(value));
+                }
+            )
+        } else {
+            quote!(
+                kwargs.insert(#lit.into(), ::serde_json::json!(&self.#ident));
+            )
+        }
+    });
+    let sets_company = COMPANY_METHODS.contains(&method.as_str()).then(|| {
+        quote!(
+            const SETS_COMPANY: bool = true;
+        )
+    });
     Ok(quote! {
         impl odoo_api::jsonrpc::OdooOrmMethod for #ident_struct {
             fn endpoint(&self) -> &'static str {
@@ -149,15 +229,45 @@ fn impl_method(ident_struct: &Ident, args: &OdooOrmArgs) -> Result<TokenStream2>
             fn method(&self) -> &'static str {
                 #method
             }
+
+            fn to_execute_kw(&self) -> (
+                String,
+                String,
+                Vec<::serde_json::Value>,
+                ::serde_json::Map<String, ::serde_json::Value>,
+            ) {
+                let args = vec![
+                    #(::serde_json::json!(&self.#ident_args)),*
+                ];
+
+                let mut kwargs = ::serde_json::Map::new();
+                #(#insert_kwargs)*
+
+                (self.model.clone(), self.method().into(), args, kwargs)
+            }
+        }
+
+        // ORM methods are always sent via `execute_kw`, so they can carry a context
+        impl odoo_api::jsonrpc::OdooKwargsMethod for #ident_struct {
+            #sets_company
         }
     })
 }
 
 /// Output the OdooClient impl
+///
+/// Optional (`Option<T>`) kwargs aren't client method arguments; instead, they
+/// default to `None`, and are set with builder methods on the returned
+/// `OdooRequest` (see [`impl_setters`]).
+///
+/// A `_manual` variant is also implemented for any `OdooClient<S: AuthState>`,
+/// taking the `db`, `uid`, and `password` as arguments (rather than fetching
+/// them from the `client.auth` struct).
 fn impl_client(
     ident_struct: &Ident,
     ident_call: &Ident,
     fields: &FieldsNamed,
+    args: &OdooOrmArgs,
     doc: &str,
 ) -> Result<TokenStream2> {
     // parse the `auth` argument options
@@ -168,6 +278,11 @@ fn impl_client(
     let mut field_assigns = Vec::new();
     let mut field_arguments = Vec::new();
     let mut field_generics = Vec::new();
+    let mut required_assigns = Vec::new();
+    let mut required_arguments = Vec::new();
+    let mut manual_assigns = Vec::new();
+    let mut manual_arguments = Vec::new();
+    let mut setters = Vec::new();
     for field in fields.named.clone() {
         let ident = field.ident.unwrap();
         let ty = if let Type::Path(path) = field.ty {
@@ -177,35 +292,56 @@ fn impl_client(
         };
         let name = ident.to_string();
         let path = ty.clone().into_token_stream().to_string();
+        let num_arguments = field_arguments.len();
         match (name.as_str(), path.as_str()) {
             // special cases (data fetched from the `client.auth` struct)
             ("database", "String") => {
                 field_assigns.push(quote!(database: self.auth.database.clone()));
+                manual_assigns.push(quote!(database: db.into()));
+                manual_arguments.push(quote!(db: &str));
             }
             ("db", "String") => {
                 field_assigns.push(quote!(db: self.auth.database.clone()));
+                manual_assigns.push(quote!(db: db.into()));
+                manual_arguments.push(quote!(db: &str));
             }
             ("uid", "OdooId") => {
                 field_assigns.push(quote!(uid: self.auth.uid));
+                manual_assigns.push(quote!(uid: uid));
+                manual_arguments.push(quote!(uid: odoo_api::jsonrpc::OdooId));
             }
             ("login", "String") => {
                 field_assigns.push(quote!(login: self.auth.login.clone()));
+                manual_assigns.push(quote!(login: login.into()));
+                manual_arguments.push(quote!(login: &str));
             }
             ("password", "String") => {
                 field_assigns.push(quote!(password: self.auth.password.clone()));
+                manual_assigns.push(quote!(password: password.into()));
+                manual_arguments.push(quote!(password: &str));
+            }
+
+            // optional kwargs are set via the `OdooRequest` builder
+            (_, _) if is_optional_kwarg(fields, args, &name) => {
+                field_assigns.push(quote!(#ident: #ident));
+                field_arguments.push(quote!(#ident: #ty));
+                required_assigns.push(quote!(#ident: None));
+                manual_assigns.push(quote!(#ident: None));
+                setters.push(ident);
+                continue;
             }
 
-            // strings are passed by ref
-            //TODO: Into<String> would be more performant in some cases
+            // strings accept `&str`, `String`, `Cow<str>`, etc (owned strings
+            // are moved, rather than cloned)
             (_, "String") => {
                 field_assigns.push(quote!(#ident: #ident.into()));
-                field_arguments.push(quote!(#ident: &str));
+                field_arguments.push(quote!(#ident: impl Into<String>));
             }
 
+            // ids accept a single id, a `Vec`, a slice, or an array
             (_, "OdooIds") => {
-                field_generics.push(quote!(ID: Into<OdooIds>));
                 field_assigns.push(quote!(#ident: #ident.into()));
-                field_arguments.push(quote!(#ident: ID));
+                field_arguments.push(quote!(#ident: impl Into<odoo_api::jsonrpc::OdooIds>));
             }
 
             (_, "CreateVals") => {
@@ -220,68 +356,468 @@ fn impl_client(
                 field_arguments.push(quote!(#ident: #ty));
             }
         }
+
+        // everything else is the same as the deprecated (positional) method
+        required_assigns.push(field_assigns.last().unwrap().clone());
+        if manual_assigns.len() < required_assigns.len() {
+            manual_assigns.push(field_assigns.last().unwrap().clone());
+        }
+        if field_arguments.len() > num_arguments {
+            required_arguments.push(field_arguments.last().unwrap().clone());
+        }
+    }
+
+    // the `_manual` variant takes the credentials, then the usual arguments
+    let ident_manual = Ident::new(&format!("{}_manual", ident_call), ident_call.span());
+    let doc_manual = format!(
+        "{}\n\nThis is available on any client (including `NotAuthed`), and takes the \
+         credentials explicitly, rather than using the authenticated session.",
+        doc
+    );
+    manual_arguments.extend(required_arguments.iter().cloned());
+    let out_manual = quote! {
+        #[cfg(feature = "client")]
+        impl<S: odoo_api::client::AuthState, I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<S, I> {
+            #[doc=#doc_manual]
+            pub fn #ident_manual<#(#field_generics),*>(&self, #(#manual_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
+                let #ident_call = #ident_struct {
+                    #(#manual_assigns),*
+                };
+
+                let endpoint = self.build_endpoint(#ident_call.endpoint());
+                self.build_kwargs_request(
+                    #ident_call,
+                    &endpoint
+                )
+            }
+        }
+    };
+
+    // without any optional kwargs, there's no need for the deprecated shim
+    if setters.is_empty() {
+        return Ok(quote! {
+            #[cfg(feature = "client")]
+            #[doc=#doc]
+            impl<I: odoo_api::client::RequestImpl, #auth_generic> odoo_api::client::OdooClient<#auth_type, I> {
+                pub fn #ident_call<#(#field_generics),*>(&self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
+                    let #ident_call = #ident_struct {
+                        #(#field_assigns),*
+                    };
+
+                    let endpoint = self.build_endpoint(#ident_call.endpoint());
+                    self.build_kwargs_request(
+                        #ident_call,
+                        &endpoint
+                    )
+                }
+            }
+
+            #out_manual
+        });
     }
 
+    let ident_positional = Ident::new(&format!("{}_positional", ident_call), ident_call.span());
+    let doc_setters = format!(
+        "{}\n\nOptional kwargs are set on the returned request (e.g., `.{}(...)`).",
+        doc, &setters[0]
+    );
+    let deprecated = format!(
+        "optional kwargs are now set on the returned request, e.g. `client.{}(...).{}(...)`",
+        ident_call, &setters[0]
+    );
+    let out_setters = impl_setters(ident_struct, fields, &setters)?;
+
     Ok(quote! {
-        #[cfg(not(feature = "types-only"))]
-        #[doc=#doc]
+        #[cfg(feature = "client")]
+        #[doc=#doc_setters]
         impl<I: odoo_api::client::RequestImpl, #auth_generic> odoo_api::client::OdooClient<#auth_type, I> {
-            pub fn #ident_call<#(#field_generics),*>(&mut self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
+            pub fn #ident_call<#(#field_generics),*>(&self, #(#required_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
+                let #ident_call = #ident_struct {
+                    #(#required_assigns),*
+                };
+
+                let endpoint = self.build_endpoint(#ident_call.endpoint());
+                self.build_kwargs_request(
+                    #ident_call,
+                    &endpoint
+                )
+            }
+
+            #[doc=#doc]
+            #[deprecated(since = "0.2.7", note = #deprecated)]
+            pub fn #ident_positional<#(#field_generics),*>(&self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
                 let #ident_call = #ident_struct {
                     #(#field_assigns),*
                 };
 
                 let endpoint = self.build_endpoint(#ident_call.endpoint());
-                self.build_request(
+                self.build_kwargs_request(
                     #ident_call,
                     &endpoint
                 )
             }
         }
+
+        #out_manual
+        #out_setters
     })
 }
 
-fn impl_serialize(ident_struct: &Ident, args: &OdooOrmArgs) -> Result<TokenStream2> {
-    let ident_args: Vec<Ident> = args
-        .args
-        .iter()
-        .map(|x| Ident::new(x, Span::call_site()))
-        .collect();
-    let lit_kwargs = args.kwargs.clone();
-    let ident_kwargs: Vec<Ident> = args
-        .kwargs
+/// Output the builder methods for the optional kwargs
+///
+/// These are implemented directly on `OdooRequest<'a, #ident_struct, I>`, so
+/// they're only available for the matching method (e.g., `.order()` for `search`).
+fn impl_setters(
+    ident_struct: &Ident,
+    fields: &FieldsNamed,
+    setters: &[Ident],
+) -> Result<TokenStream2> {
+    let methods = setters.iter().map(|ident| {
+        let doc = format!("Set the `{}` kwarg", ident);
+        match option_inner(fields, &ident.to_string()) {
+            // strings accept any `Into<String>` (matching the client methods)
+            Some(ty) if ty.to_token_stream().to_string() == "String" => quote! {
+                #[doc=#doc]
+                pub fn #ident(mut self, #ident: impl Into<String>) -> Self {
+                    self.data.params.inner.#ident = Some(#ident.into());
+                    self
+                }
+            },
+            ty => quote! {
+                #[doc=#doc]
+                pub fn #ident(mut self, #ident: #ty) -> Self {
+                    self.data.params.inner.#ident = Some(#ident);
+                    self
+                }
+            },
+        }
+    });
+
+    Ok(quote! {
+        #[cfg(feature = "client")]
+        impl<'a, I: odoo_api::client::RequestImpl> odoo_api::client::OdooRequest<'a, #ident_struct, I> {
+            #(#methods)*
+        }
+    })
+}
+
+/// Check whether `name` is a kwarg with an `Option<T>` type
+fn is_optional_kwarg(fields: &FieldsNamed, args: &OdooOrmArgs, name: &str) -> bool {
+    args.kwargs.iter().any(|lit| lit.value() == name) && is_option_field(fields, name)
+}
+
+/// Fetch the `T` from an `Option<T>` field
+fn option_inner<'a>(fields: &'a FieldsNamed, name: &str) -> Option<&'a Type> {
+    let field = fields
+        .named
         .iter()
-        .map(|x| Ident::new(x, Span::call_site()))
-        .collect();
+        .find(|field| matches!(&field.ident, Some(ident) if ident == name))?;
+    let segment = match &field.ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(generics) => match generics.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn impl_serialize(ident_struct: &Ident) -> Result<TokenStream2> {
     Ok(quote!(
         impl serde::Serialize for #ident_struct {
             fn serialize<S>(&self, serialize: S) -> ::std::result::Result<S::Ok, S::Error>
             where
                 S: serde::Serializer
             {
-                let mut state = serialize.serialize_tuple(5)?;
+                //TODO: serialize the args/kwargs directly (serialize.clone() ?)
+                let (model, method, args, kwargs) = self.to_execute_kw();
+
+                let mut state = serialize.serialize_tuple(7)?;
                 state.serialize_element(&self.database)?;
                 state.serialize_element(&self.uid)?;
                 state.serialize_element(&self.password)?;
-                state.serialize_element(&self.model)?;
-                state.serialize_element(self.method())?;
-
-                //TODO: serialize these directly (serialize.clone() ?)
-                state.serialize_element(&(
-                    ::serde_json::json!([
-                        #(&self.#ident_args),*
-                    ])
-                ))?;
-
-                //TODO: serialize these directly (serialize.clone() ?)
-                state.serialize_element(&(
-                    ::serde_json::json!({
-                        #(#lit_kwargs : &self.#ident_kwargs),*
-                    })
-                ))?;
-
+                state.serialize_element(&model)?;
+                state.serialize_element(&method)?;
+                state.serialize_element(&args)?;
+                state.serialize_element(&kwargs)?;
                 state.end()
             }
         }
     ))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Expand the macro, and return the error message
+    fn expand_err(args: TokenStream2, input: TokenStream2) -> String {
+        let args: MacroArguments = syn::parse2(args).unwrap();
+        let input: ItemStructNamed = syn::parse2(input).unwrap();
+        match odoo_orm(args, input) {
+            Err(Error::MacroError((message, span))) => {
+                assert!(span.is_some(), "error should be spanned: {}", message);
+                message
+            }
+            Err(Error::TokenStream(ts)) => panic!("unexpected error: {}", ts),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    fn read_struct() -> TokenStream2 {
+        quote! {
+            /// Read data from a record
+            pub struct Read {
+                pub database: String,
+                pub uid: OdooId,
+                pub password: String,
+                pub model: String,
+                pub ids: OdooIds,
+                pub fields: Vec<String>,
+            }
+        }
+    }
+
+    #[test]
+    fn valid() {
+        let args: MacroArguments = syn::parse2(quote!(
+            method = "read",
+            args = ["ids", "fields"],
+            kwargs = []
+        ))
+        .unwrap();
+        let input: ItemStructNamed = syn::parse2(read_struct()).unwrap();
+        assert!(odoo_orm(args, input).is_ok());
+    }
+
+    #[test]
+    fn registry() {
+        let args: MacroArguments =
+            syn::parse2(quote!(method = "read", args = ["ids"], kwargs = ["fields"])).unwrap();
+        let input: ItemStructNamed = syn::parse2(read_struct()).unwrap();
+        let output = odoo_orm(args, input).unwrap().to_string();
+        assert!(output.contains("# [cfg (feature = \"registry\")]"));
+        assert!(output.contains(
+            "service : \"orm\" , method : \"read\" , name : \"read\" , auth : true , \
+             args : & [\"ids\"] , kwargs : & [\"fields\"]"
+        ));
+    }
+
+    #[test]
+    fn optional_kwargs() {
+        let args: MacroArguments = syn::parse2(quote!(
+            method = "search",
+            args = ["domain"],
+            kwargs = ["limit", "order"]
+        ))
+        .unwrap();
+        let input: ItemStructNamed = syn::parse2(quote! {
+            /// Search for records
+            pub struct Search {
+                pub database: String,
+                pub uid: OdooId,
+                pub password: String,
+                pub model: String,
+                pub domain: Vec<Value>,
+                pub limit: Option<u32>,
+                pub order: Option<String>,
+            }
+        })
+        .unwrap();
+        let output = odoo_orm(args, input).unwrap().to_string();
+
+        // the optional kwargs are dropped from the client method..
+        assert!(output.contains(
+            "pub fn search < > (& self , model : impl Into < String > , domain : Vec < Value >)"
+        ));
+        assert!(output.contains("limit : None , order : None"));
+
+        // ..and set with builder methods instead
+        assert!(output.contains("OdooRequest < 'a , Search , I >"));
+        assert!(output.contains("pub fn limit (mut self , limit : u32) -> Self"));
+        assert!(output.contains("pub fn order (mut self , order : impl Into < String >) -> Self"));
+
+        // the old signature is kept, but deprecated
+        assert!(output.contains("# [deprecated"));
+        assert!(output.contains(
+            "pub fn search_positional < > (& self , model : impl Into < String > , domain : Vec < Value > , limit : Option < u32 > , order : Option < String >)"
+        ));
+    }
+
+    #[test]
+    fn manual() {
+        let args: MacroArguments = syn::parse2(quote!(
+            method = "read",
+            args = ["ids", "fields"],
+            kwargs = []
+        ))
+        .unwrap();
+        let input: ItemStructNamed = syn::parse2(read_struct()).unwrap();
+        let output = odoo_orm(args, input).unwrap().to_string();
+
+        // implemented for any client, taking the credentials explicitly
+        assert!(output.contains(
+            "impl < S : odoo_api :: client :: AuthState , I : odoo_api :: client :: RequestImpl > odoo_api :: client :: OdooClient < S , I >"
+        ));
+        assert!(output.contains(
+            "pub fn read_manual < > (& self , db : & str , uid : odoo_api :: jsonrpc :: OdooId , password : & str , model : impl Into < String > , ids : impl Into < odoo_api :: jsonrpc :: OdooIds > , fields : Vec < String >)"
+        ));
+        assert!(output.contains(
+            "database : db . into () , uid : uid , password : password . into () , model : model . into ()"
+        ));
+    }
+
+    #[test]
+    fn manual_optional_kwargs() {
+        let args: MacroArguments = syn::parse2(quote!(
+            method = "search",
+            args = ["domain"],
+            kwargs = ["limit"]
+        ))
+        .unwrap();
+        let input: ItemStructNamed = syn::parse2(quote! {
+            /// Search for records
+            pub struct Search {
+                pub database: String,
+                pub uid: OdooId,
+                pub password: String,
+                pub model: String,
+                pub domain: Vec<Value>,
+                pub limit: Option<u32>,
+            }
+        })
+        .unwrap();
+        let output = odoo_orm(args, input).unwrap().to_string();
+
+        // the optional kwargs are set on the returned request, as usual
+        assert!(output.contains(
+            "pub fn search_manual < > (& self , db : & str , uid : odoo_api :: jsonrpc :: OdooId , password : & str , model : impl Into < String > , domain : Vec < Value >)"
+        ));
+        assert!(output.contains("domain : domain , limit : None"));
+        assert!(!output.contains("search_positional_manual"));
+    }
+
+    #[test]
+    fn no_optional_kwargs() {
+        let args: MacroArguments = syn::parse2(quote!(
+            method = "read",
+            args = ["ids", "fields"],
+            kwargs = []
+        ))
+        .unwrap();
+        let input: ItemStructNamed = syn::parse2(read_struct()).unwrap();
+        let output = odoo_orm(args, input).unwrap().to_string();
+
+        assert!(!output.contains("OdooRequest < 'a"));
+        assert!(!output.contains("read_positional"));
+    }
+
+    #[test]
+    fn readonly() {
+        let expand = |args: TokenStream2| {
+            let args: MacroArguments = syn::parse2(args).unwrap();
+            let input: ItemStructNamed = syn::parse2(read_struct()).unwrap();
+            odoo_orm(args, input).unwrap().to_string()
+        };
+
+        // inferred from the method name..
+        let output = expand(quote!(method = "read", args = ["ids"], kwargs = ["fields"]));
+        assert!(output.contains("const IS_READONLY : bool = true ;"));
+        let output = expand(quote!(
+            method = "write",
+            args = ["ids"],
+            kwargs = ["fields"]
+        ));
+        assert!(output.contains("const IS_READONLY : bool = false ;"));
+
+        // ..or set explicitly
+        let output = expand(quote!(
+            method = "read_report",
+            args = ["ids"],
+            kwargs = ["fields"],
+            readonly
+        ));
+        assert!(output.contains("const IS_READONLY : bool = true ;"));
+        let output = expand(quote!(
+            method = "read",
+            args = ["ids"],
+            kwargs = ["fields"],
+            readonly = false
+        ));
+        assert!(output.contains("const IS_READONLY : bool = false ;"));
+    }
+
+    #[test]
+    fn typo_argument() {
+        let message = expand_err(
+            quote!(method = "read", arg = ["ids", "fields"], kwargs = []),
+            read_struct(),
+        );
+        assert_eq!(
+            message,
+            "Invalid argument `arg` (did you mean `args`?). Valid arguments are: method, name, args, kwargs, readonly"
+        );
+    }
+
+    #[test]
+    fn unknown_arg_field() {
+        let message = expand_err(
+            quote!(method = "read", args = ["ids", "field"], kwargs = []),
+            read_struct(),
+        );
+        assert_eq!(
+            message,
+            "`args` lists `field`, but `Read` has no field with that name"
+        );
+    }
+
+    #[test]
+    fn unknown_kwarg_field() {
+        let message = expand_err(
+            quote!(
+                method = "read",
+                args = ["ids"],
+                kwargs = ["fields", "limit"]
+            ),
+            read_struct(),
+        );
+        assert_eq!(
+            message,
+            "`kwargs` lists `limit`, but `Read` has no field with that name"
+        );
+    }
+
+    #[test]
+    fn invalid_field_name() {
+        // this would otherwise panic in `Ident::new`
+        let message = expand_err(
+            quote!(method = "read", args = ["ids", "my-field"], kwargs = []),
+            read_struct(),
+        );
+        assert_eq!(
+            message,
+            "`args` lists `my-field`, but `Read` has no field with that name"
+        );
+    }
+
+    #[test]
+    fn missing_required_fields() {
+        let message = expand_err(
+            quote!(method = "read", args = ["ids"], kwargs = []),
+            quote! {
+                pub struct Read {
+                    pub database: String,
+                    pub password: String,
+                    pub ids: OdooIds,
+                }
+            },
+        );
+        assert_eq!(
+            message,
+            "`Read` is missing the required ORM field(s): `uid`, `model`"
+        );
+    }
+}
@@ -2,7 +2,7 @@ use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
 use syn::{FieldsNamed, Ident, Type};
 
-use crate::common::{ItemStructNamed, MacroArguments};
+use crate::common::{redact_debug, suggest, ItemStructNamed, MacroArguments, RegistryEntry};
 use crate::{Error, Result};
 
 struct OdooWebArgs {
@@ -53,8 +53,9 @@ impl TryFrom<MacroArguments> for OdooWebArgs {
 
                 (key, _val, span) => Err((
                     format!(
-                        "Invalid argument `{}`. Valid arguments are: path, name, auth",
-                        key
+                        "Invalid argument `{}`{}. Valid arguments are: path, name, auth",
+                        key,
+                        suggest(key, &["path", "name", "auth"])
                     ),
                     Some(span),
                 ))?,
@@ -72,7 +73,7 @@ impl TryFrom<MacroArguments> for OdooWebArgs {
     }
 }
 
-pub(crate) fn odoo_web(args: MacroArguments, input: ItemStructNamed) -> Result<TokenStream2> {
+pub(crate) fn odoo_web(args: MacroArguments, mut input: ItemStructNamed) -> Result<TokenStream2> {
     let args: OdooWebArgs = args.try_into()?;
 
     // fetch the struct name (and some variations)
@@ -91,16 +92,31 @@ pub(crate) fn odoo_web(args: MacroArguments, input: ItemStructNamed) -> Result<T
     );
 
     // build the TokenStreams
+    let out_debug = redact_debug(&mut input)?;
     let out_params = impl_params(&ident_struct, &ident_response)?;
     let out_method = impl_method(&ident_struct, &args)?;
     let out_client = impl_client(&ident_struct, &ident_call, &args, &input.fields, &doc_call)?;
+    let out_registry = match args.auth {
+        Some(auth) => RegistryEntry {
+            service: "web",
+            method: &args.path,
+            name: &name_call,
+            auth,
+            args: RegistryEntry::client_args(&input.fields, auth),
+            kwargs: Vec::new(),
+        }
+        .submit(),
+        None => quote!(),
+    };
 
     // output the result!
     Ok(quote!(
         #input
+        #out_debug
         #out_params
         #out_method
         #out_client
+        #out_registry
     ))
 }
 
@@ -121,7 +137,7 @@ fn impl_method(ident_struct: &Ident, args: &OdooWebArgs) -> Result<TokenStream2>
     let path = &args.path;
     Ok(quote! {
         impl odoo_api::jsonrpc::OdooWebMethod for #ident_struct {
-            fn endpoint(&self) -> &'static str {
+            fn endpoint(&self) -> &str {
                 #path
             }
         }
@@ -182,11 +198,11 @@ fn impl_client(
                 field_assigns.push(quote!(password: self.auth.password.clone()));
             }
 
-            // strings are passed by ref
-            //TODO: Into<String> would be more performant in some cases
+            // strings accept `&str`, `String`, `Cow<str>`, etc (owned strings
+            // are moved, rather than cloned)
             (_, "String", _) => {
                 field_assigns.push(quote!(#ident: #ident.into()));
-                field_arguments.push(quote!(#ident: &str));
+                field_arguments.push(quote!(#ident: impl Into<String>));
             }
 
             // all other fields are passed as-is
@@ -198,10 +214,10 @@ fn impl_client(
     }
 
     Ok(quote! {
-        #[cfg(not(feature = "types-only"))]
+        #[cfg(feature = "client")]
         #[doc=#doc]
         impl<I: odoo_api::client::RequestImpl, #auth_generic> odoo_api::client::OdooClient<#auth_type, I> {
-            pub fn #ident_call(&mut self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
+            pub fn #ident_call(&self, #(#field_arguments),*) -> odoo_api::client::OdooRequest< #ident_struct , I> {
                 let #ident_call = #ident_struct {
                     #(#field_assigns),*
                 };
@@ -1,9 +1,131 @@
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Meta, NestedMeta};
 
+use crate::common::is_option;
 use crate::Result;
 
-//TODO
+/// Options parsed from the `#[serialize_tuple(...)]` attribute
+#[derive(Default)]
+struct SerializeTupleArgs {
+    /// Omit trailing `Option` fields that are `None`
+    skip_trailing_none: bool,
+}
+
+impl TryFrom<&DeriveInput> for SerializeTupleArgs {
+    type Error = crate::Error;
+
+    fn try_from(input: &DeriveInput) -> Result<Self> {
+        let mut args = Self::default();
+        for attr in &input.attrs {
+            if !attr.path.is_ident("serialize_tuple") {
+                continue;
+            }
+            let list = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list,
+                _ => return Err((
+                    "Expected a list of arguments (e.g., `#[serialize_tuple(skip_trailing_none)]`)",
+                    Some(Span::call_site()),
+                )
+                    .into()),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip_trailing_none") => {
+                        args.skip_trailing_none = true;
+                    }
+                    _ => {
+                        return Err((
+                            "Invalid argument. Valid arguments are: skip_trailing_none",
+                            Some(Span::call_site()),
+                        )
+                            .into())
+                    }
+                }
+            }
+        }
+
+        Ok(args)
+    }
+}
+
 pub(crate) fn serialize_tuple(input: TokenStream) -> Result<TokenStream2> {
-    Ok(input.into())
+    let input: DeriveInput = syn::parse(input).map_err(|e| e.to_compile_error())?;
+    let args = SerializeTupleArgs::try_from(&input)?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+            _ => {
+                return Err((
+                    "This macro must be applied to a struct with named fields",
+                    Some(Span::call_site()),
+                )
+                    .into())
+            }
+        },
+        _ => {
+            return Err((
+                "This macro must be applied to a struct with named fields",
+                Some(Span::call_site()),
+            )
+                .into())
+        }
+    };
+
+    // the number of trailing `Option` fields which may be skipped
+    let trailing = if args.skip_trailing_none {
+        fields.iter().rev().take_while(|f| is_option(&f.ty)).count()
+    } else {
+        0
+    };
+    let fixed = fields.len() - trailing;
+
+    let idents = fields.iter().map(|f| f.ident.as_ref().unwrap());
+    let elements = idents.clone().enumerate().map(|(i, ident)| {
+        if i < fixed {
+            quote!(state.serialize_element(&self.#ident)?;)
+        } else {
+            quote!(if #i < len { state.serialize_element(&self.#ident)?; })
+        }
+    });
+
+    // Nones are only skipped from the end, so that the remaining positions
+    // still line up
+    let trailing_idents = idents.skip(fixed);
+    let len = if trailing == 0 {
+        quote!(let len = #fixed;)
+    } else {
+        quote! {
+            let len = #fixed + [#(self.#trailing_idents.is_some()),*]
+                .iter()
+                .rposition(|some| *some)
+                .map(|i| i + 1)
+                .unwrap_or(0);
+        }
+    };
+
+    // as with serde's derive, each type param must also be `Serialize`
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(serde::Serialize));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics serde::Serialize for #ident #ty_generics #where_clause {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeTuple;
+                #len
+                let mut state = serializer.serialize_tuple(len)?;
+                #(#elements)*
+                state.end()
+            }
+        }
+    })
 }
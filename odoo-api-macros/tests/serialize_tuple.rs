@@ -0,0 +1,111 @@
+use odoo_api_macros::SerializeTuple;
+use serde_json::{json, to_value};
+
+#[derive(SerializeTuple)]
+struct Positional {
+    a: String,
+    b: Option<String>,
+}
+
+#[derive(SerializeTuple)]
+#[serialize_tuple(skip_trailing_none)]
+struct NoOptions {
+    a: String,
+    b: i32,
+}
+
+#[derive(SerializeTuple)]
+#[serialize_tuple(skip_trailing_none)]
+struct OneTrailing {
+    a: String,
+    b: Option<String>,
+}
+
+#[derive(SerializeTuple)]
+#[serialize_tuple(skip_trailing_none)]
+struct TwoTrailing {
+    a: String,
+    b: Option<i32>,
+    c: Option<String>,
+}
+
+#[derive(SerializeTuple)]
+#[serialize_tuple(skip_trailing_none)]
+struct Generic<T> {
+    a: T,
+    b: Option<T>,
+}
+
+#[test]
+fn positional() -> serde_json::Result<()> {
+    let value = Positional {
+        a: "a".into(),
+        b: None,
+    };
+    assert_eq!(to_value(value)?, json!(["a", null]));
+
+    Ok(())
+}
+
+#[test]
+fn zero_trailing() -> serde_json::Result<()> {
+    let value = NoOptions {
+        a: "a".into(),
+        b: 1,
+    };
+    assert_eq!(to_value(value)?, json!(["a", 1]));
+
+    Ok(())
+}
+
+#[test]
+fn one_trailing() -> serde_json::Result<()> {
+    let value = OneTrailing {
+        a: "a".into(),
+        b: None,
+    };
+    assert_eq!(to_value(value)?, json!(["a"]));
+
+    let value = OneTrailing {
+        a: "a".into(),
+        b: Some("b".into()),
+    };
+    assert_eq!(to_value(value)?, json!(["a", "b"]));
+
+    Ok(())
+}
+
+#[test]
+fn two_trailing() -> serde_json::Result<()> {
+    let value = TwoTrailing {
+        a: "a".into(),
+        b: None,
+        c: None,
+    };
+    assert_eq!(to_value(value)?, json!(["a"]));
+
+    let value = TwoTrailing {
+        a: "a".into(),
+        b: Some(1),
+        c: None,
+    };
+    assert_eq!(to_value(value)?, json!(["a", 1]));
+
+    // a `None` before a `Some` is kept, so the positions line up
+    let value = TwoTrailing {
+        a: "a".into(),
+        b: None,
+        c: Some("c".into()),
+    };
+    assert_eq!(to_value(value)?, json!(["a", null, "c"]));
+
+    Ok(())
+}
+
+#[test]
+fn generics() -> serde_json::Result<()> {
+    let value = Generic { a: 1, b: None };
+    assert_eq!(to_value(value)?, json!([1]));
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+//! Compile-fail tests for the macro error messages
+//!
+//! The expected compiler output is stored next to each case in `tests/ui/`.
+//! After changing an error message, regenerate it with `TRYBUILD=overwrite`.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use odoo_api_macros::odoo_api;
+
+#[odoo_api(service = "object", method = "execute", auth = true)]
+pub struct Execute {
+    pub uid: i32,
+    pub model: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use odoo_api_macros::odoo_orm;
+
+#[odoo_orm(method = "read", args = ["ids"], kwargs = [])]
+pub struct Read {
+    pub model: String,
+    pub ids: Vec<i32>,
+}
+
+fn main() {}
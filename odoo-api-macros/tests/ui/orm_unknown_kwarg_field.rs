@@ -0,0 +1,13 @@
+use odoo_api_macros::odoo_orm;
+
+#[odoo_orm(method = "read", args = ["ids"], kwargs = ["fields", "limit"])]
+pub struct Read {
+    pub database: String,
+    pub uid: i32,
+    pub password: String,
+    pub model: String,
+    pub ids: Vec<i32>,
+    pub fields: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+//! Page through a large `search_read` with the async `reqwest` client
+//!
+//! [`search_read_stream`](odoo_api::OdooClient::search_read_stream) fetches the
+//! records in `id` order, one batch at a time. This runs against a local mock
+//! server (see `support/mock_server.rs`):
+//! ```text
+//! cargo run --example async_pagination
+//! ```
+
+#[path = "support/mock_server.rs"]
+mod mock_server;
+
+use mock_server::{MockServer, DATABASE, LOGIN, PASSWORD};
+use odoo_api::prelude::*;
+use serde_json::{json, Value};
+
+fn main() -> Result<()> {
+    let server = MockServer::start();
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build the tokio runtime")
+        .block_on(run(server.url()))
+}
+
+async fn run(url: &str) -> Result<()> {
+    let client = OdooClient::new_reqwest_async(url)?
+        .authenticate(DATABASE, LOGIN, PASSWORD)
+        .await?;
+
+    // create some records to page through
+    let partners: Vec<Value> = (1..=25)
+        .map(|index| json!({"name": format!("Partner #{}", index), "is_company": index % 5 == 0}))
+        .collect();
+    client.create("res.partner", partners).send().await?;
+
+    // fetch them 10 at a time (each page is one `search_read` request)
+    let mut pages = client.search_read_stream("res.partner", jvec![], svec!["name"], 10);
+    let mut sizes = Vec::new();
+    while let Some(page) = pages.next().await? {
+        println!("fetched a page of {} records", page.len());
+        sizes.push(page.len());
+    }
+    assert_eq!(sizes, [10, 10, 5]);
+
+    // or iterate over the individual records, with a domain
+    let mut companies = client
+        .search_read_stream(
+            "res.partner",
+            jvec![["is_company", "=", true]],
+            svec!["name"],
+            2,
+        )
+        .records();
+    let mut count = 0;
+    while let Some(company) = companies.next().await? {
+        println!("company: {}", company["name"]);
+        count += 1;
+    }
+    assert_eq!(count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn async_pagination() {
+    main().unwrap();
+}
@@ -0,0 +1,75 @@
+//! Create, read, update, and delete records with the blocking `reqwest` client
+//!
+//! This runs against a local mock server (see `support/mock_server.rs`), so it
+//! doesn't need a real Odoo instance:
+//! ```text
+//! cargo run --example blocking_crud --features blocking
+//! ```
+
+#[path = "support/mock_server.rs"]
+mod mock_server;
+
+use mock_server::{MockServer, DATABASE, LOGIN, PASSWORD};
+use odoo_api::prelude::*;
+use odoo_api::service::orm::CreateResponseItem;
+
+fn main() -> Result<()> {
+    let server = MockServer::start();
+
+    // build the client, and authenticate
+    let client = OdooClient::new_reqwest_blocking(server.url())?;
+    let client = client.authenticate(DATABASE, LOGIN, PASSWORD)?;
+    println!("authenticated as uid {}", client.uid());
+
+    // create two partners
+    let created = client
+        .create(
+            "res.partner",
+            jvec![
+                {"name": "Alice", "email": "alice@example.com"},
+                {"name": "Bob", "email": "bob@example.com"},
+            ],
+        )
+        .send()?;
+    let ids = match created.ids {
+        CreateResponseItem::Multi(ids) => ids,
+        CreateResponseItem::One(id) => vec![id],
+    };
+    println!("created partners {:?}", ids);
+
+    // read them back
+    let partners = client
+        .read("res.partner", ids.clone(), svec!["name", "email"])
+        .send()?;
+    for partner in &partners.data {
+        println!("read {} <{}>", partner["name"], partner["email"]);
+    }
+    assert_eq!(partners.data.len(), 2);
+
+    // update Bob's email
+    client
+        .write("res.partner", ids[1], jmap! {"email": "robert@example.com"})
+        .send()?;
+    let bob = client
+        .search_read(
+            "res.partner",
+            jvec![["email", "ilike", "robert"]],
+            svec!["name"],
+        )
+        .send()?;
+    println!("found {} partner(s) with the new email", bob.data.len());
+    assert_eq!(bob.data[0]["name"], "Bob");
+
+    // and delete Alice
+    client.unlink("res.partner", ids[0]).send()?;
+    let remaining = client.search_count("res.partner", jvec![]).send()?;
+    println!("{} partner(s) remaining", remaining.count);
+    assert_eq!(remaining.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn blocking_crud() {
+    main().unwrap();
+}
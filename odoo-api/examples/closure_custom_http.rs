@@ -0,0 +1,118 @@
+//! Bring your own HTTP client with a blocking closure
+//!
+//! The closure receives the full request URL, the JSON body, the session id
+//! (once authenticated), and any extra headers. It returns the response body,
+//! along with the `session_id` cookie (if the server set one).
+//!
+//! This example hand-rolls an HTTP/1.1 `POST` over a [`TcpStream`], to show
+//! that any transport will do. It runs against a local mock server (see
+//! `support/mock_server.rs`):
+//! ```text
+//! cargo run --example closure_custom_http
+//! ```
+
+#[path = "support/mock_server.rs"]
+mod mock_server;
+
+use mock_server::{MockServer, DATABASE, LOGIN, PASSWORD};
+use odoo_api::prelude::*;
+use odoo_api::BlockingClosureReturn;
+use serde_json::Value;
+use std::error::Error;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+/// A minimal (plain-HTTP only) `POST`
+fn post(
+    url: &str,
+    body: &Value,
+    session_id: Option<&str>,
+    headers: &[(String, String)],
+) -> std::result::Result<(String, Option<String>), Box<dyn Error>> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or("only plain http:// URLs are supported")?;
+    let (host, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+
+    let body = body.to_string();
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n",
+        path,
+        host,
+        body.len()
+    );
+    if let Some(session_id) = session_id {
+        request.push_str(&format!("Cookie: session_id={}\r\n", session_id));
+    }
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("\r\n");
+    request.push_str(&body);
+
+    let mut stream = TcpStream::connect(host)?;
+    stream.write_all(request.as_bytes())?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("malformed HTTP response")?;
+    let mut lines = head.lines();
+    let status = lines.next().unwrap_or_default();
+    if !status.contains(" 200 ") {
+        return Err(format!("unexpected status: {}", status).into());
+    }
+
+    // pick the `session_id` out of the `Set-Cookie` header, if any
+    let session_id = lines
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(name, _)| name.eq_ignore_ascii_case("set-cookie"))
+        .filter_map(|(_, value)| value.trim().split(';').next())
+        .find_map(|cookie| cookie.strip_prefix("session_id="))
+        .map(str::to_string);
+
+    Ok((body.to_string(), session_id))
+}
+
+fn main() -> Result<()> {
+    let server = MockServer::start();
+
+    let closure = |url: &str,
+                   body: Value,
+                   session_id: Option<&str>,
+                   headers: &[(String, String)]|
+     -> BlockingClosureReturn { Ok(post(url, &body, session_id, headers)?) };
+    let client = OdooClient::new_closure_blocking_with_headers(server.url(), closure)
+        .authenticate(DATABASE, LOGIN, PASSWORD)?;
+    println!("authenticated as uid {}", client.uid());
+
+    // any JSON-RPC call goes through the closure
+    let databases = client.db_list(false).send()?;
+    println!("databases: {:?}", databases.databases);
+    assert_eq!(databases.databases, [DATABASE]);
+
+    // the mock server echoes unknown methods back
+    let response = client
+        .execute_kw(
+            "res.partner",
+            "check_access_rights",
+            jvec!["read"],
+            jmap! {"raise_exception": false},
+        )
+        .send()?;
+    println!("echoed: {}", response.data);
+    assert_eq!(response.data["method"], "check_access_rights");
+    assert_eq!(response.data["args"], serde_json::json!(["read"]));
+
+    Ok(())
+}
+
+#[test]
+fn closure_custom_http() {
+    main().unwrap();
+}
@@ -0,0 +1,426 @@
+//! A tiny mock Odoo JSON-RPC server, for the examples
+//!
+//! This implements just enough of the protocol to exercise the client end-to-end:
+//!  - `/web/session/authenticate` (sets a `session_id` cookie)
+//!  - `/web/database/list`, and `db.list` / `common.version` / `common.login`
+//!  - `object.execute_kw` (and `execute`), with an in-memory record store for
+//!    `create`, `read`, `write`, `unlink`, `search`, `search_read`, and
+//!    `search_count`. Any other method echoes its `model`, `method`, `args`,
+//!    and `kwargs` back.
+//!
+//! Domains are supported, but only as a flat list of `[field, op, value]` terms
+//! (which are AND-ed together); prefix operators like `"|"` are ignored.
+//!
+//! The server runs on a background thread, and is stopped when dropped:
+//! ```ignore
+//! let server = MockServer::start();
+//! let client = OdooClient::new_reqwest_blocking(server.url())?
+//!     .authenticate(DATABASE, LOGIN, PASSWORD)?;
+//! ```
+
+#![allow(dead_code)]
+
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use tiny_http::{Header, Request, Response, Server};
+
+/// The only database on the mock server
+pub const DATABASE: &str = "mock-db";
+
+/// The login accepted by the mock server
+pub const LOGIN: &str = "admin";
+
+/// The password accepted by the mock server
+pub const PASSWORD: &str = "admin";
+
+/// The uid of the [`LOGIN`] user
+pub const UID: u64 = 2;
+
+/// The `session_id` cookie set by `/web/session/authenticate`
+pub const SESSION_ID: &str = "mock-session";
+
+/// A running mock server
+pub struct MockServer {
+    url: String,
+    server: Arc<Server>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start the server on a random local port
+    pub fn start() -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("failed to bind the mock server"));
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let inner = server.clone();
+        let handle = thread::spawn(move || {
+            let mut store = Store::default();
+            for request in inner.incoming_requests() {
+                store.respond(request);
+            }
+        });
+
+        Self {
+            url,
+            server,
+            handle: Some(handle),
+        }
+    }
+
+    /// The base URL, e.g. `http://127.0.0.1:38521`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// The in-memory records, by model
+#[derive(Default)]
+struct Store {
+    records: BTreeMap<String, BTreeMap<u64, Map<String, Value>>>,
+    next_id: u64,
+}
+
+impl Store {
+    fn respond(&mut self, mut request: Request) {
+        let mut body = String::new();
+        let _ = request.as_reader().read_to_string(&mut body);
+        let body: Value = serde_json::from_str(&body).unwrap_or_default();
+        let id = body["id"].clone();
+        let params = &body["params"];
+
+        let path = request
+            .url()
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let (result, cookie) = match path.as_str() {
+            "/web/session/authenticate" => self.authenticate(params),
+            "/web/database/list" => (Ok(json!([DATABASE])), None),
+            "/jsonrpc" => (self.jsonrpc(params), None),
+            _ => {
+                let _ = request.respond(Response::from_string("Not Found").with_status_code(404));
+                return;
+            }
+        };
+
+        let body = match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err(error) => json!({"jsonrpc": "2.0", "id": id, "error": error}),
+        };
+        let mut response = Response::from_string(body.to_string())
+            .with_header(header("Content-Type", "application/json"));
+        if let Some(cookie) = cookie {
+            response = response.with_header(header("Set-Cookie", &cookie));
+        }
+        let _ = request.respond(response);
+    }
+
+    fn authenticate(&self, params: &Value) -> (Result<Value, Value>, Option<String>) {
+        if !check_login(&params["db"], &params["login"], &params["password"]) {
+            return (Err(access_denied()), None);
+        }
+        let result = json!({
+            "uid": UID,
+            "db": DATABASE,
+            "username": LOGIN,
+            "user_context": {"lang": "en_US", "tz": "UTC", "uid": UID},
+        });
+        let cookie = format!("session_id={}; Path=/; HttpOnly", SESSION_ID);
+        (Ok(result), Some(cookie))
+    }
+
+    fn jsonrpc(&mut self, params: &Value) -> Result<Value, Value> {
+        let args = params["args"].as_array().cloned().unwrap_or_default();
+        let service = params["service"].as_str().unwrap_or_default();
+        let method = params["method"].as_str().unwrap_or_default();
+        match (service, method) {
+            ("db", "list") => Ok(json!([DATABASE])),
+            ("common", "version") => Ok(json!({
+                "server_version": "17.0",
+                "server_version_info": [17, 0, 0, "final", 0, ""],
+                "server_serie": "17.0",
+                "protocol_version": 1
+            })),
+            ("common", "login") => {
+                let ok = check_login(&args[0], &args[1], &args[2]);
+                Ok(if ok { json!(UID) } else { json!(false) })
+            }
+            ("object", "execute_kw" | "execute") => {
+                if args.len() < 5 || !check_login(&args[0], &json!(LOGIN), &args[2]) {
+                    return Err(access_denied());
+                }
+                let model = args[3].as_str().unwrap_or_default();
+                let method = args[4].as_str().unwrap_or_default();
+                let (orm_args, kwargs) = if service_method(params) == "execute_kw" {
+                    (
+                        args.get(5)
+                            .and_then(Value::as_array)
+                            .cloned()
+                            .unwrap_or_default(),
+                        args.get(6)
+                            .and_then(Value::as_object)
+                            .cloned()
+                            .unwrap_or_default(),
+                    )
+                } else {
+                    (args[5..].to_vec(), Map::new())
+                };
+                Ok(self.orm(model, method, orm_args, kwargs))
+            }
+            _ => Err(server_error(
+                "builtins.NameError",
+                &format!("unknown method {}.{}", service, method),
+            )),
+        }
+    }
+
+    fn orm(
+        &mut self,
+        model: &str,
+        method: &str,
+        args: Vec<Value>,
+        kwargs: Map<String, Value>,
+    ) -> Value {
+        // each argument may be passed positionally, or as a kwarg
+        let arg = |index: usize, name: &str| -> Value {
+            args.get(index)
+                .or_else(|| kwargs.get(name))
+                .cloned()
+                .unwrap_or(Value::Null)
+        };
+        let next_id = &mut self.next_id;
+        let records = self.records.entry(model.to_string()).or_default();
+        match method {
+            "create" => {
+                let mut create = |vals: &Value| {
+                    *next_id += 1;
+                    let mut record = vals.as_object().cloned().unwrap_or_default();
+                    record.insert("id".into(), json!(*next_id));
+                    records.insert(*next_id, record);
+                    json!(*next_id)
+                };
+                match arg(0, "vals_list") {
+                    Value::Array(vals_list) => Value::Array(vals_list.iter().map(create).collect()),
+                    vals => create(&vals),
+                }
+            }
+            "read" => {
+                let fields = arg(1, "fields");
+                let records = ids(&arg(0, "ids"))
+                    .iter()
+                    .filter_map(|id| records.get(id))
+                    .map(|record| select(record, &fields))
+                    .collect();
+                Value::Array(records)
+            }
+            "write" => {
+                let vals = arg(1, "vals");
+                for id in ids(&arg(0, "ids")) {
+                    if let (Some(record), Some(vals)) = (records.get_mut(&id), vals.as_object()) {
+                        record.extend(vals.clone());
+                    }
+                }
+                json!(true)
+            }
+            "unlink" => {
+                for id in ids(&arg(0, "ids")) {
+                    records.remove(&id);
+                }
+                json!(true)
+            }
+            "search" => {
+                let found = search(
+                    records,
+                    &arg(0, "domain"),
+                    &arg(1, "offset"),
+                    &arg(2, "limit"),
+                    &arg(3, "order"),
+                );
+                found.iter().map(|record| record["id"].clone()).collect()
+            }
+            "search_read" => {
+                let fields = arg(1, "fields");
+                let found = search(
+                    records,
+                    &arg(0, "domain"),
+                    &arg(2, "offset"),
+                    &arg(3, "limit"),
+                    &arg(4, "order"),
+                );
+                found.iter().map(|record| select(record, &fields)).collect()
+            }
+            "search_count" => {
+                let found = search(
+                    records,
+                    &arg(0, "domain"),
+                    &Value::Null,
+                    &Value::Null,
+                    &Value::Null,
+                );
+                json!(found.len())
+            }
+            _ => json!({
+                "model": model,
+                "method": method,
+                "args": args,
+                "kwargs": kwargs,
+            }),
+        }
+    }
+}
+
+fn service_method(params: &Value) -> &str {
+    params["method"].as_str().unwrap_or_default()
+}
+
+fn check_login(db: &Value, login: &Value, password: &Value) -> bool {
+    db == DATABASE && login == LOGIN && password == PASSWORD
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).unwrap()
+}
+
+fn server_error(name: &str, message: &str) -> Value {
+    json!({
+        "code": 200,
+        "message": "Odoo Server Error",
+        "data": {
+            "name": name,
+            "debug": "",
+            "message": message,
+            "arguments": [message],
+            "context": {}
+        }
+    })
+}
+
+fn access_denied() -> Value {
+    server_error("odoo.exceptions.AccessDenied", "Access Denied")
+}
+
+/// Parse an `ids` argument, which may be a single id or a list
+fn ids(value: &Value) -> Vec<u64> {
+    match value {
+        Value::Array(ids) => ids.iter().filter_map(Value::as_u64).collect(),
+        value => value.as_u64().into_iter().collect(),
+    }
+}
+
+/// Keep only the requested `fields` (plus the `id`), or all fields if empty
+fn select(record: &Map<String, Value>, fields: &Value) -> Value {
+    let fields: Vec<&str> = fields
+        .as_array()
+        .map(|fields| fields.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    if fields.is_empty() {
+        return Value::Object(record.clone());
+    }
+    let mut selected = Map::new();
+    selected.insert("id".into(), record["id"].clone());
+    for field in fields {
+        selected.insert(
+            field.into(),
+            record.get(field).cloned().unwrap_or(json!(false)),
+        );
+    }
+    Value::Object(selected)
+}
+
+/// Filter, sort, and page the records
+fn search<'a>(
+    records: &'a BTreeMap<u64, Map<String, Value>>,
+    domain: &Value,
+    offset: &Value,
+    limit: &Value,
+    order: &Value,
+) -> Vec<&'a Map<String, Value>> {
+    let terms: Vec<&Vec<Value>> = domain
+        .as_array()
+        .map(|domain| domain.iter().filter_map(Value::as_array).collect())
+        .unwrap_or_default();
+    let mut found: Vec<_> = records
+        .values()
+        .filter(|record| terms.iter().all(|term| matches(record, term)))
+        .collect();
+
+    // only the first `order` term is used, e.g. "name desc"
+    let order = order.as_str().unwrap_or("id");
+    let mut order = order
+        .split(',')
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let field = order.next().unwrap_or("id");
+    let descending = order
+        .next()
+        .is_some_and(|dir| dir.eq_ignore_ascii_case("desc"));
+    found.sort_by(|a, b| {
+        let ordering = compare(&a[field], &b[field]).unwrap_or(Ordering::Equal);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    let offset = offset.as_u64().unwrap_or(0) as usize;
+    let limit = limit.as_u64().map_or(usize::MAX, |limit| limit as usize);
+    found.into_iter().skip(offset).take(limit).collect()
+}
+
+/// Check a single `[field, op, value]` domain term
+fn matches(record: &Map<String, Value>, term: &[Value]) -> bool {
+    let (field, op, value) = match term {
+        [Value::String(field), Value::String(op), value] => (field, op.as_str(), value),
+        _ => return true,
+    };
+    let actual = record.get(field).unwrap_or(&Value::Bool(false));
+    let ordering = compare(actual, value);
+    match op {
+        "=" => actual == value,
+        "!=" => actual != value,
+        ">" => ordering == Some(Ordering::Greater),
+        ">=" => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+        "<" => ordering == Some(Ordering::Less),
+        "<=" => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+        "in" => value
+            .as_array()
+            .is_some_and(|values| values.contains(actual)),
+        "not in" => !value
+            .as_array()
+            .is_some_and(|values| values.contains(actual)),
+        "like" | "ilike" | "=like" | "=ilike" => {
+            let (actual, pattern) = (
+                actual.as_str().unwrap_or_default(),
+                value.as_str().unwrap_or_default().replace('%', ""),
+            );
+            if op.ends_with("ilike") {
+                actual.to_lowercase().contains(&pattern.to_lowercase())
+            } else {
+                actual.contains(&pattern)
+            }
+        }
+        _ => true,
+    }
+}
+
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
@@ -0,0 +1,134 @@
+//! Shared logic for the `access_matrix` client methods
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `access_matrix()` and
+//! `access_matrices()` itself (the blocking impls send the requests in turn,
+//! the async impls send them concurrently), and builds the requests here.
+
+use super::{Authed, OdooClient, OdooRequest, RequestImpl};
+use crate::service::orm::{AccessOperation, CheckAccessRights};
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
+/// The CRUD access rights of the current user on a model
+///
+/// See [`CheckAccessRights`] for more info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AccessMatrix {
+    pub create: bool,
+    pub read: bool,
+    pub write: bool,
+    pub unlink: bool,
+}
+
+impl AccessMatrix {
+    /// The operations checked, in the order expected by [`from_results`](Self::from_results)
+    pub(crate) const OPERATIONS: [AccessOperation; 4] = [
+        AccessOperation::Create,
+        AccessOperation::Read,
+        AccessOperation::Write,
+        AccessOperation::Unlink,
+    ];
+
+    /// Build the matrix from the results of the [`OPERATIONS`](Self::OPERATIONS) checks
+    pub(crate) fn from_results(results: &[bool]) -> Self {
+        Self {
+            create: results[0],
+            read: results[1],
+            write: results[2],
+            unlink: results[3],
+        }
+    }
+}
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// Build the `check_access_rights` requests for `model` (one per operation,
+    /// without raising)
+    pub(crate) fn access_requests(
+        &self,
+        model: &str,
+    ) -> Vec<OdooRequest<'_, CheckAccessRights, I>> {
+        AccessMatrix::OPERATIONS
+            .into_iter()
+            .map(|operation| self.check_access_rights(model, operation, false))
+            .collect()
+    }
+}
+
+/// Poll `futures` concurrently, and collect their outputs (in order)
+pub(crate) async fn join_all<F: Future>(futures: Vec<F>) -> Vec<F::Output> {
+    let mut futures: Vec<_> = futures.into_iter().map(Box::pin).collect();
+    let mut outputs: Vec<Option<F::Output>> = futures.iter().map(|_| None).collect();
+    poll_fn(|cx| {
+        let mut pending = false;
+        for (future, output) in futures.iter_mut().zip(outputs.iter_mut()) {
+            if output.is_some() {
+                continue;
+            }
+            match Pin::new(future).poll(cx) {
+                Poll::Ready(value) => *output = Some(value),
+                Poll::Pending => pending = true,
+            }
+        }
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    })
+    .await;
+    outputs.into_iter().map(Option::unwrap).collect()
+}
+
+/// Poll `a` and `b` concurrently, and return both outputs
+pub(crate) async fn join<A: Future, B: Future>(a: A, b: B) -> (A::Output, B::Output) {
+    let (mut a, mut b) = (Box::pin(a), Box::pin(b));
+    let (mut a_output, mut b_output) = (None, None);
+    poll_fn(|cx| {
+        if a_output.is_none() {
+            if let Poll::Ready(value) = a.as_mut().poll(cx) {
+                a_output = Some(value);
+            }
+        }
+        if b_output.is_none() {
+            if let Poll::Ready(value) = b.as_mut().poll(cx) {
+                b_output = Some(value);
+            }
+        }
+        match a_output.is_some() && b_output.is_some() {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    })
+    .await;
+    (a_output.unwrap(), b_output.unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::pin::pin;
+    use std::task::{Context, Waker};
+
+    #[test]
+    fn join_all_order() {
+        // the second future is pending on the first poll
+        let mut polled = false;
+        let slow = poll_fn(move |cx| {
+            if polled {
+                Poll::Ready(2)
+            } else {
+                polled = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        });
+        let futures: Vec<Pin<Box<dyn Future<Output = i32>>>> =
+            vec![Box::pin(std::future::ready(1)), Box::pin(slow)];
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut joined = pin!(join_all(futures));
+        assert!(joined.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(joined.as_mut().poll(&mut cx), Poll::Ready(vec![1, 2]));
+    }
+}
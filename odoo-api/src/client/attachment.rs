@@ -0,0 +1,208 @@
+//! The [`Attachment`] type, and helpers for the `attachment_*()` client methods
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `attachment_upload()` and
+//! `attachment_download()` itself (as `send()` is either blocking or async).
+//! The closure impls download via an RPC `read` of the base64 `datas` field,
+//! while the HTTP impls fetch the raw bytes from `/web/content/<id>` instead.
+
+use crate::jsonrpc::OdooId;
+use crate::serde_helpers::OdooOption;
+use crate::service::orm::{CreateResponse, CreateResponseItem, ReadResponse};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// The fields read by `attachment_download()`
+///
+/// The base64 `datas` field is only included if `with_data` is set (i.e., the
+/// raw contents weren't fetched separately).
+pub(crate) fn read_fields(with_data: bool) -> Vec<String> {
+    let mut fields = vec!["name".to_string(), "mimetype".to_string()];
+    if with_data {
+        fields.push("datas".into());
+    }
+    fields
+}
+
+/// A downloaded `ir.attachment`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    /// The attachment id
+    pub id: OdooId,
+
+    /// The attachment name (usually the filename)
+    pub name: String,
+
+    /// The attachment mimetype (e.g., `application/pdf`), if known
+    pub mimetype: Option<String>,
+
+    /// The decoded file contents
+    pub data: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct AttachmentRecord {
+    id: OdooId,
+    #[serde(default)]
+    name: OdooOption<String>,
+    #[serde(default)]
+    mimetype: OdooOption<String>,
+    #[serde(default)]
+    datas: OdooOption<String>,
+}
+
+impl Attachment {
+    /// Build the attachment from a `read` of the [`read_fields`]
+    ///
+    /// If `data` is `None`, the `datas` field is decoded instead (an empty file
+    /// is returned by Odoo as `false`).
+    pub(crate) fn from_read(
+        response: ReadResponse,
+        data: Option<Vec<u8>>,
+    ) -> serde_json::Result<Self> {
+        let record = response
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| serde_json::Error::custom("the attachment was not found"))?;
+        let record: AttachmentRecord = serde_json::from_value(Value::Object(record))?;
+        let data = match data {
+            Some(data) => data,
+            None => match record.datas.0 {
+                Some(datas) => STANDARD.decode(datas).map_err(serde_json::Error::custom)?,
+                None => Vec::new(),
+            },
+        };
+
+        Ok(Self {
+            id: record.id,
+            name: record.name.0.unwrap_or_default(),
+            mimetype: record.mimetype.0,
+            data,
+        })
+    }
+}
+
+/// Build the `ir.attachment` values for `attachment_upload()`
+pub(crate) fn upload_values(
+    model: &str,
+    res_id: OdooId,
+    filename: &str,
+    data: &[u8],
+    mimetype: Option<&str>,
+) -> Map<String, Value> {
+    let mut values = Map::new();
+    values.insert("name".into(), filename.into());
+    values.insert("type".into(), "binary".into());
+    values.insert("res_model".into(), model.into());
+    values.insert("res_id".into(), res_id.into());
+    values.insert("datas".into(), STANDARD.encode(data).into());
+    if let Some(mimetype) = mimetype {
+        values.insert("mimetype".into(), mimetype.into());
+    }
+    values
+}
+
+/// Extract the new record id from the `create` response
+pub(crate) fn created_id(response: CreateResponse) -> OdooId {
+    match response.ids {
+        CreateResponseItem::One(id) => id,
+        // a single `values` map always creates a single record
+        CreateResponseItem::Multi(ids) => ids[0],
+    }
+}
+
+/// The web endpoint which serves the raw attachment contents
+///
+/// This relies on the `session_id` cookie for authentication.
+#[cfg(any(feature = "async", feature = "blocking", feature = "ureq"))]
+pub(crate) fn content_endpoint(id: OdooId) -> String {
+    format!("/web/content/{}", id)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::{from_value, json};
+
+    #[test]
+    fn upload_values_base64() {
+        let values = upload_values(
+            "res.partner",
+            7,
+            "hello.txt",
+            b"hello world",
+            Some("text/plain"),
+        );
+        assert_eq!(
+            Value::Object(values.clone()),
+            json!({
+                "name": "hello.txt",
+                "type": "binary",
+                "res_model": "res.partner",
+                "res_id": 7,
+                "datas": "aGVsbG8gd29ybGQ=",
+                "mimetype": "text/plain"
+            })
+        );
+
+        let values = upload_values("res.partner", 7, "hello.txt", b"hello world", None);
+        assert!(!values.contains_key("mimetype"));
+    }
+
+    #[test]
+    fn from_read_base64() -> serde_json::Result<()> {
+        let data = (0..=255).collect::<Vec<u8>>();
+        let response: ReadResponse = from_value(json!([{
+            "id": 3,
+            "name": "bytes.bin",
+            "mimetype": "application/octet-stream",
+            "datas": STANDARD.encode(&data)
+        }]))?;
+        let attachment = Attachment::from_read(response, None)?;
+        assert_eq!(
+            attachment,
+            Attachment {
+                id: 3,
+                name: "bytes.bin".into(),
+                mimetype: Some("application/octet-stream".into()),
+                data,
+            }
+        );
+
+        // empty files are returned as `false`
+        let response: ReadResponse =
+            from_value(json!([{"id": 3, "name": "empty.txt", "mimetype": false, "datas": false}]))?;
+        let attachment = Attachment::from_read(response, None)?;
+        assert!(attachment.data.is_empty());
+        assert_eq!(attachment.mimetype, None);
+
+        // data fetched separately
+        let response: ReadResponse =
+            from_value(json!([{"id": 3, "name": "a.txt", "mimetype": "text/plain"}]))?;
+        let attachment = Attachment::from_read(response, Some(b"abc".to_vec()))?;
+        assert_eq!(attachment.data, b"abc");
+
+        let response: ReadResponse = from_value(json!([{"id": 3, "datas": "not base64!"}]))?;
+        assert!(Attachment::from_read(response, None).is_err());
+
+        let response: ReadResponse = from_value(json!([]))?;
+        assert!(Attachment::from_read(response, None).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "async", feature = "blocking", feature = "ureq"))]
+    #[test]
+    fn content_url() {
+        use crate::client::odoo_request::test::capture_client;
+
+        let (client, _captured) = capture_client();
+        assert_eq!(
+            client.build_endpoint(&content_endpoint(42)),
+            "http://localhost:8069/web/content/42"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Shared logic for the `*_batched` client methods
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `write_batched()` and
+//! `unlink_batched()` itself (as `send()` is either blocking or async), and
+//! delegates the chunking here.
+
+use super::error::ClosureError;
+use crate::jsonrpc::OdooId;
+use std::future::Future;
+
+/// A progress callback for the `*_batched` methods
+///
+/// Called with `(done, total)` after each chunk is sent.
+pub type BatchProgress<'a> = &'a (dyn Fn(usize, usize) + Send + Sync);
+
+/// Implemented by the error types which have a `BatchError` variant
+pub(crate) trait BatchFailure: Sized {
+    fn batch_error(processed: usize, failed: usize, source: Self) -> Self;
+}
+
+macro_rules! impl_batch_failure {
+    ($($(#[$attr:meta])* $error:ty),*) => {$(
+        $(#[$attr])*
+        impl BatchFailure for $error {
+            fn batch_error(processed: usize, failed: usize, source: Self) -> Self {
+                Self::BatchError {
+                    processed,
+                    failed,
+                    source: Box::new(source),
+                }
+            }
+        }
+    )*};
+}
+
+impl_batch_failure!(
+    ClosureError,
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    super::error::ReqwestError,
+    #[cfg(feature = "ureq")]
+    super::error::UreqError
+);
+
+/// Send `ids` in chunks of `chunk_size`, stopping at the first failure
+///
+/// Returns `true` if every chunk returned `true`.
+pub(crate) fn run_blocking<E, F>(
+    ids: &[OdooId],
+    chunk_size: usize,
+    progress: Option<BatchProgress>,
+    mut send: F,
+) -> Result<bool, E>
+where
+    E: BatchFailure,
+    F: FnMut(Vec<OdooId>) -> Result<bool, E>,
+{
+    let mut ok = true;
+    let mut processed = 0;
+    for chunk in ids.chunks(chunk_size.max(1)) {
+        match send(chunk.to_vec()) {
+            Ok(result) => ok &= result,
+            Err(err) => return Err(E::batch_error(processed, chunk.len(), err)),
+        }
+        processed += chunk.len();
+        if let Some(progress) = progress {
+            progress(processed, ids.len());
+        }
+    }
+
+    Ok(ok)
+}
+
+/// Same as [`run_blocking`], but for the async impls
+pub(crate) async fn run_async<E, F, Fut>(
+    ids: &[OdooId],
+    chunk_size: usize,
+    progress: Option<BatchProgress<'_>>,
+    mut send: F,
+) -> Result<bool, E>
+where
+    E: BatchFailure,
+    F: FnMut(Vec<OdooId>) -> Fut,
+    Fut: Future<Output = Result<bool, E>>,
+{
+    let mut ok = true;
+    let mut processed = 0;
+    for chunk in ids.chunks(chunk_size.max(1)) {
+        match send(chunk.to_vec()).await {
+            Ok(result) => ok &= result,
+            Err(err) => return Err(E::batch_error(processed, chunk.len(), err)),
+        }
+        processed += chunk.len();
+        if let Some(progress) = progress {
+            progress(processed, ids.len());
+        }
+    }
+
+    Ok(ok)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::future::ready;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    #[test]
+    fn run_async_chunks() {
+        let mut chunks = Vec::new();
+        let future = run_async::<ClosureError, _, _>(&[1, 2, 3, 4, 5], 2, None, |chunk| {
+            chunks.push(chunk);
+            ready(Ok(true))
+        });
+
+        // the futures are all immediately ready, so one poll is enough
+        let mut cx = Context::from_waker(Waker::noop());
+        let result = pin!(future).poll(&mut cx);
+        assert!(matches!(result, Poll::Ready(Ok(true))));
+        assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    }
+}
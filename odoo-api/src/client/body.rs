@@ -0,0 +1,137 @@
+//! Reading response bodies, with an optional size limit
+//!
+//! See [`OdooClient::with_max_response_size`](super::OdooClient::with_max_response_size).
+//! The `reqwest` and `ureq` impls read the body in chunks, and stop as soon as
+//! the limit is exceeded (or before reading anything, if the `Content-Length`
+//! is already too large). The closure impls return the whole body at once, so
+//! it's only checked afterwards.
+//!
+//! The `reqwest` impls also report the download progress here, see
+//! [`OdooRequest::on_download_progress`](super::OdooRequest::on_download_progress).
+
+use super::error::{ParseResponseError, ParseResponseResult};
+#[cfg(any(feature = "async", feature = "blocking", feature = "ureq"))]
+use super::odoo_request::ProgressHook;
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+use std::io::{self, Write};
+
+/// Check a body of `size` bytes against the `limit`
+pub(crate) fn check_size(limit: Option<usize>, size: u64) -> ParseResponseResult<()> {
+    match limit {
+        Some(limit) if size > limit as u64 => Err(ParseResponseError::ResponseTooLarge { limit }),
+        _ => Ok(()),
+    }
+}
+
+/// A response body buffer, which refuses to grow past the limit
+#[cfg(any(feature = "async", feature = "blocking", feature = "ureq"))]
+pub(crate) struct LimitedBody {
+    data: Vec<u8>,
+    limit: Option<usize>,
+    exceeded: bool,
+    total: Option<u64>,
+    progress: Option<ProgressHook>,
+}
+
+#[cfg(any(feature = "async", feature = "blocking", feature = "ureq"))]
+impl LimitedBody {
+    /// Start a new body, checking the `Content-Length` (if known) first
+    pub(crate) fn new(
+        limit: Option<usize>,
+        content_length: Option<u64>,
+    ) -> ParseResponseResult<Self> {
+        let capacity = match content_length {
+            Some(length) => {
+                check_size(limit, length)?;
+                length as usize
+            }
+            None => 0,
+        };
+        Ok(Self {
+            data: Vec::with_capacity(capacity),
+            limit,
+            exceeded: false,
+            total: content_length,
+            progress: None,
+        })
+    }
+
+    /// Call `hook` with the bytes received so far, after each chunk
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    pub(crate) fn with_progress(mut self, hook: Option<ProgressHook>) -> Self {
+        self.progress = hook;
+        self
+    }
+
+    /// Append a chunk of the body
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> ParseResponseResult<()> {
+        let size = (self.data.len() + chunk.len()) as u64;
+        if let Err(err) = check_size(self.limit, size) {
+            self.exceeded = true;
+            return Err(err);
+        }
+        self.data.extend_from_slice(chunk);
+        if let Some(hook) = &self.progress {
+            hook(size, self.total);
+        }
+        Ok(())
+    }
+
+    #[cfg(any(feature = "blocking", feature = "ureq"))]
+    /// Map an error from writing to the body (via [`Write`])
+    ///
+    /// The HTTP libraries wrap the error returned by `write()`, so if the
+    /// limit was exceeded, `ResponseTooLarge` is returned instead of `err`.
+    pub(crate) fn or<E: From<ParseResponseError>>(&self, err: E) -> E {
+        match self.limit {
+            Some(limit) if self.exceeded => ParseResponseError::ResponseTooLarge { limit }.into(),
+            _ => err,
+        }
+    }
+
+    /// The body bytes
+    pub(crate) fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "ureq"))]
+impl Write for LimitedBody {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.push(buf).map_err(io::Error::other)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, any(feature = "blocking", feature = "ureq")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn limited_body() {
+        assert!(matches!(
+            LimitedBody::new(Some(4), Some(5)),
+            Err(ParseResponseError::ResponseTooLarge { limit: 4 })
+        ));
+
+        let mut body = LimitedBody::new(Some(4), None).unwrap();
+        body.write_all(b"abc").unwrap();
+        assert!(body.write_all(b"de").is_err());
+        assert!(matches!(
+            body.or(ParseResponseError::DatabaseSelector),
+            ParseResponseError::ResponseTooLarge { limit: 4 }
+        ));
+
+        let mut body = LimitedBody::new(None, Some(5)).unwrap();
+        body.write_all(b"abcde").unwrap();
+        assert!(matches!(
+            body.or(ParseResponseError::DatabaseSelector),
+            ParseResponseError::DatabaseSelector
+        ));
+        assert_eq!(body.into_inner(), b"abcde");
+    }
+}
@@ -0,0 +1,218 @@
+//! The [`MethodCallResult`] type, returned by the `call_button()` client method
+//!
+//! Workflow buttons (e.g. `action_confirm` or `button_validate`) either finish
+//! the job, or return an `ir.actions.*` dict asking the webclient to open
+//! something (usually a wizard). Each [`RequestImpl`](super::RequestImpl)
+//! implements `call_button()` itself (as `send()` is either blocking or async),
+//! and sniffs the result with [`MethodCallResult::from_value`].
+
+use crate::jsonrpc::OdooId;
+use crate::serde_helpers::false_as_none;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+/// The result of a button method, sent via `execute_kw`
+#[derive(Debug, Clone, PartialEq)]
+pub enum MethodCallResult {
+    /// The method returned `None` (sent as `null`)
+    Done,
+
+    /// The method returned `True` or `False`
+    ///
+    /// Most buttons return `True` (or `False`, e.g. `account.move.action_post`)
+    /// when they're done, so this doesn't mean the call failed.
+    Bool(bool),
+
+    /// The method returned an action for the user, e.g. a confirmation wizard
+    Action(ActionDescriptor),
+
+    /// Any other result
+    Other(Value),
+}
+
+impl MethodCallResult {
+    /// Sniff the result of a button method
+    ///
+    /// A dict with a `type` starting with `ir.actions` is an [`Action`](Self::Action).
+    /// If the action can't be parsed (e.g. the `res_id` isn't an id), it's
+    /// returned as [`Other`](Self::Other) instead.
+    pub fn from_value(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Done,
+            Value::Bool(value) => Self::Bool(value),
+            Value::Object(ref map) if is_action(map) => {
+                match ActionDescriptor::deserialize(&value) {
+                    Ok(action) => Self::Action(action),
+                    Err(_) => Self::Other(value),
+                }
+            }
+            value => Self::Other(value),
+        }
+    }
+
+    /// Whether the server wants user interaction (i.e. returned an action)
+    pub fn is_action(&self) -> bool {
+        matches!(self, Self::Action(_))
+    }
+}
+
+/// Whether `map` is an `ir.actions.*` dict
+fn is_action(map: &Map<String, Value>) -> bool {
+    map.get("type")
+        .and_then(Value::as_str)
+        .is_some_and(|kind| kind.starts_with("ir.actions"))
+}
+
+/// The commonly-used keys of an `ir.actions.*` dict
+///
+/// See [`MethodCallResult::Action`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ActionDescriptor {
+    /// The action type (e.g. `ir.actions.act_window`)
+    #[serde(rename = "type")]
+    pub action_type: String,
+
+    /// The model to open (for window actions)
+    #[serde(default, deserialize_with = "false_as_none")]
+    pub res_model: Option<String>,
+
+    /// The record to open, if any (wizards are usually opened without one)
+    #[serde(default, deserialize_with = "false_as_none")]
+    pub res_id: Option<OdooId>,
+
+    /// The views to open, e.g. `form` or `tree,form`
+    #[serde(default, deserialize_with = "false_as_none")]
+    pub view_mode: Option<String>,
+
+    /// The action context (e.g. the `default_*` values for a wizard)
+    ///
+    /// This is empty if the action has no context, or if it's a (Python)
+    /// string rather than a dict.
+    #[serde(default, deserialize_with = "context_or_empty")]
+    pub context: Map<String, Value>,
+}
+
+/// Deserialize the action `context`, ignoring anything but a dict
+fn context_or_empty<'de, D: Deserializer<'de>>(de: D) -> Result<Map<String, Value>, D::Error> {
+    match Value::deserialize(de)? {
+        Value::Object(context) => Ok(context),
+        _ => Ok(Map::new()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(
+            MethodCallResult::from_value(json!(null)),
+            MethodCallResult::Done
+        );
+        assert_eq!(
+            MethodCallResult::from_value(json!(true)),
+            MethodCallResult::Bool(true)
+        );
+        assert_eq!(
+            MethodCallResult::from_value(json!([1, 2])),
+            MethodCallResult::Other(json!([1, 2]))
+        );
+        // a dict, but not an action
+        assert_eq!(
+            MethodCallResult::from_value(json!({"type": "warning", "title": "Oops"})),
+            MethodCallResult::Other(json!({"type": "warning", "title": "Oops"}))
+        );
+    }
+
+    #[test]
+    fn account_move_action_post() {
+        // `account.move.action_post` returns `False` once the entry is posted
+        let result = MethodCallResult::from_value(json!(false));
+        assert_eq!(result, MethodCallResult::Bool(false));
+        assert!(!result.is_action());
+    }
+
+    #[test]
+    fn stock_immediate_transfer() {
+        // `stock.picking.button_validate`, when no quantities are set (Odoo 16.0)
+        let result = MethodCallResult::from_value(json!({
+            "name": "Immediate Transfer?",
+            "type": "ir.actions.act_window",
+            "view_mode": "form",
+            "res_model": "stock.immediate.transfer",
+            "views": [[412, "form"]],
+            "view_id": 412,
+            "target": "new",
+            "context": {
+                "lang": "en_US",
+                "tz": "Europe/Brussels",
+                "uid": 2,
+                "allowed_company_ids": [1],
+                "button_validate_picking_ids": [7],
+                "default_show_transfers": false,
+                "default_pick_ids": [[4, 7]],
+                "default_immediate_transfer_line_ids": [[0, 0, {"to_immediate": true, "picking_id": 7}]]
+            }
+        }));
+
+        let MethodCallResult::Action(action) = result else {
+            panic!("expected an action, got {:?}", result);
+        };
+        assert_eq!(action.action_type, "ir.actions.act_window");
+        assert_eq!(
+            action.res_model.as_deref(),
+            Some("stock.immediate.transfer")
+        );
+        assert_eq!(action.res_id, None);
+        assert_eq!(action.view_mode.as_deref(), Some("form"));
+        assert_eq!(action.context["default_pick_ids"], json!([[4, 7]]));
+
+        // `stock.immediate.transfer.process` then returns `True`
+        assert_eq!(
+            MethodCallResult::from_value(json!(true)),
+            MethodCallResult::Bool(true)
+        );
+    }
+
+    #[test]
+    fn action_fields() {
+        // `res_id: false`, and a string context (as stored on `ir.actions.act_window`)
+        let result = MethodCallResult::from_value(json!({
+            "type": "ir.actions.act_window",
+            "res_model": "account.move",
+            "res_id": false,
+            "view_mode": "tree,form",
+            "context": "{'default_move_type': 'out_invoice'}"
+        }));
+        let MethodCallResult::Action(action) = result else {
+            panic!("expected an action, got {:?}", result);
+        };
+        assert_eq!(action.res_id, None);
+        assert!(action.context.is_empty());
+
+        // client actions only have a `tag`
+        let result = MethodCallResult::from_value(json!({
+            "type": "ir.actions.client",
+            "tag": "reload"
+        }));
+        assert_eq!(
+            result,
+            MethodCallResult::Action(ActionDescriptor {
+                action_type: "ir.actions.client".into(),
+                res_model: None,
+                res_id: None,
+                view_mode: None,
+                context: Map::new(),
+            })
+        );
+
+        // an action that can't be parsed is returned as-is
+        let value = json!({"type": "ir.actions.act_window", "res_id": "oops"});
+        assert_eq!(
+            MethodCallResult::from_value(value.clone()),
+            MethodCallResult::Other(value)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Request body compression, and response decompression
+//!
+//! See `with_request_compression()` on the `reqwest` and `ureq` clients. The
+//! `reqwest` impls also send `Accept-Encoding: gzip, deflate` with JSON-RPC
+//! requests, and decode the response here (`ureq` does this itself). Brotli
+//! isn't supported.
+
+use super::body::check_size;
+use super::error::{ParseResponseError, ParseResponseResult};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+
+/// The `Accept-Encoding` sent by the `reqwest` impls
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+pub(crate) const ACCEPT_ENCODING: &str = "gzip, deflate";
+
+/// The gzip compression level, from `0` (none) to `9` (smallest)
+///
+/// The default is `6`, as with `gzip` itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionLevel(u32);
+
+impl CompressionLevel {
+    /// A level from `0` to `9` (higher levels are clamped to `9`)
+    pub fn new(level: u32) -> Self {
+        Self(level.min(9))
+    }
+
+    /// The fastest level (`1`)
+    pub fn fast() -> Self {
+        Self(1)
+    }
+
+    /// The smallest output (`9`)
+    pub fn best() -> Self {
+        Self(9)
+    }
+
+    /// The level, from `0` to `9`
+    pub fn level(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Default for CompressionLevel {
+    fn default() -> Self {
+        Self(6)
+    }
+}
+
+/// Gzip request bodies larger than the `threshold` (in bytes)
+#[cfg_attr(
+    not(any(feature = "async", feature = "blocking", feature = "ureq")),
+    allow(dead_code)
+)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RequestCompression {
+    pub(crate) level: CompressionLevel,
+    pub(crate) threshold: usize,
+}
+
+#[cfg_attr(
+    not(any(feature = "async", feature = "blocking", feature = "ureq")),
+    allow(dead_code)
+)]
+impl RequestCompression {
+    /// Gzip `body`, or return `None` if it's below the threshold
+    pub(crate) fn compress(&self, body: &[u8]) -> Option<Vec<u8>> {
+        if body.len() <= self.threshold {
+            return None;
+        }
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::new(self.level.0));
+        // writing to a `Vec` can't fail
+        encoder.write_all(body).ok()?;
+        encoder.finish().ok()
+    }
+}
+
+/// Decode a response `body` sent with the `Content-Encoding` header `encoding`
+///
+/// Unknown (and missing) encodings are returned as-is. The decoded body is
+/// checked against the `limit` too, so a small response can't expand past it.
+#[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+pub(crate) fn decompress(
+    encoding: Option<&str>,
+    body: Vec<u8>,
+    limit: Option<usize>,
+) -> ParseResponseResult<Vec<u8>> {
+    let decoder: Box<dyn Read + '_> = match encoding.map(str::trim) {
+        Some(encoding)
+            if encoding.eq_ignore_ascii_case("gzip") || encoding.eq_ignore_ascii_case("x-gzip") =>
+        {
+            Box::new(GzDecoder::new(&body[..]))
+        }
+        // HTTP's "deflate" is zlib-wrapped
+        Some(encoding) if encoding.eq_ignore_ascii_case("deflate") => {
+            Box::new(ZlibDecoder::new(&body[..]))
+        }
+        _ => return Ok(body),
+    };
+
+    // read one byte past the limit, so that an oversized body is caught
+    let max = limit.map_or(u64::MAX, |limit| limit as u64 + 1);
+    let mut decoded = Vec::new();
+    decoder.take(max).read_to_end(&mut decoded).map_err(|err| {
+        ParseResponseError::MalformedResponse {
+            reason: format!("failed to decompress the response: {}", err),
+        }
+    })?;
+    check_size(limit, decoded.len() as u64)?;
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+
+    #[test]
+    fn round_trip() -> ParseResponseResult<()> {
+        let compression = RequestCompression {
+            level: CompressionLevel::default(),
+            threshold: 16,
+        };
+        assert_eq!(compression.compress(b"{\"small\": true}"), None);
+
+        let body = br#"{"jsonrpc": "2.0", "params": {"args": ["a large payload"]}}"#.repeat(100);
+        let compressed = compression.compress(&body).unwrap();
+        assert!(compressed.len() < body.len());
+        assert_eq!(decompress(Some("gzip"), compressed, None)?, body);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decompress_encodings() -> ParseResponseResult<()> {
+        let body = b"{\"jsonrpc\": \"2.0\", \"id\": 1, \"result\": true}".to_vec();
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&body).unwrap();
+        let deflated = encoder.finish().unwrap();
+
+        assert_eq!(decompress(Some("deflate"), deflated.clone(), None)?, body);
+        assert_eq!(decompress(None, body.clone(), None)?, body);
+        assert_eq!(decompress(Some("identity"), body.clone(), None)?, body);
+
+        // the limit applies to the decoded body
+        assert!(matches!(
+            decompress(Some("deflate"), deflated, Some(8)),
+            Err(ParseResponseError::ResponseTooLarge { limit: 8 })
+        ));
+
+        // corrupt data
+        assert!(matches!(
+            decompress(Some("gzip"), body, None),
+            Err(ParseResponseError::MalformedResponse { .. })
+        ));
+
+        Ok(())
+    }
+}
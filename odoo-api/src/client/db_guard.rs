@@ -0,0 +1,188 @@
+//! The [`DbGuard`] type, which refuses destructive `db` requests
+//!
+//! The guard is checked client-side when each request is prepared (i.e., the
+//! request is never sent to Odoo), and covers:
+//!  - [`db_drop`](crate::service::db::Drop)
+//!  - [`db_rename`](crate::service::db::Rename) (the old name)
+//!  - [`db_restore`](crate::service::db::Restore) (the target name)
+//!  - `web_db_restore()` (the target name; confirmed with its `confirmed` argument)
+
+use super::error::{ParseResponseError, ParseResponseResult};
+use serde_json::Value;
+
+/// The `db` service methods checked by the guard
+const GUARDED_METHODS: [&str; 3] = ["drop", "rename", "restore"];
+
+/// A client-side guard against dropping (or overwriting) the wrong database
+///
+/// Protected databases are matched with glob patterns, where `*` matches any
+/// number of characters, and `?` matches exactly one. By default, requests
+/// targeting a protected database are always refused. With
+/// [`require_confirmation`](Self::require_confirmation), they're allowed if
+/// the request was built with [`OdooRequest::confirm_destructive`](super::OdooRequest::confirm_destructive).
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # async fn test() -> odoo_api::client::Result<()> {
+/// use odoo_api::client::DbGuard;
+/// use odoo_api::OdooClient;
+///
+/// let mut client = OdooClient::new_reqwest_async("https://demo.odoo.com")?;
+/// client.with_db_guard(
+///     DbGuard::new()
+///         .protect_pattern("*prod*")
+///         .require_confirmation()
+/// );
+///
+/// // refused with `Error::ProtectedDatabase`
+/// assert!(client.db_drop("master-password", "acme-prod").send().await.is_err());
+///
+/// // sent as usual
+/// client.db_drop("master-password", "acme-prod")
+///     .confirm_destructive()
+///     .send()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DbGuard {
+    patterns: Vec<String>,
+    allow_confirmed: bool,
+}
+
+impl DbGuard {
+    /// Build a new guard, with no protected databases
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Protect the databases matching `pattern` (e.g., `*prod*`)
+    pub fn protect_pattern(mut self, pattern: &str) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    /// Allow requests to protected databases, if they're built with
+    /// [`OdooRequest::confirm_destructive`](super::OdooRequest::confirm_destructive)
+    pub fn require_confirmation(mut self) -> Self {
+        self.allow_confirmed = true;
+        self
+    }
+
+    /// Check whether `db` matches any of the protected patterns
+    pub fn is_protected(&self, db: &str) -> bool {
+        self.patterns.iter().any(|pattern| glob_match(pattern, db))
+    }
+
+    /// Check the request `body` against the guard
+    ///
+    /// Returns a [`ParseResponseError::ProtectedDatabase`] if the request is a
+    /// guarded `db` method targeting a protected database, and it wasn't
+    /// `confirmed` (or confirmation isn't allowed).
+    pub(crate) fn check(&self, body: &Value, confirmed: bool) -> ParseResponseResult<()> {
+        let params = &body["params"];
+        if params["service"] != "db" {
+            return Ok(());
+        }
+        let method = match params["method"].as_str() {
+            Some(method) if GUARDED_METHODS.contains(&method) => method,
+            _ => return Ok(()),
+        };
+
+        // each guarded method takes `(passwd, db_name, ...)`
+        match params["args"][1].as_str() {
+            Some(db) => self.check_db(method, db, confirmed),
+            None => Ok(()),
+        }
+    }
+
+    /// Check a guarded `method` targeting `db` against the guard
+    pub(crate) fn check_db(
+        &self,
+        method: &str,
+        db: &str,
+        confirmed: bool,
+    ) -> ParseResponseResult<()> {
+        if self.is_protected(db) && !(confirmed && self.allow_confirmed) {
+            return Err(ParseResponseError::ProtectedDatabase {
+                method: method.into(),
+                db: db.into(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Match `text` against a glob `pattern` (supporting `*` and `?`)
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    // the standard greedy wildcard match, backtracking to the last `*`
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn glob() {
+        assert!(glob_match("*prod*", "prod"));
+        assert!(glob_match("*prod*", "acme-prod-2023"));
+        assert!(glob_match("acme-?", "acme-1"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("*prod*", "acme-staging"));
+        assert!(!glob_match("acme-?", "acme-12"));
+        assert!(!glob_match("prod", "production"));
+    }
+
+    #[test]
+    fn check() {
+        let body = |method: &str, db: &str| json!({"params": {"service": "db", "method": method, "args": ["master", db]}});
+        let guard = DbGuard::new().protect_pattern("*prod*");
+
+        assert!(guard.check(&body("drop", "acme-prod"), false).is_err());
+        assert!(guard.check(&body("rename", "acme-prod"), false).is_err());
+        assert!(guard.check(&body("restore", "acme-prod"), false).is_err());
+        assert!(guard.check(&body("drop", "acme-staging"), false).is_ok());
+
+        // non-destructive methods aren't checked
+        assert!(guard
+            .check(&body("duplicate_database", "acme-prod"), false)
+            .is_ok());
+        assert!(guard.check(&body("db_exist", "acme-prod"), false).is_ok());
+
+        // confirmation is only honored with `require_confirmation()`
+        assert!(guard.check(&body("drop", "acme-prod"), true).is_err());
+        let guard = guard.require_confirmation();
+        assert!(guard.check(&body("drop", "acme-prod"), true).is_ok());
+        assert!(guard.check(&body("drop", "acme-prod"), false).is_err());
+    }
+}
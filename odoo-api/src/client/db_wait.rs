@@ -0,0 +1,152 @@
+//! Shared logic for the `db_create_database_and_wait()` client methods
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements the method itself (as
+//! `send()` is either blocking or async), and delegates the polling here.
+
+use super::error::ClosureError;
+use super::timer;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// The polling options for `db_create_database_and_wait()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DbWait {
+    /// The time to wait between each probe
+    pub interval: Duration,
+
+    /// The overall deadline, measured from when the `create_database` request
+    /// returns
+    pub timeout: Duration,
+}
+
+impl Default for DbWait {
+    /// Probe every second, for up to 2 minutes
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+/// The result of a single readiness probe
+pub(crate) enum Probe<T, E> {
+    /// The login succeeded
+    Ready(T),
+
+    /// `db_exist` returned `false`
+    Missing,
+
+    /// Either request failed (e.g., because the registry is still loading)
+    Failed(E),
+}
+
+/// Implemented by the error types which have a `DatabaseNotReady` variant
+pub(crate) trait NotReadyFailure: Sized {
+    fn not_ready(db: &str, elapsed: Duration, source: Option<Self>) -> Self;
+}
+
+macro_rules! impl_not_ready_failure {
+    ($($(#[$attr:meta])* $error:ty),*) => {$(
+        $(#[$attr])*
+        impl NotReadyFailure for $error {
+            fn not_ready(db: &str, elapsed: Duration, source: Option<Self>) -> Self {
+                Self::DatabaseNotReady {
+                    db: db.into(),
+                    elapsed,
+                    source: source.map(Box::new),
+                }
+            }
+        }
+    )*};
+}
+
+impl_not_ready_failure!(
+    ClosureError,
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    super::error::ReqwestError,
+    #[cfg(feature = "ureq")]
+    super::error::UreqError
+);
+
+/// Call `probe` every `wait.interval` until it's ready, or `wait.timeout` passes
+pub(crate) fn run_blocking<T, E, F>(db: &str, wait: DbWait, mut probe: F) -> Result<T, E>
+where
+    E: NotReadyFailure,
+    F: FnMut() -> Probe<T, E>,
+{
+    let start = Instant::now();
+    loop {
+        let last = match probe() {
+            Probe::Ready(value) => return Ok(value),
+            Probe::Missing => None,
+            Probe::Failed(err) => Some(err),
+        };
+        let elapsed = start.elapsed();
+        if elapsed + wait.interval > wait.timeout {
+            return Err(E::not_ready(db, elapsed, last));
+        }
+        std::thread::sleep(wait.interval);
+    }
+}
+
+/// Same as [`run_blocking`], but for the async impls
+///
+/// See the [`timer`](super::timer) module for info on how the wait is
+/// implemented.
+pub(crate) async fn run_async<T, E, F, Fut>(db: &str, wait: DbWait, mut probe: F) -> Result<T, E>
+where
+    E: NotReadyFailure,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Probe<T, E>>,
+{
+    let start = timer::now();
+    loop {
+        let last = match probe().await {
+            Probe::Ready(value) => return Ok(value),
+            Probe::Missing => None,
+            Probe::Failed(err) => Some(err),
+        };
+        let elapsed = timer::now() - start;
+        if elapsed + wait.interval > wait.timeout {
+            return Err(E::not_ready(db, elapsed, last));
+        }
+        timer::sleep(wait.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn run_blocking_deadline() {
+        let wait = DbWait {
+            interval: Duration::from_millis(50),
+            timeout: Duration::from_millis(120),
+        };
+
+        // the database never appears
+        let mut probes = 0;
+        let err = run_blocking::<(), ClosureError, _>("new-database", wait, || {
+            probes += 1;
+            Probe::Missing
+        })
+        .unwrap_err();
+        assert_eq!(probes, 3);
+        match err {
+            ClosureError::DatabaseNotReady { db, source, .. } => {
+                assert_eq!(db, "new-database");
+                assert!(source.is_none());
+            }
+            err => panic!("unexpected error: {:?}", err),
+        }
+
+        // the last failure is included
+        let err = run_blocking::<(), ClosureError, _>("new-database", wait, || {
+            Probe::Failed(ClosureError::ClosureError("connection reset".into()))
+        })
+        .unwrap_err();
+        assert!(err.to_string().ends_with(": connection reset"), "{}", err);
+    }
+}
@@ -1,7 +1,25 @@
+use crate::client::MergeRejection;
 use crate::jsonrpc::response::JsonRpcError;
+use crate::jsonrpc::OdooId;
+use std::time::Duration;
+#[cfg(any(feature = "async", feature = "blocking"))]
+use std::time::Instant;
 use thiserror::Error;
 
-/// An error during the response parsing phase
+/// The field preset `name` isn't registered on the client
+///
+/// See [`register_preset()`](crate::client::OdooClient::register_preset).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown field preset `{name}` (registered presets: {registered:?})")]
+pub struct UnknownPreset {
+    /// The requested preset
+    pub name: String,
+
+    /// The registered presets, sorted by name
+    pub registered: Vec<String>,
+}
+
+/// An error during the request preparation or response parsing phase
 ///
 /// This error is used internally, and is typically parsed into either a
 /// [`ClosureError`] or a [`ReqwestError`].
@@ -16,9 +34,124 @@ pub enum ParseResponseError {
 
     /// The Odoo API request was not successful
     ///
-    /// See [`JsonRpcError`] for more details
-    #[error("JSON-RPC Error")]
-    JsonRpcError(#[from] JsonRpcError),
+    /// This is displayed as the error message and Python exception name. See
+    /// [`JsonRpcError`] for more details. The error is boxed, as it's fairly
+    /// large compared to the other variants.
+    #[error(transparent)]
+    JsonRpcError(#[from] Box<JsonRpcError>),
+
+    /// The server has database listing disabled (`list_db = False`)
+    ///
+    /// This is returned by [`db_list`](crate::client::OdooClient::db_list) when
+    /// Odoo responds with `AccessDenied`.
+    #[error("Database listing is disabled on this server")]
+    DbListingDisabled,
+
+    /// The old password was rejected when changing the user's password
+    ///
+    /// This is returned by [`user_change_password`](crate::client::OdooClient::user_change_password)
+    /// when Odoo responds with `AccessDenied`.
+    #[error("The old password is incorrect")]
+    PasswordChangeRejected,
+
+    /// The merge wizard refused to merge the partners
+    ///
+    /// This is returned by [`merge_partners`](crate::client::OdooClient::merge_partners)
+    /// when Odoo responds with a recognised `UserError`. The `message` is Odoo's
+    /// original (possibly translated) explanation.
+    #[error("The partners could not be merged: {message}")]
+    PartnerMergeRejected {
+        reason: MergeRejection,
+        message: String,
+    },
+
+    /// The Odoo method was called without a required positional argument
+    ///
+    /// This is usually caused by passing the wrong `args` to
+    /// [`execute`](crate::client::OdooClient::execute) or
+    /// [`execute_kw`](crate::client::OdooClient::execute_kw), which pass their
+    /// args to Odoo verbatim (e.g., `search` requires a domain).
+    #[error(
+        "Missing argument when calling `{method}`: {detail} (hint: `execute` and \
+         `execute_kw` pass args to Odoo verbatim; consider the ORM methods like \
+         `client.search()` instead)"
+    )]
+    MissingArgument { method: String, detail: String },
+
+    /// A destructive `db` request was refused by the client's [`DbGuard`](crate::client::DbGuard)
+    ///
+    /// The request was not sent to Odoo.
+    #[error("Refusing to `{method}` the protected database `{db}`")]
+    ProtectedDatabase { method: String, db: String },
+
+    /// The last `execute` argument is a dict, which was likely meant as kwargs
+    ///
+    /// Only checked when enabled with `check_kwargs_misuse()`. `execute` passes
+    /// every argument positionally. Use `execute_kw` for kwargs, or call
+    /// `allow_trailing_dict()` on the request if the dict is positional (e.g.
+    /// the `vals` for `create`). The request was not sent.
+    #[error(
+        "The last `execute` argument for `{model}.{method}()` is a dict, which Odoo passes \
+         positionally; use `execute_kw` for kwargs, or `allow_trailing_dict()`"
+    )]
+    LikelyKwargsMisuse { model: String, method: String },
+
+    /// A `/web/database/*` request was refused by the database manager
+    ///
+    /// Odoo reports these errors by re-rendering the manager page (e.g., for
+    /// a wrong master password), so `message` is the text of the error alert,
+    /// or the HTTP status if the endpoint is unavailable.
+    #[error("Database manager error: {message}")]
+    DatabaseManager { message: String },
+
+    /// The response was not a valid JSON-RPC response
+    ///
+    /// This is returned if the response has neither a `result` nor an
+    /// `error`, or (with strict responses enabled) if it has unexpected
+    /// top-level keys.
+    #[error("Malformed JSON-RPC response: {reason}")]
+    MalformedResponse { reason: String },
+
+    /// The server responded with its (HTML) database selector page
+    ///
+    /// This happens on servers hosting multiple databases, when `dbfilter`
+    /// can't pick a database from the request. Set one with
+    /// [`with_database_hint`](crate::client::OdooClient::with_database_hint).
+    #[error(
+        "The server responded with its database selector page (hint: set the \
+         database with `client.with_database_hint()`)"
+    )]
+    DatabaseSelector,
+
+    /// The server responded with an empty body
+    ///
+    /// This happens when a reverse proxy (or Odoo itself) drops the response,
+    /// e.g. when a worker is recycled mid-request. With a
+    /// [`RetryPolicy`](crate::client::RetryPolicy), these are retried by default.
+    #[error("The server responded with an empty body")]
+    EmptyResponse,
+
+    /// The response was valid JSON, but not a JSON-RPC object (e.g. a bare `null`)
+    #[error("Expected a JSON-RPC response object, got {found}")]
+    NotAnObject { found: String },
+
+    /// The response body exceeded the client's size limit
+    ///
+    /// See [`with_max_response_size`](crate::client::OdooClient::with_max_response_size).
+    #[error("The response exceeded the {limit} byte size limit")]
+    ResponseTooLarge { limit: usize },
+
+    /// A response had an unexpected key (with the `strict-responses` feature)
+    ///
+    /// `context` is the response type, and `detail` names the offending key.
+    #[error("Unexpected response shape for `{context}`: {detail}")]
+    UnexpectedResponseShape { context: String, detail: String },
+}
+
+impl From<JsonRpcError> for ParseResponseError {
+    fn from(value: JsonRpcError) -> Self {
+        Self::JsonRpcError(Box::new(value))
+    }
 }
 
 pub type ParseResponseResult<T> = std::result::Result<T, ParseResponseError>;
@@ -36,6 +169,27 @@ pub enum AuthenticationError {
     /// response
     #[error("UID Parser Error")]
     UidParseError(String),
+
+    /// The user has two-factor authentication enabled
+    ///
+    /// Odoo only opens a partial session (`session_id`) until the TOTP code is
+    /// checked. Complete the login with `authenticate_totp()` (on the `reqwest`
+    /// impls), or use an API key as the password with `authenticate_manual()`.
+    #[error(
+        "Two-factor authentication is required; use `authenticate_totp()`, or an API \
+         key with `authenticate_manual()`"
+    )]
+    TwoFactorRequired { session_id: Option<String> },
+
+    /// The TOTP code was rejected by `authenticate_totp()`
+    #[error("The two-factor authentication code was rejected")]
+    TwoFactorRejected,
+
+    /// The server has several databases, so `authenticate_auto()` can't pick one
+    ///
+    /// Use `authenticate()` with one of the `databases` instead.
+    #[error("The server has several databases ({0:?}); use `authenticate()` with one of them")]
+    MultipleDatabases(Vec<String>),
 }
 
 pub type AuthenticationResult<T> = std::result::Result<T, AuthenticationError>;
@@ -62,9 +216,166 @@ pub enum ClosureError {
 
     /// The Odoo API request was not successful
     ///
-    /// See [`JsonRpcError`] for more details
-    #[error("JSON-RPC Error")]
-    JsonRpcError(#[from] JsonRpcError),
+    /// This is displayed as the error message and Python exception name. See
+    /// [`JsonRpcError`] for more details. The error is boxed, as it's fairly
+    /// large compared to the other variants.
+    #[error(transparent)]
+    JsonRpcError(#[from] Box<JsonRpcError>),
+
+    /// The server has database listing disabled (`list_db = False`)
+    ///
+    /// This is returned by [`db_list`](crate::client::OdooClient::db_list) when
+    /// Odoo responds with `AccessDenied`.
+    #[error("Database listing is disabled on this server")]
+    DbListingDisabled,
+
+    /// The session was rejected by the server at `url`
+    ///
+    /// This is returned by `switch_url_verified()`, which then restores the
+    /// previous URL.
+    #[error("The session was rejected by the server at {url}")]
+    InvalidSession { url: String },
+
+    /// A field preset isn't registered
+    ///
+    /// This is returned by `read_preset()` and `search_read_preset()`.
+    #[error(transparent)]
+    UnknownPreset(#[from] UnknownPreset),
+
+    /// The old password was rejected when changing the user's password
+    ///
+    /// This is returned by [`user_change_password`](crate::client::OdooClient::user_change_password)
+    /// when Odoo responds with `AccessDenied`.
+    #[error("The old password is incorrect")]
+    PasswordChangeRejected,
+
+    /// The merge wizard refused to merge the partners
+    ///
+    /// This is returned by [`merge_partners`](crate::client::OdooClient::merge_partners)
+    /// when Odoo responds with a recognised `UserError`. The `message` is Odoo's
+    /// original (possibly translated) explanation.
+    #[error("The partners could not be merged: {message}")]
+    PartnerMergeRejected {
+        reason: MergeRejection,
+        message: String,
+    },
+
+    /// The Odoo method was called without a required positional argument
+    ///
+    /// This is usually caused by passing the wrong `args` to
+    /// [`execute`](crate::client::OdooClient::execute) or
+    /// [`execute_kw`](crate::client::OdooClient::execute_kw), which pass their
+    /// args to Odoo verbatim (e.g., `search` requires a domain).
+    #[error(
+        "Missing argument when calling `{method}`: {detail} (hint: `execute` and \
+         `execute_kw` pass args to Odoo verbatim; consider the ORM methods like \
+         `client.search()` instead)"
+    )]
+    MissingArgument { method: String, detail: String },
+
+    /// A destructive `db` request was refused by the client's [`DbGuard`](crate::client::DbGuard)
+    ///
+    /// The request was not sent to Odoo.
+    #[error("Refusing to `{method}` the protected database `{db}`")]
+    ProtectedDatabase { method: String, db: String },
+
+    /// The last `execute` argument is a dict, which was likely meant as kwargs
+    ///
+    /// Only checked when enabled with `check_kwargs_misuse()`. `execute` passes
+    /// every argument positionally. Use `execute_kw` for kwargs, or call
+    /// `allow_trailing_dict()` on the request if the dict is positional (e.g.
+    /// the `vals` for `create`). The request was not sent.
+    #[error(
+        "The last `execute` argument for `{model}.{method}()` is a dict, which Odoo passes \
+         positionally; use `execute_kw` for kwargs, or `allow_trailing_dict()`"
+    )]
+    LikelyKwargsMisuse { model: String, method: String },
+
+    /// A `/web/database/*` request was refused by the database manager
+    ///
+    /// Odoo reports these errors by re-rendering the manager page (e.g., for
+    /// a wrong master password), so `message` is the text of the error alert,
+    /// or the HTTP status if the endpoint is unavailable.
+    #[error("Database manager error: {message}")]
+    DatabaseManager { message: String },
+
+    /// The response was not a valid JSON-RPC response
+    ///
+    /// This is returned if the response has neither a `result` nor an
+    /// `error`, or (with strict responses enabled) if it has unexpected
+    /// top-level keys.
+    #[error("Malformed JSON-RPC response: {reason}")]
+    MalformedResponse { reason: String },
+
+    /// The server responded with its (HTML) database selector page
+    ///
+    /// This happens on servers hosting multiple databases, when `dbfilter`
+    /// can't pick a database from the request. Set one with
+    /// [`with_database_hint`](crate::client::OdooClient::with_database_hint).
+    #[error(
+        "The server responded with its database selector page (hint: set the \
+         database with `client.with_database_hint()`)"
+    )]
+    DatabaseSelector,
+
+    /// The server responded with an empty body
+    ///
+    /// This happens when a reverse proxy (or Odoo itself) drops the response,
+    /// e.g. when a worker is recycled mid-request. With a
+    /// [`RetryPolicy`](crate::client::RetryPolicy), these are retried by default.
+    #[error("The server responded with an empty body")]
+    EmptyResponse,
+
+    /// The response was valid JSON, but not a JSON-RPC object (e.g. a bare `null`)
+    #[error("Expected a JSON-RPC response object, got {found}")]
+    NotAnObject { found: String },
+
+    /// The response body exceeded the client's size limit
+    ///
+    /// See [`with_max_response_size`](crate::client::OdooClient::with_max_response_size).
+    #[error("The response exceeded the {limit} byte size limit")]
+    ResponseTooLarge { limit: usize },
+
+    /// A response had an unexpected key (with the `strict-responses` feature)
+    ///
+    /// `context` is the response type, and `detail` names the offending key.
+    #[error("Unexpected response shape for `{context}`: {detail}")]
+    UnexpectedResponseShape { context: String, detail: String },
+
+    /// A `*_batched` request failed part-way through
+    ///
+    /// The first `processed` records were sent successfully, and the failing
+    /// chunk covered the records `processed..processed + failed`.
+    #[error(
+        "Batch failed on records {processed}..{end}: {source}",
+        end = .processed + .failed
+    )]
+    BatchError {
+        processed: usize,
+        failed: usize,
+        source: Box<Self>,
+    },
+
+    /// The database wasn't ready before the `db_create_database_and_wait()`
+    /// deadline
+    ///
+    /// `source` is the last failed probe, or `None` if the database didn't
+    /// exist yet.
+    #[error(
+        "Database `{db}` was not ready after {elapsed:?}{last}",
+        last = last_failure(.source)
+    )]
+    DatabaseNotReady {
+        db: String,
+        elapsed: Duration,
+        source: Option<Box<Self>>,
+    },
+}
+
+impl From<JsonRpcError> for ClosureError {
+    fn from(value: JsonRpcError) -> Self {
+        Self::JsonRpcError(Box::new(value))
+    }
 }
 
 // This is nicer than having a `ParseError` variant on the `ClosureError` struct
@@ -74,6 +385,29 @@ impl From<ParseResponseError> for ClosureError {
         match value {
             ParseResponseError::JsonRpcError(err) => Self::JsonRpcError(err),
             ParseResponseError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            ParseResponseError::DbListingDisabled => Self::DbListingDisabled,
+            ParseResponseError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            ParseResponseError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            ParseResponseError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            ParseResponseError::ProtectedDatabase { method, db } => {
+                Self::ProtectedDatabase { method, db }
+            }
+            ParseResponseError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            ParseResponseError::DatabaseManager { message } => Self::DatabaseManager { message },
+            ParseResponseError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            ParseResponseError::DatabaseSelector => Self::DatabaseSelector,
+            ParseResponseError::EmptyResponse => Self::EmptyResponse,
+            ParseResponseError::NotAnObject { found } => Self::NotAnObject { found },
+            ParseResponseError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            ParseResponseError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
         }
     }
 }
@@ -92,6 +426,27 @@ pub enum ClosureAuthError {
     /// response
     #[error("UID Parser Error")]
     UidParseError(String),
+
+    /// The user has two-factor authentication enabled
+    ///
+    /// Odoo only opens a partial session (`session_id`) until the TOTP code is
+    /// checked. Complete the login with `authenticate_totp()` (on the `reqwest`
+    /// impls), or use an API key as the password with `authenticate_manual()`.
+    #[error(
+        "Two-factor authentication is required; use `authenticate_totp()`, or an API \
+         key with `authenticate_manual()`"
+    )]
+    TwoFactorRequired { session_id: Option<String> },
+
+    /// The TOTP code was rejected by `authenticate_totp()`
+    #[error("The two-factor authentication code was rejected")]
+    TwoFactorRejected,
+
+    /// The server has several databases, so `authenticate_auto()` can't pick one
+    ///
+    /// Use `authenticate()` with one of the `databases` instead.
+    #[error("The server has several databases ({0:?}); use `authenticate()` with one of them")]
+    MultipleDatabases(Vec<String>),
 }
 
 // As with `From<ParseResponseError>`, we'd like to avoid having duplicate error fields
@@ -102,21 +457,40 @@ impl From<AuthenticationError> for ClosureAuthError {
                 Self::ClosureError(ClosureError::SerdeJsonError(err))
             }
             AuthenticationError::UidParseError(err) => Self::UidParseError(err),
+            AuthenticationError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            AuthenticationError::TwoFactorRejected => Self::TwoFactorRejected,
+            AuthenticationError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
         }
     }
 }
 
 pub type ClosureAuthResult<T> = std::result::Result<T, ClosureAuthError>;
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 #[derive(Debug, Error)]
 pub enum ReqwestError {
     /// An error from the [`reqwest`] library
     ///
     /// See [`reqwest::Error`] for more information.
-    #[cfg(any(feature = "async", feature = "blocking"))]
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
 
+    /// The request timed out
+    ///
+    /// See [`OdooRequest::with_timeout`](crate::client::OdooRequest::with_timeout)
+    /// and [`OdooClient::with_timeout`](crate::client::OdooClient::with_timeout).
+    #[error("Request timed out after {elapsed:?}")]
+    Timeout { elapsed: Duration },
+
+    /// An error encoding or parsing an XML-RPC request
+    ///
+    /// See the [`xmlrpc`](crate::xmlrpc) module for more info.
+    #[cfg(feature = "xmlrpc")]
+    #[error(transparent)]
+    XmlRpcError(#[from] crate::xmlrpc::XmlRpcError),
+
     /// A parsing error from the serde_json library
     ///
     /// This might be raised if the returned JSON data is invalid, or couldn't
@@ -126,22 +500,233 @@ pub enum ReqwestError {
 
     /// The Odoo API request was not successful
     ///
-    /// See [`JsonRpcError`] for more details
-    #[error("JSON-RPC Error")]
-    JsonRpcError(#[from] JsonRpcError),
+    /// This is displayed as the error message and Python exception name. See
+    /// [`JsonRpcError`] for more details. The error is boxed, as it's fairly
+    /// large compared to the other variants.
+    #[error(transparent)]
+    JsonRpcError(#[from] Box<JsonRpcError>),
+
+    /// The server has database listing disabled (`list_db = False`)
+    ///
+    /// This is returned by [`db_list`](crate::client::OdooClient::db_list) when
+    /// Odoo responds with `AccessDenied`.
+    #[error("Database listing is disabled on this server")]
+    DbListingDisabled,
+
+    /// The session was rejected by the server at `url`
+    ///
+    /// This is returned by `switch_url_verified()`, which then restores the
+    /// previous URL.
+    #[error("The session was rejected by the server at {url}")]
+    InvalidSession { url: String },
+
+    /// A field preset isn't registered
+    ///
+    /// This is returned by `read_preset()` and `search_read_preset()`.
+    #[error(transparent)]
+    UnknownPreset(#[from] UnknownPreset),
+
+    /// The old password was rejected when changing the user's password
+    ///
+    /// This is returned by [`user_change_password`](crate::client::OdooClient::user_change_password)
+    /// when Odoo responds with `AccessDenied`.
+    #[error("The old password is incorrect")]
+    PasswordChangeRejected,
+
+    /// The merge wizard refused to merge the partners
+    ///
+    /// This is returned by [`merge_partners`](crate::client::OdooClient::merge_partners)
+    /// when Odoo responds with a recognised `UserError`. The `message` is Odoo's
+    /// original (possibly translated) explanation.
+    #[error("The partners could not be merged: {message}")]
+    PartnerMergeRejected {
+        reason: MergeRejection,
+        message: String,
+    },
+
+    /// The Odoo method was called without a required positional argument
+    ///
+    /// This is usually caused by passing the wrong `args` to
+    /// [`execute`](crate::client::OdooClient::execute) or
+    /// [`execute_kw`](crate::client::OdooClient::execute_kw), which pass their
+    /// args to Odoo verbatim (e.g., `search` requires a domain).
+    #[error(
+        "Missing argument when calling `{method}`: {detail} (hint: `execute` and \
+         `execute_kw` pass args to Odoo verbatim; consider the ORM methods like \
+         `client.search()` instead)"
+    )]
+    MissingArgument { method: String, detail: String },
+
+    /// A destructive `db` request was refused by the client's [`DbGuard`](crate::client::DbGuard)
+    ///
+    /// The request was not sent to Odoo.
+    #[error("Refusing to `{method}` the protected database `{db}`")]
+    ProtectedDatabase { method: String, db: String },
+
+    /// The last `execute` argument is a dict, which was likely meant as kwargs
+    ///
+    /// Only checked when enabled with `check_kwargs_misuse()`. `execute` passes
+    /// every argument positionally. Use `execute_kw` for kwargs, or call
+    /// `allow_trailing_dict()` on the request if the dict is positional (e.g.
+    /// the `vals` for `create`). The request was not sent.
+    #[error(
+        "The last `execute` argument for `{model}.{method}()` is a dict, which Odoo passes \
+         positionally; use `execute_kw` for kwargs, or `allow_trailing_dict()`"
+    )]
+    LikelyKwargsMisuse { model: String, method: String },
+
+    /// A `/web/database/*` request was refused by the database manager
+    ///
+    /// Odoo reports these errors by re-rendering the manager page (e.g., for
+    /// a wrong master password), so `message` is the text of the error alert,
+    /// or the HTTP status if the endpoint is unavailable.
+    #[error("Database manager error: {message}")]
+    DatabaseManager { message: String },
+
+    /// The response was not a valid JSON-RPC response
+    ///
+    /// This is returned if the response has neither a `result` nor an
+    /// `error`, or (with strict responses enabled) if it has unexpected
+    /// top-level keys.
+    #[error("Malformed JSON-RPC response: {reason}")]
+    MalformedResponse { reason: String },
+
+    /// The server responded with its (HTML) database selector page
+    ///
+    /// This happens on servers hosting multiple databases, when `dbfilter`
+    /// can't pick a database from the request. Set one with
+    /// [`with_database_hint`](crate::client::OdooClient::with_database_hint).
+    #[error(
+        "The server responded with its database selector page (hint: set the \
+         database with `client.with_database_hint()`)"
+    )]
+    DatabaseSelector,
+
+    /// The server responded with an empty body
+    ///
+    /// This happens when a reverse proxy (or Odoo itself) drops the response,
+    /// e.g. when a worker is recycled mid-request. With a
+    /// [`RetryPolicy`](crate::client::RetryPolicy), these are retried by default.
+    #[error("The server responded with an empty body")]
+    EmptyResponse,
+
+    /// The response was valid JSON, but not a JSON-RPC object (e.g. a bare `null`)
+    #[error("Expected a JSON-RPC response object, got {found}")]
+    NotAnObject { found: String },
+
+    /// A report request returned something other than a PDF
+    ///
+    /// This is usually an HTML error page (e.g. an access error), or the login
+    /// page if there's no valid web session. `snippet` is the start of the body.
+    #[error("Expected a PDF report, got `{content_type}` (status {status}): {snippet}")]
+    ReportNotPdf {
+        status: u16,
+        content_type: String,
+        snippet: String,
+    },
+
+    /// The response body exceeded the client's size limit
+    ///
+    /// See [`with_max_response_size`](crate::client::OdooClient::with_max_response_size).
+    #[error("The response exceeded the {limit} byte size limit")]
+    ResponseTooLarge { limit: usize },
+
+    /// A response had an unexpected key (with the `strict-responses` feature)
+    ///
+    /// `context` is the response type, and `detail` names the offending key.
+    #[error("Unexpected response shape for `{context}`: {detail}")]
+    UnexpectedResponseShape { context: String, detail: String },
+
+    /// A `*_batched` request failed part-way through
+    ///
+    /// The first `processed` records were sent successfully, and the failing
+    /// chunk covered the records `processed..processed + failed`.
+    #[error(
+        "Batch failed on records {processed}..{end}: {source}",
+        end = .processed + .failed
+    )]
+    BatchError {
+        processed: usize,
+        failed: usize,
+        source: Box<Self>,
+    },
+
+    /// The database wasn't ready before the `db_create_database_and_wait()`
+    /// deadline
+    ///
+    /// `source` is the last failed probe, or `None` if the database didn't
+    /// exist yet.
+    #[error(
+        "Database `{db}` was not ready after {elapsed:?}{last}",
+        last = last_failure(.source)
+    )]
+    DatabaseNotReady {
+        db: String,
+        elapsed: Duration,
+        source: Option<Box<Self>>,
+    },
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
+impl From<JsonRpcError> for ReqwestError {
+    fn from(value: JsonRpcError) -> Self {
+        Self::JsonRpcError(Box::new(value))
+    }
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<ParseResponseError> for ReqwestError {
     fn from(value: ParseResponseError) -> Self {
         match value {
             ParseResponseError::JsonRpcError(err) => Self::JsonRpcError(err),
             ParseResponseError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            ParseResponseError::DbListingDisabled => Self::DbListingDisabled,
+            ParseResponseError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            ParseResponseError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            ParseResponseError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            ParseResponseError::ProtectedDatabase { method, db } => {
+                Self::ProtectedDatabase { method, db }
+            }
+            ParseResponseError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            ParseResponseError::DatabaseManager { message } => Self::DatabaseManager { message },
+            ParseResponseError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            ParseResponseError::DatabaseSelector => Self::DatabaseSelector,
+            ParseResponseError::EmptyResponse => Self::EmptyResponse,
+            ParseResponseError::NotAnObject { found } => Self::NotAnObject { found },
+            ParseResponseError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            ParseResponseError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+impl ReqwestError {
+    /// Convert a [`reqwest::Error`], mapping timeouts to [`ReqwestError::Timeout`]
+    ///
+    /// `start` is the time the request was sent.
+    pub(crate) fn from_reqwest(err: reqwest::Error, start: Instant) -> Self {
+        if err.is_timeout() {
+            Self::Timeout {
+                elapsed: start.elapsed(),
+            }
+        } else {
+            Self::ReqwestError(err)
         }
     }
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 pub type ReqwestResult<T> = std::result::Result<T, ReqwestError>;
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 #[derive(Debug, Error)]
 pub enum ReqwestAuthError {
     #[error(transparent)]
@@ -151,9 +736,38 @@ pub enum ReqwestAuthError {
     /// response
     #[error("UID Parser Error")]
     UidParseError(String),
+
+    /// The user has two-factor authentication enabled
+    ///
+    /// Odoo only opens a partial session (`session_id`) until the TOTP code is
+    /// checked. Complete the login with `authenticate_totp()` (on the `reqwest`
+    /// impls), or use an API key as the password with `authenticate_manual()`.
+    #[error(
+        "Two-factor authentication is required; use `authenticate_totp()`, or an API \
+         key with `authenticate_manual()`"
+    )]
+    TwoFactorRequired { session_id: Option<String> },
+
+    /// The TOTP code was rejected by `authenticate_totp()`
+    #[error("The two-factor authentication code was rejected")]
+    TwoFactorRejected,
+
+    /// The server has several databases, so `authenticate_auto()` can't pick one
+    ///
+    /// Use `authenticate()` with one of the `databases` instead.
+    #[error("The server has several databases ({0:?}); use `authenticate()` with one of them")]
+    MultipleDatabases(Vec<String>),
+
+    /// The login was rejected by `authenticate_via_login_form()`
+    ///
+    /// The `message` is the error shown on the login form (e.g. "Wrong
+    /// login/password"), if any.
+    #[error("The login form was rejected: {message}")]
+    LoginRejected { message: String },
 }
 
 // As with `From<ParseResponseError>`, we'd like to avoid having duplicate error fields
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<AuthenticationError> for ReqwestAuthError {
     fn from(value: AuthenticationError) -> Self {
         match value {
@@ -161,12 +775,297 @@ impl From<AuthenticationError> for ReqwestAuthError {
                 Self::ReqwestError(ReqwestError::SerdeJsonError(err))
             }
             AuthenticationError::UidParseError(err) => Self::UidParseError(err),
+            AuthenticationError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            AuthenticationError::TwoFactorRejected => Self::TwoFactorRejected,
+            AuthenticationError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
         }
     }
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 pub type ReqwestAuthResult<T> = std::result::Result<T, ReqwestAuthError>;
 
+#[cfg(feature = "ureq")]
+#[derive(Debug, Error)]
+pub enum UreqError {
+    /// An error from the [`ureq`] library
+    ///
+    /// See [`ureq::Error`] for more information. This is boxed, as `ureq::Error`
+    /// may contain the full HTTP response.
+    #[error(transparent)]
+    UreqError(Box<ureq::Error>),
+
+    /// A parsing error from the serde_json library
+    ///
+    /// This might be raised if the returned JSON data is invalid, or couldn't
+    /// be parsed into the `XxxResponse` struct properly.
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+
+    /// The Odoo API request was not successful
+    ///
+    /// This is displayed as the error message and Python exception name. See
+    /// [`JsonRpcError`] for more details. The error is boxed, as it's fairly
+    /// large compared to the other variants.
+    #[error(transparent)]
+    JsonRpcError(#[from] Box<JsonRpcError>),
+
+    /// The server has database listing disabled (`list_db = False`)
+    ///
+    /// This is returned by [`db_list`](crate::client::OdooClient::db_list) when
+    /// Odoo responds with `AccessDenied`.
+    #[error("Database listing is disabled on this server")]
+    DbListingDisabled,
+
+    /// The session was rejected by the server at `url`
+    ///
+    /// This is returned by `switch_url_verified()`, which then restores the
+    /// previous URL.
+    #[error("The session was rejected by the server at {url}")]
+    InvalidSession { url: String },
+
+    /// A field preset isn't registered
+    ///
+    /// This is returned by `read_preset()` and `search_read_preset()`.
+    #[error(transparent)]
+    UnknownPreset(#[from] UnknownPreset),
+
+    /// The old password was rejected when changing the user's password
+    ///
+    /// This is returned by [`user_change_password`](crate::client::OdooClient::user_change_password)
+    /// when Odoo responds with `AccessDenied`.
+    #[error("The old password is incorrect")]
+    PasswordChangeRejected,
+
+    /// The merge wizard refused to merge the partners
+    ///
+    /// This is returned by [`merge_partners`](crate::client::OdooClient::merge_partners)
+    /// when Odoo responds with a recognised `UserError`. The `message` is Odoo's
+    /// original (possibly translated) explanation.
+    #[error("The partners could not be merged: {message}")]
+    PartnerMergeRejected {
+        reason: MergeRejection,
+        message: String,
+    },
+
+    /// The Odoo method was called without a required positional argument
+    ///
+    /// This is usually caused by passing the wrong `args` to
+    /// [`execute`](crate::client::OdooClient::execute) or
+    /// [`execute_kw`](crate::client::OdooClient::execute_kw), which pass their
+    /// args to Odoo verbatim (e.g., `search` requires a domain).
+    #[error(
+        "Missing argument when calling `{method}`: {detail} (hint: `execute` and \
+         `execute_kw` pass args to Odoo verbatim; consider the ORM methods like \
+         `client.search()` instead)"
+    )]
+    MissingArgument { method: String, detail: String },
+
+    /// A destructive `db` request was refused by the client's [`DbGuard`](crate::client::DbGuard)
+    ///
+    /// The request was not sent to Odoo.
+    #[error("Refusing to `{method}` the protected database `{db}`")]
+    ProtectedDatabase { method: String, db: String },
+
+    /// The last `execute` argument is a dict, which was likely meant as kwargs
+    ///
+    /// Only checked when enabled with `check_kwargs_misuse()`. `execute` passes
+    /// every argument positionally. Use `execute_kw` for kwargs, or call
+    /// `allow_trailing_dict()` on the request if the dict is positional (e.g.
+    /// the `vals` for `create`). The request was not sent.
+    #[error(
+        "The last `execute` argument for `{model}.{method}()` is a dict, which Odoo passes \
+         positionally; use `execute_kw` for kwargs, or `allow_trailing_dict()`"
+    )]
+    LikelyKwargsMisuse { model: String, method: String },
+
+    /// A `/web/database/*` request was refused by the database manager
+    ///
+    /// Odoo reports these errors by re-rendering the manager page (e.g., for
+    /// a wrong master password), so `message` is the text of the error alert,
+    /// or the HTTP status if the endpoint is unavailable.
+    #[error("Database manager error: {message}")]
+    DatabaseManager { message: String },
+
+    /// The response was not a valid JSON-RPC response
+    ///
+    /// This is returned if the response has neither a `result` nor an
+    /// `error`, or (with strict responses enabled) if it has unexpected
+    /// top-level keys.
+    #[error("Malformed JSON-RPC response: {reason}")]
+    MalformedResponse { reason: String },
+
+    /// The server responded with its (HTML) database selector page
+    ///
+    /// This happens on servers hosting multiple databases, when `dbfilter`
+    /// can't pick a database from the request. Set one with
+    /// [`with_database_hint`](crate::client::OdooClient::with_database_hint).
+    #[error(
+        "The server responded with its database selector page (hint: set the \
+         database with `client.with_database_hint()`)"
+    )]
+    DatabaseSelector,
+
+    /// The server responded with an empty body
+    ///
+    /// This happens when a reverse proxy (or Odoo itself) drops the response,
+    /// e.g. when a worker is recycled mid-request. With a
+    /// [`RetryPolicy`](crate::client::RetryPolicy), these are retried by default.
+    #[error("The server responded with an empty body")]
+    EmptyResponse,
+
+    /// The response was valid JSON, but not a JSON-RPC object (e.g. a bare `null`)
+    #[error("Expected a JSON-RPC response object, got {found}")]
+    NotAnObject { found: String },
+
+    /// The response body exceeded the client's size limit
+    ///
+    /// See [`with_max_response_size`](crate::client::OdooClient::with_max_response_size).
+    #[error("The response exceeded the {limit} byte size limit")]
+    ResponseTooLarge { limit: usize },
+
+    /// A response had an unexpected key (with the `strict-responses` feature)
+    ///
+    /// `context` is the response type, and `detail` names the offending key.
+    #[error("Unexpected response shape for `{context}`: {detail}")]
+    UnexpectedResponseShape { context: String, detail: String },
+
+    /// A `*_batched` request failed part-way through
+    ///
+    /// The first `processed` records were sent successfully, and the failing
+    /// chunk covered the records `processed..processed + failed`.
+    #[error(
+        "Batch failed on records {processed}..{end}: {source}",
+        end = .processed + .failed
+    )]
+    BatchError {
+        processed: usize,
+        failed: usize,
+        source: Box<Self>,
+    },
+
+    /// The database wasn't ready before the `db_create_database_and_wait()`
+    /// deadline
+    ///
+    /// `source` is the last failed probe, or `None` if the database didn't
+    /// exist yet.
+    #[error(
+        "Database `{db}` was not ready after {elapsed:?}{last}",
+        last = last_failure(.source)
+    )]
+    DatabaseNotReady {
+        db: String,
+        elapsed: Duration,
+        source: Option<Box<Self>>,
+    },
+}
+
+#[cfg(feature = "ureq")]
+impl From<ureq::Error> for UreqError {
+    fn from(value: ureq::Error) -> Self {
+        Self::UreqError(Box::new(value))
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<JsonRpcError> for UreqError {
+    fn from(value: JsonRpcError) -> Self {
+        Self::JsonRpcError(Box::new(value))
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<ParseResponseError> for UreqError {
+    fn from(value: ParseResponseError) -> Self {
+        match value {
+            ParseResponseError::JsonRpcError(err) => Self::JsonRpcError(err),
+            ParseResponseError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            ParseResponseError::DbListingDisabled => Self::DbListingDisabled,
+            ParseResponseError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            ParseResponseError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            ParseResponseError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            ParseResponseError::ProtectedDatabase { method, db } => {
+                Self::ProtectedDatabase { method, db }
+            }
+            ParseResponseError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            ParseResponseError::DatabaseManager { message } => Self::DatabaseManager { message },
+            ParseResponseError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            ParseResponseError::DatabaseSelector => Self::DatabaseSelector,
+            ParseResponseError::EmptyResponse => Self::EmptyResponse,
+            ParseResponseError::NotAnObject { found } => Self::NotAnObject { found },
+            ParseResponseError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            ParseResponseError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ureq")]
+pub type UreqResult<T> = std::result::Result<T, UreqError>;
+
+#[cfg(feature = "ureq")]
+#[derive(Debug, Error)]
+pub enum UreqAuthError {
+    #[error(transparent)]
+    UreqError(#[from] UreqError),
+
+    /// An error occured while parsing the `uid` field from the authenticate
+    /// response
+    #[error("UID Parser Error")]
+    UidParseError(String),
+
+    /// The user has two-factor authentication enabled
+    ///
+    /// Odoo only opens a partial session (`session_id`) until the TOTP code is
+    /// checked. Complete the login with `authenticate_totp()` (on the `reqwest`
+    /// impls), or use an API key as the password with `authenticate_manual()`.
+    #[error(
+        "Two-factor authentication is required; use `authenticate_totp()`, or an API \
+         key with `authenticate_manual()`"
+    )]
+    TwoFactorRequired { session_id: Option<String> },
+
+    /// The TOTP code was rejected by `authenticate_totp()`
+    #[error("The two-factor authentication code was rejected")]
+    TwoFactorRejected,
+
+    /// The server has several databases, so `authenticate_auto()` can't pick one
+    ///
+    /// Use `authenticate()` with one of the `databases` instead.
+    #[error("The server has several databases ({0:?}); use `authenticate()` with one of them")]
+    MultipleDatabases(Vec<String>),
+}
+
+// As with `From<ParseResponseError>`, we'd like to avoid having duplicate error fields
+#[cfg(feature = "ureq")]
+impl From<AuthenticationError> for UreqAuthError {
+    fn from(value: AuthenticationError) -> Self {
+        match value {
+            AuthenticationError::SerdeJsonError(err) => {
+                Self::UreqError(UreqError::SerdeJsonError(err))
+            }
+            AuthenticationError::UidParseError(err) => Self::UidParseError(err),
+            AuthenticationError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            AuthenticationError::TwoFactorRejected => Self::TwoFactorRejected,
+            AuthenticationError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
+        }
+    }
+}
+
+#[cfg(feature = "ureq")]
+pub type UreqAuthResult<T> = std::result::Result<T, UreqAuthError>;
+
 #[derive(Debug, Error)]
 pub enum Error {
     /// An error occured inside the custom closure
@@ -184,6 +1083,26 @@ pub enum Error {
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
 
+    /// The request timed out
+    ///
+    /// This is currently only returned by the `reqwest` impls.
+    #[error("Request timed out after {elapsed:?}")]
+    Timeout { elapsed: Duration },
+
+    /// An error encoding or parsing an XML-RPC request
+    ///
+    /// See the [`xmlrpc`](crate::xmlrpc) module for more info.
+    #[cfg(feature = "xmlrpc")]
+    #[error(transparent)]
+    XmlRpcError(#[from] crate::xmlrpc::XmlRpcError),
+
+    /// An error from the [`ureq`] library
+    ///
+    /// See [`ureq::Error`] for more information.
+    #[cfg(feature = "ureq")]
+    #[error(transparent)]
+    UreqError(Box<ureq::Error>),
+
     /// A parsing error from the serde_json library
     ///
     /// This might be raised if the returned JSON data is invalid, or couldn't
@@ -193,14 +1112,265 @@ pub enum Error {
 
     /// The Odoo API request was not successful
     ///
-    /// See [`JsonRpcError`] for more details
-    #[error("JSON-RPC Error")]
-    JsonRpcError(#[from] JsonRpcError),
+    /// This is displayed as the error message and Python exception name. See
+    /// [`JsonRpcError`] for more details. The error is boxed, as it's fairly
+    /// large compared to the other variants.
+    #[error(transparent)]
+    JsonRpcError(#[from] Box<JsonRpcError>),
+
+    /// The server has database listing disabled (`list_db = False`)
+    ///
+    /// This is returned by [`db_list`](crate::client::OdooClient::db_list) when
+    /// Odoo responds with `AccessDenied`.
+    #[error("Database listing is disabled on this server")]
+    DbListingDisabled,
+
+    /// The session was rejected by the server at `url`
+    ///
+    /// This is returned by `switch_url_verified()`, which then restores the
+    /// previous URL.
+    #[error("The session was rejected by the server at {url}")]
+    InvalidSession { url: String },
+
+    /// A field preset isn't registered
+    ///
+    /// This is returned by `read_preset()` and `search_read_preset()`.
+    #[error(transparent)]
+    UnknownPreset(#[from] UnknownPreset),
+
+    /// The old password was rejected when changing the user's password
+    ///
+    /// This is returned by [`user_change_password`](crate::client::OdooClient::user_change_password)
+    /// when Odoo responds with `AccessDenied`.
+    #[error("The old password is incorrect")]
+    PasswordChangeRejected,
+
+    /// The merge wizard refused to merge the partners
+    ///
+    /// This is returned by [`merge_partners`](crate::client::OdooClient::merge_partners)
+    /// when Odoo responds with a recognised `UserError`. The `message` is Odoo's
+    /// original (possibly translated) explanation.
+    #[error("The partners could not be merged: {message}")]
+    PartnerMergeRejected {
+        reason: MergeRejection,
+        message: String,
+    },
+
+    /// The Odoo method was called without a required positional argument
+    ///
+    /// This is usually caused by passing the wrong `args` to
+    /// [`execute`](crate::client::OdooClient::execute) or
+    /// [`execute_kw`](crate::client::OdooClient::execute_kw), which pass their
+    /// args to Odoo verbatim (e.g., `search` requires a domain).
+    #[error(
+        "Missing argument when calling `{method}`: {detail} (hint: `execute` and \
+         `execute_kw` pass args to Odoo verbatim; consider the ORM methods like \
+         `client.search()` instead)"
+    )]
+    MissingArgument { method: String, detail: String },
+
+    /// A destructive `db` request was refused by the client's [`DbGuard`](crate::client::DbGuard)
+    ///
+    /// The request was not sent to Odoo.
+    #[error("Refusing to `{method}` the protected database `{db}`")]
+    ProtectedDatabase { method: String, db: String },
+
+    /// The last `execute` argument is a dict, which was likely meant as kwargs
+    ///
+    /// Only checked when enabled with `check_kwargs_misuse()`. `execute` passes
+    /// every argument positionally. Use `execute_kw` for kwargs, or call
+    /// `allow_trailing_dict()` on the request if the dict is positional (e.g.
+    /// the `vals` for `create`). The request was not sent.
+    #[error(
+        "The last `execute` argument for `{model}.{method}()` is a dict, which Odoo passes \
+         positionally; use `execute_kw` for kwargs, or `allow_trailing_dict()`"
+    )]
+    LikelyKwargsMisuse { model: String, method: String },
+
+    /// A `/web/database/*` request was refused by the database manager
+    ///
+    /// Odoo reports these errors by re-rendering the manager page (e.g., for
+    /// a wrong master password), so `message` is the text of the error alert,
+    /// or the HTTP status if the endpoint is unavailable.
+    #[error("Database manager error: {message}")]
+    DatabaseManager { message: String },
+
+    /// The response was not a valid JSON-RPC response
+    ///
+    /// This is returned if the response has neither a `result` nor an
+    /// `error`, or (with strict responses enabled) if it has unexpected
+    /// top-level keys.
+    #[error("Malformed JSON-RPC response: {reason}")]
+    MalformedResponse { reason: String },
+
+    /// The server responded with its (HTML) database selector page
+    ///
+    /// This happens on servers hosting multiple databases, when `dbfilter`
+    /// can't pick a database from the request. Set one with
+    /// [`with_database_hint`](crate::client::OdooClient::with_database_hint).
+    #[error(
+        "The server responded with its database selector page (hint: set the \
+         database with `client.with_database_hint()`)"
+    )]
+    DatabaseSelector,
+
+    /// The server responded with an empty body
+    ///
+    /// This happens when a reverse proxy (or Odoo itself) drops the response,
+    /// e.g. when a worker is recycled mid-request. With a
+    /// [`RetryPolicy`](crate::client::RetryPolicy), these are retried by default.
+    #[error("The server responded with an empty body")]
+    EmptyResponse,
+
+    /// The response was valid JSON, but not a JSON-RPC object (e.g. a bare `null`)
+    #[error("Expected a JSON-RPC response object, got {found}")]
+    NotAnObject { found: String },
+
+    /// A report request returned something other than a PDF
+    ///
+    /// This is usually an HTML error page (e.g. an access error), or the login
+    /// page if there's no valid web session. `snippet` is the start of the body.
+    #[error("Expected a PDF report, got `{content_type}` (status {status}): {snippet}")]
+    ReportNotPdf {
+        status: u16,
+        content_type: String,
+        snippet: String,
+    },
+
+    /// The response body exceeded the client's size limit
+    ///
+    /// See [`with_max_response_size`](crate::client::OdooClient::with_max_response_size).
+    #[error("The response exceeded the {limit} byte size limit")]
+    ResponseTooLarge { limit: usize },
+
+    /// A response had an unexpected key (with the `strict-responses` feature)
+    ///
+    /// `context` is the response type, and `detail` names the offending key.
+    #[error("Unexpected response shape for `{context}`: {detail}")]
+    UnexpectedResponseShape { context: String, detail: String },
+
+    /// A `*_batched` request failed part-way through
+    ///
+    /// The first `processed` records were sent successfully, and the failing
+    /// chunk covered the records `processed..processed + failed`.
+    #[error(
+        "Batch failed on records {processed}..{end}: {source}",
+        end = .processed + .failed
+    )]
+    BatchError {
+        processed: usize,
+        failed: usize,
+        source: Box<Self>,
+    },
+
+    /// The database wasn't ready before the `db_create_database_and_wait()`
+    /// deadline
+    ///
+    /// `source` is the last failed probe, or `None` if the database didn't
+    /// exist yet.
+    #[error(
+        "Database `{db}` was not ready after {elapsed:?}{last}",
+        last = last_failure(.source)
+    )]
+    DatabaseNotReady {
+        db: String,
+        elapsed: Duration,
+        source: Option<Box<Self>>,
+    },
 
     /// An error occured while parsing the `uid` field from the authenticate
     /// response
     #[error("UID Parser Error")]
     UidParseError(String),
+
+    /// The user has two-factor authentication enabled
+    ///
+    /// Odoo only opens a partial session (`session_id`) until the TOTP code is
+    /// checked. Complete the login with `authenticate_totp()` (on the `reqwest`
+    /// impls), or use an API key as the password with `authenticate_manual()`.
+    #[error(
+        "Two-factor authentication is required; use `authenticate_totp()`, or an API \
+         key with `authenticate_manual()`"
+    )]
+    TwoFactorRequired { session_id: Option<String> },
+
+    /// The TOTP code was rejected by `authenticate_totp()`
+    #[error("The two-factor authentication code was rejected")]
+    TwoFactorRejected,
+
+    /// The server has several databases, so `authenticate_auto()` can't pick one
+    ///
+    /// Use `authenticate()` with one of the `databases` instead.
+    #[error("The server has several databases ({0:?}); use `authenticate()` with one of them")]
+    MultipleDatabases(Vec<String>),
+
+    /// The login was rejected by `authenticate_via_login_form()`
+    ///
+    /// The `message` is the error shown on the login form (e.g. "Wrong
+    /// login/password"), if any.
+    #[error("The login form was rejected: {message}")]
+    LoginRejected { message: String },
+
+    /// The "current" company is not a member of the allowed companies
+    ///
+    /// See [`OdooClient::with_companies`](crate::client::OdooClient::with_companies)
+    #[error("Company {current} is not a member of the allowed companies {allowed:?}")]
+    CompanyNotAllowed {
+        current: OdooId,
+        allowed: Vec<OdooId>,
+    },
+    /// A [default](crate::client::OdooRequest::with_defaults) key was already
+    /// prefixed with `default_`
+    #[error("The default `{key}` is already prefixed with `default_`")]
+    InvalidDefault { key: String },
+
+    /// The [`ClientOptions`](crate::client::ClientOptions) are missing a
+    /// required option, or have conflicting options
+    #[error("Invalid client options: {reason}")]
+    InvalidOptions { reason: String },
+}
+
+impl Error {
+    /// The error raised by the server, if the request reached Odoo
+    fn server_error(&self) -> Option<&JsonRpcError> {
+        match self {
+            Self::JsonRpcError(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Check whether this error was raised by the server (i.e., a JSON-RPC
+    /// error), rather than the HTTP layer or the client
+    pub fn is_server_error(&self) -> bool {
+        self.server_error().is_some()
+    }
+
+    /// The Python exception name (e.g. `odoo.exceptions.AccessError`)
+    ///
+    /// See [`JsonRpcError::exception_name`]
+    pub fn server_exception_name(&self) -> Option<&str> {
+        self.server_error()?.exception_name()
+    }
+
+    /// The server's error message (e.g. `You are not allowed to access ...`)
+    ///
+    /// See [`JsonRpcError::exception_message`]
+    pub fn server_message(&self) -> Option<&str> {
+        self.server_error()?.exception_message()
+    }
+
+    /// The Python traceback, if the server sent one
+    ///
+    /// See [`JsonRpcError::traceback`]
+    pub fn server_traceback(&self) -> Option<&str> {
+        self.server_error()?.traceback()
+    }
+}
+
+impl From<JsonRpcError> for Error {
+    fn from(value: JsonRpcError) -> Self {
+        Self::JsonRpcError(Box::new(value))
+    }
 }
 
 // This is nicer than having a `ParseError` variant on the `ClosureError` struct
@@ -210,6 +1380,29 @@ impl From<ParseResponseError> for Error {
         match value {
             ParseResponseError::JsonRpcError(err) => Self::JsonRpcError(err),
             ParseResponseError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            ParseResponseError::DbListingDisabled => Self::DbListingDisabled,
+            ParseResponseError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            ParseResponseError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            ParseResponseError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            ParseResponseError::ProtectedDatabase { method, db } => {
+                Self::ProtectedDatabase { method, db }
+            }
+            ParseResponseError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            ParseResponseError::DatabaseManager { message } => Self::DatabaseManager { message },
+            ParseResponseError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            ParseResponseError::DatabaseSelector => Self::DatabaseSelector,
+            ParseResponseError::EmptyResponse => Self::EmptyResponse,
+            ParseResponseError::NotAnObject { found } => Self::NotAnObject { found },
+            ParseResponseError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            ParseResponseError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
         }
     }
 }
@@ -220,6 +1413,11 @@ impl From<AuthenticationError> for Error {
         match value {
             AuthenticationError::SerdeJsonError(err) => Self::SerdeJsonError(err),
             AuthenticationError::UidParseError(err) => Self::UidParseError(err),
+            AuthenticationError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            AuthenticationError::TwoFactorRejected => Self::TwoFactorRejected,
+            AuthenticationError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
         }
     }
 }
@@ -230,6 +1428,49 @@ impl From<ClosureError> for Error {
             ClosureError::ClosureError(err) => Self::ClosureError(err),
             ClosureError::JsonRpcError(err) => Self::JsonRpcError(err),
             ClosureError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            ClosureError::DbListingDisabled => Self::DbListingDisabled,
+            ClosureError::InvalidSession { url } => Self::InvalidSession { url },
+            ClosureError::UnknownPreset(err) => Self::UnknownPreset(err),
+            ClosureError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            ClosureError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            ClosureError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            ClosureError::ProtectedDatabase { method, db } => {
+                Self::ProtectedDatabase { method, db }
+            }
+            ClosureError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            ClosureError::DatabaseManager { message } => Self::DatabaseManager { message },
+            ClosureError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            ClosureError::DatabaseSelector => Self::DatabaseSelector,
+            ClosureError::EmptyResponse => Self::EmptyResponse,
+            ClosureError::NotAnObject { found } => Self::NotAnObject { found },
+            ClosureError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            ClosureError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
+            ClosureError::BatchError {
+                processed,
+                failed,
+                source,
+            } => Self::BatchError {
+                processed,
+                failed,
+                source: Box::new((*source).into()),
+            },
+            ClosureError::DatabaseNotReady {
+                db,
+                elapsed,
+                source,
+            } => Self::DatabaseNotReady {
+                db,
+                elapsed,
+                source: source.map(|source| Box::new((*source).into())),
+            },
         }
     }
 }
@@ -239,27 +1480,247 @@ impl From<ClosureAuthError> for Error {
         match value {
             ClosureAuthError::ClosureError(err) => err.into(),
             ClosureAuthError::UidParseError(err) => Self::UidParseError(err),
+            ClosureAuthError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            ClosureAuthError::TwoFactorRejected => Self::TwoFactorRejected,
+            ClosureAuthError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
         }
     }
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<ReqwestError> for Error {
     fn from(value: ReqwestError) -> Self {
         match value {
             ReqwestError::ReqwestError(err) => Self::ReqwestError(err),
+            ReqwestError::Timeout { elapsed } => Self::Timeout { elapsed },
+            #[cfg(feature = "xmlrpc")]
+            ReqwestError::XmlRpcError(err) => Self::XmlRpcError(err),
             ReqwestError::JsonRpcError(err) => Self::JsonRpcError(err),
             ReqwestError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            ReqwestError::DbListingDisabled => Self::DbListingDisabled,
+            ReqwestError::InvalidSession { url } => Self::InvalidSession { url },
+            ReqwestError::UnknownPreset(err) => Self::UnknownPreset(err),
+            ReqwestError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            ReqwestError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            ReqwestError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            ReqwestError::ProtectedDatabase { method, db } => {
+                Self::ProtectedDatabase { method, db }
+            }
+            ReqwestError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            ReqwestError::DatabaseManager { message } => Self::DatabaseManager { message },
+            ReqwestError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            ReqwestError::DatabaseSelector => Self::DatabaseSelector,
+            ReqwestError::EmptyResponse => Self::EmptyResponse,
+            ReqwestError::NotAnObject { found } => Self::NotAnObject { found },
+            ReqwestError::ReportNotPdf {
+                status,
+                content_type,
+                snippet,
+            } => Self::ReportNotPdf {
+                status,
+                content_type,
+                snippet,
+            },
+            ReqwestError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            ReqwestError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
+            ReqwestError::BatchError {
+                processed,
+                failed,
+                source,
+            } => Self::BatchError {
+                processed,
+                failed,
+                source: Box::new((*source).into()),
+            },
+            ReqwestError::DatabaseNotReady {
+                db,
+                elapsed,
+                source,
+            } => Self::DatabaseNotReady {
+                db,
+                elapsed,
+                source: source.map(|source| Box::new((*source).into())),
+            },
         }
     }
 }
 
+#[cfg(any(feature = "async", feature = "blocking"))]
 impl From<ReqwestAuthError> for Error {
     fn from(value: ReqwestAuthError) -> Self {
         match value {
             ReqwestAuthError::ReqwestError(err) => err.into(),
             ReqwestAuthError::UidParseError(err) => Self::UidParseError(err),
+            ReqwestAuthError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            ReqwestAuthError::TwoFactorRejected => Self::TwoFactorRejected,
+            ReqwestAuthError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
+            ReqwestAuthError::LoginRejected { message } => Self::LoginRejected { message },
+        }
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<ureq::Error> for Error {
+    fn from(value: ureq::Error) -> Self {
+        Self::UreqError(Box::new(value))
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<UreqError> for Error {
+    fn from(value: UreqError) -> Self {
+        match value {
+            UreqError::UreqError(err) => Self::UreqError(err),
+            UreqError::JsonRpcError(err) => Self::JsonRpcError(err),
+            UreqError::SerdeJsonError(err) => Self::SerdeJsonError(err),
+            UreqError::DbListingDisabled => Self::DbListingDisabled,
+            UreqError::InvalidSession { url } => Self::InvalidSession { url },
+            UreqError::UnknownPreset(err) => Self::UnknownPreset(err),
+            UreqError::PasswordChangeRejected => Self::PasswordChangeRejected,
+            UreqError::PartnerMergeRejected { reason, message } => {
+                Self::PartnerMergeRejected { reason, message }
+            }
+            UreqError::MissingArgument { method, detail } => {
+                Self::MissingArgument { method, detail }
+            }
+            UreqError::ProtectedDatabase { method, db } => Self::ProtectedDatabase { method, db },
+            UreqError::LikelyKwargsMisuse { model, method } => {
+                Self::LikelyKwargsMisuse { model, method }
+            }
+            UreqError::DatabaseManager { message } => Self::DatabaseManager { message },
+            UreqError::MalformedResponse { reason } => Self::MalformedResponse { reason },
+            UreqError::DatabaseSelector => Self::DatabaseSelector,
+            UreqError::EmptyResponse => Self::EmptyResponse,
+            UreqError::NotAnObject { found } => Self::NotAnObject { found },
+            UreqError::ResponseTooLarge { limit } => Self::ResponseTooLarge { limit },
+            UreqError::UnexpectedResponseShape { context, detail } => {
+                Self::UnexpectedResponseShape { context, detail }
+            }
+            UreqError::BatchError {
+                processed,
+                failed,
+                source,
+            } => Self::BatchError {
+                processed,
+                failed,
+                source: Box::new((*source).into()),
+            },
+            UreqError::DatabaseNotReady {
+                db,
+                elapsed,
+                source,
+            } => Self::DatabaseNotReady {
+                db,
+                elapsed,
+                source: source.map(|source| Box::new((*source).into())),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl From<UreqAuthError> for Error {
+    fn from(value: UreqAuthError) -> Self {
+        match value {
+            UreqAuthError::UreqError(err) => err.into(),
+            UreqAuthError::UidParseError(err) => Self::UidParseError(err),
+            UreqAuthError::TwoFactorRequired { session_id } => {
+                Self::TwoFactorRequired { session_id }
+            }
+            UreqAuthError::TwoFactorRejected => Self::TwoFactorRejected,
+            UreqAuthError::MultipleDatabases(databases) => Self::MultipleDatabases(databases),
         }
     }
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+/// Format the `source` of a `DatabaseNotReady` error
+fn last_failure<E: std::fmt::Display>(source: &Option<Box<E>>) -> String {
+    match source {
+        Some(source) => format!(": {}", source),
+        None => " (the database doesn't exist)".into(),
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures;
+    use serde_json::{from_value, json};
+
+    /// A (trimmed) Odoo 17.0 `AccessError`
+    fn access_error() -> JsonRpcError {
+        from_value(fixtures::errors::access_error()["error"].clone()).unwrap()
+    }
+
+    /// Format the error and its sources, as `anyhow` does for `{:#}`
+    fn chain(err: &dyn std::error::Error) -> String {
+        let mut message = err.to_string();
+        let mut source = err.source();
+        while let Some(err) = source {
+            message.push_str(&format!(": {}", err));
+            source = err.source();
+        }
+        message
+    }
+
+    #[test]
+    fn server_error() {
+        let message = "Odoo Server Error (odoo.exceptions.AccessError): You are not allowed \
+                       to access 'Contact' (res.partner) records.";
+        let err = Error::from(ClosureError::from(access_error()));
+        assert_eq!(err.to_string(), message);
+        assert_eq!(chain(&err), message);
+        assert!(format!("{:?}", err).contains("Traceback (most recent call last)"));
+
+        assert!(err.is_server_error());
+        assert_eq!(
+            err.server_exception_name(),
+            Some("odoo.exceptions.AccessError")
+        );
+        assert_eq!(
+            err.server_message(),
+            Some("You are not allowed to access 'Contact' (res.partner) records.")
+        );
+        assert!(err
+            .server_traceback()
+            .unwrap()
+            .ends_with("(res.partner) records.\n"));
+
+        // the same, via the auth error
+        let err = ClosureAuthError::ClosureError(access_error().into());
+        assert_eq!(chain(&err), message);
+    }
+
+    #[test]
+    fn server_error_without_data() {
+        // e.g. a missing route
+        let err: JsonRpcError =
+            from_value(json!({"code": 404, "message": "404: Not Found"})).unwrap();
+        let err = Error::from(err);
+        assert_eq!(err.to_string(), "404: Not Found");
+        assert_eq!(err.server_exception_name(), None);
+        assert_eq!(err.server_message(), Some("404: Not Found"));
+        assert_eq!(err.server_traceback(), None);
+
+        let err: JsonRpcError = from_value(json!({"code": -32000})).unwrap();
+        assert_eq!(err.to_string(), "JSON-RPC Error -32000");
+
+        let err = Error::DbListingDisabled;
+        assert!(!err.is_server_error());
+        assert_eq!(err.server_message(), None);
+    }
+}
@@ -0,0 +1,52 @@
+//! The [`HealthReport`] returned by the `health_check()` client methods
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `health_check()` itself
+//! (as `send()` is either blocking or async), and builds the report here.
+
+use crate::service::common::VersionResponse;
+use std::time::Duration;
+
+/// The result of a `health_check()`
+///
+/// Each probe is recorded independently, so a failed probe doesn't hide the
+/// result of the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// Whether the server responded to `common.version` with a valid JSON-RPC
+    /// response
+    pub reachable: bool,
+
+    /// The server version (e.g., `16.0+e`), if the server was reachable
+    pub server_version: Option<String>,
+
+    /// Whether the database exists, if one was passed to `health_check()`
+    ///
+    /// This is `None` if no database was passed, or if the `db.db_exist`
+    /// request failed.
+    pub database_exists: Option<bool>,
+
+    /// The time taken by the `common.version` request
+    pub latency: Duration,
+}
+
+impl HealthReport {
+    /// Build the report from the individual probe results
+    pub(crate) fn new<E, F>(
+        version: Result<VersionResponse, E>,
+        database_exists: Option<Result<bool, F>>,
+        latency: Duration,
+    ) -> Self {
+        let server_version = version.ok().map(|version| version.server_version);
+        Self {
+            reachable: server_version.is_some(),
+            server_version,
+            database_exists: database_exists.and_then(Result::ok),
+            latency,
+        }
+    }
+
+    /// Check whether the server is reachable, and the database (if any) exists
+    pub fn is_healthy(&self) -> bool {
+        self.reachable && self.database_exists != Some(false)
+    }
+}
@@ -1,19 +1,50 @@
+use crate::client::access::{self, AccessMatrix};
+use crate::client::attachment::{self, Attachment};
+use crate::client::batch::{self, BatchProgress};
+use crate::client::body::check_size;
+use crate::client::button::MethodCallResult;
+use crate::client::db_wait::{self, DbWait, Probe};
 use crate::client::error::{ClosureAuthResult, ClosureError, ClosureResult};
+use crate::client::health::HealthReport;
+use crate::client::merge;
+use crate::client::metadata::ModelMetadata;
+use crate::client::metrics::MetricsTimer;
+use crate::client::odoo_client::single_database;
+use crate::client::page::Page;
+use crate::client::session::is_invalid_session;
+use crate::client::settings;
+use crate::client::stream::SearchReadStream;
+use crate::client::trace::RequestSpan;
+use crate::client::web_db::{self, BackupFormat, WebBody, WebResponse};
 use crate::client::{AuthState, Authed, NotAuthed, OdooClient, OdooRequest, RequestImpl};
-use crate::jsonrpc::JsonRpcParams;
+use crate::jsonrpc::{JsonRpcParams, OdooId, OdooIds};
+use crate::service::common::LoginResponse;
+use crate::service::db::CreateDatabase;
+use crate::service::orm::{NameGetResponse, UnlinkResponse, WriteResponse};
+use crate::OdooVersion;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{to_value, Value};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::fmt::Debug;
-use std::future::Future;
+use std::future::{Future, IntoFuture};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
 
 /// Convenience typedef. Use this as the return value for your async closure
 pub type ClosureReturn = Pin<Box<dyn Future<Output = ClosureResult<(String, Option<String>)>>>>;
-type Closure = Box<dyn Fn(String, Value, Option<String>) -> ClosureReturn>;
+type Closure = Box<dyn Fn(String, Value, Option<String>, Vec<(String, String)>) -> ClosureReturn>;
+/// Convenience typedef. Use this as the return value for your async web closure
+pub type WebClosureReturn = Pin<Box<dyn Future<Output = ClosureResult<WebResponse>>>>;
+type WebClosure = Box<dyn Fn(String, WebBody) -> WebClosureReturn>;
 
 /// **TODO:** Add an example closure for `reqwest` (and some other libs - `hyper`?)
 pub struct ClosureAsync {
     closure: Closure,
+
+    /// The closure for the `/web/database/*` form posts, if registered
+    web_closure: Option<WebClosure>,
 }
 impl RequestImpl for ClosureAsync {
     type Error = ClosureError;
@@ -29,11 +60,33 @@ impl OdooClient<NotAuthed, ClosureAsync> {
                 Option<String>,
             )
                 -> Pin<Box<dyn Future<Output = ClosureResult<(String, Option<String>)>>>>,
+    ) -> Self {
+        Self::new_closure_async_with_headers(url, move |url, data, session_id, _headers| {
+            closure(url, data, session_id)
+        })
+    }
+
+    /// Build a client using an async `closure`, which also receives the request headers
+    ///
+    /// This is the same as [`new_closure_async`](Self::new_closure_async), but
+    /// the closure is passed the headers to send as well (e.g. those set with
+    /// [`OdooRequest::with_header`], or the `User-Agent`).
+    pub fn new_closure_async_with_headers(
+        url: &str,
+        closure: impl 'static
+            + Fn(
+                String,
+                Value,
+                Option<String>,
+                Vec<(String, String)>,
+            )
+                -> Pin<Box<dyn Future<Output = ClosureResult<(String, Option<String>)>>>>,
     ) -> Self {
         Self::new(
             url,
             ClosureAsync {
                 closure: Box::new(closure),
+                web_closure: None,
             },
         )
     }
@@ -44,7 +97,7 @@ where
     S: AuthState,
 {
     pub async fn authenticate(
-        mut self,
+        self,
         db: &str,
         login: &str,
         password: &str,
@@ -53,6 +106,482 @@ where
         let (response, session_id) = request.send_internal().await?;
         Ok(self.parse_auth_response(db, login, password, response, session_id)?)
     }
+
+    /// Authenticate without knowing the database name
+    ///
+    /// The databases are listed with [`db_list`](crate::service::db::List) first.
+    /// If the server has a single database, it's used to [`authenticate()`](Self::authenticate);
+    /// if it has several, a `MultipleDatabases` error is returned. If listing is
+    /// disabled, the request is sent without a database, and the one Odoo picked
+    /// (e.g. with `dbfilter`) is read from the session info.
+    pub async fn authenticate_auto(
+        self,
+        login: &str,
+        password: &str,
+    ) -> ClosureAuthResult<OdooClient<Authed, ClosureAsync>> {
+        let databases = match self.db_list(false).send().await {
+            Ok(response) => response.databases,
+            Err(ClosureError::DbListingDisabled) => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(db) = single_database(databases)? {
+            return self.authenticate(&db, login, password).await;
+        }
+        let request = self.get_auth_request("", login, password);
+        let (response, session_id) = request.send_internal().await?;
+        Ok(self.parse_auth_auto_response(login, password, response, session_id)?)
+    }
+
+    /// Fetch the server version
+    ///
+    /// The version is fetched with [`common_version`](crate::service::common::Version)
+    /// on the first call, then cached on the client. If that fails (e.g. because
+    /// `/jsonrpc` is blocked), [`web_version_info`](crate::service::web::WebVersionInfo)
+    /// is tried instead.
+    pub async fn server_version(&self) -> ClosureResult<OdooVersion> {
+        if let Some(version) = self.version() {
+            return Ok(version);
+        }
+        let response = match self.get_version_request().send().await {
+            Ok(response) => response,
+            // some deployments block `/jsonrpc`, but the webclient still needs this
+            Err(err) => self.web_version_info().send().await.map_err(|_| err)?,
+        };
+        Ok(self.parse_version_response(response))
+    }
+
+    /// Check whether the server version is at least `major.minor`
+    ///
+    /// See [`OdooVersion::at_least`] for more info.
+    pub async fn version_at_least(&self, major: u32, minor: u32) -> ClosureResult<bool> {
+        Ok(self.server_version().await?.at_least(major, minor))
+    }
+
+    /// Probe the server, e.g. for a liveness/readiness check
+    ///
+    /// This sends a [`common_version`](crate::service::common::Version) request
+    /// (timed as the report `latency`), then a [`db_exist`](crate::service::db::DbExist)
+    /// request if `db` is passed. Failures are recorded in the [`HealthReport`]
+    /// rather than returned as an error.
+    pub async fn health_check(&self, db: Option<&str>) -> HealthReport {
+        let start = Instant::now();
+        let version = self.get_version_request().send().await;
+        let latency = start.elapsed();
+        let database_exists = match db {
+            Some(db) => Some(
+                self.db_exist(db)
+                    .send()
+                    .await
+                    .map(|response| response.exists),
+            ),
+            None => None,
+        };
+        HealthReport::new(version, database_exists, latency)
+    }
+
+    /// Create a new database, then wait until it's ready to use
+    ///
+    /// Odoo responds to [`db_create_database`](crate::service::db::CreateDatabase)
+    /// once the database is created, but the registry may take a while longer
+    /// to load (especially with large templates). This method then polls
+    /// [`db_exist`](crate::service::db::DbExist), followed by a
+    /// [`common_login`](crate::service::common::Login) with the new admin
+    /// credentials, every `wait.interval`. It returns once the login succeeds,
+    /// or with a [`ClosureError::DatabaseNotReady`] (holding the last failure) after
+    /// `wait.timeout`.
+    pub async fn db_create_database_and_wait(
+        &self,
+        create: CreateDatabase,
+        wait: DbWait,
+    ) -> ClosureResult<LoginResponse> {
+        self.get_create_database_request(&create).send().await?;
+        let create = &create;
+        db_wait::run_async(&create.db_name, wait, move || async move {
+            match self.db_exist(&create.db_name).send().await {
+                Ok(response) if !response.exists => return Probe::Missing,
+                Err(err) => return Probe::Failed(err),
+                Ok(_) => {}
+            }
+            match self.get_login_request(create).send().await {
+                Ok(response) => Probe::Ready(response),
+                Err(err) => Probe::Failed(err),
+            }
+        })
+        .await
+    }
+
+    /// Register the closure used by the `web_db_*()` methods
+    ///
+    /// The `/web/database/*` endpoints take form posts rather than JSON-RPC,
+    /// so they're sent via a separate closure, which receives the full URL and
+    /// the [`WebBody`] (see [`WebBody::encode`]). The `web_db_*()` methods
+    /// return an error if no closure is registered.
+    pub fn with_web_closure<F>(&mut self, closure: F) -> &mut Self
+    where
+        F: Fn(String, WebBody) -> WebClosureReturn + 'static,
+    {
+        self._impl.web_closure = Some(Box::new(closure));
+        self
+    }
+
+    /// Back up the database `name` via the `/web/database/backup` form
+    ///
+    /// This is useful when the JSON-RPC `db` service is blocked (otherwise, see
+    /// [`db_dump`](crate::service::db::Dump)). Returns the raw backup.
+    pub async fn web_db_backup(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        format: BackupFormat,
+    ) -> ClosureResult<Vec<u8>> {
+        let body = web_db::backup_body(master_pwd, name, format);
+        let response = self.web_db_post(web_db::BACKUP_ENDPOINT, body).await?;
+        Ok(web_db::parse_backup(response)?)
+    }
+
+    /// Restore the backup `file` as the database `name` via the
+    /// `/web/database/restore` form
+    ///
+    /// If `copy` is set, the database UUID is regenerated (i.e., the restored
+    /// database is treated as a copy). The client's [`DbGuard`](crate::client::DbGuard)
+    /// refuses protected names, unless `confirmed` is set and the guard
+    /// [requires confirmation](crate::client::DbGuard::require_confirmation)
+    /// (as with [`confirm_destructive`](crate::client::OdooRequest::confirm_destructive)).
+    pub async fn web_db_restore(
+        &self,
+        master_pwd: &str,
+        file: &[u8],
+        name: &str,
+        copy: bool,
+        confirmed: bool,
+    ) -> ClosureResult<()> {
+        if let Some(guard) = &self.db_guard {
+            guard.check_db("restore", name, confirmed)?;
+        }
+        let body = web_db::restore_body(master_pwd, file, name, copy);
+        let response = self.web_db_post(web_db::RESTORE_ENDPOINT, body).await?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Duplicate the database `name` as `new_name` via the
+    /// `/web/database/duplicate` form
+    pub async fn web_db_duplicate(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        new_name: &str,
+    ) -> ClosureResult<()> {
+        let body = web_db::duplicate_body(master_pwd, name, new_name);
+        let response = self.web_db_post(web_db::DUPLICATE_ENDPOINT, body).await?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Send a `/web/database/*` form post via the web closure
+    async fn web_db_post(&self, endpoint: &str, body: WebBody) -> ClosureResult<WebResponse> {
+        let closure = self._impl.web_closure.as_ref().ok_or_else(|| {
+            ClosureError::from(Box::<dyn std::error::Error>::from(
+                "no web closure registered (see `with_web_closure()`)",
+            ))
+        })?;
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait().await;
+        }
+        closure(self.build_endpoint(endpoint), body).await
+    }
+}
+
+impl OdooClient<Authed, ClosureAsync> {
+    /// Check whether the session is still valid, e.g. after `restore_session()`
+    ///
+    /// This sends a cheap `read` of the current user. If Odoo rejects the
+    /// credentials, `Ok(false)` is returned; any other error is returned as-is.
+    pub async fn verify_session(&self) -> ClosureResult<bool> {
+        let request = self.read("res.users", self.auth.uid, vec!["id".into()]);
+        match request.send().await {
+            Ok(_) => Ok(true),
+            Err(ClosureError::JsonRpcError(error)) if is_invalid_session(&error) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Point the client at a different server, keeping the session, and check it there
+    ///
+    /// This is for servers which share the session store (e.g. a failover node).
+    /// The session is checked with [`verify_session()`](Self::verify_session);
+    /// if it's rejected, the previous URL is restored and an `InvalidSession`
+    /// error is returned. The previous URL is restored on any other error too.
+    pub async fn switch_url_verified(&mut self, url: &str) -> ClosureResult<()> {
+        let previous = self.replace_url(url);
+        let result = match self.verify_session().await {
+            Ok(true) => return Ok(()),
+            Ok(false) => Err(ClosureError::InvalidSession {
+                url: self.url.clone(),
+            }),
+            Err(error) => Err(error),
+        };
+        self.url = previous;
+        result
+    }
+
+    /// Fetch the CRUD access rights of the current user on `model`
+    ///
+    /// This sends a [`check_access_rights`](OdooClient::check_access_rights)
+    /// request (without raising) for each operation, concurrently.
+    pub async fn access_matrix(&self, model: &str) -> ClosureResult<AccessMatrix> {
+        let requests = self.access_requests(model);
+        let results =
+            access::join_all(requests.into_iter().map(|request| request.send()).collect())
+                .await
+                .into_iter()
+                .map(|response| response.map(|response| response.ok))
+                .collect::<ClosureResult<Vec<_>>>()?;
+        Ok(AccessMatrix::from_results(&results))
+    }
+
+    /// Fetch the [`AccessMatrix`] for each of `models`, concurrently
+    ///
+    /// See [`access_matrix`](Self::access_matrix) for more info.
+    pub async fn access_matrices(
+        &self,
+        models: &[&str],
+    ) -> ClosureResult<HashMap<String, AccessMatrix>> {
+        let requests = models
+            .iter()
+            .flat_map(|model| self.access_requests(model))
+            .map(|request| request.send())
+            .collect();
+        let results = access::join_all(requests)
+            .await
+            .into_iter()
+            .map(|response| response.map(|response| response.ok))
+            .collect::<ClosureResult<Vec<_>>>()?;
+        Ok(models
+            .iter()
+            .zip(results.chunks(AccessMatrix::OPERATIONS.len()))
+            .map(|(model, results)| (model.to_string(), AccessMatrix::from_results(results)))
+            .collect())
+    }
+
+    /// Write `values` to `ids`, in chunks of `chunk_size`
+    ///
+    /// Each chunk is sent as a separate [`write`](OdooClient::write) request,
+    /// which avoids long-running transactions on the server. Sending stops at
+    /// the first failed chunk, and returns a [`ClosureError::BatchError`].
+    ///
+    /// `progress` is called with `(done, total)` after each chunk.
+    pub async fn write_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        values: Map<String, Value>,
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ClosureResult<WriteResponse> {
+        let ok = batch::run_async(ids, chunk_size, progress, |chunk| {
+            let request = self.write(model, chunk, values.clone());
+            async move { request.send().await.map(|response| response.ok) }
+        })
+        .await?;
+        Ok(WriteResponse { ok })
+    }
+
+    /// Delete `ids`, in chunks of `chunk_size`
+    ///
+    /// See [`write_batched`](Self::write_batched) for more info.
+    pub async fn unlink_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ClosureResult<UnlinkResponse> {
+        let ok = batch::run_async(ids, chunk_size, progress, |chunk| {
+            let request = self.unlink(model, chunk);
+            async move { request.send().await.map(|response| response.ok) }
+        })
+        .await?;
+        Ok(UnlinkResponse { ok })
+    }
+
+    /// Fetch the `display_name` for `ids`, falling back to `read` on Odoo 17.0+
+    ///
+    /// This sends a [`name_get`](OdooClient::name_get) request first. If the
+    /// server doesn't have `name_get` (it was removed in Odoo 17.0), the names
+    /// are fetched with [`display_names`](OdooClient::display_names) instead.
+    /// Any other error is returned as-is.
+    pub async fn name_get_with_fallback<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+    ) -> ClosureResult<NameGetResponse> {
+        let ids = ids.into();
+        match self.name_get(model, ids.clone()).send().await {
+            Err(ClosureError::JsonRpcError(error)) if error.is_missing_method("name_get") => {
+                self.display_names(model, ids).send().await
+            }
+            result => result,
+        }
+    }
+
+    /// Merge the partners `merge_ids` into `keep_id`, with Odoo's merge wizard
+    ///
+    /// This creates a `base.partner.merge.automatic.wizard`, then calls its
+    /// `action_merge` method. The references to the merged partners are moved to
+    /// `keep_id`, and the merged partners are deleted. Returns the surviving
+    /// partner id (i.e., `keep_id`).
+    ///
+    /// If the wizard refuses the merge (e.g., more than 3 partners, or partners
+    /// from different companies), this returns a `PartnerMergeRejected` error
+    /// with the [`MergeRejection`](crate::client::MergeRejection) reason.
+    pub async fn merge_partners<ID: Into<OdooIds>>(
+        &self,
+        keep_id: OdooId,
+        merge_ids: ID,
+    ) -> ClosureResult<OdooId> {
+        let values = merge::wizard_values(keep_id, merge_ids.into());
+        let response = self.create(merge::WIZARD_MODEL, values).send().await?;
+        let wizard_id = attachment::created_id(response);
+        self.execute_kw(
+            merge::WIZARD_MODEL,
+            merge::ACTION_MERGE,
+            vec![vec![wizard_id].into()],
+            Map::new(),
+        )
+        .send()
+        .await?;
+        Ok(keep_id)
+    }
+
+    /// Call the button `method` on `ids`, and sniff the result
+    ///
+    /// This sends an [`execute_kw`](OdooClient::execute_kw) request, as the
+    /// webclient does for `type="object"` buttons. Use [`MethodCallResult::is_action`]
+    /// to tell whether the server wants user interaction (e.g. a wizard).
+    pub async fn call_button<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        method: &str,
+    ) -> ClosureResult<MethodCallResult> {
+        let ids = ids.into().into_inner();
+        let response = self
+            .execute_kw(model, method, vec![ids.into()], Map::new())
+            .send()
+            .await?;
+        Ok(MethodCallResult::from_value(response.data))
+    }
+
+    /// Apply the configuration `values` (e.g. `group_use_lead`), as the settings "Save" button does
+    ///
+    /// This creates a `res.config.settings` record with the `values`, then
+    /// calls its `execute` method. Fields which aren't given keep their current
+    /// value.
+    pub async fn apply_settings(&self, values: Map<String, Value>) -> ClosureResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send().await?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id).send().await?;
+        Ok(())
+    }
+
+    /// Read the current configuration `fields` (e.g. `group_use_lead`)
+    ///
+    /// This sends a `default_get` request on `res.config.settings`, which
+    /// computes the values from the current config parameters, groups and
+    /// installed modules. Unknown fields are missing from the result.
+    pub async fn read_settings(&self, fields: Vec<String>) -> ClosureResult<Map<String, Value>> {
+        let response = self.settings_read_request(fields).send().await?;
+        Ok(settings::settings_values(response))
+    }
+
+    /// Upload `data` as an `ir.attachment` on the `model` record `res_id`
+    ///
+    /// The data is base64-encoded, and sent with a [`create`](OdooClient::create)
+    /// request. If `mimetype` is `None`, Odoo guesses it from the filename and
+    /// contents. Returns the new attachment id.
+    pub async fn attachment_upload(
+        &self,
+        model: &str,
+        res_id: OdooId,
+        filename: &str,
+        data: &[u8],
+        mimetype: Option<&str>,
+    ) -> ClosureResult<OdooId> {
+        let values = attachment::upload_values(model, res_id, filename, data, mimetype);
+        let response = self.create("ir.attachment", values).send().await?;
+        Ok(attachment::created_id(response))
+    }
+
+    /// Download the `ir.attachment` with id `attachment_id`
+    ///
+    /// The closure impls fetch the base64 `datas` field with a [`read`](OdooClient::read)
+    /// request, then decode it.
+    pub async fn attachment_download(&self, attachment_id: OdooId) -> ClosureResult<Attachment> {
+        let fields = attachment::read_fields(true);
+        let response = self
+            .read("ir.attachment", attachment_id, fields)
+            .send()
+            .await?;
+        Ok(Attachment::from_read(response, None)?)
+    }
+
+    /// Fetch page `page` of the `search_read` results for `domain`, with the total count
+    ///
+    /// This sends a [`search_count`](OdooClient::search_count) and a
+    /// [`search_read`](OdooClient::search_read) request, concurrently. Pages are
+    /// numbered from `0`, and a `page_size` of `0` is treated as `1`. If `order`
+    /// is `None`, the model's default order is used. See [`Page`].
+    pub async fn search_read_paged(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> ClosureResult<Page> {
+        let page_size = page_size.max(1);
+        let (count, read) = self.page_requests(model, domain, fields, page, page_size, order);
+        let (count, read) = access::join(count.send(), read.send()).await;
+        Ok(Page::new(read?.data, count?.count, page, page_size))
+    }
+
+    /// Fetch the field definitions of `model`, from the metadata cache if possible
+    ///
+    /// Without [`with_metadata_cache`](OdooClient::with_metadata_cache), the
+    /// fields are fetched on every call. See [`ModelMetadata`] for more info.
+    pub async fn model_metadata(&self, model: &str) -> ClosureResult<Arc<ModelMetadata>> {
+        match self.cached_metadata(model) {
+            Some(metadata) => Ok(metadata),
+            None => self.refresh_model_metadata(model).await,
+        }
+    }
+
+    /// Fetch the field definitions of `model`, skipping (and updating) the metadata cache
+    pub async fn refresh_model_metadata(&self, model: &str) -> ClosureResult<Arc<ModelMetadata>> {
+        let response = self.metadata_request(model).send().await?;
+        Ok(self.store_metadata(model, response))
+    }
+
+    /// Stream the `search_read` results for `domain`, in pages of `batch_size`
+    ///
+    /// The records are fetched in `id` order, with keyset pagination. See
+    /// [`SearchReadStream`] for more info.
+    pub fn search_read_stream(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        batch_size: u32,
+    ) -> SearchReadStream<'_, ClosureError> {
+        let model = model.to_string();
+        SearchReadStream::new(domain, batch_size, move |domain| {
+            let request = self
+                .search_read(&model, domain, fields.clone())
+                .limit(batch_size)
+                .order("id");
+            async move { request.send().await.map(|response| response.data) }
+        })
+    }
 }
 
 impl<'a, T> OdooRequest<'a, T, ClosureAsync>
@@ -64,14 +593,475 @@ where
         Ok(self.send_internal().await?.0)
     }
 
-    async fn send_internal(self) -> ClosureResult<(T::Response, Option<String>)> {
-        let data = to_value(&self.data)?;
+    /// Send the request, returning the raw `result` as a [`Value`]
+    ///
+    /// This skips the typed response, e.g. for debugging a customized server
+    /// which returns a different shape. Errors are returned as with `send()`.
+    pub async fn send_raw(self) -> ClosureResult<Value> {
+        Ok(self.send_internal().await?.0)
+    }
+
+    async fn send_internal<D: Debug + DeserializeOwned>(
+        self,
+    ) -> ClosureResult<(D, Option<String>)> {
+        let (url, headers, data) = self.prepare()?;
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait().await;
+        }
+        let span = RequestSpan::start(&url, &data);
+        let timer = MetricsTimer::start(&self.metrics_hooks, &url, &data);
         let (response, session_id) = (self._impl.closure)(
-            self.url.clone(),
+            url,
             data,
-            self.session_id.map(|s| s.to_string()),
+            self.session_id.as_deref().map(|s| s.to_string()),
+            headers,
         )
         .await?;
-        Ok((self.parse_response(&response)?, session_id))
+        check_size(self.max_response_size, response.len() as u64)?;
+        self.on_response(200, &response);
+        let result = self.parse_response(response.as_bytes());
+        span.finish(response.as_bytes(), &result);
+        timer.finish(200, response.as_bytes(), &result);
+        Ok((result?, session_id))
+    }
+}
+
+/// Requests can be `.await`ed directly, as a shortcut for `.send().await`
+///
+/// As with the closure, the future isn't `Send`.
+impl<'a, T> IntoFuture for OdooRequest<'a, T, ClosureAsync>
+where
+    T: JsonRpcParams + Debug + Serialize + 'a,
+    T::Container<T>: Debug + Serialize,
+{
+    type Output = ClosureResult<T::Response>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+// the paused clock requires the `tokio` timer
+#[cfg(all(test, feature = "async"))]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    #[test]
+    fn into_future() -> ClosureResult<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                |_url, data, _session_id| {
+                    Box::pin(async move {
+                        assert_eq!(data["params"]["args"][6], json!({"limit": 3}));
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": [1, 2, 3]}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            )
+            .authenticate_manual("some-database", "admin", 2, "password", None);
+
+            // without `.send()`
+            let ids = client.search("res.partner", jvec![]).limit(3).await?;
+            assert_eq!(ids.records, vec![1, 2, 3]);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn rate_limit() -> ClosureResult<()> {
+        use tokio::time::Instant;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let sent = Rc::new(RefCell::new(Vec::new()));
+            let inner = sent.clone();
+            let mut client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                move |_url, _data, _session_id| {
+                    inner.borrow_mut().push(Instant::now());
+                    Box::pin(async {
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": []}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            )
+            .authenticate_manual("some-database", "admin", 2, "password", None);
+            client.with_rate_limit(2, Duration::from_secs(1));
+
+            let start = Instant::now();
+            for _ in 0..5 {
+                client.search("res.partner", jvec![]).send().await?;
+            }
+
+            // a burst of 2, then one request every 500ms
+            let offsets: Vec<_> = sent
+                .borrow()
+                .iter()
+                .map(|sent| sent.duration_since(start).as_millis())
+                .collect();
+            assert_eq!(offsets, vec![0, 0, 500, 1000, 1500]);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn search_read_paged() -> ClosureResult<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let events = Rc::new(RefCell::new(Vec::new()));
+            let inner = events.clone();
+            let client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                move |_url, data, _session_id| {
+                    let args = &data["params"]["args"];
+                    let method = args[4].as_str().unwrap().to_string();
+                    let result = match method.as_str() {
+                        "search_count" => {
+                            assert_eq!(args[5], json!([[["active", "=", true]]]));
+                            json!(7)
+                        }
+                        "search_read" => {
+                            assert_eq!(args[6]["domain"], json!([["active", "=", true]]));
+                            assert_eq!(
+                                (&args[6]["offset"], &args[6]["limit"]),
+                                (&json!(3), &json!(3))
+                            );
+                            json!([{"id": 4}, {"id": 5}, {"id": 6}])
+                        }
+                        method => panic!("unexpected method: {:?}", method),
+                    };
+                    let events = inner.clone();
+                    events.borrow_mut().push(format!("start {}", method));
+                    Box::pin(async move {
+                        tokio::task::yield_now().await;
+                        events.borrow_mut().push(format!("end {}", method));
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            )
+            .authenticate_manual("some-database", "admin", 2, "password", None);
+
+            let page = client
+                .search_read_paged(
+                    "res.partner",
+                    jvec![["active", "=", true]],
+                    svec!["id"],
+                    1,
+                    3,
+                    None,
+                )
+                .await?;
+            assert_eq!(page.records.len(), 3);
+            assert_eq!((page.total, page.pages), (7, 3));
+            assert!(page.has_next());
+
+            // the requests were sent concurrently
+            assert_eq!(
+                *events.borrow(),
+                vec![
+                    "start search_count",
+                    "start search_read",
+                    "end search_count",
+                    "end search_read"
+                ]
+            );
+
+            Ok(())
+        })
+    }
+
+    fn create_database() -> CreateDatabase {
+        CreateDatabase {
+            passwd: "master-password".into(),
+            db_name: "new-database".into(),
+            demo: false,
+            lang: "en_US".into(),
+            user_password: "password".into(),
+            login: "admin".into(),
+            country_code: None,
+            phone: None,
+        }
+    }
+
+    #[test]
+    fn db_create_database_and_wait() -> ClosureResult<()> {
+        use tokio::time::Instant;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            // the database appears on the 2nd probe, and the login then succeeds
+            let sent = Rc::new(RefCell::new(Vec::new()));
+            let inner = sent.clone();
+            let client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                move |_url, data, _session_id| {
+                    let method = data["params"]["method"].as_str().unwrap().to_string();
+                    let mut sent = inner.borrow_mut();
+                    let probes = sent.iter().filter(|(m, _)| m == "db_exist").count();
+                    let result = match method.as_str() {
+                        "create_database" => json!(true),
+                        "db_exist" => json!(probes > 0),
+                        "login" => {
+                            assert_eq!(
+                                data["params"]["args"],
+                                json!(["new-database", "admin", "password"])
+                            );
+                            json!(2)
+                        }
+                        method => panic!("unexpected method: {:?}", method),
+                    };
+                    sent.push((method, Instant::now()));
+                    Box::pin(async move {
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            );
+
+            let wait = DbWait {
+                interval: Duration::from_secs(2),
+                timeout: Duration::from_secs(30),
+            };
+            let start = Instant::now();
+            let response = client
+                .db_create_database_and_wait(create_database(), wait)
+                .await?;
+            assert_eq!(response.uid, 2);
+
+            let sent: Vec<_> = sent
+                .borrow()
+                .iter()
+                .map(|(method, at)| (method.clone(), at.duration_since(start).as_secs()))
+                .collect();
+            assert_eq!(
+                sent,
+                vec![
+                    ("create_database".to_string(), 0),
+                    ("db_exist".to_string(), 0),
+                    ("db_exist".to_string(), 2),
+                    ("login".to_string(), 2),
+                ]
+            );
+
+            // the database never appears
+            let client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                |_url, data, _session_id| {
+                    let result = json!(data["params"]["method"] == "create_database");
+                    Box::pin(async move {
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            );
+            let err = client
+                .db_create_database_and_wait(create_database(), wait)
+                .await
+                .unwrap_err();
+            match err {
+                ClosureError::DatabaseNotReady {
+                    db,
+                    elapsed,
+                    source,
+                } => {
+                    assert_eq!(db, "new-database");
+                    assert_eq!(elapsed, Duration::from_secs(30));
+                    assert!(source.is_none());
+                }
+                err => panic!("unexpected error: {:?}", err),
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn access_matrix() -> ClosureResult<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            // each response yields once, so the requests only overlap if
+            // they're sent concurrently
+            let events = Rc::new(RefCell::new(Vec::new()));
+            let inner = events.clone();
+            let client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                move |_url, data, _session_id| {
+                    let args = &data["params"]["args"];
+                    let request = format!("{}.{}", args[3].as_str().unwrap(), args[5][0]);
+                    let result = args[5][0] != "unlink";
+                    inner.borrow_mut().push(format!("sent {}", request));
+                    let events = inner.clone();
+                    Box::pin(async move {
+                        tokio::task::yield_now().await;
+                        events.borrow_mut().push(format!("done {}", request));
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            )
+            .authenticate_manual("some-database", "admin", 2, "password", None);
+
+            let matrix = client.access_matrix("res.partner").await?;
+            assert_eq!(
+                matrix,
+                AccessMatrix {
+                    create: true,
+                    read: true,
+                    write: true,
+                    unlink: false,
+                }
+            );
+            let sent: Vec<_> = events.borrow()[..4].to_vec();
+            assert_eq!(
+                sent,
+                vec![
+                    r#"sent res.partner."create""#,
+                    r#"sent res.partner."read""#,
+                    r#"sent res.partner."write""#,
+                    r#"sent res.partner."unlink""#,
+                ]
+            );
+
+            events.borrow_mut().clear();
+            let matrices = client
+                .access_matrices(&["res.partner", "res.users"])
+                .await?;
+            assert_eq!(matrices["res.partner"], matrix);
+            assert_eq!(matrices["res.users"], matrix);
+            let events = events.borrow();
+            assert_eq!(events.len(), 16);
+            assert!(events[..8].iter().all(|event| event.starts_with("sent")));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn search_read_stream() -> ClosureResult<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            // three pages: two full, and one short
+            let sent = Rc::new(RefCell::new(Vec::new()));
+            let inner = sent.clone();
+            let client = OdooClient::new_closure_async(
+                "http://localhost:8069",
+                move |_url, data, _session_id| {
+                    let mut sent = inner.borrow_mut();
+                    let result = match sent.len() % 3 {
+                        0 => json!([{"id": 1}, {"id": 2}]),
+                        1 => json!([{"id": 3}, {"id": 4}]),
+                        _ => json!([{"id": 5}]),
+                    };
+                    sent.push(data["params"]["args"][6].clone());
+                    Box::pin(async move {
+                        Ok((
+                            json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                            None,
+                        ))
+                    })
+                },
+            )
+            .authenticate_manual("some-database", "admin", 2, "password", None);
+
+            let mut stream = client.search_read_stream(
+                "res.partner",
+                jvec![["active", "=", true]],
+                svec!["name"],
+                2,
+            );
+            let mut pages = Vec::new();
+            while let Some(batch) = stream.next().await? {
+                pages.push(
+                    batch
+                        .iter()
+                        .map(|record| record["id"].clone())
+                        .collect::<Vec<_>>(),
+                );
+            }
+            assert_eq!(
+                pages,
+                vec![
+                    vec![json!(1), json!(2)],
+                    vec![json!(3), json!(4)],
+                    vec![json!(5)]
+                ]
+            );
+
+            // each page continues from the last id
+            let domains: Vec<_> = sent
+                .borrow()
+                .iter()
+                .map(|kwargs| kwargs["domain"].clone())
+                .collect();
+            assert_eq!(
+                domains,
+                vec![
+                    json!([["active", "=", true]]),
+                    json!([["active", "=", true], ["id", ">", 2]]),
+                    json!([["active", "=", true], ["id", ">", 4]]),
+                ]
+            );
+            for kwargs in sent.borrow().iter() {
+                assert_eq!(kwargs["limit"], json!(2));
+                assert_eq!(kwargs["order"], json!("id"));
+                assert_eq!(kwargs["fields"], json!(["name"]));
+            }
+
+            // or as individual records
+            let mut records = client
+                .search_read_stream("res.partner", jvec![], svec!["name"], 2)
+                .records();
+            let mut ids = Vec::new();
+            while let Some(record) = records.next().await? {
+                ids.push(record["id"].clone());
+            }
+            assert_eq!(ids, vec![json!(1), json!(2), json!(3), json!(4), json!(5)]);
+
+            Ok(())
+        })
     }
 }
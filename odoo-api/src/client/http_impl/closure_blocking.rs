@@ -1,17 +1,46 @@
+use crate::client::access::AccessMatrix;
+use crate::client::attachment::{self, Attachment};
+use crate::client::batch::{self, BatchProgress};
+use crate::client::body::check_size;
+use crate::client::button::MethodCallResult;
+use crate::client::db_wait::{self, DbWait, Probe};
 use crate::client::error::{ClosureAuthResult, ClosureError, ClosureResult};
+use crate::client::health::HealthReport;
+use crate::client::merge;
+use crate::client::metadata::ModelMetadata;
+use crate::client::metrics::MetricsTimer;
+use crate::client::odoo_client::single_database;
+use crate::client::page::Page;
+use crate::client::session::is_invalid_session;
+use crate::client::settings;
+use crate::client::stream::SearchReadIter;
+use crate::client::trace::RequestSpan;
+use crate::client::web_db::{self, BackupFormat, WebBody, WebResponse};
 use crate::client::{AuthState, Authed, NotAuthed, OdooClient, OdooRequest, RequestImpl};
-use crate::jsonrpc::JsonRpcParams;
+use crate::jsonrpc::{JsonRpcParams, OdooId, OdooIds};
+use crate::service::common::LoginResponse;
+use crate::service::db::CreateDatabase;
+use crate::service::orm::{NameGetResponse, UnlinkResponse, WriteResponse};
+use crate::OdooVersion;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{to_value, Value};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Instant;
 
 /// Convenience typedef. Use this as the return value for your blocking closure
 pub type ClosureReturn = ClosureResult<(String, Option<String>)>;
-type Closure = Box<dyn Fn(&str, Value, Option<&str>) -> ClosureReturn>;
+type Closure = Box<dyn Fn(&str, Value, Option<&str>, &[(String, String)]) -> ClosureReturn>;
+type WebClosure = Box<dyn Fn(&str, WebBody) -> ClosureResult<WebResponse>>;
 
 /// **TODO:** Add an example closure for `reqwest` (and some other libs - `hyper`?)
 pub struct ClosureBlocking {
     closure: Closure,
+
+    /// The closure for the `/web/database/*` form posts, if registered
+    web_closure: Option<WebClosure>,
 }
 impl RequestImpl for ClosureBlocking {
     type Error = ClosureError;
@@ -23,11 +52,34 @@ impl OdooClient<NotAuthed, ClosureBlocking> {
     >(
         url: &str,
         closure: F,
+    ) -> Self {
+        Self::new_closure_blocking_with_headers(url, move |url, data, session_id, _headers| {
+            closure(url, data, session_id)
+        })
+    }
+
+    /// Build a client using a blocking `closure`, which also receives the request headers
+    ///
+    /// This is the same as [`new_closure_blocking`](Self::new_closure_blocking),
+    /// but the closure is passed the headers to send as well (e.g. those set with
+    /// [`OdooRequest::with_header`], or the `User-Agent`).
+    pub fn new_closure_blocking_with_headers<
+        F: Fn(
+                &str,
+                Value,
+                Option<&str>,
+                &[(String, String)],
+            ) -> ClosureResult<(String, Option<String>)>
+            + 'static,
+    >(
+        url: &str,
+        closure: F,
     ) -> Self {
         Self::new(
             url,
             ClosureBlocking {
                 closure: Box::new(closure),
+                web_closure: None,
             },
         )
     }
@@ -38,7 +90,7 @@ where
     S: AuthState,
 {
     pub fn authenticate(
-        mut self,
+        self,
         db: &str,
         login: &str,
         password: &str,
@@ -47,6 +99,449 @@ where
         let (response, session_id) = request.send_internal()?;
         Ok(self.parse_auth_response(db, login, password, response, session_id)?)
     }
+
+    /// Authenticate without knowing the database name
+    ///
+    /// The databases are listed with [`db_list`](crate::service::db::List) first.
+    /// If the server has a single database, it's used to [`authenticate()`](Self::authenticate);
+    /// if it has several, a `MultipleDatabases` error is returned. If listing is
+    /// disabled, the request is sent without a database, and the one Odoo picked
+    /// (e.g. with `dbfilter`) is read from the session info.
+    pub fn authenticate_auto(
+        self,
+        login: &str,
+        password: &str,
+    ) -> ClosureAuthResult<OdooClient<Authed, ClosureBlocking>> {
+        let databases = match self.db_list(false).send() {
+            Ok(response) => response.databases,
+            Err(ClosureError::DbListingDisabled) => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(db) = single_database(databases)? {
+            return self.authenticate(&db, login, password);
+        }
+        let request = self.get_auth_request("", login, password);
+        let (response, session_id) = request.send_internal()?;
+        Ok(self.parse_auth_auto_response(login, password, response, session_id)?)
+    }
+
+    /// Fetch the server version
+    ///
+    /// The version is fetched with [`common_version`](crate::service::common::Version)
+    /// on the first call, then cached on the client. If that fails (e.g. because
+    /// `/jsonrpc` is blocked), [`web_version_info`](crate::service::web::WebVersionInfo)
+    /// is tried instead.
+    pub fn server_version(&self) -> ClosureResult<OdooVersion> {
+        if let Some(version) = self.version() {
+            return Ok(version);
+        }
+        let response = match self.get_version_request().send() {
+            Ok(response) => response,
+            // some deployments block `/jsonrpc`, but the webclient still needs this
+            Err(err) => self.web_version_info().send().map_err(|_| err)?,
+        };
+        Ok(self.parse_version_response(response))
+    }
+
+    /// Check whether the server version is at least `major.minor`
+    ///
+    /// See [`OdooVersion::at_least`] for more info.
+    pub fn version_at_least(&self, major: u32, minor: u32) -> ClosureResult<bool> {
+        Ok(self.server_version()?.at_least(major, minor))
+    }
+
+    /// Probe the server, e.g. for a liveness/readiness check
+    ///
+    /// This sends a [`common_version`](crate::service::common::Version) request
+    /// (timed as the report `latency`), then a [`db_exist`](crate::service::db::DbExist)
+    /// request if `db` is passed. Failures are recorded in the [`HealthReport`]
+    /// rather than returned as an error.
+    pub fn health_check(&self, db: Option<&str>) -> HealthReport {
+        let start = Instant::now();
+        let version = self.get_version_request().send();
+        let latency = start.elapsed();
+        let database_exists = db.map(|db| self.db_exist(db).send().map(|response| response.exists));
+        HealthReport::new(version, database_exists, latency)
+    }
+
+    /// Create a new database, then wait until it's ready to use
+    ///
+    /// Odoo responds to [`db_create_database`](crate::service::db::CreateDatabase)
+    /// once the database is created, but the registry may take a while longer
+    /// to load (especially with large templates). This method then polls
+    /// [`db_exist`](crate::service::db::DbExist), followed by a
+    /// [`common_login`](crate::service::common::Login) with the new admin
+    /// credentials, every `wait.interval`. It returns once the login succeeds,
+    /// or with a [`ClosureError::DatabaseNotReady`] (holding the last failure) after
+    /// `wait.timeout`.
+    pub fn db_create_database_and_wait(
+        &self,
+        create: CreateDatabase,
+        wait: DbWait,
+    ) -> ClosureResult<LoginResponse> {
+        self.get_create_database_request(&create).send()?;
+        db_wait::run_blocking(&create.db_name, wait, || {
+            match self.db_exist(&create.db_name).send() {
+                Ok(response) if !response.exists => return Probe::Missing,
+                Err(err) => return Probe::Failed(err),
+                Ok(_) => {}
+            }
+            match self.get_login_request(&create).send() {
+                Ok(response) => Probe::Ready(response),
+                Err(err) => Probe::Failed(err),
+            }
+        })
+    }
+
+    /// Register the closure used by the `web_db_*()` methods
+    ///
+    /// The `/web/database/*` endpoints take form posts rather than JSON-RPC,
+    /// so they're sent via a separate closure, which receives the full URL and
+    /// the [`WebBody`] (see [`WebBody::encode`]). The `web_db_*()` methods
+    /// return an error if no closure is registered.
+    pub fn with_web_closure<F>(&mut self, closure: F) -> &mut Self
+    where
+        F: Fn(&str, WebBody) -> ClosureResult<WebResponse> + 'static,
+    {
+        self._impl.web_closure = Some(Box::new(closure));
+        self
+    }
+
+    /// Back up the database `name` via the `/web/database/backup` form
+    ///
+    /// This is useful when the JSON-RPC `db` service is blocked (otherwise, see
+    /// [`db_dump`](crate::service::db::Dump)). Returns the raw backup.
+    pub fn web_db_backup(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        format: BackupFormat,
+    ) -> ClosureResult<Vec<u8>> {
+        let body = web_db::backup_body(master_pwd, name, format);
+        let response = self.web_db_post(web_db::BACKUP_ENDPOINT, body)?;
+        Ok(web_db::parse_backup(response)?)
+    }
+
+    /// Restore the backup `file` as the database `name` via the
+    /// `/web/database/restore` form
+    ///
+    /// If `copy` is set, the database UUID is regenerated (i.e., the restored
+    /// database is treated as a copy). The client's [`DbGuard`](crate::client::DbGuard)
+    /// refuses protected names, unless `confirmed` is set and the guard
+    /// [requires confirmation](crate::client::DbGuard::require_confirmation)
+    /// (as with [`confirm_destructive`](crate::client::OdooRequest::confirm_destructive)).
+    pub fn web_db_restore(
+        &self,
+        master_pwd: &str,
+        file: &[u8],
+        name: &str,
+        copy: bool,
+        confirmed: bool,
+    ) -> ClosureResult<()> {
+        if let Some(guard) = &self.db_guard {
+            guard.check_db("restore", name, confirmed)?;
+        }
+        let body = web_db::restore_body(master_pwd, file, name, copy);
+        let response = self.web_db_post(web_db::RESTORE_ENDPOINT, body)?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Duplicate the database `name` as `new_name` via the
+    /// `/web/database/duplicate` form
+    pub fn web_db_duplicate(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        new_name: &str,
+    ) -> ClosureResult<()> {
+        let body = web_db::duplicate_body(master_pwd, name, new_name);
+        let response = self.web_db_post(web_db::DUPLICATE_ENDPOINT, body)?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Send a `/web/database/*` form post via the web closure
+    fn web_db_post(&self, endpoint: &str, body: WebBody) -> ClosureResult<WebResponse> {
+        let closure = self._impl.web_closure.as_ref().ok_or_else(|| {
+            ClosureError::from(Box::<dyn std::error::Error>::from(
+                "no web closure registered (see `with_web_closure()`)",
+            ))
+        })?;
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        closure(&self.build_endpoint(endpoint), body)
+    }
+}
+
+impl OdooClient<Authed, ClosureBlocking> {
+    /// Check whether the session is still valid, e.g. after `restore_session()`
+    ///
+    /// This sends a cheap `read` of the current user. If Odoo rejects the
+    /// credentials, `Ok(false)` is returned; any other error is returned as-is.
+    pub fn verify_session(&self) -> ClosureResult<bool> {
+        let request = self.read("res.users", self.auth.uid, vec!["id".into()]);
+        match request.send() {
+            Ok(_) => Ok(true),
+            Err(ClosureError::JsonRpcError(error)) if is_invalid_session(&error) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Point the client at a different server, keeping the session, and check it there
+    ///
+    /// This is for servers which share the session store (e.g. a failover node).
+    /// The session is checked with [`verify_session()`](Self::verify_session);
+    /// if it's rejected, the previous URL is restored and an `InvalidSession`
+    /// error is returned. The previous URL is restored on any other error too.
+    pub fn switch_url_verified(&mut self, url: &str) -> ClosureResult<()> {
+        let previous = self.replace_url(url);
+        let result = match self.verify_session() {
+            Ok(true) => return Ok(()),
+            Ok(false) => Err(ClosureError::InvalidSession {
+                url: self.url.clone(),
+            }),
+            Err(error) => Err(error),
+        };
+        self.url = previous;
+        result
+    }
+
+    /// Fetch the CRUD access rights of the current user on `model`
+    ///
+    /// This sends a [`check_access_rights`](OdooClient::check_access_rights)
+    /// request (without raising) for each operation, in turn.
+    pub fn access_matrix(&self, model: &str) -> ClosureResult<AccessMatrix> {
+        let results = self
+            .access_requests(model)
+            .into_iter()
+            .map(|request| request.send().map(|response| response.ok))
+            .collect::<ClosureResult<Vec<_>>>()?;
+        Ok(AccessMatrix::from_results(&results))
+    }
+
+    /// Fetch the [`AccessMatrix`] for each of `models`
+    ///
+    /// See [`access_matrix`](Self::access_matrix) for more info.
+    pub fn access_matrices(&self, models: &[&str]) -> ClosureResult<HashMap<String, AccessMatrix>> {
+        models
+            .iter()
+            .map(|model| Ok((model.to_string(), self.access_matrix(model)?)))
+            .collect()
+    }
+
+    /// Write `values` to `ids`, in chunks of `chunk_size`
+    ///
+    /// Each chunk is sent as a separate [`write`](OdooClient::write) request,
+    /// which avoids long-running transactions on the server. Sending stops at
+    /// the first failed chunk, and returns a [`ClosureError::BatchError`].
+    ///
+    /// `progress` is called with `(done, total)` after each chunk.
+    pub fn write_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        values: Map<String, Value>,
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ClosureResult<WriteResponse> {
+        let ok = batch::run_blocking(ids, chunk_size, progress, |chunk| {
+            self.write(model, chunk, values.clone())
+                .send()
+                .map(|response| response.ok)
+        })?;
+        Ok(WriteResponse { ok })
+    }
+
+    /// Delete `ids`, in chunks of `chunk_size`
+    ///
+    /// See [`write_batched`](Self::write_batched) for more info.
+    pub fn unlink_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ClosureResult<UnlinkResponse> {
+        let ok = batch::run_blocking(ids, chunk_size, progress, |chunk| {
+            self.unlink(model, chunk).send().map(|response| response.ok)
+        })?;
+        Ok(UnlinkResponse { ok })
+    }
+
+    /// Fetch the `display_name` for `ids`, falling back to `read` on Odoo 17.0+
+    ///
+    /// This sends a [`name_get`](OdooClient::name_get) request first. If the
+    /// server doesn't have `name_get` (it was removed in Odoo 17.0), the names
+    /// are fetched with [`display_names`](OdooClient::display_names) instead.
+    /// Any other error is returned as-is.
+    pub fn name_get_with_fallback<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+    ) -> ClosureResult<NameGetResponse> {
+        let ids = ids.into();
+        match self.name_get(model, ids.clone()).send() {
+            Err(ClosureError::JsonRpcError(error)) if error.is_missing_method("name_get") => {
+                self.display_names(model, ids).send()
+            }
+            result => result,
+        }
+    }
+
+    /// Merge the partners `merge_ids` into `keep_id`, with Odoo's merge wizard
+    ///
+    /// This creates a `base.partner.merge.automatic.wizard`, then calls its
+    /// `action_merge` method. The references to the merged partners are moved to
+    /// `keep_id`, and the merged partners are deleted. Returns the surviving
+    /// partner id (i.e., `keep_id`).
+    ///
+    /// If the wizard refuses the merge (e.g., more than 3 partners, or partners
+    /// from different companies), this returns a `PartnerMergeRejected` error
+    /// with the [`MergeRejection`](crate::client::MergeRejection) reason.
+    pub fn merge_partners<ID: Into<OdooIds>>(
+        &self,
+        keep_id: OdooId,
+        merge_ids: ID,
+    ) -> ClosureResult<OdooId> {
+        let values = merge::wizard_values(keep_id, merge_ids.into());
+        let response = self.create(merge::WIZARD_MODEL, values).send()?;
+        let wizard_id = attachment::created_id(response);
+        self.execute_kw(
+            merge::WIZARD_MODEL,
+            merge::ACTION_MERGE,
+            vec![vec![wizard_id].into()],
+            Map::new(),
+        )
+        .send()?;
+        Ok(keep_id)
+    }
+
+    /// Call the button `method` on `ids`, and sniff the result
+    ///
+    /// This sends an [`execute_kw`](OdooClient::execute_kw) request, as the
+    /// webclient does for `type="object"` buttons. Use [`MethodCallResult::is_action`]
+    /// to tell whether the server wants user interaction (e.g. a wizard).
+    pub fn call_button<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        method: &str,
+    ) -> ClosureResult<MethodCallResult> {
+        let ids = ids.into().into_inner();
+        let response = self
+            .execute_kw(model, method, vec![ids.into()], Map::new())
+            .send()?;
+        Ok(MethodCallResult::from_value(response.data))
+    }
+
+    /// Apply the configuration `values` (e.g. `group_use_lead`), as the settings "Save" button does
+    ///
+    /// This creates a `res.config.settings` record with the `values`, then
+    /// calls its `execute` method. Fields which aren't given keep their current
+    /// value.
+    pub fn apply_settings(&self, values: Map<String, Value>) -> ClosureResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send()?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id).send()?;
+        Ok(())
+    }
+
+    /// Read the current configuration `fields` (e.g. `group_use_lead`)
+    ///
+    /// This sends a `default_get` request on `res.config.settings`, which
+    /// computes the values from the current config parameters, groups and
+    /// installed modules. Unknown fields are missing from the result.
+    pub fn read_settings(&self, fields: Vec<String>) -> ClosureResult<Map<String, Value>> {
+        let response = self.settings_read_request(fields).send()?;
+        Ok(settings::settings_values(response))
+    }
+
+    /// Upload `data` as an `ir.attachment` on the `model` record `res_id`
+    ///
+    /// The data is base64-encoded, and sent with a [`create`](OdooClient::create)
+    /// request. If `mimetype` is `None`, Odoo guesses it from the filename and
+    /// contents. Returns the new attachment id.
+    pub fn attachment_upload(
+        &self,
+        model: &str,
+        res_id: OdooId,
+        filename: &str,
+        data: &[u8],
+        mimetype: Option<&str>,
+    ) -> ClosureResult<OdooId> {
+        let values = attachment::upload_values(model, res_id, filename, data, mimetype);
+        let response = self.create("ir.attachment", values).send()?;
+        Ok(attachment::created_id(response))
+    }
+
+    /// Download the `ir.attachment` with id `attachment_id`
+    ///
+    /// The closure impls fetch the base64 `datas` field with a [`read`](OdooClient::read)
+    /// request, then decode it.
+    pub fn attachment_download(&self, attachment_id: OdooId) -> ClosureResult<Attachment> {
+        let fields = attachment::read_fields(true);
+        let response = self.read("ir.attachment", attachment_id, fields).send()?;
+        Ok(Attachment::from_read(response, None)?)
+    }
+
+    /// Fetch page `page` of the `search_read` results for `domain`, with the total count
+    ///
+    /// This sends a [`search_count`](OdooClient::search_count), then a
+    /// [`search_read`](OdooClient::search_read) request. Pages are numbered
+    /// from `0`, and a `page_size` of `0` is treated as `1`. If `order` is
+    /// `None`, the model's default order is used. See [`Page`].
+    pub fn search_read_paged(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> ClosureResult<Page> {
+        let page_size = page_size.max(1);
+        let (count, read) = self.page_requests(model, domain, fields, page, page_size, order);
+        let total = count.send()?.count;
+        Ok(Page::new(read.send()?.data, total, page, page_size))
+    }
+
+    /// Fetch the field definitions of `model`, from the metadata cache if possible
+    ///
+    /// Without [`with_metadata_cache`](OdooClient::with_metadata_cache), the
+    /// fields are fetched on every call. See [`ModelMetadata`] for more info.
+    pub fn model_metadata(&self, model: &str) -> ClosureResult<Arc<ModelMetadata>> {
+        match self.cached_metadata(model) {
+            Some(metadata) => Ok(metadata),
+            None => self.refresh_model_metadata(model),
+        }
+    }
+
+    /// Fetch the field definitions of `model`, skipping (and updating) the metadata cache
+    pub fn refresh_model_metadata(&self, model: &str) -> ClosureResult<Arc<ModelMetadata>> {
+        let response = self.metadata_request(model).send()?;
+        Ok(self.store_metadata(model, response))
+    }
+
+    /// Iterate over the `search_read` results for `domain`, in pages of `batch_size`
+    ///
+    /// The records are fetched in `id` order, with keyset pagination. See
+    /// [`SearchReadIter`] for more info.
+    pub fn search_read_iter(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        batch_size: u32,
+    ) -> SearchReadIter<'_, ClosureError> {
+        let model = model.to_string();
+        SearchReadIter::new(domain, batch_size, move |domain| {
+            self.search_read(&model, domain, fields.clone())
+                .limit(batch_size)
+                .order("id")
+                .send()
+                .map(|response| response.data)
+        })
+    }
 }
 
 impl<'a, T> OdooRequest<'a, T, ClosureBlocking>
@@ -58,9 +553,1093 @@ where
         Ok(self.send_internal()?.0)
     }
 
-    fn send_internal(self) -> ClosureResult<(T::Response, Option<String>)> {
-        let data = to_value(&self.data)?;
-        let (response, session_id) = self._impl.closure.as_ref()(&self.url, data, self.session_id)?;
-        Ok((self.parse_response(&response)?, session_id))
+    /// Send the request, returning the raw `result` as a [`Value`]
+    ///
+    /// This skips the typed response, e.g. for debugging a customized server
+    /// which returns a different shape. Errors are returned as with `send()`.
+    pub fn send_raw(self) -> ClosureResult<Value> {
+        Ok(self.send_internal()?.0)
+    }
+
+    fn send_internal<D: Debug + DeserializeOwned>(self) -> ClosureResult<(D, Option<String>)> {
+        let (url, headers, data) = self.prepare()?;
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let span = RequestSpan::start(&url, &data);
+        let timer = MetricsTimer::start(&self.metrics_hooks, &url, &data);
+        let (response, session_id) =
+            self._impl.closure.as_ref()(&url, data, self.session_id.as_deref(), &headers)?;
+        check_size(self.max_response_size, response.len() as u64)?;
+        self.on_response(200, &response);
+        let result = self.parse_response(response.as_bytes());
+        span.finish(response.as_bytes(), &result);
+        timer.finish(200, response.as_bytes(), &result);
+        Ok((result?, session_id))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::error::ClosureAuthError;
+    use crate::client::MergeRejection;
+    use crate::fixtures;
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Mutex;
+
+    /// Build a client which answers `db.list` with `databases` (or `AccessDenied`
+    /// if `None`), and records the `db` param of each authenticate request
+    fn auto_client(
+        databases: Option<Value>,
+    ) -> (
+        OdooClient<NotAuthed, ClosureBlocking>,
+        Rc<RefCell<Vec<Value>>>,
+    ) {
+        let captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |url, data, _session_id| {
+                let response = if url.ends_with("/web/session/authenticate") {
+                    let db = data["params"]["db"].clone();
+                    inner.borrow_mut().push(db.clone());
+                    let db = if db.is_null() { json!("acme") } else { db };
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": 2, "db": db}})
+                } else if let Some(databases) = &databases {
+                    json!({"jsonrpc": "2.0", "id": 1, "result": databases})
+                } else {
+                    fixtures::errors::access_denied()
+                };
+                Ok((response.to_string(), Some("session".into())))
+            },
+        );
+        (client, captured)
+    }
+
+    /// Build a client which records the `ids` arg of each request, and fails
+    /// the request at index `fail_at` (if set)
+    fn batch_client(
+        fail_at: Option<usize>,
+    ) -> (OdooClient<Authed, ClosureBlocking>, Rc<RefCell<Vec<Value>>>) {
+        let captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let mut captured = inner.borrow_mut();
+                captured.push(data["params"]["args"][5][0].clone());
+                let response = if Some(captured.len() - 1) == fail_at {
+                    fixtures::errors::validation_error()
+                } else {
+                    json!({"jsonrpc": "2.0", "id": 1, "result": true})
+                };
+                Ok((response.to_string(), None))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        (client, captured)
+    }
+
+    #[test]
+    fn authenticate_two_factor() {
+        // Odoo's response when the user has two-factor authentication enabled
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            |_url, _data, _session_id| {
+                let response = json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": null}});
+                Ok((response.to_string(), Some("partial".into())))
+            },
+        );
+
+        match client.authenticate("some-database", "admin", "password") {
+            Err(ClosureAuthError::TwoFactorRequired { session_id }) => {
+                assert_eq!(session_id.as_deref(), Some("partial"))
+            }
+            Err(err) => panic!("unexpected error: {:?}", err),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    #[test]
+    fn access_matrix() -> ClosureResult<()> {
+        // only `read` is allowed
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                let operation = args[5][0].as_str().unwrap_or_default().to_string();
+                let result = operation == "read";
+                inner
+                    .borrow_mut()
+                    .push((args[3].clone(), operation, args[6].clone()));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        let matrix = client.access_matrix("res.partner")?;
+        assert_eq!(
+            matrix,
+            AccessMatrix {
+                create: false,
+                read: true,
+                write: false,
+                unlink: false,
+            }
+        );
+        let raise = json!({"raise_exception": false});
+        assert_eq!(
+            *sent.borrow(),
+            vec![
+                (json!("res.partner"), "create".into(), raise.clone()),
+                (json!("res.partner"), "read".into(), raise.clone()),
+                (json!("res.partner"), "write".into(), raise.clone()),
+                (json!("res.partner"), "unlink".into(), raise.clone()),
+            ]
+        );
+
+        let matrices = client.access_matrices(&["res.partner", "res.users"])?;
+        assert_eq!(matrices.len(), 2);
+        assert_eq!(matrices["res.users"], matrix);
+        assert_eq!(sent.borrow().len(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_batched() -> ClosureResult<()> {
+        let (client, captured) = batch_client(None);
+        let progress = Mutex::new(Vec::new());
+        let response = client.write_batched(
+            "res.partner",
+            &[1, 2, 3, 4, 5, 6, 7],
+            jmap! {"active": false},
+            3,
+            Some(&|done, total| progress.lock().unwrap().push((done, total))),
+        )?;
+
+        assert!(response.ok);
+        assert_eq!(
+            *captured.borrow(),
+            vec![json!([1, 2, 3]), json!([4, 5, 6]), json!([7])]
+        );
+        assert_eq!(*progress.lock().unwrap(), vec![(3, 7), (6, 7), (7, 7)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_batched_failure() {
+        let (client, captured) = batch_client(Some(1));
+        let err = client
+            .write_batched("res.partner", &[1, 2, 3, 4, 5, 6, 7], jmap! {}, 3, None)
+            .unwrap_err();
+
+        match err {
+            ClosureError::BatchError {
+                processed,
+                failed,
+                source,
+            } => {
+                assert_eq!((processed, failed), (3, 3));
+                assert!(matches!(*source, ClosureError::JsonRpcError(_)));
+            }
+            err => panic!("unexpected error: {:?}", err),
+        }
+
+        // the remaining chunks aren't sent
+        assert_eq!(captured.borrow().len(), 2);
+    }
+
+    #[test]
+    fn unlink_batched() -> ClosureResult<()> {
+        let (client, captured) = batch_client(None);
+        let response = client.unlink_batched("res.partner", &[1, 2, 3, 4], 2, None)?;
+
+        assert!(response.ok);
+        assert_eq!(*captured.borrow(), vec![json!([1, 2]), json!([3, 4])]);
+
+        // a `chunk_size` of zero is treated as one
+        let (client, captured) = batch_client(None);
+        client.unlink_batched("res.partner", &[1, 2], 0, None)?;
+        assert_eq!(*captured.borrow(), vec![json!([1]), json!([2])]);
+
+        Ok(())
+    }
+
+    /// An Odoo 17.0 response to `name_get`
+    fn name_get_error() -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {
+                "code": 200,
+                "message": "Odoo Server Error",
+                "data": {
+                    "name": "builtins.AttributeError",
+                    "debug": "Traceback (most recent call last):\n  File \"/usr/lib/python3/dist-packages/odoo/api.py\", line 466, in call_kw\n    method = getattr(type(model), name)\nAttributeError: type object 'res.partner' has no attribute 'name_get'\n",
+                    "message": "type object 'res.partner' has no attribute 'name_get'",
+                    "arguments": ["type object 'res.partner' has no attribute 'name_get'"],
+                    "context": {}
+                }
+            }
+        })
+    }
+
+    /// Build a client which records the ORM method of each request, and
+    /// responds to `name_get` with `name_get_response`
+    fn fallback_client(
+        name_get_response: Value,
+    ) -> (OdooClient<Authed, ClosureBlocking>, Rc<RefCell<Vec<Value>>>) {
+        let captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let method = data["params"]["args"][4].clone();
+                inner.borrow_mut().push(method.clone());
+                let response = if method == "name_get" {
+                    name_get_response.clone()
+                } else {
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": 1,
+                        "result": [
+                            {"id": 1, "display_name": "YourCompany"},
+                            {"id": 3, "display_name": "YourCompany, Administrator"}
+                        ]
+                    })
+                };
+                Ok((response.to_string(), None))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        (client, captured)
+    }
+
+    #[test]
+    fn name_get_with_fallback() -> ClosureResult<()> {
+        // Odoo 17.0+: falls back to `read`
+        let (client, captured) = fallback_client(name_get_error());
+        let response = client.name_get_with_fallback("res.partner", vec![1, 3])?;
+
+        assert_eq!(*captured.borrow(), vec![json!("name_get"), json!("read")]);
+        let names: Vec<_> = response
+            .display_names
+            .iter()
+            .map(|item| (item.id, item.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![(1, "YourCompany"), (3, "YourCompany, Administrator")]
+        );
+
+        // older versions: `name_get` is used directly
+        let (client, captured) = fallback_client(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [[1, "YourCompany"]]
+        }));
+        let response = client.name_get_with_fallback("res.partner", vec![1])?;
+
+        assert_eq!(*captured.borrow(), vec![json!("name_get")]);
+        assert_eq!(response.display_names[0].name, "YourCompany");
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_get_with_fallback_error() {
+        // other errors are returned as-is
+        let mut error = name_get_error();
+        error["error"]["data"]["name"] = json!("odoo.exceptions.AccessError");
+        error["error"]["data"]["message"] = json!("Access denied");
+        let (client, captured) = fallback_client(error);
+        let err = client
+            .name_get_with_fallback("res.partner", vec![1])
+            .unwrap_err();
+
+        assert!(matches!(err, ClosureError::JsonRpcError(_)));
+        assert_eq!(*captured.borrow(), vec![json!("name_get")]);
+    }
+
+    #[test]
+    fn health_check() {
+        // the server is up, but the database doesn't exist
+        let client =
+            OdooClient::new_closure_blocking("http://localhost:8069", |_url, data, _session_id| {
+                let result = match data["params"]["method"].as_str() {
+                    Some("version") => json!({
+                        "server_version": "16.0+e",
+                        "server_version_info": [16, 0, 0, "final", 0, "e"],
+                        "server_serie": "16.0",
+                        "protocol_version": 1
+                    }),
+                    Some("db_exist") => json!(false),
+                    method => panic!("unexpected method: {:?}", method),
+                };
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            });
+
+        let report = client.health_check(Some("some-database"));
+        assert!(report.reachable);
+        assert_eq!(report.server_version.as_deref(), Some("16.0+e"));
+        assert_eq!(report.database_exists, Some(false));
+        assert!(!report.is_healthy());
+
+        let report = client.health_check(None);
+        assert_eq!(report.database_exists, None);
+        assert!(report.is_healthy());
+
+        // the server is down; each probe fails independently
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            |_url, _data, _session_id| Err(ClosureError::ClosureError("connection refused".into())),
+        );
+
+        let report = client.health_check(Some("some-database"));
+        assert!(!report.reachable);
+        assert_eq!(report.server_version, None);
+        assert_eq!(report.database_exists, None);
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn server_version_fallback() -> ClosureResult<()> {
+        // `/jsonrpc` is blocked, so the (unauthenticated) client falls back to
+        // the webclient endpoint
+        let urls = Rc::new(RefCell::new(Vec::new()));
+        let inner = urls.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |url, _data, _session_id| {
+                inner.borrow_mut().push(url.to_string());
+                if url.ends_with("/jsonrpc") {
+                    return Err(ClosureError::ClosureError("403 Forbidden".into()));
+                }
+                let response = json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": {
+                        "server_version": "17.0",
+                        "server_version_info": [17, 0, 0, "final", 0, ""],
+                        "server_serie": "17.0",
+                        "protocol_version": 1
+                    }
+                });
+                Ok((response.to_string(), None))
+            },
+        );
+
+        let version = client.server_version()?;
+        assert_eq!((version.major, version.minor), (17, 0));
+        assert_eq!(
+            *urls.borrow(),
+            vec![
+                "http://localhost:8069/jsonrpc",
+                "http://localhost:8069/web/webclient/version_info"
+            ]
+        );
+
+        // the version is cached
+        client.server_version()?;
+        assert_eq!(urls.borrow().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_session() -> ClosureResult<()> {
+        use crate::client::odoo_request::test::{capture_client, capture_client_with};
+
+        let (client, captured) = capture_client();
+        assert!(client.verify_session()?);
+        let (data, _headers) = &captured.borrow()[0];
+        assert_eq!(data["params"]["args"][3], json!("res.users"));
+        assert_eq!(data["params"]["args"][4], json!("read"));
+        assert_eq!(data["params"]["args"][5], json!([[2]]));
+
+        // rejected credentials aren't an error
+        let mut error = name_get_error();
+        error["error"]["data"]["name"] = json!("odoo.exceptions.AccessDenied");
+        error["error"]["data"]["message"] = json!("Access Denied");
+        let (client, _captured) = capture_client_with(error);
+        assert!(!client.verify_session()?);
+
+        // but other errors are
+        let (client, _captured) = capture_client_with(name_get_error());
+        assert!(client.verify_session().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn switch_url_verified() -> ClosureResult<()> {
+        // the session is only accepted by "node-a" (which shares the session store)
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let mut client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |url, _data, session_id| {
+                inner
+                    .borrow_mut()
+                    .push((url.to_string(), session_id.map(String::from)));
+                let response = if url.starts_with("http://node-b") {
+                    fixtures::errors::access_denied()
+                } else {
+                    json!({"jsonrpc": "2.0", "id": 1, "result": []})
+                };
+                Ok((response.to_string(), None))
+            },
+        )
+        .authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("abc123".into()),
+        );
+
+        client.switch_url_verified("http://node-a:8069")?;
+        assert_eq!(client.url, "http://node-a:8069");
+        assert_eq!(client.session_id(), Some("abc123"));
+
+        let err = client
+            .switch_url_verified("http://node-b:8069")
+            .unwrap_err();
+        assert!(
+            matches!(err, ClosureError::InvalidSession { ref url } if url == "http://node-b:8069"),
+            "{:?}",
+            err
+        );
+        assert_eq!(client.url, "http://node-a:8069");
+        assert_eq!(client.session_id(), Some("abc123"));
+
+        // the probes were sent to the new URL, with the session
+        let sent = sent.borrow();
+        assert_eq!(sent[0].0, "http://node-a:8069/jsonrpc");
+        assert_eq!(sent[1].0, "http://node-b:8069/jsonrpc");
+        assert!(sent.iter().all(|(_, id)| id.as_deref() == Some("abc123")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn attachment_round_trip() -> ClosureResult<()> {
+        // stores the uploaded `datas`, and returns it from `read`
+        let stored = Rc::new(RefCell::new(Value::Null));
+        let inner = stored.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                assert_eq!(args[3], json!("ir.attachment"));
+                let result = match args[4].as_str() {
+                    Some("create") => {
+                        *inner.borrow_mut() = args[5][0].clone();
+                        json!(12)
+                    }
+                    Some("read") => {
+                        assert_eq!(args[5], json!([[12]]));
+                        assert_eq!(args[6]["fields"], json!(["name", "mimetype", "datas"]));
+                        let values = inner.borrow();
+                        json!([{
+                            "id": 12,
+                            "name": values["name"],
+                            "mimetype": values["mimetype"],
+                            "datas": values["datas"]
+                        }])
+                    }
+                    method => panic!("unexpected method: {:?}", method),
+                };
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        let data = b"%PDF-1.4\n\x00\xff\xfe".to_vec();
+        let id = client.attachment_upload(
+            "res.partner",
+            7,
+            "invoice.pdf",
+            &data,
+            Some("application/pdf"),
+        )?;
+        assert_eq!(id, 12);
+        assert_eq!(stored.borrow()["res_model"], json!("res.partner"));
+        assert_eq!(stored.borrow()["res_id"], json!(7));
+        assert_eq!(stored.borrow()["datas"], json!("JVBERi0xLjQKAP/+"));
+
+        let attachment = client.attachment_download(id)?;
+        assert_eq!(attachment.name, "invoice.pdf");
+        assert_eq!(attachment.mimetype.as_deref(), Some("application/pdf"));
+        assert_eq!(attachment.data, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_read_paged() -> ClosureResult<()> {
+        // 12 partners, with ids 1 to 12
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let inner = calls.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                inner.borrow_mut().push(json!([args[4], args[5], args[6]]));
+                let result = match args[4].as_str() {
+                    Some("search_count") => json!(12),
+                    Some("search_read") => {
+                        let offset = args[6]["offset"].as_u64().unwrap();
+                        let limit = args[6]["limit"].as_u64().unwrap();
+                        let ids = offset + 1..=(offset + limit).min(12);
+                        json!(ids.map(|id| json!({"id": id})).collect::<Vec<_>>())
+                    }
+                    method => panic!("unexpected method: {:?}", method),
+                };
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        let domain = jvec![["is_company", "=", true]];
+        let page = client.search_read_paged(
+            "res.partner",
+            domain.clone(),
+            svec!["id"],
+            2,
+            5,
+            Some("name"),
+        )?;
+        assert_eq!(page.records, vec![jmap! {"id": 11}, jmap! {"id": 12}]);
+        assert_eq!(
+            (page.total, page.page, page.page_size, page.pages),
+            (12, 2, 5, 3)
+        );
+        assert!(!page.has_next());
+
+        // both requests use the same domain
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                json!(["search_count", [domain], {}]),
+                json!([
+                    "search_read",
+                    [],
+                    {"domain": domain, "fields": ["id"], "offset": 10, "limit": 5, "order": "name"}
+                ]),
+            ]
+        );
+
+        // a page beyond the end is empty, rather than an error
+        let page = client.search_read_paged("res.partner", jvec![], svec!["id"], 7, 5, None)?;
+        assert!(page.records.is_empty());
+        assert_eq!((page.total, page.pages), (12, 3));
+
+        Ok(())
+    }
+
+    /// A closure client for the merge wizard, which records each request's
+    /// `[model, method, args]` and answers `action_merge` with `merge_error`
+    fn merge_client(
+        merge_error: Option<&'static str>,
+    ) -> (OdooClient<Authed, ClosureBlocking>, Rc<RefCell<Vec<Value>>>) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let inner = calls.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                inner.borrow_mut().push(json!([args[3], args[4], args[5]]));
+                let response = match (args[4].as_str(), merge_error) {
+                    (Some("create"), _) => json!({"jsonrpc": "2.0", "id": 1, "result": 4}),
+                    (Some("action_merge"), None) => {
+                        json!({"jsonrpc": "2.0", "id": 1, "result": true})
+                    }
+                    (Some("action_merge"), Some(message)) => fixtures::errors::user_error(message),
+                    (method, _) => panic!("unexpected method: {:?}", method),
+                };
+                Ok((response.to_string(), None))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+        (client, calls)
+    }
+
+    #[test]
+    fn merge_partners() -> ClosureResult<()> {
+        let (client, calls) = merge_client(None);
+        assert_eq!(client.merge_partners(7, vec![8, 7, 9])?, 7);
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                json!([
+                    "base.partner.merge.automatic.wizard",
+                    "create",
+                    [{"partner_ids": [[6, 0, [7, 8, 9]]], "dst_partner_id": 7}]
+                ]),
+                json!(["base.partner.merge.automatic.wizard", "action_merge", [[4]]]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_partners_rejected() {
+        let message = "For safety reasons, you cannot merge more than 3 contacts together. You \
+                       can re-open the wizard several times if needed.";
+        let (client, calls) = merge_client(Some(message));
+        let err = client.merge_partners(1, vec![2, 3, 4]).unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                ClosureError::PartnerMergeRejected {
+                    reason: MergeRejection::TooManyPartners,
+                    message: m,
+                } if m == message
+            ),
+            "{:?}",
+            err
+        );
+        assert_eq!(calls.borrow().len(), 2);
+
+        // unrecognised `UserError`s are returned as-is
+        let (client, _) = merge_client(Some("Something unexpected happened"));
+        let err = client.merge_partners(1, 2).unwrap_err();
+        assert!(matches!(err, ClosureError::JsonRpcError(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn db_create_database_and_wait() -> ClosureResult<()> {
+        use crate::client::DbWait;
+        use std::time::Duration;
+
+        // the database appears on the 2nd probe, but the registry is still
+        // loading, so the first login fails
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let method = data["params"]["method"].as_str().unwrap().to_string();
+                let mut sent = inner.borrow_mut();
+                sent.push(method.clone());
+                let count = sent.iter().filter(|m| **m == method).count();
+                let response = match (method.as_str(), count) {
+                    ("create_database", _) => json!({"jsonrpc": "2.0", "id": 1, "result": true}),
+                    ("db_exist", 1) => json!({"jsonrpc": "2.0", "id": 1, "result": false}),
+                    ("db_exist", _) => json!({"jsonrpc": "2.0", "id": 1, "result": true}),
+                    ("login", 1) => name_get_error(),
+                    ("login", _) => json!({"jsonrpc": "2.0", "id": 1, "result": 2}),
+                    (method, _) => panic!("unexpected method: {:?}", method),
+                };
+                Ok((response.to_string(), None))
+            },
+        );
+
+        let create = CreateDatabase {
+            passwd: "master-password".into(),
+            db_name: "new-database".into(),
+            demo: false,
+            lang: "en_US".into(),
+            user_password: "password".into(),
+            login: "admin".into(),
+            country_code: None,
+            phone: None,
+        };
+        let wait = DbWait {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_secs(10),
+        };
+        let response = client.db_create_database_and_wait(create, wait)?;
+
+        assert_eq!(response.uid, 2);
+        assert_eq!(
+            *sent.borrow(),
+            vec![
+                "create_database",
+                "db_exist",
+                "db_exist",
+                "login",
+                "db_exist",
+                "login"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_read_iter() -> ClosureResult<()> {
+        // two full pages, then an empty page
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let mut sent = inner.borrow_mut();
+                let result = match sent.len() {
+                    0 => json!([{"id": 1, "name": "A"}, {"id": 2, "name": "B"}]),
+                    1 => json!([{"id": 3, "name": "C"}, {"id": 4, "name": "D"}]),
+                    _ => json!([]),
+                };
+                sent.push(data["params"]["args"][6]["domain"].clone());
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        let names = client
+            .search_read_iter(
+                "res.partner",
+                jvec!["|", ["a", "=", 1], ["b", "=", 2]],
+                svec!["name"],
+                2,
+            )
+            .records()
+            .map(|record| record.map(|record| record["name"].clone()))
+            .collect::<ClosureResult<Vec<_>>>()?;
+
+        assert_eq!(names, vec![json!("A"), json!("B"), json!("C"), json!("D")]);
+        assert_eq!(
+            *sent.borrow(),
+            vec![
+                json!(["|", ["a", "=", 1], ["b", "=", 2]]),
+                json!(["|", ["a", "=", 1], ["b", "=", 2], ["id", ">", 2]]),
+                json!(["|", ["a", "=", 1], ["b", "=", 2], ["id", ">", 4]]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn web_db() -> ClosureResult<()> {
+        use crate::client::DbGuard;
+
+        let captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let mut client = OdooClient::new_closure_blocking("http://localhost:8069", |_, _, _| {
+            Ok((String::new(), None))
+        });
+
+        // without a web closure, the requests fail
+        assert!(matches!(
+            client.web_db_duplicate("master", "acme", "acme-copy"),
+            Err(ClosureError::ClosureError(_))
+        ));
+
+        client.with_web_closure(move |url, body| {
+            inner.borrow_mut().push((url.to_string(), body));
+            Ok(WebResponse {
+                status: 303,
+                content_type: None,
+                body: Vec::new(),
+            })
+        });
+        client.web_db_duplicate("master", "acme", "acme-copy")?;
+        client.web_db_restore("master", &[1, 2, 3], "acme-restored", false, false)?;
+        {
+            let captured = captured.borrow();
+            assert_eq!(
+                captured[0].0,
+                "http://localhost:8069/web/database/duplicate"
+            );
+            assert_eq!(
+                captured[0].1,
+                WebBody::Form(vec![
+                    ("master_pwd".into(), "master".into()),
+                    ("name".into(), "acme".into()),
+                    ("new_name".into(), "acme-copy".into()),
+                ])
+            );
+            assert_eq!(captured[1].0, "http://localhost:8069/web/database/restore");
+            match &captured[1].1 {
+                WebBody::Multipart(parts) => {
+                    let names: Vec<_> = parts.iter().map(|part| part.name.as_str()).collect();
+                    assert_eq!(names, vec!["master_pwd", "backup_file", "name"]);
+                    assert_eq!(parts[1].data, vec![1, 2, 3]);
+                }
+                body => panic!("expected a multipart body, got {:?}", body),
+            }
+        }
+
+        // restoring over a protected name is refused
+        client.with_db_guard(DbGuard::new().protect_pattern("*prod*"));
+        assert!(matches!(
+            client.web_db_restore("master", &[], "acme-prod", false, true),
+            Err(ClosureError::ProtectedDatabase { .. })
+        ));
+        assert_eq!(captured.borrow().len(), 2);
+
+        // unless the guard allows confirmed requests, and it's confirmed
+        client.with_db_guard(
+            DbGuard::new()
+                .protect_pattern("*prod*")
+                .require_confirmation(),
+        );
+        assert!(matches!(
+            client.web_db_restore("master", &[], "acme-prod", false, false),
+            Err(ClosureError::ProtectedDatabase { .. })
+        ));
+        client.web_db_restore("master", &[], "acme-prod", false, true)?;
+        assert_eq!(captured.borrow().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw() -> ClosureResult<()> {
+        // a customized server, which returns the version as a number
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            |_url, _data, _session_id| {
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {"server_version": 17}})
+                        .to_string(),
+                    None,
+                ))
+            },
+        );
+
+        assert!(matches!(
+            client.common_version().send(),
+            Err(ClosureError::SerdeJsonError(_))
+        ));
+        assert_eq!(
+            client.common_version().send_raw()?,
+            json!({"server_version": 17})
+        );
+
+        // errors are mapped as usual
+        let (client, _captured) = fallback_client(name_get_error());
+        let err = client
+            .execute_kw("res.partner", "name_get", jvec![[1]], jmap! {})
+            .send_raw()
+            .unwrap_err();
+        assert!(matches!(err, ClosureError::JsonRpcError(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn model_metadata() -> ClosureResult<()> {
+        use std::time::Duration;
+
+        // records the `lang` of each `fields_get` call
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let inner = calls.clone();
+        let mut client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                assert_eq!(args[4], json!("fields_get"));
+                let lang = args[6]["context"]["lang"].clone();
+                inner.borrow_mut().push(lang.clone());
+                let label = if lang == json!("fr_FR") { "Type d'adresse" } else { "Address Type" };
+                Ok((
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": 1,
+                        "result": {
+                            "type": {
+                                "string": label,
+                                "type": "selection",
+                                "selection": [["contact", "Contact"], ["invoice", "Invoice Address"]]
+                            }
+                        }
+                    })
+                    .to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        // without a cache, every call fetches the fields
+        client.model_metadata("res.partner")?;
+        client.model_metadata("res.partner")?;
+        assert_eq!(calls.borrow().len(), 2);
+        calls.borrow_mut().clear();
+
+        client.with_metadata_cache(Duration::from_secs(60));
+        let metadata = client.model_metadata("res.partner")?;
+        assert_eq!(metadata.field_label("type"), Some("Address Type"));
+        assert_eq!(metadata.field_type("type"), Some("selection"));
+
+        // the second call uses the cache
+        let cached = client.model_metadata("res.partner")?;
+        assert!(Arc::ptr_eq(&metadata, &cached));
+        assert_eq!(calls.borrow().len(), 1);
+
+        // refreshing always fetches, and updates the cache
+        let refreshed = client.refresh_model_metadata("res.partner")?;
+        assert!(!Arc::ptr_eq(&metadata, &refreshed));
+        assert!(Arc::ptr_eq(
+            &refreshed,
+            &client.model_metadata("res.partner")?
+        ));
+        assert_eq!(calls.borrow().len(), 2);
+
+        // each `lang` is cached separately
+        client.context.insert("lang".into(), json!("fr_FR"));
+        let french = client.model_metadata("res.partner")?;
+        assert_eq!(french.field_label("type"), Some("Type d'adresse"));
+        client.model_metadata("res.partner")?;
+        assert_eq!(
+            *calls.borrow(),
+            vec![json!(null), json!(null), json!("fr_FR")]
+        );
+
+        // expired entries are fetched again
+        client.with_metadata_cache(Duration::ZERO);
+        client.model_metadata("res.partner")?;
+        client.model_metadata("res.partner")?;
+        assert_eq!(calls.borrow().len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_button() -> ClosureResult<()> {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let inner = calls.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                inner.borrow_mut().push(json!([args[3], args[4], args[5]]));
+                let result = match args[4].as_str() {
+                    Some("button_validate") => json!({
+                        "type": "ir.actions.act_window",
+                        "res_model": "stock.immediate.transfer",
+                        "view_mode": "form",
+                        "target": "new",
+                        "context": {"default_pick_ids": [[4, 7]]}
+                    }),
+                    _ => json!(true),
+                };
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        let result = client.call_button("stock.picking", 7, "button_validate")?;
+        assert!(result.is_action());
+        let result = client.call_button("sale.order", vec![3, 4], "action_confirm")?;
+        assert_eq!(result, MethodCallResult::Bool(true));
+
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                json!(["stock.picking", "button_validate", [[7]]]),
+                json!(["sale.order", "action_confirm", [[3, 4]]]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn settings() -> ClosureResult<()> {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let inner = calls.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                inner.borrow_mut().push(json!([args[3], args[4], args[5]]));
+                let result = match args[4].as_str() {
+                    Some("create") => json!(42),
+                    Some("default_get") => json!({"group_use_lead": true}),
+                    _ => json!(true),
+                };
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": result}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        client.apply_settings(jmap! {"group_use_lead": true, "module_crm": true})?;
+        let values = client.read_settings(svec!["group_use_lead", "missing"])?;
+        assert_eq!(values, jmap! {"group_use_lead": true});
+
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                json!([
+                    "res.config.settings",
+                    "create",
+                    [{"group_use_lead": true, "module_crm": true}]
+                ]),
+                json!(["res.config.settings", "execute", [[42]]]),
+                json!([
+                    "res.config.settings",
+                    "default_get",
+                    [["group_use_lead", "missing"]]
+                ]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn authenticate_auto() -> ClosureAuthResult<()> {
+        // a single database is used as-is
+        let (client, captured) = auto_client(Some(json!(["acme-prod"])));
+        let client = client.authenticate_auto("admin", "password")?;
+        assert_eq!(client.database(), "acme-prod");
+        assert_eq!(*captured.borrow(), vec![json!("acme-prod")]);
+
+        // several databases are an error
+        let (client, captured) = auto_client(Some(json!(["acme-prod", "acme-staging"])));
+        let err = client.authenticate_auto("admin", "password").unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                ClosureAuthError::MultipleDatabases(databases) if databases.len() == 2
+            ),
+            "{:?}",
+            err
+        );
+        assert!(captured.borrow().is_empty());
+
+        // with listing disabled, Odoo picks the database
+        let (client, captured) = auto_client(None);
+        let client = client.authenticate_auto("admin", "password")?;
+        assert_eq!(client.database(), "acme");
+        assert_eq!(*captured.borrow(), vec![json!(null)]);
+
+        Ok(())
     }
 }
@@ -0,0 +1,369 @@
+//! The browser `fetch()` impl, for `wasm32-unknown-unknown` builds
+//!
+//! Requests are sent with the [Fetch API](https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API)
+//! on the current `window`, so the session cookie is managed by the browser
+//! (there's no cookie store, and the `session_id` isn't tracked by the client).
+//! This is intended for same-origin deployments, e.g. a Yew or Leptos frontend
+//! served by (or proxied alongside) Odoo.
+//!
+//! As with [`ClosureAsync`](crate::client::ClosureAsync), the futures aren't
+//! `Send`, and errors are returned as a [`ClosureError`] (JS exceptions are
+//! wrapped as [`ClosureError::ClosureError`]).
+
+use crate::client::access;
+use crate::client::attachment;
+use crate::client::body::check_size;
+use crate::client::button::MethodCallResult;
+use crate::client::error::{ClosureAuthResult, ClosureError, ClosureResult};
+use crate::client::metrics::MetricsTimer;
+use crate::client::odoo_client::single_database;
+use crate::client::page::Page;
+use crate::client::session::is_invalid_session;
+use crate::client::settings;
+use crate::client::stream::SearchReadStream;
+use crate::client::{AuthState, Authed, NotAuthed, OdooClient, OdooRequest, RequestImpl};
+use crate::jsonrpc::{JsonRpcParams, OdooIds};
+use crate::OdooVersion;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt::Debug;
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Headers, RequestCredentials, RequestInit, Response};
+
+/// Send requests with the browser's `fetch()`
+///
+/// The `with_timeout()`, `with_retry()` and `with_rate_limit()` options aren't
+/// applied (`std::time::Instant` isn't available in the browser), and neither
+/// are the `tracing` spans.
+#[derive(Debug, Clone, Default)]
+pub struct FetchImpl {}
+
+impl RequestImpl for FetchImpl {
+    type Error = ClosureError;
+}
+
+impl OdooClient<NotAuthed, FetchImpl> {
+    pub fn new_fetch(url: &str) -> Self {
+        Self::new(url, FetchImpl {})
+    }
+}
+
+impl<S> OdooClient<S, FetchImpl>
+where
+    S: AuthState,
+{
+    /// Authenticate, opening a browser session
+    ///
+    /// The session cookie is stored by the browser, and sent with each
+    /// following request (so [`session_id()`](OdooClient::session_id) is `None`).
+    pub async fn authenticate(
+        self,
+        db: &str,
+        login: &str,
+        password: &str,
+    ) -> ClosureAuthResult<OdooClient<Authed, FetchImpl>> {
+        let request = self.get_auth_request(db, login, password);
+        let (response, session_id) = request.send_internal().await?;
+        Ok(self.parse_auth_response(db, login, password, response, session_id)?)
+    }
+
+    /// Authenticate without knowing the database name
+    ///
+    /// The databases are listed with [`db_list`](crate::service::db::List) first.
+    /// If the server has a single database, it's used to [`authenticate()`](Self::authenticate);
+    /// if it has several, a `MultipleDatabases` error is returned. If listing is
+    /// disabled, the request is sent without a database, and the one Odoo picked
+    /// (e.g. with `dbfilter`) is read from the session info.
+    pub async fn authenticate_auto(
+        self,
+        login: &str,
+        password: &str,
+    ) -> ClosureAuthResult<OdooClient<Authed, FetchImpl>> {
+        let databases = match self.db_list(false).send().await {
+            Ok(response) => response.databases,
+            Err(ClosureError::DbListingDisabled) => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(db) = single_database(databases)? {
+            return self.authenticate(&db, login, password).await;
+        }
+        let request = self.get_auth_request("", login, password);
+        let (response, session_id) = request.send_internal().await?;
+        Ok(self.parse_auth_auto_response(login, password, response, session_id)?)
+    }
+
+    /// Fetch the server version
+    ///
+    /// The version is fetched with [`common_version`](crate::service::common::Version)
+    /// on the first call, then cached on the client. If that fails (e.g. because
+    /// `/jsonrpc` is blocked), [`web_version_info`](crate::service::web::WebVersionInfo)
+    /// is tried instead.
+    pub async fn server_version(&self) -> ClosureResult<OdooVersion> {
+        if let Some(version) = self.version() {
+            return Ok(version);
+        }
+        let response = match self.get_version_request().send().await {
+            Ok(response) => response,
+            // some deployments block `/jsonrpc`, but the webclient still needs this
+            Err(err) => self.web_version_info().send().await.map_err(|_| err)?,
+        };
+        Ok(self.parse_version_response(response))
+    }
+
+    /// Check whether the server version is at least `major.minor`
+    ///
+    /// See [`OdooVersion::at_least`] for more info.
+    pub async fn version_at_least(&self, major: u32, minor: u32) -> ClosureResult<bool> {
+        Ok(self.server_version().await?.at_least(major, minor))
+    }
+}
+
+impl OdooClient<Authed, FetchImpl> {
+    /// Check whether the browser session is still valid
+    ///
+    /// This sends a cheap `read` of the current user. If Odoo rejects the
+    /// session (e.g. the user logged out in another tab), `Ok(false)` is
+    /// returned; any other error is returned as-is.
+    pub async fn verify_session(&self) -> ClosureResult<bool> {
+        let request = self.read("res.users", self.auth.uid, vec!["id".into()]);
+        match request.send().await {
+            Ok(_) => Ok(true),
+            Err(ClosureError::JsonRpcError(error)) if is_invalid_session(&error) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Point the client at a different server, keeping the session, and check it there
+    ///
+    /// This is for servers which share the session store (e.g. a failover node).
+    /// The session is checked with [`verify_session()`](Self::verify_session);
+    /// if it's rejected, the previous URL is restored and an `InvalidSession`
+    /// error is returned. The previous URL is restored on any other error too.
+    pub async fn switch_url_verified(&mut self, url: &str) -> ClosureResult<()> {
+        let previous = self.replace_url(url);
+        let result = match self.verify_session().await {
+            Ok(true) => return Ok(()),
+            Ok(false) => Err(ClosureError::InvalidSession {
+                url: self.url.clone(),
+            }),
+            Err(error) => Err(error),
+        };
+        self.url = previous;
+        result
+    }
+
+    /// Call the button `method` on `ids`, and sniff the result
+    ///
+    /// This sends an [`execute_kw`](OdooClient::execute_kw) request, as the
+    /// webclient does for `type="object"` buttons. Use [`MethodCallResult::is_action`]
+    /// to tell whether the server wants user interaction (e.g. a wizard).
+    pub async fn call_button<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        method: &str,
+    ) -> ClosureResult<MethodCallResult> {
+        let ids = ids.into().into_inner();
+        let response = self
+            .execute_kw(model, method, vec![ids.into()], Map::new())
+            .send()
+            .await?;
+        Ok(MethodCallResult::from_value(response.data))
+    }
+
+    /// Apply the configuration `values` (e.g. `group_use_lead`), as the settings "Save" button does
+    ///
+    /// This creates a `res.config.settings` record with the `values`, then
+    /// calls its `execute` method. Fields which aren't given keep their current
+    /// value.
+    pub async fn apply_settings(&self, values: Map<String, Value>) -> ClosureResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send().await?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id).send().await?;
+        Ok(())
+    }
+
+    /// Read the current configuration `fields` (e.g. `group_use_lead`)
+    ///
+    /// This sends a `default_get` request on `res.config.settings`, which
+    /// computes the values from the current config parameters, groups and
+    /// installed modules. Unknown fields are missing from the result.
+    pub async fn read_settings(&self, fields: Vec<String>) -> ClosureResult<Map<String, Value>> {
+        let response = self.settings_read_request(fields).send().await?;
+        Ok(settings::settings_values(response))
+    }
+
+    /// Fetch page `page` of the `search_read` results for `domain`, with the total count
+    ///
+    /// This sends a [`search_count`](OdooClient::search_count) and a
+    /// [`search_read`](OdooClient::search_read) request, concurrently. Pages are
+    /// numbered from `0`, and a `page_size` of `0` is treated as `1`. If `order`
+    /// is `None`, the model's default order is used. See [`Page`].
+    pub async fn search_read_paged(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> ClosureResult<Page> {
+        let page_size = page_size.max(1);
+        let (count, read) = self.page_requests(model, domain, fields, page, page_size, order);
+        let (count, read) = access::join(count.send(), read.send()).await;
+        Ok(Page::new(read?.data, count?.count, page, page_size))
+    }
+
+    /// Stream the `search_read` results for `domain`, in pages of `batch_size`
+    ///
+    /// The records are fetched in `id` order, with keyset pagination. See
+    /// [`SearchReadStream`] for more info.
+    pub fn search_read_stream(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        batch_size: u32,
+    ) -> SearchReadStream<'_, ClosureError> {
+        let model = model.to_string();
+        SearchReadStream::new(domain, batch_size, move |domain| {
+            let request = self
+                .search_read(&model, domain, fields.clone())
+                .limit(batch_size)
+                .order("id");
+            async move { request.send().await.map(|response| response.data) }
+        })
+    }
+}
+
+impl<'a, T> OdooRequest<'a, T, FetchImpl>
+where
+    T: JsonRpcParams + Debug + Serialize,
+    T::Container<T>: Debug + Serialize,
+{
+    pub async fn send(self) -> ClosureResult<T::Response> {
+        Ok(self.send_internal().await?.0)
+    }
+
+    /// Send the request, returning the raw `result` as a [`Value`]
+    ///
+    /// This skips the typed response, e.g. for debugging a customized server
+    /// which returns a different shape. Errors are returned as with `send()`.
+    pub async fn send_raw(self) -> ClosureResult<Value> {
+        Ok(self.send_internal().await?.0)
+    }
+
+    async fn send_internal<D: Debug + DeserializeOwned>(
+        self,
+    ) -> ClosureResult<(D, Option<String>)> {
+        let (url, headers, data) = self.prepare()?;
+        let timer = MetricsTimer::start(&self.metrics_hooks, &url, &data);
+        let init = request_init(&data, &headers)?;
+        let window = web_sys::window().ok_or_else(|| {
+            js_error("no `window` (fetch is only supported in the browser)".into())
+        })?;
+        let response: Response = JsFuture::from(window.fetch_with_str_and_init(&url, &init))
+            .await
+            .map_err(js_error)?
+            .unchecked_into();
+        let status = response.status();
+        let text = JsFuture::from(response.text().map_err(js_error)?)
+            .await
+            .map_err(js_error)?
+            .as_string()
+            .unwrap_or_default();
+        check_size(self.max_response_size, text.len() as u64)?;
+        self.on_response(status, &text);
+        let result = self.parse_response(text.as_bytes());
+        timer.finish(status, text.as_bytes(), &result);
+        // the session cookie is kept by the browser
+        Ok((result?, None))
+    }
+}
+
+/// Requests can be `.await`ed directly, as a shortcut for `.send().await`
+///
+/// As with [`ClosureAsync`](crate::client::ClosureAsync), the future isn't `Send`.
+impl<'a, T> IntoFuture for OdooRequest<'a, T, FetchImpl>
+where
+    T: JsonRpcParams + Debug + Serialize + 'a,
+    T::Container<T>: Debug + Serialize,
+{
+    type Output = ClosureResult<T::Response>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+/// Build the `fetch()` options for the JSON-RPC `data`
+///
+/// Cookies are only sent to the same origin (the browser default), so the
+/// Odoo session isn't leaked to other hosts.
+fn request_init(data: &Value, headers: &[(String, String)]) -> ClosureResult<RequestInit> {
+    let fetch_headers = Headers::new().map_err(js_error)?;
+    fetch_headers
+        .set("Content-Type", "application/json")
+        .map_err(js_error)?;
+    for (name, value) in headers {
+        fetch_headers.set(name, value).map_err(js_error)?;
+    }
+
+    let init = RequestInit::new();
+    init.set_method("POST");
+    init.set_credentials(RequestCredentials::SameOrigin);
+    init.set_headers(&fetch_headers);
+    init.set_body(&JsValue::from_str(&serde_json::to_string(data)?));
+    Ok(init)
+}
+
+/// Wrap a JS exception (e.g. a rejected `fetch()`) as a [`ClosureError`]
+fn js_error(value: JsValue) -> ClosureError {
+    let message = match value.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.message()),
+        None => value.as_string().unwrap_or_else(|| format!("{:?}", value)),
+    };
+    ClosureError::from(Box::<dyn std::error::Error>::from(format!(
+        "fetch: {}",
+        message
+    )))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn request_init_serialization() {
+        let data = json!({
+            "jsonrpc": "2.0",
+            "method": "call",
+            "id": 1,
+            "params": {"service": "common", "method": "version", "args": []}
+        });
+        let headers = vec![("X-Odoo-Database".to_string(), "some-database".to_string())];
+        let init = request_init(&data, &headers).unwrap();
+
+        assert_eq!(init.get_method().as_deref(), Some("POST"));
+        assert_eq!(init.get_credentials(), Some(RequestCredentials::SameOrigin));
+        let body = init.get_body().as_string().unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&body).unwrap(), data);
+
+        let headers: Headers = init.get_headers().unchecked_into();
+        assert_eq!(
+            headers.get("Content-Type").unwrap().as_deref(),
+            Some("application/json")
+        );
+        assert_eq!(
+            headers.get("X-Odoo-Database").unwrap().as_deref(),
+            Some("some-database")
+        );
+    }
+}
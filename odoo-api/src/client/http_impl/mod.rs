@@ -6,3 +6,12 @@ pub(crate) mod reqwest_async;
 
 #[cfg(feature = "blocking")]
 pub(crate) mod reqwest_blocking;
+
+#[cfg(feature = "ureq")]
+pub(crate) mod ureq_blocking;
+
+#[cfg(feature = "wasm")]
+pub(crate) mod fetch;
+
+#[cfg(all(test, any(feature = "blocking", feature = "async", feature = "ureq")))]
+pub(crate) mod test_server;
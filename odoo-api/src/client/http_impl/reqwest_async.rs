@@ -1,12 +1,117 @@
-use crate::client::error::{ReqwestAuthResult, ReqwestError, ReqwestResult};
+use crate::client::access::{self, AccessMatrix};
+use crate::client::attachment::{self, Attachment};
+use crate::client::batch::{self, BatchProgress};
+use crate::client::body::LimitedBody;
+use crate::client::button::MethodCallResult;
+#[cfg(feature = "compression")]
+use crate::client::compression::{self, CompressionLevel, RequestCompression};
+use crate::client::db_wait::{self, DbWait, Probe};
+use crate::client::error::{ReqwestAuthError, ReqwestAuthResult, ReqwestError, ReqwestResult};
+use crate::client::health::HealthReport;
+use crate::client::login_form;
+use crate::client::merge;
+use crate::client::metadata::ModelMetadata;
+use crate::client::metrics::MetricsTimer;
+use crate::client::odoo_client::single_database;
+use crate::client::page::Page;
+use crate::client::progress;
+use crate::client::report;
+use crate::client::retry::{self, ResponseMeta};
+use crate::client::session::{
+    is_invalid_session, is_session_user, jar_restore_session_id, jar_session_id,
+};
+use crate::client::settings;
+use crate::client::stream::SearchReadStream;
+use crate::client::timer;
+use crate::client::totp;
+use crate::client::trace::RequestSpan;
+use crate::client::web_db::{self, BackupFormat, WebBody, WebResponse};
 use crate::client::{AuthState, Authed, NotAuthed, OdooClient, OdooRequest, RequestImpl};
-use crate::jsonrpc::JsonRpcParams;
-use reqwest::Client;
+use crate::jsonrpc::{JsonRpcParams, OdooId, OdooIds};
+use crate::service::common::LoginResponse;
+use crate::service::db::CreateDatabase;
+use crate::service::orm::{NameGetResponse, UnlinkResponse, WriteResponse};
+use crate::service::web::SessionAuthenticateResponse;
+use crate::OdooVersion;
+use reqwest::cookie::Jar;
+use reqwest::{Client, ClientBuilder, RequestBuilder};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+#[derive(Clone)]
 pub struct ReqwestAsync {
     client: Client,
+
+    /// The cookie store, shared with the `client`
+    ///
+    /// The `session_id` is read from here after each request, and a restored
+    /// session is added to it (see [`jar_restore_session_id`]).
+    jar: Arc<Jar>,
+
+    /// Send `/jsonrpc` requests via XML-RPC instead
+    #[cfg(feature = "xmlrpc")]
+    xmlrpc: bool,
+
+    /// Gzip large request bodies
+    #[cfg(feature = "compression")]
+    compression: Option<RequestCompression>,
+}
+
+impl ReqwestAsync {
+    /// Build the POST request for `url`
+    ///
+    /// With the XML-RPC transport enabled, `/jsonrpc` requests are re-encoded
+    /// for the matching `/xmlrpc/2/` endpoint (and `true` is returned).
+    #[cfg_attr(not(feature = "xmlrpc"), allow(clippy::unnecessary_wraps))]
+    fn post(&self, url: &str, body: &Value) -> ReqwestResult<(RequestBuilder, bool)> {
+        #[cfg(feature = "xmlrpc")]
+        if self.xmlrpc {
+            if let Some((url, xml)) = crate::xmlrpc::encode_request(url, body)? {
+                let request = self
+                    .client
+                    .post(url)
+                    .header("Content-Type", "text/xml")
+                    .body(xml);
+                return Ok((request, true));
+            }
+        }
+        #[cfg(feature = "compression")]
+        if let Some(compression) = &self.compression {
+            let json = serde_json::to_vec(body)?;
+            let request = self
+                .client
+                .post(url)
+                .header("Content-Type", "application/json");
+            let request = match compression.compress(&json) {
+                Some(gzip) => request.header("Content-Encoding", "gzip").body(gzip),
+                None => request.body(json),
+            };
+            return Ok((request, false));
+        }
+        Ok((self.client.post(url).json(body), false))
+    }
+
+    /// Convert the `response` back to JSON-RPC, if the request was sent via XML-RPC
+    #[cfg_attr(
+        not(feature = "xmlrpc"),
+        allow(unused_variables, clippy::unnecessary_wraps)
+    )]
+    fn decode(&self, xmlrpc: bool, response: Vec<u8>, body: &Value) -> ReqwestResult<Vec<u8>> {
+        #[cfg(feature = "xmlrpc")]
+        if xmlrpc {
+            let response = String::from_utf8_lossy(&response);
+            let decoded = crate::xmlrpc::decode_response(&response, &body["id"])?;
+            return Ok(decoded.to_string().into_bytes());
+        }
+        Ok(response)
+    }
 }
 impl RequestImpl for ReqwestAsync {
     type Error = ReqwestError;
@@ -14,9 +119,29 @@ impl RequestImpl for ReqwestAsync {
 
 impl OdooClient<NotAuthed, ReqwestAsync> {
     pub fn new_reqwest_async(url: &str) -> Result<Self, reqwest::Error> {
-        let client = Client::builder().cookie_store(true).build()?;
+        Self::new_reqwest_async_with(url, Client::builder())
+    }
+
+    /// Build the client from a `reqwest` client `builder` (with the cookie
+    /// store enabled)
+    pub(crate) fn new_reqwest_async_with(
+        url: &str,
+        builder: ClientBuilder,
+    ) -> Result<Self, reqwest::Error> {
+        let jar = Arc::new(Jar::default());
+        let client = builder.cookie_provider(jar.clone()).build()?;
 
-        Ok(Self::new(url, ReqwestAsync { client }))
+        Ok(Self::new(
+            url,
+            ReqwestAsync {
+                client,
+                jar,
+                #[cfg(feature = "xmlrpc")]
+                xmlrpc: false,
+                #[cfg(feature = "compression")]
+                compression: None,
+            },
+        ))
     }
 }
 
@@ -25,7 +150,7 @@ where
     S: AuthState,
 {
     pub async fn authenticate(
-        mut self,
+        self,
         db: &str,
         login: &str,
         password: &str,
@@ -34,6 +159,744 @@ where
         let (response, session_id) = request.send_internal().await?;
         Ok(self.parse_auth_response(db, login, password, response, session_id)?)
     }
+
+    /// Authenticate without knowing the database name
+    ///
+    /// The databases are listed with [`db_list`](crate::service::db::List) first.
+    /// If the server has a single database, it's used to [`authenticate()`](Self::authenticate);
+    /// if it has several, a `MultipleDatabases` error is returned. If listing is
+    /// disabled, the request is sent without a database, and the one Odoo picked
+    /// (e.g. with `dbfilter`) is read from the session info.
+    pub async fn authenticate_auto(
+        self,
+        login: &str,
+        password: &str,
+    ) -> ReqwestAuthResult<OdooClient<Authed, ReqwestAsync>> {
+        let databases = match self.db_list(false).send().await {
+            Ok(response) => response.databases,
+            Err(ReqwestError::DbListingDisabled) => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(db) = single_database(databases)? {
+            return self.authenticate(&db, login, password).await;
+        }
+        let request = self.get_auth_request("", login, password);
+        let (response, session_id) = request.send_internal().await?;
+        Ok(self.parse_auth_auto_response(login, password, response, session_id)?)
+    }
+
+    /// Authenticate a user with two-factor authentication enabled
+    ///
+    /// This sends the usual `/web/session/authenticate` request, then completes
+    /// the login by posting `totp_code` to the `/web/login/totp` form (as the
+    /// webclient does). If the user doesn't have two-factor authentication
+    /// enabled, the code is ignored.
+    ///
+    /// Returns [`ReqwestAuthError::TwoFactorRejected`] if the code is wrong (or
+    /// expired).
+    pub async fn authenticate_totp(
+        self,
+        db: &str,
+        login: &str,
+        password: &str,
+        totp_code: &str,
+    ) -> ReqwestAuthResult<OdooClient<Authed, ReqwestAsync>> {
+        let request = self.get_auth_request(db, login, password);
+        let (response, session_id): (SessionAuthenticateResponse, _) =
+            request.send_internal().await?;
+        if !totp::is_totp_required(&response.data) {
+            return Ok(self.parse_auth_response(db, login, password, response, session_id)?);
+        }
+
+        // the partial session is kept in the cookie store
+        let page = self.web_get(totp::TOTP_ENDPOINT).await?;
+        let csrf_token =
+            totp::csrf_token(&page).ok_or_else(|| ReqwestError::MalformedResponse {
+                reason: format!("no `csrf_token` on the {} form", totp::TOTP_ENDPOINT),
+            })?;
+        self.web_db_post(totp::TOTP_ENDPOINT, totp::totp_body(&csrf_token, totp_code))
+            .await?;
+
+        let (response, session_id): (SessionAuthenticateResponse, _) =
+            self.web_session_info().send_internal().await?;
+        if totp::is_totp_required(&response.data) {
+            return Err(ReqwestAuthError::TwoFactorRejected);
+        }
+        Ok(self.parse_auth_response(db, login, password, response, session_id)?)
+    }
+
+    /// Authenticate via the `/web/login` form, rather than `/web/session/authenticate`
+    ///
+    /// This is a fallback for servers which reject programmatic logins (e.g.
+    /// odoo.com SaaS trial databases). The form is fetched for its `csrf_token`,
+    /// the credentials are posted (as the browser does), then the `uid` is read
+    /// from [`web_session_info`](crate::service::web::SessionInfo). The
+    /// session is kept in the cookie store, as with [`authenticate`](Self::authenticate).
+    ///
+    /// Returns [`ReqwestAuthError::LoginRejected`] if the form shows an error
+    /// (e.g. the password is wrong). If the user has two-factor authentication
+    /// enabled, [`ReqwestAuthError::TwoFactorRequired`] is returned instead.
+    pub async fn authenticate_via_login_form(
+        self,
+        db: &str,
+        login: &str,
+        password: &str,
+    ) -> ReqwestAuthResult<OdooClient<Authed, ReqwestAsync>> {
+        let page = self.web_get(&login_form::login_endpoint(db)).await?;
+        let csrf_token =
+            totp::csrf_token(&page).ok_or_else(|| ReqwestError::MalformedResponse {
+                reason: format!("no `csrf_token` on the {} form", login_form::LOGIN_ENDPOINT),
+            })?;
+        let body = login_form::login_body(&csrf_token, db, login, password);
+        let response = self.web_db_post(login_form::LOGIN_ENDPOINT, body).await?;
+        if let Some(message) = login_form::login_error(&response) {
+            return Err(ReqwestAuthError::LoginRejected { message });
+        }
+
+        let (response, session_id): (SessionAuthenticateResponse, _) =
+            match self.web_session_info().send_internal().await {
+                Ok(response) => response,
+                Err(ReqwestError::JsonRpcError(error)) if is_invalid_session(&error) => {
+                    return Err(ReqwestAuthError::LoginRejected {
+                        message: "no session was opened".into(),
+                    })
+                }
+                Err(err) => return Err(err.into()),
+            };
+        Ok(self.parse_auth_response(db, login, password, response, session_id)?)
+    }
+
+    /// Send `/jsonrpc` requests via the XML-RPC endpoints (`/xmlrpc/2/*`) instead
+    ///
+    /// This is useful for servers which have `/jsonrpc` disabled. See the
+    /// [`xmlrpc`](crate::xmlrpc) module for more info.
+    #[cfg(feature = "xmlrpc")]
+    pub fn with_xmlrpc_transport(&mut self, enabled: bool) -> &mut Self {
+        self._impl.xmlrpc = enabled;
+        self
+    }
+
+    /// Gzip JSON-RPC request bodies larger than `threshold` bytes
+    ///
+    /// Compressed requests are sent with `Content-Encoding: gzip`. Odoo doesn't
+    /// decode these itself, so this only works behind a reverse proxy which
+    /// decompresses request bodies. XML-RPC requests aren't compressed.
+    ///
+    /// With the `compression` feature, gzip and deflate responses are decoded
+    /// either way.
+    #[cfg(feature = "compression")]
+    pub fn with_request_compression(
+        &mut self,
+        level: CompressionLevel,
+        threshold: usize,
+    ) -> &mut Self {
+        self._impl.compression = Some(RequestCompression { level, threshold });
+        self
+    }
+
+    /// Fetch the server version
+    ///
+    /// The version is fetched with [`common_version`](crate::service::common::Version)
+    /// on the first call, then cached on the client. If that fails (e.g. because
+    /// `/jsonrpc` is blocked), [`web_version_info`](crate::service::web::WebVersionInfo)
+    /// is tried instead.
+    pub async fn server_version(&self) -> ReqwestResult<OdooVersion> {
+        if let Some(version) = self.version() {
+            return Ok(version);
+        }
+        let response = match self.get_version_request().send().await {
+            Ok(response) => response,
+            // some deployments block `/jsonrpc`, but the webclient still needs this
+            Err(err) => self.web_version_info().send().await.map_err(|_| err)?,
+        };
+        Ok(self.parse_version_response(response))
+    }
+
+    /// Check whether the server version is at least `major.minor`
+    ///
+    /// See [`OdooVersion::at_least`] for more info.
+    pub async fn version_at_least(&self, major: u32, minor: u32) -> ReqwestResult<bool> {
+        Ok(self.server_version().await?.at_least(major, minor))
+    }
+
+    /// Probe the server, e.g. for a liveness/readiness check
+    ///
+    /// This sends a [`common_version`](crate::service::common::Version) request
+    /// (timed as the report `latency`), then a [`db_exist`](crate::service::db::DbExist)
+    /// request if `db` is passed. Failures are recorded in the [`HealthReport`]
+    /// rather than returned as an error.
+    pub async fn health_check(&self, db: Option<&str>) -> HealthReport {
+        let start = Instant::now();
+        let version = self.get_version_request().send().await;
+        let latency = start.elapsed();
+        let database_exists = match db {
+            Some(db) => Some(
+                self.db_exist(db)
+                    .send()
+                    .await
+                    .map(|response| response.exists),
+            ),
+            None => None,
+        };
+        HealthReport::new(version, database_exists, latency)
+    }
+
+    /// Create a new database, then wait until it's ready to use
+    ///
+    /// Odoo responds to [`db_create_database`](crate::service::db::CreateDatabase)
+    /// once the database is created, but the registry may take a while longer
+    /// to load (especially with large templates). This method then polls
+    /// [`db_exist`](crate::service::db::DbExist), followed by a
+    /// [`common_login`](crate::service::common::Login) with the new admin
+    /// credentials, every `wait.interval`. It returns once the login succeeds,
+    /// or with a [`ReqwestError::DatabaseNotReady`] (holding the last failure) after
+    /// `wait.timeout`.
+    pub async fn db_create_database_and_wait(
+        &self,
+        create: CreateDatabase,
+        wait: DbWait,
+    ) -> ReqwestResult<LoginResponse> {
+        self.get_create_database_request(&create).send().await?;
+        let create = &create;
+        db_wait::run_async(&create.db_name, wait, move || async move {
+            match self.db_exist(&create.db_name).send().await {
+                Ok(response) if !response.exists => return Probe::Missing,
+                Err(err) => return Probe::Failed(err),
+                Ok(_) => {}
+            }
+            match self.get_login_request(create).send().await {
+                Ok(response) => Probe::Ready(response),
+                Err(err) => Probe::Failed(err),
+            }
+        })
+        .await
+    }
+
+    /// Back up the database `name` via the `/web/database/backup` form
+    ///
+    /// This is useful when the JSON-RPC `db` service is blocked (otherwise, see
+    /// [`db_dump`](crate::service::db::Dump)). Returns the raw backup.
+    pub async fn web_db_backup(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        format: BackupFormat,
+    ) -> ReqwestResult<Vec<u8>> {
+        let body = web_db::backup_body(master_pwd, name, format);
+        let response = self.web_db_post(web_db::BACKUP_ENDPOINT, body).await?;
+        Ok(web_db::parse_backup(response)?)
+    }
+
+    /// Restore the backup `file` as the database `name` via the
+    /// `/web/database/restore` form
+    ///
+    /// If `copy` is set, the database UUID is regenerated (i.e., the restored
+    /// database is treated as a copy). The client's [`DbGuard`](crate::client::DbGuard)
+    /// refuses protected names, unless `confirmed` is set and the guard
+    /// [requires confirmation](crate::client::DbGuard::require_confirmation)
+    /// (as with [`confirm_destructive`](crate::client::OdooRequest::confirm_destructive)).
+    pub async fn web_db_restore(
+        &self,
+        master_pwd: &str,
+        file: &[u8],
+        name: &str,
+        copy: bool,
+        confirmed: bool,
+    ) -> ReqwestResult<()> {
+        if let Some(guard) = &self.db_guard {
+            guard.check_db("restore", name, confirmed)?;
+        }
+        let body = web_db::restore_body(master_pwd, file, name, copy);
+        let response = self.web_db_post(web_db::RESTORE_ENDPOINT, body).await?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Duplicate the database `name` as `new_name` via the
+    /// `/web/database/duplicate` form
+    pub async fn web_db_duplicate(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        new_name: &str,
+    ) -> ReqwestResult<()> {
+        let body = web_db::duplicate_body(master_pwd, name, new_name);
+        let response = self.web_db_post(web_db::DUPLICATE_ENDPOINT, body).await?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Fetch a `/web/*` page, e.g. to read a form's `csrf_token`
+    ///
+    /// Non-2xx statuses are returned as errors.
+    async fn web_get(&self, endpoint: &str) -> ReqwestResult<String> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait().await;
+        }
+        let mut request = self._impl.client.get(self.build_endpoint(endpoint));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(error)?;
+        response.text().await.map_err(error)
+    }
+
+    /// Send a `/web/database/*` form post
+    ///
+    /// Redirects are followed, and the final response is returned as-is.
+    async fn web_db_post(&self, endpoint: &str, body: WebBody) -> ReqwestResult<WebResponse> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait().await;
+        }
+        let (content_type, body) = body.encode();
+        let mut request = self
+            ._impl
+            .client
+            .post(self.build_endpoint(endpoint))
+            .header("Content-Type", content_type)
+            .body(body);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request.send().await.map_err(error)?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().await.map_err(error)?.to_vec();
+        Ok(WebResponse {
+            status,
+            content_type,
+            body,
+        })
+    }
+}
+
+impl OdooClient<Authed, ReqwestAsync> {
+    /// Check whether the session is still valid, e.g. after `restore_session()`
+    ///
+    /// This fetches the [session info](crate::service::web::SessionInfo) for the
+    /// `session_id`, and checks that it's still logged in as this user. Without
+    /// a `session_id`, a cheap `read` of the current user is sent instead (which
+    /// checks the password). If Odoo rejects the session (or credentials),
+    /// `Ok(false)` is returned; any other error is returned as-is.
+    pub async fn verify_session(&self) -> ReqwestResult<bool> {
+        let result = match self.session_id() {
+            Some(_) => self
+                .web_session_info()
+                .send()
+                .await
+                .map(|response| is_session_user(&response.data, self.auth.uid)),
+            None => self
+                .read("res.users", self.auth.uid, vec!["id".into()])
+                .send()
+                .await
+                .map(|_| true),
+        };
+        match result {
+            Err(ReqwestError::JsonRpcError(error)) if is_invalid_session(&error) => Ok(false),
+            result => result,
+        }
+    }
+
+    /// Point the client at a different server, keeping the session, and check it there
+    ///
+    /// This is for servers which share the session store (e.g. a failover node).
+    /// The session is checked with [`verify_session()`](Self::verify_session);
+    /// if it's rejected, the previous URL is restored and an `InvalidSession`
+    /// error is returned. The previous URL is restored on any other error too.
+    pub async fn switch_url_verified(&mut self, url: &str) -> ReqwestResult<()> {
+        let previous = self.replace_url(url);
+        let result = match self.verify_session().await {
+            Ok(true) => return Ok(()),
+            Ok(false) => Err(ReqwestError::InvalidSession {
+                url: self.url.clone(),
+            }),
+            Err(error) => Err(error),
+        };
+        self.url = previous;
+        result
+    }
+
+    /// Fetch the CRUD access rights of the current user on `model`
+    ///
+    /// This sends a [`check_access_rights`](OdooClient::check_access_rights)
+    /// request (without raising) for each operation, concurrently.
+    pub async fn access_matrix(&self, model: &str) -> ReqwestResult<AccessMatrix> {
+        let requests = self.access_requests(model);
+        let results =
+            access::join_all(requests.into_iter().map(|request| request.send()).collect())
+                .await
+                .into_iter()
+                .map(|response| response.map(|response| response.ok))
+                .collect::<ReqwestResult<Vec<_>>>()?;
+        Ok(AccessMatrix::from_results(&results))
+    }
+
+    /// Fetch the [`AccessMatrix`] for each of `models`, concurrently
+    ///
+    /// See [`access_matrix`](Self::access_matrix) for more info.
+    pub async fn access_matrices(
+        &self,
+        models: &[&str],
+    ) -> ReqwestResult<HashMap<String, AccessMatrix>> {
+        let requests = models
+            .iter()
+            .flat_map(|model| self.access_requests(model))
+            .map(|request| request.send())
+            .collect();
+        let results = access::join_all(requests)
+            .await
+            .into_iter()
+            .map(|response| response.map(|response| response.ok))
+            .collect::<ReqwestResult<Vec<_>>>()?;
+        Ok(models
+            .iter()
+            .zip(results.chunks(AccessMatrix::OPERATIONS.len()))
+            .map(|(model, results)| (model.to_string(), AccessMatrix::from_results(results)))
+            .collect())
+    }
+
+    /// Write `values` to `ids`, in chunks of `chunk_size`
+    ///
+    /// Each chunk is sent as a separate [`write`](OdooClient::write) request,
+    /// which avoids long-running transactions on the server. Sending stops at
+    /// the first failed chunk, and returns a [`ReqwestError::BatchError`].
+    ///
+    /// `progress` is called with `(done, total)` after each chunk.
+    pub async fn write_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        values: Map<String, Value>,
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ReqwestResult<WriteResponse> {
+        let ok = batch::run_async(ids, chunk_size, progress, |chunk| {
+            let request = self.write(model, chunk, values.clone());
+            async move { request.send().await.map(|response| response.ok) }
+        })
+        .await?;
+        Ok(WriteResponse { ok })
+    }
+
+    /// Delete `ids`, in chunks of `chunk_size`
+    ///
+    /// See [`write_batched`](Self::write_batched) for more info.
+    pub async fn unlink_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ReqwestResult<UnlinkResponse> {
+        let ok = batch::run_async(ids, chunk_size, progress, |chunk| {
+            let request = self.unlink(model, chunk);
+            async move { request.send().await.map(|response| response.ok) }
+        })
+        .await?;
+        Ok(UnlinkResponse { ok })
+    }
+
+    /// Fetch the `display_name` for `ids`, falling back to `read` on Odoo 17.0+
+    ///
+    /// This sends a [`name_get`](OdooClient::name_get) request first. If the
+    /// server doesn't have `name_get` (it was removed in Odoo 17.0), the names
+    /// are fetched with [`display_names`](OdooClient::display_names) instead.
+    /// Any other error is returned as-is.
+    pub async fn name_get_with_fallback<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+    ) -> ReqwestResult<NameGetResponse> {
+        let ids = ids.into();
+        match self.name_get(model, ids.clone()).send().await {
+            Err(ReqwestError::JsonRpcError(error)) if error.is_missing_method("name_get") => {
+                self.display_names(model, ids).send().await
+            }
+            result => result,
+        }
+    }
+
+    /// Merge the partners `merge_ids` into `keep_id`, with Odoo's merge wizard
+    ///
+    /// This creates a `base.partner.merge.automatic.wizard`, then calls its
+    /// `action_merge` method. The references to the merged partners are moved to
+    /// `keep_id`, and the merged partners are deleted. Returns the surviving
+    /// partner id (i.e., `keep_id`).
+    ///
+    /// If the wizard refuses the merge (e.g., more than 3 partners, or partners
+    /// from different companies), this returns a `PartnerMergeRejected` error
+    /// with the [`MergeRejection`](crate::client::MergeRejection) reason.
+    pub async fn merge_partners<ID: Into<OdooIds>>(
+        &self,
+        keep_id: OdooId,
+        merge_ids: ID,
+    ) -> ReqwestResult<OdooId> {
+        let values = merge::wizard_values(keep_id, merge_ids.into());
+        let response = self.create(merge::WIZARD_MODEL, values).send().await?;
+        let wizard_id = attachment::created_id(response);
+        self.execute_kw(
+            merge::WIZARD_MODEL,
+            merge::ACTION_MERGE,
+            vec![vec![wizard_id].into()],
+            Map::new(),
+        )
+        .send()
+        .await?;
+        Ok(keep_id)
+    }
+
+    /// Call the button `method` on `ids`, and sniff the result
+    ///
+    /// This sends an [`execute_kw`](OdooClient::execute_kw) request, as the
+    /// webclient does for `type="object"` buttons. Use [`MethodCallResult::is_action`]
+    /// to tell whether the server wants user interaction (e.g. a wizard).
+    pub async fn call_button<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        method: &str,
+    ) -> ReqwestResult<MethodCallResult> {
+        let ids = ids.into().into_inner();
+        let response = self
+            .execute_kw(model, method, vec![ids.into()], Map::new())
+            .send()
+            .await?;
+        Ok(MethodCallResult::from_value(response.data))
+    }
+
+    /// Apply the configuration `values` (e.g. `group_use_lead`), as the settings "Save" button does
+    ///
+    /// This creates a `res.config.settings` record with the `values`, then
+    /// calls its `execute` method. Fields which aren't given keep their current
+    /// value. Installing modules (the `module_*` fields) can take minutes, see
+    /// [`apply_settings_with_timeout()`](Self::apply_settings_with_timeout).
+    pub async fn apply_settings(&self, values: Map<String, Value>) -> ReqwestResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send().await?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id).send().await?;
+        Ok(())
+    }
+
+    /// Apply the configuration `values`, with a `timeout` for the `execute` call
+    ///
+    /// This overrides the client-wide timeout while the settings are applied
+    /// (e.g. while the `module_*` fields install their modules). See
+    /// [`apply_settings()`](Self::apply_settings).
+    pub async fn apply_settings_with_timeout(
+        &self,
+        values: Map<String, Value>,
+        timeout: Duration,
+    ) -> ReqwestResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send().await?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id)
+            .with_timeout(timeout)
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    /// Read the current configuration `fields` (e.g. `group_use_lead`)
+    ///
+    /// This sends a `default_get` request on `res.config.settings`, which
+    /// computes the values from the current config parameters, groups and
+    /// installed modules. Unknown fields are missing from the result.
+    pub async fn read_settings(&self, fields: Vec<String>) -> ReqwestResult<Map<String, Value>> {
+        let response = self.settings_read_request(fields).send().await?;
+        Ok(settings::settings_values(response))
+    }
+
+    /// Upload `data` as an `ir.attachment` on the `model` record `res_id`
+    ///
+    /// The data is base64-encoded, and sent with a [`create`](OdooClient::create)
+    /// request. If `mimetype` is `None`, Odoo guesses it from the filename and
+    /// contents. Returns the new attachment id.
+    pub async fn attachment_upload(
+        &self,
+        model: &str,
+        res_id: OdooId,
+        filename: &str,
+        data: &[u8],
+        mimetype: Option<&str>,
+    ) -> ReqwestResult<OdooId> {
+        let values = attachment::upload_values(model, res_id, filename, data, mimetype);
+        let response = self.create("ir.attachment", values).send().await?;
+        Ok(attachment::created_id(response))
+    }
+
+    /// Download the `ir.attachment` with id `attachment_id`
+    ///
+    /// The raw contents are fetched from `/web/content/<id>` (which avoids the
+    /// base64 overhead for large files), and the name and mimetype with a
+    /// [`read`](OdooClient::read) request. If there's no web session (e.g.,
+    /// after `authenticate_manual()` without a `session_id`), this falls back
+    /// to reading the base64 `datas` field instead.
+    pub async fn attachment_download(&self, attachment_id: OdooId) -> ReqwestResult<Attachment> {
+        let data = self.attachment_content(attachment_id).await?;
+        let fields = attachment::read_fields(data.is_none());
+        let response = self
+            .read("ir.attachment", attachment_id, fields)
+            .send()
+            .await?;
+        Ok(Attachment::from_read(response, data)?)
+    }
+
+    /// Fetch the raw attachment contents from `/web/content/<id>`
+    ///
+    /// Returns `None` if Odoo redirected to the login page (i.e., there's no
+    /// valid web session).
+    async fn attachment_content(&self, attachment_id: OdooId) -> ReqwestResult<Option<Vec<u8>>> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait().await;
+        }
+        let endpoint = attachment::content_endpoint(attachment_id);
+        let mut request = self._impl.client.get(self.build_endpoint(&endpoint));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        if let Some(session_id) = self.session_id() {
+            request = request.header("Cookie", format!("session_id={}", session_id));
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(error)?;
+        if response.url().path() != endpoint {
+            return Ok(None);
+        }
+        Ok(Some(response.bytes().await.map_err(error)?.to_vec()))
+    }
+
+    /// Download the PDF for `report_name` (e.g. `account.report_invoice`),
+    /// rendered for the records `res_ids`
+    ///
+    /// The PDF is fetched from [`report_pdf_url()`](OdooClient::report_pdf_url),
+    /// which relies on the web session: authenticate with `authenticate()`, or
+    /// pass a `session_id` to `authenticate_manual()`. If anything but a PDF
+    /// comes back (usually an HTML error page for an access error, or the login
+    /// page), a `ReportNotPdf` error is returned with a snippet of the body.
+    pub async fn report_pdf(
+        &self,
+        report_name: &str,
+        res_ids: &[OdooId],
+    ) -> ReqwestResult<Vec<u8>> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait().await;
+        }
+        let mut request = self
+            ._impl
+            .client
+            .get(self.report_pdf_url(report_name, res_ids));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        if let Some(session_id) = self.session_id() {
+            request = request.header("Cookie", format!("session_id={}", session_id));
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request.send().await.map_err(error)?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = response.bytes().await.map_err(error)?.to_vec();
+        match report::not_pdf_snippet(&content_type, &body) {
+            None => Ok(body),
+            Some(snippet) => Err(ReqwestError::ReportNotPdf {
+                status,
+                content_type,
+                snippet,
+            }),
+        }
+    }
+
+    /// Fetch page `page` of the `search_read` results for `domain`, with the total count
+    ///
+    /// This sends a [`search_count`](OdooClient::search_count) and a
+    /// [`search_read`](OdooClient::search_read) request, concurrently. Pages are
+    /// numbered from `0`, and a `page_size` of `0` is treated as `1`. If `order`
+    /// is `None`, the model's default order is used. See [`Page`].
+    pub async fn search_read_paged(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> ReqwestResult<Page> {
+        let page_size = page_size.max(1);
+        let (count, read) = self.page_requests(model, domain, fields, page, page_size, order);
+        let (count, read) = access::join(count.send(), read.send()).await;
+        Ok(Page::new(read?.data, count?.count, page, page_size))
+    }
+
+    /// Fetch the field definitions of `model`, from the metadata cache if possible
+    ///
+    /// Without [`with_metadata_cache`](OdooClient::with_metadata_cache), the
+    /// fields are fetched on every call. See [`ModelMetadata`] for more info.
+    pub async fn model_metadata(&self, model: &str) -> ReqwestResult<Arc<ModelMetadata>> {
+        match self.cached_metadata(model) {
+            Some(metadata) => Ok(metadata),
+            None => self.refresh_model_metadata(model).await,
+        }
+    }
+
+    /// Fetch the field definitions of `model`, skipping (and updating) the metadata cache
+    pub async fn refresh_model_metadata(&self, model: &str) -> ReqwestResult<Arc<ModelMetadata>> {
+        let response = self.metadata_request(model).send().await?;
+        Ok(self.store_metadata(model, response))
+    }
+
+    /// Stream the `search_read` results for `domain`, in pages of `batch_size`
+    ///
+    /// The records are fetched in `id` order, with keyset pagination. See
+    /// [`SearchReadStream`] for more info.
+    pub fn search_read_stream(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        batch_size: u32,
+    ) -> SearchReadStream<'_, ReqwestError> {
+        let model = model.to_string();
+        SearchReadStream::new(domain, batch_size, move |domain| {
+            let request = self
+                .search_read(&model, domain, fields.clone())
+                .limit(batch_size)
+                .order("id");
+            async move { request.send().await.map(|response| response.data) }
+        })
+    }
 }
 
 impl<'a, T> OdooRequest<'a, T, ReqwestAsync>
@@ -41,13 +904,438 @@ where
     T: JsonRpcParams + Debug + Serialize,
     T::Container<T>: Debug + Serialize,
 {
+    /// Send the request
+    ///
+    /// If a timeout was set (on the request, or on the client), the request
+    /// fails with [`ReqwestError::Timeout`] once it elapses.
+    ///
+    /// The returned future is cancel-safe: dropping it (e.g., via
+    /// `tokio::time::timeout` or `tokio::select!`) cancels the HTTP request.
+    /// Note that Odoo may still finish processing a request which was
+    /// cancelled after it was received.
+    ///
+    /// The request can also be `.await`ed directly, without calling `send()`.
     pub async fn send(self) -> ReqwestResult<T::Response> {
         Ok(self.send_internal().await?.0)
     }
 
-    async fn send_internal(self) -> ReqwestResult<(T::Response, Option<String>)> {
-        let request = self._impl.client.post(&self.url).json(&self.data);
-        let response = request.send().await?;
-        Ok((self.parse_response(&response.text().await?)?, None))
+    /// Send the request, returning the raw `result` as a [`Value`]
+    ///
+    /// This skips the typed response, e.g. for debugging a customized server
+    /// which returns a different shape. Errors are returned as with `send()`.
+    pub async fn send_raw(self) -> ReqwestResult<Value> {
+        Ok(self.send_internal().await?.0)
+    }
+
+    /// Send the request, with a timeout
+    ///
+    /// This is a shortcut for `.with_timeout(timeout).send()`, and overrides
+    /// the client-wide timeout (if any).
+    pub async fn send_with_timeout(self, timeout: Duration) -> ReqwestResult<T::Response> {
+        self.with_timeout(timeout).send().await
+    }
+
+    /// Send the request, and return how it was sent (e.g. the number of attempts)
+    ///
+    /// With a [`RetryPolicy`](crate::client::RetryPolicy), failures which didn't
+    /// reach Odoo, serialization failures, and empty responses are retried; the
+    /// attempts are counted in the [`ResponseMeta`].
+    pub async fn send_with_meta(self) -> ReqwestResult<(T::Response, ResponseMeta)> {
+        let ((response, _), meta) = self.send_internal_meta().await?;
+        Ok((response, meta))
+    }
+
+    async fn send_internal<D: Debug + DeserializeOwned>(
+        self,
+    ) -> ReqwestResult<(D, Option<String>)> {
+        Ok(self.send_internal_meta().await?.0)
+    }
+
+    async fn send_internal_meta<D: Debug + DeserializeOwned>(
+        mut self,
+    ) -> ReqwestResult<((D, Option<String>), ResponseMeta)> {
+        let (url, headers, body) = self.prepare()?;
+        let start = timer::now();
+        let mut attempt = 0;
+        loop {
+            match self.send_attempt(&url, &headers, &body, &mut attempt).await {
+                Err(ReqwestError::JsonRpcError(error)) if error.is_serialization_failure() => {
+                    match self
+                        .retry
+                        .and_then(|retry| retry.serialization_failure_delay(attempt))
+                    {
+                        Some(delay) => timer::sleep(delay).await,
+                        None => return Err(ReqwestError::JsonRpcError(error)),
+                    }
+                    attempt += 1;
+                }
+                Err(ReqwestError::EmptyResponse) => {
+                    match self
+                        .retry
+                        .and_then(|retry| retry.empty_response_delay(attempt))
+                    {
+                        Some(delay) => timer::sleep(delay).await,
+                        None => return Err(ReqwestError::EmptyResponse),
+                    }
+                    attempt += 1;
+                }
+                result => {
+                    return Ok((
+                        result?,
+                        ResponseMeta::new(attempt, timer::now().duration_since(start)),
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Send the request once (retrying any failures which didn't reach Odoo)
+    ///
+    /// `attempt` is the number of retries so far, and is incremented for each retry.
+    /// This takes `&mut self` so that the future is `Send` without the request
+    /// being `Sync`.
+    async fn send_attempt<D: Debug + DeserializeOwned>(
+        &mut self,
+        url: &str,
+        headers: &[(String, String)],
+        body: &Value,
+        attempt: &mut u32,
+    ) -> ReqwestResult<(D, Option<String>)> {
+        let span = RequestSpan::start(url, body);
+        let timer = MetricsTimer::start(&self.metrics_hooks, url, body);
+        if let Some(session_id) = &self.session_id {
+            jar_restore_session_id(&self._impl.jar, url, session_id);
+        }
+        let (mut response, xmlrpc, start) = loop {
+            if let Some(limiter) = &self.rate_limit {
+                limiter.wait().await;
+            }
+            let (mut request, xmlrpc) = self._impl.post(url, body)?;
+            if let Some(timeout) = self.timeout {
+                request = request.timeout(timeout);
+            }
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            #[cfg(feature = "compression")]
+            {
+                request = request.header("Accept-Encoding", compression::ACCEPT_ENCODING);
+            }
+            let start = Instant::now();
+            let response = match &self.upload_progress {
+                Some(hook) => {
+                    let mut request = request
+                        .build()
+                        .map_err(|err| ReqwestError::from_reqwest(err, start))?;
+                    progress::track_async(&mut request, hook.clone());
+                    self._impl.client.execute(request).await
+                }
+                None => request.send().await,
+            };
+            let retryable = match &response {
+                Ok(response) => retry::is_retryable_status(response.status().as_u16()),
+                Err(err) => err.is_connect(),
+            };
+            match self.retry.and_then(|retry| retry.delay(*attempt)) {
+                Some(delay) if retryable => timer::sleep(delay).await,
+                _ => {
+                    let response =
+                        response.map_err(|err| ReqwestError::from_reqwest(err, start))?;
+                    break (response, xmlrpc, start);
+                }
+            }
+            *attempt += 1;
+        };
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let status = response.status().as_u16();
+        #[cfg(feature = "compression")]
+        let encoding = response
+            .headers()
+            .get("Content-Encoding")
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let mut buffer = LimitedBody::new(self.max_response_size, response.content_length())?
+            .with_progress(self.download_progress.clone());
+        while let Some(chunk) = response.chunk().await.map_err(error)? {
+            buffer.push(&chunk)?;
+        }
+        let response = buffer.into_inner();
+        #[cfg(feature = "compression")]
+        let response =
+            compression::decompress(encoding.as_deref(), response, self.max_response_size)?;
+        self.on_response(status, &String::from_utf8_lossy(&response));
+        let response = self._impl.decode(xmlrpc, response, body)?;
+        let result = self.parse_response(&response);
+        span.finish(&response, &result);
+        timer.finish(status, &response, &result);
+        Ok((result?, jar_session_id(&self._impl.jar, url)))
+    }
+}
+
+/// Requests can be `.await`ed directly, as a shortcut for `.send().await`
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # async fn test() -> odoo_api::client::Result<()> {
+/// # use odoo_api::{OdooClient, jvec};
+/// # let client = OdooClient::new_reqwest_async("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let ids = client.search("res.partner", jvec![]).limit(10).await?;
+/// # Ok(())
+/// # }
+/// ```
+impl<'a, T> IntoFuture for OdooRequest<'a, T, ReqwestAsync>
+where
+    T: JsonRpcParams + Debug + Serialize + Send + 'a,
+    T::Container<T>: Debug + Serialize + Send,
+    T::Response: Send,
+{
+    type Output = ReqwestResult<T::Response>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::http_impl::test_server::{serve, serve_delayed};
+    use crate::client::SessionPassword;
+    use serde_json::json;
+    use std::thread;
+    use tiny_http::{Response, Server};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: &T) {}
+    fn assert_clone<T: Clone>() {}
+
+    #[test]
+    fn saved_session() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve(vec![
+            json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": 2, "is_admin": true}}),
+            json!({"jsonrpc": "2.0", "id": 2, "result": {"uid": 2}}),
+            json!({"jsonrpc": "2.0", "id": 3, "result": {"uid": null}}),
+        ]);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(async {
+            // the `session_id` is read back from the cookie jar
+            let client = OdooClient::new_reqwest_async(&url)?
+                .authenticate("some-database", "admin", "password")
+                .await?;
+            let saved = client.to_saved(SessionPassword::Omit);
+            assert_eq!(saved.session_id.as_deref(), Some("abc123"));
+
+            // a new client (with an empty cookie jar) sends the restored session
+            let client = OdooClient::new_reqwest_async(&url)?.restore_session(saved);
+            assert!(client.verify_session().await?);
+            assert!(!client.verify_session().await?);
+            Ok::<_, Box<dyn std::error::Error>>(())
+        })?;
+
+        let received = handle.join().unwrap();
+        for (path, _body, cookie) in &received[1..] {
+            assert_eq!(path, "/web/session/get_session_info");
+            assert_eq!(cookie.as_deref(), Some("session_id=abc123"));
+        }
+
+        Ok(())
+    }
+
+    /// The async client should be shareable across tasks (e.g. with `tokio::spawn`)
+    #[test]
+    fn send_sync() -> Result<(), reqwest::Error> {
+        assert_send_sync::<OdooClient<NotAuthed, ReqwestAsync>>();
+        assert_send_sync::<OdooClient<Authed, ReqwestAsync>>();
+        assert_clone::<OdooClient<Authed, ReqwestAsync>>();
+
+        // the request future must also be `Send`
+        let client = OdooClient::new_reqwest_async("http://localhost:8069")?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+        let future = client.search("res.partner", jvec![]).send();
+        assert_send(&future);
+        let future = client.search("res.partner", jvec![]).into_future();
+        assert_send(&future);
+
+        let future = client.write_batched("res.partner", &[1, 2], jmap! {}, 1, None);
+        assert_send(&future);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clone_shares_id() -> Result<(), reqwest::Error> {
+        let client = OdooClient::new_reqwest_async("http://localhost:8069")?;
+        let cloned = client.clone();
+
+        assert_eq!(client.next_id(), 1);
+        assert_eq!(cloned.next_id(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clone_shares_rate_limit() -> Result<(), reqwest::Error> {
+        let mut client = OdooClient::new_reqwest_async("http://localhost:8069")?;
+        client.with_rate_limit(1, Duration::from_secs(1));
+        let cloned = client.clone();
+
+        // the token reserved by `client` isn't available to `cloned`
+        let now = Instant::now();
+        let reserve = |client: &OdooClient<NotAuthed, ReqwestAsync>| {
+            client.rate_limit.as_ref().unwrap().reserve(now)
+        };
+        assert_eq!(reserve(&client), Duration::ZERO);
+        assert_eq!(reserve(&cloned), Duration::from_secs(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn detached() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve_delayed(3, Duration::from_millis(50));
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let client = OdooClient::new_reqwest_async(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+        let requests = (0..3)
+            .map(|_| client.search("res.partner", jvec![]).into_detached())
+            .collect::<Vec<_>>();
+
+        // the requests outlive the client, and can be spawned
+        drop(client);
+        runtime.block_on(async {
+            let tasks = requests
+                .into_iter()
+                .map(|request| tokio::spawn(request.send()))
+                .collect::<Vec<_>>();
+            for task in tasks {
+                let response = task.await.unwrap()?;
+                assert!(response.records.is_empty());
+            }
+            Ok::<_, ReqwestError>(())
+        })?;
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve_delayed(2, Duration::from_millis(500));
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let mut client = OdooClient::new_reqwest_async(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+
+        // per-request
+        let err = runtime
+            .block_on(
+                client
+                    .search("res.partner", jvec![])
+                    .send_with_timeout(Duration::from_millis(100)),
+            )
+            .unwrap_err();
+        assert!(
+            matches!(err, ReqwestError::Timeout { elapsed } if elapsed >= Duration::from_millis(100)),
+            "{:?}",
+            err
+        );
+
+        // client-wide
+        client.with_timeout(Duration::from_millis(100));
+        let err = runtime
+            .block_on(client.search("res.partner", jvec![]).send())
+            .unwrap_err();
+        assert!(matches!(err, ReqwestError::Timeout { .. }), "{:?}", err);
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn progress() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::service::db::{DumpFormat, RestoreType};
+        use std::sync::{Arc, Mutex};
+
+        let backup = "A".repeat(3 * 1024 * 1024);
+        let dump = json!({"jsonrpc": "2.0", "id": 1, "result": backup}).to_string();
+        let dump_len = dump.len() as u64;
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            // `db_restore`, then `db_dump`
+            let mut request = server.recv().unwrap();
+            let mut body = Vec::new();
+            request.as_reader().read_to_end(&mut body).unwrap();
+            let response = json!({"jsonrpc": "2.0", "id": 1, "result": true});
+            request
+                .respond(Response::from_string(response.to_string()))
+                .unwrap();
+
+            let request = server.recv().unwrap();
+            let response = Response::from_string(dump).with_chunked_threshold(usize::MAX);
+            request.respond(response).unwrap();
+            body.len() as u64
+        });
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let client = OdooClient::new_reqwest_async(&url)?;
+        let uploads = Arc::new(Mutex::new(Vec::new()));
+        let downloads = Arc::new(Mutex::new(Vec::new()));
+        let (inner_up, inner_down) = (uploads.clone(), downloads.clone());
+
+        runtime.block_on(async {
+            client
+                .db_restore("master", "restored", &backup, RestoreType::Copy)
+                .on_upload_progress(move |sent, total| inner_up.lock().unwrap().push((sent, total)))
+                .send()
+                .await?;
+            let response = client
+                .db_dump("master", "restored", DumpFormat::Zip)
+                .on_download_progress(move |received, total| {
+                    inner_down.lock().unwrap().push((received, total))
+                })
+                .send()
+                .await?;
+            assert_eq!(response.b64_bytes.len(), backup.len());
+            Ok::<_, ReqwestError>(())
+        })?;
+
+        // each call reports more bytes, ending at the total
+        let uploaded = handle.join().unwrap();
+        for (calls, total) in [(uploads, uploaded), (downloads, dump_len)] {
+            let calls = calls.lock().unwrap();
+            assert!(calls.len() > 1, "{:?}", calls);
+            assert!(calls.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert!(calls.iter().all(|(_, t)| *t == Some(total)));
+            assert_eq!(calls.last().unwrap().0, total);
+        }
+
+        Ok(())
     }
 }
@@ -1,12 +1,114 @@
-use crate::client::error::{ReqwestAuthResult, ReqwestError, ReqwestResult};
+use crate::client::access::AccessMatrix;
+use crate::client::attachment::{self, Attachment};
+use crate::client::batch::{self, BatchProgress};
+use crate::client::body::LimitedBody;
+use crate::client::button::MethodCallResult;
+#[cfg(feature = "compression")]
+use crate::client::compression::{self, CompressionLevel, RequestCompression};
+use crate::client::db_wait::{self, DbWait, Probe};
+use crate::client::error::{ReqwestAuthError, ReqwestAuthResult, ReqwestError, ReqwestResult};
+use crate::client::health::HealthReport;
+use crate::client::login_form;
+use crate::client::merge;
+use crate::client::metadata::ModelMetadata;
+use crate::client::metrics::MetricsTimer;
+use crate::client::odoo_client::single_database;
+use crate::client::page::Page;
+use crate::client::progress;
+use crate::client::report;
+use crate::client::retry::{self, ResponseMeta};
+use crate::client::session::{
+    is_invalid_session, is_session_user, jar_restore_session_id, jar_session_id,
+};
+use crate::client::settings;
+use crate::client::stream::SearchReadIter;
+use crate::client::totp;
+use crate::client::trace::RequestSpan;
+use crate::client::web_db::{self, BackupFormat, WebBody, WebResponse};
 use crate::client::{AuthState, Authed, NotAuthed, OdooClient, OdooRequest, RequestImpl};
-use crate::jsonrpc::JsonRpcParams;
-use reqwest::blocking::Client;
+use crate::jsonrpc::{JsonRpcParams, OdooId, OdooIds};
+use crate::service::common::LoginResponse;
+use crate::service::db::CreateDatabase;
+use crate::service::orm::{NameGetResponse, UnlinkResponse, WriteResponse};
+use crate::service::web::SessionAuthenticateResponse;
+use crate::OdooVersion;
+use reqwest::blocking::{Client, ClientBuilder, RequestBuilder};
+use reqwest::cookie::Jar;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+#[derive(Clone)]
 pub struct ReqwestBlocking {
     client: Client,
+
+    /// The cookie store, shared with the `client`
+    ///
+    /// The `session_id` is read from here after each request, and a restored
+    /// session is added to it (see [`jar_restore_session_id`]).
+    jar: Arc<Jar>,
+
+    /// Send `/jsonrpc` requests via XML-RPC instead
+    #[cfg(feature = "xmlrpc")]
+    xmlrpc: bool,
+
+    /// Gzip large request bodies
+    #[cfg(feature = "compression")]
+    compression: Option<RequestCompression>,
+}
+
+impl ReqwestBlocking {
+    /// Build the POST request for `url`
+    ///
+    /// With the XML-RPC transport enabled, `/jsonrpc` requests are re-encoded
+    /// for the matching `/xmlrpc/2/` endpoint (and `true` is returned).
+    #[cfg_attr(not(feature = "xmlrpc"), allow(clippy::unnecessary_wraps))]
+    fn post(&self, url: &str, body: &Value) -> ReqwestResult<(RequestBuilder, bool)> {
+        #[cfg(feature = "xmlrpc")]
+        if self.xmlrpc {
+            if let Some((url, xml)) = crate::xmlrpc::encode_request(url, body)? {
+                let request = self
+                    .client
+                    .post(url)
+                    .header("Content-Type", "text/xml")
+                    .body(xml);
+                return Ok((request, true));
+            }
+        }
+        #[cfg(feature = "compression")]
+        if let Some(compression) = &self.compression {
+            let json = serde_json::to_vec(body)?;
+            let request = self
+                .client
+                .post(url)
+                .header("Content-Type", "application/json");
+            let request = match compression.compress(&json) {
+                Some(gzip) => request.header("Content-Encoding", "gzip").body(gzip),
+                None => request.body(json),
+            };
+            return Ok((request, false));
+        }
+        Ok((self.client.post(url).json(body), false))
+    }
+
+    /// Convert the `response` back to JSON-RPC, if the request was sent via XML-RPC
+    #[cfg_attr(
+        not(feature = "xmlrpc"),
+        allow(unused_variables, clippy::unnecessary_wraps)
+    )]
+    fn decode(&self, xmlrpc: bool, response: Vec<u8>, body: &Value) -> ReqwestResult<Vec<u8>> {
+        #[cfg(feature = "xmlrpc")]
+        if xmlrpc {
+            let response = String::from_utf8_lossy(&response);
+            let decoded = crate::xmlrpc::decode_response(&response, &body["id"])?;
+            return Ok(decoded.to_string().into_bytes());
+        }
+        Ok(response)
+    }
 }
 impl RequestImpl for ReqwestBlocking {
     type Error = ReqwestError;
@@ -14,9 +116,29 @@ impl RequestImpl for ReqwestBlocking {
 
 impl OdooClient<NotAuthed, ReqwestBlocking> {
     pub fn new_reqwest_blocking(url: &str) -> Result<Self, reqwest::Error> {
-        let client = Client::builder().cookie_store(true).build()?;
+        Self::new_reqwest_blocking_with(url, Client::builder())
+    }
+
+    /// Build the client from a `reqwest` client `builder` (with the cookie
+    /// store enabled)
+    pub(crate) fn new_reqwest_blocking_with(
+        url: &str,
+        builder: ClientBuilder,
+    ) -> Result<Self, reqwest::Error> {
+        let jar = Arc::new(Jar::default());
+        let client = builder.cookie_provider(jar.clone()).build()?;
 
-        Ok(Self::new(url, ReqwestBlocking { client }))
+        Ok(Self::new(
+            url,
+            ReqwestBlocking {
+                client,
+                jar,
+                #[cfg(feature = "xmlrpc")]
+                xmlrpc: false,
+                #[cfg(feature = "compression")]
+                compression: None,
+            },
+        ))
     }
 }
 
@@ -25,7 +147,7 @@ where
     S: AuthState,
 {
     pub fn authenticate(
-        mut self,
+        self,
         db: &str,
         login: &str,
         password: &str,
@@ -34,6 +156,700 @@ where
         let (response, session_id) = request.send_internal()?;
         Ok(self.parse_auth_response(db, login, password, response, session_id)?)
     }
+
+    /// Authenticate without knowing the database name
+    ///
+    /// The databases are listed with [`db_list`](crate::service::db::List) first.
+    /// If the server has a single database, it's used to [`authenticate()`](Self::authenticate);
+    /// if it has several, a `MultipleDatabases` error is returned. If listing is
+    /// disabled, the request is sent without a database, and the one Odoo picked
+    /// (e.g. with `dbfilter`) is read from the session info.
+    pub fn authenticate_auto(
+        self,
+        login: &str,
+        password: &str,
+    ) -> ReqwestAuthResult<OdooClient<Authed, ReqwestBlocking>> {
+        let databases = match self.db_list(false).send() {
+            Ok(response) => response.databases,
+            Err(ReqwestError::DbListingDisabled) => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(db) = single_database(databases)? {
+            return self.authenticate(&db, login, password);
+        }
+        let request = self.get_auth_request("", login, password);
+        let (response, session_id) = request.send_internal()?;
+        Ok(self.parse_auth_auto_response(login, password, response, session_id)?)
+    }
+
+    /// Authenticate a user with two-factor authentication enabled
+    ///
+    /// This sends the usual `/web/session/authenticate` request, then completes
+    /// the login by posting `totp_code` to the `/web/login/totp` form (as the
+    /// webclient does). If the user doesn't have two-factor authentication
+    /// enabled, the code is ignored.
+    ///
+    /// Returns [`ReqwestAuthError::TwoFactorRejected`] if the code is wrong (or
+    /// expired).
+    pub fn authenticate_totp(
+        self,
+        db: &str,
+        login: &str,
+        password: &str,
+        totp_code: &str,
+    ) -> ReqwestAuthResult<OdooClient<Authed, ReqwestBlocking>> {
+        let request = self.get_auth_request(db, login, password);
+        let (response, session_id): (SessionAuthenticateResponse, _) = request.send_internal()?;
+        if !totp::is_totp_required(&response.data) {
+            return Ok(self.parse_auth_response(db, login, password, response, session_id)?);
+        }
+
+        // the partial session is kept in the cookie store
+        let page = self.web_get(totp::TOTP_ENDPOINT)?;
+        let csrf_token =
+            totp::csrf_token(&page).ok_or_else(|| ReqwestError::MalformedResponse {
+                reason: format!("no `csrf_token` on the {} form", totp::TOTP_ENDPOINT),
+            })?;
+        self.web_db_post(totp::TOTP_ENDPOINT, totp::totp_body(&csrf_token, totp_code))?;
+
+        let (response, session_id): (SessionAuthenticateResponse, _) =
+            self.web_session_info().send_internal()?;
+        if totp::is_totp_required(&response.data) {
+            return Err(ReqwestAuthError::TwoFactorRejected);
+        }
+        Ok(self.parse_auth_response(db, login, password, response, session_id)?)
+    }
+
+    /// Authenticate via the `/web/login` form, rather than `/web/session/authenticate`
+    ///
+    /// This is a fallback for servers which reject programmatic logins (e.g.
+    /// odoo.com SaaS trial databases). The form is fetched for its `csrf_token`,
+    /// the credentials are posted (as the browser does), then the `uid` is read
+    /// from [`web_session_info`](crate::service::web::SessionInfo). The
+    /// session is kept in the cookie store, as with [`authenticate`](Self::authenticate).
+    ///
+    /// Returns [`ReqwestAuthError::LoginRejected`] if the form shows an error
+    /// (e.g. the password is wrong). If the user has two-factor authentication
+    /// enabled, [`ReqwestAuthError::TwoFactorRequired`] is returned instead.
+    pub fn authenticate_via_login_form(
+        self,
+        db: &str,
+        login: &str,
+        password: &str,
+    ) -> ReqwestAuthResult<OdooClient<Authed, ReqwestBlocking>> {
+        let page = self.web_get(&login_form::login_endpoint(db))?;
+        let csrf_token =
+            totp::csrf_token(&page).ok_or_else(|| ReqwestError::MalformedResponse {
+                reason: format!("no `csrf_token` on the {} form", login_form::LOGIN_ENDPOINT),
+            })?;
+        let body = login_form::login_body(&csrf_token, db, login, password);
+        let response = self.web_db_post(login_form::LOGIN_ENDPOINT, body)?;
+        if let Some(message) = login_form::login_error(&response) {
+            return Err(ReqwestAuthError::LoginRejected { message });
+        }
+
+        let (response, session_id): (SessionAuthenticateResponse, _) =
+            match self.web_session_info().send_internal() {
+                Ok(response) => response,
+                Err(ReqwestError::JsonRpcError(error)) if is_invalid_session(&error) => {
+                    return Err(ReqwestAuthError::LoginRejected {
+                        message: "no session was opened".into(),
+                    })
+                }
+                Err(err) => return Err(err.into()),
+            };
+        Ok(self.parse_auth_response(db, login, password, response, session_id)?)
+    }
+
+    /// Send `/jsonrpc` requests via the XML-RPC endpoints (`/xmlrpc/2/*`) instead
+    ///
+    /// This is useful for servers which have `/jsonrpc` disabled. See the
+    /// [`xmlrpc`](crate::xmlrpc) module for more info.
+    #[cfg(feature = "xmlrpc")]
+    pub fn with_xmlrpc_transport(&mut self, enabled: bool) -> &mut Self {
+        self._impl.xmlrpc = enabled;
+        self
+    }
+
+    /// Gzip JSON-RPC request bodies larger than `threshold` bytes
+    ///
+    /// Compressed requests are sent with `Content-Encoding: gzip`. Odoo doesn't
+    /// decode these itself, so this only works behind a reverse proxy which
+    /// decompresses request bodies. XML-RPC requests aren't compressed.
+    ///
+    /// With the `compression` feature, gzip and deflate responses are decoded
+    /// either way.
+    #[cfg(feature = "compression")]
+    pub fn with_request_compression(
+        &mut self,
+        level: CompressionLevel,
+        threshold: usize,
+    ) -> &mut Self {
+        self._impl.compression = Some(RequestCompression { level, threshold });
+        self
+    }
+
+    /// Fetch the server version
+    ///
+    /// The version is fetched with [`common_version`](crate::service::common::Version)
+    /// on the first call, then cached on the client. If that fails (e.g. because
+    /// `/jsonrpc` is blocked), [`web_version_info`](crate::service::web::WebVersionInfo)
+    /// is tried instead.
+    pub fn server_version(&self) -> ReqwestResult<OdooVersion> {
+        if let Some(version) = self.version() {
+            return Ok(version);
+        }
+        let response = match self.get_version_request().send() {
+            Ok(response) => response,
+            // some deployments block `/jsonrpc`, but the webclient still needs this
+            Err(err) => self.web_version_info().send().map_err(|_| err)?,
+        };
+        Ok(self.parse_version_response(response))
+    }
+
+    /// Check whether the server version is at least `major.minor`
+    ///
+    /// See [`OdooVersion::at_least`] for more info.
+    pub fn version_at_least(&self, major: u32, minor: u32) -> ReqwestResult<bool> {
+        Ok(self.server_version()?.at_least(major, minor))
+    }
+
+    /// Probe the server, e.g. for a liveness/readiness check
+    ///
+    /// This sends a [`common_version`](crate::service::common::Version) request
+    /// (timed as the report `latency`), then a [`db_exist`](crate::service::db::DbExist)
+    /// request if `db` is passed. Failures are recorded in the [`HealthReport`]
+    /// rather than returned as an error.
+    pub fn health_check(&self, db: Option<&str>) -> HealthReport {
+        let start = Instant::now();
+        let version = self.get_version_request().send();
+        let latency = start.elapsed();
+        let database_exists = db.map(|db| self.db_exist(db).send().map(|response| response.exists));
+        HealthReport::new(version, database_exists, latency)
+    }
+
+    /// Create a new database, then wait until it's ready to use
+    ///
+    /// Odoo responds to [`db_create_database`](crate::service::db::CreateDatabase)
+    /// once the database is created, but the registry may take a while longer
+    /// to load (especially with large templates). This method then polls
+    /// [`db_exist`](crate::service::db::DbExist), followed by a
+    /// [`common_login`](crate::service::common::Login) with the new admin
+    /// credentials, every `wait.interval`. It returns once the login succeeds,
+    /// or with a [`ReqwestError::DatabaseNotReady`] (holding the last failure) after
+    /// `wait.timeout`.
+    pub fn db_create_database_and_wait(
+        &self,
+        create: CreateDatabase,
+        wait: DbWait,
+    ) -> ReqwestResult<LoginResponse> {
+        self.get_create_database_request(&create).send()?;
+        db_wait::run_blocking(&create.db_name, wait, || {
+            match self.db_exist(&create.db_name).send() {
+                Ok(response) if !response.exists => return Probe::Missing,
+                Err(err) => return Probe::Failed(err),
+                Ok(_) => {}
+            }
+            match self.get_login_request(&create).send() {
+                Ok(response) => Probe::Ready(response),
+                Err(err) => Probe::Failed(err),
+            }
+        })
+    }
+
+    /// Back up the database `name` via the `/web/database/backup` form
+    ///
+    /// This is useful when the JSON-RPC `db` service is blocked (otherwise, see
+    /// [`db_dump`](crate::service::db::Dump)). Returns the raw backup.
+    pub fn web_db_backup(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        format: BackupFormat,
+    ) -> ReqwestResult<Vec<u8>> {
+        let body = web_db::backup_body(master_pwd, name, format);
+        let response = self.web_db_post(web_db::BACKUP_ENDPOINT, body)?;
+        Ok(web_db::parse_backup(response)?)
+    }
+
+    /// Restore the backup `file` as the database `name` via the
+    /// `/web/database/restore` form
+    ///
+    /// If `copy` is set, the database UUID is regenerated (i.e., the restored
+    /// database is treated as a copy). The client's [`DbGuard`](crate::client::DbGuard)
+    /// refuses protected names, unless `confirmed` is set and the guard
+    /// [requires confirmation](crate::client::DbGuard::require_confirmation)
+    /// (as with [`confirm_destructive`](crate::client::OdooRequest::confirm_destructive)).
+    pub fn web_db_restore(
+        &self,
+        master_pwd: &str,
+        file: &[u8],
+        name: &str,
+        copy: bool,
+        confirmed: bool,
+    ) -> ReqwestResult<()> {
+        if let Some(guard) = &self.db_guard {
+            guard.check_db("restore", name, confirmed)?;
+        }
+        let body = web_db::restore_body(master_pwd, file, name, copy);
+        let response = self.web_db_post(web_db::RESTORE_ENDPOINT, body)?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Duplicate the database `name` as `new_name` via the
+    /// `/web/database/duplicate` form
+    pub fn web_db_duplicate(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        new_name: &str,
+    ) -> ReqwestResult<()> {
+        let body = web_db::duplicate_body(master_pwd, name, new_name);
+        let response = self.web_db_post(web_db::DUPLICATE_ENDPOINT, body)?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Fetch a `/web/*` page, e.g. to read a form's `csrf_token`
+    ///
+    /// Non-2xx statuses are returned as errors.
+    fn web_get(&self, endpoint: &str) -> ReqwestResult<String> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let mut request = self._impl.client.get(self.build_endpoint(endpoint));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(error)?;
+        response.text().map_err(error)
+    }
+
+    /// Send a `/web/database/*` form post
+    ///
+    /// Redirects are followed, and the final response is returned as-is.
+    fn web_db_post(&self, endpoint: &str, body: WebBody) -> ReqwestResult<WebResponse> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let (content_type, body) = body.encode();
+        let mut request = self
+            ._impl
+            .client
+            .post(self.build_endpoint(endpoint))
+            .header("Content-Type", content_type)
+            .body(body);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request.send().map_err(error)?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.bytes().map_err(error)?.to_vec();
+        Ok(WebResponse {
+            status,
+            content_type,
+            body,
+        })
+    }
+}
+
+impl OdooClient<Authed, ReqwestBlocking> {
+    /// Check whether the session is still valid, e.g. after `restore_session()`
+    ///
+    /// This fetches the [session info](crate::service::web::SessionInfo) for the
+    /// `session_id`, and checks that it's still logged in as this user. Without
+    /// a `session_id`, a cheap `read` of the current user is sent instead (which
+    /// checks the password). If Odoo rejects the session (or credentials),
+    /// `Ok(false)` is returned; any other error is returned as-is.
+    pub fn verify_session(&self) -> ReqwestResult<bool> {
+        let result = match self.session_id() {
+            Some(_) => self
+                .web_session_info()
+                .send()
+                .map(|response| is_session_user(&response.data, self.auth.uid)),
+            None => self
+                .read("res.users", self.auth.uid, vec!["id".into()])
+                .send()
+                .map(|_| true),
+        };
+        match result {
+            Err(ReqwestError::JsonRpcError(error)) if is_invalid_session(&error) => Ok(false),
+            result => result,
+        }
+    }
+
+    /// Point the client at a different server, keeping the session, and check it there
+    ///
+    /// This is for servers which share the session store (e.g. a failover node).
+    /// The session is checked with [`verify_session()`](Self::verify_session);
+    /// if it's rejected, the previous URL is restored and an `InvalidSession`
+    /// error is returned. The previous URL is restored on any other error too.
+    pub fn switch_url_verified(&mut self, url: &str) -> ReqwestResult<()> {
+        let previous = self.replace_url(url);
+        let result = match self.verify_session() {
+            Ok(true) => return Ok(()),
+            Ok(false) => Err(ReqwestError::InvalidSession {
+                url: self.url.clone(),
+            }),
+            Err(error) => Err(error),
+        };
+        self.url = previous;
+        result
+    }
+
+    /// Fetch the CRUD access rights of the current user on `model`
+    ///
+    /// This sends a [`check_access_rights`](OdooClient::check_access_rights)
+    /// request (without raising) for each operation, in turn.
+    pub fn access_matrix(&self, model: &str) -> ReqwestResult<AccessMatrix> {
+        let results = self
+            .access_requests(model)
+            .into_iter()
+            .map(|request| request.send().map(|response| response.ok))
+            .collect::<ReqwestResult<Vec<_>>>()?;
+        Ok(AccessMatrix::from_results(&results))
+    }
+
+    /// Fetch the [`AccessMatrix`] for each of `models`
+    ///
+    /// See [`access_matrix`](Self::access_matrix) for more info.
+    pub fn access_matrices(&self, models: &[&str]) -> ReqwestResult<HashMap<String, AccessMatrix>> {
+        models
+            .iter()
+            .map(|model| Ok((model.to_string(), self.access_matrix(model)?)))
+            .collect()
+    }
+
+    /// Write `values` to `ids`, in chunks of `chunk_size`
+    ///
+    /// Each chunk is sent as a separate [`write`](OdooClient::write) request,
+    /// which avoids long-running transactions on the server. Sending stops at
+    /// the first failed chunk, and returns a [`ReqwestError::BatchError`].
+    ///
+    /// `progress` is called with `(done, total)` after each chunk.
+    pub fn write_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        values: Map<String, Value>,
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ReqwestResult<WriteResponse> {
+        let ok = batch::run_blocking(ids, chunk_size, progress, |chunk| {
+            self.write(model, chunk, values.clone())
+                .send()
+                .map(|response| response.ok)
+        })?;
+        Ok(WriteResponse { ok })
+    }
+
+    /// Delete `ids`, in chunks of `chunk_size`
+    ///
+    /// See [`write_batched`](Self::write_batched) for more info.
+    pub fn unlink_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> ReqwestResult<UnlinkResponse> {
+        let ok = batch::run_blocking(ids, chunk_size, progress, |chunk| {
+            self.unlink(model, chunk).send().map(|response| response.ok)
+        })?;
+        Ok(UnlinkResponse { ok })
+    }
+
+    /// Fetch the `display_name` for `ids`, falling back to `read` on Odoo 17.0+
+    ///
+    /// This sends a [`name_get`](OdooClient::name_get) request first. If the
+    /// server doesn't have `name_get` (it was removed in Odoo 17.0), the names
+    /// are fetched with [`display_names`](OdooClient::display_names) instead.
+    /// Any other error is returned as-is.
+    pub fn name_get_with_fallback<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+    ) -> ReqwestResult<NameGetResponse> {
+        let ids = ids.into();
+        match self.name_get(model, ids.clone()).send() {
+            Err(ReqwestError::JsonRpcError(error)) if error.is_missing_method("name_get") => {
+                self.display_names(model, ids).send()
+            }
+            result => result,
+        }
+    }
+
+    /// Merge the partners `merge_ids` into `keep_id`, with Odoo's merge wizard
+    ///
+    /// This creates a `base.partner.merge.automatic.wizard`, then calls its
+    /// `action_merge` method. The references to the merged partners are moved to
+    /// `keep_id`, and the merged partners are deleted. Returns the surviving
+    /// partner id (i.e., `keep_id`).
+    ///
+    /// If the wizard refuses the merge (e.g., more than 3 partners, or partners
+    /// from different companies), this returns a `PartnerMergeRejected` error
+    /// with the [`MergeRejection`](crate::client::MergeRejection) reason.
+    pub fn merge_partners<ID: Into<OdooIds>>(
+        &self,
+        keep_id: OdooId,
+        merge_ids: ID,
+    ) -> ReqwestResult<OdooId> {
+        let values = merge::wizard_values(keep_id, merge_ids.into());
+        let response = self.create(merge::WIZARD_MODEL, values).send()?;
+        let wizard_id = attachment::created_id(response);
+        self.execute_kw(
+            merge::WIZARD_MODEL,
+            merge::ACTION_MERGE,
+            vec![vec![wizard_id].into()],
+            Map::new(),
+        )
+        .send()?;
+        Ok(keep_id)
+    }
+
+    /// Call the button `method` on `ids`, and sniff the result
+    ///
+    /// This sends an [`execute_kw`](OdooClient::execute_kw) request, as the
+    /// webclient does for `type="object"` buttons. Use [`MethodCallResult::is_action`]
+    /// to tell whether the server wants user interaction (e.g. a wizard).
+    pub fn call_button<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        method: &str,
+    ) -> ReqwestResult<MethodCallResult> {
+        let ids = ids.into().into_inner();
+        let response = self
+            .execute_kw(model, method, vec![ids.into()], Map::new())
+            .send()?;
+        Ok(MethodCallResult::from_value(response.data))
+    }
+
+    /// Apply the configuration `values` (e.g. `group_use_lead`), as the settings "Save" button does
+    ///
+    /// This creates a `res.config.settings` record with the `values`, then
+    /// calls its `execute` method. Fields which aren't given keep their current
+    /// value. Installing modules (the `module_*` fields) can take minutes, see
+    /// [`apply_settings_with_timeout()`](Self::apply_settings_with_timeout).
+    pub fn apply_settings(&self, values: Map<String, Value>) -> ReqwestResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send()?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id).send()?;
+        Ok(())
+    }
+
+    /// Apply the configuration `values`, with a `timeout` for the `execute` call
+    ///
+    /// This overrides the client-wide timeout while the settings are applied
+    /// (e.g. while the `module_*` fields install their modules). See
+    /// [`apply_settings()`](Self::apply_settings).
+    pub fn apply_settings_with_timeout(
+        &self,
+        values: Map<String, Value>,
+        timeout: Duration,
+    ) -> ReqwestResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send()?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id)
+            .with_timeout(timeout)
+            .send()?;
+        Ok(())
+    }
+
+    /// Read the current configuration `fields` (e.g. `group_use_lead`)
+    ///
+    /// This sends a `default_get` request on `res.config.settings`, which
+    /// computes the values from the current config parameters, groups and
+    /// installed modules. Unknown fields are missing from the result.
+    pub fn read_settings(&self, fields: Vec<String>) -> ReqwestResult<Map<String, Value>> {
+        let response = self.settings_read_request(fields).send()?;
+        Ok(settings::settings_values(response))
+    }
+
+    /// Upload `data` as an `ir.attachment` on the `model` record `res_id`
+    ///
+    /// The data is base64-encoded, and sent with a [`create`](OdooClient::create)
+    /// request. If `mimetype` is `None`, Odoo guesses it from the filename and
+    /// contents. Returns the new attachment id.
+    pub fn attachment_upload(
+        &self,
+        model: &str,
+        res_id: OdooId,
+        filename: &str,
+        data: &[u8],
+        mimetype: Option<&str>,
+    ) -> ReqwestResult<OdooId> {
+        let values = attachment::upload_values(model, res_id, filename, data, mimetype);
+        let response = self.create("ir.attachment", values).send()?;
+        Ok(attachment::created_id(response))
+    }
+
+    /// Download the `ir.attachment` with id `attachment_id`
+    ///
+    /// The raw contents are fetched from `/web/content/<id>` (which avoids the
+    /// base64 overhead for large files), and the name and mimetype with a
+    /// [`read`](OdooClient::read) request. If there's no web session (e.g.,
+    /// after `authenticate_manual()` without a `session_id`), this falls back
+    /// to reading the base64 `datas` field instead.
+    pub fn attachment_download(&self, attachment_id: OdooId) -> ReqwestResult<Attachment> {
+        let data = self.attachment_content(attachment_id)?;
+        let fields = attachment::read_fields(data.is_none());
+        let response = self.read("ir.attachment", attachment_id, fields).send()?;
+        Ok(Attachment::from_read(response, data)?)
+    }
+
+    /// Fetch the raw attachment contents from `/web/content/<id>`
+    ///
+    /// Returns `None` if Odoo redirected to the login page (i.e., there's no
+    /// valid web session).
+    fn attachment_content(&self, attachment_id: OdooId) -> ReqwestResult<Option<Vec<u8>>> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let endpoint = attachment::content_endpoint(attachment_id);
+        let mut request = self._impl.client.get(self.build_endpoint(&endpoint));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        if let Some(session_id) = self.session_id() {
+            request = request.header("Cookie", format!("session_id={}", session_id));
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(error)?;
+        if response.url().path() != endpoint {
+            return Ok(None);
+        }
+        Ok(Some(response.bytes().map_err(error)?.to_vec()))
+    }
+
+    /// Download the PDF for `report_name` (e.g. `account.report_invoice`),
+    /// rendered for the records `res_ids`
+    ///
+    /// The PDF is fetched from [`report_pdf_url()`](OdooClient::report_pdf_url),
+    /// which relies on the web session: authenticate with `authenticate()`, or
+    /// pass a `session_id` to `authenticate_manual()`. If anything but a PDF
+    /// comes back (usually an HTML error page for an access error, or the login
+    /// page), a `ReportNotPdf` error is returned with a snippet of the body.
+    pub fn report_pdf(&self, report_name: &str, res_ids: &[OdooId]) -> ReqwestResult<Vec<u8>> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let mut request = self
+            ._impl
+            .client
+            .get(self.report_pdf_url(report_name, res_ids));
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.header(name, db);
+        }
+        if let Some(session_id) = self.session_id() {
+            request = request.header("Cookie", format!("session_id={}", session_id));
+        }
+        let start = Instant::now();
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let response = request.send().map_err(error)?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = response.bytes().map_err(error)?.to_vec();
+        match report::not_pdf_snippet(&content_type, &body) {
+            None => Ok(body),
+            Some(snippet) => Err(ReqwestError::ReportNotPdf {
+                status,
+                content_type,
+                snippet,
+            }),
+        }
+    }
+
+    /// Fetch page `page` of the `search_read` results for `domain`, with the total count
+    ///
+    /// This sends a [`search_count`](OdooClient::search_count), then a
+    /// [`search_read`](OdooClient::search_read) request. Pages are numbered
+    /// from `0`, and a `page_size` of `0` is treated as `1`. If `order` is
+    /// `None`, the model's default order is used. See [`Page`].
+    pub fn search_read_paged(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> ReqwestResult<Page> {
+        let page_size = page_size.max(1);
+        let (count, read) = self.page_requests(model, domain, fields, page, page_size, order);
+        let total = count.send()?.count;
+        Ok(Page::new(read.send()?.data, total, page, page_size))
+    }
+
+    /// Fetch the field definitions of `model`, from the metadata cache if possible
+    ///
+    /// Without [`with_metadata_cache`](OdooClient::with_metadata_cache), the
+    /// fields are fetched on every call. See [`ModelMetadata`] for more info.
+    pub fn model_metadata(&self, model: &str) -> ReqwestResult<Arc<ModelMetadata>> {
+        match self.cached_metadata(model) {
+            Some(metadata) => Ok(metadata),
+            None => self.refresh_model_metadata(model),
+        }
+    }
+
+    /// Fetch the field definitions of `model`, skipping (and updating) the metadata cache
+    pub fn refresh_model_metadata(&self, model: &str) -> ReqwestResult<Arc<ModelMetadata>> {
+        let response = self.metadata_request(model).send()?;
+        Ok(self.store_metadata(model, response))
+    }
+
+    /// Iterate over the `search_read` results for `domain`, in pages of `batch_size`
+    ///
+    /// The records are fetched in `id` order, with keyset pagination. See
+    /// [`SearchReadIter`] for more info.
+    pub fn search_read_iter(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        batch_size: u32,
+    ) -> SearchReadIter<'_, ReqwestError> {
+        let model = model.to_string();
+        SearchReadIter::new(domain, batch_size, move |domain| {
+            self.search_read(&model, domain, fields.clone())
+                .limit(batch_size)
+                .order("id")
+                .send()
+                .map(|response| response.data)
+        })
+    }
 }
 
 impl<'a, T> OdooRequest<'a, T, ReqwestBlocking>
@@ -45,9 +861,1010 @@ where
         Ok(self.send_internal()?.0)
     }
 
-    fn send_internal(self) -> ReqwestResult<(T::Response, Option<String>)> {
-        let request = self._impl.client.post(&self.url).json(&self.data);
-        let response = request.send()?;
-        Ok((self.parse_response(&response.text()?)?, None))
+    /// Send the request, returning the raw `result` as a [`Value`]
+    ///
+    /// This skips the typed response, e.g. for debugging a customized server
+    /// which returns a different shape. Errors are returned as with `send()`.
+    pub fn send_raw(self) -> ReqwestResult<Value> {
+        Ok(self.send_internal()?.0)
+    }
+
+    /// Send the request, and return how it was sent (e.g. the number of attempts)
+    ///
+    /// With a [`RetryPolicy`](crate::client::RetryPolicy), failures which didn't
+    /// reach Odoo, serialization failures, and empty responses are retried; the
+    /// attempts are counted in the [`ResponseMeta`].
+    pub fn send_with_meta(self) -> ReqwestResult<(T::Response, ResponseMeta)> {
+        let ((response, _), meta) = self.send_internal_meta()?;
+        Ok((response, meta))
+    }
+
+    fn send_internal<D: Debug + DeserializeOwned>(self) -> ReqwestResult<(D, Option<String>)> {
+        Ok(self.send_internal_meta()?.0)
+    }
+
+    fn send_internal_meta<D: Debug + DeserializeOwned>(
+        self,
+    ) -> ReqwestResult<((D, Option<String>), ResponseMeta)> {
+        let (url, headers, body) = self.prepare()?;
+        let start = Instant::now();
+        let mut attempt = 0;
+        loop {
+            match self.send_attempt(&url, &headers, &body, &mut attempt) {
+                Err(ReqwestError::JsonRpcError(error)) if error.is_serialization_failure() => {
+                    match self
+                        .retry
+                        .and_then(|retry| retry.serialization_failure_delay(attempt))
+                    {
+                        Some(delay) => std::thread::sleep(delay),
+                        None => return Err(ReqwestError::JsonRpcError(error)),
+                    }
+                    attempt += 1;
+                }
+                Err(ReqwestError::EmptyResponse) => {
+                    match self
+                        .retry
+                        .and_then(|retry| retry.empty_response_delay(attempt))
+                    {
+                        Some(delay) => std::thread::sleep(delay),
+                        None => return Err(ReqwestError::EmptyResponse),
+                    }
+                    attempt += 1;
+                }
+                result => return Ok((result?, ResponseMeta::new(attempt, start.elapsed()))),
+            }
+        }
+    }
+
+    /// Send the request once (retrying any failures which didn't reach Odoo)
+    ///
+    /// `attempt` is the number of retries so far, and is incremented for each retry.
+    fn send_attempt<D: Debug + DeserializeOwned>(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        body: &Value,
+        attempt: &mut u32,
+    ) -> ReqwestResult<(D, Option<String>)> {
+        let span = RequestSpan::start(url, body);
+        let timer = MetricsTimer::start(&self.metrics_hooks, url, body);
+        if let Some(session_id) = &self.session_id {
+            jar_restore_session_id(&self._impl.jar, url, session_id);
+        }
+        let (mut response, xmlrpc, start) = loop {
+            if let Some(limiter) = &self.rate_limit {
+                limiter.wait_blocking();
+            }
+            let (mut request, xmlrpc) = self._impl.post(url, body)?;
+            if let Some(timeout) = self.timeout {
+                request = request.timeout(timeout);
+            }
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            #[cfg(feature = "compression")]
+            {
+                request = request.header("Accept-Encoding", compression::ACCEPT_ENCODING);
+            }
+            let start = Instant::now();
+            let response = match &self.upload_progress {
+                Some(hook) => {
+                    let mut request = request
+                        .build()
+                        .map_err(|err| ReqwestError::from_reqwest(err, start))?;
+                    progress::track_blocking(&mut request, hook.clone());
+                    self._impl.client.execute(request)
+                }
+                None => request.send(),
+            };
+            let retryable = match &response {
+                Ok(response) => retry::is_retryable_status(response.status().as_u16()),
+                Err(err) => err.is_connect(),
+            };
+            match self.retry.and_then(|retry| retry.delay(*attempt)) {
+                Some(delay) if retryable => std::thread::sleep(delay),
+                _ => {
+                    let response =
+                        response.map_err(|err| ReqwestError::from_reqwest(err, start))?;
+                    break (response, xmlrpc, start);
+                }
+            }
+            *attempt += 1;
+        };
+        let error = |err| ReqwestError::from_reqwest(err, start);
+        let status = response.status().as_u16();
+        #[cfg(feature = "compression")]
+        let encoding = response
+            .headers()
+            .get("Content-Encoding")
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let mut buffer = LimitedBody::new(self.max_response_size, response.content_length())?
+            .with_progress(self.download_progress.clone());
+        response
+            .copy_to(&mut buffer)
+            .map_err(|err| buffer.or(error(err)))?;
+        let response = buffer.into_inner();
+        #[cfg(feature = "compression")]
+        let response =
+            compression::decompress(encoding.as_deref(), response, self.max_response_size)?;
+        self.on_response(status, &String::from_utf8_lossy(&response));
+        let response = self._impl.decode(xmlrpc, response, body)?;
+        let result = self.parse_response(&response);
+        span.finish(&response, &result);
+        timer.finish(status, &response, &result);
+        Ok((result?, jar_session_id(&self._impl.jar, url)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::http_impl::test_server::{serve, serve_delayed};
+    use crate::client::{Error, RequestOutcome, RetryPolicy, SessionPassword};
+    use crate::fixtures;
+    use serde_json::json;
+    use std::thread;
+    use std::time::Duration;
+    use tiny_http::{Header, Response, Server};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_clone<T: Clone>() {}
+
+    #[test]
+    fn send_sync() {
+        assert_send_sync::<OdooClient<NotAuthed, ReqwestBlocking>>();
+        assert_send_sync::<OdooClient<Authed, ReqwestBlocking>>();
+        assert_clone::<OdooClient<Authed, ReqwestBlocking>>();
+    }
+
+    #[test]
+    fn saved_session() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve(vec![
+            json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": 2, "is_admin": true}}),
+            json!({"jsonrpc": "2.0", "id": 2, "result": {"uid": 2}}),
+            json!({"jsonrpc": "2.0", "id": 3, "result": {"uid": null}}),
+        ]);
+
+        // the `session_id` is read back from the cookie jar
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate(
+            "some-database",
+            "admin",
+            "password",
+        )?;
+        let saved = client.to_saved(SessionPassword::Omit);
+        assert_eq!(saved.session_id.as_deref(), Some("abc123"));
+
+        // a new client (with an empty cookie jar) sends the restored session
+        let client = OdooClient::new_reqwest_blocking(&url)?.restore_session(saved);
+        assert!(client.verify_session()?);
+        assert!(!client.verify_session()?);
+
+        let received = handle.join().unwrap();
+        for (path, _body, cookie) in &received[1..] {
+            assert_eq!(path, "/web/session/get_session_info");
+            assert_eq!(cookie.as_deref(), Some("session_id=abc123"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn timeout() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve_delayed(2, Duration::from_millis(500));
+        let mut client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+
+        // per-request
+        let err = client
+            .search("res.partner", jvec![])
+            .with_timeout(Duration::from_millis(100))
+            .send()
+            .unwrap_err();
+        assert!(
+            matches!(err, ReqwestError::Timeout { elapsed } if elapsed >= Duration::from_millis(100)),
+            "{:?}",
+            err
+        );
+
+        // client-wide
+        client.with_timeout(Duration::from_millis(100));
+        let err = client.search("res.partner", jvec![]).send().unwrap_err();
+        assert!(matches!(Error::from(err), Error::Timeout { .. }));
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn retry() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            // the proxy's error page (e.g. while Odoo restarts), then a result
+            for status in [503, 502, 200, 503] {
+                let request = server.recv().unwrap();
+                let body = match status {
+                    200 => json!({"jsonrpc": "2.0", "id": 1, "result": [1]}).to_string(),
+                    _ => "<h1>Service Unavailable</h1>".into(),
+                };
+                request
+                    .respond(Response::from_string(body).with_status_code(status))
+                    .unwrap();
+            }
+        });
+
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_retry(RetryPolicy::new(2, Duration::from_millis(10)));
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        assert_eq!(
+            client.search("res.partner", jvec![]).send()?.records,
+            vec![1]
+        );
+
+        // without retries, the error page is returned as-is
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+        assert!(client.search("res.partner", jvec![]).send().is_err());
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn retry_serialization_failure() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let failure = fixtures::errors::serialization_failure();
+            let success = json!({"jsonrpc": "2.0", "id": 1, "result": true});
+            for response in [&failure, &failure, &success, &failure] {
+                let request = server.recv().unwrap();
+                request
+                    .respond(Response::from_string(response.to_string()))
+                    .unwrap();
+            }
+        });
+
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_retry(RetryPolicy::new(3, Duration::from_millis(10)));
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        let (response, meta) = client
+            .write("res.partner", 1, jmap! {"name": "Acme"})
+            .send_with_meta()?;
+        assert!(response.ok);
+        assert_eq!(meta.attempts, 3);
+        // the two (jittered) delays are at least 5ms and 10ms
+        assert!(meta.total_elapsed >= Duration::from_millis(15));
+
+        // the serialization failures can be excluded from the policy
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_retry(
+            RetryPolicy::new(3, Duration::from_millis(10)).retry_serialization_failures(false),
+        );
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        match client.write("res.partner", 1, jmap! {}).send() {
+            Err(ReqwestError::JsonRpcError(error)) => assert!(error.is_serialization_failure()),
+            other => panic!("expected a serialization failure, got {:?}", other),
+        }
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn retry_empty_response() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let success = json!({"jsonrpc": "2.0", "id": 1, "result": true}).to_string();
+            for response in ["", &success, ""] {
+                let request = server.recv().unwrap();
+                request.respond(Response::from_string(response)).unwrap();
+            }
+        });
+
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_retry(RetryPolicy::new(3, Duration::from_millis(10)));
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        let (response, meta) = client
+            .write("res.partner", 1, jmap! {"name": "Acme"})
+            .send_with_meta()?;
+        assert!(response.ok);
+        assert_eq!(meta.attempts, 2);
+
+        // the empty responses can be excluded from the policy
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_retry(
+            RetryPolicy::new(3, Duration::from_millis(10)).retry_empty_responses(false),
+        );
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        match client.write("res.partner", 1, jmap! {}).send() {
+            Err(ReqwestError::EmptyResponse) => {}
+            other => panic!("expected an empty response, got {:?}", other),
+        }
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn metrics() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let success = json!({"jsonrpc": "2.0", "id": 1, "result": true}).to_string();
+        let length = success.len();
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            request.respond(Response::from_string(success)).unwrap();
+            let request = server.recv().unwrap();
+            request
+                .respond(Response::from_string("<html>Bad Gateway</html>").with_status_code(502))
+                .unwrap();
+        });
+
+        let reported = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let inner = reported.clone();
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_metrics(move |metrics| inner.lock().unwrap().push(metrics));
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        client
+            .write("res.partner", 1, jmap! {"name": "Acme"})
+            .send()?;
+        assert!(client.write("res.partner", 1, jmap! {}).send().is_err());
+        handle.join().unwrap();
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 2);
+        assert_eq!(reported[0].status, Some(200));
+        assert_eq!(reported[0].response_bytes, length);
+        assert_eq!(reported[0].orm_method.as_deref(), Some("write"));
+        assert_eq!(reported[0].outcome, RequestOutcome::Success);
+        assert_eq!(reported[1].status, Some(502));
+        assert_eq!(reported[1].outcome, RequestOutcome::Failed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn max_response_size() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let records: Vec<_> = (1..=1000)
+                .map(|id| json!({"id": id, "name": format!("Partner {}", id)}))
+                .collect();
+            let response = json!({"jsonrpc": "2.0", "id": 1, "result": records}).to_string();
+            // with a `Content-Length`, chunked, then under the limit
+            for (data, length) in [
+                (response.clone(), Some(response.len())),
+                (response, None),
+                (
+                    json!({"jsonrpc": "2.0", "id": 1, "result": []}).to_string(),
+                    None,
+                ),
+            ] {
+                let request = server.recv().unwrap();
+                let response = Response::new(200.into(), vec![], data.as_bytes(), length, None);
+                // the client hangs up once the limit is exceeded
+                let _ = request.respond(response);
+            }
+        });
+
+        let mut client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+        client.with_max_response_size(1024);
+        for _ in 0..2 {
+            let err = client
+                .search_read("res.partner", jvec![], svec!["name"])
+                .send()
+                .unwrap_err();
+            assert!(
+                matches!(err, ReqwestError::ResponseTooLarge { limit: 1024 }),
+                "{:?}",
+                err
+            );
+        }
+        let records = client
+            .search_read("res.partner", jvec![], svec!["name"])
+            .send()?;
+        assert!(records.data.is_empty());
+
+        handle.join().unwrap();
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compression() -> Result<(), Box<dyn std::error::Error>> {
+        use flate2::read::GzDecoder;
+        use flate2::write::GzEncoder;
+        use std::io::{Read, Write};
+
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut sent = Vec::new();
+            for _ in 0..2 {
+                let mut request = server.recv().unwrap();
+                let header = |name: &str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.to_string().eq_ignore_ascii_case(name))
+                        .map(|header| header.value.to_string())
+                };
+                let (encoding, accept) = (header("Content-Encoding"), header("Accept-Encoding"));
+                let mut body = Vec::new();
+                request.as_reader().read_to_end(&mut body).unwrap();
+                if encoding.as_deref() == Some("gzip") {
+                    let mut decoded = Vec::new();
+                    GzDecoder::new(&body[..]).read_to_end(&mut decoded).unwrap();
+                    body = decoded;
+                }
+                let body: Value = serde_json::from_slice(&body).unwrap();
+                sent.push((encoding, accept, body));
+
+                // the response is always gzipped
+                let response = json!({"jsonrpc": "2.0", "id": 1, "result": [1, 2, 3]});
+                let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(response.to_string().as_bytes()).unwrap();
+                let response = Response::from_data(encoder.finish().unwrap())
+                    .with_header(Header::from_bytes("Content-Encoding", "gzip").unwrap());
+                request.respond(response).unwrap();
+            }
+            sent
+        });
+
+        let mut client = OdooClient::new_reqwest_blocking(&url)?;
+        client.with_request_compression(CompressionLevel::fast(), 1024);
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+
+        // under, then over the threshold
+        let small = client.search("res.partner", jvec![]).send()?;
+        let domain = (1..=500).map(|id| json!(["id", "!=", id])).collect();
+        let large = client.search("res.partner", domain).send()?;
+        assert_eq!(small.records, vec![1, 2, 3]);
+        assert_eq!(large.records, vec![1, 2, 3]);
+
+        let sent = handle.join().unwrap();
+        assert_eq!(sent[0].0, None);
+        assert_eq!(sent[1].0.as_deref(), Some("gzip"));
+        for (_, accept, body) in &sent {
+            assert_eq!(accept.as_deref(), Some("gzip, deflate"));
+            assert_eq!(body["params"]["args"][4], json!("search"));
+        }
+        assert_eq!(
+            sent[1].2["params"]["args"][5][0][499],
+            json!(["id", "!=", 500])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::service::db::{DumpFormat, RestoreType};
+        use std::sync::{Arc, Mutex};
+
+        let backup = "A".repeat(3 * 1024 * 1024);
+        let dump = json!({"jsonrpc": "2.0", "id": 1, "result": backup}).to_string();
+        let dump_len = dump.len() as u64;
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            // `db_restore`, then `db_dump`
+            let mut request = server.recv().unwrap();
+            let mut body = Vec::new();
+            request.as_reader().read_to_end(&mut body).unwrap();
+            let response = json!({"jsonrpc": "2.0", "id": 1, "result": true});
+            request
+                .respond(Response::from_string(response.to_string()))
+                .unwrap();
+
+            // with a `Content-Length` (rather than chunked), so the total is known
+            let request = server.recv().unwrap();
+            let response = Response::from_string(dump).with_chunked_threshold(usize::MAX);
+            request.respond(response).unwrap();
+            body.len() as u64
+        });
+
+        let client = OdooClient::new_reqwest_blocking(&url)?;
+        let record = || {
+            let calls = Arc::new(Mutex::new(Vec::new()));
+            let inner = calls.clone();
+            let hook = move |sent, total| inner.lock().unwrap().push((sent, total));
+            (calls, hook)
+        };
+        // each call reports more bytes, ending at the total
+        let check = |calls: &[(u64, Option<u64>)], total: u64| {
+            assert!(calls.len() > 1, "{:?}", calls);
+            assert!(calls.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert!(calls.iter().all(|(_, t)| *t == Some(total)));
+            assert_eq!(calls.last().unwrap().0, total);
+        };
+
+        let (uploads, hook) = record();
+        client
+            .db_restore("master", "restored", &backup, RestoreType::Copy)
+            .on_upload_progress(hook)
+            .send()?;
+
+        let (downloads, hook) = record();
+        let response = client
+            .db_dump("master", "restored", DumpFormat::Zip)
+            .on_download_progress(hook)
+            .send()?;
+        assert_eq!(response.b64_bytes.len(), backup.len());
+
+        let uploaded = handle.join().unwrap();
+        check(&uploads.lock().unwrap(), uploaded);
+        check(&downloads.lock().unwrap(), dump_len);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "xmlrpc")]
+    #[test]
+    fn xmlrpc_transport() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let path = request.url().to_string();
+            let response = concat!(
+                "<?xml version='1.0'?>\n<methodResponse><params><param>",
+                "<value><array><data><value><int>1</int></value><value><int>2</int></value></data></array></value>",
+                "</param></params></methodResponse>\n"
+            );
+            request.respond(Response::from_string(response)).unwrap();
+            (path, body)
+        });
+
+        let mut client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+        client.with_xmlrpc_transport(true);
+        let ids = client.search("res.partner", jvec![]).send()?;
+        assert_eq!(ids.records, vec![1, 2]);
+
+        let (path, body) = handle.join().unwrap();
+        assert_eq!(path, "/xmlrpc/2/object");
+        assert!(body.contains("<methodName>execute_kw</methodName>"));
+        assert!(body.contains("<string>search</string>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn attachment_download() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut received = Vec::new();
+            // with a web session: the raw contents, then the metadata
+            // without one: a redirect to the login page, then the base64 data
+            for _ in 0..5 {
+                let mut request = server.recv().unwrap();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let path = request.url().to_string();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let response = match path.as_str() {
+                    "/web/content/5" if cookie.is_some() => Response::from_data(vec![0, 1, 2, 255]),
+                    "/web/content/5" => Response::from_data(Vec::new())
+                        .with_status_code(303)
+                        .with_header(Header::from_bytes("Location", "/web/login").unwrap()),
+                    "/web/login" => Response::from_string("<html>login</html>"),
+                    _ => {
+                        let fields = serde_json::from_str::<Value>(&body).unwrap()["params"]
+                            ["args"][6]["fields"]
+                            .clone();
+                        let mut record = json!({"id": 5, "name": "a.bin", "mimetype": false});
+                        if fields == json!(["name", "mimetype", "datas"]) {
+                            record["datas"] = json!("AAEC/w==");
+                        }
+                        let response = json!({"jsonrpc": "2.0", "id": 1, "result": [record]});
+                        Response::from_data(response.to_string().into_bytes())
+                    }
+                };
+                received.push((path, cookie));
+                request.respond(response).unwrap();
+            }
+            received
+        });
+
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("abc123".into()),
+        );
+        let attachment = client.attachment_download(5)?;
+        assert_eq!(attachment.name, "a.bin");
+        assert_eq!(attachment.data, vec![0, 1, 2, 255]);
+
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            None,
+        );
+        let attachment = client.attachment_download(5)?;
+        assert_eq!(attachment.data, vec![0, 1, 2, 255]);
+
+        let received = handle.join().unwrap();
+        let paths: Vec<_> = received.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/web/content/5",
+                "/jsonrpc",
+                "/web/content/5",
+                "/web/login",
+                "/jsonrpc"
+            ]
+        );
+        assert_eq!(received[0].1.as_deref(), Some("session_id=abc123"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_pdf() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut received = Vec::new();
+            for _ in 0..2 {
+                let request = server.recv().unwrap();
+                let path = request.url().to_string();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let response = match cookie.as_deref() {
+                    Some("session_id=abc123") => Response::from_data(b"%PDF-1.7\n%\xe2\xe3".to_vec())
+                        .with_header(Header::from_bytes("Content-Type", "application/pdf").unwrap()),
+                    _ => Response::from_string(
+                        "<!DOCTYPE html>\n<html>\n  <body>\n    <h1>Forbidden</h1>\n  </body>\n</html>",
+                    )
+                    .with_status_code(403)
+                    .with_header(
+                        Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap(),
+                    ),
+                };
+                received.push((path, cookie));
+                request.respond(response).unwrap();
+            }
+            received
+        });
+
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("abc123".into()),
+        );
+        let pdf = client.report_pdf("account.report_invoice", &[7, 8])?;
+        assert_eq!(pdf, b"%PDF-1.7\n%\xe2\xe3");
+
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("expired".into()),
+        );
+        let err = client
+            .report_pdf("account.report_invoice", &[7])
+            .unwrap_err();
+        match err {
+            ReqwestError::ReportNotPdf {
+                status,
+                content_type,
+                snippet,
+            } => {
+                assert_eq!(status, 403);
+                assert_eq!(content_type, "text/html; charset=utf-8");
+                assert!(snippet.contains("<h1>Forbidden</h1>"), "{}", snippet);
+            }
+            err => panic!("expected ReportNotPdf, got {:?}", err),
+        }
+
+        let received = handle.join().unwrap();
+        assert_eq!(received[0].0, "/report/pdf/account.report_invoice/7,8");
+        assert_eq!(received[0].1.as_deref(), Some("session_id=abc123"));
+        assert_eq!(received[1].0, "/report/pdf/account.report_invoice/7");
+
+        Ok(())
+    }
+
+    #[test]
+    fn authenticate_totp() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut received = Vec::new();
+            // accepted: authenticate, the form, the form post (and its redirect), then
+            // the session info; rejected: the same, without the redirect
+            for _ in 0..9 {
+                let mut request = server.recv().unwrap();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let path = request.url().split('?').next().unwrap().to_string();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let jsonrpc = |result: Value| {
+                    let response = json!({"jsonrpc": "2.0", "id": 1, "result": result});
+                    Response::from_data(response.to_string().into_bytes())
+                };
+                let set_cookie = |session_id: &str| {
+                    let cookie = format!("session_id={}; Path=/; HttpOnly", session_id);
+                    Header::from_bytes("Set-Cookie", cookie).unwrap()
+                };
+                let response = match path.as_str() {
+                    "/web/session/authenticate" => {
+                        jsonrpc(json!({"uid": null})).with_header(set_cookie("partial"))
+                    }
+                    "/web/login/totp" if body.is_empty() => Response::from_data(
+                        r#"<form><input type="hidden" name="csrf_token" value="csrf123"/></form>"#
+                            .as_bytes()
+                            .to_vec(),
+                    ),
+                    "/web/login/totp" if body.contains("totp_token=123456") => {
+                        Response::from_data(Vec::new())
+                            .with_status_code(303)
+                            .with_header(Header::from_bytes("Location", "/web").unwrap())
+                            .with_header(set_cookie("full"))
+                    }
+                    "/web/login/totp" => Response::from_data(b"<html>retry</html>".to_vec()),
+                    "/web/session/get_session_info"
+                        if cookie.as_deref() == Some("session_id=full") =>
+                    {
+                        jsonrpc(json!({"uid": 2, "db": "some-database"}))
+                    }
+                    "/web/session/get_session_info" => jsonrpc(json!({"uid": null})),
+                    _ => Response::from_data(b"<html>web</html>".to_vec()),
+                };
+                received.push((path, body, cookie));
+                request.respond(response).unwrap();
+            }
+            received
+        });
+
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_totp(
+            "some-database",
+            "admin",
+            "password",
+            "123456",
+        )?;
+        assert_eq!(client.uid(), 2);
+
+        let err = OdooClient::new_reqwest_blocking(&url)?
+            .authenticate_totp("some-database", "admin", "password", "000000")
+            .unwrap_err();
+        assert!(matches!(err, ReqwestAuthError::TwoFactorRejected));
+
+        let received = handle.join().unwrap();
+        let paths: Vec<_> = received.iter().map(|(path, _, _)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/web/session/authenticate",
+                "/web/login/totp",
+                "/web/login/totp",
+                "/web",
+                "/web/session/get_session_info",
+                "/web/session/authenticate",
+                "/web/login/totp",
+                "/web/login/totp",
+                "/web/session/get_session_info",
+            ]
+        );
+        assert_eq!(received[1].2.as_deref(), Some("session_id=partial"));
+        assert_eq!(received[2].1, "csrf_token=csrf123&totp_token=123456");
+
+        Ok(())
+    }
+
+    #[test]
+    fn authenticate_via_login_form() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut received = Vec::new();
+            // accepted: the form, the form post (and its redirect), then the
+            // session info; rejected: the form, then the form post
+            for _ in 0..6 {
+                let mut request = server.recv().unwrap();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let url = request.url().to_string();
+                let path = url.split('?').next().unwrap().to_string();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let html = |html: &str| {
+                    let content_type = "text/html; charset=utf-8";
+                    Response::from_data(html.as_bytes().to_vec())
+                        .with_header(Header::from_bytes("Content-Type", content_type).unwrap())
+                };
+                let set_cookie = |session_id: &str| {
+                    let cookie = format!("session_id={}; Path=/; HttpOnly", session_id);
+                    Header::from_bytes("Set-Cookie", cookie).unwrap()
+                };
+                let form = r#"<form action="/web/login" method="post">
+                    <input type="hidden" name="csrf_token" value="csrf123"/>
+                    <input type="text" name="login"/>
+                    %ERROR%
+                </form>"#;
+                let error =
+                    r#"<p class="alert alert-danger" role="alert">Wrong login/password</p>"#;
+                let session_info = json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": 2}});
+                let response = match path.as_str() {
+                    "/web/login" if body.is_empty() => {
+                        html(&form.replace("%ERROR%", "")).with_header(set_cookie("anonymous"))
+                    }
+                    "/web/login" if body.contains("password=password") => html("")
+                        .with_status_code(303)
+                        .with_header(Header::from_bytes("Location", "/odoo").unwrap())
+                        .with_header(set_cookie("user")),
+                    "/web/login" => html(&form.replace("%ERROR%", error)),
+                    "/web/session/get_session_info"
+                        if cookie.as_deref() == Some("session_id=user") =>
+                    {
+                        Response::from_data(session_info.to_string().into_bytes())
+                    }
+                    _ => html("<html>web</html>"),
+                };
+                received.push((url, body, cookie));
+                request.respond(response).unwrap();
+            }
+            received
+        });
+
+        let client = OdooClient::new_reqwest_blocking(&url)?.authenticate_via_login_form(
+            "trial",
+            "admin@example.com",
+            "password",
+        )?;
+        assert_eq!(client.uid(), 2);
+
+        let err = OdooClient::new_reqwest_blocking(&url)?
+            .authenticate_via_login_form("trial", "admin@example.com", "wrong")
+            .unwrap_err();
+        assert!(
+            matches!(&err, ReqwestAuthError::LoginRejected { message } if message == "Wrong login/password"),
+            "{:?}",
+            err
+        );
+
+        let received = handle.join().unwrap();
+        let urls: Vec<_> = received.iter().map(|(url, _, _)| url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "/web/login?db=trial",
+                "/web/login",
+                "/odoo",
+                "/web/session/get_session_info",
+                "/web/login?db=trial",
+                "/web/login",
+            ]
+        );
+        assert_eq!(
+            received[1].1,
+            "csrf_token=csrf123&db=trial&login=admin%40example.com&password=password"
+        );
+        assert_eq!(received[1].2.as_deref(), Some("session_id=anonymous"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn web_db() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut received = Vec::new();
+            // the restore (redirected to the manager), then the backup
+            for _ in 0..3 {
+                let mut request = server.recv().unwrap();
+                let mut body = Vec::new();
+                request.as_reader().read_to_end(&mut body).unwrap();
+                let path = request.url().to_string();
+                let content_type = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Content-Type"))
+                    .map(|h| h.value.to_string());
+                let response = match path.as_str() {
+                    "/web/database/restore" => Response::from_data(Vec::new())
+                        .with_status_code(303)
+                        .with_header(
+                            Header::from_bytes("Location", "/web/database/manager").unwrap(),
+                        ),
+                    "/web/database/manager" => Response::from_string("<html>manager</html>")
+                        .with_header(Header::from_bytes("Content-Type", "text/html").unwrap()),
+                    _ => Response::from_data(vec![80, 75, 3, 4]).with_header(
+                        Header::from_bytes("Content-Type", "application/octet-stream").unwrap(),
+                    ),
+                };
+                received.push((path, content_type, body));
+                request.respond(response).unwrap();
+            }
+            received
+        });
+
+        let client = OdooClient::new_reqwest_blocking(&url)?;
+        client.web_db_restore("master", &[0, 255], "acme-copy", true, false)?;
+        let backup = client.web_db_backup("master", "acme", BackupFormat::Zip)?;
+        assert_eq!(backup, vec![80, 75, 3, 4]);
+
+        let received = handle.join().unwrap();
+        let (path, content_type, body) = &received[0];
+        assert_eq!(path, "/web/database/restore");
+        let boundary = content_type
+            .as_deref()
+            .and_then(|value| value.strip_prefix("multipart/form-data; boundary="))
+            .unwrap();
+        let mut file_part = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"backup_file\"; filename=\"backup\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n",
+            boundary
+        )
+        .into_bytes();
+        file_part.extend([0, 255]);
+        assert!(body.windows(file_part.len()).any(|w| w == file_part));
+        let text = String::from_utf8_lossy(body);
+        assert!(text.contains("name=\"master_pwd\"\r\n\r\nmaster\r\n"));
+        assert!(text.contains("name=\"name\"\r\n\r\nacme-copy\r\n"));
+        assert!(text.contains("name=\"copy\"\r\n\r\ntrue\r\n"));
+
+        assert_eq!(received[1].0, "/web/database/manager");
+        let (path, content_type, body) = &received[2];
+        assert_eq!(path, "/web/database/backup");
+        assert_eq!(
+            content_type.as_deref(),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(body, b"master_pwd=master&name=acme&backup_format=zip");
+
+        Ok(())
     }
 }
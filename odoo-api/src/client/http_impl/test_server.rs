@@ -0,0 +1,64 @@
+//! Local HTTP servers for the HTTP impl tests
+
+use serde_json::Value;
+use std::thread::{self, JoinHandle};
+#[cfg(any(feature = "async", feature = "blocking"))]
+use std::time::Duration;
+use tiny_http::{Header, Response, Server};
+
+/// The path, body and `Cookie` header of each request received by [`serve`]
+pub(crate) type Received = Vec<(String, Value, Option<String>)>;
+
+/// Spawn a local HTTP server which responds to each request with the
+/// matching `responses` entry (setting a `session_id=abc123` cookie), and
+/// returns the requests it received
+pub(crate) fn serve(responses: Vec<Value>) -> (String, JoinHandle<Received>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let handle = thread::spawn(move || {
+        let mut received = Vec::new();
+        for response in responses {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            let path = request.url().to_string();
+            received.push((path, serde_json::from_str(&body).unwrap(), cookie));
+
+            let session = Header::from_bytes(
+                "Set-Cookie",
+                "session_id=abc123; Expires=Sat, 01 Jan 2033 00:00:00 GMT; HttpOnly; Path=/",
+            )
+            .unwrap();
+            request
+                .respond(Response::from_string(response.to_string()).with_header(session))
+                .unwrap();
+        }
+        received
+    });
+
+    (url, handle)
+}
+
+/// Spawn a local HTTP server which waits for `delay` before responding to
+/// each of the next `count` requests
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn serve_delayed(count: usize, delay: Duration) -> (String, JoinHandle<()>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let handle = thread::spawn(move || {
+        for _ in 0..count {
+            let request = server.recv().unwrap();
+            thread::sleep(delay);
+            let response = serde_json::json!({"jsonrpc": "2.0", "id": 1, "result": []});
+            // the client has likely hung up by now
+            let _ = request.respond(Response::from_string(response.to_string()));
+        }
+    });
+
+    (url, handle)
+}
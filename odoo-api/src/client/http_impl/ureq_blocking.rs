@@ -0,0 +1,910 @@
+use crate::client::access::AccessMatrix;
+use crate::client::attachment::{self, Attachment};
+use crate::client::batch::{self, BatchProgress};
+use crate::client::body::LimitedBody;
+use crate::client::button::MethodCallResult;
+#[cfg(feature = "compression")]
+use crate::client::compression::{CompressionLevel, RequestCompression};
+use crate::client::db_wait::{self, DbWait, Probe};
+use crate::client::error::{UreqAuthResult, UreqError, UreqResult};
+use crate::client::health::HealthReport;
+use crate::client::merge;
+use crate::client::metadata::ModelMetadata;
+use crate::client::metrics::MetricsTimer;
+use crate::client::odoo_client::single_database;
+use crate::client::page::Page;
+use crate::client::retry::{self, ResponseMeta};
+use crate::client::session::{is_invalid_session, is_session_user};
+use crate::client::settings;
+use crate::client::stream::SearchReadIter;
+use crate::client::trace::RequestSpan;
+use crate::client::web_db::{self, BackupFormat, WebBody, WebResponse};
+use crate::client::{AuthState, Authed, NotAuthed, OdooClient, OdooRequest, RequestImpl};
+use crate::jsonrpc::{JsonRpcParams, OdooId, OdooIds};
+use crate::service::common::LoginResponse;
+use crate::service::db::CreateDatabase;
+use crate::service::orm::{NameGetResponse, UnlinkResponse, WriteResponse};
+use crate::OdooVersion;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::io::{self, Read};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use ureq::{Agent, AgentBuilder};
+
+/// A blocking [`RequestImpl`] backed by [`ureq`]
+///
+/// Unlike the `reqwest` impls, `ureq` doesn't keep a cookie store. Instead, the
+/// `session_id` is parsed from the `Set-Cookie` header, and sent back with each
+/// request (in the same way as the closure impls).
+#[derive(Clone)]
+pub struct UreqBlocking {
+    agent: Agent,
+
+    /// Gzip large request bodies
+    #[cfg(feature = "compression")]
+    compression: Option<RequestCompression>,
+}
+
+impl UreqBlocking {
+    /// Send the JSON `body`, gzipped if it's over the compression threshold
+    fn send(&self, request: ureq::Request, body: &Value) -> UreqResult<ureq::Response> {
+        #[cfg(feature = "compression")]
+        if let Some(compression) = &self.compression {
+            let json = serde_json::to_vec(body)?;
+            let request = request.set("Content-Type", "application/json");
+            return Ok(match compression.compress(&json) {
+                Some(gzip) => request.set("Content-Encoding", "gzip").send_bytes(&gzip)?,
+                None => request.send_bytes(&json)?,
+            });
+        }
+        Ok(request.send_json(body)?)
+    }
+}
+impl RequestImpl for UreqBlocking {
+    type Error = UreqError;
+}
+
+impl OdooClient<NotAuthed, UreqBlocking> {
+    pub fn new_ureq(url: &str) -> Self {
+        let agent = AgentBuilder::new().build();
+
+        Self::new(
+            url,
+            UreqBlocking {
+                agent,
+                #[cfg(feature = "compression")]
+                compression: None,
+            },
+        )
+    }
+}
+
+impl<S> OdooClient<S, UreqBlocking>
+where
+    S: AuthState,
+{
+    pub fn authenticate(
+        self,
+        db: &str,
+        login: &str,
+        password: &str,
+    ) -> UreqAuthResult<OdooClient<Authed, UreqBlocking>> {
+        let request = self.get_auth_request(db, login, password);
+        let (response, session_id) = request.send_internal()?;
+        Ok(self.parse_auth_response(db, login, password, response, session_id)?)
+    }
+
+    /// Authenticate without knowing the database name
+    ///
+    /// The databases are listed with [`db_list`](crate::service::db::List) first.
+    /// If the server has a single database, it's used to [`authenticate()`](Self::authenticate);
+    /// if it has several, a `MultipleDatabases` error is returned. If listing is
+    /// disabled, the request is sent without a database, and the one Odoo picked
+    /// (e.g. with `dbfilter`) is read from the session info.
+    pub fn authenticate_auto(
+        self,
+        login: &str,
+        password: &str,
+    ) -> UreqAuthResult<OdooClient<Authed, UreqBlocking>> {
+        let databases = match self.db_list(false).send() {
+            Ok(response) => response.databases,
+            Err(UreqError::DbListingDisabled) => Vec::new(),
+            Err(error) => return Err(error.into()),
+        };
+        if let Some(db) = single_database(databases)? {
+            return self.authenticate(&db, login, password);
+        }
+        let request = self.get_auth_request("", login, password);
+        let (response, session_id) = request.send_internal()?;
+        Ok(self.parse_auth_auto_response(login, password, response, session_id)?)
+    }
+
+    /// Gzip JSON-RPC request bodies larger than `threshold` bytes
+    ///
+    /// Compressed requests are sent with `Content-Encoding: gzip`. Odoo doesn't
+    /// decode these itself, so this only works behind a reverse proxy which
+    /// decompresses request bodies. (`ureq` decodes gzip responses by default.)
+    #[cfg(feature = "compression")]
+    pub fn with_request_compression(
+        &mut self,
+        level: CompressionLevel,
+        threshold: usize,
+    ) -> &mut Self {
+        self._impl.compression = Some(RequestCompression { level, threshold });
+        self
+    }
+
+    /// Fetch the server version
+    ///
+    /// The version is fetched with [`common_version`](crate::service::common::Version)
+    /// on the first call, then cached on the client. If that fails (e.g. because
+    /// `/jsonrpc` is blocked), [`web_version_info`](crate::service::web::WebVersionInfo)
+    /// is tried instead.
+    pub fn server_version(&self) -> UreqResult<OdooVersion> {
+        if let Some(version) = self.version() {
+            return Ok(version);
+        }
+        let response = match self.get_version_request().send() {
+            Ok(response) => response,
+            // some deployments block `/jsonrpc`, but the webclient still needs this
+            Err(err) => self.web_version_info().send().map_err(|_| err)?,
+        };
+        Ok(self.parse_version_response(response))
+    }
+
+    /// Check whether the server version is at least `major.minor`
+    ///
+    /// See [`OdooVersion::at_least`] for more info.
+    pub fn version_at_least(&self, major: u32, minor: u32) -> UreqResult<bool> {
+        Ok(self.server_version()?.at_least(major, minor))
+    }
+
+    /// Probe the server, e.g. for a liveness/readiness check
+    ///
+    /// This sends a [`common_version`](crate::service::common::Version) request
+    /// (timed as the report `latency`), then a [`db_exist`](crate::service::db::DbExist)
+    /// request if `db` is passed. Failures are recorded in the [`HealthReport`]
+    /// rather than returned as an error.
+    pub fn health_check(&self, db: Option<&str>) -> HealthReport {
+        let start = Instant::now();
+        let version = self.get_version_request().send();
+        let latency = start.elapsed();
+        let database_exists = db.map(|db| self.db_exist(db).send().map(|response| response.exists));
+        HealthReport::new(version, database_exists, latency)
+    }
+
+    /// Create a new database, then wait until it's ready to use
+    ///
+    /// Odoo responds to [`db_create_database`](crate::service::db::CreateDatabase)
+    /// once the database is created, but the registry may take a while longer
+    /// to load (especially with large templates). This method then polls
+    /// [`db_exist`](crate::service::db::DbExist), followed by a
+    /// [`common_login`](crate::service::common::Login) with the new admin
+    /// credentials, every `wait.interval`. It returns once the login succeeds,
+    /// or with a [`UreqError::DatabaseNotReady`] (holding the last failure) after
+    /// `wait.timeout`.
+    pub fn db_create_database_and_wait(
+        &self,
+        create: CreateDatabase,
+        wait: DbWait,
+    ) -> UreqResult<LoginResponse> {
+        self.get_create_database_request(&create).send()?;
+        db_wait::run_blocking(&create.db_name, wait, || {
+            match self.db_exist(&create.db_name).send() {
+                Ok(response) if !response.exists => return Probe::Missing,
+                Err(err) => return Probe::Failed(err),
+                Ok(_) => {}
+            }
+            match self.get_login_request(&create).send() {
+                Ok(response) => Probe::Ready(response),
+                Err(err) => Probe::Failed(err),
+            }
+        })
+    }
+
+    /// Back up the database `name` via the `/web/database/backup` form
+    ///
+    /// This is useful when the JSON-RPC `db` service is blocked (otherwise, see
+    /// [`db_dump`](crate::service::db::Dump)). Returns the raw backup.
+    pub fn web_db_backup(
+        &self,
+        master_pwd: &str,
+        name: &str,
+        format: BackupFormat,
+    ) -> UreqResult<Vec<u8>> {
+        let body = web_db::backup_body(master_pwd, name, format);
+        let response = self.web_db_post(web_db::BACKUP_ENDPOINT, body)?;
+        Ok(web_db::parse_backup(response)?)
+    }
+
+    /// Restore the backup `file` as the database `name` via the
+    /// `/web/database/restore` form
+    ///
+    /// If `copy` is set, the database UUID is regenerated (i.e., the restored
+    /// database is treated as a copy). The client's [`DbGuard`](crate::client::DbGuard)
+    /// refuses protected names, unless `confirmed` is set and the guard
+    /// [requires confirmation](crate::client::DbGuard::require_confirmation)
+    /// (as with [`confirm_destructive`](crate::client::OdooRequest::confirm_destructive)).
+    pub fn web_db_restore(
+        &self,
+        master_pwd: &str,
+        file: &[u8],
+        name: &str,
+        copy: bool,
+        confirmed: bool,
+    ) -> UreqResult<()> {
+        if let Some(guard) = &self.db_guard {
+            guard.check_db("restore", name, confirmed)?;
+        }
+        let body = web_db::restore_body(master_pwd, file, name, copy);
+        let response = self.web_db_post(web_db::RESTORE_ENDPOINT, body)?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Duplicate the database `name` as `new_name` via the
+    /// `/web/database/duplicate` form
+    pub fn web_db_duplicate(&self, master_pwd: &str, name: &str, new_name: &str) -> UreqResult<()> {
+        let body = web_db::duplicate_body(master_pwd, name, new_name);
+        let response = self.web_db_post(web_db::DUPLICATE_ENDPOINT, body)?;
+        Ok(web_db::parse_redirect(response)?)
+    }
+
+    /// Send a `/web/database/*` form post
+    ///
+    /// Redirects are followed, and the final response is returned as-is
+    /// (including 4xx/5xx responses).
+    fn web_db_post(&self, endpoint: &str, body: WebBody) -> UreqResult<WebResponse> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let (content_type, body) = body.encode();
+        let mut request = self
+            ._impl
+            .agent
+            .post(&self.build_endpoint(endpoint))
+            .set("Content-Type", &content_type);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.set(name, db);
+        }
+        let response = match request.send_bytes(&body) {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(err.into()),
+        };
+        let status = response.status();
+        let content_type = response.header("Content-Type").map(str::to_string);
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(ureq::Error::from)?;
+        Ok(WebResponse {
+            status,
+            content_type,
+            body,
+        })
+    }
+}
+
+impl OdooClient<Authed, UreqBlocking> {
+    /// Check whether the session is still valid, e.g. after `restore_session()`
+    ///
+    /// This fetches the [session info](crate::service::web::SessionInfo) for the
+    /// `session_id`, and checks that it's still logged in as this user. Without
+    /// a `session_id`, a cheap `read` of the current user is sent instead (which
+    /// checks the password). If Odoo rejects the session (or credentials),
+    /// `Ok(false)` is returned; any other error is returned as-is.
+    pub fn verify_session(&self) -> UreqResult<bool> {
+        let result = match self.session_id() {
+            Some(_) => self
+                .web_session_info()
+                .send()
+                .map(|response| is_session_user(&response.data, self.auth.uid)),
+            None => self
+                .read("res.users", self.auth.uid, vec!["id".into()])
+                .send()
+                .map(|_| true),
+        };
+        match result {
+            Err(UreqError::JsonRpcError(error)) if is_invalid_session(&error) => Ok(false),
+            result => result,
+        }
+    }
+
+    /// Point the client at a different server, keeping the session, and check it there
+    ///
+    /// This is for servers which share the session store (e.g. a failover node).
+    /// The session is checked with [`verify_session()`](Self::verify_session);
+    /// if it's rejected, the previous URL is restored and an `InvalidSession`
+    /// error is returned. The previous URL is restored on any other error too.
+    pub fn switch_url_verified(&mut self, url: &str) -> UreqResult<()> {
+        let previous = self.replace_url(url);
+        let result = match self.verify_session() {
+            Ok(true) => return Ok(()),
+            Ok(false) => Err(UreqError::InvalidSession {
+                url: self.url.clone(),
+            }),
+            Err(error) => Err(error),
+        };
+        self.url = previous;
+        result
+    }
+
+    /// Fetch the CRUD access rights of the current user on `model`
+    ///
+    /// This sends a [`check_access_rights`](OdooClient::check_access_rights)
+    /// request (without raising) for each operation, in turn.
+    pub fn access_matrix(&self, model: &str) -> UreqResult<AccessMatrix> {
+        let results = self
+            .access_requests(model)
+            .into_iter()
+            .map(|request| request.send().map(|response| response.ok))
+            .collect::<UreqResult<Vec<_>>>()?;
+        Ok(AccessMatrix::from_results(&results))
+    }
+
+    /// Fetch the [`AccessMatrix`] for each of `models`
+    ///
+    /// See [`access_matrix`](Self::access_matrix) for more info.
+    pub fn access_matrices(&self, models: &[&str]) -> UreqResult<HashMap<String, AccessMatrix>> {
+        models
+            .iter()
+            .map(|model| Ok((model.to_string(), self.access_matrix(model)?)))
+            .collect()
+    }
+
+    /// Write `values` to `ids`, in chunks of `chunk_size`
+    ///
+    /// Each chunk is sent as a separate [`write`](OdooClient::write) request,
+    /// which avoids long-running transactions on the server. Sending stops at
+    /// the first failed chunk, and returns a [`UreqError::BatchError`].
+    ///
+    /// `progress` is called with `(done, total)` after each chunk.
+    pub fn write_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        values: Map<String, Value>,
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> UreqResult<WriteResponse> {
+        let ok = batch::run_blocking(ids, chunk_size, progress, |chunk| {
+            self.write(model, chunk, values.clone())
+                .send()
+                .map(|response| response.ok)
+        })?;
+        Ok(WriteResponse { ok })
+    }
+
+    /// Delete `ids`, in chunks of `chunk_size`
+    ///
+    /// See [`write_batched`](Self::write_batched) for more info.
+    pub fn unlink_batched(
+        &self,
+        model: &str,
+        ids: &[OdooId],
+        chunk_size: usize,
+        progress: Option<BatchProgress<'_>>,
+    ) -> UreqResult<UnlinkResponse> {
+        let ok = batch::run_blocking(ids, chunk_size, progress, |chunk| {
+            self.unlink(model, chunk).send().map(|response| response.ok)
+        })?;
+        Ok(UnlinkResponse { ok })
+    }
+
+    /// Fetch the `display_name` for `ids`, falling back to `read` on Odoo 17.0+
+    ///
+    /// This sends a [`name_get`](OdooClient::name_get) request first. If the
+    /// server doesn't have `name_get` (it was removed in Odoo 17.0), the names
+    /// are fetched with [`display_names`](OdooClient::display_names) instead.
+    /// Any other error is returned as-is.
+    pub fn name_get_with_fallback<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+    ) -> UreqResult<NameGetResponse> {
+        let ids = ids.into();
+        match self.name_get(model, ids.clone()).send() {
+            Err(UreqError::JsonRpcError(error)) if error.is_missing_method("name_get") => {
+                self.display_names(model, ids).send()
+            }
+            result => result,
+        }
+    }
+
+    /// Merge the partners `merge_ids` into `keep_id`, with Odoo's merge wizard
+    ///
+    /// This creates a `base.partner.merge.automatic.wizard`, then calls its
+    /// `action_merge` method. The references to the merged partners are moved to
+    /// `keep_id`, and the merged partners are deleted. Returns the surviving
+    /// partner id (i.e., `keep_id`).
+    ///
+    /// If the wizard refuses the merge (e.g., more than 3 partners, or partners
+    /// from different companies), this returns a `PartnerMergeRejected` error
+    /// with the [`MergeRejection`](crate::client::MergeRejection) reason.
+    pub fn merge_partners<ID: Into<OdooIds>>(
+        &self,
+        keep_id: OdooId,
+        merge_ids: ID,
+    ) -> UreqResult<OdooId> {
+        let values = merge::wizard_values(keep_id, merge_ids.into());
+        let response = self.create(merge::WIZARD_MODEL, values).send()?;
+        let wizard_id = attachment::created_id(response);
+        self.execute_kw(
+            merge::WIZARD_MODEL,
+            merge::ACTION_MERGE,
+            vec![vec![wizard_id].into()],
+            Map::new(),
+        )
+        .send()?;
+        Ok(keep_id)
+    }
+
+    /// Call the button `method` on `ids`, and sniff the result
+    ///
+    /// This sends an [`execute_kw`](OdooClient::execute_kw) request, as the
+    /// webclient does for `type="object"` buttons. Use [`MethodCallResult::is_action`]
+    /// to tell whether the server wants user interaction (e.g. a wizard).
+    pub fn call_button<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        method: &str,
+    ) -> UreqResult<MethodCallResult> {
+        let ids = ids.into().into_inner();
+        let response = self
+            .execute_kw(model, method, vec![ids.into()], Map::new())
+            .send()?;
+        Ok(MethodCallResult::from_value(response.data))
+    }
+
+    /// Apply the configuration `values` (e.g. `group_use_lead`), as the settings "Save" button does
+    ///
+    /// This creates a `res.config.settings` record with the `values`, then
+    /// calls its `execute` method. Fields which aren't given keep their current
+    /// value. Installing modules (the `module_*` fields) can take minutes, see
+    /// [`apply_settings_with_timeout()`](Self::apply_settings_with_timeout).
+    pub fn apply_settings(&self, values: Map<String, Value>) -> UreqResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send()?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id).send()?;
+        Ok(())
+    }
+
+    /// Apply the configuration `values`, with a `timeout` for the `execute` call
+    ///
+    /// This overrides the client-wide timeout while the settings are applied
+    /// (e.g. while the `module_*` fields install their modules). See
+    /// [`apply_settings()`](Self::apply_settings).
+    pub fn apply_settings_with_timeout(
+        &self,
+        values: Map<String, Value>,
+        timeout: Duration,
+    ) -> UreqResult<()> {
+        let response = self.create(settings::SETTINGS_MODEL, values).send()?;
+        let id = attachment::created_id(response);
+        self.settings_execute_request(id)
+            .with_timeout(timeout)
+            .send()?;
+        Ok(())
+    }
+
+    /// Read the current configuration `fields` (e.g. `group_use_lead`)
+    ///
+    /// This sends a `default_get` request on `res.config.settings`, which
+    /// computes the values from the current config parameters, groups and
+    /// installed modules. Unknown fields are missing from the result.
+    pub fn read_settings(&self, fields: Vec<String>) -> UreqResult<Map<String, Value>> {
+        let response = self.settings_read_request(fields).send()?;
+        Ok(settings::settings_values(response))
+    }
+
+    /// Upload `data` as an `ir.attachment` on the `model` record `res_id`
+    ///
+    /// The data is base64-encoded, and sent with a [`create`](OdooClient::create)
+    /// request. If `mimetype` is `None`, Odoo guesses it from the filename and
+    /// contents. Returns the new attachment id.
+    pub fn attachment_upload(
+        &self,
+        model: &str,
+        res_id: OdooId,
+        filename: &str,
+        data: &[u8],
+        mimetype: Option<&str>,
+    ) -> UreqResult<OdooId> {
+        let values = attachment::upload_values(model, res_id, filename, data, mimetype);
+        let response = self.create("ir.attachment", values).send()?;
+        Ok(attachment::created_id(response))
+    }
+
+    /// Download the `ir.attachment` with id `attachment_id`
+    ///
+    /// The raw contents are fetched from `/web/content/<id>` (which avoids the
+    /// base64 overhead for large files), and the name and mimetype with a
+    /// [`read`](OdooClient::read) request. If there's no web session (e.g.,
+    /// after `authenticate_manual()` without a `session_id`), this falls back
+    /// to reading the base64 `datas` field instead.
+    pub fn attachment_download(&self, attachment_id: OdooId) -> UreqResult<Attachment> {
+        let data = self.attachment_content(attachment_id)?;
+        let fields = attachment::read_fields(data.is_none());
+        let response = self.read("ir.attachment", attachment_id, fields).send()?;
+        Ok(Attachment::from_read(response, data)?)
+    }
+
+    /// Fetch the raw attachment contents from `/web/content/<id>`
+    ///
+    /// Returns `None` if Odoo redirected to the login page (i.e., there's no
+    /// valid web session).
+    fn attachment_content(&self, attachment_id: OdooId) -> UreqResult<Option<Vec<u8>>> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.wait_blocking();
+        }
+        let endpoint = attachment::content_endpoint(attachment_id);
+        let url = self.build_endpoint(&endpoint);
+        let mut request = self._impl.agent.get(&url);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request = request.set(name, db);
+        }
+        if let Some(session_id) = self.session_id() {
+            request = request.set("Cookie", &format!("session_id={}", session_id));
+        }
+        let response = request.call()?;
+        if response.get_url() != url {
+            return Ok(None);
+        }
+        let mut data = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut data)
+            .map_err(ureq::Error::from)?;
+        Ok(Some(data))
+    }
+
+    /// Fetch page `page` of the `search_read` results for `domain`, with the total count
+    ///
+    /// This sends a [`search_count`](OdooClient::search_count), then a
+    /// [`search_read`](OdooClient::search_read) request. Pages are numbered
+    /// from `0`, and a `page_size` of `0` is treated as `1`. If `order` is
+    /// `None`, the model's default order is used. See [`Page`].
+    pub fn search_read_paged(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> UreqResult<Page> {
+        let page_size = page_size.max(1);
+        let (count, read) = self.page_requests(model, domain, fields, page, page_size, order);
+        let total = count.send()?.count;
+        Ok(Page::new(read.send()?.data, total, page, page_size))
+    }
+
+    /// Fetch the field definitions of `model`, from the metadata cache if possible
+    ///
+    /// Without [`with_metadata_cache`](OdooClient::with_metadata_cache), the
+    /// fields are fetched on every call. See [`ModelMetadata`] for more info.
+    pub fn model_metadata(&self, model: &str) -> UreqResult<Arc<ModelMetadata>> {
+        match self.cached_metadata(model) {
+            Some(metadata) => Ok(metadata),
+            None => self.refresh_model_metadata(model),
+        }
+    }
+
+    /// Fetch the field definitions of `model`, skipping (and updating) the metadata cache
+    pub fn refresh_model_metadata(&self, model: &str) -> UreqResult<Arc<ModelMetadata>> {
+        let response = self.metadata_request(model).send()?;
+        Ok(self.store_metadata(model, response))
+    }
+
+    /// Iterate over the `search_read` results for `domain`, in pages of `batch_size`
+    ///
+    /// The records are fetched in `id` order, with keyset pagination. See
+    /// [`SearchReadIter`] for more info.
+    pub fn search_read_iter(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        batch_size: u32,
+    ) -> SearchReadIter<'_, UreqError> {
+        let model = model.to_string();
+        SearchReadIter::new(domain, batch_size, move |domain| {
+            self.search_read(&model, domain, fields.clone())
+                .limit(batch_size)
+                .order("id")
+                .send()
+                .map(|response| response.data)
+        })
+    }
+}
+
+impl<'a, T> OdooRequest<'a, T, UreqBlocking>
+where
+    T: JsonRpcParams + Debug + Serialize,
+    T::Container<T>: Debug + Serialize,
+{
+    pub fn send(self) -> UreqResult<T::Response> {
+        Ok(self.send_internal()?.0)
+    }
+
+    /// Send the request, returning the raw `result` as a [`Value`]
+    ///
+    /// This skips the typed response, e.g. for debugging a customized server
+    /// which returns a different shape. Errors are returned as with `send()`.
+    pub fn send_raw(self) -> UreqResult<Value> {
+        Ok(self.send_internal()?.0)
+    }
+
+    /// Send the request, and return how it was sent (e.g. the number of attempts)
+    ///
+    /// With a [`RetryPolicy`](crate::client::RetryPolicy), failures which didn't
+    /// reach Odoo, serialization failures, and empty responses are retried; the
+    /// attempts are counted in the [`ResponseMeta`].
+    pub fn send_with_meta(self) -> UreqResult<(T::Response, ResponseMeta)> {
+        let ((response, _), meta) = self.send_internal_meta()?;
+        Ok((response, meta))
+    }
+
+    fn send_internal<D: Debug + DeserializeOwned>(self) -> UreqResult<(D, Option<String>)> {
+        Ok(self.send_internal_meta()?.0)
+    }
+
+    fn send_internal_meta<D: Debug + DeserializeOwned>(
+        self,
+    ) -> UreqResult<((D, Option<String>), ResponseMeta)> {
+        let (url, headers, data) = self.prepare()?;
+        let start = Instant::now();
+        let mut attempt = 0;
+        loop {
+            match self.send_attempt(&url, &headers, &data, &mut attempt) {
+                Err(UreqError::JsonRpcError(error)) if error.is_serialization_failure() => {
+                    match self
+                        .retry
+                        .and_then(|retry| retry.serialization_failure_delay(attempt))
+                    {
+                        Some(delay) => std::thread::sleep(delay),
+                        None => return Err(UreqError::JsonRpcError(error)),
+                    }
+                    attempt += 1;
+                }
+                Err(UreqError::EmptyResponse) => {
+                    match self
+                        .retry
+                        .and_then(|retry| retry.empty_response_delay(attempt))
+                    {
+                        Some(delay) => std::thread::sleep(delay),
+                        None => return Err(UreqError::EmptyResponse),
+                    }
+                    attempt += 1;
+                }
+                result => return Ok((result?, ResponseMeta::new(attempt, start.elapsed()))),
+            }
+        }
+    }
+
+    /// Send the request once (retrying any failures which didn't reach Odoo)
+    ///
+    /// `attempt` is the number of retries so far, and is incremented for each retry.
+    fn send_attempt<D: Debug + DeserializeOwned>(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+        data: &Value,
+        attempt: &mut u32,
+    ) -> UreqResult<(D, Option<String>)> {
+        let span = RequestSpan::start(url, data);
+        let timer = MetricsTimer::start(&self.metrics_hooks, url, data);
+        let response = loop {
+            if let Some(limiter) = &self.rate_limit {
+                limiter.wait_blocking();
+            }
+            let mut request = self._impl.agent.post(url);
+            if let Some(timeout) = self.timeout {
+                request = request.timeout(timeout);
+            }
+            if let Some(session_id) = &self.session_id {
+                request = request.set("Cookie", &format!("session_id={}", session_id));
+            }
+            for (name, value) in headers {
+                request = request.set(name, value);
+            }
+            let response = self._impl.send(request, data);
+            let retryable = match &response {
+                Err(UreqError::UreqError(err)) => match err.as_ref() {
+                    ureq::Error::Status(status, _) => retry::is_retryable_status(*status),
+                    ureq::Error::Transport(err) => err.kind() == ureq::ErrorKind::ConnectionFailed,
+                },
+                _ => false,
+            };
+            match self.retry.and_then(|retry| retry.delay(*attempt)) {
+                Some(delay) if retryable => std::thread::sleep(delay),
+                _ => break response?,
+            }
+            *attempt += 1;
+        };
+
+        let session_id = response
+            .all("set-cookie")
+            .into_iter()
+            .find_map(parse_session_id);
+        let status = response.status();
+        let content_length = response
+            .header("Content-Length")
+            .and_then(|length| length.parse().ok());
+        let mut buffer = LimitedBody::new(self.max_response_size, content_length)?;
+        io::copy(&mut response.into_reader(), &mut buffer)
+            .map_err(|err| buffer.or(UreqError::from(ureq::Error::from(err))))?;
+        let body = buffer.into_inner();
+        self.on_response(status, &String::from_utf8_lossy(&body));
+        let result = self.parse_response(&body);
+        span.finish(&body, &result);
+        timer.finish(status, &body, &result);
+        Ok((result?, session_id))
+    }
+}
+
+/// Extract the `session_id` value from a `Set-Cookie` header
+fn parse_session_id(cookie: &str) -> Option<String> {
+    let (name, value) = cookie.split(';').next()?.split_once('=')?;
+    (name.trim() == "session_id").then(|| value.trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::http_impl::test_server::serve;
+    use crate::client::SessionPassword;
+    use crate::fixtures;
+    use serde_json::json;
+    use std::thread;
+    use tiny_http::{Header, Response, Server};
+
+    #[test]
+    fn authenticate_and_send() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve(vec![
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {"uid": 2, "is_admin": true}
+            }),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "result": [1, 2, 3]
+            }),
+        ]);
+
+        let client =
+            OdooClient::new_ureq(&url).authenticate("some-database", "admin", "password")?;
+        assert_eq!(client.session_id(), Some("abc123"));
+
+        let ids = client.search("res.partner", jvec![]).send()?;
+        assert_eq!(ids.records, vec![1, 2, 3]);
+
+        let received = handle.join().unwrap();
+        assert_eq!(
+            received[0].1["params"],
+            json!({"db": "some-database", "login": "admin", "password": "password"})
+        );
+        assert_eq!(received[0].2, None);
+        assert_eq!(received[1].1["params"]["args"][4], json!("search"));
+        assert_eq!(received[1].2.as_deref(), Some("session_id=abc123"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn saved_session() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, handle) = serve(vec![
+            json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": 2, "is_admin": true}}),
+            json!({"jsonrpc": "2.0", "id": 2, "result": {"uid": 2}}),
+            json!({"jsonrpc": "2.0", "id": 3, "result": {"uid": null}}),
+        ]);
+
+        let client =
+            OdooClient::new_ureq(&url).authenticate("some-database", "admin", "password")?;
+        let saved = client.to_saved(SessionPassword::Omit);
+        assert_eq!(saved.session_id.as_deref(), Some("abc123"));
+
+        // the session (not the omitted password) is checked
+        let client = OdooClient::new_ureq(&url).restore_session(saved);
+        assert!(client.verify_session()?);
+        assert!(!client.verify_session()?);
+
+        let received = handle.join().unwrap();
+        for (path, _body, cookie) in &received[1..] {
+            assert_eq!(path, "/web/session/get_session_info");
+            assert_eq!(cookie.as_deref(), Some("session_id=abc123"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn jsonrpc_error() {
+        let (url, handle) = serve(vec![fixtures::errors::access_denied()]);
+
+        let client = OdooClient::new_ureq(&url);
+        let err = client.db_list(false).send().unwrap_err();
+        assert!(matches!(err, UreqError::DbListingDisabled), "{:?}", err);
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn session_id_cookie() {
+        assert_eq!(
+            parse_session_id("session_id=abc123; HttpOnly; Path=/"),
+            Some("abc123".into())
+        );
+        assert_eq!(parse_session_id("frontend_lang=en_US; Path=/"), None);
+        assert_eq!(parse_session_id("garbage"), None);
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn send_sync() {
+        assert_send_sync::<OdooClient<Authed, UreqBlocking>>();
+    }
+
+    #[test]
+    fn web_db() -> Result<(), Box<dyn std::error::Error>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut bodies = Vec::new();
+            for status in [200, 404] {
+                let mut request = server.recv().unwrap();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let page = concat!(
+                    "<html><div class=\"alert alert-danger\">",
+                    "Database duplication error: Access Denied</div></html>"
+                );
+                let response = Response::from_string(page)
+                    .with_status_code(status)
+                    .with_header(Header::from_bytes("Content-Type", "text/html").unwrap());
+                bodies.push(body);
+                request.respond(response).unwrap();
+            }
+            bodies
+        });
+
+        let client = OdooClient::new_ureq(&url);
+        match client.web_db_duplicate("wrong", "acme", "acme copy") {
+            Err(UreqError::DatabaseManager { message }) => {
+                assert_eq!(message, "Database duplication error: Access Denied")
+            }
+            result => panic!("expected a DatabaseManager error, got {:?}", result),
+        }
+
+        // the manager is disabled (`list_db = False`)
+        match client.web_db_backup("master", "acme", BackupFormat::Dump) {
+            Err(UreqError::DatabaseManager { message }) => assert_eq!(message, "HTTP 404"),
+            result => panic!("expected a DatabaseManager error, got {:?}", result),
+        }
+
+        let bodies = handle.join().unwrap();
+        assert_eq!(
+            bodies,
+            vec![
+                "master_pwd=wrong&name=acme&new_name=acme+copy",
+                "master_pwd=master&name=acme&backup_format=dump"
+            ]
+        );
+
+        Ok(())
+    }
+}
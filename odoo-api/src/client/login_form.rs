@@ -0,0 +1,111 @@
+//! Shared logic for the `/web/login` form login
+//!
+//! Some servers (e.g. odoo.com SaaS trial databases) occasionally reject
+//! programmatic logins via `/web/session/authenticate`, while the HTML login
+//! form still works. `authenticate_via_login_form()` fetches the form (for its
+//! `csrf_token`), posts the credentials, then reads the `uid` from
+//! `/web/session/get_session_info`.
+//!
+//! As with the TOTP login, this relies on the session cookie, so it's only
+//! implemented for the `reqwest` impls.
+
+use super::web_db::{url_encode, WebBody, WebResponse};
+
+/// The login form
+pub(crate) const LOGIN_ENDPOINT: &str = "/web/login";
+
+/// The login form URL, selecting the database `db`
+pub(crate) fn login_endpoint(db: &str) -> String {
+    format!("{}?db={}", LOGIN_ENDPOINT, url_encode(db))
+}
+
+/// Build the login form
+pub(crate) fn login_body(csrf_token: &str, db: &str, login: &str, password: &str) -> WebBody {
+    WebBody::Form(vec![
+        ("csrf_token".into(), csrf_token.into()),
+        ("db".into(), db.into()),
+        ("login".into(), login.into()),
+        ("password".into(), password.into()),
+    ])
+}
+
+/// Find the error on a login form response, if the login failed
+///
+/// On success, Odoo redirects to the webclient; on failure, it re-renders the
+/// form with an `alert-danger` (e.g. "Wrong login/password").
+pub(crate) fn login_error(response: &WebResponse) -> Option<String> {
+    if response.status >= 400 {
+        return Some(format!("HTTP {}", response.status));
+    }
+    let body = String::from_utf8_lossy(&response.body);
+    let start = body.find("alert-danger")?;
+    let start = start + body[start..].find('>')? + 1;
+    let end = start + body[start..].find('<')?;
+    let text = body[start..end].split_whitespace().collect::<Vec<_>>();
+    Some(match text.is_empty() {
+        true => "the login form was rejected".into(),
+        false => text.join(" "),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::totp::csrf_token;
+
+    /// A (trimmed) Odoo 17.0 `/web/login` page
+    const LOGIN_PAGE: &str = r#"
+        <form class="oe_login_form" role="form" action="/web/login" method="post">
+            <input type="hidden" name="csrf_token" value="0f1e2d3c4b5a69788796a5b4c3d2e1f0a9b8c7d6o1735689600"/>
+            <div class="mb-3 field-login">
+                <label for="login" class="form-label">Email</label>
+                <input type="text" placeholder="Email" name="login" id="login" required="required" autofocus="autofocus" autocapitalize="off" class="form-control "/>
+            </div>
+            <div class="mb-3">
+                <label for="password" class="form-label">Password</label>
+                <input type="password" placeholder="Password" name="password" id="password" required="required" autocomplete="current-password" maxlength="4096" class="form-control "/>
+            </div>
+            %ERROR%
+            <input type="hidden" name="redirect"/>
+        </form>
+    "#;
+
+    fn page(status: u16, error: &str) -> WebResponse {
+        WebResponse {
+            status,
+            content_type: Some("text/html; charset=utf-8".into()),
+            body: LOGIN_PAGE.replace("%ERROR%", error).into_bytes(),
+        }
+    }
+
+    #[test]
+    fn login_page() {
+        assert_eq!(
+            csrf_token(LOGIN_PAGE).as_deref(),
+            Some("0f1e2d3c4b5a69788796a5b4c3d2e1f0a9b8c7d6o1735689600")
+        );
+        assert_eq!(login_endpoint("acme trial"), "/web/login?db=acme+trial");
+
+        let (_, body) = login_body("abc", "acme", "admin@example.com", "p&ss").encode();
+        assert_eq!(
+            body,
+            b"csrf_token=abc&db=acme&login=admin%40example.com&password=p%26ss"
+        );
+    }
+
+    #[test]
+    fn login_errors() {
+        assert_eq!(login_error(&page(200, "")), None);
+        assert_eq!(
+            login_error(&page(
+                200,
+                r#"<p class="alert alert-danger" role="alert">
+                    Wrong login/password
+                </p>"#
+            ))
+            .as_deref(),
+            Some("Wrong login/password")
+        );
+        assert_eq!(login_error(&page(500, "")).as_deref(), Some("HTTP 500"));
+    }
+}
@@ -0,0 +1,187 @@
+//! Shared logic for the `merge_partners()` client method
+//!
+//! Odoo merges contacts with the `base.partner.merge.automatic.wizard`. Each
+//! [`RequestImpl`](super::RequestImpl) implements `merge_partners()` itself (as
+//! `send()` is either blocking or async), by creating the wizard with the
+//! [`wizard_values`], then calling its `action_merge` method.
+//!
+//! The wizard reports its checks as `UserError`s, which are recognised by
+//! [`rejection`] and returned as `PartnerMergeRejected` errors.
+
+use super::error::ParseResponseError;
+use crate::jsonrpc::response::JsonRpcErrorData;
+use crate::jsonrpc::{OdooId, OdooIds};
+use crate::service::object::ExecuteKw;
+use serde_json::{json, Map, Value};
+
+/// The merge wizard model
+pub(crate) const WIZARD_MODEL: &str = "base.partner.merge.automatic.wizard";
+
+/// The wizard method which performs the merge
+pub(crate) const ACTION_MERGE: &str = "action_merge";
+
+/// Why Odoo refused to merge a set of partners
+///
+/// See `merge_partners()` on the [`OdooClient`](super::OdooClient).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeRejection {
+    /// More than 3 partners were given (including the one that's kept)
+    ///
+    /// Odoo limits each merge to 3 contacts "for safety reasons", so larger
+    /// sets must be merged in several steps.
+    TooManyPartners,
+
+    /// One of the partners is a parent (or child) of another
+    ParentChild,
+
+    /// The partners have different emails (only the Administrator can merge these)
+    DifferentEmails,
+
+    /// The partners belong to different companies
+    DifferentCompanies,
+
+    /// A partner that would be removed is linked to journal items (only the
+    /// Administrator can merge these)
+    JournalItems,
+}
+
+/// Build the wizard values, merging `merge_ids` into `keep_id`
+///
+/// `partner_ids` is set with a `(6, 0, ids)` command, and must include the
+/// destination partner too.
+pub(crate) fn wizard_values(keep_id: OdooId, merge_ids: OdooIds) -> Map<String, Value> {
+    let mut ids = vec![keep_id];
+    ids.extend(merge_ids.into_inner());
+    let ids = OdooIds::from(ids).dedup();
+
+    let mut values = Map::new();
+    values.insert("partner_ids".into(), json!([[6, 0, ids]]));
+    values.insert("dst_partner_id".into(), keep_id.into());
+    values
+}
+
+/// Recognise the merge wizard's `UserError` `message`
+///
+/// These are matched on the (English) messages, so other languages fall back
+/// to the plain server error.
+pub(crate) fn rejection(message: &str) -> Option<MergeRejection> {
+    let message = message.to_lowercase();
+    let rejection = if message.contains("cannot merge more than") {
+        MergeRejection::TooManyPartners
+    } else if message.contains("with one of his parent")
+        || message.contains("with one of their parent")
+    {
+        MergeRejection::ParentChild
+    } else if message.contains("must have the same email") {
+        MergeRejection::DifferentEmails
+    } else if message.contains("incompatible compan") || message.contains("different compan") {
+        MergeRejection::DifferentCompanies
+    } else if message.contains("journal items") {
+        MergeRejection::JournalItems
+    } else {
+        return None;
+    };
+    Some(rejection)
+}
+
+/// Map a merge wizard `UserError` on an `action_merge` call to a `PartnerMergeRejected` error
+///
+/// This is the [`map_error`](crate::jsonrpc::JsonRpcParams::map_error) hook for
+/// [`ExecuteKw`]; any other `execute_kw` error is left as-is.
+pub(crate) fn execute_kw_error(
+    call: &ExecuteKw,
+    error: &JsonRpcErrorData,
+) -> Option<ParseResponseError> {
+    if call.model != WIZARD_MODEL
+        || call.method != ACTION_MERGE
+        || !error.name.ends_with("exceptions.UserError")
+    {
+        return None;
+    }
+    rejection(&error.message).map(|reason| ParseResponseError::PartnerMergeRejected {
+        reason,
+        message: error.message.clone(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn values() {
+        let values = wizard_values(7, vec![8, 7, 9].into());
+        assert_eq!(
+            Value::Object(values),
+            json!({
+                "partner_ids": [[6, 0, [7, 8, 9]]],
+                "dst_partner_id": 7
+            })
+        );
+    }
+
+    #[test]
+    fn rejections() {
+        assert_eq!(
+            rejection(
+                "For safety reasons, you cannot merge more than 3 contacts together. You can \
+                 re-open the wizard several times if needed."
+            ),
+            Some(MergeRejection::TooManyPartners)
+        );
+        assert_eq!(
+            rejection("You cannot merge a contact with one of his parent."),
+            Some(MergeRejection::ParentChild)
+        );
+        assert_eq!(
+            rejection(
+                "All contacts must have the same email. Only the Administrator can merge \
+                 contacts with different emails."
+            ),
+            Some(MergeRejection::DifferentEmails)
+        );
+        assert_eq!(
+            rejection("Incompatible companies on records:\n- 'Acme' belongs to company 'B'"),
+            Some(MergeRejection::DifferentCompanies)
+        );
+        assert_eq!(
+            rejection(
+                "Only the destination contact may be linked to existing Journal Items. Please \
+                 ask the Administrator if you need to merge several contacts linked to existing \
+                 Journal Items."
+            ),
+            Some(MergeRejection::JournalItems)
+        );
+        assert_eq!(rejection("Something else went wrong"), None);
+    }
+
+    #[test]
+    fn execute_kw_errors() {
+        let call = |model: &str, method: &str| ExecuteKw {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+            model: model.into(),
+            method: method.into(),
+            args: vec![json!([1])],
+            kwargs: Map::new(),
+        };
+        let error = JsonRpcErrorData {
+            name: "odoo.exceptions.UserError".into(),
+            message: "You cannot merge a contact with one of his parent.".into(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            execute_kw_error(&call(WIZARD_MODEL, ACTION_MERGE), &error),
+            Some(ParseResponseError::PartnerMergeRejected {
+                reason: MergeRejection::ParentChild,
+                ..
+            })
+        ));
+
+        // the same message from any other model/method is left as-is
+        assert!(execute_kw_error(&call("res.partner", ACTION_MERGE), &error).is_none());
+        assert!(execute_kw_error(&call(WIZARD_MODEL, "write"), &error).is_none());
+    }
+}
@@ -0,0 +1,163 @@
+//! The [`ModelMetadata`] type, and the cache behind the `model_metadata()` client method
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `model_metadata()` and
+//! `refresh_model_metadata()` itself (as `send()` is either blocking or async).
+//! The cache is enabled with [`OdooClient::with_metadata_cache`], and shared
+//! between clones of the client.
+
+use super::{Authed, OdooClient, OdooRequest, RequestImpl};
+use crate::service::orm::{FieldsGet, FieldsGetResponse};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The field attributes fetched by `model_metadata()`
+const ATTRIBUTES: &[&str] = &[
+    "string",
+    "type",
+    "help",
+    "selection",
+    "relation",
+    "required",
+    "readonly",
+];
+
+/// The field definitions of a model, from [`fields_get`](FieldsGet)
+///
+/// Labels and selection values are translated into the `lang` from the
+/// client-wide context (see [`OdooClientBuilder::default_context`](super::OdooClientBuilder::default_context)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelMetadata {
+    /// The Odoo model
+    pub model: String,
+
+    /// The attributes of each field, by field name
+    pub fields: Map<String, Value>,
+
+    /// When the fields were fetched
+    pub fetched_at: Instant,
+}
+
+impl ModelMetadata {
+    /// The field's label (its `string`)
+    pub fn field_label(&self, field: &str) -> Option<&str> {
+        self.fields.get(field)?.get("string")?.as_str()
+    }
+
+    /// The field's type (e.g. `char` or `many2one`)
+    pub fn field_type(&self, field: &str) -> Option<&str> {
+        self.fields.get(field)?.get("type")?.as_str()
+    }
+
+    /// The `(value, label)` options of a `selection` field
+    ///
+    /// Returns `None` if the field doesn't exist, or isn't a selection field.
+    pub fn selection_options(&self, field: &str) -> Option<Vec<(&str, &str)>> {
+        let options = self.fields.get(field)?.get("selection")?.as_array()?;
+        options
+            .iter()
+            .map(|option| match option.as_array()?.as_slice() {
+                [value, label] => Some((value.as_str()?, label.as_str()?)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The cache key: the database, model, and `lang`
+type CacheKey = (String, String, Option<String>);
+
+/// The cached [`ModelMetadata`], which expires after `ttl`
+pub(crate) struct MetadataCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<CacheKey, Arc<ModelMetadata>>>,
+}
+
+impl MetadataCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// The cache key for `model`
+    fn metadata_key(&self, model: &str) -> CacheKey {
+        let lang = self.context.get("lang").and_then(Value::as_str);
+        (
+            self.auth.database.clone(),
+            model.into(),
+            lang.map(String::from),
+        )
+    }
+
+    /// The cached metadata for `model`, if it hasn't expired
+    pub(crate) fn cached_metadata(&self, model: &str) -> Option<Arc<ModelMetadata>> {
+        let cache = self.metadata_cache.as_ref()?;
+        let entries = cache.entries.lock().unwrap();
+        entries
+            .get(&self.metadata_key(model))
+            .filter(|metadata| metadata.fetched_at.elapsed() < cache.ttl)
+            .cloned()
+    }
+
+    /// Build the `fields_get` request for `model`
+    pub(crate) fn metadata_request(&self, model: &str) -> OdooRequest<'_, FieldsGet, I> {
+        let attributes = ATTRIBUTES.iter().map(|attr| attr.to_string()).collect();
+        self.fields_get(model).attributes(attributes)
+    }
+
+    /// Build the metadata from the `fields_get` `response`, and cache it
+    pub(crate) fn store_metadata(
+        &self,
+        model: &str,
+        response: FieldsGetResponse,
+    ) -> Arc<ModelMetadata> {
+        let metadata = Arc::new(ModelMetadata {
+            model: model.into(),
+            fields: response.fields,
+            fetched_at: Instant::now(),
+        });
+        if let Some(cache) = &self.metadata_cache {
+            cache
+                .entries
+                .lock()
+                .unwrap()
+                .insert(self.metadata_key(model), metadata.clone());
+        }
+        metadata
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accessors() {
+        let metadata = ModelMetadata {
+            model: "res.partner".into(),
+            fields: jmap! {
+                "email": {"string": "Email", "type": "char"},
+                "type": {
+                    "string": "Address Type",
+                    "type": "selection",
+                    "selection": [["contact", "Contact"], ["invoice", "Invoice Address"]]
+                }
+            },
+            fetched_at: Instant::now(),
+        };
+
+        assert_eq!(metadata.field_label("email"), Some("Email"));
+        assert_eq!(metadata.field_type("type"), Some("selection"));
+        assert_eq!(
+            metadata.selection_options("type"),
+            Some(vec![("contact", "Contact"), ("invoice", "Invoice Address")])
+        );
+        assert_eq!(metadata.selection_options("email"), None);
+        assert_eq!(metadata.field_label("missing"), None);
+    }
+}
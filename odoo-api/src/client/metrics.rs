@@ -0,0 +1,456 @@
+//! Per-request metrics, reported to the hooks registered with
+//! [`OdooClient::with_metrics`](super::OdooClient::with_metrics)
+//!
+//! Each attempt is timed from when it's sent (including any retries of
+//! transport failures) until its response is parsed. The hooks are called
+//! once the response is parsed, or when the attempt fails (e.g., with a
+//! transport error). With the `metrics` feature, [`RequestMetrics::record`]
+//! emits them via the [`metrics`](https://docs.rs/metrics) crate.
+
+use crate::client::error::{ParseResponseError, ParseResponseResult};
+use crate::client::timer;
+use crate::jsonrpc::JsonRpcId;
+use serde_json::Value;
+use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A hook registered with `with_metrics()`
+pub(crate) type MetricsHook = Arc<dyn Fn(RequestMetrics) + Send + Sync>;
+
+/// How a request was handled, passed to the `with_metrics()` hooks
+///
+/// The service/method fields are only set for the `/jsonrpc` methods; the
+/// "Web" methods are identified by their [`endpoint`](Self::endpoint).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetrics {
+    /// The URL path (e.g., `/jsonrpc`, `/web/session/authenticate`)
+    pub endpoint: String,
+
+    /// The JSON-RPC service (e.g., `object`)
+    pub service: Option<String>,
+
+    /// The JSON-RPC method (e.g., `execute_kw`)
+    pub method: Option<String>,
+
+    /// For `execute`/`execute_kw`, the model
+    pub model: Option<String>,
+
+    /// For `execute`/`execute_kw`, the ORM method (e.g., `search_read`)
+    pub orm_method: Option<String>,
+
+    /// The JSON-RPC request id
+    pub id: Option<JsonRpcId>,
+
+    /// The HTTP status, if a response was received
+    ///
+    /// The closure-based impls don't have access to the HTTP status, so they
+    /// report `200` (matching [`Middleware::on_response`](super::Middleware::on_response)).
+    pub status: Option<u16>,
+
+    /// The time taken
+    pub elapsed: Duration,
+
+    /// The size of the JSON request body, in bytes (before any compression)
+    pub request_bytes: usize,
+
+    /// The size of the JSON response body, in bytes (0 if there was no
+    /// response, or after any decompression)
+    pub response_bytes: usize,
+
+    /// Whether the request succeeded
+    pub outcome: RequestOutcome,
+
+    /// The Python exception name, for JSON-RPC errors
+    pub error_name: Option<String>,
+}
+
+/// The outcome of a request, see [`RequestMetrics`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestOutcome {
+    /// The response was parsed successfully
+    Success,
+
+    /// Odoo returned a JSON-RPC error (e.g., an `AccessError`)
+    Error,
+
+    /// The request failed before a valid response was parsed (e.g., a
+    /// transport error, or an invalid response body)
+    Failed,
+}
+
+impl RequestOutcome {
+    /// The outcome name (`success`, `error`, or `failed`), as used by the
+    /// `tracing` spans and the `metrics` labels
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Error => "error",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl RequestMetrics {
+    /// Emit these metrics via the [`metrics`](https://docs.rs/metrics) crate
+    ///
+    /// The following are emitted:
+    ///  - `odoo_requests_total` (counter)
+    ///  - `odoo_request_duration_seconds` (histogram)
+    ///  - `odoo_request_bytes`, `odoo_response_bytes` (histograms)
+    ///
+    /// The counter and duration are labeled with the `endpoint`, `service`,
+    /// `method` (the ORM method, for `execute_kw`), `model`, and `outcome`.
+    /// The sizes are labeled with the `endpoint` and `service` only.
+    ///
+    /// Register this as a hook to record every request:
+    /// ```no_run
+    /// # #[cfg(feature = "async")]
+    /// # fn test() -> odoo_api::client::Result<()> {
+    /// use odoo_api::OdooClient;
+    ///
+    /// let mut client = OdooClient::new_reqwest_async("https://demo.odoo.com")?;
+    /// client.with_metrics(|metrics| metrics.record());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn record(&self) {
+        let labels = [
+            ("endpoint", self.endpoint.clone()),
+            ("service", self.service.clone().unwrap_or_default()),
+            (
+                "method",
+                self.orm_method
+                    .clone()
+                    .or(self.method.clone())
+                    .unwrap_or_default(),
+            ),
+            ("model", self.model.clone().unwrap_or_default()),
+            ("outcome", self.outcome.as_str().to_string()),
+        ];
+        ::metrics::counter!("odoo_requests_total", &labels).increment(1);
+        ::metrics::histogram!("odoo_request_duration_seconds", &labels)
+            .record(self.elapsed.as_secs_f64());
+
+        let labels = &labels[..2];
+        ::metrics::histogram!("odoo_request_bytes", labels).record(self.request_bytes as f64);
+        ::metrics::histogram!("odoo_response_bytes", labels).record(self.response_bytes as f64);
+    }
+}
+
+/// A guard which reports the [`RequestMetrics`] for an attempt
+///
+/// Call [`finish`](Self::finish) once the response has been parsed. If the
+/// guard is dropped before then (e.g., because the request failed to send),
+/// the hooks are called with [`RequestOutcome::Failed`]. Without any hooks,
+/// this is a no-op.
+pub(crate) struct MetricsTimer {
+    hooks: Vec<MetricsHook>,
+    metrics: Option<RequestMetrics>,
+    start: Instant,
+}
+
+impl MetricsTimer {
+    /// Start timing a request to `url`
+    pub(crate) fn start(hooks: &[MetricsHook], url: &str, body: &Value) -> Self {
+        let metrics = (!hooks.is_empty()).then(|| {
+            let params = &body["params"];
+            let mut metrics = RequestMetrics {
+                endpoint: endpoint(url).into(),
+                service: None,
+                method: None,
+                model: None,
+                orm_method: None,
+                id: body["id"].as_u64().and_then(|id| id.try_into().ok()),
+                status: None,
+                elapsed: Duration::ZERO,
+                request_bytes: json_size(body),
+                response_bytes: 0,
+                outcome: RequestOutcome::Failed,
+                error_name: None,
+            };
+            if let (Some(service), Some(method)) =
+                (params["service"].as_str(), params["method"].as_str())
+            {
+                if service == "object" && method.starts_with("execute") {
+                    metrics.model = params["args"][3].as_str().map(String::from);
+                    metrics.orm_method = params["args"][4].as_str().map(String::from);
+                }
+                metrics.service = Some(service.into());
+                metrics.method = Some(method.into());
+            }
+            metrics
+        });
+
+        Self {
+            hooks: hooks.to_vec(),
+            metrics,
+            start: timer::now(),
+        }
+    }
+
+    /// Record the response, and call the hooks
+    pub(crate) fn finish<D>(
+        mut self,
+        status: u16,
+        response: &[u8],
+        result: &ParseResponseResult<D>,
+    ) {
+        if let Some(metrics) = &mut self.metrics {
+            metrics.status = Some(status);
+            metrics.response_bytes = response.len();
+            match result {
+                Ok(_) => metrics.outcome = RequestOutcome::Success,
+                Err(ParseResponseError::JsonRpcError(error)) => {
+                    metrics.outcome = RequestOutcome::Error;
+                    metrics.error_name = Some(error.data.name.clone());
+                }
+                Err(_) => metrics.outcome = RequestOutcome::Failed,
+            }
+        }
+    }
+}
+
+impl Drop for MetricsTimer {
+    fn drop(&mut self) {
+        let Some(mut metrics) = self.metrics.take() else {
+            return;
+        };
+        metrics.elapsed = timer::now().duration_since(self.start);
+        if let Some((last, hooks)) = self.hooks.split_last() {
+            for hook in hooks {
+                hook(metrics.clone());
+            }
+            last(metrics);
+        }
+    }
+}
+
+/// Fetch the path component of `url`
+pub(crate) fn endpoint(url: &str) -> &str {
+    let host = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    host.find('/').map(|i| &host[i..]).unwrap_or("/")
+}
+
+/// The serialized size of `body`, without allocating it
+fn json_size(body: &Value) -> usize {
+    struct Counter(usize);
+
+    impl io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = serde_json::to_writer(&mut counter, body);
+    counter.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::error::{ClosureError, Result};
+    use crate::client::odoo_request::test::{capture_client, capture_client_with};
+    use crate::client::OdooClient;
+    use crate::fixtures;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    type Reported = Arc<Mutex<Vec<RequestMetrics>>>;
+
+    /// A hook which collects the reported metrics
+    fn collect() -> (Reported, impl Fn(RequestMetrics) + Send + Sync + 'static) {
+        let reported: Reported = Arc::default();
+        let inner = reported.clone();
+        (reported, move |metrics| inner.lock().unwrap().push(metrics))
+    }
+
+    #[test]
+    fn success() -> Result<()> {
+        let (mut client, captured) = capture_client();
+        let (reported, hook) = collect();
+        client.with_metrics(hook);
+
+        client.search("res.partner", jvec![]).send()?;
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported.len(), 1);
+        let metrics = &reported[0];
+        assert_eq!(metrics.endpoint, "/jsonrpc");
+        assert_eq!(metrics.service.as_deref(), Some("object"));
+        assert_eq!(metrics.method.as_deref(), Some("execute_kw"));
+        assert_eq!(metrics.model.as_deref(), Some("res.partner"));
+        assert_eq!(metrics.orm_method.as_deref(), Some("search"));
+        assert_eq!(metrics.status, Some(200));
+        assert_eq!(metrics.outcome, RequestOutcome::Success);
+        assert_eq!(metrics.error_name, None);
+        assert!(metrics.elapsed < Duration::from_secs(5));
+
+        // the id and sizes match what was sent and received
+        let (body, _headers) = &captured.borrow()[0];
+        assert_eq!(metrics.id.map(u64::from), body["id"].as_u64());
+        assert_eq!(metrics.request_bytes, body.to_string().len());
+        assert_eq!(
+            metrics.response_bytes,
+            r#"{"id":1,"jsonrpc":"2.0","result":[]}"#.len()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_rpc_error() {
+        let (mut client, _captured) = capture_client_with(fixtures::errors::access_error());
+        let (reported, hook) = collect();
+        client.with_metrics(hook);
+
+        assert!(client.read("res.partner", 1, svec!["name"]).send().is_err());
+
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported[0].outcome, RequestOutcome::Error);
+        assert_eq!(
+            reported[0].error_name.as_deref(),
+            Some("odoo.exceptions.AccessError")
+        );
+        assert_eq!(reported[0].status, Some(200));
+    }
+
+    #[test]
+    fn failed() {
+        let mut client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            |_url, _data, _session_id| Err(ClosureError::ClosureError("connection refused".into())),
+        );
+        let (reported, hook) = collect();
+        let (reported_too, hook_too) = collect();
+        client.with_metrics(hook).with_metrics(hook_too);
+
+        assert!(client.db_list(false).send().is_err());
+
+        // every hook is called, even without a response
+        for reported in [reported, reported_too] {
+            let reported = reported.lock().unwrap();
+            assert_eq!(reported.len(), 1);
+            assert_eq!(reported[0].service.as_deref(), Some("db"));
+            assert_eq!(reported[0].method.as_deref(), Some("list"));
+            assert_eq!(reported[0].orm_method, None);
+            assert_eq!(reported[0].outcome, RequestOutcome::Failed);
+            assert_eq!(reported[0].status, None);
+            assert_eq!(reported[0].response_bytes, 0);
+        }
+    }
+
+    /// A `metrics` recorder which collects the registered keys
+    #[cfg(feature = "metrics")]
+    #[derive(Default)]
+    struct Keys(Mutex<Vec<String>>);
+
+    #[cfg(feature = "metrics")]
+    impl Keys {
+        fn push(&self, kind: &str, key: &::metrics::Key) {
+            let labels: Vec<String> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let key = format!("{} {}{{{}}}", kind, key.name(), labels.join(","));
+            self.0.lock().unwrap().push(key);
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    impl ::metrics::Recorder for Keys {
+        fn describe_counter(
+            &self,
+            _: ::metrics::KeyName,
+            _: Option<::metrics::Unit>,
+            _: ::metrics::SharedString,
+        ) {
+        }
+
+        fn describe_gauge(
+            &self,
+            _: ::metrics::KeyName,
+            _: Option<::metrics::Unit>,
+            _: ::metrics::SharedString,
+        ) {
+        }
+
+        fn describe_histogram(
+            &self,
+            _: ::metrics::KeyName,
+            _: Option<::metrics::Unit>,
+            _: ::metrics::SharedString,
+        ) {
+        }
+
+        fn register_counter(
+            &self,
+            key: &::metrics::Key,
+            _: &::metrics::Metadata<'_>,
+        ) -> ::metrics::Counter {
+            self.push("counter", key);
+            ::metrics::Counter::noop()
+        }
+
+        fn register_gauge(
+            &self,
+            key: &::metrics::Key,
+            _: &::metrics::Metadata<'_>,
+        ) -> ::metrics::Gauge {
+            self.push("gauge", key);
+            ::metrics::Gauge::noop()
+        }
+
+        fn register_histogram(
+            &self,
+            key: &::metrics::Key,
+            _: &::metrics::Metadata<'_>,
+        ) -> ::metrics::Histogram {
+            self.push("histogram", key);
+            ::metrics::Histogram::noop()
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn record() -> Result<()> {
+        let (mut client, _captured) = capture_client();
+        client.with_metrics(|metrics| metrics.record());
+
+        let keys = Keys::default();
+        ::metrics::with_local_recorder(&keys, || {
+            client.search("res.partner", jvec![]).send().map(|_| ())
+        })?;
+
+        let labels =
+            "endpoint=/jsonrpc,service=object,method=search,model=res.partner,outcome=success";
+        assert_eq!(
+            *keys.0.lock().unwrap(),
+            vec![
+                format!("counter odoo_requests_total{{{}}}", labels),
+                format!("histogram odoo_request_duration_seconds{{{}}}", labels),
+                "histogram odoo_request_bytes{endpoint=/jsonrpc,service=object}".to_string(),
+                "histogram odoo_response_bytes{endpoint=/jsonrpc,service=object}".to_string(),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(json_size(&json!({"a": [1, 2]})), r#"{"a":[1,2]}"#.len());
+        assert_eq!(
+            endpoint("https://example.com/web/session/authenticate"),
+            "/web/session/authenticate"
+        );
+        assert_eq!(endpoint("https://example.com"), "/");
+    }
+}
@@ -0,0 +1,175 @@
+//! The [`Middleware`] trait, for hooking into each request
+
+use serde_json::Value;
+
+/// A hook which is called around each request
+///
+/// Middleware is registered with [`OdooClient::with_middleware`](super::OdooClient::with_middleware),
+/// and is called in registration order. Each method has a default (no-op)
+/// impl, so only the relevant hooks need to be implemented.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # fn test() -> odoo_api::client::Result<()> {
+/// use odoo_api::OdooClient;
+/// use odoo_api::client::Middleware;
+/// use serde_json::Value;
+///
+/// struct CorrelationId;
+///
+/// impl Middleware for CorrelationId {
+///     fn on_request(&self, _url: &mut String, headers: &mut Vec<(String, String)>, _body: &mut Value) {
+///         headers.push(("X-Correlation-Id".into(), "abc123".into()));
+///     }
+///
+///     fn on_response(&self, status: u16, _body: &str) {
+///         println!("Odoo responded with {}", status);
+///     }
+/// }
+///
+/// let mut client = OdooClient::new_reqwest_async("https://demo.odoo.com")?;
+/// client.with_middleware(CorrelationId);
+/// # Ok(())
+/// # }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent
+    ///
+    /// The `url`, `headers`, and JSON `body` may be modified. The body is final
+    /// at this point (i.e., any per-request context has already been merged in,
+    /// and any [`map_params`](crate::client::OdooRequest::map_params) patches applied).
+    fn on_request(&self, url: &mut String, headers: &mut Vec<(String, String)>, body: &mut Value) {
+        let _ = (url, headers, body);
+    }
+
+    /// Called with the raw response, before it's parsed
+    ///
+    /// The closure-based impls don't have access to the HTTP status, so they
+    /// always report `200` (assuming the closure itself returned `Ok`).
+    fn on_response(&self, status: u16, body: &str) {
+        let _ = (status, body);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::error::Result;
+    use crate::client::odoo_request::test::capture_client;
+    use crate::client::OdooClient;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    /// Records each call, and tags the URL, headers, and body with `name`
+    struct Tag {
+        name: &'static str,
+        log: Log,
+    }
+
+    impl Middleware for Tag {
+        fn on_request(
+            &self,
+            url: &mut String,
+            headers: &mut Vec<(String, String)>,
+            body: &mut Value,
+        ) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{}:request", self.name));
+            url.push_str(&format!("/{}", self.name));
+            headers.push(("X-Tag".into(), self.name.into()));
+            match body.get_mut("tags") {
+                Some(Value::Array(tags)) => tags.push(self.name.into()),
+                _ => body["tags"] = json!([self.name]),
+            }
+        }
+
+        fn on_response(&self, status: u16, body: &str) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{}:response:{}:{}", self.name, status, body));
+        }
+    }
+
+    #[test]
+    fn middleware_order() -> Result<()> {
+        let log: Log = Arc::default();
+        let (mut client, _captured) = capture_client();
+        client
+            .with_middleware(Tag {
+                name: "first",
+                log: log.clone(),
+            })
+            .with_middleware(Tag {
+                name: "second",
+                log: log.clone(),
+            });
+
+        client.search("res.partner", jvec![]).send()?;
+
+        let response = json!({"jsonrpc": "2.0", "id": 1, "result": []}).to_string();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "first:request".to_string(),
+                "second:request".to_string(),
+                format!("first:response:200:{}", response),
+                format!("second:response:200:{}", response),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn middleware_mutation() -> Result<()> {
+        let captured = Arc::new(Mutex::new(None));
+        let inner = captured.clone();
+        let mut client = OdooClient::new_closure_blocking_with_headers(
+            "http://localhost:8069",
+            move |url, data, _session_id, headers| {
+                *inner.lock().unwrap() = Some((url.to_string(), headers.to_vec(), data));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": []}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+        let log: Log = Arc::default();
+        client
+            .with_middleware(Tag {
+                name: "first",
+                log: log.clone(),
+            })
+            .with_middleware(Tag {
+                name: "second",
+                log,
+            });
+
+        client
+            .search("res.partner", jvec![])
+            .with_header("X-Request", "search")
+            .send()?;
+
+        let (url, headers, body) = captured.lock().unwrap().take().unwrap();
+        assert_eq!(url, "http://localhost:8069/jsonrpc/first/second");
+        assert_eq!(
+            headers,
+            vec![
+                ("X-Request".to_string(), "search".to_string()),
+                ("X-Tag".to_string(), "first".to_string()),
+                ("X-Tag".to_string(), "second".to_string()),
+            ]
+        );
+        assert_eq!(body["tags"], json!(["first", "second"]));
+        assert_eq!(body["params"]["method"], json!("execute_kw"));
+
+        Ok(())
+    }
+}
@@ -5,13 +5,37 @@
 //! **TODO**: Proper examples for async/blocking, error handling, and authentication options
 
 pub use http_impl::closure_async::ClosureReturn as AsyncClosureReturn;
+pub use http_impl::closure_async::WebClosureReturn as AsyncWebClosureReturn;
 pub use http_impl::closure_blocking::ClosureReturn as BlockingClosureReturn;
+pub use middleware::Middleware;
+pub use model::Model;
 pub use odoo_client::{AuthState, Authed, NotAuthed, OdooClient, RequestImpl};
 pub use odoo_request::OdooRequest;
+pub use options::{ClientOptions, OdooClientBuilder, RateLimitOptions};
+pub use retry::{ResponseMeta, RetryPolicy};
+pub use tls::{TlsIdentity, TlsOptions};
+pub use translation::TranslationMode;
 
+pub use access::AccessMatrix;
+pub use attachment::Attachment;
+pub use batch::BatchProgress;
+pub use button::{ActionDescriptor, MethodCallResult};
+pub use db_guard::DbGuard;
+pub use db_wait::DbWait;
 pub use error::{Error, Result};
+pub use health::HealthReport;
 pub use http_impl::closure_async::ClosureAsync;
 pub use http_impl::closure_blocking::ClosureBlocking;
+pub use merge::MergeRejection;
+pub use metadata::ModelMetadata;
+pub use metrics::{RequestMetrics, RequestOutcome};
+pub use page::Page;
+pub use session::{SavedSession, SessionPassword};
+pub use stream::{Batch, Records, SearchReadIter, SearchReadStream};
+pub use web_db::{BackupFormat, FormPart, WebBody, WebResponse};
+
+#[cfg(feature = "compression")]
+pub use compression::CompressionLevel;
 
 #[cfg(feature = "async")]
 pub use http_impl::reqwest_async::ReqwestAsync;
@@ -19,7 +43,47 @@ pub use http_impl::reqwest_async::ReqwestAsync;
 #[cfg(feature = "blocking")]
 pub use http_impl::reqwest_blocking::ReqwestBlocking;
 
+#[cfg(feature = "ureq")]
+pub use http_impl::ureq_blocking::UreqBlocking;
+
+#[cfg(feature = "wasm")]
+pub use http_impl::fetch::FetchImpl;
+
+mod access;
+mod attachment;
+mod batch;
+mod body;
+mod button;
+#[cfg(feature = "compression")]
+mod compression;
+mod db_guard;
+mod db_wait;
 pub mod error;
+mod health;
 mod http_impl;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod login_form;
+pub(crate) mod merge;
+mod metadata;
+mod metrics;
+mod middleware;
+mod model;
 mod odoo_client;
-mod odoo_request;
+pub(crate) mod odoo_request;
+mod options;
+mod page;
+mod preset;
+#[cfg(any(feature = "async", feature = "blocking"))]
+mod progress;
+mod rate_limit;
+mod report;
+mod retry;
+mod session;
+mod settings;
+mod stream;
+mod timer;
+mod tls;
+mod totp;
+mod trace;
+mod translation;
+mod web_db;
@@ -0,0 +1,369 @@
+//! A handle for making ORM calls against a single model
+
+use super::{AuthState, Authed, OdooClient, OdooRequest, RequestImpl};
+use crate::jsonrpc::{OdooId, OdooIds};
+use crate::service::object::{Execute, ExecuteKw};
+use crate::service::orm::{
+    AccessOperation, ActivitySchedule, Archive, CheckAccessRights, CheckAccessRules,
+    CheckFieldAccessRights, Copy, CopyData, CopyMulti, Create, CreateVals, DisplayNames, Exists,
+    FieldsGet, GetExternalId, GetMetadata, GetXmlId, MessagePost, NameCreate, NameGet, NameSearch,
+    Read, ReadGroup, Search, SearchCount, SearchRead, Unarchive, Unlink, Write,
+};
+use serde_json::{Map, Value};
+
+/// A handle for making ORM calls against a single model
+///
+/// Every ORM method takes the model name as its first argument. The handle
+/// fixes the model once, so it isn't repeated (or mistyped) on each call. Each
+/// method forwards to the client method of the same name, and optional kwargs
+/// are set on the returned request, as usual.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jvec, svec};
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let partners = client.model("res.partner");
+///
+/// let ids = partners.search(jvec![["is_company", "=", true]]).send()?;
+/// let records = partners.read(ids.records, svec!["name"]).send()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Model<'a, S, I>
+where
+    S: AuthState,
+    I: RequestImpl,
+{
+    client: &'a OdooClient<S, I>,
+    model: String,
+}
+
+impl<S, I> OdooClient<S, I>
+where
+    S: AuthState,
+    I: RequestImpl,
+{
+    /// Build a [`Model`] handle for `model` (e.g. `res.partner`)
+    pub fn model(&self, model: impl Into<String>) -> Model<'_, S, I> {
+        Model {
+            client: self,
+            model: model.into(),
+        }
+    }
+}
+
+impl<'a, S, I> Model<'a, S, I>
+where
+    S: AuthState,
+    I: RequestImpl,
+{
+    /// The model name
+    pub fn name(&self) -> &str {
+        &self.model
+    }
+
+    /// The client this handle borrows
+    pub fn client(&self) -> &'a OdooClient<S, I> {
+        self.client
+    }
+}
+
+impl<'a, I> Model<'a, Authed, I>
+where
+    I: RequestImpl,
+{
+    /// See [`Create`]
+    pub fn create<V: Into<CreateVals>>(&self, values: V) -> OdooRequest<'a, Create, I> {
+        self.client.create(self.model.as_str(), values)
+    }
+
+    /// See [`Read`]
+    pub fn read<ID: Into<OdooIds>>(
+        &self,
+        ids: ID,
+        fields: Vec<String>,
+    ) -> OdooRequest<'a, Read, I> {
+        self.client.read(self.model.as_str(), ids, fields)
+    }
+
+    /// See [`Write`]
+    pub fn write<ID: Into<OdooIds>>(
+        &self,
+        ids: ID,
+        values: Map<String, Value>,
+    ) -> OdooRequest<'a, Write, I> {
+        self.client.write(self.model.as_str(), ids, values)
+    }
+
+    /// See [`Unlink`]
+    pub fn unlink<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, Unlink, I> {
+        self.client.unlink(self.model.as_str(), ids)
+    }
+
+    /// See [`Archive`]
+    pub fn archive<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, Archive, I> {
+        self.client.archive(self.model.as_str(), ids)
+    }
+
+    /// See [`Unarchive`]
+    pub fn unarchive<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, Unarchive, I> {
+        self.client.unarchive(self.model.as_str(), ids)
+    }
+
+    /// See [`ReadGroup`]
+    pub fn read_group(
+        &self,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        groupby: Vec<String>,
+        lazy: bool,
+    ) -> OdooRequest<'a, ReadGroup, I> {
+        self.client
+            .read_group(self.model.as_str(), domain, fields, groupby, lazy)
+    }
+
+    /// See [`SearchRead`]
+    pub fn search_read(
+        &self,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+    ) -> OdooRequest<'a, SearchRead, I> {
+        self.client.search_read(self.model.as_str(), domain, fields)
+    }
+
+    /// See [`Search`]
+    pub fn search(&self, domain: Vec<Value>) -> OdooRequest<'a, Search, I> {
+        self.client.search(self.model.as_str(), domain)
+    }
+
+    /// See [`SearchCount`]
+    pub fn search_count(&self, domain: Vec<Value>) -> OdooRequest<'a, SearchCount, I> {
+        self.client.search_count(self.model.as_str(), domain)
+    }
+
+    /// See [`OdooClient::search_count_limited`]
+    pub fn search_count_limited(
+        &self,
+        domain: Vec<Value>,
+        limit: u32,
+    ) -> OdooRequest<'a, SearchCount, I> {
+        self.client
+            .search_count_limited(self.model.as_str(), domain, limit)
+    }
+
+    /// See [`Copy`]
+    pub fn copy(&self, id: OdooId) -> OdooRequest<'a, Copy, I> {
+        self.client.copy(self.model.as_str(), id)
+    }
+
+    /// See [`CopyMulti`]
+    pub fn copy_multi<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, CopyMulti, I> {
+        self.client.copy_multi(self.model.as_str(), ids)
+    }
+
+    /// See [`CopyData`]
+    pub fn copy_data<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, CopyData, I> {
+        self.client.copy_data(self.model.as_str(), ids)
+    }
+
+    /// See [`Exists`]
+    pub fn exists<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, Exists, I> {
+        self.client.exists(self.model.as_str(), ids)
+    }
+
+    /// See [`CheckAccessRights`]
+    pub fn check_access_rights(
+        &self,
+        operation: AccessOperation,
+        raise_exception: bool,
+    ) -> OdooRequest<'a, CheckAccessRights, I> {
+        self.client
+            .check_access_rights(self.model.as_str(), operation, raise_exception)
+    }
+
+    /// See [`CheckAccessRules`]
+    pub fn check_access_rules<ID: Into<OdooIds>>(
+        &self,
+        ids: ID,
+        operation: AccessOperation,
+    ) -> OdooRequest<'a, CheckAccessRules, I> {
+        self.client
+            .check_access_rules(self.model.as_str(), ids, operation)
+    }
+
+    /// See [`CheckFieldAccessRights`]
+    pub fn check_field_access_rights(
+        &self,
+        operation: AccessOperation,
+        fields: Vec<String>,
+    ) -> OdooRequest<'a, CheckFieldAccessRights, I> {
+        self.client
+            .check_field_access_rights(self.model.as_str(), operation, fields)
+    }
+
+    /// See [`FieldsGet`]
+    pub fn fields_get(&self) -> OdooRequest<'a, FieldsGet, I> {
+        self.client.fields_get(self.model.as_str())
+    }
+
+    /// See [`GetMetadata`]
+    pub fn get_metadata<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, GetMetadata, I> {
+        self.client.get_metadata(self.model.as_str(), ids)
+    }
+
+    /// See [`GetExternalId`]
+    pub fn get_external_id<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, GetExternalId, I> {
+        self.client.get_external_id(self.model.as_str(), ids)
+    }
+
+    /// See [`GetXmlId`]
+    pub fn get_xml_id<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, GetXmlId, I> {
+        self.client.get_xml_id(self.model.as_str(), ids)
+    }
+
+    /// See [`NameGet`]
+    pub fn name_get<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, NameGet, I> {
+        self.client.name_get(self.model.as_str(), ids)
+    }
+
+    /// See [`DisplayNames`]
+    pub fn display_names<ID: Into<OdooIds>>(&self, ids: ID) -> OdooRequest<'a, DisplayNames, I> {
+        self.client.display_names(self.model.as_str(), ids)
+    }
+
+    /// See [`NameCreate`]
+    pub fn name_create(&self, name: impl Into<String>) -> OdooRequest<'a, NameCreate, I> {
+        self.client.name_create(self.model.as_str(), name)
+    }
+
+    /// See [`NameSearch`]
+    pub fn name_search(&self, name: impl Into<String>) -> OdooRequest<'a, NameSearch, I> {
+        self.client.name_search(self.model.as_str(), name)
+    }
+
+    /// See [`MessagePost`]
+    pub fn message_post<ID: Into<OdooIds>>(
+        &self,
+        ids: ID,
+        body: impl Into<String>,
+    ) -> OdooRequest<'a, MessagePost, I> {
+        self.client.message_post(self.model.as_str(), ids, body)
+    }
+
+    /// See [`ActivitySchedule`]
+    pub fn activity_schedule<ID: Into<OdooIds>>(
+        &self,
+        ids: ID,
+        act_type_xmlid: impl Into<String>,
+    ) -> OdooRequest<'a, ActivitySchedule, I> {
+        self.client
+            .activity_schedule(self.model.as_str(), ids, act_type_xmlid)
+    }
+
+    /// Call an arbitrary model method, with positional arguments only
+    ///
+    /// See [`Execute`]
+    pub fn execute(
+        &self,
+        method: impl Into<String>,
+        args: Vec<Value>,
+    ) -> OdooRequest<'a, Execute, I> {
+        self.client.execute(self.model.as_str(), method, args)
+    }
+
+    /// Call an arbitrary model method, with positional and keyword arguments
+    ///
+    /// See [`ExecuteKw`]
+    pub fn execute_kw(
+        &self,
+        method: impl Into<String>,
+        args: Vec<Value>,
+        kwargs: Map<String, Value>,
+    ) -> OdooRequest<'a, ExecuteKw, I> {
+        self.client
+            .execute_kw(self.model.as_str(), method, args, kwargs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client::odoo_request::test::capture_client;
+    use crate::service::orm::AccessOperation;
+    use crate::service::test::Result;
+    use serde_json::{to_value, Value};
+
+    /// The request params (the request ids differ between calls)
+    fn params<T: serde::Serialize>(request: T) -> Result<Value> {
+        Ok(to_value(request)?["params"].clone())
+    }
+
+    #[test]
+    fn model() -> Result<()> {
+        let (client, _) = capture_client();
+        let partners = client.model("res.partner");
+        assert_eq!(partners.name(), "res.partner");
+
+        assert_eq!(
+            params(&partners.create(jmap! {"name": "Alice"}).data)?,
+            params(&client.create("res.partner", jmap! {"name": "Alice"}).data)?,
+        );
+        assert_eq!(
+            params(&partners.read(vec![1, 2], svec!["name"]).data)?,
+            params(&client.read("res.partner", vec![1, 2], svec!["name"]).data)?,
+        );
+        assert_eq!(
+            params(&partners.write(3, jmap! {"name": "Bob"}).data)?,
+            params(&client.write("res.partner", 3, jmap! {"name": "Bob"}).data)?,
+        );
+        assert_eq!(
+            params(&partners.unlink(4).data)?,
+            params(&client.unlink("res.partner", 4).data)?,
+        );
+        assert_eq!(
+            params(&partners.search(jvec![["active", "=", true]]).limit(5).data)?,
+            params(
+                &client
+                    .search("res.partner", jvec![["active", "=", true]])
+                    .limit(5)
+                    .data
+            )?,
+        );
+        assert_eq!(
+            params(&partners.search_read(jvec![], svec!["email"]).data)?,
+            params(
+                &client
+                    .search_read("res.partner", jvec![], svec!["email"])
+                    .data
+            )?,
+        );
+        assert_eq!(
+            params(
+                &partners
+                    .check_access_rights(AccessOperation::Read, false)
+                    .data
+            )?,
+            params(
+                &client
+                    .check_access_rights("res.partner", AccessOperation::Read, false)
+                    .data
+            )?,
+        );
+        assert_eq!(
+            params(
+                &partners
+                    .execute_kw("name_search", jvec!["Al"], jmap! {})
+                    .data
+            )?,
+            params(
+                &client
+                    .execute_kw("res.partner", "name_search", jvec!["Al"], jmap! {})
+                    .data
+            )?,
+        );
+
+        Ok(())
+    }
+}
@@ -1,12 +1,31 @@
 //! The [`OdooClient`] type and associated bits
 
-use super::error::{AuthenticationError, AuthenticationResult};
-use super::OdooRequest;
-use crate::jsonrpc::{JsonRpcId, JsonRpcParams, OdooId, OdooWebMethod};
+use super::db_guard::DbGuard;
+use super::error::{AuthenticationError, AuthenticationResult, Error, Result};
+use super::metadata::MetadataCache;
+use super::metrics::{MetricsHook, RequestMetrics};
+use super::odoo_request::WarningHook;
+use super::preset::FieldPresets;
+use super::rate_limit::RateLimiter;
+use super::retry::RetryPolicy;
+use super::totp::is_totp_required;
+use super::web_db::url_encode;
+use super::{Middleware, OdooRequest};
+use crate::jsonrpc::{
+    JsonRpcId, JsonRpcParams, OdooApiMethod, OdooId, OdooKwargsMethod, OdooWebMethod,
+};
+use crate::service::common::{Login, Version, VersionResponse};
+use crate::service::db::CreateDatabase;
 use crate::service::web::{SessionAuthenticate, SessionAuthenticateResponse};
+use crate::warning::Warning;
+use crate::OdooVersion;
 use serde::Serialize;
-use serde_json::{from_str, to_string};
+use serde_json::{from_str, from_value, to_string, Map, Value};
+use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 /// The "authentication" state of a client object
 ///
@@ -15,9 +34,13 @@ use std::fmt::Debug;
 pub trait AuthState {
     /// Get the current stored `session_id`, if available
     fn get_session_id(&self) -> Option<&str>;
+
+    /// Forget the stored `session_id`, e.g. when the client switches servers
+    fn clear_session_id(&mut self) {}
 }
 
 /// Implemented by "authenticated" clients
+#[derive(Clone)]
 pub struct Authed {
     pub(crate) database: String,
     pub(crate) login: String,
@@ -29,9 +52,27 @@ impl AuthState for Authed {
     fn get_session_id(&self) -> Option<&str> {
         self.session_id.as_deref()
     }
+
+    fn clear_session_id(&mut self) {
+        self.session_id = None;
+    }
+}
+
+// don't leak the password (or session) into logs
+impl Debug for Authed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Authed")
+            .field("database", &self.database)
+            .field("login", &self.login)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .field("session_id", &self.session_id.as_ref().map(|_| "***"))
+            .finish()
+    }
 }
 
 /// Implemented by "non-authenticated" clients
+#[derive(Debug, Clone)]
 pub struct NotAuthed {}
 impl AuthState for NotAuthed {
     fn get_session_id(&self) -> Option<&str> {
@@ -54,13 +95,19 @@ pub trait RequestImpl {
 /// (`execute`, `create_database`, etc), "Web" methods (`/web/session/authenticate`, etc)
 /// and ORM methods (`read_group`, `create`, etc).
 ///
+/// All request methods take `&self`, and the `reqwest` clients are `Clone + Send + Sync`,
+/// so a single authenticated client can be shared between threads or async tasks
+/// (clones share the underlying connection pool and request id counter).
+///
 /// ## Usage:
 /// ```no_run
+/// # #[cfg(feature = "async")]
 /// use odoo_api::{OdooClient, jvec, jmap};
 ///
+/// # #[cfg(feature = "async")]
 /// # async fn test() -> odoo_api::client::Result<()> {
 /// let url = "https://demo.odoo.com";
-/// let mut client = OdooClient::new_reqwest_async(url)?
+/// let client = OdooClient::new_reqwest_async(url)?
 ///     .authenticate(
 ///         "test-database",
 ///         "admin",
@@ -76,9 +123,16 @@ pub trait RequestImpl {
 /// ).send().await?;
 ///
 /// println!("Found user IDs: {:?}", user_ids.data);
+///
+/// // the auth info is available via `uid()`, `database()` and `login()`
+/// let my_tasks = client.search(
+///     "project.task",
+///     jvec![["user_ids", "in", [client.uid()]]]
+/// ).send().await?;
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct OdooClient<S, I>
 where
     S: AuthState,
@@ -89,7 +143,99 @@ where
     pub(crate) auth: S,
     pub(crate) _impl: I,
 
-    pub(crate) id: JsonRpcId,
+    /// The JSON-RPC request id counter (shared between clones)
+    pub(crate) id: Arc<AtomicU32>,
+
+    /// The client-wide context, applied to every request that accepts kwargs
+    pub(crate) context: Map<String, Value>,
+
+    /// The server version, cached after the first `server_version()` call
+    pub(crate) version: OnceLock<OdooVersion>,
+
+    /// The registered middleware, called in order around each request
+    pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+
+    /// The registered warning hooks (see `on_warning()`)
+    pub(crate) warning_hooks: Vec<WarningHook>,
+
+    /// The registered metrics hooks (see `with_metrics()`)
+    pub(crate) metrics_hooks: Vec<MetricsHook>,
+
+    /// The default timeout, applied to every request
+    pub(crate) timeout: Option<Duration>,
+
+    /// The request rate limiter (shared between clones)
+    pub(crate) rate_limit: Option<Arc<RateLimiter>>,
+
+    /// The guard against destructive `db` requests
+    pub(crate) db_guard: Option<Arc<DbGuard>>,
+
+    /// Whether to reject responses with unexpected top-level keys
+    pub(crate) strict: bool,
+
+    /// Whether to refuse `execute` requests with a trailing dict
+    pub(crate) check_kwargs_misuse: bool,
+
+    /// The current company (see `with_companies()`)
+    pub(crate) company: Option<OdooId>,
+
+    /// The maximum response body size, in bytes
+    pub(crate) max_response_size: Option<usize>,
+
+    /// The database to select on `dbfilter` deployments (see `with_database_hint()`)
+    pub(crate) database_hint: Option<String>,
+
+    /// The header used to send the `database_hint`
+    pub(crate) database_hint_header: String,
+
+    /// Replaces the `/jsonrpc` path (see `with_jsonrpc_path()`)
+    pub(crate) jsonrpc_path: Option<String>,
+
+    /// Prepended to the `/web/*` paths (see `with_web_prefix()`)
+    pub(crate) web_prefix: Option<String>,
+
+    /// The base URL for read-only requests (see `with_read_replica()`)
+    pub(crate) read_replica: Option<String>,
+
+    /// How to retry requests which didn't reach Odoo (see `with_retry()`)
+    pub(crate) retry: Option<RetryPolicy>,
+
+    /// The `User-Agent` header sent with each request
+    pub(crate) user_agent: Option<String>,
+
+    /// The `model_metadata()` cache (shared between clones)
+    pub(crate) metadata_cache: Option<Arc<MetadataCache>>,
+
+    /// The field presets (shared between clones, see `register_preset()`)
+    pub(crate) presets: Arc<FieldPresets>,
+}
+
+/// The default header for [`OdooClient::with_database_hint`]
+pub(crate) const DATABASE_HINT_HEADER: &str = "X-Odoo-Db";
+
+// the impl (and middleware) aren't `Debug`, and the auth state redacts itself
+impl<S, I> Debug for OdooClient<S, I>
+where
+    S: AuthState + Debug,
+    I: RequestImpl,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OdooClient")
+            .field("url", &self.url)
+            .field("auth", &self.auth)
+            .field("context", &self.context)
+            .field("timeout", &self.timeout)
+            .field("strict", &self.strict)
+            .field("check_kwargs_misuse", &self.check_kwargs_misuse)
+            .field("max_response_size", &self.max_response_size)
+            .field("database_hint", &self.database_hint)
+            .field("jsonrpc_path", &self.jsonrpc_path)
+            .field("web_prefix", &self.web_prefix)
+            .field("read_replica", &self.read_replica)
+            .field("retry", &self.retry)
+            .field("user_agent", &self.user_agent)
+            .finish_non_exhaustive()
+    }
 }
 
 // Base client methods
@@ -100,9 +246,9 @@ where
 {
     /// Validate and parse URLs
     ///
-    /// We cache the "/jsonrpc" endpoint because that's used across all of
-    /// the JSON-RPC methods. We also store the bare URL, because that's
-    /// used for "Web" methods
+    /// Only the bare URL is stored: every endpoint (e.g. `/jsonrpc`, or the
+    /// "Web" methods) is derived from it by [`build_endpoint`](Self::build_endpoint)
+    /// when the request is built.
     pub(crate) fn validate_url(url: &str) -> String {
         // ensure the last char isn't "/"
         let len = url.len();
@@ -113,8 +259,43 @@ where
         }
     }
 
-    pub(crate) fn build_endpoint(&self, endpoint: &str) -> String {
-        format!("{}{}", self.url, endpoint)
+    /// Build the full URL for `endpoint` (e.g. `/web/session/authenticate`)
+    ///
+    /// If a [database hint](Self::with_database_hint) is set, it's appended to
+    /// the web endpoints as `?db=`. The database manager (`/web/database/*`)
+    /// doesn't select a database, so it's left as-is.
+    ///
+    /// The [`jsonrpc_path`](Self::with_jsonrpc_path) and
+    /// [`web_prefix`](Self::with_web_prefix) overrides are applied here too.
+    pub fn build_endpoint(&self, endpoint: &str) -> String {
+        let path = self.remap_endpoint(endpoint);
+        match &self.database_hint {
+            Some(db)
+                if endpoint.starts_with("/web/") && !endpoint.starts_with("/web/database/") =>
+            {
+                let separator = if endpoint.contains('?') { '&' } else { '?' };
+                format!("{}{}{}db={}", self.url, path, separator, url_encode(db))
+            }
+            _ => format!("{}{}", self.url, path),
+        }
+    }
+
+    /// Apply the path overrides to `endpoint`
+    fn remap_endpoint<'e>(&self, endpoint: &'e str) -> Cow<'e, str> {
+        match (&self.jsonrpc_path, &self.web_prefix) {
+            (Some(path), _) if endpoint == "/jsonrpc" => Cow::Owned(path.clone()),
+            (_, Some(prefix)) if endpoint.starts_with("/web/") => {
+                Cow::Owned(format!("{}{}", prefix, endpoint))
+            }
+            _ => Cow::Borrowed(endpoint),
+        }
+    }
+
+    /// The database hint header, as a `(name, value)` pair (if a hint is set)
+    pub(crate) fn database_hint_header(&self) -> Option<(&str, &str)> {
+        self.database_hint
+            .as_deref()
+            .map(|db| (self.database_hint_header.as_str(), db))
     }
 
     /// Build the data `T` into a request for the fully-qualified endpoint `url`
@@ -122,25 +303,125 @@ where
     /// This returns an [`OdooRequest`] typed to the Clients (`self`s) [`RequestImpl`],
     /// and to its auth state. The returned request is bound by lifetime `'a` to the client.
     /// The URL is converted into a full String, so no lifetimes apply there.
-    pub(crate) fn build_request<'a, T>(&'a mut self, data: T, url: &str) -> OdooRequest<'a, T, I>
+    ///
+    /// If `T` is [read-only](JsonRpcParams::IS_READONLY) and a
+    /// [read replica](Self::with_read_replica) is set, the request is sent to
+    /// the replica instead.
+    pub(crate) fn build_request<'a, T>(&'a self, data: T, url: &str) -> OdooRequest<'a, T, I>
     where
         T: JsonRpcParams + Debug,
         T::Container<T>: Debug + Serialize,
         S: AuthState,
     {
-        OdooRequest::new(
+        let replica_url = match &self.read_replica {
+            Some(replica) if T::IS_READONLY => url
+                .strip_prefix(self.url.as_str())
+                .map(|path| format!("{}{}", replica, path)),
+            _ => None,
+        };
+        let mut request = OdooRequest::new(
             data.build(self.next_id()),
-            url.into(),
+            replica_url.clone().unwrap_or_else(|| url.into()),
             self.session_id(),
+            &self.middleware,
             &self._impl,
-        )
+        );
+        if replica_url.is_some() {
+            request.primary_url = Some(url.into());
+        }
+        request.warning_hooks = Cow::Borrowed(&self.warning_hooks);
+        request.metrics_hooks = Cow::Borrowed(&self.metrics_hooks);
+        request.timeout = self.timeout;
+        request.rate_limit = self.rate_limit.clone();
+        request.db_guard = self.db_guard.clone();
+        request.strict = self.strict;
+        request.check_kwargs_misuse = self.check_kwargs_misuse;
+        request.version = self.version();
+        request.max_response_size = self.max_response_size;
+        request.retry = self.retry;
+        if let Some(user_agent) = &self.user_agent {
+            request
+                .headers
+                .push(("User-Agent".into(), user_agent.clone()));
+        }
+        if let Some((name, db)) = self.database_hint_header() {
+            request.headers.push((name.into(), db.into()));
+        }
+        request
+    }
+
+    /// Build the data `T` into a request, applying the client-wide context
+    ///
+    /// This is identical to [`build_request`](Self::build_request), but is only
+    /// available for methods that accept kwargs (and therefore, a context).
+    pub(crate) fn build_kwargs_request<'a, T>(&'a self, data: T, url: &str) -> OdooRequest<'a, T, I>
+    where
+        T: JsonRpcParams + OdooKwargsMethod + Debug,
+        T::Container<T>: Debug + Serialize,
+        S: AuthState,
+    {
+        let mut context = self.context.clone();
+        if let (true, Some(company)) = (T::SETS_COMPANY, self.company) {
+            context.extend(company_context(company, self.version()));
+        }
+        let request = self.build_request(data, url);
+        if context.is_empty() {
+            request
+        } else {
+            request.with_context(context)
+        }
     }
 
     /// Fetch the next id
-    pub(crate) fn next_id(&mut self) -> JsonRpcId {
-        let id = self.id;
-        self.id += 1;
-        id
+    pub(crate) fn next_id(&self) -> JsonRpcId {
+        self.id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// The cached server version, if it has been fetched
+    ///
+    /// See `server_version()` to fetch (and cache) the version.
+    pub fn version(&self) -> Option<OdooVersion> {
+        self.version.get().copied()
+    }
+
+    /// Helper method to build the `server_version()` request
+    ///
+    /// Like [`get_auth_request`](Self::get_auth_request), this is sent by each
+    /// [`RequestImpl`] using its own `send()` method.
+    pub(crate) fn get_version_request(&self) -> OdooRequest<'_, Version, I> {
+        let version = Version {};
+        let endpoint = self.build_endpoint(version.endpoint());
+        self.build_request(version, &endpoint)
+    }
+
+    /// Helper method to parse and cache the `server_version()` response
+    pub(crate) fn parse_version_response(&self, response: VersionResponse) -> OdooVersion {
+        *self.version.get_or_init(|| OdooVersion::from(&response))
+    }
+
+    /// Helper method to build the `db_create_database_and_wait()` request
+    ///
+    /// As with [`get_auth_request`](Self::get_auth_request), this is sent by
+    /// each [`RequestImpl`] using its own `send()` method.
+    pub(crate) fn get_create_database_request(
+        &self,
+        create: &CreateDatabase,
+    ) -> OdooRequest<'_, CreateDatabase, I> {
+        let endpoint = self.build_endpoint(create.endpoint());
+        self.build_request(create.clone(), &endpoint)
+    }
+
+    /// Helper method to build the `db_create_database_and_wait()` login probe
+    ///
+    /// A new request is built for each probe, using the new admin credentials.
+    pub(crate) fn get_login_request(&self, create: &CreateDatabase) -> OdooRequest<'_, Login, I> {
+        let login = Login {
+            db: create.db_name.clone(),
+            login: create.login.clone(),
+            password: create.user_password.clone(),
+        };
+        let endpoint = self.build_endpoint(login.endpoint());
+        self.build_request(login, &endpoint)
     }
 
     /// Helper method to perform the 1st stage of the authentication request
@@ -151,11 +432,11 @@ where
     /// This is necessary because each `RequestImpl` has its own `send()` signature
     /// (i.e., some are `fn send()`, some are `async fn send()`).
     pub(crate) fn get_auth_request(
-        &mut self,
+        &self,
         db: &str,
         login: &str,
         password: &str,
-    ) -> OdooRequest<SessionAuthenticate, I> {
+    ) -> OdooRequest<'_, SessionAuthenticate, I> {
         let authenticate = crate::service::web::SessionAuthenticate {
             db: db.into(),
             login: login.into(),
@@ -180,6 +461,9 @@ where
         response: SessionAuthenticateResponse,
         session_id: Option<String>,
     ) -> AuthenticationResult<OdooClient<Authed, I>> {
+        if is_totp_required(&response.data) {
+            return Err(AuthenticationError::TwoFactorRequired { session_id });
+        }
         let uid = response.data.get("uid").ok_or_else(|| {
             AuthenticationError::UidParseError(
                 "Failed to parse UID from /web/session/authenticate call".into(),
@@ -188,6 +472,9 @@ where
 
         //TODO: this is a bit awkward..
         let uid = from_str(&to_string(uid)?)?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(database = db, login, uid, "odoo authenticated");
+
         let auth = Authed {
             database: db.into(),
             uid,
@@ -201,9 +488,48 @@ where
             auth,
             _impl: self._impl,
             id: self.id,
+            context: self.context,
+            version: self.version,
+            middleware: self.middleware,
+            warning_hooks: self.warning_hooks,
+            metrics_hooks: self.metrics_hooks,
+            timeout: self.timeout,
+            rate_limit: self.rate_limit,
+            db_guard: self.db_guard,
+            strict: self.strict,
+            check_kwargs_misuse: self.check_kwargs_misuse,
+            company: self.company,
+            max_response_size: self.max_response_size,
+            database_hint: self.database_hint,
+            database_hint_header: self.database_hint_header,
+            jsonrpc_path: self.jsonrpc_path,
+            web_prefix: self.web_prefix,
+            read_replica: self.read_replica,
+            retry: self.retry,
+            user_agent: self.user_agent,
+            metadata_cache: self.metadata_cache,
+            presets: self.presets,
         })
     }
 
+    /// Helper method to finish `authenticate_auto()`, when the database wasn't known
+    ///
+    /// The request was sent without a database, so the database Odoo picked is
+    /// read from the session info (the `db` key).
+    pub(crate) fn parse_auth_auto_response(
+        self,
+        login: &str,
+        password: &str,
+        response: SessionAuthenticateResponse,
+        session_id: Option<String>,
+    ) -> AuthenticationResult<OdooClient<Authed, I>> {
+        if is_totp_required(&response.data) {
+            return Err(AuthenticationError::TwoFactorRequired { session_id });
+        }
+        let db: String = from_value(response.data.get("db").cloned().unwrap_or_default())?;
+        self.parse_auth_response(&db, login, password, response, session_id)
+    }
+
     pub fn session_id(&self) -> Option<&str> {
         self.auth.get_session_id()
     }
@@ -229,12 +555,312 @@ where
             auth,
             _impl: self._impl,
             id: self.id,
+            context: self.context,
+            version: self.version,
+            middleware: self.middleware,
+            warning_hooks: self.warning_hooks,
+            metrics_hooks: self.metrics_hooks,
+            timeout: self.timeout,
+            rate_limit: self.rate_limit,
+            db_guard: self.db_guard,
+            strict: self.strict,
+            check_kwargs_misuse: self.check_kwargs_misuse,
+            company: self.company,
+            max_response_size: self.max_response_size,
+            database_hint: self.database_hint,
+            database_hint_header: self.database_hint_header,
+            jsonrpc_path: self.jsonrpc_path,
+            web_prefix: self.web_prefix,
+            read_replica: self.read_replica,
+            retry: self.retry,
+            user_agent: self.user_agent,
+            metadata_cache: self.metadata_cache,
+            presets: self.presets,
         }
     }
 
     /// Update the URL for this client
+    ///
+    /// Endpoints are derived from the URL when each request is built, so this
+    /// applies to every following request (including the "Web" methods).
+    ///
+    /// The `session_id` belongs to the old server, so an authenticated client
+    /// forgets it if the origin (scheme, host and port) changes. Use
+    /// [`switch_url()`](OdooClient::switch_url) to find out whether it was
+    /// cleared, or `switch_url_verified()` to keep the session and check it
+    /// against the new server instead.
     pub fn with_url(&mut self, url: &str) -> &mut Self {
-        self.url = Self::validate_url(url);
+        let url = Self::validate_url(url);
+        if origin(&url) != origin(&self.url) {
+            self.auth.clear_session_id();
+        }
+        self.url = url;
+        self
+    }
+
+    /// Set the default timeout for all subsequent requests
+    ///
+    /// This can be overridden per-request with
+    /// [`OdooRequest::with_timeout`]. As with that method, the timeout is
+    /// honored by the `reqwest` and `ureq` impls; closure-based impls are
+    /// responsible for their own timeouts.
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Reject JSON-RPC responses with unexpected top-level keys
+    ///
+    /// By default, any keys other than `jsonrpc`, `id`, `result` and `error`
+    /// are ignored. With strict responses enabled, they're returned as a
+    /// `MalformedResponse` error instead, which is useful for catching a proxy
+    /// or a non-Odoo server answering on the JSON-RPC endpoint.
+    pub fn with_strict_responses(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Refuse `execute` requests whose last argument is a dict
+    ///
+    /// This enables [`OdooRequest::check_kwargs_misuse`](crate::client::OdooRequest::check_kwargs_misuse)
+    /// for every request. It's off by default, as `create` and `write` take
+    /// their `vals` as a positional dict; those requests need
+    /// [`allow_trailing_dict`](crate::client::OdooRequest::allow_trailing_dict)
+    /// while the check is on.
+    pub fn with_kwargs_misuse_check(&mut self, check: bool) -> &mut Self {
+        self.check_kwargs_misuse = check;
+        self
+    }
+
+    /// Limit the size of JSON-RPC response bodies, in bytes
+    ///
+    /// A large `search_read` (or a runaway server) can return hundreds of MB of
+    /// JSON, all of which is buffered before it's parsed. With a limit set, the
+    /// request fails with `ResponseTooLarge` instead. The `reqwest` and `ureq`
+    /// impls stop reading as soon as the limit is exceeded; closure-based impls
+    /// are only checked once the closure returns.
+    pub fn with_max_response_size(&mut self, bytes: usize) -> &mut Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Select the database `db` on servers hosting multiple databases
+    ///
+    /// With `dbfilter`, Odoo picks the database from the request, and responds
+    /// with the (HTML) database selector if it can't. Once set, the hint is
+    /// appended to web endpoints as a `?db=` query parameter, and sent as a
+    /// header on every request (`X-Odoo-Db` by default, see
+    /// [`with_database_hint_header`](Self::with_database_hint_header)) for
+    /// custom endpoints or proxies which expect it.
+    pub fn with_database_hint(&mut self, db: impl Into<String>) -> &mut Self {
+        self.database_hint = Some(db.into());
+        self
+    }
+
+    /// Set the header used to send the [database hint](Self::with_database_hint)
+    pub fn with_database_hint_header(&mut self, name: impl Into<String>) -> &mut Self {
+        self.database_hint_header = name.into();
+        self
+    }
+
+    /// Send JSON-RPC requests to `path`, rather than `/jsonrpc`
+    ///
+    /// For reverse proxies which remap Odoo's routes, e.g.
+    /// `with_jsonrpc_path("/rpc/v1/jsonrpc")`. This affects the "API" and ORM
+    /// methods; see [`with_web_prefix`](Self::with_web_prefix) for the "Web"
+    /// methods. With the `xmlrpc` feature, the XML-RPC endpoints are derived
+    /// by replacing a trailing `/jsonrpc`, so `path` should keep that suffix.
+    pub fn with_jsonrpc_path(&mut self, path: &str) -> &mut Self {
+        self.jsonrpc_path = Some(path.into());
+        self
+    }
+
+    /// Prefix the `/web/*` endpoints with `prefix`
+    ///
+    /// For reverse proxies which serve Odoo's web routes under a sub-path, e.g.
+    /// `with_web_prefix("/odoo")` sends `/web/session/authenticate` to
+    /// `/odoo/web/session/authenticate`. This affects the "Web" methods, and
+    /// any other `/web/*` URLs the client builds (e.g. attachment downloads).
+    pub fn with_web_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.web_prefix = Some(prefix.trim_end_matches('/').into());
+        self
+    }
+
+    /// Send read-only requests to the replica at `url`
+    ///
+    /// The read-only ORM methods (`read`, `search`, `search_read`, `read_group`,
+    /// etc) are sent to the replica, and everything else goes to the primary.
+    /// The generic `execute()` and `execute_kw()` methods can't be classified,
+    /// so they're always sent to the primary. Use
+    /// [`OdooRequest::force_primary`] for reads that must see a recent write.
+    ///
+    /// The path overrides (e.g. [`with_jsonrpc_path`](Self::with_jsonrpc_path))
+    /// apply to the replica too.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "async")]
+    /// # async fn test() -> odoo_api::client::Result<()> {
+    /// use odoo_api::{OdooClient, jmap, jvec, svec};
+    ///
+    /// let mut client = OdooClient::new_reqwest_async("https://odoo.example.com")?;
+    /// client.with_read_replica("https://replica.odoo.example.com");
+    /// let client = client.authenticate("acme", "admin", "password").await?;
+    ///
+    /// // sent to the replica..
+    /// let partners = client
+    ///     .search_read("res.partner", jvec![], svec!["name"])
+    ///     .send()
+    ///     .await?;
+    ///
+    /// // ..and this to the primary
+    /// client.write("res.partner", vec![1], jmap! {"name": "Acme"}).send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_read_replica(&mut self, url: &str) -> &mut Self {
+        self.read_replica = Some(Self::validate_url(url));
+        self
+    }
+
+    /// Retry requests which fail before they reach Odoo
+    ///
+    /// Connection errors, and `502`/`503` responses from a reverse proxy (e.g.
+    /// while Odoo restarts) are retried up to `policy.max_retries` times, with
+    /// an exponential backoff. Timeouts aren't retried, as a write may have
+    /// completed anyways. See [`RetryPolicy`] for more info.
+    ///
+    /// PostgreSQL serialization failures (concurrent updates to the same rows)
+    /// are rolled back by Odoo, so they're retried too, with a jittered delay.
+    /// Use `send_with_meta()` on the request to see how many
+    /// attempts a request took.
+    ///
+    /// This is honored by the `reqwest` and `ureq` impls for JSON-RPC requests.
+    /// The closure-based impls can't tell why a request failed, so they're
+    /// responsible for their own retries.
+    pub fn with_retry(&mut self, policy: RetryPolicy) -> &mut Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Send `user_agent` as the `User-Agent` header with each request
+    ///
+    /// This is sent with the JSON-RPC requests (i.e. the "API", ORM and "Web"
+    /// methods), and replaces the HTTP library's default.
+    pub fn with_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Limit the client to `requests` HTTP requests per `per`
+    ///
+    /// This is a token bucket: bursts of up to `requests` are sent immediately,
+    /// then further requests wait (blocking impls sleep, async impls await)
+    /// until a token is available. The limiter is shared between clones of the
+    /// client, so concurrent tasks respect the same budget.
+    ///
+    /// Each HTTP request consumes a token, including each chunk of the
+    /// `*_batched` methods, the probes sent by `health_check()`, and any retries.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "async")]
+    /// # fn test() -> odoo_api::client::Result<()> {
+    /// use odoo_api::OdooClient;
+    /// use std::time::Duration;
+    ///
+    /// let mut client = OdooClient::new_reqwest_async("https://demo.odoo.com")?;
+    /// client.with_rate_limit(10, Duration::from_secs(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_rate_limit(&mut self, requests: u32, per: Duration) -> &mut Self {
+        self.rate_limit = Some(Arc::new(RateLimiter::new(requests, per)));
+        self
+    }
+
+    /// Refuse destructive `db` requests targeting protected databases
+    ///
+    /// `db_drop`, `db_rename` and `db_restore` requests are checked before
+    /// they're sent, and return a `ProtectedDatabase` error if the target
+    /// database matches the guard. See [`DbGuard`] for more info.
+    pub fn with_db_guard(&mut self, guard: DbGuard) -> &mut Self {
+        self.db_guard = Some(Arc::new(guard));
+        self
+    }
+
+    /// Cache the [`ModelMetadata`](super::ModelMetadata) fetched by `model_metadata()`, for `ttl`
+    ///
+    /// Each model is cached per database and `lang` (from the client-wide
+    /// context), as the labels are translated. The cache is shared between
+    /// clones of the client. Use `refresh_model_metadata()` to skip the
+    /// cache, e.g. after installing a module.
+    pub fn with_metadata_cache(&mut self, ttl: Duration) -> &mut Self {
+        self.metadata_cache = Some(Arc::new(MetadataCache::new(ttl)));
+        self
+    }
+
+    /// Register a [`Middleware`], which is called around each request
+    ///
+    /// Middleware is called in registration order, and is kept when the
+    /// client is authenticated.
+    pub fn with_middleware<M: Middleware + 'static>(&mut self, middleware: M) -> &mut Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Call `hook` for each server [`Warning`] in a response
+    ///
+    /// Some methods (e.g. `onchange()` and `load()`) report warnings in an
+    /// otherwise successful result. The hook is called for each of them, for
+    /// any request sent by this client, which is useful for logging them in one
+    /// place. See the [`warning`](crate::warning) module for the layouts detected.
+    ///
+    /// Like middleware, hooks are called in registration order, and are kept
+    /// when the client is authenticated.
+    pub fn on_warning<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&Warning) + Send + Sync + 'static,
+    {
+        self.warning_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` with the [`RequestMetrics`] of each request
+    ///
+    /// The metrics include the method, HTTP status, time taken, body sizes,
+    /// and outcome, e.g. for per-method latency monitoring. The hook is called
+    /// after each attempt (including failed attempts), by every impl. With the
+    /// `metrics` feature, pass [`RequestMetrics::record`] to emit them via the
+    /// [`metrics`](https://docs.rs/metrics) crate.
+    ///
+    /// Like middleware, hooks are called in registration order, and are kept
+    /// when the client is authenticated.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "async")]
+    /// # fn test() -> odoo_api::client::Result<()> {
+    /// use odoo_api::OdooClient;
+    ///
+    /// let mut client = OdooClient::new_reqwest_async("https://demo.odoo.com")?;
+    /// client.with_metrics(|metrics| {
+    ///     println!(
+    ///         "{} {:?} took {:?} ({})",
+    ///         metrics.endpoint,
+    ///         metrics.orm_method,
+    ///         metrics.elapsed,
+    ///         metrics.outcome.as_str()
+    ///     );
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_metrics<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(RequestMetrics) + Send + Sync + 'static,
+    {
+        self.metrics_hooks.push(Arc::new(hook));
         self
     }
 }
@@ -258,7 +884,578 @@ where
             url,
             auth: NotAuthed {},
             _impl,
-            id: 1,
+            id: Arc::new(AtomicU32::new(1)),
+            context: Map::new(),
+            version: OnceLock::new(),
+            middleware: Vec::new(),
+            warning_hooks: Vec::new(),
+            metrics_hooks: Vec::new(),
+            timeout: None,
+            rate_limit: None,
+            db_guard: None,
+            strict: false,
+            check_kwargs_misuse: false,
+            company: None,
+            max_response_size: None,
+            database_hint: None,
+            database_hint_header: DATABASE_HINT_HEADER.into(),
+            jsonrpc_path: None,
+            web_prefix: None,
+            read_replica: None,
+            retry: None,
+            user_agent: None,
+            metadata_cache: None,
+            presets: Arc::default(),
         }
     }
 }
+
+/// Methods for authenticated clients
+impl<I> OdooClient<Authed, I>
+where
+    I: RequestImpl,
+{
+    /// The authenticated user id
+    ///
+    /// This is useful for building domains, e.g. `[["user_id", "=", client.uid()]]`.
+    pub fn uid(&self) -> OdooId {
+        self.auth.uid
+    }
+
+    /// The database this client is authenticated with
+    pub fn database(&self) -> &str {
+        &self.auth.database
+    }
+
+    /// The login this client is authenticated with
+    pub fn login(&self) -> &str {
+        &self.auth.login
+    }
+
+    /// Point the client at a different server, keeping the credentials
+    ///
+    /// Returns `true` if the stored `session_id` was cleared (because the
+    /// origin changed), in which case the "Web" methods need a new session:
+    /// call `authenticate()` again. The `/jsonrpc` methods send the password
+    /// with each request, so they keep working as long as the database exists
+    /// on the new server. See [`with_url()`](Self::with_url).
+    pub fn switch_url(&mut self, url: &str) -> bool {
+        let had_session = self.auth.session_id.is_some();
+        self.with_url(url);
+        had_session && self.auth.session_id.is_none()
+    }
+
+    /// Replace the URL without clearing the session, and return the previous URL
+    ///
+    /// This is used by `switch_url_verified()`, which checks the session
+    /// against the new URL (and restores the previous one if it's rejected).
+    pub(crate) fn replace_url(&mut self, url: &str) -> String {
+        std::mem::replace(&mut self.url, Self::validate_url(url))
+    }
+
+    /// Set the active companies for all subsequent requests
+    ///
+    /// This sets `allowed_company_ids` in the client-wide context, which is then
+    /// applied to every ORM and `execute_kw` request. Odoo treats the first
+    /// entry as the "current" company, so `current` is always placed first.
+    ///
+    /// The `create` and `write` requests also get the current company as
+    /// `company_id` in their context. Before Odoo 13 (which doesn't support
+    /// `allowed_company_ids`), `force_company` is set instead; this needs the
+    /// server version to be known (see `server_version()`), otherwise
+    /// `company_id` is used.
+    ///
+    /// `current` must be a member of `allowed`, otherwise an error is returned.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> odoo_api::client::Result<()> {
+    /// # use odoo_api::{OdooClient, jvec};
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let mut client = client.authenticate_manual("", "", 1, "", None);
+    /// // work in company 3, with access to companies 1 and 3
+    /// client.with_companies(3, vec![1, 3])?;
+    ///
+    /// let ids = client.search("sale.order", jvec![]).send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_companies(&mut self, current: OdooId, allowed: Vec<OdooId>) -> Result<&mut Self> {
+        self.context.extend(companies_context(current, allowed)?);
+        self.company = Some(current);
+        Ok(self)
+    }
+}
+
+/// Build the `allowed_company_ids` context for `current` and `allowed`
+///
+/// The `current` company is moved to the front of the list (Odoo treats the
+/// first entry as the "current" company).
+pub(crate) fn companies_context(
+    current: OdooId,
+    allowed: Vec<OdooId>,
+) -> Result<Map<String, Value>> {
+    if !allowed.contains(&current) {
+        return Err(Error::CompanyNotAllowed { current, allowed });
+    }
+
+    let mut company_ids = vec![current];
+    company_ids.extend(allowed.into_iter().filter(|id| *id != current));
+
+    let mut context = Map::new();
+    context.insert("allowed_company_ids".into(), company_ids.into());
+    Ok(context)
+}
+
+/// Build the context which sets the `current` company for `create` and `write`
+///
+/// This is `force_company` before Odoo 13, and `company_id` otherwise (or if
+/// the `version` isn't known).
+pub(crate) fn company_context(current: OdooId, version: Option<OdooVersion>) -> Map<String, Value> {
+    let key = match version {
+        Some(version) if !version.at_least(13, 0) => "force_company",
+        _ => "company_id",
+    };
+    let mut context = Map::new();
+    context.insert(key.into(), current.into());
+    context
+}
+
+/// The origin (scheme, host and port) of `url`
+fn origin(url: &str) -> &str {
+    let start = url.find("://").map_or(0, |i| i + 3);
+    match url[start..].find('/') {
+        Some(end) => &url[..start + end],
+        None => url,
+    }
+}
+
+/// Pick the database for `authenticate_auto()` from the `db.list` result
+///
+/// Returns `None` if no database is listed (so Odoo is left to pick one), and
+/// a `MultipleDatabases` error if several are.
+pub(crate) fn single_database(mut databases: Vec<String>) -> AuthenticationResult<Option<String>> {
+    match databases.len() {
+        0 | 1 => Ok(databases.pop()),
+        _ => Err(AuthenticationError::MultipleDatabases(databases)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client::error::{Error, Result};
+    use crate::client::odoo_request::test::{capture_client, capture_client_with};
+    use crate::client::OdooClient;
+    use serde_json::{json, Map};
+
+    #[test]
+    fn auth_getters() {
+        let client = OdooClient::new_closure_blocking("http://localhost:8069", |_, _, _| {
+            Ok((String::new(), None))
+        })
+        .authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("abc123".into()),
+        );
+
+        assert_eq!(client.uid(), 2);
+        assert_eq!(client.database(), "some-database");
+        assert_eq!(client.login(), "admin");
+
+        let debug = format!("{:?}", client);
+        assert!(debug.contains("http://localhost:8069"));
+        assert!(debug.contains("some-database"));
+        assert!(debug.contains("admin"));
+        assert!(debug.contains("uid: 2"));
+        assert!(!debug.contains("\"password\""));
+        assert!(!debug.contains("abc123"));
+    }
+
+    #[test]
+    fn with_companies() -> Result<()> {
+        let (mut client, captured) = capture_client();
+        client.with_companies(3, vec![1, 2, 3])?;
+        client.search("res.partner", jvec![]).send()?;
+        client
+            .execute_kw("res.partner", "search", jvec![[]], Map::new())
+            .send()?;
+
+        for (body, _) in captured.borrow().iter() {
+            assert_eq!(
+                body["params"]["args"][6]["context"],
+                json!({"allowed_company_ids": [3, 1, 2]})
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_companies_request() -> Result<()> {
+        let (mut client, captured) = capture_client();
+        client.with_companies(1, vec![1, 2])?;
+        client
+            .search("res.partner", jvec![])
+            .with_companies(2, vec![2])?
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"][6]["context"],
+            json!({"allowed_company_ids": [2]})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_companies_create_write() -> Result<()> {
+        let (mut client, captured) = capture_client();
+        client.with_companies(3, vec![1, 3])?;
+        client
+            .create("res.partner", jmap! {"name": "Acme"})
+            .send_raw()?;
+        client
+            .write("res.partner", 7, jmap! {"name": "Acme"})
+            .send_raw()?;
+        client.search("res.partner", jvec![]).send()?;
+
+        let captured = captured.borrow();
+        let context = |i: usize| captured[i].0["params"]["args"][6]["context"].clone();
+        let expected = json!({"allowed_company_ids": [3, 1], "company_id": 3});
+        assert_eq!(context(0), expected);
+        assert_eq!(context(1), expected);
+        assert_eq!(context(2), json!({"allowed_company_ids": [3, 1]}));
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_companies_create_request() -> Result<()> {
+        let (client, captured) = capture_client();
+        client
+            .create("res.partner", jmap! {"name": "Acme"})
+            .with_companies(2, vec![1, 2])?
+            .send_raw()?;
+        client
+            .search("res.partner", jvec![])
+            .with_companies(2, vec![1, 2])?
+            .send()?;
+
+        let captured = captured.borrow();
+        assert_eq!(
+            captured[0].0["params"]["args"][6]["context"],
+            json!({"allowed_company_ids": [2, 1], "company_id": 2})
+        );
+        assert_eq!(
+            captured[1].0["params"]["args"][6]["context"],
+            json!({"allowed_company_ids": [2, 1]})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_companies_force_company() -> Result<()> {
+        // before Odoo 13, the company is set with `force_company`
+        let (mut client, captured) = capture_client();
+        client.version.set("12.0".parse().unwrap()).unwrap();
+        client.with_companies(3, vec![1, 3])?;
+        client
+            .create("res.partner", jmap! {"name": "Acme"})
+            .send_raw()?;
+        client
+            .write("res.partner", 7, jmap! {"name": "Acme"})
+            .with_companies(1, vec![1])?
+            .send_raw()?;
+
+        let captured = captured.borrow();
+        assert_eq!(
+            captured[0].0["params"]["args"][6]["context"],
+            json!({"allowed_company_ids": [3, 1], "force_company": 3})
+        );
+        assert_eq!(
+            captured[1].0["params"]["args"][6]["context"],
+            json!({"allowed_company_ids": [1], "force_company": 1})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_companies_not_allowed() {
+        let (mut client, _captured) = capture_client();
+        match client.with_companies(3, vec![1, 2]) {
+            Err(Error::CompanyNotAllowed { current, allowed }) => {
+                assert_eq!(current, 3);
+                assert_eq!(allowed, vec![1, 2]);
+            }
+            _ => panic!("Expected the `CompanyNotAllowed` error"),
+        }
+    }
+
+    #[test]
+    fn server_version_cached() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let (client, captured) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "server_version": "saas~16.3+e",
+                "server_version_info": [16, 3, 0, "final", 0, "e"],
+                "server_serie": "saas~16.3",
+                "protocol_version": 1
+            }
+        }));
+
+        assert_eq!(client.version(), None);
+        assert_eq!(client.server_version()?, "saas~16.3+e".parse()?);
+        assert!(client.version_at_least(16, 0)?);
+        assert!(!client.version_at_least(17, 0)?);
+
+        // only the first call hits the server
+        assert_eq!(captured.borrow().len(), 1);
+        assert_eq!(captured.borrow()[0].0["params"]["method"], json!("version"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn database_hint() -> Result<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let mut client = OdooClient::new_closure_blocking_with_headers(
+            "http://localhost:8069",
+            move |url, _data, _session_id, headers| {
+                inner.borrow_mut().push((url.to_string(), headers.to_vec()));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": false}).to_string(),
+                    None,
+                ))
+            },
+        );
+        client
+            .with_database_hint("acme & co")
+            .with_database_hint_header("X-Db-Filter");
+        let client = client.authenticate_manual("acme & co", "admin", 2, "password", None);
+
+        // web endpoints get the `db` query param, and every request gets the header
+        client.web_action_load(1, None).send()?;
+        client.execute("res.partner", "search", vec![]).send()?;
+        let sent = sent.borrow();
+        assert_eq!(
+            sent[0].0,
+            "http://localhost:8069/web/action/load?db=acme+%26+co"
+        );
+        assert_eq!(sent[1].0, "http://localhost:8069/jsonrpc");
+        for (_, headers) in sent.iter() {
+            assert!(headers.contains(&("X-Db-Filter".into(), "acme & co".into())));
+        }
+
+        // the database manager doesn't select a database
+        assert_eq!(
+            client.build_endpoint("/web/database/list"),
+            "http://localhost:8069/web/database/list"
+        );
+        assert_eq!(
+            client.build_endpoint("/web/content/1?download=true"),
+            "http://localhost:8069/web/content/1?download=true&db=acme+%26+co"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn endpoint_overrides() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let mut client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |url, _data, _session_id| {
+                inner.borrow_mut().push(url.to_string());
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": false}).to_string(),
+                    None,
+                ))
+            },
+        );
+        client
+            .with_jsonrpc_path("/rpc/v1/jsonrpc")
+            .with_web_prefix("/odoo/");
+        let client = client.authenticate_manual("acme", "admin", 2, "password", None);
+
+        // API, ORM, and Web methods (only the URLs matter here, not the responses)
+        let _ = client.common_version().send();
+        let _ = client.search("res.partner", vec![]).send();
+        let _ = client.web_database_list().send();
+        let _ = client.web_action_load(1, None).send();
+
+        // a one-off override
+        let _ = client
+            .common_version()
+            .with_url("http://localhost:8069/rpc/v2/jsonrpc")
+            .send();
+
+        assert_eq!(
+            *sent.borrow(),
+            vec![
+                "http://localhost:8069/rpc/v1/jsonrpc",
+                "http://localhost:8069/rpc/v1/jsonrpc",
+                "http://localhost:8069/odoo/web/database/list",
+                "http://localhost:8069/odoo/web/action/load",
+                "http://localhost:8069/rpc/v2/jsonrpc",
+            ]
+        );
+        assert_eq!(
+            client.build_endpoint("/web/session/authenticate"),
+            "http://localhost:8069/odoo/web/session/authenticate"
+        );
+    }
+
+    #[test]
+    fn read_replica() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let mut client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |url, _data, _session_id| {
+                inner.borrow_mut().push(url.to_string());
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": false}).to_string(),
+                    None,
+                ))
+            },
+        );
+        client
+            .with_read_replica("http://replica:8069/")
+            .with_jsonrpc_path("/rpc/v1/jsonrpc");
+        let client = client.authenticate_manual("acme", "admin", 2, "password", None);
+
+        // only the URLs matter here, not the responses
+        let _ = client.search_read("res.partner", vec![], vec![]).send();
+        let _ = client.write("res.partner", 1, Map::new()).send();
+        let _ = client.search("res.partner", vec![]).force_primary().send();
+        let _ = client.execute("res.partner", "search", vec![]).send();
+        let _ = client.web_database_list().send();
+
+        assert_eq!(
+            *sent.borrow(),
+            vec![
+                "http://replica:8069/rpc/v1/jsonrpc",
+                "http://localhost:8069/rpc/v1/jsonrpc",
+                "http://localhost:8069/rpc/v1/jsonrpc",
+                "http://localhost:8069/rpc/v1/jsonrpc",
+                "http://localhost:8069/web/database/list",
+            ]
+        );
+    }
+
+    #[test]
+    fn with_url() -> Result<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let inner = sent.clone();
+        let mut client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            move |url, _data, session_id| {
+                inner
+                    .borrow_mut()
+                    .push((url.to_string(), session_id.map(String::from)));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {
+                        "server_version": "16.0",
+                        "server_version_info": [16, 0, 0, "final", 0, ""],
+                        "server_serie": "16.0",
+                        "protocol_version": 1
+                    }})
+                    .to_string(),
+                    None,
+                ))
+            },
+        );
+
+        // not authenticated: the next request goes to the new server
+        client.with_url("http://node-a:8069/");
+        client.common_version().send()?;
+        assert_eq!(sent.borrow()[0].0, "http://node-a:8069/jsonrpc");
+
+        let mut client = client.authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("abc123".into()),
+        );
+
+        // same origin: the session is kept
+        client.with_url("http://node-a:8069/odoo");
+        assert_eq!(client.session_id(), Some("abc123"));
+        client.common_version().send()?;
+        assert_eq!(sent.borrow()[1].0, "http://node-a:8069/odoo/jsonrpc");
+        assert!(!client.switch_url("http://node-a:8069"));
+
+        // a different origin: the session is cleared
+        assert!(client.switch_url("https://node-a:8069"));
+        assert_eq!(client.session_id(), None);
+        client.common_version().send()?;
+        assert_eq!(
+            sent.borrow()[2],
+            ("https://node-a:8069/jsonrpc".to_string(), None)
+        );
+
+        // nothing left to clear
+        assert!(!client.switch_url("http://node-b:8069"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn origin() {
+        use super::origin;
+
+        assert_eq!(origin("http://localhost:8069"), "http://localhost:8069");
+        assert_eq!(
+            origin("http://localhost:8069/odoo"),
+            "http://localhost:8069"
+        );
+        assert_eq!(origin("https://example.com/a/b"), "https://example.com");
+    }
+
+    #[test]
+    fn database_selector() {
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069",
+            |_url, _data, _session_id| {
+                let html = "<!DOCTYPE html>\n<html><head><title>Odoo</title></head>\
+                            <body><div class=\"o_database_list\"></div></body></html>";
+                Ok((html.to_string(), None))
+            },
+        )
+        .authenticate_manual("acme", "admin", 2, "password", None);
+
+        let err = client
+            .execute("res.partner", "search", vec![])
+            .send()
+            .unwrap_err();
+        assert!(
+            matches!(err, crate::client::error::ClosureError::DatabaseSelector),
+            "{:?}",
+            err
+        );
+        assert!(err.to_string().contains("with_database_hint()"));
+    }
+}
@@ -1,13 +1,96 @@
 //! The [`OdooRequest`] type and associated bits
 
-use super::RequestImpl;
-use crate::client::error::ParseResponseResult;
-use crate::jsonrpc::{JsonRpcParams, JsonRpcRequest, JsonRpcResponse};
+use super::db_guard::DbGuard;
+use super::metrics::MetricsHook;
+use super::odoo_client::{companies_context, company_context};
+use super::rate_limit::RateLimiter;
+use super::retry::RetryPolicy;
+use super::{Middleware, RequestImpl};
+use crate::client::error::{Error, ParseResponseError, ParseResponseResult, Result};
+use crate::jsonrpc::response::{JsonRpcErrorData, RawResponse, MISSING_RESULT};
+use crate::jsonrpc::{JsonRpcParams, JsonRpcRequest, JsonRpcResponse, OdooId, OdooKwargsMethod};
+use crate::service::object::Execute;
+use crate::warning::{HasWarnings, Warning};
+use crate::OdooVersion;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::from_str;
+use serde_json::value::RawValue;
+use serde_json::{from_slice, to_value, Map, Value};
+use std::borrow::Cow;
 use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
 
+/// A function that merges a context into the serialized request `params`
+///
+/// See [`OdooKwargsMethod::inject_context`]
+type ContextInjector = fn(&mut Value, &Map<String, Value>);
+
+/// The custom HTTP headers for a request, as `(name, value)` pairs
+type Headers = Vec<(String, String)>;
+
+/// A function that patches the serialized request `params`
+///
+/// See [`OdooRequest::map_params`]
+type ParamsPatch = Box<dyn Fn(&mut Value) + Send + Sync>;
+
+/// A function called for each server [`Warning`] in a response
+///
+/// See [`OdooClient::on_warning`](crate::client::OdooClient::on_warning)
+pub(crate) type WarningHook = Arc<dyn Fn(&Warning) + Send + Sync>;
+
+/// A function called with the bytes transferred so far, and the total (if known)
+///
+/// See [`OdooRequest::on_download_progress`] and [`OdooRequest::on_upload_progress`]
+pub(crate) type ProgressHook = Arc<dyn Fn(u64, Option<u64>) + Send + Sync>;
+
+/// The request impl, borrowed from the client, or owned by a detached request
+///
+/// See [`OdooRequest::into_detached`]
+pub(crate) enum ImplRef<'a, I> {
+    Borrowed(&'a I),
+    Owned(I),
+}
+
+impl<I> Deref for ImplRef<'_, I> {
+    type Target = I;
+
+    fn deref(&self) -> &I {
+        match self {
+            Self::Borrowed(i) => i,
+            Self::Owned(i) => i,
+        }
+    }
+}
+
+/// A built Odoo request, ready to be sent
+///
+/// Before calling `send()`, the request can be customized with the builder
+/// methods below. ORM methods also have a builder method for each optional
+/// kwarg (e.g., `.limit()`):
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # async fn test() -> odoo_api::client::Result<()> {
+/// use odoo_api::{OdooClient, jvec, jmap, svec};
+/// use std::time::Duration;
+///
+/// # let client = OdooClient::new_reqwest_async("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let partners = client.search_read(
+///     "res.partner",
+///     jvec![],
+///     svec!["name", "active"],
+/// )
+/// .limit(80)
+/// .with_context(jmap!{"active_test": false})
+/// .with_timeout(Duration::from_secs(120))
+/// .with_header("X-Request-Source", "odoo-api")
+/// .send()
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
 pub struct OdooRequest<'a, T, I>
 where
     T: JsonRpcParams + Debug + Serialize,
@@ -16,8 +99,35 @@ where
 {
     pub(crate) data: JsonRpcRequest<T>,
     pub(crate) url: String,
-    pub(crate) session_id: Option<&'a str>,
-    pub(crate) _impl: &'a I,
+    /// The primary URL, if `url` was routed to the read replica
+    pub(crate) primary_url: Option<String>,
+    pub(crate) session_id: Option<Cow<'a, str>>,
+    pub(crate) context: Option<(Map<String, Value>, ContextInjector)>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) headers: Headers,
+    pub(crate) middleware: Cow<'a, [Arc<dyn Middleware>]>,
+    pub(crate) warning_hooks: Cow<'a, [WarningHook]>,
+    pub(crate) metrics_hooks: Cow<'a, [MetricsHook]>,
+    pub(crate) rate_limit: Option<Arc<RateLimiter>>,
+    pub(crate) db_guard: Option<Arc<DbGuard>>,
+    pub(crate) confirmed: bool,
+    pub(crate) check_kwargs_misuse: bool,
+    pub(crate) allow_trailing_dict: bool,
+    /// The client's cached server version, if known
+    pub(crate) version: Option<OdooVersion>,
+    pub(crate) params_patches: Vec<ParamsPatch>,
+    pub(crate) strict: bool,
+    pub(crate) max_response_size: Option<usize>,
+    #[cfg_attr(
+        not(any(feature = "async", feature = "blocking", feature = "ureq")),
+        allow(dead_code)
+    )]
+    pub(crate) retry: Option<RetryPolicy>,
+    #[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+    pub(crate) download_progress: Option<ProgressHook>,
+    #[cfg_attr(not(any(feature = "async", feature = "blocking")), allow(dead_code))]
+    pub(crate) upload_progress: Option<ProgressHook>,
+    pub(crate) _impl: ImplRef<'a, I>,
 }
 
 impl<'a, T, I> OdooRequest<'a, T, I>
@@ -30,25 +140,1118 @@ where
         data: JsonRpcRequest<T>,
         url: String,
         session_id: Option<&'a str>,
+        middleware: &'a [Arc<dyn Middleware>],
         _impl: &'a I,
     ) -> Self {
         Self {
             data,
             url,
-            session_id,
-            _impl,
+            primary_url: None,
+            session_id: session_id.map(Cow::Borrowed),
+            context: None,
+            timeout: None,
+            headers: Vec::new(),
+            middleware: Cow::Borrowed(middleware),
+            warning_hooks: Cow::Borrowed(&[]),
+            metrics_hooks: Cow::Borrowed(&[]),
+            rate_limit: None,
+            db_guard: None,
+            confirmed: false,
+            check_kwargs_misuse: false,
+            allow_trailing_dict: false,
+            version: None,
+            params_patches: Vec::new(),
+            strict: false,
+            max_response_size: None,
+            retry: None,
+            download_progress: None,
+            upload_progress: None,
+            _impl: ImplRef::Borrowed(_impl),
+        }
+    }
+
+    /// Set a timeout for this request
+    ///
+    /// This overrides the client-wide timeout (see
+    /// [`OdooClient::with_timeout`](crate::client::OdooClient::with_timeout)).
+    /// It's honored by the `reqwest` and `ureq` impls. Closure-based impls are
+    /// responsible for their own timeouts.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a custom HTTP header to this request
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send this request to `url`, rather than the endpoint built by the client
+    ///
+    /// For one-off requests to a remapped route. To remap every request, see
+    /// [`OdooClient::with_jsonrpc_path`](crate::client::OdooClient::with_jsonrpc_path)
+    /// and [`OdooClient::with_web_prefix`](crate::client::OdooClient::with_web_prefix).
+    /// `url` must be the full URL (e.g. `https://example.com/rpc/v2/jsonrpc`).
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = url.into();
+        self.primary_url = None;
+        self
+    }
+
+    /// Send this request to the primary server, even if it's read-only
+    ///
+    /// With a [read replica](crate::client::OdooClient::with_read_replica),
+    /// read-only requests are sent to the replica. Use this when the read
+    /// must see a write that the replica may not have caught up with yet.
+    pub fn force_primary(mut self) -> Self {
+        if let Some(url) = self.primary_url.take() {
+            self.url = url;
+        }
+        self
+    }
+
+    /// Call `hook` as the response body is received
+    ///
+    /// The hook is called after each chunk with the bytes received so far, and
+    /// the total from the `Content-Length` (if the server sent one). Sizes are
+    /// as sent over the wire, i.e. before any decompression. This is mostly
+    /// useful for large transfers, like [`db_dump`](crate::service::db::Dump).
+    /// It's honored by the `reqwest` impls only.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::OdooClient;
+    /// # use odoo_api::service::db::DumpFormat;
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// let dump = client
+    ///     .db_dump("master-password", "my-database", DumpFormat::Zip)
+    ///     .on_download_progress(|received, total| match total {
+    ///         Some(total) => println!("{}/{} bytes", received, total),
+    ///         None => println!("{} bytes", received),
+    ///     })
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_download_progress<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        self.download_progress = Some(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` as the request body is sent
+    ///
+    /// The hook is called after each chunk with the bytes sent so far, and the
+    /// total body size. This is the counterpart to
+    /// [`on_download_progress`](Self::on_download_progress), e.g. for a large
+    /// [`db_restore`](crate::service::db::Restore). It's honored by the
+    /// `reqwest` impls only.
+    pub fn on_upload_progress<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        self.upload_progress = Some(Arc::new(hook));
+        self
+    }
+
+    /// Confirm that this destructive `db` request is intended
+    ///
+    /// This overrides the client's [`DbGuard`], if it was built with
+    /// [`require_confirmation`](DbGuard::require_confirmation).
+    pub fn confirm_destructive(mut self) -> Self {
+        self.confirmed = true;
+        self
+    }
+
+    /// Patch the serialized `params` before this request is sent (advanced)
+    ///
+    /// This is an escape hatch for servers which expect something the typed
+    /// request can't express, e.g. an extra key consumed by a custom module.
+    /// The function receives the JSON-RPC `params` object once the typed
+    /// request (and its context) is serialized. It runs before the client's
+    /// [`DbGuard`] check and any [`Middleware`], and may be called more than
+    /// once (e.g. if the request is retried). Multiple patches run in order.
+    ///
+    /// Nothing checks the result, so a patch can easily produce a request that
+    /// Odoo rejects. Prefer the typed builder methods where they exist. The
+    /// patch must be `'static`, so that the request can still be
+    /// [detached](Self::into_detached).
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::{OdooClient, jvec};
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// let ids = client
+    ///     .search("res.partner", jvec![])
+    ///     .map_params(|params| {
+    ///         params["tenant_token"] = "abc123".into();
+    ///     })
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_params<F>(mut self, patch: F) -> Self
+    where
+        F: Fn(&mut Value) + Send + Sync + 'static,
+    {
+        self.params_patches.push(Box::new(patch));
+        self
+    }
+
+    /// Build the final JSON body for this request
+    ///
+    /// This is where the per-request context (if any) is merged into the
+    /// params, and any [`map_params`](Self::map_params) patches are applied.
+    pub(crate) fn build_body(&self) -> serde_json::Result<Value> {
+        let mut body = to_value(&self.data)?;
+        if let Some((context, inject)) = &self.context {
+            if let Some(params) = body.get_mut("params") {
+                inject(params, context);
+            }
+        }
+        if let Some(params) = body.get_mut("params") {
+            for patch in &self.params_patches {
+                patch(params);
+            }
+        }
+        Ok(body)
+    }
+
+    /// Build the final URL, headers, and JSON body for this request
+    ///
+    /// This checks the `execute` args (if enabled, see [`check_kwargs_misuse`](OdooRequest::check_kwargs_misuse))
+    /// and the client's [`DbGuard`] (if any), then runs the `on_request` hook
+    /// for each [`Middleware`], in order.
+    pub(crate) fn prepare(&self) -> ParseResponseResult<(String, Headers, Value)> {
+        let mut url = self.url.clone();
+        let mut headers = self.headers.clone();
+        let mut body = self.build_body()?;
+        if self.check_kwargs_misuse && !self.allow_trailing_dict {
+            check_trailing_dict(&body)?;
+        }
+        if let Some(guard) = &self.db_guard {
+            guard.check(&body, self.confirmed)?;
+        }
+        for middleware in self.middleware.iter() {
+            middleware.on_request(&mut url, &mut headers, &mut body);
+        }
+        Ok((url, headers, body))
+    }
+
+    /// Run the `on_response` hook for each [`Middleware`], in order
+    pub(crate) fn on_response(&self, status: u16, body: &str) {
+        for middleware in self.middleware.iter() {
+            middleware.on_response(status, body);
         }
     }
 
     pub(crate) fn parse_response<D: Debug + DeserializeOwned>(
         &self,
-        data: &str,
+        data: &[u8],
     ) -> ParseResponseResult<D> {
-        let response: JsonRpcResponse<D> = from_str(data)?;
+        if data.iter().all(u8::is_ascii_whitespace) {
+            return Err(ParseResponseError::EmptyResponse);
+        }
+        // borrow the `result` from `data`, rather than copying it
+        let raw: RawResponse<&RawValue> = match from_slice(data) {
+            Ok(raw) => raw,
+            Err(_) if is_database_selector(data) => {
+                return Err(ParseResponseError::DatabaseSelector)
+            }
+            Err(err) => {
+                return Err(match non_object_kind(data) {
+                    Some(found) => ParseResponseError::NotAnObject {
+                        found: found.into(),
+                    },
+                    None => err.into(),
+                })
+            }
+        };
+        if let Some(result) = raw.result() {
+            self.on_warnings(result.get());
+        }
+        if self.strict && !raw.unknown.is_empty() {
+            return Err(ParseResponseError::MalformedResponse {
+                reason: format!("unexpected key(s): {}", raw.unknown.join(", ")),
+            });
+        }
+        let response = raw
+            .into_response::<D>()
+            .map_err(shape_error::<D>)?
+            .ok_or_else(|| ParseResponseError::MalformedResponse {
+                reason: MISSING_RESULT.into(),
+            })?;
 
         match response {
             JsonRpcResponse::Success(data) => Ok(data.result),
-            JsonRpcResponse::Error(data) => Err(data.error.into()),
+            JsonRpcResponse::Error(data) => {
+                if let Some(err) = self.data.inner().map_error(&data.error.data) {
+                    Err(err)
+                } else if let Some((method, detail)) = missing_argument(&data.error.data) {
+                    Err(ParseResponseError::MissingArgument { method, detail })
+                } else {
+                    Err(data.error.into())
+                }
+            }
+        }
+    }
+
+    /// Run the warning hooks for each [`Warning`] in the raw `result`
+    ///
+    /// The result is only parsed (again) if a hook is registered, and it looks
+    /// like it might contain a warning.
+    fn on_warnings(&self, result: &str) {
+        if self.warning_hooks.is_empty()
+            || !(result.contains("\"warning\"") || result.contains("\"messages\""))
+        {
+            return;
+        }
+        let Ok(result) = serde_json::from_str::<Value>(result) else {
+            return;
+        };
+        for warning in result.warnings() {
+            for hook in self.warning_hooks.iter() {
+                hook(&warning);
+            }
+        }
+    }
+}
+
+impl<'a, T, I> OdooRequest<'a, T, I>
+where
+    T: JsonRpcParams + Debug + Serialize,
+    T::Container<T>: Debug + Serialize,
+    I: RequestImpl + Clone,
+{
+    /// Detach this request from the client, so that it no longer borrows it
+    ///
+    /// The session id, middleware, and warning/metrics hooks are copied into the
+    /// request, and the impl is cloned (this is cheap for the `reqwest` and
+    /// `ureq` impls, which share their connection pool between clones). The
+    /// rate limiter and [`DbGuard`] are still shared with the client.
+    ///
+    /// The detached request is `'static`, so it can be sent from a spawned task
+    /// (or stored in a collection of pending futures). With the `async` impl,
+    /// it's also `Send`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "async")]
+    /// # async fn test() -> odoo_api::client::Result<()> {
+    /// use odoo_api::{OdooClient, jvec, svec};
+    ///
+    /// # let client = OdooClient::new_reqwest_async("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// let mut tasks = Vec::new();
+    /// for model in ["res.partner", "res.users", "res.company"] {
+    ///     let request = client.search_read(model, jvec![], svec!["name"]).into_detached();
+    ///     tasks.push(tokio::spawn(request.send()));
+    /// }
+    /// for task in tasks {
+    ///     let records = task.await.unwrap()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_detached(self) -> OdooRequest<'static, T, I> {
+        OdooRequest {
+            data: self.data,
+            url: self.url,
+            primary_url: self.primary_url,
+            session_id: self.session_id.map(|id| Cow::Owned(id.into_owned())),
+            context: self.context,
+            timeout: self.timeout,
+            headers: self.headers,
+            middleware: Cow::Owned(self.middleware.into_owned()),
+            warning_hooks: Cow::Owned(self.warning_hooks.into_owned()),
+            metrics_hooks: Cow::Owned(self.metrics_hooks.into_owned()),
+            rate_limit: self.rate_limit,
+            db_guard: self.db_guard,
+            confirmed: self.confirmed,
+            check_kwargs_misuse: self.check_kwargs_misuse,
+            allow_trailing_dict: self.allow_trailing_dict,
+            version: self.version,
+            params_patches: self.params_patches,
+            strict: self.strict,
+            max_response_size: self.max_response_size,
+            retry: self.retry,
+            download_progress: self.download_progress,
+            upload_progress: self.upload_progress,
+            _impl: ImplRef::Owned(match self._impl {
+                ImplRef::Borrowed(i) => i.clone(),
+                ImplRef::Owned(i) => i,
+            }),
+        }
+    }
+}
+
+/// Report unknown keys as `UnexpectedResponseShape` (with `strict-responses`)
+///
+/// The strict response types reject unknown keys with serde's "unknown field"
+/// error, so any other parse error is returned as-is.
+fn shape_error<D>(err: serde_json::Error) -> ParseResponseError {
+    let detail = err.to_string();
+    if cfg!(feature = "strict-responses") && detail.starts_with("unknown field") {
+        let name = std::any::type_name::<D>();
+        let context = name.rsplit("::").next().unwrap_or(name).to_string();
+        return ParseResponseError::UnexpectedResponseShape { context, detail };
+    }
+    err.into()
+}
+
+/// The kind of JSON value in `data`, if it's valid JSON but not an object
+fn non_object_kind(data: &[u8]) -> Option<&'static str> {
+    match from_slice::<Value>(data).ok()? {
+        Value::Null => Some("null"),
+        Value::Bool(_) => Some("a boolean"),
+        Value::Number(_) => Some("a number"),
+        Value::String(_) => Some("a string"),
+        Value::Array(_) => Some("an array"),
+        Value::Object(_) => None,
+    }
+}
+
+/// Detect Odoo's (HTML) database selector page
+fn is_database_selector(data: &[u8]) -> bool {
+    let data = String::from_utf8_lossy(data);
+    data.trim_start().starts_with('<') && data.contains("<title>Odoo</title>")
+}
+
+/// Detect a Python `TypeError` caused by a missing positional argument
+///
+/// The message looks like `search() missing 1 required positional argument: 'args'`
+/// (or `BaseModel.search() ...` on Python 3.10+). Returns the method name and the
+/// full message.
+fn missing_argument(data: &JsonRpcErrorData) -> Option<(String, String)> {
+    if !data.name.ends_with("TypeError") || !data.message.contains("required positional argument") {
+        return None;
+    }
+    let (callable, _) = data.message.split_once("()")?;
+    let method = callable.rsplit('.').next().unwrap_or(callable);
+    Some((method.to_string(), data.message.clone()))
+}
+
+/// Refuse an `execute` request whose last argument is a dict
+///
+/// `execute` passes its args positionally (after the credentials, model and
+/// method), so a trailing dict is most likely kwargs meant for `execute_kw`.
+fn check_trailing_dict(body: &Value) -> ParseResponseResult<()> {
+    let params = &body["params"];
+    if params["service"] != "object" || params["method"] != "execute" {
+        return Ok(());
+    }
+    let args = match params["args"].as_array() {
+        Some(args) => args,
+        None => return Ok(()),
+    };
+    match args.get(5..) {
+        Some([.., Value::Object(_)]) => Err(ParseResponseError::LikelyKwargsMisuse {
+            model: args[3].as_str().unwrap_or_default().into(),
+            method: args[4].as_str().unwrap_or_default().into(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+impl<'a, I> OdooRequest<'a, Execute, I>
+where
+    I: RequestImpl,
+{
+    /// Refuse this request if the last `execute` argument is a dict
+    ///
+    /// `execute` passes its args positionally, so a trailing dict is often
+    /// kwargs meant for [`execute_kw`](crate::service::object::ExecuteKw), which
+    /// Odoo would silently treat as a positional argument. With this check on,
+    /// those requests are refused with a `LikelyKwargsMisuse` error, without
+    /// being sent. Some methods do take a positional dict (e.g. the `vals` for
+    /// `create` or `write`); use [`allow_trailing_dict`](OdooRequest::allow_trailing_dict)
+    /// to send those.
+    ///
+    /// To check every `execute` request, see
+    /// [`OdooClient::with_kwargs_misuse_check`](crate::client::OdooClient::with_kwargs_misuse_check).
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::{OdooClient, jvec};
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// // refused with a `LikelyKwargsMisuse` error
+    /// let result = client
+    ///     .execute("res.partner", "search_read", jvec![[], {"fields": ["name"]}])
+    ///     .check_kwargs_misuse()
+    ///     .send();
+    /// assert!(result.is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check_kwargs_misuse(mut self) -> Self {
+        self.check_kwargs_misuse = true;
+        self
+    }
+
+    /// Allow a dict as the last `execute` argument
+    ///
+    /// This overrides [`check_kwargs_misuse`](OdooRequest::check_kwargs_misuse)
+    /// (or the client-wide check), for methods which take a positional dict,
+    /// e.g. the `vals` for `create` or `write`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::{OdooClient, jvec};
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let mut client = client.authenticate_manual("", "", 1, "", None);
+    /// client.with_kwargs_misuse_check(true);
+    /// client
+    ///     .execute("res.partner", "write", jvec![[1], {"name": "Acme"}])
+    ///     .allow_trailing_dict()
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn allow_trailing_dict(mut self) -> Self {
+        self.allow_trailing_dict = true;
+        self
+    }
+}
+
+impl<'a, T, I> OdooRequest<'a, T, I>
+where
+    T: JsonRpcParams + OdooKwargsMethod + Debug + Serialize,
+    T::Container<T>: Debug + Serialize,
+    I: RequestImpl,
+{
+    /// Set the Odoo `context` for this request
+    ///
+    /// The context is merged into the method kwargs, so any existing context
+    /// keys are preserved (unless overridden here). Calling this method multiple
+    /// times will merge each context in turn.
+    ///
+    /// This is only available for methods that accept kwargs (see [`OdooKwargsMethod`]).
+    pub fn with_context(mut self, context: Map<String, Value>) -> Self {
+        match &mut self.context {
+            Some((existing, _)) => existing.extend(context),
+            None => self.context = Some((context, T::inject_context)),
+        }
+        self
+    }
+
+    /// Set the active companies for this request only
+    ///
+    /// See [`OdooClient::with_companies`](crate::client::OdooClient::with_companies)
+    /// for more info.
+    pub fn with_companies(self, current: OdooId, allowed: Vec<OdooId>) -> Result<Self> {
+        let mut context = companies_context(current, allowed)?;
+        if T::SETS_COMPANY {
+            context.extend(company_context(current, self.version));
+        }
+        Ok(self.with_context(context))
+    }
+
+    /// Set default field values, as `default_*` context keys
+    ///
+    /// Each key is prefixed with `default_` and merged into the context, e.g.
+    /// `{"order_id": 42}` becomes `{"default_order_id": 42}`. Odoo uses these
+    /// for any field not set in the `create()` values (including on records
+    /// created indirectly, like the lines of a one2many).
+    ///
+    /// Keys which already start with `default_` are rejected with an
+    /// [`InvalidDefault`](crate::client::Error::InvalidDefault) error, rather
+    /// than being sent as `default_default_*`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::{OdooClient, jmap};
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// let line = client
+    ///     .create("sale.order.line", jmap! {"product_id": 7})
+    ///     .with_defaults(jmap! {"order_id": 42, "product_uom_qty": 1})?
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_defaults(self, defaults: Map<String, Value>) -> Result<Self> {
+        Ok(self.with_context(defaults_context(defaults)?))
+    }
+}
+
+/// Prefix each of the `defaults` keys with `default_`
+fn defaults_context(defaults: Map<String, Value>) -> Result<Map<String, Value>> {
+    defaults
+        .into_iter()
+        .map(|(key, value)| match key.starts_with("default_") {
+            true => Err(Error::InvalidDefault { key }),
+            false => Ok((format!("default_{}", key), value)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::client::error::{ClosureError, Result};
+    use crate::client::{Middleware, OdooClient};
+    use crate::fixtures;
+    use serde_json::{json, Value};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    pub(crate) type Captured = Rc<RefCell<Vec<(Value, Vec<(String, String)>)>>>;
+
+    /// Build a closure-based client which records each request body and headers
+    pub(crate) fn capture_client() -> (
+        OdooClient<crate::client::Authed, crate::client::ClosureBlocking>,
+        Captured,
+    ) {
+        capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": []
+        }))
+    }
+
+    /// Same as [`capture_client`], but always responds with `response`
+    pub(crate) fn capture_client_with(
+        response: Value,
+    ) -> (
+        OdooClient<crate::client::Authed, crate::client::ClosureBlocking>,
+        Captured,
+    ) {
+        let captured: Captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let client = OdooClient::new_closure_blocking_with_headers(
+            "http://localhost:8069",
+            move |_url, data, _session_id, headers| {
+                inner.borrow_mut().push((data, headers.to_vec()));
+                Ok((response.to_string(), None))
+            },
+        )
+        .authenticate_manual("some-database", "admin", 2, "password", None);
+
+        (client, captured)
+    }
+
+    #[test]
+    fn with_context() -> Result<()> {
+        let (client, captured) = capture_client();
+        client
+            .search_read("res.partner", jvec![], svec!["name"])
+            .with_context(jmap! {"active_test": false})
+            .with_context(jmap! {"lang": "en_GB"})
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"][6]["context"],
+            json!({"active_test": false, "lang": "en_GB"})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_context_merge() -> Result<()> {
+        let (client, captured) = capture_client();
+        client
+            .execute_kw(
+                "res.partner",
+                "search",
+                jvec![[]],
+                jmap! {
+                    "context": {"lang": "en_GB", "tz": "UTC"}
+                },
+            )
+            .with_context(jmap! {"lang": "fr_FR"})
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"][6]["context"],
+            json!({"lang": "fr_FR", "tz": "UTC"})
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_defaults() -> Result<()> {
+        let (client, captured) = capture_client();
+        client
+            .create("sale.order.line", jmap! {"product_id": 7})
+            .with_context(jmap! {"lang": "en_GB"})
+            .with_defaults(jmap! {"order_id": 42, "product_uom_qty": 1})?
+            .send_raw()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(body["params"]["args"][4], json!("create"));
+        assert_eq!(
+            body["params"]["args"][6]["context"],
+            json!({"lang": "en_GB", "default_order_id": 42, "default_product_uom_qty": 1})
+        );
+
+        // already-prefixed keys are refused
+        let result = client
+            .create("sale.order.line", jmap! {"product_id": 7})
+            .with_defaults(jmap! {"default_order_id": 42});
+        assert!(matches!(
+            result,
+            Err(crate::client::Error::InvalidDefault { ref key }) if key == "default_order_id"
+        ));
+        assert_eq!(captured.borrow().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_header() -> Result<()> {
+        let (client, captured) = capture_client();
+        client
+            .execute("res.partner", "search", jvec![[]])
+            .with_header("X-Odoo-Database", "some-database")
+            .with_header("X-Request-Source", "odoo-api")
+            .send()?;
+
+        let (body, headers) = &captured.borrow()[0];
+        assert_eq!(
+            headers,
+            &vec![
+                ("X-Odoo-Database".to_string(), "some-database".to_string()),
+                ("X-Request-Source".to_string(), "odoo-api".to_string()),
+            ]
+        );
+        assert_eq!(body["params"]["method"], json!("execute"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn trailing_dict() -> Result<()> {
+        let (client, captured) = capture_client();
+
+        // with the check on, a trailing dict is refused, without sending the request
+        let err = client
+            .execute(
+                "res.partner",
+                "search_read",
+                jvec![[], {"fields": ["name"]}],
+            )
+            .check_kwargs_misuse()
+            .send()
+            .unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                ClosureError::LikelyKwargsMisuse { model, method }
+                    if model == "res.partner" && method == "search_read"
+            ),
+            "{:?}",
+            err
+        );
+        assert!(captured.borrow().is_empty());
+
+        // unless it's allowed
+        client
+            .execute("res.partner", "write", jvec![[1], {"name": "Acme"}])
+            .check_kwargs_misuse()
+            .allow_trailing_dict()
+            .send()?;
+
+        // other args (and `execute_kw`) are sent as-is
+        client
+            .execute("res.partner", "read", jvec![[1], ["name"]])
+            .send()?;
+        client
+            .execute_kw(
+                "res.partner",
+                "read",
+                jvec![[1]],
+                jmap! {"fields": ["name"]},
+            )
+            .send()?;
+
+        let captured = captured.borrow();
+        let args: Vec<_> = captured
+            .iter()
+            .map(|(body, _)| body["params"]["args"].clone())
+            .collect();
+        assert_eq!(args[0][5], json!([1]));
+        assert_eq!(args[0][6], json!({"name": "Acme"}));
+        assert_eq!(args[1][6], json!(["name"]));
+        assert_eq!(args[2][6], json!({"fields": ["name"]}));
+
+        Ok(())
+    }
+
+    #[test]
+    fn trailing_dict_default() -> Result<()> {
+        let (mut client, captured) = capture_client();
+
+        // the check is off by default, so `create` and `write` work as-is
+        client
+            .execute("res.partner", "create", jvec![{"name": "Acme"}])
+            .send()?;
+        client
+            .execute("res.partner", "write", jvec![[1], {"name": "Acme"}])
+            .send()?;
+        assert_eq!(captured.borrow().len(), 2);
+
+        // the client-wide check applies to every request
+        client.with_kwargs_misuse_check(true);
+        let err = client
+            .execute("res.partner", "create", jvec![{"name": "Acme"}])
+            .send()
+            .unwrap_err();
+        assert!(
+            matches!(err, ClosureError::LikelyKwargsMisuse { .. }),
+            "{:?}",
+            err
+        );
+        client
+            .execute("res.partner", "create", jvec![{"name": "Acme"}])
+            .allow_trailing_dict()
+            .send()?;
+        assert_eq!(captured.borrow().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_params() -> Result<()> {
+        // the middleware sees the patched params
+        struct Tenant;
+        impl Middleware for Tenant {
+            fn on_request(
+                &self,
+                _url: &mut String,
+                headers: &mut Vec<(String, String)>,
+                body: &mut Value,
+            ) {
+                if let Some(token) = body["params"]["tenant_token"].as_str() {
+                    headers.push(("X-Tenant".into(), token.into()));
+                }
+            }
+        }
+
+        let (mut client, captured) = capture_client();
+        client.with_middleware(Tenant);
+        client
+            .search("res.partner", jvec![["active", "=", true]])
+            .limit(5)
+            .map_params(|params| params["tenant_token"] = json!("abc123"))
+            .send()?;
+
+        let (body, headers) = &captured.borrow()[0];
+        assert_eq!(body["params"]["tenant_token"], json!("abc123"));
+        assert_eq!(headers, &vec![("X-Tenant".into(), "abc123".into())]);
+
+        // the typed params are otherwise intact
+        let mut params = body["params"].clone();
+        params.as_object_mut().unwrap().remove("tenant_token");
+        let expected = client
+            .search("res.partner", jvec![["active", "=", true]])
+            .limit(5)
+            .build_body()?;
+        assert_eq!(params, expected["params"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn db_guard() -> Result<()> {
+        use crate::client::DbGuard;
+
+        let (mut client, captured) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": true
+        }));
+        client.with_db_guard(
+            DbGuard::new()
+                .protect_pattern("*prod*")
+                .require_confirmation(),
+        );
+
+        // protected databases are refused, and nothing is sent
+        let err = client
+            .db_drop("master-password", "acme-prod")
+            .send()
+            .unwrap_err();
+        match err {
+            ClosureError::ProtectedDatabase { method, db } => {
+                assert_eq!(method, "drop");
+                assert_eq!(db, "acme-prod");
+            }
+            err => panic!("Expected ProtectedDatabase, got {:?}", err),
+        }
+        let err = client
+            .db_rename("master-password", "acme-prod", "acme-old")
+            .send()
+            .unwrap_err();
+        assert!(
+            matches!(err, ClosureError::ProtectedDatabase { .. }),
+            "{:?}",
+            err
+        );
+        assert!(captured.borrow().is_empty());
+
+        // other databases are sent as usual
+        client.db_drop("master-password", "acme-staging").send()?;
+        client
+            .db_rename("master-password", "acme-staging", "acme-prod")
+            .send()?;
+        assert_eq!(captured.borrow().len(), 2);
+
+        // unless the request is confirmed
+        client
+            .db_drop("master-password", "acme-prod")
+            .confirm_destructive()
+            .send()?;
+        let (body, _) = &captured.borrow()[2];
+        assert_eq!(
+            body["params"]["args"],
+            json!(["master-password", "acme-prod"])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn db_list_disabled() {
+        let (client, _) = capture_client_with(fixtures::errors::access_denied());
+
+        let err = client.db_list(false).send().unwrap_err();
+        assert!(matches!(err, ClosureError::DbListingDisabled), "{:?}", err);
+
+        // other methods still surface the raw JSON-RPC error
+        let err = client.db_list_lang().send().unwrap_err();
+        assert!(matches!(err, ClosureError::JsonRpcError(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn on_warning() -> Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        let (mut client, _) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "value": {"price_unit": 0.0},
+                "warning": {"title": "Warning", "message": "No price found", "type": "dialog"}
+            }
+        }));
+        let warnings = Arc::new(Mutex::new(Vec::new()));
+        let inner = warnings.clone();
+        client.on_warning(move |warning| inner.lock().unwrap().push(warning.clone()));
+
+        let response = client
+            .execute_kw(
+                "sale.order.line",
+                "onchange",
+                jvec![{}, "product_id", {}],
+                jmap! {},
+            )
+            .send()?;
+        assert_eq!(response.data["value"]["price_unit"], json!(0.0));
+
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].title.as_deref(), Some("Warning"));
+        assert_eq!(warnings[0].message, "No price found");
+
+        Ok(())
+    }
+
+    #[test]
+    fn password_change_rejected() {
+        let (client, _) = capture_client_with(fixtures::errors::access_denied());
+
+        let err = client
+            .user_change_password("wrong-password", "new-password")
+            .send()
+            .unwrap_err();
+        assert!(
+            matches!(err, ClosureError::PasswordChangeRejected),
+            "{:?}",
+            err
+        );
+
+        // other methods still surface the raw JSON-RPC error
+        let err = client.user_groups().send().unwrap_err();
+        assert!(matches!(err, ClosureError::JsonRpcError(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn missing_argument() {
+        let (client, _) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {
+                "code": 200,
+                "message": "Odoo Server Error",
+                "data": {
+                    "name": "builtins.TypeError",
+                    "debug": "Traceback (most recent call last):\n  File \"/opt/odoo/odoo/http.py\", line 1589, in _serve_db\n    return service_model.retrying(self._serve_ir_http, self.env)\n  File \"/opt/odoo/odoo/service/model.py\", line 133, in retrying\n    result = func()\n  File \"/opt/odoo/odoo/service/model.py\", line 61, in execute_cr\n    result = retrying(partial(odoo.api.call_kw, recs, method, args, kw), env)\n  File \"/opt/odoo/odoo/api.py\", line 461, in call_kw\n    result = _call_kw_model(method, model, args, kwargs)\n  File \"/opt/odoo/odoo/api.py\", line 434, in _call_kw_model\n    result = method(recs, *args, **kwargs)\nTypeError: BaseModel.search() missing 1 required positional argument: 'args'\n",
+                    "message": "BaseModel.search() missing 1 required positional argument: 'args'",
+                    "arguments": ["BaseModel.search() missing 1 required positional argument: 'args'"],
+                    "context": {}
+                }
+            }
+        }));
+
+        let err = client
+            .execute("res.users", "search", jvec![])
+            .send()
+            .unwrap_err();
+        match err {
+            ClosureError::MissingArgument { method, detail } => {
+                assert_eq!(method, "search");
+                assert_eq!(
+                    detail,
+                    "BaseModel.search() missing 1 required positional argument: 'args'"
+                );
+            }
+            err => panic!("Expected MissingArgument, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn other_type_errors() {
+        let (client, _) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {
+                "code": 200,
+                "message": "Odoo Server Error",
+                "data": {
+                    "name": "builtins.TypeError",
+                    "debug": "Traceback (most recent call last):\n...",
+                    "message": "read() got an unexpected keyword argument 'foo'",
+                    "arguments": ["read() got an unexpected keyword argument 'foo'"],
+                    "context": {}
+                }
+            }
+        }));
+
+        let err = client
+            .execute("res.users", "read", jvec![[1]])
+            .send()
+            .unwrap_err();
+        assert!(matches!(err, ClosureError::JsonRpcError(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn empty_and_non_object_bodies() {
+        let send = |body: &'static str| {
+            OdooClient::new_closure_blocking("http://localhost:8069", move |_, _, _| {
+                Ok((body.to_string(), None))
+            })
+            .authenticate_manual("some-database", "admin", 2, "password", None)
+            .read("res.partner", 1, vec!["name".into()])
+            .send()
+            .unwrap_err()
+        };
+
+        let err = send("");
+        assert!(matches!(err, ClosureError::EmptyResponse), "{:?}", err);
+        let err = send(" \r\n\t");
+        assert!(matches!(err, ClosureError::EmptyResponse), "{:?}", err);
+
+        let err = send("null");
+        assert!(
+            matches!(err, ClosureError::NotAnObject { ref found } if found == "null"),
+            "{:?}",
+            err
+        );
+        assert_eq!(
+            err.to_string(),
+            "Expected a JSON-RPC response object, got null"
+        );
+        let err = send("[1, 2]");
+        assert!(
+            matches!(err, ClosureError::NotAnObject { ref found } if found == "an array"),
+            "{:?}",
+            err
+        );
+
+        // a truncated body is still a JSON error
+        let err = send(r#"{"jsonrpc": "2.0", "id": 1, "result": [{"id": 1, "na"#);
+        match err {
+            ClosureError::SerdeJsonError(err) => assert!(err.is_eof(), "{:?}", err),
+            err => panic!("expected a SerdeJsonError, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn strict_responses() -> Result<()> {
+        let (mut client, _) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [],
+            "proxy": "cache-hit"
+        }));
+
+        // unknown keys are ignored by default
+        client.search("res.partner", jvec![]).send()?;
+
+        client.with_strict_responses(true);
+        let err = client.search("res.partner", jvec![]).send().unwrap_err();
+        match err {
+            ClosureError::MalformedResponse { reason } => {
+                assert_eq!(reason, "unexpected key(s): proxy")
+            }
+            err => panic!("Expected MalformedResponse, got {:?}", err),
+        }
+
+        // a response with neither a result nor an error is always rejected
+        let (client, _) = capture_client_with(json!({"jsonrpc": "2.0", "id": 1}));
+        let err = client.search("res.partner", jvec![]).send().unwrap_err();
+        assert!(
+            matches!(err, ClosureError::MalformedResponse { .. }),
+            "{:?}",
+            err
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unexpected_response_shape() {
+        let (client, _) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "server_version": "17.0",
+                "server_version_info": [17, 0, 0, "final", 0, ""],
+                "server_serie": "17.0",
+                "protocol_version": 1,
+                "build": "20240101"
+            }
+        }));
+
+        let result = client.common_version().send();
+        if cfg!(feature = "strict-responses") {
+            match result.unwrap_err() {
+                ClosureError::UnexpectedResponseShape { context, detail } => {
+                    assert_eq!(context, "VersionResponse");
+                    assert!(detail.contains("`build`"), "{}", detail);
+                }
+                err => panic!("Expected UnexpectedResponseShape, got {:?}", err),
+            }
+        } else {
+            assert_eq!(result.unwrap().server_serie, "17.0");
         }
     }
 }
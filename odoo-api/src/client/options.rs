@@ -0,0 +1,739 @@
+//! The [`OdooClientBuilder`], and the [`ClientOptions`] it collects
+//!
+//! The options are `Serialize` and `Deserialize`, so a client can be configured
+//! from a file. Durations are written in seconds (e.g. `timeout = 30`, or
+//! `backoff = 0.5`), and every key except `url` is optional.
+
+use super::error::{Error, Result};
+use super::http_impl::closure_async::ClosureReturn as AsyncClosureReturn;
+use super::http_impl::closure_blocking::ClosureReturn as BlockingClosureReturn;
+use super::retry::RetryPolicy;
+use super::tls::{self, TlsIdentity, TlsOptions};
+use super::{ClosureAsync, ClosureBlocking, NotAuthed, OdooClient, RequestImpl};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+/// The configuration for an [`OdooClient`], usually built with [`OdooClientBuilder`]
+///
+/// ## Example
+/// ```
+/// use odoo_api::client::ClientOptions;
+///
+/// let options: ClientOptions = serde_json::from_str(r#"{
+///     "url": "https://odoo.example.com",
+///     "read_replica": "https://replica.odoo.example.com",
+///     "timeout": 30,
+///     "retry": {"max_retries": 3, "backoff": 0.5},
+///     "context": {"lang": "en_US"}
+/// }"#)?;
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientOptions {
+    /// The (primary) server URL, e.g. `https://odoo.example.com`
+    pub url: String,
+
+    /// See [`OdooClient::with_read_replica`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_replica: Option<String>,
+
+    /// See [`OdooClient::with_timeout`]
+    #[serde(
+        with = "duration_secs::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout: Option<Duration>,
+
+    /// See [`OdooClient::with_retry`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+
+    /// See [`OdooClient::with_rate_limit`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitOptions>,
+
+    /// The client-wide context, applied to every request that accepts kwargs
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub context: Map<String, Value>,
+
+    /// See [`OdooClient::with_user_agent`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// See [`OdooClient::with_jsonrpc_path`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsonrpc_path: Option<String>,
+
+    /// See [`OdooClient::with_web_prefix`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_prefix: Option<String>,
+
+    /// See [`OdooClient::with_database_hint`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_hint: Option<String>,
+
+    /// See [`OdooClient::with_database_hint_header`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_hint_header: Option<String>,
+
+    /// See [`OdooClient::with_strict_responses`]
+    pub strict_responses: bool,
+
+    /// See [`OdooClient::with_max_response_size`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_response_size: Option<usize>,
+
+    /// The TLS options (for the `reqwest` impls only)
+    #[serde(skip_serializing_if = "TlsOptions::is_empty")]
+    pub tls: TlsOptions,
+}
+
+/// The [`OdooClient::with_rate_limit`] options
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitOptions {
+    /// The number of requests (and the burst size)
+    pub requests: u32,
+
+    /// The period (in seconds, when serialized)
+    #[serde(with = "duration_secs")]
+    pub per: Duration,
+}
+
+impl ClientOptions {
+    /// Check for missing or conflicting options
+    ///
+    /// This is called by the `OdooClientBuilder::build_*()` methods, and
+    /// returns an [`Error::InvalidOptions`] describing the first problem found.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: &str| {
+            Err(Error::InvalidOptions {
+                reason: reason.into(),
+            })
+        };
+
+        if self.url.is_empty() {
+            return match self.read_replica {
+                Some(_) => invalid("a `read_replica` requires the primary `url`"),
+                None => invalid("the `url` is required"),
+            };
+        }
+        if self.read_replica.as_deref().map(trim_url) == Some(trim_url(&self.url)) {
+            return invalid("the `read_replica` is the same as the primary `url`");
+        }
+        if matches!(&self.jsonrpc_path, Some(path) if !path.starts_with('/')) {
+            return invalid("the `jsonrpc_path` must start with `/`");
+        }
+        if matches!(&self.web_prefix, Some(prefix) if !prefix.starts_with('/')) {
+            return invalid("the `web_prefix` must start with `/`");
+        }
+        if matches!(self.rate_limit, Some(limit) if limit.requests == 0) {
+            return invalid("the `rate_limit` must allow at least one request");
+        }
+        let plain_http = tls::is_plain_http(&self.url)
+            || self.read_replica.as_deref().is_some_and(tls::is_plain_http);
+        if !self.tls.is_empty() && plain_http {
+            return invalid("the `tls` options require an `https://` url");
+        }
+
+        Ok(())
+    }
+}
+
+/// Strip the trailing `/` from a URL, as [`OdooClient`] does
+fn trim_url(url: &str) -> &str {
+    url.trim_end_matches('/')
+}
+
+/// Build an [`OdooClient`] from a set of [`ClientOptions`]
+///
+/// Start with [`OdooClient::builder`] (or [`from_options`](Self::from_options)
+/// for options loaded from a file), then finish with one of the `build_*()`
+/// methods. Each option maps to one of the `OdooClient::with_*()` methods.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # async fn test() -> odoo_api::client::Result<()> {
+/// use odoo_api::{jmap, OdooClient};
+/// use odoo_api::client::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let client = OdooClient::builder("https://odoo.example.com")
+///     .timeout(Duration::from_secs(30))
+///     .retry(RetryPolicy::new(3, Duration::from_millis(500)))
+///     .default_context(jmap! {"lang": "en_US"})
+///     .user_agent("acme-sync/1.0")
+///     .build_reqwest_async()?
+///     .authenticate("acme", "admin", "password")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OdooClientBuilder {
+    options: ClientOptions,
+}
+
+impl OdooClientBuilder {
+    /// Start building a client for the server at `url`
+    pub fn new(url: &str) -> Self {
+        Self::from_options(ClientOptions {
+            url: url.into(),
+            ..Default::default()
+        })
+    }
+
+    /// Start from an existing set of `options` (e.g. loaded from a file)
+    pub fn from_options(options: ClientOptions) -> Self {
+        Self { options }
+    }
+
+    /// The options collected so far
+    pub fn options(&self) -> &ClientOptions {
+        &self.options
+    }
+
+    /// See [`OdooClient::with_read_replica`]
+    pub fn read_replica(mut self, url: &str) -> Self {
+        self.options.read_replica = Some(url.into());
+        self
+    }
+
+    /// See [`OdooClient::with_timeout`]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// See [`OdooClient::with_retry`]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.options.retry = Some(policy);
+        self
+    }
+
+    /// See [`OdooClient::with_rate_limit`]
+    pub fn rate_limit(mut self, requests: u32, per: Duration) -> Self {
+        self.options.rate_limit = Some(RateLimitOptions { requests, per });
+        self
+    }
+
+    /// Merge `context` into the client-wide context
+    ///
+    /// This is applied to every request that accepts kwargs, and can be
+    /// extended per-request with
+    /// [`OdooRequest::with_context`](crate::client::OdooRequest::with_context).
+    pub fn default_context(mut self, context: Map<String, Value>) -> Self {
+        self.options.context.extend(context);
+        self
+    }
+
+    /// See [`OdooClient::with_user_agent`]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.options.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// See [`OdooClient::with_jsonrpc_path`]
+    pub fn jsonrpc_path(mut self, path: &str) -> Self {
+        self.options.jsonrpc_path = Some(path.into());
+        self
+    }
+
+    /// See [`OdooClient::with_web_prefix`]
+    pub fn web_prefix(mut self, prefix: &str) -> Self {
+        self.options.web_prefix = Some(prefix.into());
+        self
+    }
+
+    /// See [`OdooClient::with_database_hint`]
+    pub fn database_hint(mut self, db: &str) -> Self {
+        self.options.database_hint = Some(db.into());
+        self
+    }
+
+    /// See [`OdooClient::with_database_hint_header`]
+    pub fn database_hint_header(mut self, name: &str) -> Self {
+        self.options.database_hint_header = Some(name.into());
+        self
+    }
+
+    /// See [`OdooClient::with_strict_responses`]
+    pub fn strict_responses(mut self, strict: bool) -> Self {
+        self.options.strict_responses = strict;
+        self
+    }
+
+    /// See [`OdooClient::with_max_response_size`]
+    pub fn max_response_size(mut self, bytes: usize) -> Self {
+        self.options.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Accept any server certificate, **without verifying it**
+    ///
+    /// This is only meant for throwaway test servers, as anyone between the
+    /// client and the server could impersonate it. To trust an internal CA,
+    /// use [`add_root_certificate`](Self::add_root_certificate) instead. See
+    /// [`TlsOptions`].
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.options.tls.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// Trust the CA certificate(s) in `pem`, in addition to the system's
+    ///
+    /// See [`TlsOptions`].
+    pub fn add_root_certificate(mut self, pem: &[u8]) -> Self {
+        let pem = String::from_utf8_lossy(pem).into_owned();
+        self.options.tls.root_certificates.push(pem);
+        self
+    }
+
+    /// Identify the client with a certificate, for mutual TLS
+    ///
+    /// Both are PEM-encoded, and the private key must be PKCS #8. See
+    /// [`TlsOptions`].
+    pub fn identity(mut self, certificate_pem: &[u8], private_key_pem: &[u8]) -> Self {
+        self.options.tls.identity = Some(TlsIdentity {
+            certificate: String::from_utf8_lossy(certificate_pem).into_owned(),
+            private_key: String::from_utf8_lossy(private_key_pem).into_owned(),
+        });
+        self
+    }
+
+    /// Apply the options to a newly-built `client`
+    fn configure<I: RequestImpl>(self, client: &mut OdooClient<NotAuthed, I>) {
+        let options = self.options;
+        if let Some(url) = &options.read_replica {
+            client.with_read_replica(url);
+        }
+        if let Some(timeout) = options.timeout {
+            client.with_timeout(timeout);
+        }
+        if let Some(policy) = options.retry {
+            client.with_retry(policy);
+        }
+        if let Some(limit) = options.rate_limit {
+            client.with_rate_limit(limit.requests, limit.per);
+        }
+        client.context.extend(options.context);
+        if let Some(user_agent) = &options.user_agent {
+            client.with_user_agent(user_agent);
+        }
+        if let Some(path) = &options.jsonrpc_path {
+            client.with_jsonrpc_path(path);
+        }
+        if let Some(prefix) = &options.web_prefix {
+            client.with_web_prefix(prefix);
+        }
+        if let Some(db) = options.database_hint {
+            client.with_database_hint(db);
+        }
+        if let Some(name) = options.database_hint_header {
+            client.with_database_hint_header(name);
+        }
+        client.with_strict_responses(options.strict_responses);
+        if let Some(bytes) = options.max_response_size {
+            client.with_max_response_size(bytes);
+        }
+    }
+
+    /// The closure impls can't tell why a request failed, so they can't retry
+    fn check_closure(&self) -> Result<()> {
+        self.options.validate()?;
+        self.check_no_tls()?;
+        match self.options.retry {
+            Some(_) => Err(Error::InvalidOptions {
+                reason: "`retry` isn't supported by the closure-based impls".into(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// The TLS options are mapped onto the `reqwest` client builder only
+    fn check_no_tls(&self) -> Result<()> {
+        match self.options.tls.is_empty() {
+            true => Ok(()),
+            false => Err(Error::InvalidOptions {
+                reason: "the `tls` options are only supported by the reqwest impls".into(),
+            }),
+        }
+    }
+
+    /// Build a client using the blocking `closure`
+    ///
+    /// See [`OdooClient::new_closure_blocking`].
+    pub fn build_closure_blocking<F>(
+        self,
+        closure: F,
+    ) -> Result<OdooClient<NotAuthed, ClosureBlocking>>
+    where
+        F: Fn(&str, Value, Option<&str>) -> BlockingClosureReturn + 'static,
+    {
+        self.build_closure_blocking_with_headers(move |url, data, session_id, _headers| {
+            closure(url, data, session_id)
+        })
+    }
+
+    /// Build a client using the blocking `closure`, which also receives the headers
+    ///
+    /// See [`OdooClient::new_closure_blocking_with_headers`].
+    pub fn build_closure_blocking_with_headers<F>(
+        self,
+        closure: F,
+    ) -> Result<OdooClient<NotAuthed, ClosureBlocking>>
+    where
+        F: Fn(&str, Value, Option<&str>, &[(String, String)]) -> BlockingClosureReturn + 'static,
+    {
+        self.check_closure()?;
+        let mut client = OdooClient::new_closure_blocking_with_headers(&self.options.url, closure);
+        self.configure(&mut client);
+        Ok(client)
+    }
+
+    /// Build a client using the async `closure`
+    ///
+    /// See [`OdooClient::new_closure_async`].
+    pub fn build_closure_async(
+        self,
+        closure: impl 'static + Fn(String, Value, Option<String>) -> AsyncClosureReturn,
+    ) -> Result<OdooClient<NotAuthed, ClosureAsync>> {
+        self.build_closure_async_with_headers(move |url, data, session_id, _headers| {
+            closure(url, data, session_id)
+        })
+    }
+
+    /// Build a client using the async `closure`, which also receives the headers
+    ///
+    /// See [`OdooClient::new_closure_async_with_headers`].
+    pub fn build_closure_async_with_headers(
+        self,
+        closure: impl 'static
+            + Fn(String, Value, Option<String>, Vec<(String, String)>) -> AsyncClosureReturn,
+    ) -> Result<OdooClient<NotAuthed, ClosureAsync>> {
+        self.check_closure()?;
+        let mut client = OdooClient::new_closure_async_with_headers(&self.options.url, closure);
+        self.configure(&mut client);
+        Ok(client)
+    }
+
+    /// Build an async `reqwest` client
+    ///
+    /// See [`OdooClient::new_reqwest_async`].
+    #[cfg(feature = "async")]
+    pub fn build_reqwest_async(self) -> Result<OdooClient<NotAuthed, super::ReqwestAsync>> {
+        self.options.validate()?;
+        let builder = self
+            .options
+            .tls
+            .configure_async(reqwest::Client::builder())?;
+        let mut client = OdooClient::new_reqwest_async_with(&self.options.url, builder)?;
+        self.configure(&mut client);
+        Ok(client)
+    }
+
+    /// Build a blocking `reqwest` client
+    ///
+    /// See [`OdooClient::new_reqwest_blocking`].
+    #[cfg(feature = "blocking")]
+    pub fn build_reqwest_blocking(self) -> Result<OdooClient<NotAuthed, super::ReqwestBlocking>> {
+        self.options.validate()?;
+        let builder = self
+            .options
+            .tls
+            .configure_blocking(reqwest::blocking::Client::builder())?;
+        let mut client = OdooClient::new_reqwest_blocking_with(&self.options.url, builder)?;
+        self.configure(&mut client);
+        Ok(client)
+    }
+
+    /// Build a blocking `ureq` client
+    ///
+    /// See [`OdooClient::new_ureq`].
+    #[cfg(feature = "ureq")]
+    pub fn build_ureq(self) -> Result<OdooClient<NotAuthed, super::UreqBlocking>> {
+        self.options.validate()?;
+        self.check_no_tls()?;
+        let mut client = OdooClient::new_ureq(&self.options.url);
+        self.configure(&mut client);
+        Ok(client)
+    }
+
+    /// Build a browser `fetch()` client
+    ///
+    /// See [`OdooClient::new_fetch`]. The `timeout`, `retry`, `rate_limit`,
+    /// and `tls` options aren't supported in the browser.
+    #[cfg(feature = "wasm")]
+    pub fn build_fetch(self) -> Result<OdooClient<NotAuthed, super::FetchImpl>> {
+        self.options.validate()?;
+        self.check_no_tls()?;
+        let unsupported = [
+            ("timeout", self.options.timeout.is_some()),
+            ("retry", self.options.retry.is_some()),
+            ("rate_limit", self.options.rate_limit.is_some()),
+        ];
+        if let Some((name, _)) = unsupported.iter().find(|(_, set)| *set) {
+            return Err(Error::InvalidOptions {
+                reason: format!("`{}` isn't supported by the fetch impl", name),
+            });
+        }
+        let mut client = OdooClient::new_fetch(&self.options.url);
+        self.configure(&mut client);
+        Ok(client)
+    }
+}
+
+// `builder()` is only defined for one impl, so that `OdooClient::builder(..)`
+// doesn't need the impl type (which is chosen by the `build_*()` method instead)
+impl OdooClient<NotAuthed, ClosureBlocking> {
+    /// Start building a client for the server at `url`
+    ///
+    /// See [`OdooClientBuilder`].
+    pub fn builder(url: &str) -> OdooClientBuilder {
+        OdooClientBuilder::new(url)
+    }
+}
+
+/// Serialize a [`Duration`] as a number of seconds
+pub(crate) mod duration_secs {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(secs)
+            .map_err(|_| D::Error::custom(format!("invalid duration `{}`", secs)))
+    }
+
+    /// The same, for an `Option<Duration>`
+    pub(crate) mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use std::time::Duration;
+
+        pub(crate) fn serialize<S: Serializer>(
+            duration: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match duration {
+                Some(duration) => super::serialize(duration, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            #[derive(Deserialize)]
+            struct Secs(#[serde(with = "super")] Duration);
+
+            Ok(Option::<Secs>::deserialize(deserializer)?.map(|secs| secs.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::odoo_request::test::Captured;
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const TOML: &str = r#"
+        url = "http://localhost:8069/"
+        read_replica = "http://replica:8069"
+        timeout = 2.5
+        user_agent = "acme-sync/1.0"
+        jsonrpc_path = "/rpc/jsonrpc"
+        max_response_size = 1048576
+
+        [retry]
+        max_retries = 3
+        backoff = 0.5
+
+        [rate_limit]
+        requests = 10
+        per = 1
+
+        [context]
+        lang = "fr_FR"
+        tz = "Europe/Paris"
+    "#;
+
+    #[test]
+    fn from_toml() {
+        let options: ClientOptions = toml::from_str(TOML).unwrap();
+        assert_eq!(
+            options,
+            ClientOptions {
+                url: "http://localhost:8069/".into(),
+                read_replica: Some("http://replica:8069".into()),
+                timeout: Some(Duration::from_millis(2500)),
+                retry: Some(RetryPolicy::new(3, Duration::from_millis(500))),
+                rate_limit: Some(RateLimitOptions {
+                    requests: 10,
+                    per: Duration::from_secs(1),
+                }),
+                context: jmap! {"lang": "fr_FR", "tz": "Europe/Paris"},
+                user_agent: Some("acme-sync/1.0".into()),
+                jsonrpc_path: Some("/rpc/jsonrpc".into()),
+                max_response_size: Some(1048576),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn from_json() {
+        let options: ClientOptions = serde_json::from_value(json!({
+            "url": "http://localhost:8069",
+            "timeout": 30,
+            "strict_responses": true,
+        }))
+        .unwrap();
+        assert_eq!(options.timeout, Some(Duration::from_secs(30)));
+        assert!(options.strict_responses);
+        assert_eq!(options.retry, None);
+
+        // round-trip, skipping the unset options
+        let value = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            value,
+            json!({"url": "http://localhost:8069", "timeout": 30.0, "strict_responses": true})
+        );
+        assert_eq!(
+            serde_json::from_value::<ClientOptions>(value).unwrap(),
+            options
+        );
+
+        // typos are caught, rather than silently ignored
+        let err = serde_json::from_value::<ClientOptions>(json!({"timout": 30})).unwrap_err();
+        assert!(err.to_string().contains("unknown field `timout`"));
+        let err = serde_json::from_value::<ClientOptions>(json!({"timeout": -1})).unwrap_err();
+        assert!(err.to_string().contains("invalid duration"));
+    }
+
+    #[test]
+    fn build() -> Result<()> {
+        let captured: Captured = Rc::new(RefCell::new(Vec::new()));
+        let urls = Rc::new(RefCell::new(Vec::new()));
+        let (inner, inner_urls) = (captured.clone(), urls.clone());
+        let mut options: ClientOptions = toml::from_str(TOML).unwrap();
+        options.retry = None;
+        let client = OdooClientBuilder::from_options(options)
+            .default_context(jmap! {"active_test": false})
+            .build_closure_blocking_with_headers(move |url, data, _session_id, headers| {
+                inner_urls.borrow_mut().push(url.to_string());
+                inner.borrow_mut().push((data, headers.to_vec()));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": []}).to_string(),
+                    None,
+                ))
+            })?
+            .authenticate_manual("acme", "admin", 2, "password", None);
+
+        assert_eq!(client.url, "http://localhost:8069");
+        assert_eq!(client.timeout, Some(Duration::from_millis(2500)));
+        assert!(client.rate_limit.is_some());
+        assert_eq!(client.max_response_size, Some(1048576));
+
+        client
+            .search_read("res.partner", jvec![], svec!["name"])
+            .send()?;
+        client
+            .write("res.partner", 1, jmap! {"name": "Acme"})
+            .send_raw()?;
+
+        assert_eq!(
+            *urls.borrow(),
+            vec![
+                "http://replica:8069/rpc/jsonrpc",
+                "http://localhost:8069/rpc/jsonrpc"
+            ]
+        );
+        let (body, headers) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"][6]["context"],
+            json!({"lang": "fr_FR", "tz": "Europe/Paris", "active_test": false})
+        );
+        assert_eq!(
+            headers,
+            &vec![("User-Agent".to_string(), "acme-sync/1.0".to_string())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate() {
+        let reason = |builder: OdooClientBuilder| match builder
+            .build_closure_blocking(|_, _, _| Ok((String::new(), None)))
+        {
+            Err(Error::InvalidOptions { reason }) => reason,
+            other => panic!("expected InvalidOptions, got {:?}", other.map(|_| ())),
+        };
+
+        assert_eq!(
+            reason(OdooClientBuilder::default().read_replica("http://replica:8069")),
+            "a `read_replica` requires the primary `url`"
+        );
+        assert_eq!(
+            reason(OdooClientBuilder::default()),
+            "the `url` is required"
+        );
+        assert_eq!(
+            reason(
+                OdooClient::builder("http://localhost:8069").read_replica("http://localhost:8069/")
+            ),
+            "the `read_replica` is the same as the primary `url`"
+        );
+        assert_eq!(
+            reason(OdooClient::builder("http://localhost:8069").jsonrpc_path("jsonrpc")),
+            "the `jsonrpc_path` must start with `/`"
+        );
+        assert_eq!(
+            reason(
+                OdooClient::builder("http://localhost:8069").rate_limit(0, Duration::from_secs(1))
+            ),
+            "the `rate_limit` must allow at least one request"
+        );
+        assert_eq!(
+            reason(
+                OdooClient::builder("http://localhost:8069")
+                    .retry(RetryPolicy::new(3, Duration::from_secs(1)))
+            ),
+            "`retry` isn't supported by the closure-based impls"
+        );
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn build_fetch() {
+        let client = OdooClient::builder("http://localhost:8069")
+            .user_agent("acme-web/1.0")
+            .build_fetch()
+            .unwrap();
+        assert_eq!(client.url, "http://localhost:8069");
+
+        match OdooClient::builder("http://localhost:8069")
+            .timeout(Duration::from_secs(5))
+            .build_fetch()
+        {
+            Err(Error::InvalidOptions { reason }) => {
+                assert_eq!(reason, "`timeout` isn't supported by the fetch impl")
+            }
+            other => panic!("expected InvalidOptions, got {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! The [`Page`] type, and helpers for the `search_read_paged()` client method
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `search_read_paged()`
+//! itself (the blocking impls send the requests in turn, the async impls send
+//! them concurrently), and builds the requests here.
+
+use super::{Authed, OdooClient, OdooRequest, RequestImpl};
+use crate::service::orm::{SearchCount, SearchRead};
+use serde_json::{Map, Value};
+
+/// One page of `search_read` results, with the total number of matching records
+///
+/// Pages are numbered from `0`. A page beyond the end has no `records` (but
+/// the `total` and `pages` are still set).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T = Map<String, Value>> {
+    /// The records on this page
+    pub records: Vec<T>,
+
+    /// The number of records matching the domain (on all pages)
+    pub total: u32,
+
+    /// The page number (from `0`)
+    pub page: u32,
+
+    /// The maximum number of records per page
+    pub page_size: u32,
+
+    /// The number of pages
+    pub pages: u32,
+}
+
+impl<T> Page<T> {
+    /// Build the page, and count the pages
+    pub(crate) fn new(records: Vec<T>, total: u32, page: u32, page_size: u32) -> Self {
+        Self {
+            records,
+            total,
+            page,
+            page_size,
+            pages: total.div_ceil(page_size),
+        }
+    }
+
+    /// Whether there's a page after this one
+    pub fn has_next(&self) -> bool {
+        self.page.saturating_add(1) < self.pages
+    }
+}
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// Build the `search_count` and `search_read` requests for page `page`
+    ///
+    /// `page_size` must already be at least `1` (a `limit` of `0` would return
+    /// every record).
+    pub(crate) fn page_requests(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        fields: Vec<String>,
+        page: u32,
+        page_size: u32,
+        order: Option<&str>,
+    ) -> (
+        OdooRequest<'_, SearchCount, I>,
+        OdooRequest<'_, SearchRead, I>,
+    ) {
+        let count = self.search_count(model, domain.clone());
+        let mut read = self
+            .search_read(model, domain, fields)
+            .offset(page.saturating_mul(page_size))
+            .limit(page_size);
+        if let Some(order) = order {
+            read = read.order(order);
+        }
+        (count, read)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pages() {
+        let page = Page::new(vec![1, 2], 12, 2, 5);
+        assert_eq!(page.pages, 3);
+        assert!(!page.has_next());
+        assert!(Page::new(vec![1], 12, 1, 5).has_next());
+
+        assert_eq!(Page::<u32>::new(vec![], 10, 0, 5).pages, 2);
+        assert_eq!(Page::<u32>::new(vec![], 0, 0, 5).pages, 0);
+        assert!(!Page::<u32>::new(vec![], 0, 0, 5).has_next());
+    }
+}
@@ -0,0 +1,241 @@
+//! Named field lists ("presets") for `read` and `search_read` requests
+//!
+//! An app usually reads the same few fields of a model in many places. Rather
+//! than repeating the field list at each call, register it once with
+//! [`OdooClient::register_preset`], and refer to it by name with
+//! [`read_preset()`](OdooClient::read_preset) or
+//! [`search_read_preset()`](OdooClient::search_read_preset). The presets are
+//! shared between clones of the client, and may be changed at any time.
+
+use super::error::UnknownPreset;
+use super::{AuthState, Authed, OdooClient, OdooRequest, RequestImpl};
+use crate::jsonrpc::OdooIds;
+use crate::service::orm::{Read, SearchRead};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// The registered presets, by name
+#[derive(Debug, Default)]
+pub(crate) struct FieldPresets {
+    presets: RwLock<HashMap<String, Vec<String>>>,
+}
+
+impl FieldPresets {
+    /// The fields of preset `name`
+    fn get(&self, name: &str) -> Result<Vec<String>, UnknownPreset> {
+        let presets = self.presets.read().unwrap();
+        match presets.get(name) {
+            Some(fields) => Ok(fields.clone()),
+            None => Err(UnknownPreset {
+                name: name.into(),
+                registered: sorted_names(&presets),
+            }),
+        }
+    }
+}
+
+/// The preset names, sorted
+fn sorted_names(presets: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut names: Vec<String> = presets.keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+impl<S: AuthState, I: RequestImpl> OdooClient<S, I> {
+    /// Register the field preset `name` (e.g. `partner.basic`)
+    ///
+    /// An existing preset with the same name is replaced, and its fields are
+    /// returned. The preset is visible to every clone of this client.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::{OdooClient, jvec, svec};
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// client.register_preset("partner.basic", svec!["id", "name", "email", "phone"]);
+    ///
+    /// let partners = client.read_preset("res.partner", vec![1, 2], "partner.basic")?.send()?;
+    /// let companies = client
+    ///     .search_read_preset("res.partner", jvec![["is_company", "=", true]], "partner.basic")?
+    ///     .limit(10)
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_preset(&self, name: &str, fields: Vec<String>) -> Option<Vec<String>> {
+        let mut presets = self.presets.presets.write().unwrap();
+        presets.insert(name.into(), fields)
+    }
+
+    /// Remove the field preset `name`, returning its fields
+    pub fn remove_preset(&self, name: &str) -> Option<Vec<String>> {
+        self.presets.presets.write().unwrap().remove(name)
+    }
+
+    /// The fields of preset `name`
+    pub fn preset_fields(&self, name: &str) -> Result<Vec<String>, UnknownPreset> {
+        self.presets.get(name)
+    }
+
+    /// The registered preset names, sorted
+    pub fn preset_names(&self) -> Vec<String> {
+        sorted_names(&self.presets.presets.read().unwrap())
+    }
+}
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// Build a [`read`](OdooClient::read) request for the fields of preset `preset`
+    ///
+    /// Returns an [`UnknownPreset`] error (listing the registered presets) if
+    /// `preset` isn't registered. This converts into each client error type,
+    /// so `?` can be used as usual.
+    pub fn read_preset(
+        &self,
+        model: &str,
+        ids: impl Into<OdooIds>,
+        preset: &str,
+    ) -> Result<OdooRequest<'_, Read, I>, UnknownPreset> {
+        let fields = self.presets.get(preset)?;
+        Ok(self.read(model, ids, fields))
+    }
+
+    /// Build a [`search_read`](OdooClient::search_read) request for the fields
+    /// of preset `preset`
+    ///
+    /// See [`read_preset()`](Self::read_preset).
+    pub fn search_read_preset(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        preset: &str,
+    ) -> Result<OdooRequest<'_, SearchRead, I>, UnknownPreset> {
+        let fields = self.presets.get(preset)?;
+        Ok(self.search_read(model, domain, fields))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client::error::{ClosureError, Error, UnknownPreset};
+    use crate::client::odoo_request::test::capture_client;
+    use serde_json::json;
+
+    #[test]
+    fn register() {
+        let (client, _captured) = capture_client();
+        assert_eq!(client.register_preset("partner.basic", svec!["id"]), None);
+        assert_eq!(
+            client.register_preset("partner.basic", svec!["id", "name"]),
+            Some(svec!["id"])
+        );
+
+        client.register_preset("product.basic", svec!["id", "default_code"]);
+        assert_eq!(
+            client.preset_fields("partner.basic"),
+            Ok(svec!["id", "name"])
+        );
+        assert_eq!(
+            client.preset_names(),
+            svec!["partner.basic", "product.basic"]
+        );
+
+        assert_eq!(
+            client.remove_preset("product.basic"),
+            Some(svec!["id", "default_code"])
+        );
+        assert_eq!(client.preset_names(), svec!["partner.basic"]);
+    }
+
+    /// Clones share the presets, including later changes
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn shared_between_clones() -> Result<(), Box<dyn std::error::Error>> {
+        let client = crate::OdooClient::new_reqwest_blocking("http://localhost:8069")?;
+        client.register_preset("partner.basic", svec!["id", "name"]);
+
+        let clone = client.clone();
+        clone.register_preset("product.basic", svec!["id"]);
+        assert_eq!(
+            client.preset_names(),
+            svec!["partner.basic", "product.basic"]
+        );
+
+        // and survive authentication
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        client.remove_preset("partner.basic");
+        assert_eq!(clone.preset_names(), svec!["product.basic"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown() {
+        let (client, _captured) = capture_client();
+        client.register_preset("partner.basic", svec!["id"]);
+        client.register_preset("order.summary", svec!["id"]);
+
+        let Err(err) = client.read_preset("res.partner", 1, "partner.full") else {
+            panic!("expected an UnknownPreset error");
+        };
+        assert_eq!(
+            err,
+            UnknownPreset {
+                name: "partner.full".into(),
+                registered: svec!["order.summary", "partner.basic"],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Unknown field preset `partner.full` (registered presets: \
+             [\"order.summary\", \"partner.basic\"])"
+        );
+
+        // `?` converts it into the client errors
+        let err = ClosureError::from(err);
+        assert!(matches!(err, ClosureError::UnknownPreset(_)));
+        assert!(matches!(Error::from(err), Error::UnknownPreset(_)));
+    }
+
+    #[test]
+    fn kwargs() -> Result<(), Box<dyn std::error::Error>> {
+        let (client, captured) = capture_client();
+        client.register_preset("partner.basic", svec!["id", "name", "email", "phone"]);
+
+        client
+            .read_preset("res.partner", vec![1, 2], "partner.basic")?
+            .send()?;
+        client
+            .search_read_preset(
+                "res.partner",
+                jvec![["is_company", "=", true]],
+                "partner.basic",
+            )?
+            .limit(5)
+            .send()?;
+
+        let captured = captured.borrow();
+        let (read, _headers) = &captured[0];
+        assert_eq!(read["params"]["args"][4], json!("read"));
+        assert_eq!(read["params"]["args"][5], json!([[1, 2]]));
+        assert_eq!(
+            read["params"]["args"][6],
+            json!({"fields": ["id", "name", "email", "phone"]})
+        );
+
+        let (search_read, _headers) = &captured[1];
+        assert_eq!(search_read["params"]["args"][4], json!("search_read"));
+        assert_eq!(
+            search_read["params"]["args"][6],
+            json!({
+                "domain": [["is_company", "=", true]],
+                "fields": ["id", "name", "email", "phone"],
+                "limit": 5
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! Upload progress for the `reqwest` impls
+//!
+//! See [`OdooRequest::on_upload_progress`](super::OdooRequest::on_upload_progress).
+//! The request body is already serialized, so it's handed to the HTTP library
+//! in chunks, and the hook is called as each chunk is taken. Download progress
+//! is reported by [`LimitedBody`](super::body::LimitedBody) instead.
+
+use super::odoo_request::ProgressHook;
+
+/// The size of each uploaded chunk
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The request body, split into chunks which report their progress
+struct Upload<B> {
+    body: B,
+    sent: usize,
+    hook: ProgressHook,
+}
+
+impl<B: AsRef<[u8]>> Upload<B> {
+    fn new(body: B, hook: ProgressHook) -> Self {
+        Self {
+            body,
+            sent: 0,
+            hook,
+        }
+    }
+
+    /// Take the next chunk (of at most `max` bytes), as a range into the body
+    fn next_chunk(&mut self, max: usize) -> Option<std::ops::Range<usize>> {
+        let total = self.body.as_ref().len();
+        if self.sent >= total {
+            return None;
+        }
+        let start = self.sent;
+        self.sent = total.min(start + max.min(CHUNK_SIZE));
+        (self.hook)(self.sent as u64, Some(total as u64));
+        Some(start..self.sent)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl std::io::Read for Upload<Vec<u8>> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.next_chunk(buf.len()) {
+            Some(range) => {
+                let len = range.len();
+                buf[..len].copy_from_slice(&self.body[range]);
+                Ok(len)
+            }
+            None => Ok(0),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl futures_core::Stream for Upload<hyper::body::Bytes> {
+    type Item = Result<hyper::body::Bytes, std::convert::Infallible>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let chunk = self
+            .next_chunk(CHUNK_SIZE)
+            .map(|range| Ok(self.body.slice(range)));
+        std::task::Poll::Ready(chunk)
+    }
+}
+
+/// Replace the `request` body with one that reports its upload progress
+///
+/// Streaming bodies (and requests without a body) are left as-is.
+#[cfg(feature = "blocking")]
+pub(crate) fn track_blocking(request: &mut reqwest::blocking::Request, hook: ProgressHook) {
+    let body = match request.body().and_then(|body| body.as_bytes()) {
+        Some(body) => body.to_vec(),
+        None => return,
+    };
+    let len = body.len() as u64;
+    *request.body_mut() = Some(reqwest::blocking::Body::sized(Upload::new(body, hook), len));
+}
+
+/// Replace the `request` body with one that reports its upload progress
+///
+/// Streaming bodies (and requests without a body) are left as-is. The body is
+/// sent with a `Content-Length`, rather than chunked.
+#[cfg(feature = "async")]
+pub(crate) fn track_async(request: &mut reqwest::Request, hook: ProgressHook) {
+    let body = match request.body().and_then(|body| body.as_bytes()) {
+        Some(body) => hyper::body::Bytes::copy_from_slice(body),
+        None => return,
+    };
+    request
+        .headers_mut()
+        .insert(reqwest::header::CONTENT_LENGTH, body.len().into());
+    let stream = hyper::Body::wrap_stream(Upload::new(body, hook));
+    *request.body_mut() = Some(stream.into());
+}
@@ -0,0 +1,89 @@
+//! The client-wide request rate limiter
+//!
+//! This is a token bucket (implemented as a [GCRA](https://en.wikipedia.org/wiki/Generic_cell_rate_algorithm)),
+//! shared between clones of the [`OdooClient`](super::OdooClient). Each HTTP
+//! request reserves a slot before it's sent, then waits until that slot is due,
+//! so concurrent callers are queued fairly without any busy-looping.
+
+use super::timer;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A token bucket allowing bursts of `requests`, refilled at `requests` per `per`
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// The time between tokens (`per / requests`)
+    interval: Duration,
+
+    /// How far ahead of the theoretical arrival time a request may be sent
+    /// (i.e., the burst size, as a duration)
+    tolerance: Duration,
+
+    /// The theoretical arrival time of the next request
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(requests: u32, per: Duration) -> Self {
+        let requests = requests.max(1);
+        let interval = per / requests;
+        Self {
+            interval,
+            tolerance: per.saturating_sub(interval),
+            next: Mutex::new(None),
+        }
+    }
+
+    /// Reserve the next token, returning how long to wait (from `now`) before
+    /// sending the request
+    pub(crate) fn reserve(&self, now: Instant) -> Duration {
+        let mut next = self.next.lock().unwrap_or_else(|err| err.into_inner());
+        let arrival = match *next {
+            Some(next) if next > now => next,
+            _ => now,
+        };
+        *next = Some(arrival + self.interval);
+        (arrival - now).saturating_sub(self.tolerance)
+    }
+
+    /// Reserve a token, and block the current thread until it's due
+    pub(crate) fn wait_blocking(&self) {
+        let wait = self.reserve(Instant::now());
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+
+    /// Reserve a token, and wait until it's due
+    ///
+    /// See the [`timer`](super::timer) module for more info.
+    pub(crate) async fn wait(&self) {
+        let wait = self.reserve(timer::now());
+        timer::sleep(wait).await;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reserve() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(1));
+        let now = Instant::now();
+
+        // the initial burst is allowed immediately...
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+
+        // ...then requests are spaced by `per / requests`
+        assert_eq!(limiter.reserve(now), Duration::from_millis(500));
+        assert_eq!(limiter.reserve(now), Duration::from_millis(1000));
+
+        // and the bucket refills over time
+        let later = now + Duration::from_secs(10);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+        assert_eq!(limiter.reserve(later), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,92 @@
+//! Shared logic for the `report_pdf()` client methods
+//!
+//! The [`report`](crate::service::report) service was removed in Odoo 11.0;
+//! instead, the webclient downloads reports from `/report/pdf/<report_name>/<ids>`. The
+//! route authenticates with the `session_id` cookie, and responds with an HTML
+//! page if the report can't be rendered (e.g. an access error, or a redirect to
+//! the login page when there's no valid session).
+//!
+//! Only the `reqwest` impls fetch the report themselves; with the other
+//! [`RequestImpl`](super::RequestImpl)s, build the URL with
+//! [`report_pdf_url()`](OdooClient::report_pdf_url) and fetch it with your own
+//! HTTP client (sending the `session_id` cookie).
+
+use super::{Authed, OdooClient, RequestImpl};
+use crate::jsonrpc::OdooId;
+
+/// The maximum length of the body snippet in a `ReportNotPdf` error
+#[cfg(any(feature = "async", feature = "blocking"))]
+const SNIPPET_LENGTH: usize = 200;
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// The URL which serves `report_name` (e.g. `account.report_invoice`) as a
+    /// PDF, rendered for the records `res_ids`
+    ///
+    /// Fetch it with a `GET` request, sending the `session_id` cookie (see
+    /// [`session_id()`](Self::session_id)).
+    pub fn report_pdf_url(&self, report_name: &str, res_ids: &[OdooId]) -> String {
+        self.build_endpoint(&pdf_endpoint(report_name, res_ids))
+    }
+}
+
+/// The web endpoint which serves `report_name` for `res_ids`
+pub(crate) fn pdf_endpoint(report_name: &str, res_ids: &[OdooId]) -> String {
+    let ids: Vec<String> = res_ids.iter().map(OdooId::to_string).collect();
+    format!("/report/pdf/{}/{}", report_name, ids.join(","))
+}
+
+/// Check that a report response is a PDF
+///
+/// Returns a snippet of the body if it isn't (whitespace is collapsed, so an
+/// HTML error page fits on one line).
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn not_pdf_snippet(content_type: &str, body: &[u8]) -> Option<String> {
+    if content_type.starts_with("application/pdf") {
+        return None;
+    }
+    let body = String::from_utf8_lossy(body);
+    let snippet: String = body
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(SNIPPET_LENGTH)
+        .collect();
+    Some(snippet)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn url() {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        assert_eq!(
+            client.report_pdf_url("account.report_invoice", &[7, 8]),
+            "http://localhost:8069/report/pdf/account.report_invoice/7,8"
+        );
+        assert_eq!(
+            pdf_endpoint("sale.report_saleorder", &[42]),
+            "/report/pdf/sale.report_saleorder/42"
+        );
+    }
+
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    #[test]
+    fn snippet() {
+        assert_eq!(not_pdf_snippet("application/pdf", b"%PDF-1.7"), None);
+
+        let html = b"<html>\n  <body>\n    <p>Access Denied</p>\n  </body>\n</html>";
+        assert_eq!(
+            not_pdf_snippet("text/html; charset=utf-8", html).as_deref(),
+            Some("<html> <body> <p>Access Denied</p> </body> </html>")
+        );
+
+        let long = "x".repeat(500);
+        assert_eq!(
+            not_pdf_snippet("text/html", long.as_bytes()).map(|s| s.len()),
+            Some(SNIPPET_LENGTH)
+        );
+    }
+}
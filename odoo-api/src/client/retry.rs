@@ -0,0 +1,203 @@
+//! Retrying requests which didn't reach Odoo (or which Odoo rolled back)
+//!
+//! See [`OdooClient::with_retry`](super::OdooClient::with_retry). Only failures
+//! where the request can't have been processed are retried: connection errors,
+//! and `502 Bad Gateway` or `503 Service Unavailable` responses from a reverse
+//! proxy (e.g. while Odoo restarts). Timeouts aren't retried, as the server may
+//! have completed a write anyways.
+//!
+//! Serialization failures (`could not serialize access due to concurrent
+//! update`) are retried too, as Odoo has rolled back the transaction. The
+//! server retries these itself a few times, then advises the client to retry
+//! the whole call. These are retried with a jittered backoff, so that the
+//! conflicting clients don't collide again.
+//!
+//! Empty responses (a `200 OK` with no body) are retried too. These come from
+//! a reverse proxy dropping the response, e.g. when an Odoo worker is recycled
+//! mid-request. Like a timeout, the server may have completed the request, so
+//! disable this with [`RetryPolicy::retry_empty_responses`] if that matters.
+
+use super::options::duration_secs;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How many times to retry a failed request, and how long to wait in between
+///
+/// The first retry waits for `backoff`, and each retry after that waits twice
+/// as long as the previous one. Serialization failures wait between half and
+/// one-and-a-half times as long (chosen at random).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// The number of retries (after the first attempt)
+    pub max_retries: u32,
+
+    /// The delay before the first retry (in seconds, when serialized)
+    #[serde(with = "duration_secs")]
+    pub backoff: Duration,
+
+    /// Retry Odoo's serialization failures (on by default)
+    #[serde(default = "default_true")]
+    pub serialization_failures: bool,
+
+    /// Retry empty responses (on by default)
+    #[serde(default = "default_true")]
+    pub empty_responses: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            backoff,
+            serialization_failures: true,
+            empty_responses: true,
+        }
+    }
+
+    /// Set whether Odoo's serialization failures are retried
+    ///
+    /// See the [`retry`](self) module for more info.
+    pub fn retry_serialization_failures(mut self, enabled: bool) -> Self {
+        self.serialization_failures = enabled;
+        self
+    }
+
+    /// Set whether empty responses are retried
+    ///
+    /// See the [`retry`](self) module for more info.
+    pub fn retry_empty_responses(mut self, enabled: bool) -> Self {
+        self.empty_responses = enabled;
+        self
+    }
+
+    /// The delay before retry number `attempt` (from `0`), or `None` once the
+    /// retries are used up
+    #[cfg_attr(
+        not(any(feature = "async", feature = "blocking", feature = "ureq")),
+        allow(dead_code)
+    )]
+    pub(crate) fn delay(&self, attempt: u32) -> Option<Duration> {
+        (attempt < self.max_retries)
+            .then(|| self.backoff.saturating_mul(2u32.saturating_pow(attempt)))
+    }
+
+    /// The (jittered) delay before retrying a serialization failure, or `None`
+    /// if these aren't retried (or the retries are used up)
+    #[cfg_attr(
+        not(any(feature = "async", feature = "blocking", feature = "ureq")),
+        allow(dead_code)
+    )]
+    pub(crate) fn serialization_failure_delay(&self, attempt: u32) -> Option<Duration> {
+        match self.serialization_failures {
+            true => Some(jitter(self.delay(attempt)?, rand::random())),
+            false => None,
+        }
+    }
+
+    /// The delay before retrying an empty response, or `None` if these aren't
+    /// retried (or the retries are used up)
+    #[cfg_attr(
+        not(any(feature = "async", feature = "blocking", feature = "ureq")),
+        allow(dead_code)
+    )]
+    pub(crate) fn empty_response_delay(&self, attempt: u32) -> Option<Duration> {
+        self.empty_responses.then(|| self.delay(attempt)).flatten()
+    }
+}
+
+/// Scale `delay` by `0.5 + random` (for a `random` value from `0.0` to `1.0`)
+fn jitter(delay: Duration, random: f64) -> Duration {
+    delay.mul_f64(0.5 + random)
+}
+
+/// How a request was sent, returned by `send_with_meta()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// The number of attempts (the first attempt, plus any retries)
+    pub attempts: u8,
+
+    /// The time from the first attempt until the final response, including
+    /// the retry delays
+    pub total_elapsed: Duration,
+}
+
+impl ResponseMeta {
+    #[cfg_attr(
+        not(any(feature = "async", feature = "blocking", feature = "ureq")),
+        allow(dead_code)
+    )]
+    pub(crate) fn new(retries: u32, total_elapsed: Duration) -> Self {
+        Self {
+            attempts: retries.saturating_add(1).try_into().unwrap_or(u8::MAX),
+            total_elapsed,
+        }
+    }
+}
+
+/// Whether the HTTP `status` means the request wasn't processed
+#[cfg_attr(
+    not(any(feature = "async", feature = "blocking", feature = "ureq")),
+    allow(dead_code)
+)]
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 502 | 503)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn delays() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(100));
+        assert_eq!(policy.delay(0), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(1), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(2), Some(Duration::from_millis(400)));
+        assert_eq!(policy.delay(3), None);
+
+        assert_eq!(RetryPolicy::new(0, Duration::ZERO).delay(0), None);
+        assert!(is_retryable_status(503));
+        assert!(!is_retryable_status(500));
+    }
+
+    #[test]
+    fn serialization_failure_delays() {
+        let delay = Duration::from_millis(200);
+        assert_eq!(jitter(delay, 0.0), Duration::from_millis(100));
+        assert_eq!(jitter(delay, 0.5), Duration::from_millis(200));
+
+        let policy = RetryPolicy::new(2, Duration::from_millis(100));
+        for attempt in 0..2 {
+            let delay = policy.serialization_failure_delay(attempt).unwrap();
+            let base = policy.delay(attempt).unwrap();
+            assert!(delay >= base / 2 && delay <= base * 3 / 2);
+        }
+        assert_eq!(policy.serialization_failure_delay(2), None);
+
+        let policy = policy.retry_serialization_failures(false);
+        assert_eq!(policy.serialization_failure_delay(0), None);
+    }
+
+    #[test]
+    fn empty_response_delays() {
+        let policy = RetryPolicy::new(2, Duration::from_millis(100));
+        assert_eq!(
+            policy.empty_response_delay(1),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(policy.empty_response_delay(2), None);
+
+        let policy = policy.retry_empty_responses(false);
+        assert_eq!(policy.empty_response_delay(0), None);
+    }
+
+    #[test]
+    fn response_meta() {
+        assert_eq!(ResponseMeta::new(2, Duration::ZERO).attempts, 3);
+        assert_eq!(ResponseMeta::new(1000, Duration::ZERO).attempts, u8::MAX);
+    }
+}
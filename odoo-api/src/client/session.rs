@@ -0,0 +1,252 @@
+//! The [`SavedSession`] type, for reusing an authenticated session
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `verify_session()`
+//! itself (as `send()` is either blocking or async).
+
+use super::odoo_client::Authed;
+use super::{OdooClient, RequestImpl};
+use crate::jsonrpc::response::JsonRpcError;
+use crate::jsonrpc::OdooId;
+#[cfg(any(feature = "blocking", feature = "async"))]
+use reqwest::cookie::{CookieStore, Jar};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+use serde_json::Value;
+use std::fmt;
+
+/// Whether the password is included in a [`SavedSession`]
+///
+/// The `/jsonrpc` methods (e.g., ORM methods and `execute_kw`) send the
+/// password with every request, so a session restored without one can only
+/// be used for the "Web" methods (which rely on the `session_id`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionPassword {
+    /// Don't save the password
+    Omit,
+
+    /// Save the password **in plain text**
+    Include,
+}
+
+/// An authenticated session, which can be persisted and restored later
+///
+/// This is created with [`OdooClient::to_saved`], and restored with
+/// [`OdooClient::restore_session`] (which doesn't make any requests).
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// use odoo_api::OdooClient;
+/// use odoo_api::client::{SavedSession, SessionPassword};
+///
+/// let client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?
+///     .authenticate("test-database", "admin", "password")?;
+///
+/// // save the session...
+/// let saved = client.to_saved(SessionPassword::Include);
+/// std::fs::write("session.json", serde_json::to_string(&saved)?)?;
+///
+/// // ...then restore it later, without logging in again
+/// let saved: SavedSession = serde_json::from_str(&std::fs::read_to_string("session.json")?)?;
+/// let client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?
+///     .restore_session(saved);
+///
+/// if !client.verify_session()? {
+///     // the session has expired (or the password changed)
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSession {
+    /// The database name
+    pub database: String,
+
+    /// The user login
+    pub login: String,
+
+    /// The user id
+    pub uid: OdooId,
+
+    /// The `session_id` cookie, if one was returned
+    pub session_id: Option<String>,
+
+    /// The user password, if [`SessionPassword::Include`] was used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+// don't leak the password (or session) into logs
+impl fmt::Debug for SavedSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redact = |value: &Option<String>| value.as_ref().map(|_| "***");
+        f.debug_struct("SavedSession")
+            .field("database", &self.database)
+            .field("login", &self.login)
+            .field("uid", &self.uid)
+            .field("session_id", &redact(&self.session_id))
+            .field("password", &redact(&self.password))
+            .finish()
+    }
+}
+
+impl Authed {
+    /// Export this auth state as a [`SavedSession`]
+    pub fn to_saved(&self, password: SessionPassword) -> SavedSession {
+        SavedSession {
+            database: self.database.clone(),
+            login: self.login.clone(),
+            uid: self.uid,
+            session_id: self.session_id.clone(),
+            password: match password {
+                SessionPassword::Include => Some(self.password.clone()),
+                SessionPassword::Omit => None,
+            },
+        }
+    }
+}
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// Export the authenticated session, so it can be restored later
+    ///
+    /// See [`SavedSession`] for more info.
+    pub fn to_saved(&self, password: SessionPassword) -> SavedSession {
+        self.auth.to_saved(password)
+    }
+}
+
+impl<I: RequestImpl> OdooClient<super::NotAuthed, I> {
+    /// Restore a [`SavedSession`], without making any requests
+    ///
+    /// The `session_id` is sent with each subsequent request (the `reqwest`
+    /// impls add it to their cookie store). Use `verify_session()` to check
+    /// that the session is still valid.
+    pub fn restore_session(self, saved: SavedSession) -> OdooClient<Authed, I> {
+        self.authenticate_manual(
+            &saved.database,
+            &saved.login,
+            saved.uid,
+            &saved.password.unwrap_or_default(),
+            saved.session_id,
+        )
+    }
+}
+
+/// Check whether `error` means the session (or password) is no longer valid
+pub(crate) fn is_invalid_session(error: &JsonRpcError) -> bool {
+    let name = &error.data.name;
+    name.ends_with("AccessDenied") || name.ends_with("SessionExpiredException")
+}
+
+/// Check whether the [`SessionInfo`](crate::service::web::SessionInfo) `data`
+/// is still logged in as `uid`
+///
+/// An expired (or anonymous) session has a `null` uid.
+#[cfg(any(feature = "blocking", feature = "async", feature = "ureq"))]
+pub(crate) fn is_session_user(data: &Value, uid: OdooId) -> bool {
+    data.get("uid").and_then(Value::as_i64) == Some(uid.into())
+}
+
+/// Read the `session_id` cookie for `url` from a `reqwest` cookie `jar`
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn jar_session_id(jar: &Jar, url: &str) -> Option<String> {
+    let cookies = jar.cookies(&Url::parse(url).ok()?)?;
+    cookies.to_str().ok()?.split(';').find_map(|cookie| {
+        let (name, value) = cookie.split_once('=')?;
+        (name.trim() == "session_id").then(|| value.trim().to_string())
+    })
+}
+
+/// Store `session_id` in the `reqwest` cookie `jar` for `url`
+///
+/// This is how a restored session is sent, as the jar is otherwise only filled
+/// by the server's `Set-Cookie` headers. If the jar already holds the same
+/// session, it's left as-is.
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn jar_restore_session_id(jar: &Jar, url: &str, session_id: &str) {
+    if jar_session_id(jar, url).as_deref() == Some(session_id) {
+        return;
+    }
+    if let Ok(url) = Url::parse(url) {
+        jar.add_cookie_str(&format!("session_id={}; Path=/", session_id), &url);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::error::Result;
+    use crate::client::odoo_request::test::capture_client;
+    use crate::client::OdooClient;
+    use serde_json::{from_value, json, to_value};
+
+    #[test]
+    fn saved_session_round_trip() -> Result<()> {
+        let (client, _captured) = capture_client();
+
+        let saved = client.to_saved(SessionPassword::Include);
+        let value = to_value(&saved)?;
+        assert_eq!(
+            value,
+            json!({
+                "database": "some-database",
+                "login": "admin",
+                "uid": 2,
+                "session_id": null,
+                "password": "password"
+            })
+        );
+        assert_eq!(from_value::<SavedSession>(value)?, saved);
+
+        let client = OdooClient::new_closure_blocking("http://localhost:8069", |_, _, _| {
+            unreachable!("restoring a session doesn't send any requests")
+        })
+        .restore_session(saved.clone());
+        assert_eq!(client.to_saved(SessionPassword::Include), saved);
+
+        Ok(())
+    }
+
+    #[test]
+    fn saved_session_debug_redacted() {
+        let (client, _captured) = capture_client();
+        let mut saved = client.to_saved(SessionPassword::Include);
+        saved.session_id = Some("abc123".into());
+
+        let debug = format!("{:?}", saved);
+        assert!(debug.contains("password: Some(\"***\")"), "{}", debug);
+        assert!(!debug.contains("abc123"), "{}", debug);
+        assert!(!debug.contains("\"password\""), "{}", debug);
+    }
+
+    #[test]
+    fn saved_session_omit_password() -> Result<()> {
+        let (client, _captured) = capture_client();
+        let mut saved = client.to_saved(SessionPassword::Include);
+        saved.session_id = Some("abc123".into());
+        let client =
+            OdooClient::new_closure_blocking("http://localhost:8069", |_, _, _| unreachable!())
+                .restore_session(saved);
+
+        let saved = client.to_saved(SessionPassword::Omit);
+        let value = to_value(&saved)?;
+        assert_eq!(
+            value,
+            json!({
+                "database": "some-database",
+                "login": "admin",
+                "uid": 2,
+                "session_id": "abc123"
+            })
+        );
+
+        let restored: SavedSession = from_value(value)?;
+        assert_eq!(restored.password, None);
+        assert_eq!(restored.session_id.as_deref(), Some("abc123"));
+
+        Ok(())
+    }
+}
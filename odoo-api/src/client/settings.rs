@@ -0,0 +1,68 @@
+//! Shared logic for the `apply_settings()` and `read_settings()` client methods
+//!
+//! Odoo's configuration screens are backed by the transient `res.config.settings`
+//! model: the values are applied by creating a settings record, then calling
+//! its `execute` method (as the webclient's "Save" button does). Each
+//! [`RequestImpl`](super::RequestImpl) implements the client methods itself (as
+//! `send()` is either blocking or async), and builds the requests here.
+
+use super::{Authed, OdooClient, OdooRequest, RequestImpl};
+use crate::jsonrpc::OdooId;
+use crate::service::object::{ExecuteKw, ExecuteKwResponse};
+use serde_json::{Map, Value};
+
+/// The settings model
+pub(crate) const SETTINGS_MODEL: &str = "res.config.settings";
+
+/// The settings method which applies the values
+pub(crate) const EXECUTE: &str = "execute";
+
+impl<I: RequestImpl> OdooClient<Authed, I> {
+    /// Build the `execute` request for the settings record `id`
+    pub(crate) fn settings_execute_request(&self, id: OdooId) -> OdooRequest<'_, ExecuteKw, I> {
+        self.execute_kw(SETTINGS_MODEL, EXECUTE, vec![vec![id].into()], Map::new())
+    }
+
+    /// Build the `default_get` request for the settings `fields`
+    ///
+    /// `res.config.settings` computes its defaults from the current config
+    /// parameters, groups and installed modules, so these are the current values.
+    pub(crate) fn settings_read_request(
+        &self,
+        fields: Vec<String>,
+    ) -> OdooRequest<'_, ExecuteKw, I> {
+        self.execute_kw(
+            SETTINGS_MODEL,
+            "default_get",
+            vec![fields.into()],
+            Map::new(),
+        )
+    }
+}
+
+/// Parse the `default_get` response
+///
+/// Unknown fields are left out by Odoo, so they're missing from the result.
+pub(crate) fn settings_values(response: ExecuteKwResponse) -> Map<String, Value> {
+    match response.data {
+        Value::Object(values) => values,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn values() {
+        let values = settings_values(ExecuteKwResponse {
+            data: json!({"group_use_lead": true, "module_crm": false}),
+        });
+        assert_eq!(values["group_use_lead"], json!(true));
+        assert_eq!(values.len(), 2);
+
+        assert!(settings_values(ExecuteKwResponse { data: json!(false) }).is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+//! Paginated `search_read`, as an [`Iterator`] or [`Stream`]
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements `search_read_iter()` (for
+//! the blocking impls) or `search_read_stream()` (for the async impls) itself,
+//! and delegates the pagination here.
+//!
+//! Pages are fetched with keyset pagination: the records are ordered by `id`,
+//! and each page appends `["id", ">", last_id]` to the domain. Unlike `offset`,
+//! this stays fast on large tables, and doesn't skip or repeat records if
+//! earlier records are created or deleted in the meantime.
+
+use crate::jsonrpc::OdooId;
+use futures_core::Stream;
+use serde_json::{json, Map, Value};
+use std::collections::VecDeque;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A page of records
+pub type Batch = Vec<Map<String, Value>>;
+
+type BatchFuture<'a, E> = Pin<Box<dyn Future<Output = Result<Batch, E>> + 'a>>;
+
+/// The keyset pagination state
+struct Pager {
+    domain: Vec<Value>,
+    batch_size: u32,
+    last_id: Option<OdooId>,
+    done: bool,
+}
+
+impl Pager {
+    fn new(domain: Vec<Value>, batch_size: u32) -> Self {
+        Self {
+            domain,
+            batch_size: batch_size.max(1),
+            last_id: None,
+            done: false,
+        }
+    }
+
+    /// The domain for the next page
+    fn next_domain(&self) -> Vec<Value> {
+        let mut domain = self.domain.clone();
+        if let Some(last_id) = self.last_id {
+            // top-level terms are implicitly AND-ed, even after a prefix operator
+            domain.push(json!(["id", ">", last_id]));
+        }
+        domain
+    }
+
+    /// Record the result of a page, returning the batch (if it isn't empty)
+    fn advance<E>(&mut self, result: Result<Batch, E>) -> Option<Result<Batch, E>> {
+        let batch = match result {
+            Ok(batch) => batch,
+            Err(err) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+        };
+        self.last_id = batch
+            .last()
+            .and_then(|record| record.get("id"))
+            .and_then(Value::as_u64)
+            .map(|id| id as OdooId);
+        // a short page is the last one (as is a page without ids, which can't
+        // be continued)
+        self.done = batch.len() < self.batch_size as usize || self.last_id.is_none();
+        if batch.is_empty() {
+            None
+        } else {
+            Some(Ok(batch))
+        }
+    }
+}
+
+/// A blocking, paginated `search_read`
+///
+/// Each item is a page of up to `batch_size` records. Use
+/// [`records`](Self::records) to iterate over the individual records instead.
+/// The iterator ends after the first error.
+pub struct SearchReadIter<'a, E> {
+    fetch: Box<dyn FnMut(Vec<Value>) -> Result<Batch, E> + 'a>,
+    pager: Pager,
+}
+
+impl<'a, E> SearchReadIter<'a, E> {
+    /// Build the iterator, where `fetch` sends a `search_read` for one page
+    /// (with `limit = batch_size` and `order = "id"`)
+    pub(crate) fn new(
+        domain: Vec<Value>,
+        batch_size: u32,
+        fetch: impl FnMut(Vec<Value>) -> Result<Batch, E> + 'a,
+    ) -> Self {
+        Self {
+            fetch: Box::new(fetch),
+            pager: Pager::new(domain, batch_size),
+        }
+    }
+
+    /// Yield individual records, rather than pages
+    pub fn records(self) -> Records<Self> {
+        Records::new(self)
+    }
+}
+
+impl<E> Iterator for SearchReadIter<'_, E> {
+    type Item = Result<Batch, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pager.done {
+            return None;
+        }
+        let result = (self.fetch)(self.pager.next_domain());
+        self.pager.advance(result)
+    }
+}
+
+/// An async, paginated `search_read`
+///
+/// Each item is a page of up to `batch_size` records. Use
+/// [`records`](Self::records) to stream the individual records instead. The
+/// stream ends after the first error.
+///
+/// This implements [`Stream`], and also has an inherent [`next`](Self::next)
+/// method, so no extra crates are needed:
+/// ```no_run
+/// # #[cfg(feature = "async")]
+/// # async fn test() -> odoo_api::client::Result<()> {
+/// # use odoo_api::{OdooClient, jvec, svec};
+/// # let client = OdooClient::new_reqwest_async("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let mut stream = client.search_read_stream(
+///     "res.partner",
+///     jvec![["customer_rank", ">", 0]],
+///     svec!["name", "email"],
+///     500,
+/// );
+/// while let Some(batch) = stream.next().await? {
+///     println!("Fetched {} partners", batch.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct SearchReadStream<'a, E> {
+    fetch: Box<dyn FnMut(Vec<Value>) -> BatchFuture<'a, E> + 'a>,
+    pending: Option<BatchFuture<'a, E>>,
+    pager: Pager,
+}
+
+impl<'a, E> SearchReadStream<'a, E> {
+    /// Build the stream, where `fetch` sends a `search_read` for one page
+    /// (with `limit = batch_size` and `order = "id"`)
+    pub(crate) fn new<F>(
+        domain: Vec<Value>,
+        batch_size: u32,
+        mut fetch: impl FnMut(Vec<Value>) -> F + 'a,
+    ) -> Self
+    where
+        F: Future<Output = Result<Batch, E>> + 'a,
+    {
+        Self {
+            fetch: Box::new(move |domain| Box::pin(fetch(domain))),
+            pending: None,
+            pager: Pager::new(domain, batch_size),
+        }
+    }
+
+    /// Fetch the next page, or `None` if there are no more records
+    pub async fn next(&mut self) -> Result<Option<Batch>, E> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_next(cx))
+            .await
+            .transpose()
+    }
+
+    /// Yield individual records, rather than pages
+    pub fn records(self) -> Records<Self> {
+        Records::new(self)
+    }
+}
+
+impl<E> Stream for SearchReadStream<'_, E> {
+    type Item = Result<Batch, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let pending = match &mut this.pending {
+                Some(pending) => pending,
+                None if this.pager.done => return Poll::Ready(None),
+                None => this.pending.insert((this.fetch)(this.pager.next_domain())),
+            };
+            let result = match pending.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.pending = None;
+            match this.pager.advance(result) {
+                Some(item) => return Poll::Ready(Some(item)),
+                // an empty page; `done` is now set
+                None => continue,
+            }
+        }
+    }
+}
+
+/// Flattens a [`SearchReadIter`] or [`SearchReadStream`] into individual records
+pub struct Records<T> {
+    inner: T,
+    buffer: VecDeque<Map<String, Value>>,
+}
+
+impl<T> Records<T> {
+    fn new(inner: T) -> Self {
+        Self {
+            inner,
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+impl<E> Iterator for Records<SearchReadIter<'_, E>> {
+    type Item = Result<Map<String, Value>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.buffer.pop_front() {
+                return Some(Ok(record));
+            }
+            match self.inner.next()? {
+                Ok(batch) => self.buffer.extend(batch),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+impl<'a, E> Records<SearchReadStream<'a, E>> {
+    /// Fetch the next record, or `None` if there are no more records
+    pub async fn next(&mut self) -> Result<Option<Map<String, Value>>, E> {
+        poll_fn(|cx| Pin::new(&mut *self).poll_next(cx))
+            .await
+            .transpose()
+    }
+}
+
+impl<E> Stream for Records<SearchReadStream<'_, E>> {
+    type Item = Result<Map<String, Value>, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(record) = this.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(record)));
+            }
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(batch))) => this.buffer.extend(batch),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn page(ids: &[OdooId]) -> Batch {
+        ids.iter()
+            .map(|id| {
+                let mut record = Map::new();
+                record.insert("id".into(), json!(id));
+                record
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pager() {
+        let mut pager = Pager::new(
+            vec![json!("|"), json!(["a", "=", 1]), json!(["b", "=", 2])],
+            2,
+        );
+        assert_eq!(pager.next_domain().len(), 3);
+
+        assert!(pager.advance::<()>(Ok(page(&[3, 7]))).is_some());
+        assert!(!pager.done);
+        assert_eq!(pager.next_domain()[3], json!(["id", ">", 7]));
+
+        // a short page ends the pagination
+        assert!(pager.advance::<()>(Ok(page(&[9]))).is_some());
+        assert!(pager.done);
+
+        // as does an empty page, or an error
+        let mut pager = Pager::new(vec![], 2);
+        assert!(pager.advance::<()>(Ok(page(&[]))).is_none());
+        assert!(pager.done);
+        let mut pager = Pager::new(vec![], 2);
+        assert!(matches!(pager.advance(Err(())), Some(Err(()))));
+        assert!(pager.done);
+    }
+
+    #[test]
+    fn records() {
+        let mut pages = vec![Ok(page(&[1, 2])), Err("boom"), Ok(page(&[3]))].into_iter();
+        let iter = SearchReadIter::new(vec![], 2, move |_domain| pages.next().unwrap());
+        let records: Vec<_> = iter
+            .records()
+            .map(|record| record.map(|record| record["id"].clone()))
+            .collect();
+
+        // the iterator ends after the first error
+        assert_eq!(records, vec![Ok(json!(1)), Ok(json!(2)), Err("boom")]);
+    }
+}
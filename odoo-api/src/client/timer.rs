@@ -0,0 +1,84 @@
+//! Runtime-agnostic timer helpers for the async impls
+//!
+//! With the `async` feature, these use the `tokio` timer (which is already
+//! required by `reqwest`). Otherwise, a helper thread is used to wake the task,
+//! so the closure impls aren't tied to any particular runtime.
+
+use std::time::{Duration, Instant};
+
+/// The current time, according to the async timer
+///
+/// Under `tokio`, this follows the (possibly paused) runtime clock.
+pub(crate) fn now() -> Instant {
+    #[cfg(feature = "async")]
+    {
+        tokio::time::Instant::now().into_std()
+    }
+
+    #[cfg(not(feature = "async"))]
+    {
+        Instant::now()
+    }
+}
+
+/// Wait for `duration`, without blocking the current thread
+pub(crate) async fn sleep(duration: Duration) {
+    if duration.is_zero() {
+        return;
+    }
+
+    #[cfg(feature = "async")]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(not(feature = "async"))]
+    thread_sleep::Sleep::new(Instant::now() + duration).await;
+}
+
+/// A runtime-agnostic sleep future, for builds without `tokio`
+#[cfg(not(feature = "async"))]
+mod thread_sleep {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::time::Instant;
+
+    pub(super) struct Sleep {
+        deadline: Instant,
+        waker: Option<Arc<Mutex<Waker>>>,
+    }
+
+    impl Sleep {
+        pub(super) fn new(deadline: Instant) -> Self {
+            Self {
+                deadline,
+                waker: None,
+            }
+        }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+            match &self.waker {
+                // the task may have moved, so always wake the latest waker
+                Some(waker) => *waker.lock().unwrap() = cx.waker().clone(),
+                None => {
+                    let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                    let inner = waker.clone();
+                    let deadline = self.deadline;
+                    std::thread::spawn(move || {
+                        std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                        inner.lock().unwrap().wake_by_ref();
+                    });
+                    self.waker = Some(waker);
+                }
+            }
+            Poll::Pending
+        }
+    }
+}
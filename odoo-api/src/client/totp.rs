@@ -0,0 +1,84 @@
+//! Shared logic for the two-factor (TOTP) login
+//!
+//! With two-factor authentication enabled, `/web/session/authenticate` only
+//! opens a partial session, and responds with `{"uid": null}`. The login is
+//! completed by posting the TOTP code to the `/web/login/totp` form (which is
+//! CSRF-protected, so the form is fetched first).
+//!
+//! The form post relies on the session cookie, so `authenticate_totp()` is only
+//! implemented for the `reqwest` impls (which keep a cookie store).
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+use super::web_db::WebBody;
+use serde_json::Value;
+
+/// The TOTP login form
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) const TOTP_ENDPOINT: &str = "/web/login/totp";
+
+/// Check whether a `/web/session/authenticate` (or `/web/session/get_session_info`)
+/// response is waiting on the second factor
+pub(crate) fn is_totp_required(data: &Value) -> bool {
+    data.get("uid").is_some_and(Value::is_null)
+}
+
+/// Find the `csrf_token` input on a login form (e.g. `/web/login/totp`)
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn csrf_token(html: &str) -> Option<String> {
+    html.split("<input")
+        .skip(1)
+        .filter_map(|tag| tag.split('>').next())
+        .find(|tag| tag.contains(r#"name="csrf_token""#))
+        .and_then(|tag| tag.split(r#"value=""#).nth(1))
+        .and_then(|value| value.split('"').next())
+        .map(str::to_string)
+}
+
+/// Build the TOTP login form
+#[cfg(any(feature = "async", feature = "blocking"))]
+pub(crate) fn totp_body(csrf_token: &str, code: &str) -> WebBody {
+    WebBody::Form(vec![
+        ("csrf_token".into(), csrf_token.into()),
+        ("totp_token".into(), code.into()),
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn totp_required() {
+        // Odoo's response when the second factor is pending
+        assert!(is_totp_required(&json!({"uid": null})));
+        assert!(!is_totp_required(&json!({"uid": 2, "db": "some-database"})));
+        assert!(!is_totp_required(&json!({})));
+    }
+
+    #[test]
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    fn csrf_token_input() {
+        // from a (trimmed) Odoo 16.0 `/web/login/totp` page
+        let html = r#"
+            <form method="POST" action="" class="oe_login_form">
+                <input type="hidden" name="csrf_token" value="5d3b0e1c2f8a9e7c4b6d1f0a3e2c5b8d7f9a1c3eo1735689600"/>
+                <input type="hidden" name="redirect"/>
+                <input id="totp_token" name="totp_token" class="form-control mb-2" autocomplete="one-time-code"/>
+            </form>
+        "#;
+        assert_eq!(
+            csrf_token(html).as_deref(),
+            Some("5d3b0e1c2f8a9e7c4b6d1f0a3e2c5b8d7f9a1c3eo1735689600")
+        );
+        assert_eq!(csrf_token("<html><body>Login</body></html>"), None);
+    }
+
+    #[test]
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    fn totp_form() {
+        let (content_type, body) = totp_body("abc", "123456").encode();
+        assert_eq!(content_type, "application/x-www-form-urlencoded");
+        assert_eq!(body, b"csrf_token=abc&totp_token=123456");
+    }
+}
@@ -0,0 +1,388 @@
+//! Request instrumentation via the [`tracing`](https://docs.rs/tracing) crate
+//!
+//! Each request is wrapped in an `odoo_request` span (at `DEBUG` level), with
+//! the following fields:
+//!  - `service`, `method`: The JSON-RPC service/method (e.g., `object`, `execute_kw`)
+//!  - `model`, `orm_method`: For `execute`/`execute_kw`, the model and ORM method
+//!  - `endpoint`: The URL path (e.g., `/jsonrpc`, `/web/session/authenticate`)
+//!  - `id`: The JSON-RPC request id
+//!  - `url`: The full request URL
+//!  - `elapsed_ms`: Time taken, recorded when the response arrives
+//!  - `outcome`: `success`, `error` (a JSON-RPC error), or `failed` (e.g., a
+//!    transport or parse error)
+//!  - `error_name`: The Python exception name, for JSON-RPC errors
+//!
+//! The request and response bodies are logged at `TRACE` level. Passwords are
+//! redacted from the request body first.
+//!
+//! Everything here is a no-op unless the `tracing` feature is enabled.
+
+use crate::client::error::ParseResponseResult;
+use serde_json::Value;
+
+/// A guard for the `odoo_request` span
+///
+/// Call [`finish`](Self::finish) once the response has been parsed. If the
+/// guard is dropped before then (e.g., because the request failed to send),
+/// the span is closed with `outcome = "failed"`.
+pub(crate) struct RequestSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+
+    #[cfg(feature = "tracing")]
+    start: std::time::Instant,
+
+    #[cfg(feature = "tracing")]
+    finished: bool,
+}
+
+#[cfg(not(feature = "tracing"))]
+impl RequestSpan {
+    pub(crate) fn start(_url: &str, _body: &Value) -> Self {
+        Self {}
+    }
+
+    pub(crate) fn finish<D>(self, _response: &[u8], _result: &ParseResponseResult<D>) {}
+}
+
+#[cfg(feature = "tracing")]
+impl RequestSpan {
+    /// Open the span, and log the (redacted) request body
+    pub(crate) fn start(url: &str, body: &Value) -> Self {
+        use tracing::field::Empty;
+
+        let params = &body["params"];
+        let span = tracing::debug_span!(
+            "odoo_request",
+            service = Empty,
+            method = Empty,
+            model = Empty,
+            orm_method = Empty,
+            endpoint = crate::client::metrics::endpoint(url),
+            id = body["id"].as_u64(),
+            url,
+            elapsed_ms = Empty,
+            outcome = Empty,
+            error_name = Empty,
+        );
+        if let (Some(service), Some(method)) =
+            (params["service"].as_str(), params["method"].as_str())
+        {
+            span.record("service", service);
+            span.record("method", method);
+            if service == "object" && method.starts_with("execute") {
+                span.record("model", params["args"][3].as_str());
+                span.record("orm_method", params["args"][4].as_str());
+            }
+        }
+
+        if tracing::enabled!(tracing::Level::TRACE) {
+            let body = redact(body);
+            tracing::trace!(parent: &span, body = %body, "odoo request");
+        }
+
+        Self {
+            span,
+            start: std::time::Instant::now(),
+            finished: false,
+        }
+    }
+
+    /// Record the result, and log the response body
+    pub(crate) fn finish<D>(mut self, response: &[u8], result: &ParseResponseResult<D>) {
+        use crate::client::error::ParseResponseError;
+
+        if tracing::enabled!(tracing::Level::TRACE) {
+            let body = String::from_utf8_lossy(response);
+            tracing::trace!(parent: &self.span, body = &*body, "odoo response");
+        }
+        self.record_elapsed();
+        match result {
+            Ok(_) => {
+                self.span.record("outcome", "success");
+            }
+            Err(ParseResponseError::JsonRpcError(error)) => {
+                self.span.record("outcome", "error");
+                self.span.record("error_name", error.data.name.as_str());
+            }
+            Err(_) => {
+                self.span.record("outcome", "failed");
+            }
+        }
+        tracing::debug!(parent: &self.span, "odoo request finished");
+        self.finished = true;
+    }
+
+    fn record_elapsed(&self) {
+        let elapsed = self.start.elapsed().as_secs_f64() * 1000.0;
+        self.span.record("elapsed_ms", elapsed);
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for RequestSpan {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.record_elapsed();
+            self.span.record("outcome", "failed");
+            tracing::debug!(parent: &self.span, "odoo request failed");
+        }
+    }
+}
+
+/// The placeholder for redacted values
+#[cfg(feature = "tracing")]
+const REDACTED: &str = "********";
+
+/// Object keys which are always redacted (e.g., `/web/session/authenticate`)
+///
+/// This is the same list used to redact the request types' `Debug` output.
+#[cfg(feature = "tracing")]
+const PASSWORD_KEYS: &[&str] = &odoo_api_macros::secret_fields!();
+
+/// Return a copy of the request `body` with passwords redacted
+///
+/// Most requests send the password positionally, so the `args` positions are
+/// redacted based on the service/method:
+///  - `object`: `args[2]` is the user password
+///  - `common` (`login`/`authenticate`): `args[2]` is the user password
+///  - `db`: `args[0]` is the master password (`passwd`), except for the handful
+///    of methods which don't require one. `change_admin_password` also has the
+///    new password at `args[1]`, and `create_database` has the new admin
+///    user's password at `args[4]`
+///
+/// Any object keys listed in [`PASSWORD_KEYS`] are also redacted.
+#[cfg(feature = "tracing")]
+pub(crate) fn redact(body: &Value) -> Value {
+    let mut body = body.clone();
+    if let Some(params) = body.get_mut("params") {
+        let service = params["service"].as_str().unwrap_or_default().to_string();
+        let method = params["method"].as_str().unwrap_or_default().to_string();
+        let positions: &[usize] = match (service.as_str(), method.as_str()) {
+            ("object", _) => &[2],
+            ("common", "login" | "authenticate") => &[2],
+            ("db", "db_exist" | "list" | "list_lang" | "server_version") => &[],
+            ("db", "change_admin_password") => &[0, 1],
+            ("db", "create_database") => &[0, 4],
+            ("db", _) => &[0],
+            _ => &[],
+        };
+        if let Some(Value::Array(args)) = params.get_mut("args") {
+            for position in positions {
+                if let Some(arg) = args.get_mut(*position) {
+                    *arg = REDACTED.into();
+                }
+            }
+        }
+        redact_keys(params);
+    }
+
+    body
+}
+
+/// Recursively redact any object keys listed in [`PASSWORD_KEYS`]
+#[cfg(feature = "tracing")]
+fn redact_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if PASSWORD_KEYS.contains(&key.as_str()) {
+                    *value = REDACTED.into();
+                } else {
+                    redact_keys(value);
+                }
+            }
+        }
+        Value::Array(array) => array.iter_mut().for_each(redact_keys),
+        _ => {}
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod test {
+    use super::*;
+    use crate::client::error::Result;
+    use crate::client::odoo_request::test::{capture_client, capture_client_with};
+    use crate::fixtures;
+    use serde_json::json;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    /// A `MakeWriter` which captures the formatted output
+    #[derive(Clone, Default)]
+    struct Output(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Output {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    /// Run `f` with a `TRACE` subscriber, and return the captured output
+    fn capture<F: FnOnce() -> Result<()>>(f: F) -> Result<String> {
+        let output = Output::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, f)?;
+        Ok(output.contents())
+    }
+
+    #[test]
+    fn request_span() -> Result<()> {
+        let output = capture(|| {
+            let (client, _captured) = capture_client();
+            client.search("res.partner", jvec![]).send()?;
+            Ok(())
+        })?;
+
+        assert!(output.contains("odoo_request{"));
+        assert!(output.contains("service=\"object\""));
+        assert!(output.contains("method=\"execute_kw\""));
+        assert!(output.contains("model=\"res.partner\""));
+        assert!(output.contains("orm_method=\"search\""));
+        assert!(output.contains("endpoint=\"/jsonrpc\""));
+        assert!(output.contains("url=\"http://localhost:8069/jsonrpc\""));
+        assert!(output.contains("outcome=\"success\""));
+        assert!(output.contains("elapsed_ms="));
+
+        // the body is logged, but the password isn't
+        assert!(output.contains("odoo request"));
+        assert!(output.contains(REDACTED));
+        assert!(!output.contains("\"password\""));
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_span_error() -> Result<()> {
+        let output = capture(|| {
+            let (client, _captured) = capture_client_with(fixtures::errors::access_error());
+            assert!(client.search("res.partner", jvec![]).send().is_err());
+            Ok(())
+        })?;
+
+        assert!(output.contains("outcome=\"error\""));
+        assert!(output.contains("error_name=\"odoo.exceptions.AccessError\""));
+
+        Ok(())
+    }
+
+    #[test]
+    fn authenticate_span() -> Result<()> {
+        let output = capture(|| {
+            crate::client::OdooClient::new_closure_blocking(
+                "http://localhost:8069",
+                |_url, _data, _session_id| {
+                    Ok((
+                        json!({"jsonrpc": "2.0", "id": 1, "result": {"uid": 2}}).to_string(),
+                        Some("abc123".into()),
+                    ))
+                },
+            )
+            .authenticate("some-database", "admin", "hunter2")?;
+            Ok(())
+        })?;
+
+        assert!(output.contains("endpoint=\"/web/session/authenticate\""));
+        assert!(output.contains("outcome=\"success\""));
+        assert!(output.contains("odoo authenticated"));
+        assert!(!output.contains("hunter2"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn redact_positional() {
+        let body = json!({
+            "params": {
+                "service": "object",
+                "method": "execute_kw",
+                "args": ["db", 2, "secret", "res.partner", "search", [[]], {}]
+            }
+        });
+        assert_eq!(
+            redact(&body)["params"]["args"],
+            json!(["db", 2, REDACTED, "res.partner", "search", [[]], {}])
+        );
+
+        let body = json!({
+            "params": {
+                "service": "db",
+                "method": "change_admin_password",
+                "args": ["old-secret", "new-secret"]
+            }
+        });
+        assert_eq!(redact(&body)["params"]["args"], json!([REDACTED, REDACTED]));
+
+        let body = json!({
+            "params": {
+                "service": "db",
+                "method": "create_database",
+                "args": ["master-secret", "new-database", false, "en_US", "admin-secret", "admin", "NZ", ""]
+            }
+        });
+        assert_eq!(
+            redact(&body)["params"]["args"],
+            json!([
+                REDACTED,
+                "new-database",
+                false,
+                "en_US",
+                REDACTED,
+                "admin",
+                "NZ",
+                ""
+            ])
+        );
+
+        let body = json!({
+            "params": {
+                "service": "db",
+                "method": "db_exist",
+                "args": ["some-database"]
+            }
+        });
+        assert_eq!(redact(&body)["params"]["args"], json!(["some-database"]));
+    }
+
+    #[test]
+    fn redact_password_keys() {
+        let body = json!({
+            "params": {
+                "db": "some-database",
+                "login": "admin",
+                "password": "secret"
+            }
+        });
+        assert_eq!(
+            redact(&body)["params"],
+            json!({
+                "db": "some-database",
+                "login": "admin",
+                "password": REDACTED
+            })
+        );
+
+        // shared with the macros' redacted `Debug` list
+        for key in ["new_password", "admin_password", "user_password"] {
+            let body = json!({"params": {"kwargs": {key: "secret"}}});
+            assert_eq!(redact(&body)["params"]["kwargs"][key], REDACTED);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+//! Helpers for reading and writing translated field values
+//!
+//! Odoo changed how translations are stored in 16.0:
+//!  - Before 16.0, translations live in the `ir.translation` model
+//!  - From 16.0, translations are stored on the field itself (as `jsonb`), and
+//!    are read/written by passing `lang` in the context
+//!
+//! The helpers here pick the right calls based on [`TranslationMode`].
+
+use super::{AuthState, OdooClient, OdooRequest, RequestImpl};
+use crate::jsonrpc::{OdooId, OdooIds};
+use crate::service::object::{ExecuteKw, ExecuteKwResponse};
+use crate::OdooVersion;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// How translated values are fetched from the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranslationMode {
+    /// Odoo 16.0+: `read`/`write` the field with `lang` in the context
+    Context,
+
+    /// Odoo <16.0: `search_read` the `ir.translation` model
+    ///
+    /// Writes still use `write` with `lang` in the context; Odoo creates or
+    /// updates the matching `ir.translation` record itself.
+    IrTranslation,
+}
+
+impl TranslationMode {
+    /// Pick the translation mode for an Odoo version
+    pub fn for_version(version: &OdooVersion) -> Self {
+        if version.at_least(16, 0) {
+            Self::Context
+        } else {
+            Self::IrTranslation
+        }
+    }
+
+    /// Parse the response to a [`translation_read`](OdooClient::translation_read) request
+    ///
+    /// Returns a map of `record id -> translated value`. Records without a
+    /// translation are either missing ([`TranslationMode::IrTranslation`]), or
+    /// `None` ([`TranslationMode::Context`]).
+    pub fn parse_read(
+        &self,
+        field: &str,
+        response: ExecuteKwResponse,
+    ) -> HashMap<OdooId, Option<String>> {
+        let (id_key, value_key) = match self {
+            Self::Context => ("id", field),
+            Self::IrTranslation => ("res_id", "value"),
+        };
+
+        let records = match response.data {
+            Value::Array(records) => records,
+            _ => return HashMap::new(),
+        };
+        records
+            .iter()
+            .filter_map(|record| {
+                let id = record.get(id_key)?.as_i64()?;
+                let value = record
+                    .get(value_key)
+                    .and_then(Value::as_str)
+                    .map(String::from);
+                Some((OdooId::try_from(id).ok()?, value))
+            })
+            .collect()
+    }
+}
+
+impl From<OdooVersion> for TranslationMode {
+    fn from(value: OdooVersion) -> Self {
+        Self::for_version(&value)
+    }
+}
+
+impl<S, I> OdooClient<S, I>
+where
+    S: AuthState,
+    I: RequestImpl,
+{
+    /// The translation mode for this server
+    ///
+    /// This uses the cached server version if available, otherwise it assumes
+    /// Odoo 16.0+. Fetch the version first (e.g. with `server_version()`) when
+    /// talking to older servers.
+    pub fn translation_mode(&self) -> TranslationMode {
+        self.version()
+            .map(TranslationMode::from)
+            .unwrap_or(TranslationMode::Context)
+    }
+}
+
+impl<I> OdooClient<super::Authed, I>
+where
+    I: RequestImpl,
+{
+    /// Read the translated values of `field` in `lang`
+    ///
+    /// The [`TranslationMode`] is picked with [`translation_mode`](Self::translation_mode).
+    /// Use [`TranslationMode::parse_read`] to parse the response.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::OdooClient;
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// client.server_version()?;
+    ///
+    /// let resp = client
+    ///     .translation_read("product.template", vec![1, 2], "name", "fr_FR")
+    ///     .send()?;
+    /// let names = client.translation_mode().parse_read("name", resp);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn translation_read<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+        field: &str,
+        lang: &str,
+    ) -> OdooRequest<'_, ExecuteKw, I> {
+        self.translation_read_with(self.translation_mode(), model, ids, field, lang)
+    }
+
+    /// Same as [`translation_read`](Self::translation_read), with an explicit [`TranslationMode`]
+    pub fn translation_read_with<ID: Into<OdooIds>>(
+        &self,
+        mode: TranslationMode,
+        model: &str,
+        ids: ID,
+        field: &str,
+        lang: &str,
+    ) -> OdooRequest<'_, ExecuteKw, I> {
+        let ids = ids.into();
+        match mode {
+            TranslationMode::Context => self
+                .execute_kw(model, "read", jvec![ids, [field]], Map::new())
+                .with_context(jmap! {"lang": lang}),
+            TranslationMode::IrTranslation => self.execute_kw(
+                "ir.translation",
+                "search_read",
+                jvec![[
+                    ["name", "=", format!("{},{}", model, field)],
+                    ["res_id", "in", ids],
+                    ["lang", "=", lang]
+                ]],
+                jmap! {"fields": ["res_id", "value"]},
+            ),
+        }
+    }
+
+    /// Write the translated value of `field` in `lang`
+    ///
+    /// The value is written with `lang` in the context on all versions, so
+    /// the [`TranslationMode`] doesn't matter here.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # #[cfg(feature = "blocking")]
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use odoo_api::OdooClient;
+    /// # let client = OdooClient::new_reqwest_blocking("")?;
+    /// # let client = client.authenticate_manual("", "", 1, "", None);
+    /// client
+    ///     .translation_write("product.template", 1, "name", "fr_FR", "Chaise")
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn translation_write(
+        &self,
+        model: &str,
+        id: OdooId,
+        field: &str,
+        lang: &str,
+        value: &str,
+    ) -> OdooRequest<'_, ExecuteKw, I> {
+        let mut values = Map::new();
+        values.insert(field.into(), json!(value));
+
+        self.execute_kw(model, "write", jvec![[id], values], Map::new())
+            .with_context(jmap! {"lang": lang})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::error::Result;
+    use crate::client::odoo_request::test::{capture_client, capture_client_with};
+    use serde_json::from_value;
+
+    #[test]
+    fn mode_for_version() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mode = |v: &str| v.parse::<OdooVersion>().map(TranslationMode::from);
+        assert_eq!(mode("15.0")?, TranslationMode::IrTranslation);
+        assert_eq!(mode("saas~15.2")?, TranslationMode::IrTranslation);
+        assert_eq!(mode("16.0")?, TranslationMode::Context);
+        assert_eq!(mode("17.0+e")?, TranslationMode::Context);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mode_from_cached_version() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let (client, _) = capture_client();
+        assert_eq!(client.translation_mode(), TranslationMode::Context);
+
+        client.version.set("14.0".parse()?).unwrap();
+        assert_eq!(client.translation_mode(), TranslationMode::IrTranslation);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_context() -> Result<()> {
+        let (client, captured) = capture_client();
+        client
+            .translation_read_with(
+                TranslationMode::Context,
+                "product.template",
+                vec![1, 2],
+                "name",
+                "fr_FR",
+            )
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"],
+            json!([
+                "some-database",
+                2,
+                "password",
+                "product.template",
+                "read",
+                [[1, 2], ["name"]],
+                {"context": {"lang": "fr_FR"}}
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_ir_translation() -> Result<()> {
+        let (client, captured) = capture_client();
+        client.version.set("14.0".parse().unwrap()).unwrap();
+        client
+            .translation_read("product.template", vec![1, 2], "name", "fr_FR")
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"],
+            json!([
+                "some-database",
+                2,
+                "password",
+                "ir.translation",
+                "search_read",
+                [[
+                    ["name", "=", "product.template,name"],
+                    ["res_id", "in", [1, 2]],
+                    ["lang", "=", "fr_FR"]
+                ]],
+                {"fields": ["res_id", "value"]}
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_overrides_client_lang() -> Result<()> {
+        let (mut client, captured) = capture_client();
+        client.context.insert("lang".into(), json!("en_US"));
+        client
+            .translation_read("product.template", 1, "name", "fr_FR")
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(body["params"]["args"][6]["context"]["lang"], json!("fr_FR"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write() -> Result<()> {
+        let (client, captured) = capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": true
+        }));
+        client
+            .translation_write("product.template", 1, "name", "fr_FR", "Chaise")
+            .send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"],
+            json!([
+                "some-database",
+                2,
+                "password",
+                "product.template",
+                "write",
+                [[1], {"name": "Chaise"}],
+                {"context": {"lang": "fr_FR"}}
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_read() -> serde_json::Result<()> {
+        let response: ExecuteKwResponse = from_value(json!([
+            {"id": 1, "name": "Chaise"},
+            {"id": 2, "name": false}
+        ]))?;
+        let values = TranslationMode::Context.parse_read("name", response);
+        assert_eq!(values[&1].as_deref(), Some("Chaise"));
+        assert_eq!(values[&2], None);
+
+        let response: ExecuteKwResponse = from_value(json!([
+            {"id": 40, "res_id": 1, "value": "Chaise"},
+            {"id": 41, "res_id": 2, "value": ""}
+        ]))?;
+        let values = TranslationMode::IrTranslation.parse_read("name", response);
+        assert_eq!(values[&1].as_deref(), Some("Chaise"));
+        assert_eq!(values[&2].as_deref(), Some(""));
+        assert!(!values.contains_key(&40));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,349 @@
+//! The `/web/database/*` database manager endpoints
+//!
+//! Many hosting setups block the JSON-RPC `db` service, but keep the database
+//! manager forms available (behind the master password). These endpoints take
+//! HTML form posts rather than JSON-RPC, and the backup is returned as raw bytes.
+//!
+//! Each [`RequestImpl`](super::RequestImpl) implements the `web_db_*()` methods
+//! itself (as sending is either blocking or async). The form bodies are built,
+//! encoded, and parsed here, so the HTTP impls only need to post bytes.
+
+use super::error::{ParseResponseError, ParseResponseResult};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+pub(crate) const BACKUP_ENDPOINT: &str = "/web/database/backup";
+pub(crate) const RESTORE_ENDPOINT: &str = "/web/database/restore";
+pub(crate) const DUPLICATE_ENDPOINT: &str = "/web/database/duplicate";
+
+/// The format of a database backup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackupFormat {
+    /// A zip archive containing the SQL dump and the filestore
+    #[default]
+    Zip,
+
+    /// A `pg_dump` custom-format dump, without the filestore
+    Dump,
+}
+
+impl BackupFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::Dump => "dump",
+        }
+    }
+}
+
+/// A single field in a `multipart/form-data` body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormPart {
+    /// The field name
+    pub name: String,
+
+    /// The filename, if this field is a file upload
+    pub filename: Option<String>,
+
+    /// The field contents
+    pub data: Vec<u8>,
+}
+
+/// The body of a `/web/database/*` request
+///
+/// This is passed to the closure registered with `with_web_closure()`. Use
+/// [`encode`](Self::encode) to get the `Content-Type` and raw body, or build
+/// the form with your HTTP library of choice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebBody {
+    /// An `application/x-www-form-urlencoded` form
+    Form(Vec<(String, String)>),
+
+    /// A `multipart/form-data` form (e.g., with a file upload)
+    Multipart(Vec<FormPart>),
+}
+
+impl WebBody {
+    /// Encode the body, returning the `Content-Type` header and the raw bytes
+    pub fn encode(&self) -> (String, Vec<u8>) {
+        match self {
+            Self::Form(fields) => {
+                let body = fields
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", url_encode(name), url_encode(value)))
+                    .collect::<Vec<_>>()
+                    .join("&");
+                (
+                    "application/x-www-form-urlencoded".into(),
+                    body.into_bytes(),
+                )
+            }
+            Self::Multipart(parts) => {
+                let boundary: String = rand::thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(32)
+                    .map(char::from)
+                    .collect();
+                let mut body = Vec::new();
+                for part in parts {
+                    body.extend(format!("--{}\r\n", boundary).into_bytes());
+                    let disposition = match &part.filename {
+                        Some(filename) => format!(
+                            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+                             Content-Type: application/octet-stream\r\n",
+                            part.name, filename
+                        ),
+                        None => {
+                            format!("Content-Disposition: form-data; name=\"{}\"\r\n", part.name)
+                        }
+                    };
+                    body.extend(disposition.into_bytes());
+                    body.extend(b"\r\n");
+                    body.extend(&part.data);
+                    body.extend(b"\r\n");
+                }
+                body.extend(format!("--{}--\r\n", boundary).into_bytes());
+                (format!("multipart/form-data; boundary={}", boundary), body)
+            }
+        }
+    }
+}
+
+/// The response to a `/web/database/*` request
+///
+/// This is returned by the closure registered with `with_web_closure()`.
+/// Redirects may be followed, or returned as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebResponse {
+    /// The HTTP status code
+    pub status: u16,
+
+    /// The `Content-Type` header, if any
+    pub content_type: Option<String>,
+
+    /// The raw response body
+    pub body: Vec<u8>,
+}
+
+/// Build the `/web/database/backup` form
+pub(crate) fn backup_body(master_pwd: &str, name: &str, format: BackupFormat) -> WebBody {
+    WebBody::Form(vec![
+        ("master_pwd".into(), master_pwd.into()),
+        ("name".into(), name.into()),
+        ("backup_format".into(), format.as_str().into()),
+    ])
+}
+
+/// Build the `/web/database/restore` form
+///
+/// `copy` is a checkbox, so it's only sent if set.
+pub(crate) fn restore_body(master_pwd: &str, file: &[u8], name: &str, copy: bool) -> WebBody {
+    let field = |name: &str, value: &str| FormPart {
+        name: name.into(),
+        filename: None,
+        data: value.as_bytes().to_vec(),
+    };
+    let mut parts = vec![
+        field("master_pwd", master_pwd),
+        FormPart {
+            name: "backup_file".into(),
+            filename: Some("backup".into()),
+            data: file.to_vec(),
+        },
+        field("name", name),
+    ];
+    if copy {
+        parts.push(field("copy", "true"));
+    }
+    WebBody::Multipart(parts)
+}
+
+/// Build the `/web/database/duplicate` form
+pub(crate) fn duplicate_body(master_pwd: &str, name: &str, new_name: &str) -> WebBody {
+    WebBody::Form(vec![
+        ("master_pwd".into(), master_pwd.into()),
+        ("name".into(), name.into()),
+        ("new_name".into(), new_name.into()),
+    ])
+}
+
+/// Parse a backup response, returning the backup bytes
+///
+/// On failure, Odoo re-renders the (HTML) manager page rather than sending
+/// the backup.
+pub(crate) fn parse_backup(response: WebResponse) -> ParseResponseResult<Vec<u8>> {
+    check_status(&response)?;
+    if is_html(&response) {
+        let message =
+            alert_message(&response.body).unwrap_or_else(|| "the backup was not returned".into());
+        return Err(ParseResponseError::DatabaseManager { message });
+    }
+    Ok(response.body)
+}
+
+/// Parse a restore or duplicate response
+///
+/// On success, Odoo redirects to the manager page; on failure, it renders the
+/// page with an error alert instead.
+pub(crate) fn parse_redirect(response: WebResponse) -> ParseResponseResult<()> {
+    check_status(&response)?;
+    match alert_message(&response.body) {
+        Some(message) => Err(ParseResponseError::DatabaseManager { message }),
+        None => Ok(()),
+    }
+}
+
+/// Fail on 4xx/5xx responses (e.g., a 404 when `list_db = False`)
+fn check_status(response: &WebResponse) -> ParseResponseResult<()> {
+    if response.status >= 400 {
+        return Err(ParseResponseError::DatabaseManager {
+            message: format!("HTTP {}", response.status),
+        });
+    }
+    Ok(())
+}
+
+fn is_html(response: &WebResponse) -> bool {
+    matches!(&response.content_type, Some(content_type) if content_type.starts_with("text/html"))
+}
+
+/// Extract the text of the `alert-danger` element from the manager page
+fn alert_message(body: &[u8]) -> Option<String> {
+    let body = String::from_utf8_lossy(body);
+    let start = body.find("alert-danger")?;
+    let start = start + body[start..].find('>')? + 1;
+    let end = start + body[start..].find("</div>")?;
+
+    // strip any nested tags, and collapse the whitespace
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    (!text.is_empty()).then_some(text)
+}
+
+/// Percent-encode `value` for an `application/x-www-form-urlencoded` body
+pub(crate) fn url_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            byte => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn html(body: &str) -> WebResponse {
+        WebResponse {
+            status: 200,
+            content_type: Some("text/html; charset=utf-8".into()),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn encode_form() {
+        let (content_type, body) = backup_body("p@ss word", "acme", BackupFormat::Dump).encode();
+        assert_eq!(content_type, "application/x-www-form-urlencoded");
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "master_pwd=p%40ss+word&name=acme&backup_format=dump"
+        );
+    }
+
+    #[test]
+    fn encode_multipart() {
+        let (content_type, body) =
+            restore_body("admin", &[0, 159, 146, 150], "acme", true).encode();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let mut expected = format!(
+            "--{b}\r\nContent-Disposition: form-data; name=\"master_pwd\"\r\n\r\nadmin\r\n\
+             --{b}\r\nContent-Disposition: form-data; name=\"backup_file\"; filename=\"backup\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n",
+            b = boundary
+        )
+        .into_bytes();
+        expected.extend([0, 159, 146, 150]);
+        expected.extend(
+            format!(
+                "\r\n--{b}\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\nacme\r\n\
+                 --{b}\r\nContent-Disposition: form-data; name=\"copy\"\r\n\r\ntrue\r\n--{b}--\r\n",
+                b = boundary
+            )
+            .into_bytes(),
+        );
+        assert_eq!(body, expected);
+
+        // `copy` is a checkbox, so it's omitted when unset
+        match restore_body("admin", &[], "acme", false) {
+            WebBody::Multipart(parts) => assert!(parts.iter().all(|part| part.name != "copy")),
+            body => panic!("expected a multipart body, got {:?}", body),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let error = concat!(
+            "<html><body><div class=\"alert alert-danger\" role=\"alert\">\n",
+            "  Database backup error: Access Denied &amp; <b>logged</b>\n",
+            "</div></body></html>"
+        );
+        match parse_backup(html(error)) {
+            Err(ParseResponseError::DatabaseManager { message }) => {
+                assert_eq!(message, "Database backup error: Access Denied & logged")
+            }
+            result => panic!("expected a DatabaseManager error, got {:?}", result),
+        }
+        assert!(parse_redirect(html(error)).is_err());
+
+        // the backup itself
+        let backup = WebResponse {
+            status: 200,
+            content_type: Some("application/octet-stream; charset=binary".into()),
+            body: vec![80, 75, 3, 4],
+        };
+        assert_eq!(parse_backup(backup).unwrap(), vec![80, 75, 3, 4]);
+
+        // the redirected manager page, or the redirect itself
+        assert!(parse_redirect(html("<div class=\"o_database_list\"></div>")).is_ok());
+        let redirect = WebResponse {
+            status: 303,
+            content_type: None,
+            body: Vec::new(),
+        };
+        assert!(parse_redirect(redirect).is_ok());
+
+        // the manager is disabled
+        let not_found = WebResponse {
+            status: 404,
+            ..html("Not Found")
+        };
+        assert!(matches!(
+            parse_redirect(not_found),
+            Err(ParseResponseError::DatabaseManager { message }) if message == "HTTP 404"
+        ));
+    }
+}
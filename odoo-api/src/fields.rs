@@ -0,0 +1,493 @@
+//! Typed Odoo field values
+//!
+//! The ORM `read` methods return each field in its "JSON" form, which doesn't
+//! carry any type information. For example, a many2one is returned as
+//! `[id, display_name]`, and an x2many as a list of ids:
+//! ```json
+//! {
+//!     "name": "Admin",
+//!     "parent_id": [1, "My Company"],
+//!     "category_id": [3, 4],
+//!     "email": false
+//! }
+//! ```
+//!
+//! [`FieldValue`] sniffs these shapes, so the values can be matched without
+//! re-implementing the same checks each time.
+//!
+//! When writing x2many fields, Odoo expects a list of "command" triplets
+//! instead (e.g., `[[6, 0, [3, 4]]]`). These can be built with [`Command`] and
+//! [`Commands`].
+
+use crate::jsonrpc::OdooId;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// A typed Odoo field value
+///
+/// The shape of the JSON value is used to pick a variant:
+///  - `false` or `null` → [`FieldValue::False`] (Odoo's "empty" value)
+///  - `true` → [`FieldValue::Bool`]
+///  - `[int, string]` → [`FieldValue::Many2One`]
+///  - `[int, int, ...]` (or `[]`) → [`FieldValue::Ids`]
+///  - strings and numbers → [`FieldValue::Str`], [`FieldValue::Int`], or [`FieldValue::Float`]
+///  - anything else → [`FieldValue::Other`]
+///
+/// Note that Odoo returns `false` for both "empty" fields *and* boolean fields
+/// that are unset, so a `false` boolean is parsed as [`FieldValue::False`]. Use
+/// [`FieldValue::as_bool`] to handle both.
+///
+/// ## Example
+/// ```
+/// use odoo_api::fields::FieldValue;
+/// use serde_json::json;
+///
+/// let value: FieldValue = serde_json::from_value(json!([1, "My Company"]))?;
+/// assert_eq!(value.as_many2one(), Some((1, "My Company")));
+///
+/// let value: FieldValue = serde_json::from_value(json!(false))?;
+/// assert!(value.is_false());
+/// assert_eq!(value.as_many2one(), None);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// A many2one value, e.g. `[1, "My Company"]`
+    Many2One { id: OdooId, display_name: String },
+
+    /// A list of ids (one2many or many2many), e.g. `[3, 4]`
+    Ids(Vec<OdooId>),
+
+    /// A string value (char, text, selection, date, etc)
+    Str(String),
+
+    /// An integer value
+    Int(i64),
+
+    /// A float value (float, monetary)
+    Float(f64),
+
+    /// A `true` boolean value
+    Bool(bool),
+
+    /// Odoo's "empty" value (`false`)
+    False,
+
+    /// Any other value
+    Other(Value),
+}
+
+impl FieldValue {
+    /// Check whether this is Odoo's "empty" value (`false`)
+    pub fn is_false(&self) -> bool {
+        matches!(self, Self::False)
+    }
+
+    /// Fetch the `(id, display_name)` of a many2one value
+    pub fn as_many2one(&self) -> Option<(OdooId, &str)> {
+        match self {
+            Self::Many2One { id, display_name } => Some((*id, display_name)),
+            _ => None,
+        }
+    }
+
+    /// Fetch the id of a many2one value
+    pub fn as_id(&self) -> Option<OdooId> {
+        self.as_many2one().map(|(id, _)| id)
+    }
+
+    /// Fetch the ids of an x2many value
+    pub fn as_ids(&self) -> Option<&[OdooId]> {
+        match self {
+            Self::Ids(ids) => Some(ids),
+            _ => None,
+        }
+    }
+
+    /// Fetch a string value
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Str(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Fetch an integer value
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Fetch a float value (integers are converted)
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Fetch a boolean value ([`FieldValue::False`] is treated as `false`)
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            Self::False => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Convert a map of raw values (e.g. a `read` record) into typed values
+    pub fn from_record(record: &Map<String, Value>) -> HashMap<String, FieldValue> {
+        record
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone().into()))
+            .collect()
+    }
+}
+
+impl From<Value> for FieldValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null | Value::Bool(false) => Self::False,
+            Value::Bool(true) => Self::Bool(true),
+            Value::String(value) => Self::Str(value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => Self::Int(value),
+                None => match number.as_f64() {
+                    Some(value) => Self::Float(value),
+                    None => Self::Other(Value::Number(number)),
+                },
+            },
+            Value::Array(array) => from_array(array),
+            other => Self::Other(other),
+        }
+    }
+}
+
+// `[int, string]` is a many2one, and a list of ints is an x2many
+fn from_array(array: Vec<Value>) -> FieldValue {
+    if let [Value::Number(id), Value::String(display_name)] = array.as_slice() {
+        if let Some(id) = id.as_i64().and_then(|id| OdooId::try_from(id).ok()) {
+            return FieldValue::Many2One {
+                id,
+                display_name: display_name.clone(),
+            };
+        }
+    }
+
+    let ids: Option<Vec<OdooId>> = array
+        .iter()
+        .map(|value| value.as_i64().and_then(|id| OdooId::try_from(id).ok()))
+        .collect();
+    match ids {
+        Some(ids) => FieldValue::Ids(ids),
+        None => FieldValue::Other(Value::Array(array)),
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldValue {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Value::deserialize(de)?.into())
+    }
+}
+
+impl Serialize for FieldValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Many2One { id, display_name } => (id, display_name).serialize(serializer),
+            Self::Ids(ids) => ids.serialize(serializer),
+            Self::Str(value) => value.serialize(serializer),
+            Self::Int(value) => value.serialize(serializer),
+            Self::Float(value) => value.serialize(serializer),
+            Self::Bool(value) => value.serialize(serializer),
+            Self::False => serializer.serialize_bool(false),
+            Self::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// A write command for a one2many or many2many field
+///
+/// Each command is serialized as the `[command, id, value]` triplet expected by
+/// `create` and `write`, using the numbering of `odoo.fields.Command` (Odoo 15+,
+/// although the numbers are the same in earlier versions).
+///
+/// See: [odoo/fields.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/fields.py)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Create a new record with `vals`, and link it: `[0, 0, vals]`
+    Create(Map<String, Value>),
+
+    /// Write `vals` on a linked record: `[1, id, vals]`
+    Update(OdooId, Map<String, Value>),
+
+    /// Remove a record, and delete it from the database: `[2, id, 0]`
+    Delete(OdooId),
+
+    /// Remove a record, without deleting it: `[3, id, 0]`
+    ///
+    /// Note that on a one2many field, the inverse many2one is usually required,
+    /// so the record may be deleted anyways.
+    Unlink(OdooId),
+
+    /// Link an existing record: `[4, id, 0]`
+    Link(OdooId),
+
+    /// Remove all records, without deleting them: `[5, 0, 0]`
+    Clear,
+
+    /// Replace the linked records with `ids`: `[6, 0, ids]`
+    Set(Vec<OdooId>),
+}
+
+impl Serialize for Command {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Create(vals) => (0, 0, vals).serialize(serializer),
+            Self::Update(id, vals) => (1, id, vals).serialize(serializer),
+            Self::Delete(id) => (2, id, 0).serialize(serializer),
+            Self::Unlink(id) => (3, id, 0).serialize(serializer),
+            Self::Link(id) => (4, id, 0).serialize(serializer),
+            Self::Clear => (5, 0, 0).serialize(serializer),
+            Self::Set(ids) => (6, 0, ids).serialize(serializer),
+        }
+    }
+}
+
+/// A list of x2many [`Command`]s
+///
+/// This serializes as a list of triplets, so it can be used directly as a
+/// field value in `create` and `write` (or converted into a [`Value`]).
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// use odoo_api::fields::Commands;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// // add a new order line, update the quantity on line 7, and remove line 8
+/// client.write(
+///     "sale.order",
+///     1,
+///     jmap!{
+///         "order_line": Commands::new()
+///             .create(jmap!{"product_id": 5, "product_uom_qty": 2.0})
+///             .update(7, jmap!{"product_uom_qty": 10.0})
+///             .delete(8)
+///     }
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Commands(pub Vec<Command>);
+
+impl Commands {
+    /// Build an empty list of commands
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new record with `vals`, and link it
+    pub fn create(mut self, vals: Map<String, Value>) -> Self {
+        self.0.push(Command::Create(vals));
+        self
+    }
+
+    /// Write `vals` on the linked record `id`
+    pub fn update(mut self, id: OdooId, vals: Map<String, Value>) -> Self {
+        self.0.push(Command::Update(id, vals));
+        self
+    }
+
+    /// Remove the record `id`, and delete it from the database
+    pub fn delete(mut self, id: OdooId) -> Self {
+        self.0.push(Command::Delete(id));
+        self
+    }
+
+    /// Remove the record `id`, without deleting it
+    pub fn unlink(mut self, id: OdooId) -> Self {
+        self.0.push(Command::Unlink(id));
+        self
+    }
+
+    /// Link the existing record `id`
+    pub fn link(mut self, id: OdooId) -> Self {
+        self.0.push(Command::Link(id));
+        self
+    }
+
+    /// Remove all records, without deleting them
+    pub fn clear(mut self) -> Self {
+        self.0.push(Command::Clear);
+        self
+    }
+
+    /// Replace the linked records with `ids`
+    pub fn set(mut self, ids: Vec<OdooId>) -> Self {
+        self.0.push(Command::Set(ids));
+        self
+    }
+}
+
+impl From<Vec<Command>> for Commands {
+    fn from(value: Vec<Command>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Commands> for Value {
+    fn from(value: Commands) -> Self {
+        Value::Array(
+            value
+                .0
+                .iter()
+                .map(|command| serde_json::to_value(command).expect("commands always serialize"))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::{from_value, json, to_value};
+
+    fn parse(value: Value) -> FieldValue {
+        from_value(value).unwrap()
+    }
+
+    #[test]
+    fn many2one() {
+        assert_eq!(
+            parse(json!([1, "My Company"])),
+            FieldValue::Many2One {
+                id: 1,
+                display_name: "My Company".into()
+            }
+        );
+        assert_eq!(parse(json!([7, ""])).as_many2one(), Some((7, "")));
+        assert_eq!(parse(json!([7, "Partner"])).as_id(), Some(7));
+    }
+
+    #[test]
+    fn ids() {
+        assert_eq!(parse(json!([3, 4])), FieldValue::Ids(vec![3, 4]));
+        assert_eq!(parse(json!([3])), FieldValue::Ids(vec![3]));
+        assert_eq!(parse(json!([])), FieldValue::Ids(vec![]));
+        assert_eq!(parse(json!([-5, 4])).as_ids(), Some(&[-5, 4][..]));
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(parse(json!("Admin")), FieldValue::Str("Admin".into()));
+        assert_eq!(parse(json!(42)), FieldValue::Int(42));
+        assert_eq!(parse(json!(4.5)), FieldValue::Float(4.5));
+        assert_eq!(parse(json!(true)), FieldValue::Bool(true));
+        assert_eq!(parse(json!(42)).as_f64(), Some(42.0));
+    }
+
+    #[test]
+    fn false_values() {
+        assert_eq!(parse(json!(false)), FieldValue::False);
+        assert_eq!(parse(json!(null)), FieldValue::False);
+        assert_eq!(parse(json!(false)).as_bool(), Some(false));
+        assert_eq!(parse(json!(false)).as_str(), None);
+        assert_eq!(parse(json!(false)).as_ids(), None);
+    }
+
+    #[test]
+    fn other() {
+        // a "reference" field, or a list with non-id values
+        assert_eq!(
+            parse(json!(["res.partner", 1])),
+            FieldValue::Other(json!(["res.partner", 1]))
+        );
+        assert_eq!(
+            parse(json!([1, "a", "b"])),
+            FieldValue::Other(json!([1, "a", "b"]))
+        );
+        assert_eq!(
+            parse(json!([1, 99999999999_i64])),
+            FieldValue::Other(json!([1, 99999999999_i64]))
+        );
+        assert_eq!(
+            parse(json!({"en_US": "Name"})),
+            FieldValue::Other(json!({"en_US": "Name"}))
+        );
+    }
+
+    #[test]
+    fn roundtrip() -> serde_json::Result<()> {
+        for value in [
+            json!([1, "My Company"]),
+            json!([3, 4]),
+            json!("Admin"),
+            json!(42),
+            json!(4.5),
+            json!(true),
+            json!(false),
+            json!({"en_US": "Name"}),
+        ] {
+            assert_eq!(to_value(parse(value.clone()))?, value);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn commands() -> serde_json::Result<()> {
+        let vals = || {
+            let mut vals = Map::new();
+            vals.insert("name".into(), json!("Line"));
+            vals
+        };
+        for (command, expected) in [
+            (Command::Create(vals()), json!([0, 0, {"name": "Line"}])),
+            (Command::Update(7, vals()), json!([1, 7, {"name": "Line"}])),
+            (Command::Delete(7), json!([2, 7, 0])),
+            (Command::Unlink(7), json!([3, 7, 0])),
+            (Command::Link(7), json!([4, 7, 0])),
+            (Command::Clear, json!([5, 0, 0])),
+            (Command::Set(vec![3, 4]), json!([6, 0, [3, 4]])),
+        ] {
+            assert_eq!(to_value(command)?, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn commands_builder() -> serde_json::Result<()> {
+        let commands = Commands::new()
+            .clear()
+            .link(5)
+            .create(Map::new())
+            .unlink(6)
+            .set(vec![]);
+        let expected = json!([[5, 0, 0], [4, 5, 0], [0, 0, {}], [3, 6, 0], [6, 0, []]]);
+        assert_eq!(to_value(&commands)?, expected);
+        assert_eq!(Value::from(commands), expected);
+
+        let commands: Commands = vec![Command::Delete(1), Command::Link(2)].into();
+        assert_eq!(to_value(commands)?, json!([[2, 1, 0], [4, 2, 0]]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+//! Payloads for the [`common`](crate::service::common) methods
+
+use serde_json::{json, Value};
+
+/// A [`Login`](crate::service::common::Login) request
+pub fn login_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "common",
+            "method": "login",
+            "args": [
+                "some-database",
+                "admin",
+                "password",
+            ]
+        }
+    })
+}
+
+/// A [`LoginResponse`](crate::service::common::LoginResponse)
+pub fn login_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 2
+    })
+}
+
+/// An [`Authenticate`](crate::service::common::Authenticate) request
+pub fn authenticate_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "common",
+            "method": "authenticate",
+            "args": [
+                "some-database",
+                "admin",
+                "password",
+                {
+                    "base_location": "https://demo.odoo.com"
+                }
+            ]
+        }
+    })
+}
+
+/// An [`AuthenticateResponse`](crate::service::common::AuthenticateResponse)
+pub fn authenticate_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 2
+    })
+}
+
+/// A [`Version`](crate::service::common::Version) request
+pub fn version_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "common",
+            "method": "version",
+            "args": []
+        }
+    })
+}
+
+/// A [`VersionResponse`](crate::service::common::VersionResponse) (Odoo 14.0+e)
+pub fn version_response_v14_enterprise() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "server_version": "14.0+e",
+            "server_version_info": [
+                14,
+                0,
+                0,
+                "final",
+                0,
+                "e"
+            ],
+            "server_serie": "14.0",
+            "protocol_version": 1
+        }
+    })
+}
+
+/// A [`VersionResponse`](crate::service::common::VersionResponse) (Odoo 16.0 community)
+///
+/// The edition is an empty string, rather than `false`.
+pub fn version_response_v16() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "server_version": "16.0",
+            "server_version_info": [16, 0, 0, "final", 0, ""],
+            "server_serie": "16.0",
+            "protocol_version": 1
+        }
+    })
+}
+
+/// A [`VersionResponse`](crate::service::common::VersionResponse) (Odoo 16.0+e)
+pub fn version_response_v16_enterprise() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "server_version": "16.0+e",
+            "server_version_info": [16, 0, 0, "final", 0, "e"],
+            "server_serie": "16.0",
+            "protocol_version": 1
+        }
+    })
+}
+
+/// A [`VersionResponse`](crate::service::common::VersionResponse) (Odoo 8.0, without an edition)
+pub fn version_response_v8() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "server_version": "8.0",
+            "server_version_info": [8, 0, 0, "final", 0],
+            "server_serie": "8.0",
+            "protocol_version": 1
+        }
+    })
+}
+
+/// A [`VersionResponse`](crate::service::common::VersionResponse) (Odoo 14.0 community)
+pub fn version_response_v14() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "server_version": "14.0",
+            "server_version_info": [
+                14,
+                0,
+                0,
+                "final",
+                0,
+                false
+            ],
+            "server_serie": "14.0",
+            "protocol_version": 1
+        }
+    })
+}
+
+/// An [`About`](crate::service::common::About) request (with `extended = false`)
+pub fn about_basic_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "common",
+            "method": "about",
+            "args": [
+                false
+            ]
+        }
+    })
+}
+
+/// An [`AboutResponse`](crate::service::common::AboutResponse) (with `extended = false`)
+pub fn about_basic_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": "See http://openerp.com"
+    })
+}
+
+/// An [`About`](crate::service::common::About) request (with `extended = true`)
+pub fn about_extended_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "common",
+            "method": "about",
+            "args": [
+                true
+            ]
+        }
+    })
+}
+
+/// An [`AboutResponse`](crate::service::common::AboutResponse) (with `extended = true`)
+pub fn about_extended_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            "See http://openerp.com",
+            "14.0+e"
+        ]
+    })
+}
@@ -0,0 +1,415 @@
+//! Payloads for the [`db`](crate::service::db) methods
+
+use serde_json::{json, Value};
+
+/// A [`CreateDatabase`](crate::service::db::CreateDatabase) request
+pub fn create_database_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "create_database",
+            "args": [
+                "master-password",
+                "new-database",
+                false,
+                "en_US",
+                "password",
+                "admin",
+                null,
+                "123 123 123"
+            ]
+        }
+    })
+}
+
+/// A [`CreateDatabaseResponse`](crate::service::db::CreateDatabaseResponse)
+pub fn create_database_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`DuplicateDatabase`](crate::service::db::DuplicateDatabase) request
+pub fn duplicate_database_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "duplicate_database",
+            "args": [
+                "master-password",
+                "old-database",
+                "new-database",
+            ]
+        }
+    })
+}
+
+/// A [`DuplicateDatabaseResponse`](crate::service::db::DuplicateDatabaseResponse)
+pub fn duplicate_database_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`Drop`](crate::service::db::Drop) request
+pub fn drop_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "drop",
+            "args": [
+                "master-password",
+                "old-database",
+            ]
+        }
+    })
+}
+
+/// A [`DropResponse`](crate::service::db::DropResponse)
+pub fn drop_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`Dump`](crate::service::db::Dump) request (the `zip` format, with the filestore)
+pub fn dump_zip_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "dump",
+            "args": [
+                "master-password",
+                "old-database",
+                "zip",
+            ]
+        }
+    })
+}
+
+/// A [`Dump`](crate::service::db::Dump) request (the `dump` format, without the filestore)
+pub fn dump_dump_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "dump",
+            "args": [
+                "master-password",
+                "old-database",
+                "dump",
+            ]
+        }
+    })
+}
+
+/// A [`DumpResponse`](crate::service::db::DumpResponse)
+pub fn dump_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": "base64-data-will-be-here"
+    })
+}
+
+/// A [`Restore`](crate::service::db::Restore) request (with `copy = false`)
+pub fn restore_move_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "restore",
+            "args": [
+                "master-password",
+                "restored-database",
+                "base64-data-would-be-here",
+                false,
+            ]
+        }
+    })
+}
+
+/// A [`Restore`](crate::service::db::Restore) request (with `copy = true`)
+pub fn restore_copy_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "restore",
+            "args": [
+                "master-password",
+                "restored-database",
+                "base64-data-would-be-here",
+                true,
+            ]
+        }
+    })
+}
+
+/// A [`RestoreResponse`](crate::service::db::RestoreResponse)
+pub fn restore_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`Rename`](crate::service::db::Rename) request
+pub fn rename_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "rename",
+            "args": [
+                "master-password",
+                "old-database",
+                "new-database"
+            ]
+        }
+    })
+}
+
+/// A [`RenameResponse`](crate::service::db::RenameResponse)
+pub fn rename_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`ChangeAdminPassword`](crate::service::db::ChangeAdminPassword) request
+pub fn change_admin_password_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "change_admin_password",
+            "args": [
+                "master-password",
+                "new-master-password",
+            ]
+        }
+    })
+}
+
+/// A [`ChangeAdminPasswordResponse`](crate::service::db::ChangeAdminPasswordResponse)
+pub fn change_admin_password_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`MigrateDatabases`](crate::service::db::MigrateDatabases) request
+pub fn migrate_databases_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "migrate_databases",
+            "args": [
+                "master-password",
+                [
+                    "new-database",
+                    "new-database2",
+                ]
+            ]
+        }
+    })
+}
+
+/// A [`MigrateDatabasesResponse`](crate::service::db::MigrateDatabasesResponse)
+pub fn migrate_databases_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`DbExist`](crate::service::db::DbExist) request
+pub fn db_exist_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "db_exist",
+            "args": [
+                "new-database"
+            ]
+        }
+    })
+}
+
+/// A [`DbExistResponse`](crate::service::db::DbExistResponse)
+pub fn db_exist_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// A [`List`](crate::service::db::List) request
+pub fn list_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "list",
+            "args": [
+                false
+            ]
+        }
+    })
+}
+
+/// A [`ListResponse`](crate::service::db::ListResponse)
+pub fn list_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            "old-database",
+            "new-database",
+            "new-database2"
+        ]
+    })
+}
+
+/// A [`ListLang`](crate::service::db::ListLang) request
+pub fn list_lang_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "list_lang",
+            "args": []
+        }
+    })
+}
+
+/// A [`ListLangResponse`](crate::service::db::ListLangResponse)
+pub fn list_lang_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            [
+                "sq_AL",
+                "Albanian / Shqip"
+            ],
+            [
+                "am_ET",
+                "Amharic / አምሃርኛ"
+            ],
+            [
+                "ar_SY",
+                "Arabic (Syria) / الْعَرَبيّة"
+            ],
+            // snipped for brevity
+        ]
+    })
+}
+
+/// A [`ListCountries`](crate::service::db::ListCountries) request
+pub fn list_countries_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "list_countries",
+            "args": [
+                "master-password"
+            ]
+        }
+    })
+}
+
+/// A [`ListCountriesResponse`](crate::service::db::ListCountriesResponse)
+pub fn list_countries_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            [
+                "af",
+                "Afghanistan"
+            ],
+            [
+                "al",
+                "Albania"
+            ],
+            [
+                "dz",
+                "Algeria"
+            ],
+            // snipped for brevity
+        ]
+    })
+}
+
+/// A [`ServerVersion`](crate::service::db::ServerVersion) request
+pub fn server_version_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "db",
+            "method": "server_version",
+            "args": []
+        }
+    })
+}
+
+/// A [`ServerVersionResponse`](crate::service::db::ServerVersionResponse)
+pub fn server_version_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": "14.0+e"
+    })
+}
@@ -0,0 +1,144 @@
+//! Representative Odoo error responses
+//!
+//! These are the errors raised by the server for most JSON-RPC requests (the
+//! tracebacks are trimmed). Any method may return them, so they're kept here
+//! rather than with each service.
+
+use serde_json::{json, Value};
+
+/// An `AccessDenied` error
+///
+/// This is raised for a wrong password, and by `db.list` when the database
+/// listing is disabled (`list_db = False`).
+pub fn access_denied() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": {
+            "code": 200,
+            "message": "Odoo Server Error",
+            "data": {
+                "name": "odoo.exceptions.AccessDenied",
+                "debug": "Traceback (most recent call last):\n...",
+                "message": "Access Denied",
+                "arguments": ["Access Denied"],
+                "context": {}
+            }
+        }
+    })
+}
+
+/// An `AccessError`, when the user may not access a model (Odoo 17.0)
+pub fn access_error() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": {
+            "code": 200,
+            "message": "Odoo Server Error",
+            "data": {
+                "name": "odoo.exceptions.AccessError",
+                "debug": "Traceback (most recent call last):\n  File \"/usr/lib/python3/dist-packages/odoo/http.py\", line 1765, in _serve_db\n    return service_model.retrying(self._serve_ir_http, self.env)\nodoo.exceptions.AccessError: You are not allowed to access 'Contact' (res.partner) records.\n",
+                "message": "You are not allowed to access 'Contact' (res.partner) records.",
+                "arguments": ["You are not allowed to access 'Contact' (res.partner) records."],
+                "context": {}
+            }
+        }
+    })
+}
+
+/// A `ValidationError`, e.g. from a failed `@api.constrains` check
+pub fn validation_error() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": {
+            "code": 200,
+            "message": "Odoo Server Error",
+            "data": {
+                "name": "odoo.exceptions.ValidationError",
+                "debug": "Traceback (most recent call last):\n...",
+                "message": "Invalid value",
+                "arguments": ["Invalid value"],
+                "context": {}
+            }
+        }
+    })
+}
+
+/// A `UserError` with the given `message`, e.g. from a wizard or a button
+pub fn user_error(message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": {
+            "code": 200,
+            "message": "Odoo Server Error",
+            "data": {
+                "name": "odoo.exceptions.UserError",
+                "debug": "",
+                "message": message,
+                "arguments": [message],
+                "context": {}
+            }
+        }
+    })
+}
+
+/// A PostgreSQL serialization failure, from a concurrent update to the same rows
+pub fn serialization_failure() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": {
+            "code": 200,
+            "message": "Odoo Server Error",
+            "data": {
+                "name": "psycopg2.errors.SerializationFailure",
+                "debug": "Traceback (most recent call last):\n...",
+                "message": "could not serialize access due to concurrent update\n",
+                "arguments": ["could not serialize access due to concurrent update\n"],
+                "context": {}
+            }
+        }
+    })
+}
+
+/// An expired (or logged out) session, for requests sent with a `session_id`
+pub fn session_expired() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "error": {
+            "code": 100,
+            "message": "Odoo Session Expired",
+            "data": {
+                "name": "odoo.http.SessionExpiredException",
+                "debug": "Traceback (most recent call last):\n...",
+                "message": "Session expired",
+                "arguments": ["Session expired"],
+                "context": {}
+            }
+        }
+    })
+}
+
+/// A `404` for an unknown route
+///
+/// This is raised before the JSON-RPC request is parsed, so there's no `id`,
+/// and no `debug` or `context`.
+pub fn not_found() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": null,
+        "error": {
+            "code": 404,
+            "message": "404: Not Found",
+            "data": {
+                "name": "werkzeug.exceptions.NotFound",
+                "message": "404 Not Found: The requested URL was not found on the server. If you entered the URL manually please check your spelling and try again.",
+                "arguments": []
+            }
+        }
+    })
+}
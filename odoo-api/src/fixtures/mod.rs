@@ -0,0 +1,38 @@
+//! Canned Odoo JSON-RPC payloads, for testing code built on this crate
+//!
+//! Each module mirrors a [`service`](crate::service) module, with a
+//! `*_request()` function for the JSON sent by a method (with the JSON-RPC id
+//! `1000`), and `*_response()` functions for the JSON returned by Odoo. Unless
+//! noted, the payloads were captured from an Odoo 14.0+e instance. The
+//! [`errors`] module has a few representative error responses.
+//!
+//! These are the same payloads used by the crate's own tests, so they're kept
+//! in sync with the request and response types. Enable the `test-support`
+//! feature to use them, e.g. as the responses of a mock transport:
+//! ```
+//! # #[cfg(feature = "client")]
+//! # fn test() -> odoo_api::client::Result<()> {
+//! use odoo_api::fixtures;
+//! use odoo_api::OdooClient;
+//!
+//! let client = OdooClient::new_closure_blocking(
+//!     "http://localhost:8069",
+//!     |_url, _data, _session_id| {
+//!         Ok((fixtures::common::version_response_v16().to_string(), None))
+//!     },
+//! );
+//! let version = client.common_version().send()?;
+//! assert_eq!(version.server_serie, "16.0");
+//! # Ok(())
+//! # }
+//! # #[cfg(feature = "client")]
+//! # test().unwrap();
+//! ```
+
+pub mod common;
+pub mod db;
+pub mod errors;
+pub mod object;
+pub mod orm;
+pub mod report;
+pub mod web;
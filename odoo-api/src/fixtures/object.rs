@@ -0,0 +1,117 @@
+//! Payloads for the [`object`](crate::service::object) methods
+
+use serde_json::{json, Value};
+
+/// An [`Execute`](crate::service::object::Execute) request
+pub fn execute_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "read",
+                [1, 2],
+                ["id", "login"]
+            ]
+        }
+    })
+}
+
+/// An [`ExecuteResponse`](crate::service::object::ExecuteResponse)
+pub fn execute_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 1,
+                "login": "__system__"
+            },
+            {
+                "id": 2,
+                "login": "admin"
+            }
+        ]
+    })
+}
+
+/// An [`ExecuteKw`](crate::service::object::ExecuteKw) request
+pub fn execute_kw_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "read",
+                [
+                    [1, 2]
+                ],
+                {
+                    "fields": ["id", "login"]
+                }
+            ]
+        }
+    })
+}
+
+/// An [`ExecuteKwResponse`](crate::service::object::ExecuteKwResponse)
+pub fn execute_kw_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 1,
+                "login": "__system__"
+            },
+            {
+                "id": 2,
+                "login": "admin"
+            }
+        ]
+    })
+}
+
+/// An [`ExecWorkflow`](crate::service::object::ExecWorkflow) request
+pub fn exec_workflow_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "exec_workflow",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "account.invoice",
+                "invoice_open",
+                42
+            ]
+        }
+    })
+}
+
+/// An [`ExecWorkflowResponse`](crate::service::object::ExecWorkflowResponse)
+pub fn exec_workflow_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": false
+    })
+}
@@ -0,0 +1,1435 @@
+//! Payloads for the [`orm`](crate::service::orm) methods
+
+use serde_json::{json, Value};
+
+/// A [`Create`](crate::service::orm::Create) request (a single record)
+pub fn create_one_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "create",
+                [
+                    {"name": "Hello, world!"}
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`CreateResponse`](crate::service::orm::CreateResponse) (a single id)
+pub fn create_one_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 47
+    })
+}
+
+/// A [`Create`](crate::service::orm::Create) request (several records)
+pub fn create_multi_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "create",
+                [
+                    [
+                        {"name": "Hello, world!"},
+                        {"name": "Marco, polo!"}
+                    ]
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`CreateResponse`](crate::service::orm::CreateResponse) (a list of ids)
+pub fn create_multi_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            50,
+            51
+        ]
+    })
+}
+
+/// A [`Read`](crate::service::orm::Read) request
+pub fn read_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "read",
+                [
+                    [1, 2, 3]
+                ],
+                {
+                    "fields": ["id", "login"]
+                }
+            ]
+        }
+    })
+}
+
+/// A [`ReadResponse`](crate::service::orm::ReadResponse)
+pub fn read_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 1,
+                "name": "My Company (San Francisco)"
+            },
+            {
+                "id": 2,
+                "name": "OdooBot"
+            },
+            {
+                "id": 3,
+                "name": "Administrator"
+            }
+        ]
+    })
+}
+
+/// A [`ReadResponse`](crate::service::orm::ReadResponse) (with each field type)
+pub fn read_response_typed() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 3,
+                "name": "Administrator",
+                "parent_id": [1, "My Company (San Francisco)"],
+                "category_id": [4, 5],
+                "child_ids": [],
+                "email": false,
+                "active": true,
+                "credit_limit": 1500.5,
+                "color": 0
+            },
+            {
+                "id": 2,
+                "name": "OdooBot",
+                "parent_id": false,
+                "category_id": [],
+                "child_ids": [],
+                "email": "odoobot@example.com",
+                "active": false,
+                "credit_limit": 0.0,
+                "color": 0
+            }
+        ]
+    })
+}
+
+/// A [`Write`](crate::service::orm::Write) request
+pub fn write_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "write",
+                [
+                    [2],
+                    {
+                        "name": "The Admin Account"
+                    }
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`WriteResponse`](crate::service::orm::WriteResponse)
+pub fn write_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// An [`Unlink`](crate::service::orm::Unlink) request
+pub fn unlink_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "unlink",
+                [
+                    [3],
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// An [`UnlinkResponse`](crate::service::orm::UnlinkResponse)
+pub fn unlink_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": true
+    })
+}
+
+/// An [`Archive`](crate::service::orm::Archive) request
+pub fn archive_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "action_archive",
+                [
+                    [3, 4],
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`ReadGroup`](crate::service::orm::ReadGroup) request
+pub fn read_group_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "read_group",
+                [
+                    [
+                        ["id", ">", 0]
+                    ],
+                    [
+                        "id",
+                        "name",
+                        "company_type"
+                    ],
+                    [
+                        "create_date:month",
+                        "company_id"
+                    ]
+                ],
+                {
+                    "offset": 0,
+                    "limit": 100,
+                    "orderby": "create_date desc",
+                    "lazy": false
+                }
+            ]
+        }
+    })
+}
+
+/// A [`ReadGroup`](crate::service::orm::ReadGroup) request (with the `None` kwargs left out)
+pub fn read_group_none_kwargs_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "read_group",
+                [
+                    [],
+                    [
+                        "id"
+                    ],
+                    [
+                        "company_id"
+                    ]
+                ],
+                {
+                    "limit": 10,
+                    "lazy": true
+                }
+            ]
+        }
+    })
+}
+
+/// A [`ReadGroupResponse`](crate::service::orm::ReadGroupResponse)
+pub fn read_group_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "__count": 5,
+                "create_date:month": "January 2023",
+                "company_id": false,
+                "__domain": [
+                    "&",
+                    "&",
+                    "&",
+                    [
+                        "create_date",
+                        ">=",
+                        "2023-01-01 00:00:00"
+                    ],
+                    [
+                        "create_date",
+                        "<",
+                        "2023-02-01 00:00:00"
+                    ],
+                    [
+                        "company_id",
+                        "=",
+                        false
+                    ],
+                    [
+                        "id",
+                        ">",
+                        0
+                    ]
+                ]
+            },
+            {
+                "__count": 1,
+                "create_date:month": "December 2022",
+                "company_id": [
+                    1,
+                    "Test!"
+                ],
+                "__domain": [
+                    "&",
+                    "&",
+                    "&",
+                    [
+                        "create_date",
+                        ">=",
+                        "2022-12-01 00:00:00"
+                    ],
+                    [
+                        "create_date",
+                        "<",
+                        "2023-01-01 00:00:00"
+                    ],
+                    [
+                        "company_id",
+                        "=",
+                        1
+                    ],
+                    [
+                        "id",
+                        ">",
+                        0
+                    ]
+                ],
+                // Odoo 16.0+
+                "__range": {
+                    "create_date:month": {
+                        "from": "2022-12-01 00:00:00",
+                        "to": "2023-01-01 00:00:00"
+                    }
+                }
+            },
+        ]
+    })
+}
+
+/// A [`SearchRead`](crate::service::orm::SearchRead) request
+pub fn search_read_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "search_read",
+                [],
+                {
+                    "domain": [
+                        ["company_type", "=", "company"]
+                    ],
+                    "fields": [
+                        "id",
+                        "name",
+                        "company_type"
+                    ],
+                    "offset": 0,
+                    "limit": 100,
+                    "order": "create_date desc"
+                }
+            ]
+        }
+    })
+}
+
+/// A [`SearchReadResponse`](crate::service::orm::SearchReadResponse)
+pub fn search_read_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 48,
+                "name": "Partner #1",
+                "company_type": "person"
+            },
+            {
+                "id": 49,
+                "name": "Partner #2",
+                "company_type": "person"
+            },
+        ]
+    })
+}
+
+/// A [`Search`](crate::service::orm::Search) request
+pub fn search_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "search",
+                [
+                    [
+                        ["company_type", "=", "company"]
+                    ]
+                ],
+                {
+                    "offset": 0,
+                    "limit": 100,
+                    "order": "create_date desc"
+                }
+            ]
+        }
+    })
+}
+
+/// A [`SearchResponse`](crate::service::orm::SearchResponse)
+pub fn search_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            48,
+            49,
+            47,
+            45,
+            44,
+        ]
+    })
+}
+
+/// A [`SearchCount`](crate::service::orm::SearchCount) request
+pub fn search_count_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "search_count",
+                [
+                    [
+                        ["company_type", "=", "company"]
+                    ]
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`SearchCountResponse`](crate::service::orm::SearchCountResponse)
+pub fn search_count_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 46
+    })
+}
+
+/// A [`Copy`](crate::service::orm::Copy) request
+pub fn copy_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "copy",
+                [
+                    2
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`CopyResponse`](crate::service::orm::CopyResponse)
+pub fn copy_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 54
+    })
+}
+
+/// A [`CopyMulti`](crate::service::orm::CopyMulti) request
+pub fn copy_multi_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "copy",
+                [
+                    [2, 3]
+                ],
+                {
+                    "default": {
+                        "active": false
+                    }
+                }
+            ]
+        }
+    })
+}
+
+/// A [`CopyMultiResponse`](crate::service::orm::CopyMultiResponse)
+///
+/// Copying multiple records returns a list
+pub fn copy_multi_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [54, 55]
+    })
+}
+
+/// A [`CopyMultiResponse`](crate::service::orm::CopyMultiResponse) (a single record)
+///
+/// But copying one record returns a plain int
+pub fn copy_multi_response_one() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 54
+    })
+}
+
+/// A [`CopyData`](crate::service::orm::CopyData) request
+pub fn copy_data_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "copy_data",
+                [
+                    [2]
+                ],
+                {
+                    "default": {
+                        "name": "Copy of Admin"
+                    }
+                }
+            ]
+        }
+    })
+}
+
+/// A [`CopyDataResponse`](crate::service::orm::CopyDataResponse)
+pub fn copy_data_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "name": "Copy of Admin",
+                "active": true,
+                "category_id": [[6, 0, [1, 2]]]
+            }
+        ]
+    })
+}
+
+/// An [`Exists`](crate::service::orm::Exists) request
+pub fn exists_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "exists",
+                [
+                    [1, 2, -1, 999999999]
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// An [`ExistsResponse`](crate::service::orm::ExistsResponse)
+pub fn exists_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            1,
+            2
+        ]
+    })
+}
+
+/// A [`CheckAccessRights`](crate::service::orm::CheckAccessRights) request
+pub fn check_access_rights_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "stock.quant",
+                "check_access_rights",
+                [
+                    "unlink"
+                ],
+                {
+                    "raise_exception": false
+                }
+            ]
+        }
+    })
+}
+
+/// A [`CheckAccessRightsResponse`](crate::service::orm::CheckAccessRightsResponse)
+pub fn check_access_rights_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": false
+    })
+}
+
+/// A [`CheckAccessRules`](crate::service::orm::CheckAccessRules) request
+pub fn check_access_rules_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "check_access_rule",
+                [
+                    [1, 2],
+                    "unlink"
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`CheckAccessRulesResponse`](crate::service::orm::CheckAccessRulesResponse)
+pub fn check_access_rules_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            "id",
+            "email",
+            "this_is_a_fake_field"
+        ]
+    })
+}
+
+/// A [`CheckFieldAccessRights`](crate::service::orm::CheckFieldAccessRights) request
+pub fn check_field_access_rights_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "check_field_access_rights",
+                [
+                    "unlink",
+                    [
+                        "id",
+                        "email",
+                        "this_is_a_fake_field"
+                    ]
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`CheckFieldAccessRightsResponse`](crate::service::orm::CheckFieldAccessRightsResponse)
+pub fn check_field_access_rights_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            "id",
+            "email",
+            "this_is_a_fake_field"
+        ]
+    })
+}
+
+/// A [`FieldsGet`](crate::service::orm::FieldsGet) request
+pub fn fields_get_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "fields_get",
+                [],
+                {
+                    "attributes": ["string", "type"]
+                }
+            ]
+        }
+    })
+}
+
+/// A [`FieldsGetResponse`](crate::service::orm::FieldsGetResponse)
+pub fn fields_get_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "email": {
+                "string": "Email",
+                "type": "char"
+            },
+            "type": {
+                "string": "Address Type",
+                "type": "selection",
+                "selection": [["contact", "Contact"], ["invoice", "Invoice Address"]]
+            }
+        }
+    })
+}
+
+/// A [`GetMetadata`](crate::service::orm::GetMetadata) request
+pub fn get_metadata_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "get_metadata",
+                [
+                    [1, 2]
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`GetMetadataResponse`](crate::service::orm::GetMetadataResponse)
+pub fn get_metadata_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 1,
+                "create_uid": false,
+                "create_date": "2022-09-15 20:00:41",
+                "write_uid": [
+                    2,
+                    "Administrator"
+                ],
+                "write_date": "2023-01-16 01:17:19",
+                "xmlid": "base.main_partner",
+                "noupdate": true
+            },
+            {
+                "id": 2,
+                "create_uid": [
+                    1,
+                    "OdooBot"
+                ],
+                "create_date": "2022-09-15 20:00:43",
+                "write_uid": [
+                    1,
+                    "OdooBot"
+                ],
+                "write_date": "2023-02-20 22:32:37",
+                "xmlid": "base.partner_root",
+                "noupdate": true
+            }
+        ]
+    })
+}
+
+/// A [`GetExternalId`](crate::service::orm::GetExternalId) request
+pub fn get_external_id_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "get_external_id",
+                [
+                    [1, 2]
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`GetExternalIdResponse`](crate::service::orm::GetExternalIdResponse)
+pub fn get_external_id_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "1": "base.main_partner",
+            "2": "base.partner_root"
+        }
+    })
+}
+
+/// A [`GetExternalIdResponse`](crate::service::orm::GetExternalIdResponse)
+///
+/// Record `7` has no external id, so it's `false`.
+pub fn get_external_id_response_false() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "1": "base.main_partner",
+            "7": false
+        }
+    })
+}
+
+/// A [`GetXmlIdResponse`](crate::service::orm::GetXmlIdResponse)
+///
+/// Records `9` and `4` have no external id, so they're empty strings.
+pub fn get_external_id_response_empty() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "1": "base.main_partner",
+            "9": "",
+            "4": ""
+        }
+    })
+}
+
+/// A [`GetXmlId`](crate::service::orm::GetXmlId) request
+pub fn get_xml_id_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "get_xml_id",
+                [
+                    [1, 2]
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`GetXmlIdResponse`](crate::service::orm::GetXmlIdResponse)
+pub fn get_xml_id_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "1": "base.main_partner",
+            "2": "base.partner_root"
+        }
+    })
+}
+
+/// A [`NameGet`](crate::service::orm::NameGet) request
+pub fn name_get_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "name_get",
+                [
+                    [1, 2, 3]
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`NameGetResponse`](crate::service::orm::NameGetResponse)
+pub fn name_get_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            [
+                1,
+                "Test!"
+            ],
+            [
+                2,
+                "OdooBot"
+            ],
+            [
+                3,
+                "YourCompany, Administrator"
+            ]
+        ]
+    })
+}
+
+/// A [`DisplayNames`](crate::service::orm::DisplayNames) request
+pub fn display_names_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "read",
+                [
+                    [1, 2, 3]
+                ],
+                {
+                    "fields": ["display_name"]
+                }
+            ]
+        }
+    })
+}
+
+/// A [`NameGetResponse`](crate::service::orm::NameGetResponse)
+pub fn display_names_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 1,
+                "display_name": "Test!"
+            },
+            {
+                "id": 3,
+                "display_name": "YourCompany, Administrator"
+            },
+            {
+                "id": 4,
+                "display_name": false
+            }
+        ]
+    })
+}
+
+/// A [`HasGroup`](crate::service::orm::HasGroup) request
+pub fn has_group_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "has_group",
+                [
+                    [2],
+                    "account.group_account_manager"
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`UserGroups`](crate::service::orm::UserGroups) request
+pub fn user_groups_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "read",
+                [
+                    [2]
+                ],
+                {
+                    "fields": ["groups_id"]
+                }
+            ]
+        }
+    })
+}
+
+/// A [`UserGroupsResponse`](crate::service::orm::UserGroupsResponse)
+pub fn user_groups_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            {
+                "id": 2,
+                "groups_id": [1, 7, 12]
+            }
+        ]
+    })
+}
+
+/// A [`UserGroupsResponse`](crate::service::orm::UserGroupsResponse) (no user)
+///
+/// The user isn't readable (e.g. archived)
+pub fn user_groups_response_empty() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": []
+    })
+}
+
+/// A [`ChangePassword`](crate::service::orm::ChangePassword) request
+pub fn change_password_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "change_password",
+                [
+                    "old-password",
+                    "new-password"
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// An [`UpdatePreferences`](crate::service::orm::UpdatePreferences) request
+pub fn update_preferences_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "write",
+                [
+                    [2],
+                    {
+                        "tz": "Europe/Brussels",
+                        "notification_type": "inbox"
+                    }
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`NameCreate`](crate::service::orm::NameCreate) request
+pub fn name_create_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "name_create",
+                [
+                    "I am a test!"
+                ],
+                {
+                }
+            ]
+        }
+    })
+}
+
+/// A [`NameCreateResponse`](crate::service::orm::NameCreateResponse)
+pub fn name_create_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            56,
+            "I am a test!"
+        ]
+    })
+}
+
+/// A [`NameSearch`](crate::service::orm::NameSearch) request
+pub fn name_search_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "name_search",
+                [
+                    "I am a test!"
+                ],
+                {}
+            ]
+        }
+    })
+}
+
+/// A [`NameSearch`](crate::service::orm::NameSearch) request (with an `operator`)
+pub fn name_search_operator_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "name_search",
+                [
+                    "Admin"
+                ],
+                {
+                    "args": [["is_company", "=", true]],
+                    "operator": "not ilike",
+                    "limit": 5
+                }
+            ]
+        }
+    })
+}
+
+/// A [`NameSearchResponse`](crate::service::orm::NameSearchResponse)
+pub fn name_search_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            [
+                56,
+                "I am a test!"
+            ],
+            [
+                57,
+                "I am a test!"
+            ]
+        ]
+    })
+}
+
+/// A [`MessagePost`](crate::service::orm::MessagePost) request
+pub fn message_post_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "message_post",
+                [
+                    [7]
+                ],
+                {
+                    "body": "<p>The order has <b>shipped</b> &amp; will arrive soon</p>",
+                    "subject": "Order S00042",
+                    "message_type": "comment",
+                    "subtype_xmlid": "mail.mt_comment",
+                    "partner_ids": [3, 14],
+                    "attachment_ids": [21]
+                }
+            ]
+        }
+    })
+}
+
+/// A [`MessagePostResponse`](crate::service::orm::MessagePostResponse)
+pub fn message_post_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": 512
+    })
+}
+
+/// An [`ActivitySchedule`](crate::service::orm::ActivitySchedule) request
+pub fn activity_schedule_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "object",
+            "method": "execute_kw",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "crm.lead",
+                "activity_schedule",
+                [
+                    [12]
+                ],
+                {
+                    "act_type_xmlid": "mail.mail_activity_data_call",
+                    "date_deadline": "2024-03-01",
+                    "summary": "Follow up",
+                    "note": "<p>Ask about the <i>renewal</i></p>",
+                    "user_id": 6
+                }
+            ]
+        }
+    })
+}
+
+/// An [`ActivityScheduleResponse`](crate::service::orm::ActivityScheduleResponse)
+///
+/// The new activities are sent as the recordset `repr`.
+pub fn activity_schedule_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": "mail.activity(31,)"
+    })
+}
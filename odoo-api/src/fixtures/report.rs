@@ -0,0 +1,37 @@
+//! Payloads for the [`report`](crate::service::report) methods
+
+use serde_json::{json, Value};
+
+/// A [`RenderReport`](crate::service::report::RenderReport) request
+pub fn render_report_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "service": "report",
+            "method": "render_report",
+            "args": [
+                "some-database",
+                2,
+                "password",
+                "sale.report_saleorder",
+                [42],
+                null
+            ]
+        }
+    })
+}
+
+/// A [`RenderReportResponse`](crate::service::report::RenderReportResponse)
+pub fn render_report_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "state": true,
+            "result": "JVBERi0xLjQK",
+            "format": "pdf"
+        }
+    })
+}
@@ -0,0 +1,161 @@
+//! Payloads for the [`web`](crate::service::web) methods
+
+use serde_json::{json, Value};
+
+/// A [`DatabaseList`](crate::service::web::DatabaseList) request
+pub fn database_list_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": []
+    })
+}
+
+/// A [`SessionAuthenticate`](crate::service::web::SessionAuthenticate) request
+pub fn session_authenticate_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "db": "some-database",
+            "login": "admin",
+            "password": "password"
+        }
+    })
+}
+
+/// A [`SessionInfo`](crate::service::web::SessionInfo) request
+pub fn session_info_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {}
+    })
+}
+
+/// A [`WebVersionInfo`](crate::service::web::WebVersionInfo) request
+pub fn version_info_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {}
+    })
+}
+
+/// A [`WebVersionInfoResponse`](crate::service::web::WebVersionInfoResponse)
+pub fn version_info_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "server_version": "16.0+e",
+            "server_version_info": [16, 0, 0, "final", 0, "e"],
+            "server_serie": "16.0",
+            "protocol_version": 1
+        }
+    })
+}
+
+/// A [`DatabaseListResponse`](crate::service::web::DatabaseListResponse)
+pub fn database_list_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": [
+            "old-database",
+            "new-database",
+            "database3"
+        ]
+    })
+}
+
+/// An [`ActionLoad`](crate::service::web::ActionLoad) request
+pub fn action_load_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "action_id": 42,
+            "additional_context": {
+                "active_id": 7
+            }
+        }
+    })
+}
+
+/// An [`ActionLoadResponse`](crate::service::web::ActionLoadResponse)
+pub fn action_load_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "id": 42,
+            "name": "Customers",
+            "type": "ir.actions.act_window",
+            "res_model": "res.partner",
+            "view_mode": "kanban,tree,form",
+            "views": [[false, "kanban"], [101, "tree"], [false, "form"]],
+            "view_id": false,
+            "domain": false,
+            "context": "{'res_partner_search_mode': 'customer', 'default_is_company': True}",
+            "target": "current",
+            "search_view_id": [120, "res.partner.select"],
+            "binding_model_id": false
+        }
+    })
+}
+
+/// An [`ActionLoadResponse`](crate::service::web::ActionLoadResponse) (a missing action)
+pub fn action_load_response_missing() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": false
+    })
+}
+
+/// A [`DatasetCallButton`](crate::service::web::DatasetCallButton) request
+pub fn dataset_call_button_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "call",
+        "id": 1000,
+        "params": {
+            "model": "account.move",
+            "method": "action_post",
+            "args": [[42]],
+            "kwargs": {
+                "context": {
+                    "lang": "en_US"
+                }
+            }
+        }
+    })
+}
+
+/// A [`DatasetCallButtonResponse`](crate::service::web::DatasetCallButtonResponse)
+/// (the button opened a wizard)
+pub fn dataset_call_button_response() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1000,
+        "result": {
+            "name": "Post entries",
+            "type": "ir.actions.act_window",
+            "res_model": "validate.account.move",
+            "view_mode": "form",
+            "views": [[false, "form"]],
+            "target": "new",
+            "context": {
+                "lang": "en_US",
+                "active_model": "account.move",
+                "active_ids": [42]
+            }
+        }
+    })
+}
@@ -5,17 +5,50 @@
 //!
 //! As a crate user, you shouldn't need to interact with these directly. Instead, see [`crate::client`].
 //!
-//! **TODO**: Add examples for the `types-only` feature
+//! ## Types Only
+//!
+//! With the `types-only` feature, the request types in [`crate::service`] are
+//! still available, and can be sent with any HTTP library. Build the request
+//! body with [`JsonRpcParams::build`], and parse the response body into a
+//! [`JsonRpcResponse`]:
+//! ```
+//! use odoo_api::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+//! use odoo_api::service::orm::{SearchRead, SearchReadResponse};
+//! use odoo_api::{jvec, svec};
+//!
+//! # fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! let request = SearchRead {
+//!     database: "some-database".into(),
+//!     uid: 2,
+//!     password: "password".into(),
+//!     model: "res.partner".into(),
+//!     domain: jvec![["is_company", "=", true]],
+//!     fields: svec!["name"],
+//!     offset: None,
+//!     limit: Some(10),
+//!     order: None,
+//! };
+//!
+//! // POST this to `/jsonrpc`
+//! let body = serde_json::to_string(&request.build(1))?;
+//!
+//! // ...
+//! # let response = r#"{"jsonrpc": "2.0", "id": 1, "result": []}"#;
+//! let response: JsonRpcResponse<SearchReadResponse> = serde_json::from_str(response)?;
+//! # Ok(())
+//! # }
+//! ```
 
 pub mod request;
 pub mod response;
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fmt::Debug;
 
 pub use request::{
-    JsonRpcParams, JsonRpcRequest, OdooApiContainer, OdooApiMethod, OdooOrmContainer,
-    OdooOrmMethod, OdooWebContainer, OdooWebMethod,
+    JsonRpcParams, JsonRpcRequest, OdooApiContainer, OdooApiMethod, OdooKwargsMethod,
+    OdooOrmContainer, OdooOrmMethod, OdooWebContainer, OdooWebMethod,
 };
 pub use response::JsonRpcResponse;
 
@@ -31,23 +64,74 @@ pub type OdooId = i32;
 
 /// A vec of [`OdooId`].
 ///
-/// This type also implements `From<OdooId>`, which allows for flexible function
-/// args, e.g.:
+/// This type implements `From` for a single id, a `Vec`, a slice, and an array,
+/// which allows for flexible function args. Every client method which takes
+/// `ids` accepts `impl Into<OdooIds>`, e.g.:
 /// ```
-/// use odoo_api::jsonrpc::OdooIds;
-/// fn my_function<I: Into<OdooIds>>(ids: I) {
+/// use odoo_api::jsonrpc::{OdooId, OdooIds};
+/// fn my_function(ids: impl Into<OdooIds>) {
 ///     // ...
 /// }
 ///
 /// // call with a list of ids...
 /// my_function(vec![1, 2, 3]);
 ///
+/// // ... an array or slice...
+/// my_function([1, 2, 3]);
+/// let ids: &[OdooId] = &[1, 2, 3];
+/// my_function(ids);
+///
 /// // ... or with a single id
 /// my_function(1);
 /// ```
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// ## Ordering
+/// The ids are kept (and serialized) in insertion order, including any
+/// duplicates. None of the request types reorder them. This matters for some
+/// methods (e.g., `resequence`, or anything which iterates over `self`), but
+/// not for others (e.g., `read`). Use [`sorted`](Self::sorted) and
+/// [`dedup`](Self::dedup) to normalize the ids explicitly.
+///
+/// Equality is also order-sensitive, so `[1, 2]` and `[2, 1]` aren't equal:
+/// ```
+/// use odoo_api::jsonrpc::OdooIds;
+///
+/// let a = OdooIds::from(vec![2, 1, 2]);
+/// let b = OdooIds::from(vec![1, 2]);
+/// assert_ne!(a, b);
+/// assert_eq!(a.sorted().dedup(), b);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OdooIds(Vec<OdooId>);
 
+impl OdooIds {
+    /// The ids, in order
+    pub fn as_slice(&self) -> &[OdooId] {
+        &self.0
+    }
+
+    /// Convert into the inner `Vec`
+    pub fn into_inner(self) -> Vec<OdooId> {
+        self.0
+    }
+
+    /// Sort the ids in ascending order
+    pub fn sorted(mut self) -> Self {
+        self.0.sort_unstable();
+        self
+    }
+
+    /// Remove duplicate ids, keeping the first occurrence of each
+    ///
+    /// Unlike [`Vec::dedup`], this also removes non-consecutive duplicates, and
+    /// otherwise preserves the order.
+    pub fn dedup(mut self) -> Self {
+        let mut seen = HashSet::with_capacity(self.0.len());
+        self.0.retain(|id| seen.insert(*id));
+        self
+    }
+}
+
 impl From<OdooId> for OdooIds {
     fn from(value: OdooId) -> Self {
         OdooIds(vec![value])
@@ -58,6 +142,21 @@ impl From<Vec<OdooId>> for OdooIds {
         Self(value)
     }
 }
+impl From<&[OdooId]> for OdooIds {
+    fn from(value: &[OdooId]) -> Self {
+        Self(value.to_vec())
+    }
+}
+impl<const N: usize> From<[OdooId; N]> for OdooIds {
+    fn from(value: [OdooId; N]) -> Self {
+        Self(value.to_vec())
+    }
+}
+impl<const N: usize> From<&[OdooId; N]> for OdooIds {
+    fn from(value: &[OdooId; N]) -> Self {
+        Self(value.to_vec())
+    }
+}
 
 /// A string representing the JSON-RPC version
 ///
@@ -77,3 +176,93 @@ pub enum JsonRpcMethod {
     #[serde(rename = "call")]
     Call,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::service::object::Execute;
+    use crate::service::orm::{SearchRead, SearchReadResponse};
+    use crate::service::test::Result;
+    use serde_json::{from_value, json, to_value};
+
+    /// The request types don't need a client (e.g. with `types-only`)
+    #[test]
+    fn without_client() -> Result<()> {
+        let execute = Execute {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+            model: "res.partner".into(),
+            method: "search".into(),
+            args: jvec![[["is_company", "=", true]]],
+        };
+        let actual = to_value(execute.build(1))?;
+        assert_eq!(actual["params"]["service"], "object");
+        assert_eq!(actual["params"]["method"], "execute");
+        assert_eq!(
+            actual["params"]["args"],
+            json!([
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                "search",
+                [["is_company", "=", true]]
+            ])
+        );
+
+        let search_read = SearchRead {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+            model: "res.partner".into(),
+            domain: jvec![],
+            fields: svec!["name"],
+            offset: None,
+            limit: Some(10),
+            order: None,
+        };
+        let actual = to_value(search_read.build(2))?;
+        assert_eq!(actual["params"]["method"], "execute_kw");
+        assert_eq!(actual["params"]["args"][4], "search_read");
+        assert_eq!(
+            actual["params"]["args"][6],
+            json!({"domain": [], "fields": ["name"], "limit": 10})
+        );
+
+        let response: JsonRpcResponse<SearchReadResponse> = from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "result": [{"id": 1, "name": "Acme"}]
+        }))?;
+        match response {
+            JsonRpcResponse::Success(response) => assert_eq!(response.result.data.len(), 1),
+            JsonRpcResponse::Error(_) => panic!("expected a success response"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn odoo_ids() -> Result<()> {
+        let ids = OdooIds::from(vec![3, 1, 3, 2]);
+        assert_eq!(ids.as_slice(), &[3, 1, 3, 2]);
+
+        // duplicates and order are kept as-is
+        assert_eq!(to_value(&ids)?, json!([3, 1, 3, 2]));
+        assert_eq!(from_value::<OdooIds>(json!([3, 1, 3, 2]))?, ids);
+
+        assert_eq!(ids.clone().dedup().into_inner(), vec![3, 1, 2]);
+        assert_eq!(ids.clone().sorted().into_inner(), vec![1, 2, 3, 3]);
+        assert_eq!(ids.sorted().dedup().into_inner(), vec![1, 2, 3]);
+        assert_eq!(OdooIds::default().into_inner(), Vec::<OdooId>::new());
+
+        // equality (and hashing) is order-sensitive
+        let mut set = HashSet::new();
+        set.insert(OdooIds::from(vec![1, 2]));
+        assert!(set.contains(&OdooIds::from(vec![1, 2])));
+        assert!(!set.contains(&OdooIds::from(vec![2, 1])));
+
+        Ok(())
+    }
+}
@@ -1,8 +1,13 @@
 //! JSON-RPC Requests
 
 use super::{JsonRpcId, JsonRpcMethod, JsonRpcVersion};
+#[cfg(feature = "client")]
+use crate::client::error::ParseResponseError;
+#[cfg(feature = "client")]
+use crate::jsonrpc::response::JsonRpcErrorData;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::{Map, Value};
 use std::fmt::Debug;
 
 mod api;
@@ -51,10 +56,72 @@ pub trait JsonRpcParams
 where
     Self: Sized + Debug + Serialize,
 {
-    type Container<T>: Debug + Serialize;
+    type Container<T>: Debug + Serialize + AsRef<Self>;
     type Response: Debug + DeserializeOwned;
 
+    /// Whether this method is safe to send to a read replica
+    ///
+    /// See [`OdooClient::with_read_replica`](crate::client::OdooClient::with_read_replica).
+    /// The macros set this for the read-only ORM methods (`read`, `search_read`,
+    /// `read_group`, etc); generic calls like `execute_kw` always go to the primary.
+    const IS_READONLY: bool = false;
+
     fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self>;
+
+    /// Map an Odoo error response to a method-specific error
+    ///
+    /// This is checked before the generic [`JsonRpcError`](crate::client::error::ParseResponseError::JsonRpcError)
+    /// is returned, so that e.g. `db.list` can report a bare `AccessDenied` as
+    /// `DbListingDisabled`. By default, no errors are mapped.
+    #[cfg(feature = "client")]
+    fn map_error(&self, _error: &JsonRpcErrorData) -> Option<ParseResponseError> {
+        None
+    }
+}
+
+/// Implemented by Odoo "method" types that accept keyword arguments
+///
+/// Only these methods are able to carry a `context`, so this trait is used to
+/// gate [`OdooRequest::with_context`](crate::client::OdooRequest::with_context).
+///
+/// The default impl targets the `execute_kw` layout, where the kwargs are
+/// passed as the final element of the `args` tuple:
+/// ```json
+/// {
+///     "service": "object",
+///     "method": "execute_kw",
+///     "args": [db, uid, password, model, method, [args], {kwargs}]
+/// }
+/// ```
+pub trait OdooKwargsMethod
+where
+    Self: JsonRpcParams,
+{
+    /// Whether the client should set the current company in the context
+    ///
+    /// This is `true` for the ORM `create` and `write` methods, so records are
+    /// written in the company selected with `with_companies()`.
+    const SETS_COMPANY: bool = false;
+
+    /// Merge `context` into the serialized `params` object
+    ///
+    /// Keys in `context` will override any existing context keys.
+    fn inject_context(params: &mut Value, context: &Map<String, Value>) {
+        let kwargs = params
+            .get_mut("args")
+            .and_then(Value::as_array_mut)
+            .and_then(|args| args.last_mut())
+            .and_then(Value::as_object_mut);
+
+        if let Some(kwargs) = kwargs {
+            match kwargs.get_mut("context").and_then(Value::as_object_mut) {
+                Some(existing) => existing.extend(context.clone()),
+                None => {
+                    kwargs.insert("context".into(), Value::Object(context.clone()));
+                }
+            }
+        }
+    }
 }
 
 /// A struct representing the full JSON-RPC request body
@@ -80,3 +147,15 @@ where
     /// The request params (service, method, and arguments)
     pub(crate) params: <T as JsonRpcParams>::Container<T>,
 }
+
+impl<T> JsonRpcRequest<T>
+where
+    T: JsonRpcParams + Serialize + Debug,
+    T::Container<T>: Debug + Serialize,
+{
+    /// The "method" type, without its container
+    #[cfg(feature = "client")]
+    pub(crate) fn inner(&self) -> &T {
+        self.params.as_ref()
+    }
+}
@@ -16,6 +16,15 @@ where
     pub(crate) inner: T,
 }
 
+impl<T> AsRef<T> for OdooApiContainer<T>
+where
+    T: OdooApiMethod + JsonRpcParams<Container<T> = Self>,
+{
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
 // Custom "man-in-the-middle" serialize impl
 impl<T> Serialize for OdooApiContainer<T>
 where
@@ -41,7 +50,10 @@ where
     Self::Container<Self>: Debug + Serialize,
 {
     /// Describe the JSON-RPC service and method for this type
-    fn describe(&self) -> (&'static str, &'static str);
+    ///
+    /// This borrows from `self`, so that the service and method may be set at
+    /// runtime (see [`ApiCall`](crate::service::custom::ApiCall)).
+    fn describe(&self) -> (&str, &str);
 
     /// Describe method endpoint (e.g., "/web/session/authenticate")
     fn endpoint(&self) -> &'static str;
@@ -1,5 +1,6 @@
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
 use std::fmt::Debug;
 
 use super::{JsonRpcId, JsonRpcMethod, JsonRpcParams, JsonRpcRequest, JsonRpcVersion};
@@ -20,6 +21,15 @@ where
     pub(crate) inner: T,
 }
 
+impl<T> AsRef<T> for OdooOrmContainer<T>
+where
+    T: OdooOrmMethod + JsonRpcParams<Container<T> = Self>,
+{
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
 // Custom "man-in-the-middle" serialize impl
 impl<T> Serialize for OdooOrmContainer<T>
 where
@@ -49,6 +59,13 @@ where
     /// Return the model method name (e.g., "read_group" or "create")
     fn method(&self) -> &'static str;
 
+    /// Return the equivalent `execute_kw` call, as `(model, method, args, kwargs)`
+    ///
+    /// This is the same data the request is serialized with, so it can be used
+    /// to log the call, or to send it via a transport which only supports
+    /// [`ExecuteKw`](crate::service::object::ExecuteKw).
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>);
+
     /// Build `self` into a full [`JsonRpcRequest`]
     fn _build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
         JsonRpcRequest {
@@ -22,6 +22,15 @@ where
     pub(crate) inner: T,
 }
 
+impl<T> AsRef<T> for OdooWebContainer<T>
+where
+    T: OdooWebMethod + JsonRpcParams<Container<T> = Self>,
+{
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
 /// An Odoo "Web" request type
 pub trait OdooWebMethod
 where
@@ -29,7 +38,10 @@ where
     Self::Container<Self>: Debug + Serialize,
 {
     /// Describe method endpoint (e.g., "/web/session/authenticate")
-    fn endpoint(&self) -> &'static str;
+    ///
+    /// This borrows from `self`, so that endpoints may be set at runtime (see
+    /// [`WebCall`](crate::service::web::WebCall)).
+    fn endpoint(&self) -> &str;
 
     /// Build `self` into a full [`JsonRpcRequest`]
     fn _build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
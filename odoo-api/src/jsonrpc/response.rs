@@ -1,9 +1,13 @@
 //! JSON-RPC Responses
 
 use super::{JsonRpcId, JsonRpcVersion};
-use serde::{Deserialize, Serialize};
+use serde::de::{self, DeserializeOwned, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
 use serde_json::{Map, Value};
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::Deref;
 
 /// An Odoo JSON-RPC API response
 ///
@@ -11,8 +15,14 @@ use std::fmt::Debug;
 /// *request* [`OdooApiMethod`](super::OdooApiMethod). The deserialization struct is chosen by
 /// looking at the associated type [`OdooApiMethod::Response`](super::OdooApiMethod).
 ///
+/// The variant is chosen by the top-level keys (rather than by trying each
+/// variant in turn): a non-null `error` is always an `Error`, even if a
+/// `result` is also present. Otherwise, the `result` is a `Success` (a `null`
+/// result is valid for methods returning `()`). A response with neither is
+/// rejected.
+///
 /// See: [odoo/http.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/http.py#L1805-L1841)
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum JsonRpcResponse<T>
 where
@@ -22,6 +32,118 @@ where
     Error(JsonRpcResponseError),
 }
 
+impl<'de, T> Deserialize<'de> for JsonRpcResponse<T>
+where
+    T: Debug + DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        RawResponse::<Box<RawValue>>::deserialize(deserializer)?
+            .into_response()
+            .map_err(de::Error::custom)?
+            .ok_or_else(|| de::Error::custom(MISSING_RESULT))
+    }
+}
+
+/// The error message for a response without a `result` or `error`
+pub(crate) const MISSING_RESULT: &str = "missing both `result` and `error`";
+
+/// The top-level keys of a JSON-RPC response
+///
+/// The `result` and `error` are kept as raw JSON until the variant is chosen,
+/// and any unknown keys are recorded (for the client's strict mode).
+///
+/// The raw JSON is owned by default. The client parses with `R = &RawValue`
+/// instead, which borrows from the response body rather than copying it.
+pub(crate) struct RawResponse<R = Box<RawValue>> {
+    jsonrpc: Option<JsonRpcVersion>,
+    id: Option<JsonRpcId>,
+    result: Option<R>,
+    error: Option<R>,
+
+    /// Any other top-level keys
+    pub(crate) unknown: Vec<String>,
+}
+
+impl<R: Deref<Target = RawValue>> RawResponse<R> {
+    /// The raw `result`, if present
+    #[cfg(feature = "client")]
+    pub(crate) fn result(&self) -> Option<&RawValue> {
+        self.result.as_deref()
+    }
+
+    /// Parse the `result` or `error`, returning `None` if neither is present
+    pub(crate) fn into_response<T>(self) -> serde_json::Result<Option<JsonRpcResponse<T>>>
+    where
+        T: Debug + DeserializeOwned,
+    {
+        let jsonrpc = self
+            .jsonrpc
+            .ok_or_else(|| de::Error::missing_field("jsonrpc"))?;
+        let error = self.error.filter(|error| error.get() != "null");
+        match (error, self.result) {
+            (Some(error), _) => Ok(Some(JsonRpcResponse::Error(JsonRpcResponseError {
+                jsonrpc,
+                id: self.id,
+                error: serde_json::from_str(error.get())?,
+            }))),
+            (None, Some(result)) => Ok(Some(JsonRpcResponse::Success(JsonRpcResponseSuccess {
+                jsonrpc,
+                id: self.id.ok_or_else(|| de::Error::missing_field("id"))?,
+                result: serde_json::from_str(result.get())?,
+            }))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+impl<'de, R: Deserialize<'de>> Deserialize<'de> for RawResponse<R> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RawResponseVisitor<R>(PhantomData<R>);
+
+        impl<'de, R: Deserialize<'de>> Visitor<'de> for RawResponseVisitor<R> {
+            type Value = RawResponse<R>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a JSON-RPC response object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut raw = RawResponse {
+                    jsonrpc: None,
+                    id: None,
+                    result: None,
+                    error: None,
+                    unknown: Vec::new(),
+                };
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "jsonrpc" => raw.jsonrpc = Some(map.next_value()?),
+                        "id" => raw.id = map.next_value()?,
+                        "result" => raw.result = Some(map.next_value()?),
+                        "error" => raw.error = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                            raw.unknown.push(key);
+                        }
+                    }
+                }
+                Ok(raw)
+            }
+        }
+
+        deserializer.deserialize_map(RawResponseVisitor(PhantomData))
+    }
+}
+
 /// A successful Odoo API response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JsonRpcResponseSuccess<T>
@@ -50,38 +172,125 @@ pub struct JsonRpcResponseError {
 
     /// The request id
     ///
-    /// This is not used for any stateful behaviour on the Odoo/Python side
-    pub(crate) id: JsonRpcId,
+    /// This is not used for any stateful behaviour on the Odoo/Python side.
+    /// Errors raised before the request is parsed (e.g. a 404 from werkzeug)
+    /// have a `null` id.
+    #[serde(default)]
+    pub(crate) id: Option<JsonRpcId>,
 
     /// A struct containing the error information
     pub(crate) error: JsonRpcError,
 }
 
+/// The error information
+///
+/// The shape of this object varies across Odoo versions and controllers, so
+/// everything except the `code` is optional (or defaulted).
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JsonRpcError {
-    /// The error code. Currently hardcoded to `200`
-    pub code: u32,
+    /// The error code
+    ///
+    /// This is usually `200`, but Odoo returns `100` for an expired session,
+    /// `404` for a missing route, and some proxies return negative codes.
+    pub code: i64,
 
     /// The error "message". This is a short string indicating the type of
     /// error. Some examples are:
     ///  * `Odoo Server Error`
     ///  * `404: Not Found`
     ///  * `Odoo Session Expired`
+    #[serde(default)]
     pub message: String,
 
     /// The actual error data
+    ///
+    /// Some controllers omit this entirely, in which case every field is empty.
+    #[serde(default)]
     pub data: JsonRpcErrorData,
 }
 
+impl JsonRpcError {
+    /// Check whether this error was caused by calling a missing model method
+    ///
+    /// Odoo raises an `AttributeError` in this case, e.g. Odoo 17.0 raises
+    /// `'res.partner' object has no attribute 'name_get'`.
+    pub fn is_missing_method(&self, method: &str) -> bool {
+        self.data.name.ends_with("AttributeError")
+            && self
+                .data
+                .message
+                .contains(&format!("has no attribute '{}'", method))
+    }
+
+    /// Check whether the transaction was rolled back by a concurrent update
+    ///
+    /// Postgres raises a `SerializationFailure` (`could not serialize access due
+    /// to concurrent update`) when two transactions write the same rows. Odoo
+    /// retries these itself a few times, then gives up; the whole call can be
+    /// retried safely, as nothing was committed.
+    pub fn is_serialization_failure(&self) -> bool {
+        self.data.name.ends_with("SerializationFailure")
+            || self.data.name.ends_with("TransactionRollbackError")
+            || self
+                .data
+                .message
+                .contains("could not serialize access due to concurrent update")
+    }
+
+    /// The Python exception name (e.g. `odoo.exceptions.AccessError`)
+    ///
+    /// Returns `None` if the server didn't send one.
+    pub fn exception_name(&self) -> Option<&str> {
+        non_empty(&self.data.name)
+    }
+
+    /// The Python exception message, or the error `message` if there isn't one
+    ///
+    /// For example, `You are not allowed to access 'Contact' (res.partner) records.`
+    /// for an `AccessError`, or `404: Not Found` for a missing route.
+    pub fn exception_message(&self) -> Option<&str> {
+        non_empty(&self.data.message).or_else(|| non_empty(&self.message))
+    }
+
+    /// The Python traceback
+    ///
+    /// Returns `None` if the server didn't send one (e.g., some proxies and
+    /// controllers only send the `message`).
+    pub fn traceback(&self) -> Option<&str> {
+        non_empty(&self.data.debug)
+    }
+}
+
+/// Shows the error message, then the Python exception name and message (if
+/// any), e.g. `Odoo Server Error (odoo.exceptions.AccessError): You are not
+/// allowed to access 'Contact' (res.partner) records.`
+///
+/// The traceback is only included in the `Debug` output.
 impl std::fmt::Display for JsonRpcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        match self.message.as_str() {
+            "" => write!(f, "JSON-RPC Error {}", self.code)?,
+            message => write!(f, "{}", message)?,
+        }
+        if let Some(name) = self.exception_name() {
+            write!(f, " ({})", name)?;
+        }
+        match self.data.message.as_str() {
+            "" => Ok(()),
+            message if message == self.message => Ok(()),
+            message => write!(f, ": {}", message),
+        }
     }
 }
 
 impl std::error::Error for JsonRpcError {}
 
-#[derive(Debug, Serialize, Deserialize)]
+fn non_empty(value: &str) -> Option<&str> {
+    (!value.is_empty()).then_some(value)
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct JsonRpcErrorData {
     /// The module? and type of the object where the exception was raised
     ///
@@ -90,15 +299,145 @@ pub struct JsonRpcErrorData {
     ///  * `odoo.addons.account.models.account_move.AccountMove`
     pub name: String,
 
-    /// The Python exception stack trace
+    /// The Python exception stack trace (empty if the server didn't send one)
     pub debug: String,
 
     /// The Python exception message (e.g. `str(exception)`)
     pub message: String,
 
-    /// The Python exception arguments (e.g. `excetion.args`)
+    /// The Python exception arguments (e.g. `exception.args`)
     pub arguments: Vec<Value>,
 
-    /// The Python exception context (e.g. `excetion.context`)
+    /// The Python exception context (e.g. `exception.context`)
     pub context: Map<String, Value>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures;
+    use serde_json::{from_value, json};
+
+    fn parse_error(value: Value) -> JsonRpcResponseError {
+        match from_value::<JsonRpcResponse<Value>>(value).unwrap() {
+            JsonRpcResponse::Error(error) => error,
+            JsonRpcResponse::Success(success) => panic!("expected an error, got {:?}", success),
+        }
+    }
+
+    #[test]
+    fn session_expired() {
+        let error = parse_error(fixtures::errors::session_expired());
+        assert_eq!(error.id, Some(1));
+        assert_eq!(error.error.code, 100);
+        assert_eq!(error.error.data.name, "odoo.http.SessionExpiredException");
+        assert_eq!(error.error.data.arguments, vec![json!("Session expired")]);
+    }
+
+    #[test]
+    fn werkzeug_not_found() {
+        // raised before the JSON-RPC request is parsed, so there's no id, and
+        // no `debug` or `context`
+        let error = parse_error(fixtures::errors::not_found());
+        assert_eq!(error.id, None);
+        assert_eq!(error.error.code, 404);
+        assert_eq!(error.error.message, "404: Not Found");
+        assert_eq!(error.error.data.name, "werkzeug.exceptions.NotFound");
+        assert!(error.error.data.debug.is_empty());
+        assert!(error.error.data.context.is_empty());
+    }
+
+    #[test]
+    fn minimal() {
+        let error = parse_error(json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "error": {
+                "code": -32000,
+                "message": "Bad Gateway"
+            }
+        }));
+        assert_eq!(error.error.code, -32000);
+        assert_eq!(error.error.to_string(), "Bad Gateway");
+        assert!(error.error.data.name.is_empty());
+        assert!(error.error.data.arguments.is_empty());
+        assert!(!error.error.is_missing_method("name_get"));
+    }
+
+    #[test]
+    fn serialization_failure() {
+        let error = parse_error(fixtures::errors::serialization_failure());
+        assert!(error.error.is_serialization_failure());
+        assert!(!parse_error(json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "error": {"code": 200, "message": "Odoo Server Error", "data": {"name": "odoo.exceptions.UserError"}}
+        }))
+        .error
+        .is_serialization_failure());
+    }
+
+    #[test]
+    fn result_and_error() {
+        // some proxies (and older Odoo versions) send `"result": null` alongside
+        // the error, which must not be parsed as a successful `()` response
+        let response: JsonRpcResponse<()> = from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": null,
+            "error": {"code": 200, "message": "Odoo Server Error"}
+        }))
+        .unwrap();
+        assert!(
+            matches!(response, JsonRpcResponse::Error(_)),
+            "{:?}",
+            response
+        );
+
+        // a null error is ignored
+        let response: JsonRpcResponse<Vec<i32>> = from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [1, 2],
+            "error": null
+        }))
+        .unwrap();
+        match response {
+            JsonRpcResponse::Success(success) => assert_eq!(success.result, vec![1, 2]),
+            response => panic!("expected a success, got {:?}", response),
+        }
+    }
+
+    #[test]
+    fn result_only() {
+        let response: JsonRpcResponse<()> = from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": null
+        }))
+        .unwrap();
+        assert!(
+            matches!(response, JsonRpcResponse::Success(_)),
+            "{:?}",
+            response
+        );
+
+        // a result can't be parsed as a different type, even if it's null
+        assert!(from_value::<JsonRpcResponse<Vec<i32>>>(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": null
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn missing_result() {
+        let err = from_value::<JsonRpcResponse<Value>>(json!({
+            "jsonrpc": "2.0",
+            "id": 1
+        }))
+        .unwrap_err();
+        assert_eq!(err.to_string(), MISSING_RESULT);
+    }
+}
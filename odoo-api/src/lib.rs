@@ -31,6 +31,29 @@
 //! simple shim closure. See [`client::ClosureAsync`] or [`client::ClosureBlocking`]
 //! for more info.
 //!
+//! To drop the [`reqwest`] dependency entirely, disable the default features and
+//! enable `client` instead:
+//! ```toml
+//! [dependencies]
+//! odoo_api = { version = "0.2", default-features = false, features = ["client"] }
+//! ```
+//!
+//! | Feature    | Includes                                                 |
+//! |------------|----------------------------------------------------------|
+//! | `client`   | [`OdooClient`] with the closure impls only (no `reqwest`) |
+//! | `async`    | `client`, plus the async `reqwest` impl (default)        |
+//! | `blocking` | `client`, plus the blocking `reqwest` impl               |
+//! | `ureq`     | `client`, plus a blocking [`ureq`] impl                  |
+//! | `wasm`     | `client`, plus a browser `fetch()` impl (for `wasm32-unknown-unknown`) |
+//! | `tracing`  | `client`, plus a [`tracing`] span for each request      |
+//! | `metrics`  | `client`, plus [`RequestMetrics::record`](client::RequestMetrics::record), via the [`metrics`](https://docs.rs/metrics) crate |
+//! | `xmlrpc`   | The [`xmlrpc`] encoding, as an alternate transport       |
+//! | `time`     | The [`types::OdooDate`] and [`types::OdooDateTime`] types |
+//! | `registry` | The [`registry`] of supported methods, for runtime lookup |
+//! | `strict-responses` | Reject unexpected keys in the core response types |
+//! | `compression` | `client`, plus gzip request bodies and gzip/deflate responses (via [`flate2`]) |
+//! | `test-support` | The canned Odoo payloads in [`fixtures`], for testing with a mock transport |
+//!
 //! ### Types Only
 //!
 //! The crate offers a `types-only` feature. When enabled, the library only exposes
@@ -50,19 +73,18 @@
 //! odoo_api = "0.2"
 //! ```
 //!
-//! Then make your requests:
+//! Then make your requests (the [`prelude`] covers the common imports):
 //! ```no_run
-//! # #[cfg(not(feature = "types-only"))]
-//! use odoo_api::{OdooClient, jvec, jmap};
+//! use odoo_api::prelude::*;
 //!
-//! # #[cfg(not(feature = "types-only"))]
-//! # async fn test() -> odoo_api::client::Result<()> {
+//! # #[cfg(feature = "async")]
+//! # async fn test() -> Result<()> {
 //! // build the client
 //! let url = "https://odoo.example.com";
-//! let mut client = OdooClient::new_reqwest_async(url)?;
+//! let client = OdooClient::new_reqwest_async(url)?;
 //!
 //! // authenticate with `some-database`
-//! let mut client = client.authenticate(
+//! let client = client.authenticate(
 //!     "some-database",
 //!     "admin",
 //!     "password",
@@ -77,11 +99,11 @@
 //!     ]
 //! ).send().await?;
 //!
-//! // fetch the login and partner_id fields from user id=1
+//! // fetch the login and partner_id fields from the authenticated user
 //! let info = client.execute_kw(
 //!     "res.users",
 //!     "read",
-//!     jvec![[1]],
+//!     jvec![[client.uid()]],
 //!     jmap!{
 //!         "fields": ["login", "partner_id"]
 //!     }
@@ -110,12 +132,15 @@
 //! # }
 //! ```
 
+// The crate docs mirror the README, including its list indentation
+#![allow(clippy::doc_overindented_list_items)]
+
 // The `types-only` feature implies that the `client` module isn't included, so
-// `async` and `blocking` have no effect
-#[cfg(all(feature = "types-only", any(feature = "async", feature = "blocking")))]
+// `client`, `async` and `blocking` have no effect
+#[cfg(all(feature = "types-only", feature = "client"))]
 std::compile_error!(
-    "The `types-only` feature is mutually exclusive with the `async` and `blocking` \
-     features. Please disable the `async` and `blocking` by adding `default-features = false` \
+    "The `types-only` feature is mutually exclusive with the `client`, `async`, and \
+     `blocking` features. Please disable them by adding `default-features = false` \
      to your Cargo.toml"
 );
 
@@ -124,11 +149,39 @@ pub mod service;
 #[macro_use]
 mod macros;
 
-#[cfg(not(feature = "types-only"))]
+#[cfg(feature = "client")]
 pub mod client;
 
-#[cfg(not(feature = "types-only"))]
+#[cfg(feature = "client")]
 pub use client::{AsyncClosureReturn, BlockingClosureReturn, OdooClient};
 
 pub mod jsonrpc;
 pub use jsonrpc::OdooId;
+
+pub mod serde_helpers;
+
+pub mod fields;
+
+pub mod model;
+
+pub mod version;
+pub use version::OdooVersion;
+
+pub mod secret;
+pub use secret::SecretString;
+
+pub mod warning;
+
+pub mod prelude;
+
+#[cfg(feature = "xmlrpc")]
+pub mod xmlrpc;
+
+#[cfg(feature = "time")]
+pub mod types;
+
+#[cfg(feature = "registry")]
+pub mod registry;
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod fixtures;
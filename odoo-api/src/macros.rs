@@ -9,15 +9,19 @@ use serde_json::{Map, Value};
 /// This is useful when using any of the API methods that require a `Vec<Value>`,
 /// as [`serde_json`] doesn't have a way to build these.
 ///
+/// The syntax is the same as [`serde_json::json!`], so values can be nested
+/// arrays/objects, or any expression that implements [`Serialize`](serde::Serialize).
+/// Trailing commas are allowed.
+///
 /// ## Example:
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> odoo_api::client::error::Result<()> {
 /// # use serde_json::{json, Value};
 /// # use odoo_api::{jvec, jmap};
 /// # use odoo_api::{OdooClient};
 /// # let client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // Manually
 /// let mut args = Vec::<Value>::new();
 /// args.push(json!([1, 2, 3]));
@@ -43,16 +47,13 @@ use serde_json::{Map, Value};
 /// ```
 #[macro_export]
 macro_rules! jvec {
-    [$($v:tt),*] => {
-        {
-            let mut vec = ::std::vec::Vec::<serde_json::Value>::new();
-            $(
-                vec.push(::serde_json::json!($v));
-            )*
-            vec
+    [$($tt:tt)*] => {
+        // `json!` already handles nesting, expressions, and trailing commas
+        match ::serde_json::json!([$($tt)*]) {
+            ::serde_json::Value::Array(vec) => vec,
+            _ => ::std::unreachable!(),
         }
     };
-    () => { compiler_error!("")};
 }
 
 /// Helper macro to build a [`Map<String, Value>`]
@@ -60,15 +61,17 @@ macro_rules! jvec {
 /// This is useful when using any of the API methods that require a `Map<String, Value>`,
 /// as [`serde_json`] doesn't have a way to build these.
 ///
+/// Like [`jvec!`], the syntax is the same as [`serde_json::json!`].
+///
 /// ## Example:
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> odoo_api::client::error::Result<()> {
 /// # use serde_json::{json, Value, Map};
 /// # use odoo_api::{jvec, jmap};
 /// # use odoo_api::{OdooClient};
 /// # let client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // Manually
 /// let mut kwargs = Map::<String, Value>::new();
 /// kwargs.insert("domain".into(), json!([["name", "ilike", "admin"]]));
@@ -96,16 +99,12 @@ macro_rules! jvec {
 /// ```
 #[macro_export]
 macro_rules! jmap {
-    {$($k:tt: $v:tt),*} => {
-        {
-            let mut map = ::serde_json::Map::<String, ::serde_json::Value>::new();
-            $(
-                map.insert($k.into(), ::serde_json::json!($v));
-            )*
-            map
+    {$($tt:tt)*} => {
+        match ::serde_json::json!({$($tt)*}) {
+            ::serde_json::Value::Object(map) => map,
+            _ => ::std::unreachable!(),
         }
     };
-    () => { compiler_error!("")};
 }
 
 /// Helper macro to build a [`Vec<String>`]
@@ -124,14 +123,216 @@ macro_rules! jmap {
 /// ```
 #[macro_export]
 macro_rules! svec {
-    [$($v:tt),*] => {
+    [$($v:expr),* $(,)?] => {
         {
-            let mut vec = ::std::vec::Vec::<String>::new();
-            $(
-                vec.push($v.to_string());
-            )*
+            let vec: ::std::vec::Vec<String> = ::std::vec![
+                $($v.to_string()),*
+            ];
             vec
         }
     };
-    () => { compiler_error!("")};
+}
+
+/// Helper macro to build an Odoo domain (a [`Vec<Value>`])
+///
+/// Each "leaf" is written as `field operator value`, and is lowered to the
+/// usual `[field, operator, value]` triplet. The supported operators are:
+///  - `==`, `!=`, `<`, `>`, `<=`, `>=` (`==` is lowered to `=`)
+///  - `=word`, for word-like operators, e.g. `=ilike`, `=in`, `=child_of`
+///  - any string literal, e.g. `"not in"` or `"=like"`
+///
+/// The logical operators `|`, `&`, and `!` can be used as standalone entries.
+///
+/// Values can be JSON literals (as in [`jvec!`]), or any expression that implements
+/// [`Serialize`](serde::Serialize).
+///
+/// ## Example:
+/// ```
+/// use odoo_api::{sdomain, jvec};
+///
+/// let company_ids = vec![1, 2];
+/// let domain = sdomain![
+///     |,
+///     "email" =ilike "%@example.com",
+///     "active" == true,
+///     "company_id" =in company_ids,
+///     "state" "not in" ["cancel", "draft"],
+/// ];
+///
+/// assert_eq!(domain, jvec![
+///     "|",
+///     ["email", "ilike", "%@example.com"],
+///     ["active", "=", true],
+///     ["company_id", "in", [1, 2]],
+///     ["state", "not in", ["cancel", "draft"]],
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! sdomain {
+    [$($tt:tt)*] => {
+        {
+            let domain: ::std::vec::Vec<::serde_json::Value> =
+                $crate::__sdomain!(@entry []; $($tt)*);
+            domain
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sdomain {
+    // done
+    (@entry [$($out:expr,)*];) => {
+        ::std::vec![$($out),*]
+    };
+
+    // logical operators
+    (@entry [$($out:expr,)*]; | $(, $($rest:tt)*)?) => {
+        $crate::__sdomain!(@entry [$($out,)* ::serde_json::Value::from("|"),]; $($($rest)*)?)
+    };
+    (@entry [$($out:expr,)*]; & $(, $($rest:tt)*)?) => {
+        $crate::__sdomain!(@entry [$($out,)* ::serde_json::Value::from("&"),]; $($($rest)*)?)
+    };
+    (@entry [$($out:expr,)*]; ! $(, $($rest:tt)*)?) => {
+        $crate::__sdomain!(@entry [$($out,)* ::serde_json::Value::from("!"),]; $($($rest)*)?)
+    };
+
+    // leaf operators
+    (@entry $out:tt; $f:tt == $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, "="; $($rest)+)
+    };
+    (@entry $out:tt; $f:tt != $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, "!="; $($rest)+)
+    };
+    (@entry $out:tt; $f:tt <= $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, "<="; $($rest)+)
+    };
+    (@entry $out:tt; $f:tt >= $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, ">="; $($rest)+)
+    };
+    (@entry $out:tt; $f:tt < $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, "<"; $($rest)+)
+    };
+    (@entry $out:tt; $f:tt > $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, ">"; $($rest)+)
+    };
+    (@entry $out:tt; $f:tt = $op:ident $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, stringify!($op); $($rest)+)
+    };
+    (@entry $out:tt; $f:tt $op:literal $($rest:tt)+) => {
+        $crate::__sdomain!(@value $out; $f, $op; $($rest)+)
+    };
+
+    // values; try a single JSON-like token first (e.g. `[1, 2]` or `true`),
+    // then fall back to an arbitrary expression
+    (@value [$($out:expr,)*]; $f:tt, $op:expr; $v:tt $(, $($rest:tt)*)?) => {
+        $crate::__sdomain!(@entry [$($out,)* ::serde_json::Value::Array(::std::vec![
+            ::serde_json::json!($f),
+            ::serde_json::Value::from($op),
+            ::serde_json::json!($v),
+        ]),]; $($($rest)*)?)
+    };
+    (@value [$($out:expr,)*]; $f:tt, $op:expr; $v:expr $(, $($rest:tt)*)?) => {
+        $crate::__sdomain!(@entry [$($out,)* ::serde_json::Value::Array(::std::vec![
+            ::serde_json::json!($f),
+            ::serde_json::Value::from($op),
+            ::serde_json::json!($v),
+        ]),]; $($($rest)*)?)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::jsonrpc::OdooId;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn jvec() {
+        let name = String::from("Admin");
+        let id: i32 = 42;
+        let ids: Vec<OdooId> = vec![1, 2, 3];
+
+        assert_eq!(Value::Array(jvec![]), json!([]));
+        assert_eq!(
+            Value::Array(jvec![
+                [["name", "=", name], ["id", "!=", id]],
+                ids.clone(),
+                id + 1,
+                [1, 2, 3,],
+            ]),
+            json!([
+                [["name", "=", "Admin"], ["id", "!=", 42]],
+                [1, 2, 3],
+                43,
+                [1, 2, 3]
+            ])
+        );
+    }
+
+    #[test]
+    fn jmap() {
+        let lang = String::from("en_GB");
+        let ids: Vec<OdooId> = vec![1, 2];
+
+        assert_eq!(Value::Object(jmap! {}), json!({}));
+        assert_eq!(
+            Value::Object(jmap! {
+                "context": {"lang": lang, "allowed_company_ids": ids,},
+                "limit": ids.len() * 10,
+            }),
+            json!({
+                "context": {"lang": "en_GB", "allowed_company_ids": [1, 2]},
+                "limit": 20
+            })
+        );
+    }
+
+    #[test]
+    fn svec() {
+        let field = String::from("email");
+
+        assert_eq!(svec![], Vec::<String>::new());
+        assert_eq!(svec!["name", field, 'x',], vec!["name", "email", "x"]);
+    }
+
+    #[test]
+    fn sdomain() {
+        let email = String::from("%@example.com");
+        let partner_id: i32 = 3;
+        let company_ids: Vec<OdooId> = vec![1, 2];
+
+        assert_eq!(sdomain![], Vec::<Value>::new());
+        assert_eq!(
+            Value::Array(sdomain![
+                "email" =ilike email,
+                "active" == true,
+                |,
+                "partner_id" != partner_id,
+                "partner_id.parent_id" == partner_id,
+                "company_id" =in company_ids.clone(),
+                "state" "not in" ["cancel", "draft"],
+                !,
+                "amount_total" <= -5,
+                "amount_total" > 10.5,
+                "date" >= "2023-01-01",
+                "name" "=like" "S0%",
+                "parent_id" =child_of 1,
+            ]),
+            json!([
+                ["email", "ilike", "%@example.com"],
+                ["active", "=", true],
+                "|",
+                ["partner_id", "!=", 3],
+                ["partner_id.parent_id", "=", 3],
+                ["company_id", "in", [1, 2]],
+                ["state", "not in", ["cancel", "draft"]],
+                "!",
+                ["amount_total", "<=", -5],
+                ["amount_total", ">", 10.5],
+                ["date", ">=", "2023-01-01"],
+                ["name", "=like", "S0%"],
+                ["parent_id", "child_of", 1]
+            ])
+        );
+    }
 }
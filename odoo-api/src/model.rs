@@ -0,0 +1,204 @@
+//! Typed records, via `#[derive(OdooModel)]`
+//!
+//! The ORM `read` methods return each record as a JSON object, which can be
+//! parsed into a struct with `serde`. The [`OdooModel`] derive goes a step
+//! further: it lists the struct's fields, so the `fields` kwarg is filled in
+//! automatically, and handles Odoo's `false` values and many2one pairs.
+//!
+//! The derived `Deserialize` impl reads each field by its Odoo name:
+//!  - `Option` fields treat `false` (or a missing key) as `None`
+//!  - `#[odoo(many2one)]` fields accept `[id, display_name]` (or `false`),
+//!    see [`many2one`] for the supported types
+//!  - `#[odoo(field = "...")]` maps a field to a different Odoo name
+//!
+//! Only reading is supported.
+//!
+//! ## Example
+//! ```no_run
+//! # #[cfg(feature = "blocking")]
+//! # fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! # use odoo_api::OdooClient;
+//! # let client = OdooClient::new_reqwest_blocking("")?;
+//! # let client = client.authenticate_manual("", "", 1, "", None);
+//! use odoo_api::model::OdooModel;
+//! use odoo_api::OdooId;
+//!
+//! #[derive(Debug, OdooModel)]
+//! #[odoo(model = "res.partner")]
+//! struct Partner {
+//!     id: OdooId,
+//!     name: String,
+//!     email: Option<String>,
+//!
+//!     #[odoo(field = "parent_id", many2one)]
+//!     parent: Option<(OdooId, String)>,
+//! }
+//!
+//! // reads `["id", "name", "email", "parent_id"]` from `res.partner`
+//! let partners = client.read_model::<Partner>(vec![1, 2, 3]).send()?;
+//! for partner in partners.records {
+//!     println!("{}: {:?}", partner.name, partner.parent);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::jsonrpc::OdooId;
+use crate::serde_helpers::OdooOption;
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::Deserialize;
+
+pub use odoo_api_macros::OdooModel;
+
+// used by the `OdooModel` derive, so that crate users don't need `serde` directly
+#[doc(hidden)]
+pub use serde as __serde;
+
+/// A struct mapped to an Odoo model
+///
+/// This is usually derived, see the [module docs](self).
+pub trait OdooModel: DeserializeOwned {
+    /// The Odoo model name (e.g. `res.partner`)
+    const MODEL: &'static str;
+
+    /// The Odoo field names, passed as the `fields` kwarg to `read`
+    const FIELDS: &'static [&'static str];
+}
+
+/// A type which can be built from a many2one value
+///
+/// Implemented for the id alone (`OdooId`), and for the `(id, display_name)`
+/// pair. The `Option` versions accept `false`; the others return an error.
+pub trait Many2OneField: Sized {
+    /// Build the field from the `(id, display_name)`, or `None` for `false`
+    ///
+    /// Returns `None` if the field can't represent the value.
+    fn from_many2one(value: Option<(OdooId, String)>) -> Option<Self>;
+}
+
+impl Many2OneField for OdooId {
+    fn from_many2one(value: Option<(OdooId, String)>) -> Option<Self> {
+        value.map(|(id, _)| id)
+    }
+}
+
+impl Many2OneField for (OdooId, String) {
+    fn from_many2one(value: Option<(OdooId, String)>) -> Option<Self> {
+        value
+    }
+}
+
+impl<T: Many2OneField> Many2OneField for Option<T> {
+    fn from_many2one(value: Option<(OdooId, String)>) -> Option<Self> {
+        match value {
+            Some(value) => T::from_many2one(Some(value)).map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+/// Deserialize a many2one value into any [`Many2OneField`]
+///
+/// Odoo returns many2one values as `[id, display_name]`, or `false` when the
+/// field is empty. A bare `id` is also accepted (with an empty `display_name`),
+/// as returned by some methods. This is used by `#[odoo(many2one)]`, and can
+/// be used on its own with `#[serde(deserialize_with = "many2one")]`.
+pub fn many2one<'de, D, T>(de: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Many2OneField,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Pair(OdooId, String),
+        Id(OdooId),
+    }
+
+    let value = OdooOption::<Raw>::deserialize(de)?
+        .into_inner()
+        .map(|raw| match raw {
+            Raw::Pair(id, display_name) => (id, display_name),
+            Raw::Id(id) => (id, String::new()),
+        });
+    T::from_many2one(value)
+        .ok_or_else(|| de::Error::invalid_type(de::Unexpected::Bool(false), &"a many2one value"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate as odoo_api;
+    use serde_json::{from_value, json};
+
+    #[derive(Debug, PartialEq, OdooModel)]
+    #[odoo(model = "res.partner")]
+    struct Partner {
+        id: OdooId,
+        name: String,
+        email: Option<String>,
+        r#type: String,
+
+        #[odoo(field = "parent_id", many2one)]
+        parent: Option<(OdooId, String)>,
+
+        #[odoo(many2one)]
+        company_id: OdooId,
+    }
+
+    #[test]
+    fn derive() -> serde_json::Result<()> {
+        assert_eq!(Partner::MODEL, "res.partner");
+        assert_eq!(
+            Partner::FIELDS,
+            &["id", "name", "email", "type", "parent_id", "company_id"]
+        );
+
+        let partner: Partner = from_value(json!({
+            "id": 7,
+            "name": "Bob",
+            "email": false,
+            "type": "contact",
+            "parent_id": [3, "Acme"],
+            "company_id": [1, "My Company"]
+        }))?;
+        assert_eq!(
+            partner,
+            Partner {
+                id: 7,
+                name: "Bob".into(),
+                email: None,
+                r#type: "contact".into(),
+                parent: Some((3, "Acme".into())),
+                company_id: 1,
+            }
+        );
+
+        // `Option` fields may be `false` or missing
+        let partner: Partner = from_value(json!({
+            "id": 3,
+            "name": "Acme",
+            "type": "contact",
+            "parent_id": false,
+            "company_id": 1
+        }))?;
+        assert_eq!(partner.email, None);
+        assert_eq!(partner.parent, None);
+        assert_eq!(partner.company_id, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn many2one_required() {
+        let err = from_value::<Partner>(json!({
+            "id": 3,
+            "name": "Acme",
+            "type": "contact",
+            "parent_id": false,
+            "company_id": false
+        }))
+        .unwrap_err();
+        assert!(err.to_string().contains("a many2one value"), "{}", err);
+    }
+}
@@ -0,0 +1,88 @@
+//! Re-exports of the commonly used types and macros
+//!
+//! For most programs, this single import is enough:
+//! ```no_run
+//! use odoo_api::prelude::*;
+//!
+//! # #[cfg(feature = "async")]
+//! # async fn test() -> Result<()> {
+//! // build the client
+//! let url = "https://odoo.example.com";
+//! let client = OdooClient::new_reqwest_async(url)?;
+//!
+//! // authenticate with `some-database`
+//! let client = client.authenticate(
+//!     "some-database",
+//!     "admin",
+//!     "password",
+//! ).await?;
+//!
+//! // fetch a list of users with the `execute` method
+//! let users = client.execute(
+//!     "res.users",
+//!     "search",
+//!     jvec![
+//!         [["active", "=", true], ["login", "!=", "__system__"]]
+//!     ]
+//! ).send().await?;
+//!
+//! // fetch the login and partner_id fields from the authenticated user
+//! let info = client.execute_kw(
+//!     "res.users",
+//!     "read",
+//!     jvec![[client.uid()]],
+//!     jmap!{
+//!         "fields": ["login", "partner_id"]
+//!     }
+//! ).send().await?;
+//!
+//! // create 2 new partners with the `create` ORM method
+//! let partners: CreateResponse = client.create(
+//!     "res.partner",
+//!     jvec![{
+//!         "name": "Alice",
+//!         "email": "alice@example.com",
+//!         "phone": "555-555-5555",
+//!     }, {
+//!         "name": "Bob",
+//!         "email": "bob@example.com",
+//!         "phone": "555-555-5555",
+//!     }]
+//! ).send().await?;
+//!
+//! // check the current user's access
+//! let can_write = client.check_access_rights(
+//!     "res.partner",
+//!     AccessOperation::Write,
+//!     false, // raise_exception
+//! ).send().await?;
+//!
+//! // fetch a list of databases
+//! let databases = client.db_list(false).send().await?;
+//!
+//! // fetch server version info
+//! let version_info = client.common_version().send().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The client types (e.g., [`OdooClient`](crate::OdooClient), [`Result`]) are
+//! only included with the `client` feature; the remaining types are also
+//! available with `types-only`.
+
+pub use crate::fields::{Command, Commands};
+pub use crate::jsonrpc::{OdooId, OdooIds};
+pub use crate::model::OdooModel;
+pub use crate::serde_helpers::OdooOption;
+pub use crate::service::common::VersionResponse;
+pub use crate::service::object::{ExecuteKwResponse, ExecuteResponse};
+pub use crate::service::orm::{
+    AccessOperation, CreateResponse, NameGetResponse, Operator, ReadResponse, SearchCountResponse,
+    SearchReadResponse, SearchResponse, UnlinkResponse, WriteResponse,
+};
+pub use crate::warning::{HasWarnings, Warning};
+pub use crate::{jmap, jvec, sdomain, svec};
+pub use crate::{OdooVersion, SecretString};
+
+#[cfg(feature = "client")]
+pub use crate::client::{Authed, Error, NotAuthed, OdooClient, OdooRequest, Result};
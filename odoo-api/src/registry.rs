@@ -0,0 +1,107 @@
+//! A runtime list of the supported API methods
+//!
+//! Each method defined with the `#[odoo_api]`, `#[odoo_orm]`, or `#[odoo_web]`
+//! macros (and which has an [`OdooClient`](crate::OdooClient) method) is
+//! registered here, which is useful for building interactive tools:
+//! ```
+//! use odoo_api::registry;
+//!
+//! let method = registry::get("search_read").unwrap();
+//! assert_eq!(method.service, "orm");
+//! assert!(method.kwargs.contains(&"domain"));
+//!
+//! for method in registry::all() {
+//!     println!("{}: {}({})", method.service, method.name, method.args.join(", "));
+//! }
+//! ```
+//!
+//! This module is only available with the `registry` feature.
+
+use std::sync::OnceLock;
+
+#[doc(hidden)]
+pub use inventory;
+
+/// A registered API method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MethodInfo {
+    /// The service (`"object"`, `"db"`, `"common"`, `"orm"`, or `"web"`)
+    pub service: &'static str,
+
+    /// The Odoo method (e.g. `"list"`), or the endpoint path for `"web"` methods
+    pub method: &'static str,
+
+    /// The [`OdooClient`](crate::OdooClient) method name (e.g. `"db_list"`)
+    pub name: &'static str,
+
+    /// Whether the client method requires an authenticated client
+    pub auth: bool,
+
+    /// The positional arguments
+    ///
+    /// For non-ORM methods, these are the client method arguments (skipping
+    /// the credentials which are auto-filled on authed clients).
+    pub args: &'static [&'static str],
+
+    /// The keyword arguments (ORM methods only)
+    pub kwargs: &'static [&'static str],
+}
+
+inventory::collect!(MethodInfo);
+
+/// Fetch all registered methods, sorted by service and name
+pub fn all() -> &'static [&'static MethodInfo] {
+    static ALL: OnceLock<Vec<&'static MethodInfo>> = OnceLock::new();
+    ALL.get_or_init(|| {
+        let mut methods: Vec<_> = inventory::iter::<MethodInfo>.into_iter().collect();
+        methods.sort_by_key(|method| (method.service, method.name));
+        methods
+    })
+}
+
+/// Find a method by its [`OdooClient`](crate::OdooClient) method name
+pub fn get(name: &str) -> Option<&'static MethodInfo> {
+    all().iter().copied().find(|method| method.name == name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registered() {
+        let execute_kw = get("execute_kw").unwrap();
+        assert_eq!(execute_kw.service, "object");
+        assert!(execute_kw.auth);
+        assert_eq!(execute_kw.args, ["model", "method", "args", "kwargs"]);
+        assert!(execute_kw.kwargs.is_empty());
+
+        let search_read = get("search_read").unwrap();
+        assert_eq!(search_read.service, "orm");
+        assert!(search_read.args.is_empty());
+        assert_eq!(
+            search_read.kwargs,
+            ["domain", "fields", "offset", "limit", "order"]
+        );
+
+        let db_list = get("db_list").unwrap();
+        assert_eq!(db_list.service, "db");
+        assert_eq!(db_list.method, "list");
+        assert!(!db_list.auth);
+        assert_eq!(db_list.args, ["document"]);
+
+        // hand-written methods are registered too
+        assert_eq!(get("user_has_group").unwrap().args, ["group"]);
+        assert!(get("does_not_exist").is_none());
+    }
+
+    #[test]
+    fn sorted() {
+        let all = all();
+        assert!(all
+            .windows(2)
+            .all(|pair| (pair[0].service, pair[0].name) <= (pair[1].service, pair[1].name)));
+        assert!(all.iter().any(|method| method.service == "web"));
+        assert!(all.iter().any(|method| method.service == "common"));
+    }
+}
@@ -0,0 +1,99 @@
+//! The [`SecretString`] type
+//!
+//! The request structs generated by `#[odoo_api]`/`#[odoo_orm]`/`#[odoo_web]`
+//! already redact their `password` (and `passwd`, etc) fields from the `Debug`
+//! output. `SecretString` is an alternative for your own types, e.g. a config
+//! struct which holds the Odoo password:
+//! ```
+//! use odoo_api::SecretString;
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Config {
+//!     url: String,
+//!     password: SecretString,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{
+//!     "url": "https://demo.odoo.com",
+//!     "password": "hunter2"
+//! }"#)?;
+//!
+//! assert!(!format!("{:?}", config).contains("hunter2"));
+//! assert_eq!(config.password.expose(), "hunter2");
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A `String` which is redacted from the `Debug` and `Display` output
+///
+/// This (de)serializes as a plain string, so it's still sent to Odoo as-is. Use
+/// [`expose`](Self::expose) to access the value.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Wrap `value`
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Access the secret value
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Convert into the inner `String`
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt("***", f)
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::{from_value, json, to_value};
+
+    #[test]
+    fn redacted() {
+        let secret = SecretString::new("hunter2");
+        assert_eq!(format!("{:?}", secret), "\"***\"");
+        assert_eq!(secret.to_string(), "***");
+        assert_eq!(secret.expose(), "hunter2");
+    }
+
+    #[test]
+    fn serde() -> serde_json::Result<()> {
+        let secret: SecretString = from_value(json!("hunter2"))?;
+        assert_eq!(secret.expose(), "hunter2");
+        assert_eq!(to_value(&secret)?, json!("hunter2"));
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+//! Helpers for deserializing Odoo's "falsy" values
+//!
+//! Odoo (well, Python) doesn't really distinguish between "no value" and `False`,
+//! so unset fields are generally returned as `false` rather than `null` (or
+//! being omitted entirely). For example, a `read` on a partner with no email:
+//! ```json
+//! {"id": 1, "name": "Admin", "email": false}
+//! ```
+//!
+//! This module provides some reusable deserializers to handle this:
+//!  - [`false_as_none`]: Deserialize `false` (or `null`) as `None`
+//!  - [`false_as_empty_vec`]: Deserialize `false` (or `null`) as an empty `Vec`
+//!  - [`OdooOption`]: An `Option<T>` wrapper that treats `false` as `None`
+//!
+//! ## Example
+//! ```
+//! use odoo_api::serde_helpers::{false_as_none, false_as_empty_vec, OdooOption};
+//! use odoo_api::OdooId;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Partner {
+//!     id: OdooId,
+//!     name: String,
+//!
+//!     #[serde(deserialize_with = "false_as_none")]
+//!     email: Option<String>,
+//!
+//!     #[serde(deserialize_with = "false_as_empty_vec")]
+//!     category_id: Vec<OdooId>,
+//!
+//!     parent_id: OdooOption<OdooId>,
+//! }
+//!
+//! let partner: Partner = serde_json::from_str(r#"{
+//!     "id": 3,
+//!     "name": "Admin",
+//!     "email": false,
+//!     "category_id": false,
+//!     "parent_id": false
+//! }"#)?;
+//!
+//! assert_eq!(partner.email, None);
+//! assert!(partner.category_id.is_empty());
+//! assert!(partner.parent_id.is_none());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! **Note**: Because `false` is treated as "no value", these helpers shouldn't
+//! be used with `bool` values (`false` would be deserialized as `None`).
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// Deserialize `false` or `null` as `None`, and any other value as `Some(T)`
+///
+/// Use this with `#[serde(deserialize_with = "false_as_none")]` on an `Option<T>`
+/// field. Add `#[serde(default)]` if the field may also be omitted.
+pub fn false_as_none<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    OdooOption::deserialize(de).map(|value| value.0)
+}
+
+/// Deserialize `false` or `null` as an empty `Vec`, and a list as `Vec<T>`
+///
+/// Use this with `#[serde(deserialize_with = "false_as_empty_vec")]` on a `Vec<T>`
+/// field. Add `#[serde(default)]` if the field may also be omitted.
+pub fn false_as_empty_vec<'de, D, T>(de: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    OdooOption::<Vec<T>>::deserialize(de).map(|value| value.0.unwrap_or_default())
+}
+
+/// An `Option<T>` which deserializes `false` as `None`
+///
+/// Values are serialized as `T`, or `false` for `None` (which is what Odoo
+/// expects when writing an "empty" value).
+///
+/// This type derefs to `Option<T>`, so the usual `Option` methods are available:
+/// ```
+/// use odoo_api::serde_helpers::OdooOption;
+///
+/// let email: OdooOption<String> = serde_json::from_str("false")?;
+/// assert!(email.is_none());
+///
+/// let email: OdooOption<String> = serde_json::from_str(r#""admin@example.com""#)?;
+/// assert_eq!(email.as_deref(), Some("admin@example.com"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OdooOption<T>(pub Option<T>);
+
+impl<T> OdooOption<T> {
+    /// Convert into the inner `Option<T>`
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for OdooOption<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Deref for OdooOption<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OdooOption<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Option<T>> for OdooOption<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<OdooOption<T>> for Option<T> {
+    fn from(value: OdooOption<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T: Serialize> Serialize for OdooOption<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match &self.0 {
+            Some(value) => value.serialize(serializer),
+            None => serializer.serialize_bool(false),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OdooOption<T> {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // `False` is tried first, so that `T` never sees a bare `false`
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum FalseOr<T> {
+            False(False),
+            Value(Option<T>),
+        }
+
+        match FalseOr::deserialize(de)? {
+            FalseOr::False(_) => Ok(Self(None)),
+            FalseOr::Value(value) => Ok(Self(value)),
+        }
+    }
+}
+
+/// A unit type which only deserializes from the literal `false`
+struct False;
+
+impl<'de> Deserialize<'de> for False {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = False;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("the boolean `false`")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if v {
+                    Err(de::Error::invalid_value(de::Unexpected::Bool(v), &self))
+                } else {
+                    Ok(False)
+                }
+            }
+        }
+
+        de.deserialize_bool(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::jsonrpc::OdooId;
+    use serde_json::{from_value, json, to_value};
+
+    #[derive(Debug, Deserialize)]
+    struct Record {
+        #[serde(deserialize_with = "false_as_none")]
+        name: Option<String>,
+
+        #[serde(deserialize_with = "false_as_none")]
+        parent_id: Option<OdooId>,
+
+        #[serde(deserialize_with = "false_as_empty_vec")]
+        child_ids: Vec<OdooId>,
+
+        company_id: OdooOption<OdooId>,
+    }
+
+    #[test]
+    fn false_values() -> serde_json::Result<()> {
+        let record: Record = from_value(json!({
+            "name": false,
+            "parent_id": false,
+            "child_ids": false,
+            "company_id": false
+        }))?;
+
+        assert_eq!(record.name, None);
+        assert_eq!(record.parent_id, None);
+        assert!(record.child_ids.is_empty());
+        assert_eq!(record.company_id, OdooOption(None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn null_values() -> serde_json::Result<()> {
+        let record: Record = from_value(json!({
+            "name": null,
+            "parent_id": null,
+            "child_ids": null,
+            "company_id": null
+        }))?;
+
+        assert_eq!(record.name, None);
+        assert_eq!(record.parent_id, None);
+        assert!(record.child_ids.is_empty());
+        assert_eq!(record.company_id, OdooOption(None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn some_values() -> serde_json::Result<()> {
+        let record: Record = from_value(json!({
+            "name": "Admin",
+            "parent_id": 1,
+            "child_ids": [4, 5],
+            "company_id": 1
+        }))?;
+
+        assert_eq!(record.name.as_deref(), Some("Admin"));
+        assert_eq!(record.parent_id, Some(1));
+        assert_eq!(record.child_ids, vec![4, 5]);
+        assert_eq!(record.company_id, OdooOption(Some(1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn true_is_invalid() {
+        assert!(from_value::<OdooOption<String>>(json!(true)).is_err());
+        assert!(from_value::<OdooOption<OdooId>>(json!(true)).is_err());
+    }
+
+    #[test]
+    fn serialize() -> serde_json::Result<()> {
+        assert_eq!(to_value(OdooOption::<String>(None))?, json!(false));
+        assert_eq!(to_value(OdooOption(Some("Admin")))?, json!("Admin"));
+
+        Ok(())
+    }
+}
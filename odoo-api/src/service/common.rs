@@ -9,9 +9,10 @@
 
 use crate as odoo_api;
 use crate::jsonrpc::{OdooApiMethod, OdooId};
+use crate::serde_helpers::false_as_none;
 use odoo_api_macros::odoo_api;
 use serde::ser::SerializeTuple;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::{Map, Value};
 use serde_tuple::Serialize_tuple;
 
@@ -27,11 +28,11 @@ use serde_tuple::Serialize_tuple;
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // note that auth fields (db, login, password) are auto-filled
 /// // for you by the client
 /// let resp = client.common_login().send()?;
@@ -50,7 +51,7 @@ use serde_tuple::Serialize_tuple;
     name = "common_login",
     auth = true
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct Login {
     /// The database name
     pub db: String,
@@ -63,7 +64,7 @@ pub struct Login {
 }
 
 /// Represents the response to an Odoo [`Login`] call
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct LoginResponse {
     pub uid: OdooId,
@@ -79,11 +80,11 @@ pub struct LoginResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// use odoo_api::jmap;
 ///
 /// // note that auth fields (db, login, password) are auto-filled
@@ -108,7 +109,7 @@ pub struct LoginResponse {
     name = "common_authenticate",
     auth = true
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct Authenticate {
     /// The database name
     pub db: String,
@@ -124,7 +125,7 @@ pub struct Authenticate {
 }
 
 /// Represents the response to an Odoo [`Authenticate`] call
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct AuthenticateResponse {
     pub uid: OdooId,
@@ -142,11 +143,11 @@ pub struct AuthenticateResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.common_version().send()?;
 ///
 /// println!("Version Info: {:#?}", resp);
@@ -164,7 +165,7 @@ pub struct AuthenticateResponse {
     name = "common_version",
     auth = false
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Version {}
 
 // Version has no fields, but needs to output in JSON: `[]`
@@ -179,7 +180,10 @@ impl Serialize for Version {
 }
 
 /// Represents the response to an Odoo [`Version`] call
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// With the `strict-responses` feature, unknown keys are rejected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-responses", serde(deny_unknown_fields))]
 pub struct VersionResponse {
     /// The "pretty" version, normally something like `16.0+e` or `15.0`
     pub server_version: String,
@@ -198,7 +202,7 @@ pub struct VersionResponse {
 ///
 /// See: [odoo/services/common.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/common.py#L12-L17)  
 /// See also: [odoo/release.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/release.py)
-#[derive(Debug, Serialize_tuple, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize_tuple, Deserialize)]
 pub struct ServerVersionInfo {
     /// The "major" version (e.g., `16`)
     pub major: u32,
@@ -216,9 +220,34 @@ pub struct ServerVersionInfo {
     pub serial: u32,
 
     /// A string indicating whether Odoo is running in Enterprise or Community mode; `None` = Community, Some("e") = Enterprise
+    ///
+    /// Community servers may return `""` or `false` here, and very old servers
+    /// (e.g. 8.0) omit the element entirely. All of these are treated as `None`
+    #[serde(default, deserialize_with = "enterprise_deserialize")]
     pub enterprise: Option<String>,
 }
 
+impl ServerVersionInfo {
+    /// Check whether the server is running Odoo Enterprise
+    pub fn is_enterprise(&self) -> bool {
+        self.enterprise.as_deref() == Some("e")
+    }
+
+    /// The `(major, minor)` version, e.g. `(16, 0)`
+    pub fn version(&self) -> (u32, u32) {
+        (self.major, self.minor)
+    }
+}
+
+// Treat `false` and `""` as `None` (Community edition)
+fn enterprise_deserialize<'de, D>(de: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let enterprise: Option<String> = false_as_none(de)?;
+    Ok(enterprise.filter(|e| !e.is_empty()))
+}
+
 /// Fetch basic information about the Odoo version
 ///
 /// Returns a link to the old OpenERP website, and optionally the "basic" Odoo
@@ -229,11 +258,11 @@ pub struct ServerVersionInfo {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.common_about(true).send()?;
 ///
 /// println!("About Info: {:?}", resp);
@@ -250,14 +279,14 @@ pub struct ServerVersionInfo {
     name = "common_about",
     auth = false
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct About {
     pub extended: bool,
 }
 
 //TODO: flat deserializ so we can have either `result: "http://..."` or `result: ["http://..", "14.0+e"]`
 /// Represents the response to an Odoo [`About`] call
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum AboutResponse {
     /// Basic response; includes only the `info` string
@@ -268,7 +297,7 @@ pub enum AboutResponse {
 }
 
 /// Represents the response to an Odoo [`About`] call
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct AboutResponseBasic {
     /// The "info" string
@@ -278,7 +307,7 @@ pub struct AboutResponseBasic {
 }
 
 /// Represents the response to an Odoo [`About`] call
-#[derive(Debug, Serialize_tuple, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize_tuple, Deserialize)]
 pub struct AboutResponseExtended {
     /// The "info" string
     ///
@@ -295,28 +324,16 @@ pub struct AboutResponseExtended {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::client::error::Result;
+    use crate::fixtures;
     use crate::jmap;
     use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::test::Result;
     use serde_json::{from_value, json, to_value};
 
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn login() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "common",
-                "method": "login",
-                "args": [
-                    "some-database",
-                    "admin",
-                    "password",
-                ]
-            }
-        });
+        let expected = fixtures::common::login_request();
         let actual = to_value(
             Login {
                 db: "some-database".into(),
@@ -331,14 +348,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn login_debug_redacted() {
+        let login = Login {
+            db: "some-database".into(),
+            login: "admin".into(),
+            password: "hunter2".into(),
+        };
+        assert_eq!(
+            format!("{:?}", login),
+            r#"Login { db: "some-database", login: "admin", password: "***" }"#
+        );
+    }
+
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn login_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": 2
-        });
+        let payload = fixtures::common::login_response();
 
         let response: JsonRpcResponse<LoginResponse> = from_value(payload)?;
         match response {
@@ -350,23 +376,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn authenticate() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "common",
-                "method": "authenticate",
-                "args": [
-                    "some-database",
-                    "admin",
-                    "password",
-                    {
-                        "base_location": "https://demo.odoo.com"
-                    }
-                ]
-            }
-        });
+        let expected = fixtures::common::authenticate_request();
         let actual = to_value(
             Authenticate {
                 db: "some-database".into(),
@@ -387,11 +397,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn authenticate_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": 2
-        });
+        let payload = fixtures::common::authenticate_response();
 
         let response: JsonRpcResponse<AuthenticateResponse> = from_value(payload)?;
         match response {
@@ -403,16 +409,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn version() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "common",
-                "method": "version",
-                "args": []
-            }
-        });
+        let expected = fixtures::common::version_request();
         let actual = to_value(Version {}.build(1000))?;
 
         assert_eq!(actual, expected);
@@ -423,23 +420,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn version_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": {
-                "server_version": "14.0+e",
-                "server_version_info": [
-                    14,
-                    0,
-                    0,
-                    "final",
-                    0,
-                    "e"
-                ],
-                "server_serie": "14.0",
-                "protocol_version": 1
-            }
-        });
+        let payload = fixtures::common::version_response_v14_enterprise();
 
         let response: JsonRpcResponse<VersionResponse> = from_value(payload)?;
         match response {
@@ -448,21 +429,97 @@ mod test {
         }
     }
 
+    /// Unknown keys are ignored, or rejected with `strict-responses`
+    #[test]
+    fn version_response_extra_key() {
+        let result = from_value::<VersionResponse>(json!({
+            "server_version": "17.0",
+            "server_version_info": [17, 0, 0, "final", 0, ""],
+            "server_serie": "17.0",
+            "protocol_version": 1,
+            "build": "20240101"
+        }));
+        if cfg!(feature = "strict-responses") {
+            let err = result.unwrap_err().to_string();
+            assert!(err.starts_with("unknown field `build`"), "{}", err);
+        } else {
+            assert_eq!(result.unwrap().server_serie, "17.0");
+        }
+    }
+
+    /// Stock Community servers return `""` for the enterprise flag
+    #[test]
+    fn version_response_community_empty() -> Result<()> {
+        let payload = fixtures::common::version_response_v16();
+
+        let response: JsonRpcResponse<VersionResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                let info = data.result.server_version_info;
+                assert_eq!(info.enterprise, None);
+                assert!(!info.is_enterprise());
+                assert_eq!(info.version(), (16, 0));
+                Ok(())
+            }
+        }
+    }
+
+    /// Enterprise servers return `"e"` for the enterprise flag
+    #[test]
+    fn version_response_enterprise() -> Result<()> {
+        let payload = fixtures::common::version_response_v16_enterprise();
+
+        let response: JsonRpcResponse<VersionResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                let info = data.result.server_version_info;
+                assert!(info.is_enterprise());
+                assert_eq!(info.version(), (16, 0));
+                Ok(())
+            }
+        }
+    }
+
+    /// Older servers (e.g. 8.0) omit the enterprise flag entirely
+    #[test]
+    fn version_response_legacy() -> Result<()> {
+        let payload = fixtures::common::version_response_v8();
+
+        let response: JsonRpcResponse<VersionResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                let info = data.result.server_version_info;
+                assert_eq!(info.enterprise, None);
+                assert!(!info.is_enterprise());
+                assert_eq!(info.version(), (8, 0));
+                Ok(())
+            }
+        }
+    }
+
+    /// Community edition servers may return `false` for the enterprise flag
+    #[test]
+    fn version_response_community() -> Result<()> {
+        let payload = fixtures::common::version_response_v14();
+
+        let response: JsonRpcResponse<VersionResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.server_version_info.major, 14);
+                assert_eq!(data.result.server_version_info.enterprise, None);
+                Ok(())
+            }
+        }
+    }
+
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn about_basic() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "common",
-                "method": "about",
-                "args": [
-                    false
-                ]
-            }
-        });
+        let expected = fixtures::common::about_basic_request();
         let actual = to_value(About { extended: false }.build(1000))?;
 
         assert_eq!(actual, expected);
@@ -473,11 +530,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn about_basic_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": "See http://openerp.com"
-        });
+        let payload = fixtures::common::about_basic_response();
 
         let response: JsonRpcResponse<AboutResponse> = from_value(payload)?;
         match response {
@@ -494,18 +547,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn about_extended() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "common",
-                "method": "about",
-                "args": [
-                    true
-                ]
-            }
-        });
+        let expected = fixtures::common::about_extended_request();
         let actual = to_value(About { extended: true }.build(1000))?;
 
         assert_eq!(actual, expected);
@@ -516,14 +558,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn about_extended_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                "See http://openerp.com",
-                "14.0+e"
-            ]
-        });
+        let payload = fixtures::common::about_extended_response();
 
         let response: JsonRpcResponse<AboutResponse> = from_value(payload)?;
         match response {
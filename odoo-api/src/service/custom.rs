@@ -0,0 +1,217 @@
+//! Custom JSON-RPC services
+//!
+//! Odoo modules may register their own services with the JSON-RPC dispatcher.
+//! These can be called with [`ApiCall`], without defining a new method type.
+
+use crate::jsonrpc::{JsonRpcId, JsonRpcParams, JsonRpcRequest, OdooApiContainer, OdooApiMethod};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Call a method on any JSON-RPC service
+///
+/// This builds the usual `/jsonrpc` envelope (`{"service": ..., "method": ...,
+/// "args": ...}`), sending `args` as-is, and deserializes the `result` into
+/// `R`. As the caller controls the args, no auth info is added.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # use serde_json::Value;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// use serde_json::json;
+///
+/// let resp: Value = client.api_call(
+///     "my_service",
+///     "do_thing",
+///     json!(["some-database", 42])
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ApiCall<R> {
+    /// The service name (e.g. `my_service`)
+    pub service: String,
+
+    /// The method name (e.g. `do_thing`)
+    pub method: String,
+
+    /// The positional args
+    pub args: Value,
+
+    _response: PhantomData<fn() -> R>,
+}
+
+// derived `Clone` would require `R: Clone`
+impl<R> Clone for ApiCall<R> {
+    fn clone(&self) -> Self {
+        Self {
+            service: self.service.clone(),
+            method: self.method.clone(),
+            args: self.args.clone(),
+            _response: PhantomData,
+        }
+    }
+}
+
+impl<R> ApiCall<R> {
+    pub fn new(service: &str, method: &str, args: Value) -> Self {
+        Self {
+            service: service.into(),
+            method: method.into(),
+            args,
+            _response: PhantomData,
+        }
+    }
+}
+
+// only the args are serialized here; the container adds the service and method
+impl<R> Serialize for ApiCall<R> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.args.serialize(serializer)
+    }
+}
+
+impl<R> JsonRpcParams for ApiCall<R>
+where
+    R: Debug + DeserializeOwned,
+{
+    type Container<T> = OdooApiContainer<Self>;
+    type Response = R;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl<R> OdooApiMethod for ApiCall<R>
+where
+    R: Debug + DeserializeOwned,
+{
+    fn describe(&self) -> (&str, &str) {
+        (&self.service, &self.method)
+    }
+
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+}
+
+#[cfg(feature = "client")]
+impl<S, I> crate::client::OdooClient<S, I>
+where
+    S: crate::client::AuthState,
+    I: crate::client::RequestImpl,
+{
+    /// Call a method on any JSON-RPC service
+    ///
+    /// See [`ApiCall`] for more info.
+    pub fn api_call<R>(
+        &self,
+        service: &str,
+        method: &str,
+        args: Value,
+    ) -> crate::client::OdooRequest<'_, ApiCall<R>, I>
+    where
+        R: Debug + DeserializeOwned,
+    {
+        let api_call = ApiCall::new(service, method, args);
+
+        let endpoint = self.build_endpoint(api_call.endpoint());
+        self.build_request(api_call, &endpoint)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::service::test::Result;
+    use serde_json::{json, to_value};
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn api_call() -> Result<()> {
+        let expected = json!({
+            "jsonrpc": "2.0",
+            "method": "call",
+            "id": 1000,
+            "params": {
+                "service": "my_service",
+                "method": "do_thing",
+                "args": ["some-database", 42, {"dry_run": true}]
+            }
+        });
+        let call: ApiCall<Value> = ApiCall::new(
+            "my_service",
+            "do_thing",
+            json!(["some-database", 42, {"dry_run": true}]),
+        );
+        assert_eq!(call.describe(), ("my_service", "do_thing"));
+        let actual = to_value(call.build(1000))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn api_call_client() -> std::result::Result<(), crate::client::error::ClosureError> {
+        use crate::client::OdooClient;
+        use serde::Deserialize;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[derive(Debug, Deserialize)]
+        struct ThingResponse {
+            done: bool,
+            count: u32,
+        }
+
+        let captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069/",
+            move |url, data, _session_id| {
+                inner.borrow_mut().push((url.to_string(), data));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {"done": true, "count": 3}})
+                        .to_string(),
+                    None,
+                ))
+            },
+        );
+
+        // not-authed clients can make the call
+        let resp: ThingResponse = client
+            .api_call("my_service", "do_thing", json!(["some-database"]))
+            .send()?;
+        assert!(resp.done);
+        assert_eq!(resp.count, 3);
+
+        {
+            let (url, body) = &captured.borrow()[0];
+            assert_eq!(url, "http://localhost:8069/jsonrpc");
+            assert_eq!(body["params"]["service"], json!("my_service"));
+            assert_eq!(body["params"]["method"], json!("do_thing"));
+            assert_eq!(body["params"]["args"], json!(["some-database"]));
+        }
+
+        // and so can authed clients, without any auth info added
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        let _: Value = client
+            .api_call("my_service", "do_thing", json!([]))
+            .send()?;
+        assert_eq!(captured.borrow()[1].1["params"]["args"], json!([]));
+
+        Ok(())
+    }
+}
@@ -7,8 +7,12 @@
 //! "master password", which can be found in the Odoo `.conf` file as the `admin_passwd` key.
 
 use crate as odoo_api;
+#[cfg(feature = "client")]
+use crate::client::error::ParseResponseError;
+#[cfg(feature = "client")]
+use crate::jsonrpc::response::JsonRpcErrorData;
 use crate::jsonrpc::OdooApiMethod;
-use odoo_api_macros::odoo_api;
+use odoo_api_macros::{odoo_api, SerializeTuple};
 use serde::de::Visitor;
 use serde::ser::SerializeTuple;
 use serde::{Deserialize, Serialize};
@@ -21,11 +25,11 @@ use serde_tuple::Serialize_tuple;
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_create_database(
 ///     "master-password",
 ///     "new-database-name",
@@ -48,7 +52,8 @@ use serde_tuple::Serialize_tuple;
     name = "db_create_database",
     auth = false
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, SerializeTuple)]
+#[serialize_tuple(skip_trailing_none)]
 pub struct CreateDatabase {
     /// The Odoo master password
     pub passwd: String,
@@ -88,7 +93,7 @@ pub struct CreateDatabase {
 }
 
 /// The response to a [`CreateDatabase`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CreateDatabaseResponse {
     pub ok: bool,
@@ -101,11 +106,11 @@ pub struct CreateDatabaseResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_duplicate_database(
 ///     "master-password",
 ///     "old-database",
@@ -123,7 +128,7 @@ pub struct CreateDatabaseResponse {
     name = "db_duplicate_database",
     auth = false
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct DuplicateDatabase {
     /// The Odoo master password
     pub passwd: String,
@@ -136,7 +141,7 @@ pub struct DuplicateDatabase {
 }
 
 /// The response to a [`DuplicateDatabase`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct DuplicateDatabaseResponse {
     pub ok: bool,
@@ -149,11 +154,11 @@ pub struct DuplicateDatabaseResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_drop(
 ///     "master-password",
 ///     "database-to-delete",
@@ -165,7 +170,7 @@ pub struct DuplicateDatabaseResponse {
 ///
 /// Reference: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L212-L217)
 #[odoo_api(service = "db", method = "drop", name = "db_drop", auth = false)]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct Drop {
     /// The Odoo master password
     pub passwd: String,
@@ -175,7 +180,7 @@ pub struct Drop {
 }
 
 /// The response to a [`Drop`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct DropResponse {
     pub ok: bool,
@@ -190,11 +195,11 @@ pub struct DropResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// # #[allow(non_camel_case_types)]
 /// # struct base64 {}
 /// # impl base64 { fn decode(input: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> { Ok(Vec::new()) }}
@@ -219,7 +224,7 @@ pub struct DropResponse {
 /// Reference: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L212-L217)  
 /// See also: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L219-L269)
 #[odoo_api(service = "db", method = "dump", name = "db_dump", auth = false)]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct Dump {
     /// The Odoo master password
     pub passwd: String,
@@ -232,7 +237,7 @@ pub struct Dump {
 }
 
 /// The format for a database dump
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DumpFormat {
     /// Output a zipfile containing the SQL dump in "plain" format, manifest, and filestore
     ///
@@ -261,14 +266,14 @@ pub enum DumpFormat {
 }
 
 /// The response to a [`Dump`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct DumpResponse {
     /// The database dump, as a base-64 encoded string
     ///
     /// Note that the file type will depend on the `format` used in the original request:
-    /// - [`DumpFormat::Zip`]: `backup.zip`
-    /// - [`DumpFormat::Dump`]: `backup.dump` (text file containig SQL CREATE/INSERT/etc statements )
+    /// - [`DumpFormat::Zip`] - `backup.zip`
+    /// - [`DumpFormat::Dump`] - `backup.dump` (text file containig SQL CREATE/INSERT/etc statements )
     pub b64_bytes: String,
 }
 
@@ -287,11 +292,11 @@ pub struct DumpResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// # #[allow(non_camel_case_types)]
 /// # struct base64 {}
 /// # impl base64 { fn encode(data: &Vec<u8>) -> Result<Vec<u8>, Box<dyn std::error::Error>> { Ok(data.to_owned()) }}
@@ -308,9 +313,10 @@ pub struct DumpResponse {
 /// // convert base64's `Vec<u8>` to a `&str`
 /// let data_b64 = std::str::from_utf8(&data_b64)?;
 ///
-/// // read `id` and `login` from users id=1,2,3
+/// // restore the backup as a new database
 /// client.db_restore(
 ///     "master-password",
+///     "restored-database",
 ///     data_b64,
 ///     RestoreType::Copy
 /// ).send()?;
@@ -322,11 +328,14 @@ pub struct DumpResponse {
 /// Reference: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L271-L284)  
 /// See also: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L286-L335)
 #[odoo_api(service = "db", method = "restore", name = "db_restore", auth = false)]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct Restore {
     /// The Odoo master password
     pub passwd: String,
 
+    /// The name of the new database
+    pub db_name: String,
+
     /// The backup data, as a base64-encoded string
     pub b64_data: String,
 
@@ -335,7 +344,7 @@ pub struct Restore {
 }
 
 /// The type of database restore
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum RestoreType {
     /// Restore as a "copy"
     ///
@@ -399,7 +408,7 @@ impl<'de> Deserialize<'de> for RestoreType {
 }
 
 /// The response to a [`Restore`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct RestoreResponse {
     pub ok: bool,
@@ -417,11 +426,11 @@ pub struct RestoreResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_rename(
 ///     "master-password",
 ///     "old-database-name",
@@ -434,7 +443,7 @@ pub struct RestoreResponse {
 ///
 /// Reference: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L337-L358)
 #[odoo_api(service = "db", method = "rename", name = "db_rename", auth = false)]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct Rename {
     /// The Odoo master password
     pub passwd: String,
@@ -447,7 +456,7 @@ pub struct Rename {
 }
 
 /// The response to a [`Rename`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct RenameResponse {
     pub ok: bool,
@@ -460,11 +469,11 @@ pub struct RenameResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_change_admin_password(
 ///     "master-password",
 ///     "new-master-password",
@@ -481,7 +490,7 @@ pub struct RenameResponse {
     name = "db_change_admin_password",
     auth = false
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct ChangeAdminPassword {
     /// The Odoo master password
     pub passwd: String,
@@ -491,7 +500,7 @@ pub struct ChangeAdminPassword {
 }
 
 /// The response to a [`ChangeAdminPassword`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ChangeAdminPasswordResponse {
     pub ok: bool,
@@ -509,11 +518,11 @@ pub struct ChangeAdminPasswordResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_migrate_databases(
 ///     "master-password",
 ///     vec![
@@ -533,7 +542,7 @@ pub struct ChangeAdminPasswordResponse {
     name = "db_migrate_databases",
     auth = false
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct MigrateDatabases {
     /// The Odoo master password
     pub passwd: String,
@@ -543,7 +552,7 @@ pub struct MigrateDatabases {
 }
 
 /// The response to a [`MigrateDatabases`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct MigrateDatabasesResponse {
     pub ok: bool,
@@ -553,11 +562,11 @@ pub struct MigrateDatabasesResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_exist(
 ///     "does-this-database-exist?",
 /// ).send()?;
@@ -568,14 +577,14 @@ pub struct MigrateDatabasesResponse {
 ///
 /// Reference: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L378-L386)
 #[odoo_api(service = "db", method = "db_exist", auth = false)]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct DbExist {
     /// The database name to check
     pub db_name: String,
 }
 
 /// The response to a [`DbExist`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct DbExistResponse {
     pub exists: bool,
@@ -585,11 +594,11 @@ pub struct DbExistResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_list(false).send()?;
 ///
 /// println!("Databases: {:#?}", resp.databases);
@@ -600,15 +609,31 @@ pub struct DbExistResponse {
 ///
 /// Reference: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L439-L442)  
 /// See also: [odoo/service/db.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/db.py#L388-L409)
-#[odoo_api(service = "db", method = "list", name = "db_list", auth = false)]
-#[derive(Debug, Serialize_tuple)]
+#[odoo_api(
+    service = "db",
+    method = "list",
+    name = "db_list",
+    auth = false,
+    map_error = "list_error"
+)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct List {
     /// This argument isn't currently used and has no effect on the output
     pub document: bool,
 }
 
+// `db.list` raises a bare `AccessDenied` when the server is running with
+// `list_db = False`
+#[cfg(feature = "client")]
+fn list_error(_list: &List, error: &JsonRpcErrorData) -> Option<ParseResponseError> {
+    error
+        .name
+        .ends_with("exceptions.AccessDenied")
+        .then_some(ParseResponseError::DbListingDisabled)
+}
+
 /// The response to a [`List`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ListResponse {
     pub databases: Vec<String>,
@@ -621,11 +646,11 @@ pub struct ListResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_list_lang().send()?;
 ///
 /// println!("Languages: {:#?}", resp.languages);
@@ -641,7 +666,7 @@ pub struct ListResponse {
     name = "db_list_lang",
     auth = false
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ListLang {}
 
 // ListLang has no fields, but needs to output in JSON: `[]`
@@ -656,14 +681,17 @@ impl Serialize for ListLang {
 }
 
 /// The response to a [`ListLang`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ListLangResponse {
     pub languages: Vec<ListLangResponseItem>,
 }
 
 /// A single language item from the [`ListLang`] request
-#[derive(Debug, Serialize_tuple, Deserialize)]
+///
+/// This is sent as a `[code, name]` pair. Any extra elements are ignored (or
+/// rejected, with the `strict-responses` feature).
+#[derive(Debug, Clone, PartialEq, Serialize_tuple)]
 pub struct ListLangResponseItem {
     /// The ISO language code (e.g., `en_GB`)
     pub code: String,
@@ -679,6 +707,48 @@ pub struct ListLangResponseItem {
     pub name: String,
 }
 
+impl<'de> Deserialize<'de> for ListLangResponseItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ItemVisitor;
+
+        impl<'de> Visitor<'de> for ItemVisitor {
+            type Value = ListLangResponseItem;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a `[code, name]` pair")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let code = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let name = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                let mut extra = 0;
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    extra += 1;
+                }
+                if cfg!(feature = "strict-responses") && extra > 0 {
+                    return Err(serde::de::Error::custom(format!(
+                        "unknown field at index 2 ({} extra element(s), expected `[code, name]`)",
+                        extra
+                    )));
+                }
+                Ok(ListLangResponseItem { code, name })
+            }
+        }
+
+        deserializer.deserialize_seq(ItemVisitor)
+    }
+}
+
 /// List the countries available to Odoo (ISO name + code)
 ///
 /// Note that this function is used by the database manager, in order to let the
@@ -686,11 +756,11 @@ pub struct ListLangResponseItem {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_list_countries(
 ///     "master-password",
 /// ).send()?;
@@ -708,21 +778,21 @@ pub struct ListLangResponseItem {
     name = "db_list_countries",
     auth = false
 )]
-#[derive(Debug, Serialize_tuple)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct ListCountries {
     /// The Odoo master password
     pub passwd: String,
 }
 
 /// The response to a [`ListCountries`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ListCountriesResponse {
     pub countries: Vec<ListLangResponseItem>,
 }
 
 /// A single country item from the [`ListCountries`] request
-#[derive(Debug, Serialize_tuple, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize_tuple, Deserialize)]
 pub struct ListCountriesResponseItem {
     /// The ISO country code
     pub code: String,
@@ -741,11 +811,11 @@ pub struct ListCountriesResponseItem {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// let resp = client.db_server_version().send()?;
 ///
 /// println!("Version: {}", resp.version);
@@ -761,7 +831,7 @@ pub struct ListCountriesResponseItem {
     name = "db_server_version",
     auth = false
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ServerVersion {}
 
 // ServerVersion has no fields, but needs to output in JSON: `[]`
@@ -776,7 +846,7 @@ impl Serialize for ServerVersion {
 }
 
 /// The response to a [`ServerVersion`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ServerVersionResponse {
     /// The database version, e.g., `14.0` or `15.0`
@@ -786,32 +856,15 @@ pub struct ServerVersionResponse {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::client::error::Result;
+    use crate::fixtures;
     use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::test::Result;
     use serde_json::{from_value, json, to_value};
 
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn create_database() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "create_database",
-                "args": [
-                    "master-password",
-                    "new-database",
-                    false,
-                    "en_US",
-                    "password",
-                    "admin",
-                    null,
-                    "123 123 123"
-                ]
-            }
-        });
+        let expected = fixtures::db::create_database_request();
         let actual = to_value(
             CreateDatabase {
                 passwd: "master-password".into(),
@@ -831,14 +884,61 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn create_database_debug_redacted() {
+        let create = CreateDatabase {
+            passwd: "master-hunter2".into(),
+            db_name: "new-database".into(),
+            demo: false,
+            lang: "en_US".into(),
+            user_password: "admin-hunter2".into(),
+            login: "admin".into(),
+            country_code: None,
+            phone: None,
+        };
+        let debug = format!("{:?}", create);
+        assert!(debug.contains("passwd: \"***\""), "{}", debug);
+        assert!(debug.contains("user_password: \"***\""), "{}", debug);
+        assert!(debug.contains("db_name: \"new-database\""), "{}", debug);
+        assert!(!debug.contains("hunter2"), "{}", debug);
+    }
+
+    #[test]
+    fn create_database_trailing_none() -> Result<()> {
+        let actual = to_value(
+            CreateDatabase {
+                passwd: "master-password".into(),
+                db_name: "new-database".into(),
+                demo: false,
+                lang: "en_US".into(),
+                user_password: "password".into(),
+                login: "admin".into(),
+                country_code: None,
+                phone: None,
+            }
+            .build(1000),
+        )?;
+
+        // trailing `None`s are omitted, so Odoo uses its defaults
+        assert_eq!(
+            actual["params"]["args"],
+            json!([
+                "master-password",
+                "new-database",
+                false,
+                "en_US",
+                "password",
+                "admin"
+            ])
+        );
+
+        Ok(())
+    }
+
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn create_database_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::create_database_response();
 
         let response: JsonRpcResponse<CreateDatabaseResponse> = from_value(payload)?;
         match response {
@@ -850,20 +950,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn duplicate_database() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "duplicate_database",
-                "args": [
-                    "master-password",
-                    "old-database",
-                    "new-database",
-                ]
-            }
-        });
+        let expected = fixtures::db::duplicate_database_request();
         let actual = to_value(
             DuplicateDatabase {
                 passwd: "master-password".into(),
@@ -881,11 +968,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn duplicate_database_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::duplicate_database_response();
 
         let response: JsonRpcResponse<DuplicateDatabaseResponse> = from_value(payload)?;
         match response {
@@ -897,19 +980,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn drop() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "drop",
-                "args": [
-                    "master-password",
-                    "old-database",
-                ]
-            }
-        });
+        let expected = fixtures::db::drop_request();
         let actual = to_value(
             Drop {
                 passwd: "master-password".into(),
@@ -926,11 +997,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn drop_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::drop_response();
 
         let response: JsonRpcResponse<DropResponse> = from_value(payload)?;
         match response {
@@ -942,20 +1009,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn dump_zip() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "dump",
-                "args": [
-                    "master-password",
-                    "old-database",
-                    "zip",
-                ]
-            }
-        });
+        let expected = fixtures::db::dump_zip_request();
         let actual = to_value(
             Dump {
                 passwd: "master-password".into(),
@@ -973,20 +1027,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn dump_dump() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "dump",
-                "args": [
-                    "master-password",
-                    "old-database",
-                    "dump",
-                ]
-            }
-        });
+        let expected = fixtures::db::dump_dump_request();
         let actual = to_value(
             Dump {
                 passwd: "master-password".into(),
@@ -1004,11 +1045,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn dump_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": "base64-data-will-be-here"
-        });
+        let payload = fixtures::db::dump_response();
 
         let response: JsonRpcResponse<DumpResponse> = from_value(payload)?;
         match response {
@@ -1020,23 +1057,11 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn restore_move() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "restore",
-                "args": [
-                    "master-password",
-                    "base64-data-would-be-here",
-                    false,
-                ]
-            }
-        });
+        let expected = fixtures::db::restore_move_request();
         let actual = to_value(
             Restore {
                 passwd: "master-password".into(),
+                db_name: "restored-database".into(),
                 b64_data: "base64-data-would-be-here".into(),
                 restore_type: RestoreType::Move,
             }
@@ -1051,23 +1076,11 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn restore_copy() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "restore",
-                "args": [
-                    "master-password",
-                    "base64-data-would-be-here",
-                    true,
-                ]
-            }
-        });
+        let expected = fixtures::db::restore_copy_request();
         let actual = to_value(
             Restore {
                 passwd: "master-password".into(),
+                db_name: "restored-database".into(),
                 b64_data: "base64-data-would-be-here".into(),
                 restore_type: RestoreType::Copy,
             }
@@ -1082,11 +1095,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn restore_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::restore_response();
 
         let response: JsonRpcResponse<RestoreResponse> = from_value(payload)?;
         match response {
@@ -1098,20 +1107,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn rename() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "rename",
-                "args": [
-                    "master-password",
-                    "old-database",
-                    "new-database"
-                ]
-            }
-        });
+        let expected = fixtures::db::rename_request();
         let actual = to_value(
             Rename {
                 passwd: "master-password".into(),
@@ -1129,11 +1125,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn rename_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::rename_response();
 
         let response: JsonRpcResponse<RenameResponse> = from_value(payload)?;
         match response {
@@ -1145,19 +1137,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn change_admin_password() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "change_admin_password",
-                "args": [
-                    "master-password",
-                    "new-master-password",
-                ]
-            }
-        });
+        let expected = fixtures::db::change_admin_password_request();
         let actual = to_value(
             ChangeAdminPassword {
                 passwd: "master-password".into(),
@@ -1174,11 +1154,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn change_admin_password_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::change_admin_password_response();
 
         let response: JsonRpcResponse<ChangeAdminPasswordResponse> = from_value(payload)?;
         match response {
@@ -1190,22 +1166,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn migrate_databases() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "migrate_databases",
-                "args": [
-                    "master-password",
-                    [
-                        "new-database",
-                        "new-database2",
-                    ]
-                ]
-            }
-        });
+        let expected = fixtures::db::migrate_databases_request();
         let actual = to_value(
             MigrateDatabases {
                 passwd: "master-password".into(),
@@ -1222,11 +1183,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn migrate_databases_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::migrate_databases_response();
 
         let response: JsonRpcResponse<MigrateDatabasesResponse> = from_value(payload)?;
         match response {
@@ -1238,18 +1195,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn db_exist() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "db_exist",
-                "args": [
-                    "new-database"
-                ]
-            }
-        });
+        let expected = fixtures::db::db_exist_request();
         let actual = to_value(
             DbExist {
                 db_name: "new-database".into(),
@@ -1265,11 +1211,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn db_exist_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+        let payload = fixtures::db::db_exist_response();
 
         let response: JsonRpcResponse<DbExistResponse> = from_value(payload)?;
         match response {
@@ -1281,18 +1223,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn list() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "list",
-                "args": [
-                    false
-                ]
-            }
-        });
+        let expected = fixtures::db::list_request();
         let actual = to_value(List { document: false }.build(1000))?;
 
         assert_eq!(actual, expected);
@@ -1303,15 +1234,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn list_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                "old-database",
-                "new-database",
-                "new-database2"
-            ]
-        });
+        let payload = fixtures::db::list_response();
 
         let response: JsonRpcResponse<ListResponse> = from_value(payload)?;
         match response {
@@ -1323,16 +1246,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn list_lang() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "list_lang",
-                "args": []
-            }
-        });
+        let expected = fixtures::db::list_lang_request();
         let actual = to_value(ListLang {}.build(1000))?;
 
         assert_eq!(actual, expected);
@@ -1341,27 +1255,29 @@ mod test {
     }
 
     /// See [`crate::service::object::test::execute_response`] for more info
+    /// Extra elements are ignored, or rejected with `strict-responses`
+    #[test]
+    fn list_lang_response_extra_element() {
+        let result = from_value::<ListLangResponse>(json!([
+            ["en_GB", "English (UK)"],
+            ["da_DK", "Danish / Dansk", "da"]
+        ]));
+        if cfg!(feature = "strict-responses") {
+            let err = result.unwrap_err().to_string();
+            assert!(err.starts_with("unknown field at index 2"), "{}", err);
+        } else {
+            let languages = result.unwrap().languages;
+            assert_eq!(languages[1].code, "da_DK");
+            assert_eq!(languages[1].name, "Danish / Dansk");
+        }
+
+        // too few elements is always an error
+        assert!(from_value::<ListLangResponse>(json!([["en_GB"]])).is_err());
+    }
+
     #[test]
     fn list_lang_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                [
-                    "sq_AL",
-                    "Albanian / Shqip"
-                ],
-                [
-                    "am_ET",
-                    "Amharic / አምሃርኛ"
-                ],
-                [
-                    "ar_SY",
-                    "Arabic (Syria) / الْعَرَبيّة"
-                ],
-                // snipped for brevity
-            ]
-        });
+        let payload = fixtures::db::list_lang_response();
 
         let response: JsonRpcResponse<ListLangResponse> = from_value(payload)?;
         match response {
@@ -1373,18 +1289,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn list_countries() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "list_countries",
-                "args": [
-                    "master-password"
-                ]
-            }
-        });
+        let expected = fixtures::db::list_countries_request();
         let actual = to_value(
             ListCountries {
                 passwd: "master-password".into(),
@@ -1400,25 +1305,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn list_countries_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                [
-                    "af",
-                    "Afghanistan"
-                ],
-                [
-                    "al",
-                    "Albania"
-                ],
-                [
-                    "dz",
-                    "Algeria"
-                ],
-                // snipped for brevity
-            ]
-        });
+        let payload = fixtures::db::list_countries_response();
 
         let response: JsonRpcResponse<ListCountriesResponse> = from_value(payload)?;
         match response {
@@ -1430,16 +1317,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn server_version() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "db",
-                "method": "server_version",
-                "args": []
-            }
-        });
+        let expected = fixtures::db::server_version_request();
         let actual = to_value(ServerVersion {}.build(1000))?;
 
         assert_eq!(actual, expected);
@@ -1450,11 +1328,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn server_version_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": "14.0+e"
-        });
+        let payload = fixtures::db::server_version_response();
 
         let response: JsonRpcResponse<ServerVersionResponse> = from_value(payload)?;
         match response {
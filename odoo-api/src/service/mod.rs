@@ -16,6 +16,7 @@
 //! |-|-|-|
 //! |[`execute`](object::Execute)|Call a business-logic method on an Odoo model (positional args)|**Yes**|
 //! |[`execute_kw`](object::ExecuteKw)|Call a business-logic method on an Odoo model (positional & keyword args)|**Yes**|
+//! |[`exec_workflow`](object::ExecWorkflow)|Trigger a workflow signal on a record (legacy, pre-11.0)|**Yes**|
 //!
 //! <br />
 //!
@@ -27,6 +28,7 @@
 //! |-|-|-|
 //! |[`create`](orm::Create)|Create a new record (or set of records)|**Yes**|
 //! |[`read`](orm::Read)|Read data from a record (or set of records)|**Yes**|
+//! |[`read_model`](orm::ReadModel)|Read records into an `OdooModel` struct|**Yes**|
 //! |[`read_group`](orm::ReadGroup)|Read some grouped data from a record (or set of records)|**Yes**|
 //! |[`write`](orm::Write)|Write data to a record (or set of records)|**Yes**|
 //! |[`unlink`](orm::Unlink)|Delete a record (or set of records)|**Yes**|
@@ -38,12 +40,15 @@
 //! |[`check_access_rights`](orm::CheckAccessRights)|Check model access rights (according to `ir.model.access`)|**Yes**|
 //! |[`check_access_rules`](orm::CheckAccessRules)|Check model access rules (according to `ir.rule`)|**Yes**|
 //! |[`check_field_access_rights`](orm::CheckFieldAccessRights)|Check the user access rights on the given fields|**Yes**|
+//! |[`fields_get`](orm::FieldsGet)|Return the definition of each field on a model|**Yes**|
 //! |[`get_metadata`](orm::GetExternalId)|Return some metadata about the given record(s)|**Yes**|
 //! |[`get_external_id`](orm::GetMetadata)|Fetch the XMLID for the given record(s)|**Yes**|
 //! |[`get_xml_id`](orm::GetXmlId)|Fetch the XMLID for the given record(s)|**Yes**|
 //! |[`name_get`](orm::NameGet)|Fetch the `display_naame` for the given record(s)|**Yes**|
 //! |[`name_create`](orm::NameCreate)|Create a new record, passing only the `name` field|**Yes**|
 //! |[`name_search`](orm::NameSearch)|Search for records based on their `name` field|**Yes**|
+//! |[`message_post`](orm::MessagePost)|Post a message in a record's chatter|**Yes**|
+//! |[`activity_schedule`](orm::ActivitySchedule)|Schedule an activity on a record (or set of records)|**Yes**|
 //!
 //! <br />
 //!
@@ -78,15 +83,189 @@
 //!
 //! <br />
 //!
+//! <span style="font-size: 1.125rem; margin: 15px 0 5px 0;">[`report`](crate::service::report)</span>
+//!
+//! |<div style="width: 250px">Method</div>|<div style="width: 550px">Description</div>|<div style="width: 50px">Auth?</div>|
+//! |-|-|-|
+//! |[`render_report`](report::RenderReport)|Render a report for the given record(s) (legacy, pre-11.0)|**Yes**|
+//!
+//! <br />
+//!
 //! <span style="font-size: 1.125rem; margin: 15px 0 5px 0;">[`web`](crate::service::web)</span>
 //!
 //! |<div style="width: 250px">Method</div>|<div style="width: 550px">Description</div>|<div style="width: 50px">Auth?</div>|
 //! |-|-|-|
 //! |[`web_session_authenticate`](web::SessionAuthenticate)|Docs TBC|-|
+//! |[`dataset_call_button`](web::DatasetCallButton)|Call a button method the way the webclient does|**Yes**|
+//!
+//! <br />
+//!
+//! <span style="font-size: 1.125rem; margin: 15px 0 5px 0;">[`custom`](crate::service::custom)</span>
+//!
+//! |<div style="width: 250px">Method</div>|<div style="width: 550px">Description</div>|<div style="width: 50px">Auth?</div>|
+//! |-|-|-|
+//! |[`api_call`](custom::ApiCall)|Call a method on any JSON-RPC service|-|
 //!
 
 pub mod common;
+pub mod custom;
 pub mod db;
 pub mod object;
 pub mod orm;
+pub mod report;
 pub mod web;
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::{common, custom, db, object, orm, report, web};
+    use serde_json::Value;
+
+    /// A `Result` for the service tests, which doesn't depend on the `client` feature
+    pub(crate) type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Assert (at compile-time) that each type implements the given traits
+    macro_rules! assert_impl {
+        ($($trait:path),+ => $($ty:ty),+ $(,)?) => {
+            const _: fn() = || {
+                fn assert<T: ?Sized $(+ $trait)+>() {}
+                $(assert::<$ty>();)+
+            };
+        };
+    }
+
+    // request types, so a request can be rebuilt (e.g., for a retry)
+    assert_impl!(Clone =>
+        common::Login,
+        common::Authenticate,
+        common::Version,
+        common::About,
+        db::CreateDatabase,
+        db::DuplicateDatabase,
+        db::Drop,
+        db::Dump,
+        db::Restore,
+        db::Rename,
+        db::ChangeAdminPassword,
+        db::MigrateDatabases,
+        db::DbExist,
+        db::List,
+        db::ListLang,
+        db::ListCountries,
+        db::ServerVersion,
+        object::Execute,
+        object::ExecuteKw,
+        object::ExecWorkflow,
+        orm::Create,
+        orm::Read,
+        orm::Write,
+        orm::Unlink,
+        orm::ReadGroup,
+        orm::SearchRead,
+        orm::Search,
+        orm::SearchCount,
+        orm::Copy,
+        orm::CopyMulti,
+        orm::CopyData,
+        orm::Exists,
+        orm::CheckAccessRights,
+        orm::CheckAccessRules,
+        orm::CheckFieldAccessRights,
+        orm::GetMetadata,
+        orm::GetExternalId,
+        orm::GetXmlId,
+        orm::NameGet,
+        orm::DisplayNames,
+        orm::HasGroup,
+        orm::UserGroups,
+        orm::ChangePassword,
+        orm::UpdatePreferences,
+        orm::NameCreate,
+        orm::NameSearch,
+        orm::MessagePost,
+        orm::ActivitySchedule,
+        report::RenderReport,
+        web::SessionAuthenticate,
+        web::DatabaseList,
+        web::WebVersionInfo,
+        web::ActionLoad,
+        web::DatasetCallButton,
+        web::WebCall<Value, Value>,
+        custom::ApiCall<Value>,
+        orm::ReadModel<Value>,
+    );
+
+    // response (and argument) types
+    assert_impl!(Clone, PartialEq =>
+        common::LoginResponse,
+        common::AuthenticateResponse,
+        common::VersionResponse,
+        common::ServerVersionInfo,
+        common::AboutResponse,
+        common::AboutResponseBasic,
+        common::AboutResponseExtended,
+        db::CreateDatabaseResponse,
+        db::DuplicateDatabaseResponse,
+        db::DropResponse,
+        db::DumpFormat,
+        db::DumpResponse,
+        db::RestoreType,
+        db::RestoreResponse,
+        db::RenameResponse,
+        db::ChangeAdminPasswordResponse,
+        db::MigrateDatabasesResponse,
+        db::DbExistResponse,
+        db::ListResponse,
+        db::ListLangResponse,
+        db::ListLangResponseItem,
+        db::ListCountriesResponse,
+        db::ListCountriesResponseItem,
+        db::ServerVersionResponse,
+        object::ExecuteResponse,
+        object::ExecuteKwResponse,
+        object::ExecWorkflowResponse,
+        orm::CreateVals,
+        orm::CreateResponse,
+        orm::CreateResponseItem,
+        orm::ReadResponse,
+        orm::ReadModelResponse<Value>,
+        orm::WriteResponse,
+        orm::UnlinkResponse,
+        orm::ReadGroupResponse,
+        orm::ReadGroupItem,
+        orm::GroupKey,
+        orm::GroupRange,
+        orm::SearchReadResponse,
+        orm::SearchResponse,
+        orm::SearchCountResponse,
+        orm::CopyResponse,
+        orm::CopyMultiResponse,
+        orm::CopyDataResponse,
+        orm::ExistsResponse,
+        orm::AccessOperation,
+        orm::Operator,
+        orm::CheckAccessRightsResponse,
+        orm::CheckAccessRulesResponse,
+        orm::CheckFieldAccessRightsResponse,
+        orm::GetMetadataResponse,
+        orm::GetExternalIdResponse,
+        orm::GetXmlIdResponse,
+        orm::NameGetResponse,
+        orm::NameGetResponseItem,
+        orm::HasGroupResponse,
+        orm::UserGroupsResponse,
+        orm::ChangePasswordResponse,
+        orm::NotificationType,
+        orm::UserPreferences,
+        orm::NameCreateResponse,
+        orm::NameSearchResponse,
+        orm::NameSearchResponseItem,
+        orm::MessagePostResponse,
+        orm::MessageType,
+        orm::ActivityScheduleResponse,
+        report::RenderReportResponse,
+        web::SessionAuthenticateResponse,
+        web::DatabaseListResponse,
+        web::ActionLoadResponse,
+        web::DatasetCallButtonResponse,
+    );
+}
@@ -25,11 +25,11 @@ use serde_tuple::Serialize_tuple;
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// use odoo_api::jvec;
 ///
 /// // read `id` and `login` from users id=1,2,3
@@ -89,9 +89,18 @@ use serde_tuple::Serialize_tuple;
 /// have the `@api.model` decoration are a notable exception - for those methods,
 /// you should not pass anything for the `self` arg.
 ///
+/// Because the args are passed verbatim, any required positional args must be
+/// included. For example, `search` requires a domain, so `jvec![]` will fail
+/// (use `jvec![[]]` to match all records, or the ORM [`Search`](crate::service::orm::Search)
+/// method). In that case, the request will return a `MissingArgument` error.
+///
+/// A dict as the last argument is often kwargs meant for [`ExecuteKw`]. The
+/// client can refuse those requests (with a `LikelyKwargsMisuse` error), see
+/// [`check_kwargs_misuse`](crate::client::OdooRequest::check_kwargs_misuse).
+///
 /// See: [odoo/service/model.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/model.py#L62-L68)
 #[odoo_api(service = "object", method = "execute", auth = true)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Execute {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -155,7 +164,7 @@ impl Serialize for Execute {
 ///
 /// This struct is intentionally very generic, as the `execute` call can return
 /// any arbitrary JSON data.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ExecuteResponse {
     pub data: Value,
@@ -171,11 +180,11 @@ pub struct ExecuteResponse {
 ///
 /// ## Execute:
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// use odoo_api::{jvec, jmap};
 ///
 /// // read `id` and `login` from any user whose email matches "%@example.com"
@@ -225,8 +234,13 @@ pub struct ExecuteResponse {
 /// <br />
 ///
 /// Reference: [odoo/service/model.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/service/model.py#L58-L59)
-#[odoo_api(service = "object", method = "execute_kw", auth = true)]
-#[derive(Debug, Serialize_tuple)]
+#[odoo_api(
+    service = "object",
+    method = "execute_kw",
+    auth = true,
+    map_error = "crate::client::merge::execute_kw_error"
+)]
+#[derive(Debug, Clone, Serialize_tuple)]
 pub struct ExecuteKw {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -254,19 +268,81 @@ pub struct ExecuteKw {
 ///
 /// This struct is intentionally very generic, as the `execute` call can return
 /// any arbitrary JSON data.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ExecuteKwResponse {
     pub data: Value,
 }
 
+/// Trigger a workflow signal on a record (legacy)
+///
+/// Odoo versions prior to 11.0 used a workflow engine to drive document states
+/// (e.g., validating an invoice with the `invoice_open` signal). This method
+/// sends a workflow `signal` to the record `id`.
+///
+/// **Note**: The workflow engine was removed in Odoo 11.0, so this method will
+/// only work against legacy servers.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// // validate invoice id=42
+/// client.exec_workflow(
+///     "account.invoice",
+///     "invoice_open",
+///     42
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// <br />
+///
+/// Reference: [odoo/service/model.py](https://github.com/odoo/odoo/blob/10.0/odoo/service/model.py)
+#[odoo_api(service = "object", method = "exec_workflow", auth = true)]
+#[derive(Debug, Clone, Serialize_tuple)]
+pub struct ExecWorkflow {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The model name
+    pub model: String,
+
+    /// The workflow signal (e.g., `invoice_open`)
+    pub signal: String,
+
+    /// The record id
+    pub id: OdooId,
+}
+
+/// Represents the response to an Odoo [`ExecWorkflow`] call
+///
+/// This struct is intentionally very generic, as the workflow signal may return
+/// arbitrary JSON data (typically `false`, or an action).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExecWorkflowResponse {
+    pub data: Value,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::client::error::Result;
+    use crate::fixtures;
     use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::test::Result;
     use crate::{jmap, jvec};
-    use serde_json::{from_value, json, to_value};
+    use serde_json::{from_value, to_value};
 
     /// Test that serializing the [`Execute`] struct produces the expected
     /// JSON output.
@@ -288,24 +364,7 @@ mod test {
     /// This should ensure that the crate is producing valid JSON payloads
     #[test]
     fn execute() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.users",
-                    "read",
-                    [1, 2],
-                    ["id", "login"]
-                ]
-            }
-        });
+        let expected = fixtures::object::execute_request();
         let actual = to_value(
             Execute {
                 database: "some-database".into(),
@@ -324,6 +383,55 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn execute_debug_redacted() {
+        let execute = Execute {
+            database: "some-database".into(),
+            uid: 2,
+            password: "hunter2".into(),
+
+            model: "res.users".into(),
+            method: "read".into(),
+            args: jvec![[1, 2]],
+        };
+        let debug = format!("{:?}", execute);
+        assert!(debug.contains("password: \"***\""), "{}", debug);
+        assert!(debug.contains("database: \"some-database\""), "{}", debug);
+        assert!(!debug.contains("hunter2"), "{}", debug);
+
+        // and via the request container
+        let debug = format!("{:?}", execute.build(1000));
+        assert!(!debug.contains("hunter2"), "{}", debug);
+    }
+
+    /// String arguments accept `&str`, `String`, and `Cow<str>`
+    #[cfg(feature = "client")]
+    #[test]
+    fn execute_string_args() -> Result<()> {
+        use serde_json::json;
+        use std::borrow::Cow;
+
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let model = String::from("res.users");
+        let bodies = [
+            client
+                .execute("res.users", "read", jvec![[1]])
+                .build_body()?,
+            client
+                .execute(model, String::from("read"), jvec![[1]])
+                .build_body()?,
+            client
+                .execute(Cow::Borrowed("res.users"), Cow::from("read"), jvec![[1]])
+                .build_body()?,
+        ];
+        for body in bodies {
+            assert_eq!(body["params"]["args"][3], json!("res.users"));
+            assert_eq!(body["params"]["args"][4], json!("read"));
+        }
+
+        Ok(())
+    }
+
     /// Test that a valid Odoo response payload is serializable into [`ExecuteResponse`]
     ///
     /// As with [`execute`] above, this is achieved by firing a JSON-RPC request
@@ -333,20 +441,7 @@ mod test {
     /// If this succeeds, then the response struct is set up properly!
     #[test]
     fn execute_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                {
-                    "id": 1,
-                    "login": "__system__"
-                },
-                {
-                    "id": 2,
-                    "login": "admin"
-                }
-            ]
-        });
+        let payload = fixtures::object::execute_response();
 
         let response: JsonRpcResponse<ExecuteResponse> = from_value(payload)?;
 
@@ -362,28 +457,7 @@ mod test {
     /// See [`crate::service::object::test::execute`] for more info
     #[test]
     fn execute_kw() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.users",
-                    "read",
-                    [
-                        [1, 2]
-                    ],
-                    {
-                        "fields": ["id", "login"]
-                    }
-                ]
-            }
-        });
+        let expected = fixtures::object::execute_kw_request();
         let actual = to_value(
             ExecuteKw {
                 database: "some-database".into(),
@@ -408,20 +482,7 @@ mod test {
     /// See [`crate::service::object::test::execute_response`] for more info
     #[test]
     fn execute_kw_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                {
-                    "id": 1,
-                    "login": "__system__"
-                },
-                {
-                    "id": 2,
-                    "login": "admin"
-                }
-            ]
-        });
+        let payload = fixtures::object::execute_kw_response();
 
         let response: JsonRpcResponse<ExecuteKwResponse> = from_value(payload)?;
         match response {
@@ -429,4 +490,83 @@ mod test {
             JsonRpcResponse::Success(_) => Ok(()),
         }
     }
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn exec_workflow() -> Result<()> {
+        let expected = fixtures::object::exec_workflow_request();
+        let actual = to_value(
+            ExecWorkflow {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "account.invoice".into(),
+                signal: "invoice_open".into(),
+                id: 42,
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    /// See [`crate::service::object::test::execute_response`] for more info
+    #[test]
+    fn exec_workflow_response() -> Result<()> {
+        let payload = fixtures::object::exec_workflow_response();
+
+        let response: JsonRpcResponse<ExecWorkflowResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    /// Execute a method, with optional auth
+    #[cfg(feature = "client")]
+    #[odoo_api(
+        service = "object",
+        method = "execute",
+        name = "test_execute",
+        auth = "optional"
+    )]
+    #[derive(Debug, Serialize)]
+    struct TestExecute {
+        database: String,
+        uid: OdooId,
+        password: String,
+        model: String,
+    }
+
+    #[cfg(feature = "client")]
+    #[derive(Debug, Deserialize)]
+    struct TestExecuteResponse {}
+
+    /// Methods with `auth = "optional"` are available on both client types
+    #[cfg(feature = "client")]
+    #[test]
+    fn auth_optional() -> std::result::Result<(), crate::client::error::ClosureError> {
+        use crate::client::OdooClient;
+        use serde_json::json;
+
+        let client =
+            OdooClient::new_closure_blocking("http://localhost:8069", |_url, data, _session_id| {
+                assert_eq!(data["params"]["args"]["database"], json!("some-database"));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {}}).to_string(),
+                    None,
+                ))
+            });
+        client
+            .test_execute_manual("some-database", 2, "password", "res.partner")
+            .send()?;
+
+        let client = client.authenticate_manual("some-database", "admin", 2, "password", None);
+        client.test_execute("res.partner").send()?;
+
+        Ok(())
+    }
 }
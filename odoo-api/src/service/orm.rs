@@ -5,7 +5,17 @@
 //! with better type checking.
 
 use crate as odoo_api;
-use crate::jsonrpc::{OdooId, OdooIds, OdooOrmMethod};
+#[cfg(feature = "client")]
+use crate::client::error::ParseResponseError;
+use crate::fields::FieldValue;
+#[cfg(feature = "client")]
+use crate::jsonrpc::response::JsonRpcErrorData;
+use crate::jsonrpc::{
+    JsonRpcId, JsonRpcParams, JsonRpcRequest, OdooId, OdooIds, OdooKwargsMethod, OdooOrmContainer,
+    OdooOrmMethod,
+};
+use crate::model::OdooModel;
+use crate::serde_helpers::OdooOption;
 use odoo_api_macros::odoo_orm;
 use serde::ser::SerializeTuple;
 use serde::{de, Deserialize, Deserializer, Serialize};
@@ -13,16 +23,17 @@ use serde_json::{Map, Value};
 use serde_tuple::{Deserialize_tuple, Serialize_tuple};
 use std::collections::HashMap;
 use std::fmt;
+use std::marker::PhantomData;
 
 /// Create a new record (or set of records)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // create a single record
 /// let resp = client.create(
 ///     "res.partner",
@@ -53,7 +64,7 @@ use std::fmt;
     args = ["values"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Create {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -96,7 +107,7 @@ pub struct Create {
 ///     ]
 /// );
 /// ```
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CreateVals {
     /// Create a single new record
@@ -119,7 +130,7 @@ impl From<Vec<Value>> for CreateVals {
 }
 
 /// The response to a [`Create`] requests
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CreateResponse {
     /// The new record(s) id(s)
@@ -131,7 +142,7 @@ pub struct CreateResponse {
 /// Because thr [`Create`] request can create one OR multiple records, the response
 /// may be one or multiple ids. In the "one" case, Odoo returns a plain int. In
 /// the "multi" case, Odoo returns an array of ints.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum CreateResponseItem {
     /// The new records' id
@@ -145,11 +156,11 @@ pub enum CreateResponseItem {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, svec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // read from a single record
 /// let resp = client.read(
 ///     "res.partner",
@@ -176,7 +187,7 @@ pub enum CreateResponseItem {
     args = ["ids"],
     kwargs = ["fields"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Read {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -198,22 +209,206 @@ pub struct Read {
 }
 
 /// The response to a [`Read`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ReadResponse {
     /// The fetched fields
     pub data: Vec<Map<String, Value>>,
 }
 
+impl ReadResponse {
+    /// Convert the records into typed [`FieldValue`]s
+    ///
+    /// The raw `data` is left untouched; this is computed on each call.
+    /// ```
+    /// use odoo_api::service::orm::ReadResponse;
+    /// use odoo_api::fields::FieldValue;
+    /// use serde_json::json;
+    ///
+    /// let response: ReadResponse = serde_json::from_value(json!([
+    ///     {"id": 3, "parent_id": [1, "My Company"], "email": false}
+    /// ]))?;
+    ///
+    /// let records = response.records_typed();
+    /// assert_eq!(records[0]["parent_id"].as_many2one(), Some((1, "My Company")));
+    /// assert!(records[0]["email"].is_false());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn records_typed(&self) -> Vec<HashMap<String, FieldValue>> {
+        self.data.iter().map(FieldValue::from_record).collect()
+    }
+}
+
+/// Read records into an [`OdooModel`] struct
+///
+/// This is [`Read`], with the model and `fields` taken from `T`, and the
+/// records deserialized into `T`. See the [`model`](crate::model) module for
+/// the `#[derive(OdooModel)]` macro.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// use odoo_api::model::OdooModel;
+/// use odoo_api::OdooId;
+///
+/// #[derive(Debug, OdooModel)]
+/// #[odoo(model = "res.users")]
+/// struct User {
+///     login: String,
+///
+///     #[odoo(many2one)]
+///     partner_id: OdooId,
+/// }
+///
+/// let users = client.read_model::<User>(vec![2]).send()?;
+/// println!("Partner: {}", users.records[0].partner_id);
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L2958-L2991)
+pub struct ReadModel<T> {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The records
+    pub ids: OdooIds,
+
+    _model: PhantomData<fn() -> T>,
+}
+
+impl<T> ReadModel<T> {
+    pub fn new(database: &str, uid: OdooId, password: &str, ids: impl Into<OdooIds>) -> Self {
+        Self {
+            database: database.into(),
+            uid,
+            password: password.into(),
+            ids: ids.into(),
+            _model: PhantomData,
+        }
+    }
+}
+
+// derived `Clone` would require `T: Clone`
+impl<T> Clone for ReadModel<T> {
+    fn clone(&self) -> Self {
+        Self {
+            database: self.database.clone(),
+            uid: self.uid,
+            password: self.password.clone(),
+            ids: self.ids.clone(),
+            _model: PhantomData,
+        }
+    }
+}
+
+// as with the macro-generated structs, the password is redacted
+impl<T> fmt::Debug for ReadModel<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadModel")
+            .field("database", &self.database)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .field("ids", &self.ids)
+            .finish()
+    }
+}
+
+impl<T: OdooModel + fmt::Debug> Serialize for ReadModel<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_orm(self, &self.database, self.uid, &self.password, serializer)
+    }
+}
+
+impl<T: OdooModel + fmt::Debug> JsonRpcParams for ReadModel<T> {
+    type Container<C> = OdooOrmContainer<Self>;
+    type Response = ReadModelResponse<T>;
+    const IS_READONLY: bool = true;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl<T: OdooModel + fmt::Debug> OdooOrmMethod for ReadModel<T> {
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+
+    fn method(&self) -> &'static str {
+        "read"
+    }
+
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>) {
+        let args = vec![serde_json::json!(self.ids)];
+        let mut kwargs = Map::new();
+        kwargs.insert("fields".into(), serde_json::json!(T::FIELDS));
+        (T::MODEL.into(), self.method().into(), args, kwargs)
+    }
+}
+
+impl<T: OdooModel + fmt::Debug> OdooKwargsMethod for ReadModel<T> {}
+
+/// The response to a [`ReadModel`] request
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(transparent, bound = "T: OdooModel")]
+pub struct ReadModelResponse<T> {
+    /// The records
+    pub records: Vec<T>,
+}
+
+#[cfg(feature = "client")]
+impl<I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<odoo_api::client::Authed, I> {
+    /// Read records into an [`OdooModel`] struct
+    ///
+    /// See [`ReadModel`] for more info.
+    pub fn read_model<T: OdooModel + fmt::Debug>(
+        &self,
+        ids: impl Into<OdooIds>,
+    ) -> odoo_api::client::OdooRequest<'_, ReadModel<T>, I> {
+        let read_model =
+            ReadModel::new(&self.auth.database, self.auth.uid, &self.auth.password, ids);
+
+        let endpoint = self.build_endpoint(read_model.endpoint());
+        self.build_kwargs_request(read_model, &endpoint)
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "read",
+        name: "read_model",
+        auth: true,
+        args: &["ids"],
+        kwargs: &["fields"],
+    }
+}
+
 /// Write data to a record (or set of records)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // write to a single record
 /// client.write(
 ///     "res.partner",
@@ -242,7 +437,7 @@ pub struct ReadResponse {
     args = ["ids", "values"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Write {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -264,7 +459,7 @@ pub struct Write {
 }
 
 /// The response to a [`Write`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct WriteResponse {
     pub ok: bool,
@@ -274,11 +469,11 @@ pub struct WriteResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// // delete one record
 /// client.unlink(
 ///     "res.partner",
@@ -301,7 +496,7 @@ pub struct WriteResponse {
     args = ["ids"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Unlink {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -319,21 +514,133 @@ pub struct Unlink {
     pub ids: OdooIds,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct UnlinkResponse {
     pub ok: bool,
 }
 
+/// Archive a record (or set of records)
+///
+/// This sets `active = False`, but (unlike a plain `write()`) also runs any
+/// archiving logic defined by the model. The model must have an `active` field.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client.archive(
+///     "res.partner",
+///     vec![1, 2, 3]
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py)
+#[odoo_orm(
+    method = "action_archive",
+    name = "archive",
+    args = ["ids"],
+    kwargs = [],
+)]
+#[derive(Debug, Clone)]
+pub struct Archive {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The records to be archived
+    pub ids: OdooIds,
+}
+
+/// The response to an [`Archive`] or [`Unarchive`] request
+///
+/// The base methods return `True`, but some models override them (e.g.,
+/// to open a wizard) and return an action dict instead, or nothing at all.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArchiveResponse {
+    /// The records were (un)archived
+    Bool(bool),
+
+    /// The model returned a client action
+    Action(Map<String, Value>),
+
+    /// The model returned `None`
+    Null,
+}
+
+/// Unarchive a record (or set of records)
+///
+/// This sets `active = True`, and runs any unarchiving logic defined by the
+/// model. The model must have an `active` field.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client.unarchive(
+///     "res.partner",
+///     vec![1, 2, 3]
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py)
+#[odoo_orm(
+    method = "action_unarchive",
+    name = "unarchive",
+    args = ["ids"],
+    kwargs = [],
+)]
+#[derive(Debug, Clone)]
+pub struct Unarchive {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The records to be unarchived
+    pub ids: OdooIds,
+}
+
+/// The response to an [`Unarchive`] request (see [`ArchiveResponse`])
+pub type UnarchiveResponse = ArchiveResponse;
+
 /// Read some grouped data from a record (or set of records)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec, svec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.read_group(
 ///     "res.partner",
 ///
@@ -349,11 +656,12 @@ pub struct UnlinkResponse {
 ///     // groupby
 ///     svec!["create_date:month", "email"],
 ///
-///     Some(0), // offset
-///     Some(10), // limit
-///     Some("create_date desc".into()), // orderby
 ///     false // lazy
-/// ).send()?;
+/// )
+/// .offset(0)
+/// .limit(10)
+/// .orderby("create_date desc")
+/// .send()?;
 ///
 /// # Ok(())
 /// # }
@@ -366,7 +674,7 @@ pub struct UnlinkResponse {
     args = ["domain", "fields", "groupby"],
     kwargs = ["offset", "limit", "orderby", "lazy"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReadGroup {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -413,23 +721,170 @@ pub struct ReadGroup {
     pub lazy: bool,
 }
 
-//TODO: a better response type (e.g., struct with __domain/etc, and a `data` key for the actual fields)
 /// The response to a [`ReadGroup`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ReadGroupResponse {
-    pub result: Vec<Map<String, Value>>,
+    pub result: Vec<ReadGroupItem>,
+}
+
+/// A single group from a [`ReadGroupResponse`]
+///
+/// The raw data is kept as-is (including the `__domain`, `__count`, etc keys).
+/// Use [`key`](Self::key) and [`range`](Self::range) to fetch typed values for
+/// a `groupby` field.
+///
+/// ## Example
+/// ```
+/// use odoo_api::service::orm::{GroupKey, ReadGroupItem};
+/// use serde_json::json;
+///
+/// let item: ReadGroupItem = serde_json::from_value(json!({
+///     "__count": 1,
+///     "company_id": [1, "My Company"],
+///     "create_date:month": "January 2023",
+/// }))?;
+/// assert_eq!(item.key("company_id").as_many2one(), Some((1, "My Company")));
+/// assert_eq!(item.key("create_date:month"), GroupKey::Text("January 2023".into()));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReadGroupItem {
+    pub data: Map<String, Value>,
+}
+
+impl ReadGroupItem {
+    /// Fetch the group key for the `groupby` field (e.g., `company_id`)
+    ///
+    /// Date groupbys with a granularity (e.g., `create_date:month`) may be
+    /// passed with or without the granularity. Missing keys are returned as
+    /// [`GroupKey::None`].
+    pub fn key(&self, groupby: &str) -> GroupKey {
+        find_groupby(&self.data, groupby)
+            .map(|value| GroupKey::from(value.clone()))
+            .unwrap_or(GroupKey::None)
+    }
+
+    /// Fetch the date range for the `groupby` field (e.g., `create_date:month`)
+    ///
+    /// This is only returned by Odoo 16.0+ (via the `__range` key), and is
+    /// `None` for the "empty" group.
+    pub fn range(&self, groupby: &str) -> Option<GroupRange> {
+        let ranges = self.data.get("__range")?.as_object()?;
+        GroupRange::deserialize(find_groupby(ranges, groupby)?).ok()
+    }
+}
+
+/// Fetch the value for `groupby`, ignoring the granularity if needed
+///
+/// For example, `create_date` will match a `create_date:month` key (and vice-versa).
+fn find_groupby<'a>(data: &'a Map<String, Value>, groupby: &str) -> Option<&'a Value> {
+    fn field_name(groupby: &str) -> &str {
+        groupby.split(':').next().unwrap_or(groupby)
+    }
+
+    data.get(groupby).or_else(|| {
+        data.iter()
+            .find(|(key, _)| field_name(key) == field_name(groupby))
+            .map(|(_, value)| value)
+    })
+}
+
+/// A typed `read_group` key
+///
+/// The shape of the JSON value is used to pick a variant:
+///  - `[int, string]` → [`GroupKey::Many2One`]
+///  - strings → [`GroupKey::Text`] (e.g., `"January 2023"` for a date groupby)
+///  - `true` → [`GroupKey::Bool`]
+///  - `false` or `null` → [`GroupKey::None`] (Odoo's "empty" group)
+///  - anything else (e.g., an integer groupby) → [`GroupKey::Other`]
+///
+/// As with [`FieldValue`], Odoo returns `false` for both the "empty" group
+/// *and* the `false` group of a boolean field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupKey {
+    /// A many2one group, e.g. `[1, "My Company"]`
+    Many2One { id: OdooId, name: String },
+
+    /// A string group (char, selection, or a formatted date)
+    Text(String),
+
+    /// A `true` boolean group
+    Bool(bool),
+
+    /// The "empty" group (`false`)
+    None,
+
+    /// Any other value
+    Other(Value),
+}
+
+impl GroupKey {
+    /// Fetch the `(id, name)` of a many2one group
+    pub fn as_many2one(&self) -> Option<(OdooId, &str)> {
+        match self {
+            Self::Many2One { id, name } => Some((*id, name)),
+            _ => None,
+        }
+    }
+
+    /// Check whether this is the "empty" group
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl From<Value> for GroupKey {
+    fn from(value: Value) -> Self {
+        match FieldValue::from(value) {
+            FieldValue::Many2One { id, display_name } => Self::Many2One {
+                id,
+                name: display_name,
+            },
+            FieldValue::Str(value) => Self::Text(value),
+            FieldValue::Bool(value) => Self::Bool(value),
+            FieldValue::False => Self::None,
+            FieldValue::Ids(ids) => Self::Other(ids.into()),
+            FieldValue::Int(value) => Self::Other(value.into()),
+            FieldValue::Float(value) => Self::Other(value.into()),
+            FieldValue::Other(value) => Self::Other(value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GroupKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Value::deserialize(deserializer)?.into())
+    }
+}
+
+/// The date range for a `read_group` date/datetime group (Odoo 16.0+)
+///
+/// The `start` is inclusive, and the `end` is exclusive, e.g. `2023-01-01`
+/// to `2023-02-01` for `January 2023`. Datetimes are formatted as
+/// `2023-01-01 00:00:00` (UTC).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupRange {
+    #[serde(rename = "from")]
+    pub start: String,
+
+    #[serde(rename = "to")]
+    pub end: String,
 }
 
 /// Perform a `search` and `read` in one call
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec, svec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.search_read(
 ///     "res.partner",
 ///
@@ -441,11 +896,11 @@ pub struct ReadGroupResponse {
 ///
 ///     // fields
 ///     svec!["id", "name", "email", "phone"],
-///
-///     Some(0), // offset
-///     Some(10), // limit
-///     Some("create_date desc".into()), // order
-/// ).send()?;
+/// )
+/// .offset(0)
+/// .limit(10)
+/// .order("create_date desc")
+/// .send()?;
 ///
 /// # Ok(())
 /// # }
@@ -458,7 +913,7 @@ pub struct ReadGroupResponse {
     args = [],
     kwargs = ["domain", "fields", "offset", "limit", "order"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SearchRead {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -491,22 +946,31 @@ pub struct SearchRead {
     pub order: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct SearchReadResponse {
     pub data: Vec<Map<String, Value>>,
 }
 
+impl SearchReadResponse {
+    /// Convert the records into typed [`FieldValue`]s
+    ///
+    /// See [`ReadResponse::records_typed`] for more info.
+    pub fn records_typed(&self) -> Vec<HashMap<String, FieldValue>> {
+        self.data.iter().map(FieldValue::from_record).collect()
+    }
+}
+
 //TODO: notes about the `count` flag (maybe disable that - we have search_count)
 /// Return the ids of records matching a domain
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.search(
 ///     "res.partner",
 ///
@@ -515,11 +979,11 @@ pub struct SearchReadResponse {
 ///         ["email", "=ilike", "%@example.com"],
 ///         ["phone", "!=", false]
 ///     ],
-///
-///     Some(0), // offset
-///     Some(10), // limit
-///     Some("create_date desc".into()), // order
-/// ).send()?;
+/// )
+/// .offset(0)
+/// .limit(10)
+/// .order("create_date desc")
+/// .send()?;
 ///
 /// # Ok(())
 /// # }
@@ -532,7 +996,7 @@ pub struct SearchReadResponse {
     args = ["domain"],
     kwargs = ["offset", "limit", "order"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Search {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -563,7 +1027,7 @@ pub struct Search {
 }
 
 /// The response to a [`Search`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct SearchResponse {
     pub records: Vec<OdooId>,
@@ -573,11 +1037,11 @@ pub struct SearchResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.search_count(
 ///     "res.partner",
 ///
@@ -586,8 +1050,6 @@ pub struct SearchResponse {
 ///         ["email", "=ilike", "%@example.com"],
 ///         ["phone", "!=", false]
 ///     ],
-///
-///     None, // limit
 /// ).send()?;
 /// # Ok(())
 /// # }
@@ -600,7 +1062,7 @@ pub struct SearchResponse {
     args = ["domain"],
     kwargs = ["limit"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SearchCount {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -617,32 +1079,74 @@ pub struct SearchCount {
     /// The domain to search on
     pub domain: Vec<Value>,
 
-    /// An optional limit
+    /// An optional limit (Odoo 16.0+)
+    ///
+    /// The count stops at `limit`, so the result is `min(count, limit)`. Older
+    /// servers don't accept the `limit` kwarg at all, so it's omitted when `None`.
+    /// See [`search_count_limited`](crate::client::OdooClient::search_count_limited)
+    /// for a version-aware alternative.
     pub limit: Option<u32>,
 }
 
 /// The response to a [`SearchCount`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct SearchCountResponse {
     pub count: u32,
 }
 
+#[cfg(feature = "client")]
+impl<I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<odoo_api::client::Authed, I> {
+    /// Count the records matching `domain`, stopping at `limit`
+    ///
+    /// The `limit` kwarg is only sent if the server supports it (Odoo 16.0+),
+    /// based on the cached server version. Older servers count every match, so
+    /// the result may exceed `limit`. If the version hasn't been fetched yet
+    /// (e.g. with `server_version()`), Odoo 16.0+ is assumed.
+    ///
+    /// See [`SearchCount`] for more info.
+    pub fn search_count_limited(
+        &self,
+        model: &str,
+        domain: Vec<Value>,
+        limit: u32,
+    ) -> odoo_api::client::OdooRequest<'_, SearchCount, I> {
+        let request = self.search_count(model, domain);
+        match self.version() {
+            Some(version) if !version.at_least(16, 0) => request,
+            _ => request.limit(limit),
+        }
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "search_count",
+        name: "search_count_limited",
+        auth: true,
+        args: &["domain"],
+        kwargs: &["limit"],
+    }
+}
+
 /// Copy a record
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.copy(
 ///     "res.partner",
 ///
 ///     2, // record id
-///     None, // override fields
-/// ).send()?;
+/// )
+/// .default(jmap!{"name": "Copy of Admin"}) // override fields
+/// .send()?;
 /// # Ok(())
 /// # }
 /// ```
@@ -654,7 +1158,7 @@ pub struct SearchCountResponse {
     args = ["id"],
     kwargs = ["default"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Copy {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -675,43 +1179,46 @@ pub struct Copy {
     pub default: Option<Map<String, Value>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CopyResponse {
     pub id: OdooId,
 }
 
-/// Check if the record(s) exist in the Odoo database
+/// Copy a set of records
 ///
-/// **Note**: This method works by accepting a list of ids, and returning only
-/// the ids that actually exist. See [`ExistsResponse`] for more info.
+/// Odoo 18.0+ supports copying multiple records at once. Older versions only
+/// accept a single record, so use [`Copy`] for those.
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
-/// client.exists(
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.copy_multi(
 ///     "res.partner",
-///     vec![1, 2, -1, 999999999]
-/// ).send()?;
+///     vec![2, 3], // record ids
+/// )
+/// .default(jmap!{"active": false}) // override fields
+/// .send()?;
 ///
-/// // The response would be [1, 2], assuming that -1 and 999999999 do not exist
+/// println!("New partner ids: {:?}", resp.ids);
 /// # Ok(())
 /// # }
 /// ```
 ///<br />
 ///
-/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L4773-L4793)
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L4755-L4771)
 #[odoo_orm(
-    method = "exists",
+    method = "copy",
+    name = "copy_multi",
     args = ["ids"],
-    kwargs = [],
+    kwargs = ["default"],
 )]
-#[derive(Debug)]
-pub struct Exists {
+#[derive(Debug, Clone)]
+pub struct CopyMulti {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
 
@@ -724,42 +1231,163 @@ pub struct Exists {
     /// The Odoo model
     pub model: String,
 
-    /// The ids to check
+    /// The records to copy
     pub ids: OdooIds,
+
+    /// The fields to be overridden
+    pub default: Option<Map<String, Value>>,
 }
 
-/// The response to an [`Exists`] call
-///
-/// The [`Exists`] method is soemwhat unituitive; It accepts *multiple* ids, then
-/// returns only the ids that actually exist (rather than returning true/false).
+/// The response to a [`CopyMulti`] request
 ///
-/// To use this method, you should pass the record ids you want to check, then
-/// test whether those ids were returned in the `existing_records` field.
-#[derive(Debug, Serialize, Deserialize)]
+/// When copying a single record, Odoo returns a plain int rather than a list,
+/// so this uses the same container as [`CreateResponse`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct ExistsResponse {
-    pub existing_records: OdooIds,
-}
-
-/// An access operation type
-#[derive(Debug, Serialize)]
-pub enum AccessOperation {
-    #[serde(rename = "create")]
-    Create,
-
-    #[serde(rename = "read")]
-    Read,
-
-    #[serde(rename = "write")]
-    Write,
-
-    #[serde(rename = "unlink")]
-    Unlink,
+pub struct CopyMultiResponse {
+    /// The new record(s) id(s)
+    pub ids: CreateResponseItem,
 }
 
-/// Check model access rights (according to `ir.model.access`)
+/// Fetch the values that [`Copy`] would use for the new record(s)
 ///
-/// This method checks against `ir.model.access`, e.g. basic per-group CRUD rules.
+/// This doesn't write anything, so it can be used to preview a copy.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.copy_data(
+///     "res.partner",
+///     2, // record id
+/// )
+/// .default(jmap!{"name": "Copy of Admin"}) // override fields
+/// .send()?;
+///
+/// println!("Values: {:?}", resp.values);
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L4677-L4753)
+#[odoo_orm(
+    method = "copy_data",
+    args = ["ids"],
+    kwargs = ["default"],
+)]
+#[derive(Debug, Clone)]
+pub struct CopyData {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The records to fetch copy values for
+    pub ids: OdooIds,
+
+    /// The fields to be overridden
+    pub default: Option<Map<String, Value>>,
+}
+
+/// The response to a [`CopyData`] request
+///
+/// This contains one map of field values per record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CopyDataResponse {
+    pub values: Vec<Map<String, Value>>,
+}
+
+/// Check if the record(s) exist in the Odoo database
+///
+/// **Note**: This method works by accepting a list of ids, and returning only
+/// the ids that actually exist. See [`ExistsResponse`] for more info.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client.exists(
+///     "res.partner",
+///     vec![1, 2, -1, 999999999]
+/// ).send()?;
+///
+/// // The response would be [1, 2], assuming that -1 and 999999999 do not exist
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L4773-L4793)
+#[odoo_orm(
+    method = "exists",
+    args = ["ids"],
+    kwargs = [],
+)]
+#[derive(Debug, Clone)]
+pub struct Exists {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The ids to check
+    pub ids: OdooIds,
+}
+
+/// The response to an [`Exists`] call
+///
+/// The [`Exists`] method is soemwhat unituitive; It accepts *multiple* ids, then
+/// returns only the ids that actually exist (rather than returning true/false).
+///
+/// To use this method, you should pass the record ids you want to check, then
+/// test whether those ids were returned in the `existing_records` field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExistsResponse {
+    pub existing_records: OdooIds,
+}
+
+/// An access operation type
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum AccessOperation {
+    #[serde(rename = "create")]
+    Create,
+
+    #[serde(rename = "read")]
+    Read,
+
+    #[serde(rename = "write")]
+    Write,
+
+    #[serde(rename = "unlink")]
+    Unlink,
+}
+
+/// Check model access rights (according to `ir.model.access`)
+///
+/// This method checks against `ir.model.access`, e.g. basic per-group CRUD rules.
 /// You should also call [`CheckAccessRules`] in order to determine if any advanced
 /// access rules apply to this model/user.
 ///
@@ -769,11 +1397,11 @@ pub enum AccessOperation {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// use odoo_api::service::orm::AccessOperation;
 /// client.check_access_rights(
 ///     "stock.quant",
@@ -793,7 +1421,7 @@ pub enum AccessOperation {
     args = ["operation"],
     kwargs = ["raise_exception"],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CheckAccessRights {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -818,7 +1446,7 @@ pub struct CheckAccessRights {
 }
 
 /// Response to a [`CheckAccessRights`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CheckAccessRightsResponse {
     pub ok: bool,
@@ -838,11 +1466,11 @@ pub struct CheckAccessRightsResponse {
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// use odoo_api::service::orm::AccessOperation;
 /// client.check_access_rules(
 ///     "res.partner",
@@ -861,7 +1489,7 @@ pub struct CheckAccessRightsResponse {
     args = ["ids", "operation"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CheckAccessRules {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -882,7 +1510,7 @@ pub struct CheckAccessRules {
     pub operation: AccessOperation,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CheckAccessRulesResponse {}
 
 /// Check the user access rights on the given fields
@@ -894,11 +1522,11 @@ pub struct CheckAccessRulesResponse {}
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap, svec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// use odoo_api::service::orm::AccessOperation;
 /// client.check_field_access_rights(
 ///     "res.partner",
@@ -916,7 +1544,7 @@ pub struct CheckAccessRulesResponse {}
     args = ["operation", "fields"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CheckFieldAccessRights {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -938,21 +1566,79 @@ pub struct CheckFieldAccessRights {
 }
 
 /// The response to a [`CheckFieldAccessRights`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct CheckFieldAccessRightsResponse {
     pub result: Option<Vec<String>>,
 }
 
+/// Return the definition of each field on a model
+///
+/// The result maps each field name to its attributes (e.g. `string`, `type`,
+/// `selection`, `relation`). Labels and selection values are translated into
+/// the `lang` from the context.
+///
+/// To fetch the fields repeatedly (e.g. to render labels), see the client's
+/// `model_metadata()` method, which caches the result.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, svec};
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let fields = client.fields_get("res.partner")
+///     .attributes(svec!["string", "type"])
+///     .send()?;
+/// println!("{:?}", fields.fields["email"]["string"]);
+/// # Ok(())
+/// # }
+/// ```
+#[odoo_orm(
+    method = "fields_get",
+    args = [],
+    kwargs = ["allfields", "attributes"],
+)]
+#[derive(Debug, Clone)]
+pub struct FieldsGet {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// Only return these fields (Odoo returns every field by default)
+    pub allfields: Option<Vec<String>>,
+
+    /// Only return these attributes of each field (Odoo returns every
+    /// attribute by default)
+    pub attributes: Option<Vec<String>>,
+}
+
+/// The response to a [`FieldsGet`] request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FieldsGetResponse {
+    /// The attributes of each field, by field name
+    pub fields: Map<String, Value>,
+}
+
 /// Return some metadata about the given record(s)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.get_metadata(
 ///     "res.partner",
 ///     vec![1, 2]
@@ -968,7 +1654,7 @@ pub struct CheckFieldAccessRightsResponse {
     args = ["ids"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GetMetadata {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -987,20 +1673,99 @@ pub struct GetMetadata {
 }
 
 /// The response to a [`GetMetadata`] request
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// With the `strict-responses` feature, records with unknown keys are rejected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct GetMetadataResponse {
+    #[cfg_attr(
+        feature = "strict-responses",
+        serde(deserialize_with = "get_metadata_deserialize")
+    )]
     pub metadata: Vec<Map<String, Value>>,
 }
 
-// Allow the map of {str: str} to be deserialized into {i32: str}
-fn get_external_id_deserialize<'de, D>(de: D) -> Result<HashMap<OdooId, String>, D::Error>
+/// The keys of a `get_metadata` record (`xmlids` was added in 16.0)
+#[cfg(feature = "strict-responses")]
+const METADATA_FIELDS: &[&str] = &[
+    "id",
+    "create_uid",
+    "create_date",
+    "write_uid",
+    "write_date",
+    "xmlid",
+    "xmlids",
+    "noupdate",
+];
+
+#[cfg(feature = "strict-responses")]
+fn get_metadata_deserialize<'de, D>(de: D) -> Result<Vec<Map<String, Value>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let metadata = Vec::<Map<String, Value>>::deserialize(de)?;
+    for record in &metadata {
+        if let Some(key) = record
+            .keys()
+            .find(|key| !METADATA_FIELDS.contains(&key.as_str()))
+        {
+            return Err(de::Error::unknown_field(key, METADATA_FIELDS));
+        }
+    }
+    Ok(metadata)
+}
+
+#[cfg(feature = "time")]
+impl GetMetadataResponse {
+    /// Parse the metadata into [`RecordMetadata`] structs
+    ///
+    /// The raw maps are kept in [`metadata`](Self::metadata), as some Odoo versions
+    /// return extra keys (e.g. `xmlids`).
+    pub fn records(&self) -> serde_json::Result<Vec<RecordMetadata>> {
+        self.metadata
+            .iter()
+            .map(|record| serde_json::from_value(Value::Object(record.clone())))
+            .collect()
+    }
+}
+
+/// A typed [`GetMetadataResponse`] record
+///
+/// Returned by [`GetMetadataResponse::records`].
+#[cfg(feature = "time")]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RecordMetadata {
+    /// The record id
+    pub id: OdooId,
+
+    /// The creation date (`create_date`)
+    #[serde(default)]
+    pub create_date: OdooOption<crate::types::OdooDateTime>,
+
+    /// The last modified date (`write_date`)
+    #[serde(default)]
+    pub write_date: OdooOption<crate::types::OdooDateTime>,
+
+    /// The record's external id, if any
+    #[serde(default)]
+    pub xmlid: OdooOption<String>,
+
+    /// Whether the record's external id is set to `noupdate`
+    #[serde(default)]
+    pub noupdate: bool,
+}
+
+// Allow the map of {str: str} to be deserialized into {i32: Option<str>}
+//
+// Records without an external id are returned as `""` or `false` (depending on
+// the Odoo version), which are both normalized to `None`
+fn get_external_id_deserialize<'de, D>(de: D) -> Result<HashMap<OdooId, Option<String>>, D::Error>
 where
     D: Deserializer<'de>,
 {
     struct Visitor;
     impl<'de> de::Visitor<'de> for Visitor {
-        type Value = HashMap<OdooId, String>;
+        type Value = HashMap<OdooId, Option<String>>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
             formatter.write_str("a map of \"id\": \"external_id\"")
@@ -1014,13 +1779,14 @@ where
 
             // While there are entries remaining in the input, add them
             // into our map.
-            while let Some((key, value)) = access.next_entry::<String, String>()? {
+            while let Some((key, value)) = access.next_entry::<String, OdooOption<String>>()? {
                 let key = key.parse().map_err(|_e| {
                     de::Error::invalid_value(
                         de::Unexpected::Str(&key),
                         &"A String representing an i32",
                     )
                 })?;
+                let value = value.into_inner().filter(|xmlid| !xmlid.is_empty());
                 map.insert(key, value);
             }
 
@@ -1031,15 +1797,26 @@ where
     de.deserialize_map(Visitor)
 }
 
+// shared by `GetExternalIdResponse` and `GetXmlIdResponse`
+fn missing_external_ids(external_ids: &HashMap<OdooId, Option<String>>) -> Vec<OdooId> {
+    let mut missing: Vec<OdooId> = external_ids
+        .iter()
+        .filter(|(_, xmlid)| xmlid.is_none())
+        .map(|(id, _)| *id)
+        .collect();
+    missing.sort_unstable();
+    missing
+}
+
 /// Fetch the XMLID for the given record(s)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jvec};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.get_external_id(
 ///     "res.partner",
 ///     vec![1, 2]
@@ -1055,7 +1832,7 @@ where
     args = ["ids"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GetExternalId {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -1074,22 +1851,35 @@ pub struct GetExternalId {
 }
 
 /// The response to a [`GetExternalId`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct GetExternalIdResponse {
+    /// The external id for each requested record (`None` if it has none)
     #[serde(deserialize_with = "get_external_id_deserialize")]
-    pub external_ids: HashMap<OdooId, String>,
+    pub external_ids: HashMap<OdooId, Option<String>>,
+}
+
+impl GetExternalIdResponse {
+    /// Fetch the external id for the record `id`
+    pub fn get(&self, id: OdooId) -> Option<&str> {
+        self.external_ids.get(&id)?.as_deref()
+    }
+
+    /// Fetch the (sorted) ids of the records without an external id
+    pub fn missing(&self) -> Vec<OdooId> {
+        missing_external_ids(&self.external_ids)
+    }
 }
 
 /// Fetch the XMLID for the given record(s)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.get_xml_id(
 ///     "res.partner",
 ///     vec![1, 2]
@@ -1105,7 +1895,7 @@ pub struct GetExternalIdResponse {
     args = ["ids"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GetXmlId {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -1124,22 +1914,35 @@ pub struct GetXmlId {
 }
 
 /// The response to a [`GetXmlId`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct GetXmlIdResponse {
+    /// The external id for each requested record (`None` if it has none)
     #[serde(deserialize_with = "get_external_id_deserialize")]
-    pub external_ids: HashMap<OdooId, String>,
+    pub external_ids: HashMap<OdooId, Option<String>>,
+}
+
+impl GetXmlIdResponse {
+    /// Fetch the external id for the record `id`
+    pub fn get(&self, id: OdooId) -> Option<&str> {
+        self.external_ids.get(&id)?.as_deref()
+    }
+
+    /// Fetch the (sorted) ids of the records without an external id
+    pub fn missing(&self) -> Vec<OdooId> {
+        missing_external_ids(&self.external_ids)
+    }
 }
 
 /// Fetch the `display_naame` for the given record(s)
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
 /// # use odoo_api::{OdooClient, jmap};
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
+/// # let client = client.authenticate_manual("", "", 1, "", None);
 /// client.name_get(
 ///     "res.partner",
 ///     vec![1, 2, 3]
@@ -1147,6 +1950,10 @@ pub struct GetXmlIdResponse {
 /// # Ok(())
 /// # }
 /// ```
+///
+/// **Note:** `name_get` was removed in Odoo 17.0. Use [`DisplayNames`] instead,
+/// or `name_get_with_fallback()` to try `name_get` first and fall back to
+/// reading `display_name` if the server doesn't have it.
 /// <br />
 ///
 /// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L1560-L1584)
@@ -1155,7 +1962,7 @@ pub struct GetXmlIdResponse {
     args = ["ids"],
     kwargs = [],
 )]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct NameGet {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
@@ -1174,14 +1981,18 @@ pub struct NameGet {
 }
 
 /// The response to a [`NameGet`] request
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct NameGetResponse {
     pub display_names: Vec<NameGetResponseItem>,
 }
 
 /// An individual [`NameGet`] response item
-#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
+///
+/// This is serialized as an `[id, name]` pair. For [`DisplayNames`], it's
+/// deserialized from a `{"id": .., "display_name": ..}` record instead (with
+/// the `strict-responses` feature, any other keys are rejected).
+#[derive(Debug, Clone, PartialEq, Serialize_tuple)]
 pub struct NameGetResponseItem {
     /// The record id
     pub id: OdooId,
@@ -1190,32 +2001,70 @@ pub struct NameGetResponseItem {
     pub name: String,
 }
 
-/// Create a new record, passing only the `name` field
+impl<'de> Deserialize<'de> for NameGetResponseItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            // `name_get`
+            Pair(OdooId, String),
+
+            // `read(["display_name"])`
+            Record {
+                id: OdooId,
+                display_name: Value,
+                #[serde(flatten)]
+                _extra: Map<String, Value>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Pair(id, name) => Self { id, name },
+            Repr::Record {
+                id,
+                display_name,
+                _extra,
+            } => {
+                #[cfg(feature = "strict-responses")]
+                if let Some(key) = _extra.keys().next() {
+                    return Err(de::Error::unknown_field(key, &["id", "display_name"]));
+                }
+                Self {
+                    id,
+                    name: display_name.as_str().unwrap_or_default().to_string(),
+                }
+            }
+        })
+    }
+}
+
+/// Fetch the `display_name` for the given record(s), via `read`
+///
+/// Odoo 17.0 removed `name_get` in favour of the `display_name` field. This
+/// reads `display_name` instead, and returns the same response as [`NameGet`],
+/// so it works on all versions.
+///
+/// See also `name_get_with_fallback()`, which tries `name_get` first.
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
-/// # use odoo_api::{OdooClient, jmap};
+/// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
-/// client.name_create(
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let names = client.display_names(
 ///     "res.partner",
-///     "I am a test!".into()
+///     vec![1, 2, 3]
 /// ).send()?;
 /// # Ok(())
 /// # }
 /// ```
-/// <br />
-///
-/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L1586-L1606)
-#[odoo_orm(
-    method = "name_create",
-    args = ["name"],
-    kwargs = [],
-)]
-#[derive(Debug)]
-pub struct NameCreate {
+#[derive(Clone)]
+pub struct DisplayNames {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
 
@@ -1228,138 +2077,1652 @@ pub struct NameCreate {
     /// The Odoo model
     pub model: String,
 
-    /// A name for the new record
-    pub name: String,
+    /// The records
+    pub ids: OdooIds,
 }
 
-#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
-pub struct NameCreateResponse {
-    /// The record id
-    pub id: OdooId,
+// as with the macro-generated structs, the password is redacted
+impl fmt::Debug for DisplayNames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayNames")
+            .field("database", &self.database)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .field("model", &self.model)
+            .field("ids", &self.ids)
+            .finish()
+    }
+}
 
-    /// The record `display_name`
-    pub name: String,
+// sent as `read(ids, fields=["display_name"])`
+impl Serialize for DisplayNames {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_orm(self, &self.database, self.uid, &self.password, serializer)
+    }
 }
 
-/// Search for records based on their `name` field
+/// Serialize a hand-written ORM request as the `execute_kw` arg tuple
 ///
-/// This is a shortcut to the `search()` method with only one domain component:
-/// `[("name", "ilike", name)]`. This function is generally used by the Odoo searchbar,
-/// and by the search function on x2many fields.
+/// This matches the Serialize impl generated by `#[odoo_orm]`.
+fn serialize_orm<T, S>(
+    request: &T,
+    database: &str,
+    uid: OdooId,
+    password: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: OdooOrmMethod,
+    S: serde::Serializer,
+{
+    let (model, method, args, kwargs) = request.to_execute_kw();
+
+    let mut state = serializer.serialize_tuple(7)?;
+    state.serialize_element(database)?;
+    state.serialize_element(&uid)?;
+    state.serialize_element(password)?;
+    state.serialize_element(&model)?;
+    state.serialize_element(&method)?;
+    state.serialize_element(&args)?;
+    state.serialize_element(&kwargs)?;
+    state.end()
+}
+
+impl JsonRpcParams for DisplayNames {
+    type Container<T> = OdooOrmContainer<Self>;
+    type Response = NameGetResponse;
+    const IS_READONLY: bool = true;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl OdooOrmMethod for DisplayNames {
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+
+    fn method(&self) -> &'static str {
+        "read"
+    }
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>) {
+        let mut kwargs = Map::new();
+        kwargs.insert("fields".into(), serde_json::json!(["display_name"]));
+        let args = vec![serde_json::json!(self.ids)];
+        (self.model.clone(), self.method().into(), args, kwargs)
+    }
+}
+
+impl OdooKwargsMethod for DisplayNames {}
+
+#[cfg(feature = "client")]
+impl<I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<odoo_api::client::Authed, I> {
+    /// Fetch the `display_name` for the given record(s), via `read`
+    ///
+    /// See [`DisplayNames`] for more info.
+    pub fn display_names<ID: Into<OdooIds>>(
+        &self,
+        model: &str,
+        ids: ID,
+    ) -> odoo_api::client::OdooRequest<'_, DisplayNames, I> {
+        let display_names = DisplayNames {
+            database: self.auth.database.clone(),
+            uid: self.auth.uid,
+            password: self.auth.password.clone(),
+            model: model.into(),
+            ids: ids.into(),
+        };
+
+        let endpoint = self.build_endpoint(display_names.endpoint());
+        self.build_kwargs_request(display_names, &endpoint)
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "read",
+        name: "display_names",
+        auth: true,
+        args: &["ids"],
+        kwargs: &[],
+    }
+}
+
+/// Check whether the current user belongs to a group
 ///
-/// **Note**: Some models may override this method to provide custom "name search"
-/// behaviour.
+/// This calls `res.users.has_group()` on the current user, with the group's
+/// XMLID (e.g. `account.group_account_manager`).
+///
+/// Note that Odoo 17.0+ expects `has_group()` to be called on a user record
+/// (which is sent here); older versions ignore the record, and check the
+/// current user.
 ///
 /// ## Example
 /// ```no_run
-/// # #[cfg(not(feature = "types-only"))]
+/// # #[cfg(feature = "blocking")]
 /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
-/// # use odoo_api::{OdooClient, jmap};
+/// # use odoo_api::OdooClient;
 /// # let client = OdooClient::new_reqwest_blocking("")?;
-/// # let mut client = client.authenticate_manual("", "", 1, "", None);
-/// client.name_search(
-///     "res.partner",
-///     "Admini%".into(),
-///     None,
-///     None,
-///     None,
-/// ).send()?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.user_has_group("account.group_account_manager").send()?;
+/// if resp.has_group {
+///     println!("The user is an accounting manager");
+/// }
 /// # Ok(())
 /// # }
 /// ```
-/// <br />
+///<br />
 ///
-/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L1608-L1634)
-#[odoo_orm(
-    method = "name_search",
+/// See: [base/models/res_users.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/addons/base/models/res_users.py#L1017-L1044)
+#[derive(Clone)]
+pub struct HasGroup {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The group XMLID (e.g. `base.group_user`)
+    pub group: String,
+}
+
+// as with the macro-generated structs, the password is redacted
+impl fmt::Debug for HasGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HasGroup")
+            .field("database", &self.database)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .field("group", &self.group)
+            .finish()
+    }
+}
+
+// sent as `res.users.browse(uid).has_group(group)`
+impl Serialize for HasGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_orm(self, &self.database, self.uid, &self.password, serializer)
+    }
+}
+
+impl JsonRpcParams for HasGroup {
+    type Container<T> = OdooOrmContainer<Self>;
+    type Response = HasGroupResponse;
+    const IS_READONLY: bool = true;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl OdooOrmMethod for HasGroup {
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+
+    fn method(&self) -> &'static str {
+        "has_group"
+    }
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>) {
+        let args = vec![serde_json::json!([self.uid]), serde_json::json!(self.group)];
+        ("res.users".into(), self.method().into(), args, Map::new())
+    }
+}
+
+impl OdooKwargsMethod for HasGroup {}
+
+/// The response to a [`HasGroup`] request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HasGroupResponse {
+    pub has_group: bool,
+}
+
+/// Fetch the groups of the current user
+///
+/// This reads the `groups_id` field of the current user, and returns the group
+/// ids. To resolve the group XMLIDs, pass the ids to
+/// [`get_external_id`](GetExternalId).
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.user_groups().send()?;
+///
+/// // optionally, resolve the XMLIDs (e.g. `base.group_user`)
+/// let xmlids = client.get_external_id("res.groups", resp.groups).send()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UserGroups {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+}
+
+// as with the macro-generated structs, the password is redacted
+impl fmt::Debug for UserGroups {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserGroups")
+            .field("database", &self.database)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .finish()
+    }
+}
+
+// sent as `res.users.read([uid], fields=["groups_id"])`
+impl Serialize for UserGroups {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_orm(self, &self.database, self.uid, &self.password, serializer)
+    }
+}
+
+impl JsonRpcParams for UserGroups {
+    type Container<T> = OdooOrmContainer<Self>;
+    type Response = UserGroupsResponse;
+    const IS_READONLY: bool = true;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl OdooOrmMethod for UserGroups {
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+
+    fn method(&self) -> &'static str {
+        "read"
+    }
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>) {
+        let mut kwargs = Map::new();
+        kwargs.insert("fields".into(), serde_json::json!(["groups_id"]));
+        let args = vec![serde_json::json!([self.uid])];
+        ("res.users".into(), self.method().into(), args, kwargs)
+    }
+}
+
+impl OdooKwargsMethod for UserGroups {}
+
+/// The response to a [`UserGroups`] request
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UserGroupsResponse {
+    /// The `res.groups` ids
+    pub groups: Vec<OdooId>,
+}
+
+impl<'de> Deserialize<'de> for UserGroupsResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Record {
+            #[serde(default)]
+            groups_id: Vec<OdooId>,
+        }
+
+        // `read` returns a list with the single user record
+        let records = Vec::<Record>::deserialize(deserializer)?;
+        Ok(Self {
+            groups: records
+                .into_iter()
+                .next()
+                .map(|record| record.groups_id)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "client")]
+impl<I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<odoo_api::client::Authed, I> {
+    /// Check whether the current user belongs to a group
+    ///
+    /// See [`HasGroup`] for more info.
+    pub fn user_has_group(&self, group: &str) -> odoo_api::client::OdooRequest<'_, HasGroup, I> {
+        let has_group = HasGroup {
+            database: self.auth.database.clone(),
+            uid: self.auth.uid,
+            password: self.auth.password.clone(),
+            group: group.into(),
+        };
+
+        let endpoint = self.build_endpoint(has_group.endpoint());
+        self.build_kwargs_request(has_group, &endpoint)
+    }
+
+    /// Fetch the groups of the current user
+    ///
+    /// See [`UserGroups`] for more info.
+    pub fn user_groups(&self) -> odoo_api::client::OdooRequest<'_, UserGroups, I> {
+        let user_groups = UserGroups {
+            database: self.auth.database.clone(),
+            uid: self.auth.uid,
+            password: self.auth.password.clone(),
+        };
+
+        let endpoint = self.build_endpoint(user_groups.endpoint());
+        self.build_kwargs_request(user_groups, &endpoint)
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "has_group",
+        name: "user_has_group",
+        auth: true,
+        args: &["group"],
+        kwargs: &[],
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "read",
+        name: "user_groups",
+        auth: true,
+        args: &[],
+        kwargs: &[],
+    }
+}
+
+/// Change the current user's password
+///
+/// This calls `res.users.change_password()`, which checks the old password
+/// server-side (as the "Change Password" wizard in the webclient does). An
+/// incorrect old password is returned as a `PasswordChangeRejected` error.
+///
+/// **Note**: The client still holds the old password afterwards, so any
+/// further requests will fail. Authenticate a new client with the new password.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client.user_change_password("old-password", "new-password").send()?;
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// See: [base/models/res_users.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/addons/base/models/res_users.py)
+#[derive(Clone)]
+pub struct ChangePassword {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The current password
+    pub old_password: String,
+
+    /// The new password
+    pub new_password: String,
+}
+
+// as with the macro-generated structs, the passwords are redacted
+impl fmt::Debug for ChangePassword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChangePassword")
+            .field("database", &self.database)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .field("old_password", &"***")
+            .field("new_password", &"***")
+            .finish()
+    }
+}
+
+// sent as `res.users.change_password(old_password, new_password)`
+impl Serialize for ChangePassword {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_orm(self, &self.database, self.uid, &self.password, serializer)
+    }
+}
+
+impl JsonRpcParams for ChangePassword {
+    type Container<T> = OdooOrmContainer<Self>;
+    type Response = ChangePasswordResponse;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+
+    // `res.users.change_password` checks the old password itself
+    #[cfg(feature = "client")]
+    fn map_error(&self, error: &JsonRpcErrorData) -> Option<ParseResponseError> {
+        error
+            .name
+            .ends_with("exceptions.AccessDenied")
+            .then_some(ParseResponseError::PasswordChangeRejected)
+    }
+}
+
+impl OdooOrmMethod for ChangePassword {
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+
+    fn method(&self) -> &'static str {
+        "change_password"
+    }
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>) {
+        let args = vec![
+            serde_json::json!(self.old_password),
+            serde_json::json!(self.new_password),
+        ];
+        ("res.users".into(), self.method().into(), args, Map::new())
+    }
+}
+
+impl OdooKwargsMethod for ChangePassword {}
+
+/// The response to a [`ChangePassword`] request
+///
+/// The return value differs between Odoo versions (`True` or `None`), so it's
+/// ignored. Failures are returned as errors instead.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangePasswordResponse {}
+
+impl<'de> Deserialize<'de> for ChangePasswordResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de::IgnoredAny::deserialize(deserializer)?;
+        Ok(Self {})
+    }
+}
+
+/// The user's notification preference (from the `mail` module)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum NotificationType {
+    /// Receive notifications by email
+    #[serde(rename = "email")]
+    Email,
+
+    /// Receive notifications in the Odoo inbox
+    #[serde(rename = "inbox")]
+    Inbox,
+}
+
+/// The preferences a user can change on their own account
+///
+/// Only the fields which are set are written. These fields are in Odoo's
+/// `SELF_WRITEABLE_FIELDS`, so they can be written without admin rights.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UserPreferences {
+    /// The user's language (e.g. `en_US`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// The user's timezone (e.g. `Europe/Brussels`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+
+    /// The user's email signature (HTML)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+
+    /// How the user receives notifications (requires the `mail` module)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_type: Option<NotificationType>,
+}
+
+/// Update the current user's preferences
+///
+/// This writes the [`UserPreferences`] fields which are set on the current
+/// user (`res.users`).
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # use odoo_api::service::orm::UserPreferences;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client
+///     .user_update_preferences(UserPreferences {
+///         tz: Some("Europe/Brussels".into()),
+///         ..Default::default()
+///     })
+///     .send()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UpdatePreferences {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The preferences to write
+    pub preferences: UserPreferences,
+}
+
+// as with the macro-generated structs, the password is redacted
+impl fmt::Debug for UpdatePreferences {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpdatePreferences")
+            .field("database", &self.database)
+            .field("uid", &self.uid)
+            .field("password", &"***")
+            .field("preferences", &self.preferences)
+            .finish()
+    }
+}
+
+// sent as `res.users.write([uid], preferences)`
+impl Serialize for UpdatePreferences {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_orm(self, &self.database, self.uid, &self.password, serializer)
+    }
+}
+
+impl JsonRpcParams for UpdatePreferences {
+    type Container<T> = OdooOrmContainer<Self>;
+    type Response = WriteResponse;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl OdooOrmMethod for UpdatePreferences {
+    fn endpoint(&self) -> &'static str {
+        "/jsonrpc"
+    }
+
+    fn method(&self) -> &'static str {
+        "write"
+    }
+    fn to_execute_kw(&self) -> (String, String, Vec<Value>, Map<String, Value>) {
+        let args = vec![
+            serde_json::json!([self.uid]),
+            serde_json::json!(self.preferences),
+        ];
+        ("res.users".into(), self.method().into(), args, Map::new())
+    }
+}
+
+impl OdooKwargsMethod for UpdatePreferences {}
+
+#[cfg(feature = "client")]
+impl<I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<odoo_api::client::Authed, I> {
+    /// Change the current user's password
+    ///
+    /// See [`ChangePassword`] for more info.
+    pub fn user_change_password(
+        &self,
+        old_password: &str,
+        new_password: &str,
+    ) -> odoo_api::client::OdooRequest<'_, ChangePassword, I> {
+        let change_password = ChangePassword {
+            database: self.auth.database.clone(),
+            uid: self.auth.uid,
+            password: self.auth.password.clone(),
+            old_password: old_password.into(),
+            new_password: new_password.into(),
+        };
+
+        let endpoint = self.build_endpoint(change_password.endpoint());
+        self.build_kwargs_request(change_password, &endpoint)
+    }
+
+    /// Update the current user's preferences
+    ///
+    /// See [`UpdatePreferences`] for more info.
+    pub fn user_update_preferences(
+        &self,
+        preferences: UserPreferences,
+    ) -> odoo_api::client::OdooRequest<'_, UpdatePreferences, I> {
+        let update_preferences = UpdatePreferences {
+            database: self.auth.database.clone(),
+            uid: self.auth.uid,
+            password: self.auth.password.clone(),
+            preferences,
+        };
+
+        let endpoint = self.build_endpoint(update_preferences.endpoint());
+        self.build_kwargs_request(update_preferences, &endpoint)
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "change_password",
+        name: "user_change_password",
+        auth: true,
+        args: &["old_password", "new_password"],
+        kwargs: &[],
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "orm",
+        method: "write",
+        name: "user_update_preferences",
+        auth: true,
+        args: &["preferences"],
+        kwargs: &[],
+    }
+}
+
+/// Create a new record, passing only the `name` field
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client.name_create(
+///     "res.partner",
+///     "I am a test!"
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+/// <br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L1586-L1606)
+#[odoo_orm(
+    method = "name_create",
     args = ["name"],
-    kwargs = ["args", "operator", "limit"],
+    kwargs = [],
 )]
-#[derive(Debug)]
-pub struct NameSearch {
+#[derive(Debug, Clone)]
+pub struct NameCreate {
     /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub database: String,
 
     /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
     pub uid: OdooId,
 
-    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
-    pub password: String,
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// A name for the new record
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize_tuple, Deserialize_tuple)]
+pub struct NameCreateResponse {
+    /// The record id
+    pub id: OdooId,
+
+    /// The record `display_name`
+    pub name: String,
+}
+
+/// A domain comparison operator
+///
+/// See: [odoo/osv/expression.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/osv/expression.py)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Operator {
+    #[serde(rename = "=")]
+    Eq,
+
+    #[serde(rename = "!=")]
+    NotEq,
+
+    #[serde(rename = "<")]
+    Lt,
+
+    #[serde(rename = "<=")]
+    Le,
+
+    #[serde(rename = ">")]
+    Gt,
+
+    #[serde(rename = ">=")]
+    Ge,
+
+    #[serde(rename = "=?")]
+    EqOrUnset,
+
+    #[serde(rename = "like")]
+    Like,
+
+    #[serde(rename = "not like")]
+    NotLike,
+
+    #[serde(rename = "ilike")]
+    ILike,
+
+    #[serde(rename = "not ilike")]
+    NotILike,
+
+    #[serde(rename = "=like")]
+    EqLike,
+
+    #[serde(rename = "=ilike")]
+    EqILike,
+
+    #[serde(rename = "in")]
+    In,
+
+    #[serde(rename = "not in")]
+    NotIn,
+
+    #[serde(rename = "child_of")]
+    ChildOf,
+
+    #[serde(rename = "parent_of")]
+    ParentOf,
+}
+
+/// Search for records based on their `name` field
+///
+/// This is a shortcut to the `search()` method with only one domain component:
+/// `[("name", "ilike", name)]`. This function is generally used by the Odoo searchbar,
+/// and by the search function on x2many fields.
+///
+/// **Note**: Some models may override this method to provide custom "name search"
+/// behaviour.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// # use odoo_api::service::orm::Operator;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// client.name_search(
+///     "res.partner",
+///     "Admini",
+/// )
+/// .operator(Operator::EqILike)
+/// .limit(10)
+/// .send()?;
+/// # Ok(())
+/// # }
+/// ```
+/// <br />
+///
+/// See: [odoo/models.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/odoo/models.py#L1608-L1634)
+#[odoo_orm(
+    method = "name_search",
+    args = ["name"],
+    kwargs = ["args", "operator", "limit"],
+)]
+#[derive(Debug, Clone)]
+pub struct NameSearch {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The name to search for (can include operators like `%`)
+    pub name: String,
+
+    /// An optional search domain (e.g. built with [`sdomain!`](crate::sdomain))
+    pub args: Option<Vec<Value>>,
+
+    /// The operator for the "name test" (Odoo defaults to `ilike`)
+    pub operator: Option<Operator>,
+
+    /// Limit the number of results
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize_tuple, Deserialize_tuple)]
+#[serde(transparent)]
+pub struct NameSearchResponse {
+    pub records: Vec<NameSearchResponseItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize_tuple, Deserialize_tuple)]
+pub struct NameSearchResponseItem {
+    /// The record id
+    pub id: OdooId,
+
+    /// The record `display_name`
+    pub name: String,
+}
+
+/// Post a message in a record's chatter
+///
+/// The model must inherit `mail.thread`, and `ids` must be a single record. The
+/// `body` is sent as-is (it isn't escaped), so it may contain HTML, e.g.
+/// `<p>Hello</p>`.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # use odoo_api::service::orm::MessageType;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.message_post(
+///     "res.partner",
+///     7,
+///     "<p>The order has shipped</p>",
+/// )
+/// .message_type(MessageType::Comment)
+/// .subtype_xmlid("mail.mt_comment")
+/// .partner_ids(vec![3])
+/// .send()?;
+///
+/// println!("New message id: {}", resp.id);
+/// # Ok(())
+/// # }
+/// ```
+/// <br />
+///
+/// See: [mail/models/mail_thread.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/mail/models/mail_thread.py)
+#[odoo_orm(
+    method = "message_post",
+    args = ["ids"],
+    kwargs = [
+        "body",
+        "subject",
+        "message_type",
+        "subtype_xmlid",
+        "partner_ids",
+        "attachment_ids"
+    ],
+)]
+#[derive(Debug, Clone)]
+pub struct MessagePost {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The record to post on
+    pub ids: OdooIds,
+
+    /// The message body (HTML)
+    pub body: String,
+
+    /// The message subject
+    pub subject: Option<String>,
+
+    /// The message type (Odoo defaults to `notification`)
+    pub message_type: Option<MessageType>,
+
+    /// The message subtype, e.g. `mail.mt_comment` (a message) or `mail.mt_note`
+    /// (an internal note)
+    pub subtype_xmlid: Option<String>,
+
+    /// Extra recipients (`res.partner` ids)
+    ///
+    /// These are plain ids, rather than x2many commands.
+    pub partner_ids: Option<Vec<OdooId>>,
+
+    /// Existing attachments (`ir.attachment` ids) to link to the message
+    pub attachment_ids: Option<Vec<OdooId>>,
+}
+
+/// The response to a [`MessagePost`] request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MessagePostResponse {
+    /// The new `mail.message` id
+    pub id: OdooId,
+}
+
+/// The type of a chatter message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MessageType {
+    #[serde(rename = "comment")]
+    Comment,
+
+    #[serde(rename = "notification")]
+    Notification,
+
+    #[serde(rename = "email")]
+    Email,
+
+    #[serde(rename = "user_notification")]
+    UserNotification,
+}
+
+/// Schedule an activity on a record (or set of records)
+///
+/// The model must inherit `mail.activity.mixin`. One activity is created for
+/// each record. The `user_id` defaults to the activity type's default user, or
+/// the current user.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.activity_schedule(
+///     "crm.lead",
+///     12,
+///     "mail.mail_activity_data_call",
+/// )
+/// .date_deadline("2024-03-01")
+/// .summary("Follow up")
+/// .user_id(6)
+/// .send()?;
+///
+/// println!("New activity: {:?}", resp.id());
+/// # Ok(())
+/// # }
+/// ```
+/// <br />
+///
+/// See: [mail/models/mail_activity_mixin.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/mail/models/mail_activity_mixin.py)
+#[odoo_orm(
+    method = "activity_schedule",
+    args = ["ids"],
+    kwargs = ["act_type_xmlid", "date_deadline", "summary", "note", "user_id"],
+)]
+#[derive(Debug, Clone)]
+pub struct ActivitySchedule {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The Odoo model
+    pub model: String,
+
+    /// The records to schedule the activity on
+    pub ids: OdooIds,
+
+    /// The activity type, e.g. `mail.mail_activity_data_todo`
+    pub act_type_xmlid: String,
+
+    /// The deadline, as `YYYY-MM-DD` (Odoo defaults to the activity type's delay)
+    pub date_deadline: Option<String>,
+
+    /// A short summary
+    pub summary: Option<String>,
+
+    /// The activity note (HTML)
+    pub note: Option<String>,
+
+    /// The assigned user (`res.users` id)
+    pub user_id: Option<OdooId>,
+}
+
+/// The response to an [`ActivitySchedule`] request
+///
+/// `activity_schedule()` returns the new `mail.activity` records, which aren't
+/// converted to ids by Odoo; over JSON-RPC they're sent as their `repr` (e.g.
+/// `"mail.activity(12,)"`). This parses the ids from the `repr`, or from a
+/// plain id (or list of ids), in case an override returns those.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ActivityScheduleResponse {
+    /// The new `mail.activity` ids (one per record)
+    #[serde(deserialize_with = "deserialize_activity_ids")]
+    pub ids: Vec<OdooId>,
+}
+
+impl ActivityScheduleResponse {
+    /// The new activity id, if exactly one was created
+    pub fn id(&self) -> Option<OdooId> {
+        match self.ids[..] {
+            [id] => Some(id),
+            _ => None,
+        }
+    }
+}
+
+/// Deserialize the `activity_schedule()` result (see [`ActivityScheduleResponse`])
+fn deserialize_activity_ids<'de, D: Deserializer<'de>>(de: D) -> Result<Vec<OdooId>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Ids {
+        One(OdooId),
+        Many(Vec<OdooId>),
+        Repr(String),
+    }
+
+    match Ids::deserialize(de)? {
+        Ids::One(id) => Ok(vec![id]),
+        Ids::Many(ids) => Ok(ids),
+        Ids::Repr(repr) => parse_recordset_repr(&repr).ok_or_else(|| {
+            de::Error::custom(format!(
+                "expected a `mail.activity` recordset, got {:?}",
+                repr
+            ))
+        }),
+    }
+}
+
+/// Parse the ids from a recordset `repr`, e.g. `mail.activity(12, 13)`
+fn parse_recordset_repr(repr: &str) -> Option<Vec<OdooId>> {
+    let inner = repr.strip_suffix(')')?.split_once('(')?.1;
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| id.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures;
+    use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::test::Result;
+    use crate::{jmap, jvec, sdomain, svec};
+    use serde_json::{from_value, json, to_value};
+
+    #[test]
+    fn create_one() -> Result<()> {
+        let expected = fixtures::orm::create_one_request();
+        let actual = to_value(
+            Create {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+                values: jmap! {"name": "Hello, world!"}.into(),
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_one_response() -> Result<()> {
+        let payload = fixtures::orm::create_one_response();
+
+        let response: JsonRpcResponse<CreateResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    #[test]
+    fn create_multi() -> Result<()> {
+        let expected = fixtures::orm::create_multi_request();
+        let actual = to_value(
+            Create {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+                values: jvec![
+                    {"name": "Hello, world!"},
+                    {"name": "Marco, polo!"}
+                ]
+                .into(),
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_multi_response() -> Result<()> {
+        let payload = fixtures::orm::create_multi_response();
+
+        let response: JsonRpcResponse<CreateResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    #[test]
+    fn read() -> Result<()> {
+        let expected = fixtures::orm::read_request();
+        let actual = to_value(
+            Read {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+                ids: vec![1, 2, 3].into(),
+                fields: svec!["id", "login"],
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn read_model() -> Result<()> {
+        use crate::model::OdooModel;
+
+        #[derive(Debug, PartialEq, OdooModel)]
+        #[odoo(model = "res.users")]
+        struct User {
+            id: OdooId,
+            login: String,
+            signature: Option<String>,
+
+            #[odoo(field = "partner_id", many2one)]
+            partner: (OdooId, String),
+
+            #[odoo(many2one)]
+            parent_id: Option<OdooId>,
+        }
+
+        // a captured `read` payload
+        let (client, captured) = crate::client::odoo_request::test::capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [
+                {
+                    "id": 2,
+                    "login": "admin",
+                    "signature": "<p>Mitchell Admin</p>",
+                    "partner_id": [3, "Mitchell Admin"],
+                    "parent_id": false
+                },
+                {
+                    "id": 6,
+                    "login": "demo",
+                    "signature": false,
+                    "partner_id": [7, "Marc Demo"],
+                    "parent_id": [3, "Mitchell Admin"]
+                }
+            ]
+        }));
+        let response = client.read_model::<User>(vec![2, 6]).send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(
+            body["params"]["args"],
+            json!([
+                "some-database",
+                2,
+                "password",
+                "res.users",
+                "read",
+                [[2, 6]],
+                {"fields": ["id", "login", "signature", "partner_id", "parent_id"]}
+            ])
+        );
+        assert_eq!(
+            response.records,
+            vec![
+                User {
+                    id: 2,
+                    login: "admin".into(),
+                    signature: Some("<p>Mitchell Admin</p>".into()),
+                    partner: (3, "Mitchell Admin".into()),
+                    parent_id: None,
+                },
+                User {
+                    id: 6,
+                    login: "demo".into(),
+                    signature: None,
+                    partner: (7, "Marc Demo".into()),
+                    parent_id: Some(3),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    /// None of the `OdooIds` requests reorder (or dedup) the ids
+    #[test]
+    fn ids_order() -> Result<()> {
+        fn check<T>(request: T) -> Result<()>
+        where
+            T: OdooOrmMethod + JsonRpcParams,
+            T::Container<T>: Serialize,
+        {
+            let actual = to_value(request.build(1000))?;
+            assert_eq!(actual["params"]["args"][5][0], json!([3, 1, 3, 2]));
+            Ok(())
+        }
+
+        let (database, uid, password) = ("some-database".to_string(), 2, "password".to_string());
+        let model = "res.partner".to_string();
+        let ids = || OdooIds::from(vec![3, 1, 3, 2]);
+        macro_rules! check {
+            ($ty:ident { $($field:ident: $value:expr),* $(,)? }) => {
+                check($ty {
+                    database: database.clone(),
+                    uid,
+                    password: password.clone(),
+                    model: model.clone(),
+                    ids: ids(),
+                    $($field: $value),*
+                })?
+            };
+        }
+
+        check!(Read {
+            fields: svec!["name"]
+        });
+        check!(Write { values: Map::new() });
+        check!(Unlink {});
+        check!(Archive {});
+        check!(Unarchive {});
+        check!(CopyMulti { default: None });
+        check!(Exists {});
+        check!(CheckAccessRules {
+            operation: AccessOperation::Write
+        });
+        check!(GetMetadata {});
+        check!(GetExternalId {});
+        check!(GetXmlId {});
+        check!(NameGet {});
+        check!(DisplayNames {});
+
+        #[derive(Debug)]
+        struct Partner;
+        impl<'de> Deserialize<'de> for Partner {
+            fn deserialize<D: Deserializer<'de>>(_: D) -> std::result::Result<Self, D::Error> {
+                Ok(Partner)
+            }
+        }
+        impl OdooModel for Partner {
+            const MODEL: &'static str = "res.partner";
+            const FIELDS: &'static [&'static str] = &["name"];
+        }
+        check(ReadModel::<Partner>::new(&database, uid, &password, ids()))?;
+
+        Ok(())
+    }
+
+    /// `to_execute_kw()` matches the serialized `execute_kw` args
+    #[test]
+    fn to_execute_kw() -> Result<()> {
+        fn check<T>(request: T) -> Result<()>
+        where
+            T: OdooOrmMethod + JsonRpcParams,
+            T::Container<T>: Serialize,
+        {
+            let (model, method, args, kwargs) = request.to_execute_kw();
+            let actual = to_value(request.build(1000))?;
+            assert_eq!(
+                actual["params"]["args"],
+                json!(["some-database", 2, "password", model, method, args, kwargs])
+            );
+            Ok(())
+        }
+
+        let read = Read {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+            model: "res.partner".into(),
+            ids: vec![1, 2, 3].into(),
+            fields: svec!["id", "login"],
+        };
+        let (model, method, args, kwargs) = read.to_execute_kw();
+        assert_eq!(model, "res.partner");
+        assert_eq!(method, "read");
+        assert_eq!(args, vec![json!([1, 2, 3])]);
+        assert_eq!(Value::Object(kwargs), json!({"fields": ["id", "login"]}));
+        check(read)?;
+
+        let search_read = SearchRead {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+            model: "res.partner".into(),
+            domain: jvec![["name", "ilike", "admin"]],
+            fields: svec!["name"],
+            offset: None,
+            limit: Some(5),
+            order: Some("id desc".into()),
+        };
+        let (_, _, _, kwargs) = search_read.to_execute_kw();
+        // unset `Option` kwargs are omitted
+        assert!(!kwargs.contains_key("offset"));
+        check(search_read)?;
+
+        check(NameSearch {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+            model: "res.partner".into(),
+            name: "Admin".into(),
+            args: None,
+            operator: Some(Operator::EqILike),
+            limit: None,
+        })?;
+
+        Ok(())
+    }
+
+    /// String arguments accept `&str`, `String`, and `Cow<str>`
+    #[cfg(feature = "client")]
+    #[test]
+    fn read_string_args() -> Result<()> {
+        use std::borrow::Cow;
+
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let model = String::from("res.partner");
+        let bodies = [
+            client.read("res.partner", 1, svec!["name"]).build_body()?,
+            client.read(model, 1, svec!["name"]).build_body()?,
+            client
+                .read(Cow::Borrowed("res.partner"), 1, svec!["name"])
+                .build_body()?,
+        ];
+        for body in bodies {
+            assert_eq!(body["params"]["args"][3], json!("res.partner"));
+            assert_eq!(body["params"]["args"][4], json!("read"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_response() -> Result<()> {
+        let payload = fixtures::orm::read_response();
+
+        let response: JsonRpcResponse<ReadResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    #[test]
+    fn read_response_typed() -> Result<()> {
+        let payload = fixtures::orm::read_response_typed();
+
+        let response: JsonRpcResponse<ReadResponse> = from_value(payload)?;
+        let records = match response {
+            JsonRpcResponse::Error(e) => return Err(e.error.into()),
+            JsonRpcResponse::Success(data) => data.result.records_typed(),
+        };
+
+        assert_eq!(records.len(), 2);
+        let admin = &records[0];
+        assert_eq!(admin["id"], FieldValue::Int(3));
+        assert_eq!(admin["name"].as_str(), Some("Administrator"));
+        assert_eq!(
+            admin["parent_id"].as_many2one(),
+            Some((1, "My Company (San Francisco)"))
+        );
+        assert_eq!(admin["category_id"].as_ids(), Some(&[4, 5][..]));
+        assert_eq!(admin["child_ids"], FieldValue::Ids(vec![]));
+        assert!(admin["email"].is_false());
+        assert_eq!(admin["active"].as_bool(), Some(true));
+        assert_eq!(admin["credit_limit"].as_f64(), Some(1500.5));
+
+        let bot = &records[1];
+        assert!(bot["parent_id"].is_false());
+        assert_eq!(bot["parent_id"].as_id(), None);
+        assert_eq!(bot["email"].as_str(), Some("odoobot@example.com"));
+        assert_eq!(bot["active"].as_bool(), Some(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write() -> Result<()> {
+        let expected = fixtures::orm::write_request();
+        let actual = to_value(
+            Write {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+                ids: 2.into(),
+                values: jmap! {"name": "The Admin Account"},
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_response() -> Result<()> {
+        let payload = fixtures::orm::write_response();
+
+        let response: JsonRpcResponse<WriteResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    #[test]
+    fn unlink() -> Result<()> {
+        let expected = fixtures::orm::unlink_request();
+        let actual = to_value(
+            Unlink {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+                ids: 3.into(), // the "default" user - be careful!
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unlink_response() -> Result<()> {
+        let payload = fixtures::orm::unlink_response();
 
-    /// The Odoo model
-    pub model: String,
+        let response: JsonRpcResponse<UnlinkResponse> = from_value(payload)?;
 
-    /// The name to search for (can include operators like `%`)
-    pub name: String,
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
 
-    /// An optional search domain
-    pub args: Option<Vec<Value>>,
+    #[test]
+    fn archive() -> Result<()> {
+        let expected = fixtures::orm::archive_request();
+        let actual = to_value(
+            Archive {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
 
-    /// A domain operator for the "name test"
-    ///
-    /// For example:
-    ///  * `ilike` (default)
-    ///  * `=`
-    ///  * ...etc
-    pub operator: Option<String>,
+                model: "res.partner".into(),
+                ids: vec![3, 4].into(),
+            }
+            .build(1000),
+        )?;
 
-    /// Limit the number of results
-    pub limit: Option<u32>,
-}
+        assert_eq!(actual, expected);
 
-#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
-#[serde(transparent)]
-pub struct NameSearchResponse {
-    pub records: Vec<NameSearchResponseItem>,
-}
+        Ok(())
+    }
 
-#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
-pub struct NameSearchResponseItem {
-    /// The record id
-    pub id: OdooId,
+    #[test]
+    fn archive_response() -> Result<()> {
+        let response = |result: Value| -> Result<ArchiveResponse> {
+            let payload = json!({
+                "jsonrpc": "2.0",
+                "id": 1000,
+                "result": result
+            });
+            match from_value::<JsonRpcResponse<ArchiveResponse>>(payload)? {
+                JsonRpcResponse::Error(e) => Err(e.error.into()),
+                JsonRpcResponse::Success(data) => Ok(data.result),
+            }
+        };
 
-    /// The record `display_name`
-    pub name: String,
-}
+        assert_eq!(response(json!(true))?, ArchiveResponse::Bool(true));
+        assert_eq!(response(Value::Null)?, ArchiveResponse::Null);
+        assert_eq!(
+            response(json!({"type": "ir.actions.act_window", "res_model": "account.move"}))?,
+            ArchiveResponse::Action(
+                jmap! {"type": "ir.actions.act_window", "res_model": "account.move"}
+            )
+        );
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::client::error::Result;
-    use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
-    use crate::{jmap, jvec, svec};
-    use serde_json::{from_value, json, to_value};
+        Ok(())
+    }
 
+    #[cfg(feature = "client")]
     #[test]
-    fn create_one() -> Result<()> {
-        let expected = json!({
+    fn unarchive() -> Result<()> {
+        let (client, captured) = crate::client::odoo_request::test::capture_client_with(json!({
             "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "create",
-                    [
-                        {"name": "Hello, world!"}
-                    ],
-                    {}
-                ]
+            "id": 1,
+            "result": true
+        }));
+        client.unarchive("res.partner", 3).send()?;
+
+        let (body, _) = &captured.borrow()[0];
+        assert_eq!(body["params"]["args"][4], json!("action_unarchive"));
+        assert_eq!(body["params"]["args"][5], json!([[3]]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_group() -> Result<()> {
+        let expected = fixtures::orm::read_group_request();
+        let actual = to_value(
+            ReadGroup {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+
+                domain: jvec![["id", ">", 0]],
+                fields: svec!["id", "name", "company_type"],
+                groupby: svec!["create_date:month", "company_id"],
+                offset: Some(0),
+                limit: Some(100),
+                orderby: Some("create_date desc".into()),
+                lazy: false,
             }
-        });
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    /// `None` kwargs are omitted, rather than sent as `null`
+    ///
+    /// Some Odoo versions crash on `orderby=None` for `read_group`.
+    #[test]
+    fn read_group_none_kwargs() -> Result<()> {
+        let expected = fixtures::orm::read_group_none_kwargs_request();
         let actual = to_value(
-            Create {
+            ReadGroup {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
-                values: jmap! {"name": "Hello, world!"}.into(),
+
+                domain: jvec![],
+                fields: svec!["id"],
+                groupby: svec!["company_id"],
+                offset: None,
+                limit: Some(10),
+                orderby: None,
+                lazy: true,
             }
             .build(1000),
         )?;
@@ -1370,14 +3733,78 @@ mod test {
     }
 
     #[test]
-    fn create_one_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": 47
-        });
+    fn read_group_response() -> Result<()> {
+        let payload = fixtures::orm::read_group_response();
 
-        let response: JsonRpcResponse<CreateResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<ReadGroupResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                let groups = &data.result.result;
+                assert_eq!(groups[0].key("company_id"), GroupKey::None);
+                assert_eq!(
+                    groups[0].key("create_date:month"),
+                    GroupKey::Text("January 2023".into())
+                );
+                assert_eq!(groups[0].range("create_date:month"), None);
+
+                assert_eq!(
+                    groups[1].key("company_id"),
+                    GroupKey::Many2One {
+                        id: 1,
+                        name: "Test!".into()
+                    }
+                );
+                assert_eq!(
+                    groups[1].range("create_date:month"),
+                    Some(GroupRange {
+                        start: "2022-12-01 00:00:00".into(),
+                        end: "2023-01-01 00:00:00".into(),
+                    })
+                );
+
+                // the granularity is optional, and missing keys are empty
+                assert_eq!(
+                    groups[1].key("create_date"),
+                    groups[1].key("create_date:month")
+                );
+                assert_eq!(groups[1].key("partner_id"), GroupKey::None);
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn search_read() -> Result<()> {
+        let expected = fixtures::orm::search_read_request();
+        let actual = to_value(
+            SearchRead {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+
+                domain: jvec![["company_type", "=", "company"]],
+                fields: svec!["id", "name", "company_type"],
+                offset: Some(0),
+                limit: Some(100),
+                order: Some("create_date desc".into()),
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_read_response() -> Result<()> {
+        let payload = fixtures::orm::search_read_response();
+
+        let response: JsonRpcResponse<SearchReadResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -1386,42 +3813,54 @@ mod test {
     }
 
     #[test]
-    fn create_multi() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "create",
-                    [
-                        [
-                            {"name": "Hello, world!"},
-                            {"name": "Marco, polo!"}
-                        ]
-                    ],
-                    {}
-                ]
+    fn search() -> Result<()> {
+        let expected = fixtures::orm::search_request();
+        let actual = to_value(
+            Search {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+
+                domain: jvec![["company_type", "=", "company"]],
+                offset: Some(0),
+                limit: Some(100),
+                order: Some("create_date desc".into()),
             }
-        });
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn search_response() -> Result<()> {
+        let payload = fixtures::orm::search_response();
+
+        let response: JsonRpcResponse<SearchResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    #[test]
+    fn search_count() -> Result<()> {
+        let expected = fixtures::orm::search_count_request();
         let actual = to_value(
-            Create {
+            SearchCount {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
-                values: jvec![
-                    {"name": "Hello, world!"},
-                    {"name": "Marco, polo!"}
-                ]
-                .into(),
+
+                domain: jvec![["company_type", "=", "company"]],
+                limit: None,
             }
             .build(1000),
         )?;
@@ -1432,17 +3871,10 @@ mod test {
     }
 
     #[test]
-    fn create_multi_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                50,
-                51
-            ]
-        });
+    fn search_count_response() -> Result<()> {
+        let payload = fixtures::orm::search_count_response();
 
-        let response: JsonRpcResponse<CreateResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<SearchCountResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -1451,38 +3883,64 @@ mod test {
     }
 
     #[test]
-    fn read() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "read",
-                    [
-                        [1, 2, 3]
-                    ],
-                    {
-                        "fields": ["id", "login"]
-                    }
-                ]
+    fn search_count_limit() -> Result<()> {
+        let actual = to_value(
+            SearchCount {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+
+                domain: jvec![],
+                limit: Some(10),
             }
-        });
+            .build(1000),
+        )?;
+
+        assert_eq!(actual["params"]["args"][6], json!({"limit": 10}));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "client")]
+    fn search_count_limited() -> Result<()> {
+        let (client, captured) = crate::client::odoo_request::test::capture_client_with(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": 10
+        }));
+
+        // the version is unknown, so 16.0+ is assumed
+        client
+            .search_count_limited("res.partner", jvec![], 10)
+            .send()?;
+        client.version.set("15.0".parse()?).unwrap();
+        client
+            .search_count_limited("res.partner", jvec![], 10)
+            .send()?;
+
+        let captured = captured.borrow();
+        assert_eq!(captured[0].0["params"]["args"][6], json!({"limit": 10}));
+        assert_eq!(captured[1].0["params"]["args"][6], json!({}));
+
+        Ok(())
+    }
+
+    #[test]
+    fn copy() -> Result<()> {
+        let expected = fixtures::orm::copy_request();
         let actual = to_value(
-            Read {
+            Copy {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
-                ids: vec![1, 2, 3].into(),
-                fields: svec!["id", "login"],
+
+                id: 2,
+                default: None,
             }
             .build(1000),
         )?;
@@ -1493,68 +3951,83 @@ mod test {
     }
 
     #[test]
-    fn read_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                {
-                    "id": 1,
-                    "name": "My Company (San Francisco)"
-                },
-                {
-                    "id": 2,
-                    "name": "OdooBot"
-                },
-                {
-                    "id": 3,
-                    "name": "Administrator"
-                }
-            ]
-        });
+    fn copy_response() -> Result<()> {
+        let payload = fixtures::orm::copy_response();
+
+        let response: JsonRpcResponse<CopyResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(_) => Ok(()),
+        }
+    }
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn copy_multi() -> Result<()> {
+        let expected = fixtures::orm::copy_multi_request();
+        let actual = to_value(
+            CopyMulti {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+
+                ids: vec![2, 3].into(),
+                default: Some(jmap! {"active": false}),
+            }
+            .build(1000),
+        )?;
 
-        let response: JsonRpcResponse<ReadResponse> = from_value(payload)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn copy_multi_response() -> Result<()> {
+        // copying multiple records returns a list
+        let payload = fixtures::orm::copy_multi_response();
+
+        let response: JsonRpcResponse<CopyMultiResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => return Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert!(
+                    matches!(data.result.ids, CreateResponseItem::Multi(ids) if ids == [54, 55])
+                )
+            }
+        }
+
+        // but copying one record returns a plain int
+        let payload = fixtures::orm::copy_multi_response_one();
 
+        let response: JsonRpcResponse<CopyMultiResponse> = from_value(payload)?;
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                assert!(matches!(data.result.ids, CreateResponseItem::One(54)));
+                Ok(())
+            }
         }
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn write() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "write",
-                    [
-                        [2],
-                        {
-                            "name": "The Admin Account"
-                        }
-                    ],
-                    {}
-                ]
-            }
-        });
+    fn copy_data() -> Result<()> {
+        let expected = fixtures::orm::copy_data_request();
         let actual = to_value(
-            Write {
+            CopyData {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
+
                 ids: 2.into(),
-                values: jmap! {"name": "The Admin Account"},
+                default: Some(jmap! {"name": "Copy of Admin"}),
             }
             .build(1000),
         )?;
@@ -1564,52 +4037,59 @@ mod test {
         Ok(())
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn write_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+    fn copy_data_response() -> Result<()> {
+        let payload = fixtures::orm::copy_data_response();
 
-        let response: JsonRpcResponse<WriteResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<CopyDataResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.values.len(), 1);
+                assert_eq!(data.result.values[0]["name"], json!("Copy of Admin"));
+                Ok(())
+            }
         }
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
+    #[cfg(feature = "client")]
     #[test]
-    fn unlink() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "unlink",
-                    [
-                        [3],
-                    ],
-                    {}
-                ]
-            }
-        });
+    fn copy_multi_builder() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .copy_multi("res.partner", vec![2, 3])
+            .default(jmap! {"active": false})
+            .build_body()?;
+        assert_eq!(body["params"]["args"][4], json!("copy"));
+        assert_eq!(body["params"]["args"][5], json!([[2, 3]]));
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({"default": {"active": false}})
+        );
+
+        let body = client.copy_data("res.partner", 2).build_body()?;
+        assert_eq!(body["params"]["args"][4], json!("copy_data"));
+        assert_eq!(body["params"]["args"][5], json!([[2]]));
+        assert_eq!(body["params"]["args"][6], json!({}));
+
+        Ok(())
+    }
+
+    #[test]
+    fn exists() -> Result<()> {
+        let expected = fixtures::orm::exists_request();
         let actual = to_value(
-            Unlink {
+            Exists {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
-                ids: 3.into(), // the "default" user - be careful!
+
+                ids: vec![1, 2, -1, 999999999].into(),
             }
             .build(1000),
         )?;
@@ -1620,14 +4100,10 @@ mod test {
     }
 
     #[test]
-    fn unlink_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": true
-        });
+    fn exists_response() -> Result<()> {
+        let payload = fixtures::orm::exists_response();
 
-        let response: JsonRpcResponse<UnlinkResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<ExistsResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -1636,58 +4112,18 @@ mod test {
     }
 
     #[test]
-    fn read_group() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "read_group",
-                    [
-                        [
-                            ["id", ">", 0]
-                        ],
-                        [
-                            "id",
-                            "name",
-                            "company_type"
-                        ],
-                        [
-                            "create_date:month",
-                            "company_id"
-                        ]
-                    ],
-                    {
-                        "offset": 0,
-                        "limit": 100,
-                        "orderby": "create_date desc",
-                        "lazy": false
-                    }
-                ]
-            }
-        });
+    fn check_access_rights() -> Result<()> {
+        let expected = fixtures::orm::check_access_rights_request();
         let actual = to_value(
-            ReadGroup {
+            CheckAccessRights {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
-                model: "res.partner".into(),
+                model: "stock.quant".into(),
 
-                domain: jvec![["id", ">", 0]],
-                fields: svec!["id", "name", "company_type"],
-                groupby: svec!["create_date:month", "company_id"],
-                offset: Some(0),
-                limit: Some(100),
-                orderby: Some("create_date desc".into()),
-                lazy: false,
+                operation: AccessOperation::Unlink,
+                raise_exception: false,
             }
             .build(1000),
         )?;
@@ -1698,78 +4134,10 @@ mod test {
     }
 
     #[test]
-    fn read_group_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                {
-                    "__count": 5,
-                    "create_date:month": "January 2023",
-                    "company_id": false,
-                    "__domain": [
-                        "&",
-                        "&",
-                        "&",
-                        [
-                            "create_date",
-                            ">=",
-                            "2023-01-01 00:00:00"
-                        ],
-                        [
-                            "create_date",
-                            "<",
-                            "2023-02-01 00:00:00"
-                        ],
-                        [
-                            "company_id",
-                            "=",
-                            false
-                        ],
-                        [
-                            "id",
-                            ">",
-                            0
-                        ]
-                    ]
-                },
-                {
-                    "__count": 1,
-                    "create_date:month": "December 2022",
-                    "company_id": [
-                        1,
-                        "Test!"
-                    ],
-                    "__domain": [
-                        "&",
-                        "&",
-                        "&",
-                        [
-                            "create_date",
-                            ">=",
-                            "2022-12-01 00:00:00"
-                        ],
-                        [
-                            "create_date",
-                            "<",
-                            "2023-01-01 00:00:00"
-                        ],
-                        [
-                            "company_id",
-                            "=",
-                            1
-                        ],
-                        [
-                            "id",
-                            ">",
-                            0
-                        ]
-                    ]
-                },
-            ]
-        });
+    fn check_access_rights_response() -> Result<()> {
+        let payload = fixtures::orm::check_access_rights_response();
 
-        let response: JsonRpcResponse<ReadGroupResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<CheckAccessRightsResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -1778,50 +4146,18 @@ mod test {
     }
 
     #[test]
-    fn search_read() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "search_read",
-                    [],
-                    {
-                        "domain": [
-                            ["company_type", "=", "company"]
-                        ],
-                        "fields": [
-                            "id",
-                            "name",
-                            "company_type"
-                        ],
-                        "offset": 0,
-                        "limit": 100,
-                        "order": "create_date desc"
-                    }
-                ]
-            }
-        });
+    fn check_access_rules() -> Result<()> {
+        let expected = fixtures::orm::check_access_rules_request();
         let actual = to_value(
-            SearchRead {
+            CheckAccessRules {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                domain: jvec![["company_type", "=", "company"]],
-                fields: svec!["id", "name", "company_type"],
-                offset: Some(0),
-                limit: Some(100),
-                order: Some("create_date desc".into()),
+                ids: vec![1, 2].into(),
+                operation: AccessOperation::Unlink,
             }
             .build(1000),
         )?;
@@ -1832,25 +4168,22 @@ mod test {
     }
 
     #[test]
-    fn search_read_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                {
-                    "id": 48,
-                    "name": "Partner #1",
-                    "company_type": "person"
-                },
-                {
-                    "id": 49,
-                    "name": "Partner #2",
-                    "company_type": "person"
-                },
-            ]
-        });
+    fn check_access_rules_response() -> Result<()> {
+        //TODO: this method, annoyingly, returns None on success. because of this,
+        // the `result` field is never added. we need to modify JsonRpcResponse to
+        // support this. until then, this method does not work!
+        let payload = fixtures::orm::check_access_rules_response();
 
-        let response: JsonRpcResponse<SearchReadResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<CheckAccessRulesResponse> = {
+            match from_value(payload) {
+                Ok(d) => d,
+                Err(_) => {
+                    // As a *super* hacky workaround, we can check for deserialization
+                    // errors
+                    return Ok(());
+                }
+            }
+        };
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -1859,45 +4192,18 @@ mod test {
     }
 
     #[test]
-    fn search() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "search",
-                    [
-                        [
-                            ["company_type", "=", "company"]
-                        ]
-                    ],
-                    {
-                        "offset": 0,
-                        "limit": 100,
-                        "order": "create_date desc"
-                    }
-                ]
-            }
-        });
+    fn check_field_access_rights() -> Result<()> {
+        let expected = fixtures::orm::check_field_access_rights_request();
         let actual = to_value(
-            Search {
+            CheckFieldAccessRights {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                domain: jvec![["company_type", "=", "company"]],
-                offset: Some(0),
-                limit: Some(100),
-                order: Some("create_date desc".into()),
+                operation: AccessOperation::Unlink,
+                fields: svec!["id", "email", "this_is_a_fake_field"],
             }
             .build(1000),
         )?;
@@ -1908,20 +4214,10 @@ mod test {
     }
 
     #[test]
-    fn search_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                48,
-                49,
-                47,
-                45,
-                44,
-            ]
-        });
+    fn check_field_access_rights_response() -> Result<()> {
+        let payload = fixtures::orm::check_field_access_rights_response();
 
-        let response: JsonRpcResponse<SearchResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<CheckFieldAccessRightsResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -1930,41 +4226,18 @@ mod test {
     }
 
     #[test]
-    fn search_count() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "search_count",
-                    [
-                        [
-                            ["company_type", "=", "company"]
-                        ]
-                    ],
-                    {
-                        "limit": null
-                    }
-                ]
-            }
-        });
+    fn fields_get() -> Result<()> {
+        let expected = fixtures::orm::fields_get_request();
         let actual = to_value(
-            SearchCount {
+            FieldsGet {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                domain: jvec![["company_type", "=", "company"]],
-                limit: None,
+                allfields: None,
+                attributes: Some(svec!["string", "type"]),
             }
             .build(1000),
         )?;
@@ -1975,55 +4248,32 @@ mod test {
     }
 
     #[test]
-    fn search_count_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": 46
-        });
+    fn fields_get_response() -> Result<()> {
+        let payload = fixtures::orm::fields_get_response();
 
-        let response: JsonRpcResponse<SearchCountResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<FieldsGetResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.fields["email"]["string"], json!("Email"));
+                Ok(())
+            }
         }
     }
 
     #[test]
-    fn copy() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "copy",
-                    [
-                        2
-                    ],
-                    {
-                        "default": null
-                    }
-                ]
-            }
-        });
+    fn get_metadata() -> Result<()> {
+        let expected = fixtures::orm::get_metadata_request();
         let actual = to_value(
-            Copy {
+            GetMetadata {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                id: 2,
-                default: None,
+                ids: vec![1, 2].into(),
             }
             .build(1000),
         )?;
@@ -2034,14 +4284,10 @@ mod test {
     }
 
     #[test]
-    fn copy_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": 54
-        });
+    fn get_metadata_response() -> Result<()> {
+        let payload = fixtures::orm::get_metadata_response();
 
-        let response: JsonRpcResponse<CopyResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<GetMetadataResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -2049,38 +4295,84 @@ mod test {
         }
     }
 
+    /// Unknown keys are ignored, or rejected with `strict-responses`
     #[test]
-    fn exists() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "exists",
-                    [
-                        [1, 2, -1, 999999999]
-                    ],
-                    {
-                    }
-                ]
+    fn get_metadata_response_extra_key() {
+        let result = from_value::<GetMetadataResponse>(json!([{
+            "id": 1,
+            "create_uid": false,
+            "create_date": "2022-09-15 20:00:41",
+            "write_uid": false,
+            "write_date": "2023-01-16 01:17:19",
+            "xmlid": "base.main_partner",
+            "xmlids": [{"xmlid": "base.main_partner", "noupdate": true}],
+            "noupdate": true,
+            "company_id": 1
+        }]));
+        if cfg!(feature = "strict-responses") {
+            let err = result.unwrap_err().to_string();
+            assert!(err.starts_with("unknown field `company_id`"), "{}", err);
+        } else {
+            assert_eq!(result.unwrap().metadata[0]["company_id"], json!(1));
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn get_metadata_response_records() -> Result<()> {
+        use time::macros::datetime;
+
+        let response: GetMetadataResponse = from_value(json!([
+            {
+                "id": 1,
+                "create_uid": false,
+                "create_date": "2022-09-15 20:00:41.123456",
+                "write_uid": [2, "Administrator"],
+                "write_date": "2023-01-16 01:17:19",
+                "xmlid": "base.main_partner",
+                "noupdate": true
+            },
+            {
+                "id": 7,
+                "create_uid": [2, "Administrator"],
+                "create_date": "2023-02-20 22:32:37",
+                "write_uid": [2, "Administrator"],
+                "write_date": false,
+                "xmlid": false,
+                "noupdate": false
             }
-        });
+        ]))?;
+
+        let records = response.records()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0].create_date.map(|date| date.0),
+            Some(datetime!(2022-09-15 20:00:41.123456))
+        );
+        assert_eq!(
+            records[0].write_date.map(|date| date.0),
+            Some(datetime!(2023-01-16 01:17:19))
+        );
+        assert_eq!(records[0].xmlid.as_deref(), Some("base.main_partner"));
+        assert!(records[0].noupdate);
+        assert!(records[1].write_date.is_none());
+        assert!(records[1].xmlid.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_external_id() -> Result<()> {
+        let expected = fixtures::orm::get_external_id_request();
         let actual = to_value(
-            Exists {
+            GetExternalId {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                ids: vec![1, 2, -1, 999999999].into(),
+                ids: vec![1, 2].into(),
             }
             .build(1000),
         )?;
@@ -2091,17 +4383,10 @@ mod test {
     }
 
     #[test]
-    fn exists_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                1,
-                2
-            ]
-        });
+    fn get_external_id_response() -> Result<()> {
+        let payload = fixtures::orm::get_external_id_response();
 
-        let response: JsonRpcResponse<ExistsResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<GetExternalIdResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -2109,40 +4394,55 @@ mod test {
         }
     }
 
+    /// Records without an external id may be returned as `false`
     #[test]
-    fn check_access_rights() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "stock.quant",
-                    "check_access_rights",
-                    [
-                        "unlink"
-                    ],
-                    {
-                        "raise_exception": false
-                    }
-                ]
+    fn get_external_id_response_false() -> Result<()> {
+        let payload = fixtures::orm::get_external_id_response_false();
+
+        let response: JsonRpcResponse<GetExternalIdResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.get(1), Some("base.main_partner"));
+                assert_eq!(data.result.external_ids[&7], None);
+                assert_eq!(data.result.missing(), vec![7]);
+                Ok(())
             }
-        });
+        }
+    }
+
+    /// Records without an external id may also be returned as `""`
+    #[test]
+    fn get_external_id_response_empty() -> Result<()> {
+        let payload = fixtures::orm::get_external_id_response_empty();
+
+        let response: JsonRpcResponse<GetXmlIdResponse> = from_value(payload)?;
+
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.get(1), Some("base.main_partner"));
+                assert_eq!(data.result.get(9), None);
+                assert_eq!(data.result.get(2), None);
+                assert_eq!(data.result.missing(), vec![4, 9]);
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn get_xml_id() -> Result<()> {
+        let expected = fixtures::orm::get_xml_id_request();
         let actual = to_value(
-            CheckAccessRights {
+            GetXmlId {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
-                model: "stock.quant".into(),
+                model: "res.partner".into(),
 
-                operation: AccessOperation::Unlink,
-                raise_exception: false,
+                ids: vec![1, 2].into(),
             }
             .build(1000),
         )?;
@@ -2153,14 +4453,10 @@ mod test {
     }
 
     #[test]
-    fn check_access_rights_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": false
-        });
+    fn get_xml_id_response() -> Result<()> {
+        let payload = fixtures::orm::get_xml_id_response();
 
-        let response: JsonRpcResponse<CheckAccessRightsResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<GetXmlIdResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -2169,39 +4465,17 @@ mod test {
     }
 
     #[test]
-    fn check_access_rules() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "check_access_rule",
-                    [
-                        [1, 2],
-                        "unlink"
-                    ],
-                    {
-                    }
-                ]
-            }
-        });
+    fn name_get() -> Result<()> {
+        let expected = fixtures::orm::name_get_request();
         let actual = to_value(
-            CheckAccessRules {
+            NameGet {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                ids: vec![1, 2].into(),
-                operation: AccessOperation::Unlink,
+                ids: vec![1, 2, 3].into(),
             }
             .build(1000),
         )?;
@@ -2212,30 +4486,10 @@ mod test {
     }
 
     #[test]
-    fn check_access_rules_response() -> Result<()> {
-        //TODO: this method, annoyingly, returns None on success. because of this,
-        // the `result` field is never added. we need to modify JsonRpcResponse to
-        // support this. until then, this method does not work!
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                "id",
-                "email",
-                "this_is_a_fake_field"
-            ]
-        });
+    fn name_get_response() -> Result<()> {
+        let payload = fixtures::orm::name_get_response();
 
-        let response: JsonRpcResponse<CheckAccessRulesResponse> = {
-            match from_value(payload) {
-                Ok(d) => d,
-                Err(_) => {
-                    // As a *super* hacky workaround, we can check for deserialization
-                    // errors
-                    return Ok(());
-                }
-            }
-        };
+        let response: JsonRpcResponse<NameGetResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -2243,44 +4497,19 @@ mod test {
         }
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn check_field_access_rights() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "check_field_access_rights",
-                    [
-                        "unlink",
-                        [
-                            "id",
-                            "email",
-                            "this_is_a_fake_field"
-                        ]
-                    ],
-                    {
-                    }
-                ]
-            }
-        });
+    fn display_names() -> Result<()> {
+        let expected = fixtures::orm::display_names_request();
         let actual = to_value(
-            CheckFieldAccessRights {
+            DisplayNames {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                operation: AccessOperation::Unlink,
-                fields: svec!["id", "email", "this_is_a_fake_field"],
+                ids: vec![1, 2, 3].into(),
             }
             .build(1000),
         )?;
@@ -2290,58 +4519,56 @@ mod test {
         Ok(())
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
+    /// Unknown keys are ignored, or rejected with `strict-responses`
     #[test]
-    fn check_field_access_rights_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                "id",
-                "email",
-                "this_is_a_fake_field"
-            ]
-        });
+    fn display_names_response_extra_key() {
+        let result = from_value::<NameGetResponse>(json!([
+            {"id": 1, "display_name": "Test!", "name": "Test"}
+        ]));
+        if cfg!(feature = "strict-responses") {
+            let err = result.unwrap_err().to_string();
+            assert!(err.starts_with("unknown field `name`"), "{}", err);
+        } else {
+            assert_eq!(result.unwrap().display_names[0].name, "Test!");
+        }
+    }
 
-        let response: JsonRpcResponse<CheckFieldAccessRightsResponse> = from_value(payload)?;
+    #[test]
+    fn display_names_response() -> Result<()> {
+        let payload = fixtures::orm::display_names_response();
+
+        let response: JsonRpcResponse<NameGetResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                let names: Vec<_> = data
+                    .result
+                    .display_names
+                    .iter()
+                    .map(|item| (item.id, item.name.as_str()))
+                    .collect();
+                assert_eq!(
+                    names,
+                    vec![(1, "Test!"), (3, "YourCompany, Administrator"), (4, "")]
+                );
+                Ok(())
+            }
         }
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn get_metadata() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "get_metadata",
-                    [
-                        [1, 2]
-                    ],
-                    {
-                    }
-                ]
-            }
-        });
+    fn has_group() -> Result<()> {
+        let expected = fixtures::orm::has_group_request();
         let actual = to_value(
-            GetMetadata {
+            HasGroup {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
-                model: "res.partner".into(),
-
-                ids: vec![1, 2].into(),
+                group: "account.group_account_manager".into(),
             }
             .build(1000),
         )?;
@@ -2351,82 +4578,34 @@ mod test {
         Ok(())
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn get_metadata_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                {
-                    "id": 1,
-                    "create_uid": false,
-                    "create_date": "2022-09-15 20:00:41",
-                    "write_uid": [
-                        2,
-                        "Administrator"
-                    ],
-                    "write_date": "2023-01-16 01:17:19",
-                    "xmlid": "base.main_partner",
-                    "noupdate": true
-                },
-                {
-                    "id": 2,
-                    "create_uid": [
-                        1,
-                        "OdooBot"
-                    ],
-                    "create_date": "2022-09-15 20:00:43",
-                    "write_uid": [
-                        1,
-                        "OdooBot"
-                    ],
-                    "write_date": "2023-02-20 22:32:37",
-                    "xmlid": "base.partner_root",
-                    "noupdate": true
-                }
-            ]
-        });
-
-        let response: JsonRpcResponse<GetMetadataResponse> = from_value(payload)?;
-
-        match response {
-            JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+    fn has_group_response() -> Result<()> {
+        for (result, expected) in [(json!(true), true), (json!(false), false)] {
+            let payload = json!({
+                "jsonrpc": "2.0",
+                "id": 1000,
+                "result": result
+            });
+            let response: JsonRpcResponse<HasGroupResponse> = from_value(payload)?;
+            match response {
+                JsonRpcResponse::Error(e) => return Err(e.error.into()),
+                JsonRpcResponse::Success(data) => assert_eq!(data.result.has_group, expected),
+            }
         }
+
+        Ok(())
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn get_external_id() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "get_external_id",
-                    [
-                        [1, 2]
-                    ],
-                    {
-                    }
-                ]
-            }
-        });
+    fn user_groups() -> Result<()> {
+        let expected = fixtures::orm::user_groups_request();
         let actual = to_value(
-            GetExternalId {
+            UserGroups {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
-
-                model: "res.partner".into(),
-
-                ids: vec![1, 2].into(),
             }
             .build(1000),
         )?;
@@ -2436,57 +4615,92 @@ mod test {
         Ok(())
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn get_external_id_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": {
-                "1": "base.main_partner",
-                "2": "base.partner_root"
-            }
-        });
-
-        let response: JsonRpcResponse<GetExternalIdResponse> = from_value(payload)?;
+    fn user_groups_response() -> Result<()> {
+        let payload = fixtures::orm::user_groups_response();
+        let response: JsonRpcResponse<UserGroupsResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => return Err(e.error.into()),
+            JsonRpcResponse::Success(data) => assert_eq!(data.result.groups, vec![1, 7, 12]),
+        }
 
+        // the user isn't readable (e.g. archived)
+        let payload = fixtures::orm::user_groups_response_empty();
+        let response: JsonRpcResponse<UserGroupsResponse> = from_value(payload)?;
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                assert!(data.result.groups.is_empty());
+                Ok(())
+            }
         }
     }
 
+    /// See [`crate::service::object::test::execute`] for more info
     #[test]
-    fn get_xml_id() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "get_xml_id",
-                    [
-                        [1, 2]
-                    ],
-                    {
-                    }
-                ]
+    fn change_password() -> Result<()> {
+        let expected = fixtures::orm::change_password_request();
+        let request = ChangePassword {
+            database: "some-database".into(),
+            uid: 2,
+            password: "password".into(),
+
+            old_password: "old-password".into(),
+            new_password: "new-password".into(),
+        };
+        assert!(!format!("{:?}", request).contains("old-password"));
+        let actual = to_value(request.build(1000))?;
+
+        assert_eq!(actual, expected);
+
+        // the result differs between versions
+        for result in [json!(true), json!(null)] {
+            let payload = json!({"jsonrpc": "2.0", "id": 1000, "result": result});
+            let response: JsonRpcResponse<ChangePasswordResponse> = from_value(payload)?;
+            assert!(matches!(response, JsonRpcResponse::Success(_)));
+        }
+
+        Ok(())
+    }
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn update_preferences() -> Result<()> {
+        let expected = fixtures::orm::update_preferences_request();
+        let actual = to_value(
+            UpdatePreferences {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                // unset fields aren't written
+                preferences: UserPreferences {
+                    tz: Some("Europe/Brussels".into()),
+                    notification_type: Some(NotificationType::Inbox),
+                    ..Default::default()
+                },
             }
-        });
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_create() -> Result<()> {
+        let expected = fixtures::orm::name_create_request();
         let actual = to_value(
-            GetXmlId {
+            NameCreate {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                ids: vec![1, 2].into(),
+                name: "I am a test!".into(),
             }
             .build(1000),
         )?;
@@ -2497,17 +4711,10 @@ mod test {
     }
 
     #[test]
-    fn get_xml_id_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": {
-                "1": "base.main_partner",
-                "2": "base.partner_root"
-            }
-        });
+    fn name_create_response() -> Result<()> {
+        let payload = fixtures::orm::name_create_response();
 
-        let response: JsonRpcResponse<GetXmlIdResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<NameCreateResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -2516,68 +4723,59 @@ mod test {
     }
 
     #[test]
-    fn name_get() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "name_get",
-                    [
-                        [1, 2, 3]
-                    ],
-                    {
-                    }
-                ]
+    fn name_search() -> Result<()> {
+        let expected = fixtures::orm::name_search_request();
+        let actual = to_value(
+            NameSearch {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                model: "res.partner".into(),
+
+                name: "I am a test!".into(),
+                args: None,
+                operator: None,
+                limit: None,
             }
-        });
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_search_operator() -> Result<()> {
+        let expected = fixtures::orm::name_search_operator_request();
         let actual = to_value(
-            NameGet {
+            NameSearch {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                ids: vec![1, 2, 3].into(),
+                name: "Admin".into(),
+                args: Some(sdomain!["is_company" == true]),
+                operator: Some(Operator::NotILike),
+                limit: Some(5),
             }
             .build(1000),
         )?;
 
         assert_eq!(actual, expected);
+        assert_eq!(to_value(Operator::ChildOf)?, json!("child_of"));
 
         Ok(())
     }
 
     #[test]
-    fn name_get_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                [
-                    1,
-                    "Test!"
-                ],
-                [
-                    2,
-                    "OdooBot"
-                ],
-                [
-                    3,
-                    "YourCompany, Administrator"
-                ]
-            ]
-        });
+    fn name_search_response() -> Result<()> {
+        let payload = fixtures::orm::name_search_response();
 
-        let response: JsonRpcResponse<NameGetResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<NameSearchResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
@@ -2586,37 +4784,23 @@ mod test {
     }
 
     #[test]
-    fn name_create() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "name_create",
-                    [
-                        "I am a test!"
-                    ],
-                    {
-                    }
-                ]
-            }
-        });
+    fn message_post() -> Result<()> {
+        let expected = fixtures::orm::message_post_request();
         let actual = to_value(
-            NameCreate {
+            MessagePost {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
                 model: "res.partner".into(),
 
-                name: "I am a test!".into(),
+                ids: 7.into(),
+                body: "<p>The order has <b>shipped</b> &amp; will arrive soon</p>".into(),
+                subject: Some("Order S00042".into()),
+                message_type: Some(MessageType::Comment),
+                subtype_xmlid: Some("mail.mt_comment".into()),
+                partner_ids: Some(vec![3, 14]),
+                attachment_ids: Some(vec![21]),
             }
             .build(1000),
         )?;
@@ -2627,62 +4811,37 @@ mod test {
     }
 
     #[test]
-    fn name_create_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                56,
-                "I am a test!"
-            ]
-        });
+    fn message_post_response() -> Result<()> {
+        let payload = fixtures::orm::message_post_response();
 
-        let response: JsonRpcResponse<NameCreateResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<MessagePostResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.id, 512);
+                Ok(())
+            }
         }
     }
 
     #[test]
-    fn name_search() -> Result<()> {
-        let expected = json!({
-            "jsonrpc": "2.0",
-            "method": "call",
-            "id": 1000,
-            "params": {
-                "service": "object",
-                "method": "execute_kw",
-                "args": [
-                    "some-database",
-                    2,
-                    "password",
-                    "res.partner",
-                    "name_search",
-                    [
-                        "I am a test!"
-                    ],
-                    {
-                        "args": null,
-                        "operator": null,
-                        "limit": null,
-                    }
-                ]
-            }
-        });
+    fn activity_schedule() -> Result<()> {
+        let expected = fixtures::orm::activity_schedule_request();
         let actual = to_value(
-            NameSearch {
+            ActivitySchedule {
                 database: "some-database".into(),
                 uid: 2,
                 password: "password".into(),
 
-                model: "res.partner".into(),
+                model: "crm.lead".into(),
 
-                name: "I am a test!".into(),
-                args: None,
-                operator: None,
-                limit: None,
+                ids: 12.into(),
+                act_type_xmlid: "mail.mail_activity_data_call".into(),
+                date_deadline: Some("2024-03-01".into()),
+                summary: Some("Follow up".into()),
+                note: Some("<p>Ask about the <i>renewal</i></p>".into()),
+                user_id: Some(6),
             }
             .build(1000),
         )?;
@@ -2693,27 +4852,252 @@ mod test {
     }
 
     #[test]
-    fn name_search_response() -> Result<()> {
-        let payload = json!({
-            "jsonrpc": "2.0",
-            "id": 1000,
-            "result": [
-                [
-                    56,
-                    "I am a test!"
-                ],
-                [
-                    57,
-                    "I am a test!"
-                ]
-            ]
-        });
+    fn activity_schedule_response() -> Result<()> {
+        let payload = fixtures::orm::activity_schedule_response();
 
-        let response: JsonRpcResponse<NameSearchResponse> = from_value(payload)?;
+        let response: JsonRpcResponse<ActivityScheduleResponse> = from_value(payload)?;
 
         match response {
             JsonRpcResponse::Error(e) => Err(e.error.into()),
-            JsonRpcResponse::Success(_) => Ok(()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.id(), Some(31));
+
+                let ids: ActivityScheduleResponse = from_value(json!("mail.activity(31, 32)"))?;
+                assert_eq!(ids.ids, vec![31, 32]);
+                let ids: ActivityScheduleResponse = from_value(json!([31, 32]))?;
+                assert_eq!(ids.id(), None);
+                let ids: ActivityScheduleResponse = from_value(json!(31))?;
+                assert_eq!(ids.id(), Some(31));
+                assert!(from_value::<ActivityScheduleResponse>(json!("mail.activity")).is_err());
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Every method taking `ids` accepts a single id, a `Vec`, a slice, or an array
+    #[cfg(feature = "client")]
+    #[test]
+    fn ids_input_forms() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let ids = |body: Value| body["params"]["args"][5][0].clone();
+        let slice: &[OdooId] = &[1, 2];
+
+        let bodies = vec![
+            client.read("res.partner", 1, svec!["name"]).build_body()?,
+            client
+                .write("res.partner", vec![1, 2], jmap! {})
+                .build_body()?,
+            client.unlink("res.partner", slice).build_body()?,
+            client.exists("res.partner", [1, 2]).build_body()?,
+            client.name_get("res.partner", vec![1, 2]).build_body()?,
+            client.get_metadata("res.partner", 1).build_body()?,
+            client.get_external_id("res.partner", slice).build_body()?,
+            client
+                .check_access_rules("res.partner", [1, 2], AccessOperation::Write)
+                .build_body()?,
+        ];
+        let expected = [
+            json!([1]),
+            json!([1, 2]),
+            json!([1, 2]),
+            json!([1, 2]),
+            json!([1, 2]),
+            json!([1]),
+            json!([1, 2]),
+            json!([1, 2]),
+        ];
+        for (body, expected) in bodies.into_iter().zip(expected) {
+            assert_eq!(ids(body), expected);
         }
+
+        Ok(())
+    }
+
+    /// The `_manual` variants send the given credentials, from any client
+    #[cfg(feature = "client")]
+    #[test]
+    fn read_manual() -> std::result::Result<(), crate::client::error::ClosureError> {
+        use crate::client::OdooClient;
+
+        let client =
+            OdooClient::new_closure_blocking("http://localhost:8069", |_url, data, _session_id| {
+                let args = &data["params"]["args"];
+                assert_eq!(args[0], json!("some-database"));
+                assert_eq!(args[1], json!(2));
+                assert_eq!(args[2], json!("password"));
+                assert_eq!(args[4], json!("read"));
+                Ok((fixtures::orm::read_response().to_string(), None))
+            });
+        let response = client
+            .read_manual(
+                "some-database",
+                2,
+                "password",
+                "res.partner",
+                vec![1, 2, 3],
+                svec!["id", "name"],
+            )
+            .send()?;
+        assert_eq!(response.data.len(), 3);
+
+        // optional kwargs are still set on the returned request
+        let body = client
+            .search_manual("some-database", 2, "password", "res.partner", jvec![])
+            .limit(5)
+            .build_body()?;
+        assert_eq!(body["params"]["args"][6], json!({"limit": 5}));
+
+        Ok(())
+    }
+
+    /// The kwargs are laid out as `mail.thread` expects
+    #[cfg(feature = "client")]
+    #[test]
+    fn message_post_builder() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .message_post("res.partner", 7, "<p>Hi <a href=\"/odoo\">there</a></p>")
+            .subtype_xmlid("mail.mt_note")
+            .partner_ids(vec![3])
+            .build_body()?;
+
+        assert_eq!(body["params"]["args"][4], json!("message_post"));
+        assert_eq!(body["params"]["args"][5], json!([[7]]));
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({
+                "body": "<p>Hi <a href=\"/odoo\">there</a></p>",
+                "subtype_xmlid": "mail.mt_note",
+                "partner_ids": [3]
+            })
+        );
+
+        let body = client
+            .activity_schedule("crm.lead", vec![12, 13], "mail.mail_activity_data_todo")
+            .user_id(6)
+            .build_body()?;
+        assert_eq!(body["params"]["args"][5], json!([[12, 13]]));
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({
+                "act_type_xmlid": "mail.mail_activity_data_todo",
+                "user_id": 6
+            })
+        );
+
+        Ok(())
+    }
+
+    /// Optional kwargs are set with builder methods
+    #[cfg(feature = "client")]
+    #[test]
+    fn search_builder() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .search("res.partner", jvec![["id", ">", 0]])
+            .offset(5)
+            .limit(80)
+            .order("id desc")
+            .build_body()?;
+
+        assert_eq!(body["params"]["args"][5], json!([[["id", ">", 0]]]));
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({
+                "offset": 5,
+                "limit": 80,
+                "order": "id desc"
+            })
+        );
+
+        Ok(())
+    }
+
+    /// Optional kwargs are set with builder methods
+    #[cfg(feature = "client")]
+    #[test]
+    fn search_read_builder() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .search_read("res.partner", jvec![], svec!["name"])
+            .limit(10)
+            .build_body()?;
+
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({
+                "domain": [],
+                "fields": ["name"],
+                "limit": 10
+            })
+        );
+
+        Ok(())
+    }
+
+    /// Optional kwargs are set with builder methods
+    #[cfg(feature = "client")]
+    #[test]
+    fn read_group_builder() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .read_group(
+                "res.partner",
+                jvec![],
+                svec!["id"],
+                svec!["company_id"],
+                true,
+            )
+            .orderby("company_id")
+            .build_body()?;
+
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({
+                "orderby": "company_id",
+                "lazy": true
+            })
+        );
+
+        Ok(())
+    }
+
+    /// Optional kwargs are set with builder methods
+    #[cfg(feature = "client")]
+    #[test]
+    fn name_search_builder() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .name_search("res.partner", "Admin")
+            .args(jvec![["active", "=", true]])
+            .operator(Operator::EqILike)
+            .build_body()?;
+
+        assert_eq!(body["params"]["args"][5], json!(["Admin"]));
+        assert_eq!(
+            body["params"]["args"][6],
+            json!({
+                "args": [["active", "=", true]],
+                "operator": "=ilike"
+            })
+        );
+
+        Ok(())
+    }
+
+    /// The old positional signatures are still available (but deprecated)
+    #[cfg(feature = "client")]
+    #[test]
+    #[allow(deprecated)]
+    fn search_positional() -> Result<()> {
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .search_positional("res.partner", jvec![], None, Some(80), None)
+            .build_body()?;
+
+        assert_eq!(body["params"]["args"][6], json!({"limit": 80}));
+
+        Ok(())
     }
 }
@@ -0,0 +1,135 @@
+//! The Odoo "report" service (JSON-RPC)
+//!
+//! This service allows reports (e.g., invoice or sale order PDFs) to be rendered
+//! over RPC.
+//!
+//! Note that the `report` service was removed in Odoo 11.0. Newer versions render
+//! reports via the `/report/<type>/<report_name>/<ids>` HTTP routes instead.
+//! With the `async` or `blocking` features, the client's `report_pdf()` method
+//! downloads PDFs from those routes.
+
+use crate as odoo_api;
+use crate::jsonrpc::{OdooApiMethod, OdooId};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use odoo_api_macros::odoo_api;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_tuple::Serialize_tuple;
+
+/// Render a report for the given record(s)
+///
+/// This renders the report synchronously, and returns the base64-encoded report
+/// data (typically a PDF).
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.render_report(
+///     "sale.report_saleorder",
+///     vec![42],
+///     None, // data
+/// ).send()?;
+///
+/// // decode the base64 report data into raw bytes
+/// let pdf: Vec<u8> = resp.decode()?;
+///
+/// // write the data to a file ...
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// Reference: [odoo/service/report.py](https://github.com/odoo/odoo/blob/10.0/odoo/service/report.py)
+#[odoo_api(service = "report", method = "render_report", auth = true)]
+#[derive(Debug, Clone, Serialize_tuple)]
+pub struct RenderReport {
+    /// The database name (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub database: String,
+
+    /// The user id (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub uid: OdooId,
+
+    /// The user password (auto-filled by [`OdooClient`](crate::client::OdooClient))
+    pub password: String,
+
+    /// The report name (e.g., `sale.report_saleorder`)
+    pub report_name: String,
+
+    /// The records to render the report for
+    pub ids: Vec<OdooId>,
+
+    /// Optional report data
+    ///
+    /// This is passed to the report as `data`, and is typically used by
+    /// wizard-driven reports.
+    pub data: Option<Map<String, Value>>,
+}
+
+/// The response to a [`RenderReport`] request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderReportResponse {
+    /// The report data, as a base64-encoded string
+    pub result: String,
+
+    /// The report format (e.g., `pdf` or `html`)
+    pub format: String,
+}
+
+impl RenderReportResponse {
+    /// Decode the base64 `result` into raw bytes
+    pub fn decode(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        STANDARD.decode(&self.result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures;
+    use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::test::Result;
+    use serde_json::{from_value, to_value};
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn render_report() -> Result<()> {
+        let expected = fixtures::report::render_report_request();
+        let actual = to_value(
+            RenderReport {
+                database: "some-database".into(),
+                uid: 2,
+                password: "password".into(),
+
+                report_name: "sale.report_saleorder".into(),
+                ids: vec![42],
+                data: None,
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    /// See [`crate::service::object::test::execute_response`] for more info
+    #[test]
+    fn render_report_response() -> Result<()> {
+        let payload = fixtures::report::render_report_response();
+
+        let response: JsonRpcResponse<RenderReportResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.format, "pdf");
+                assert_eq!(data.result.decode().unwrap(), b"%PDF-1.4\n");
+                Ok(())
+            }
+        }
+    }
+}
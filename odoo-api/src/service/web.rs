@@ -5,16 +5,22 @@
 //! functionality that can be achieved with `execute` and `execute_kw`
 
 use crate as odoo_api;
-use crate::jsonrpc::OdooWebMethod;
+use crate::jsonrpc::{
+    JsonRpcId, JsonRpcParams, JsonRpcRequest, OdooId, OdooKwargsMethod, OdooWebContainer,
+    OdooWebMethod,
+};
+use crate::service::common::VersionResponse;
 use odoo_api_macros::odoo_web;
+use serde::de::DeserializeOwned;
 use serde::ser::SerializeTuple;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
+use std::fmt::Debug;
+use std::marker::PhantomData;
 
 //TODO: /web/session/get_lang_list (only v15+?)
 //TODO: /web/session/check
 //TODO: /web/session/change_password
-//TODO: /web/session/get_session_info
 //TODO: /web/session/modules
 //TODO: /web/session/modules
 //TODO: /web/session/destroy
@@ -43,31 +49,113 @@ use serde_json::Value;
     name = "web_session_authenticate",
     auth = false
 )]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionAuthenticate {
+    /// The database, or `null` if empty (see `authenticate_auto()`)
+    #[serde(serialize_with = "empty_as_null")]
     pub(crate) db: String,
     pub(crate) login: String,
     pub(crate) password: String,
 }
 
+/// Serialize an empty `db` as `null`, so Odoo picks the database itself
+///
+/// This works when the server has a single database (or a `dbfilter` which
+/// matches a single database).
+fn empty_as_null<S: serde::Serializer>(db: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    match db {
+        "" => serializer.serialize_none(),
+        db => serializer.serialize_str(db),
+    }
+}
+
 /// Represents the response to an Odoo [`SessionAuthenticate`] call
 ///
 /// Note that the generated `session_id` is not returned here. The response
 /// data contains some information about the Odoo session.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct SessionAuthenticateResponse {
     pub data: Value,
 }
 
+/// Fetch the current session info
+///
+/// This returns the same data as [`SessionAuthenticate`], for the session
+/// identified by the `session_id` cookie. The `uid` is `null` if the session
+/// isn't (fully) authenticated.
+///
+/// Reference: [web/controllers/session.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/web/controllers/session.py)
+#[odoo_web(
+    path = "/web/session/get_session_info",
+    name = "web_session_info",
+    auth = false
+)]
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionInfo {}
+
+/// Represents the response to an Odoo [`SessionInfo`] call
+pub type SessionInfoResponse = SessionAuthenticateResponse;
+
+/// Fetch the server version, via the webclient
+///
+/// This returns the same data as [`common_version`](crate::service::common::Version),
+/// but via `/web/webclient/version_info`. It's useful on deployments which block
+/// the `/jsonrpc` endpoint, and *doesn't require a session token*.
+///
+/// The client's `server_version()` falls back to this endpoint if `/jsonrpc` fails.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// let client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?;
+/// let resp = client.web_version_info().send()?;
+///
+/// println!("Version: {}", resp.server_version);
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// Reference: [web/controllers/webclient.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/web/controllers/webclient.py)
+#[odoo_web(
+    path = "/web/webclient/version_info",
+    name = "web_version_info",
+    auth = false
+)]
+#[derive(Debug, Clone, Serialize)]
+pub struct WebVersionInfo {}
+
+/// Represents the response to an Odoo [`WebVersionInfo`] call
+pub type WebVersionInfoResponse = VersionResponse;
+
 /// List the available databases
 ///
 /// This function *doesn't require a session token*, so it can be run on an OdooClient
 /// that hasn't been authenticated yet.
 ///
+/// Unlike [`db_list`](crate::client::OdooClient::db_list), this endpoint applies
+/// the server's `dbfilter`, and may still be available when `list_db` is disabled.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// let client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?;
+/// let resp = client.web_database_list().send()?;
+///
+/// println!("Databases: {:#?}", resp.databases);
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
 /// Reference: [web/controller/database.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/web/controllers/database.py#L176-L183)
 #[odoo_web(path = "/web/database/list", name = "web_database_list", auth = false)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DatabaseList {}
 
 // DatabaseList has no fields, but needs to output in JSON: `[]`
@@ -82,8 +170,587 @@ impl Serialize for DatabaseList {
 }
 
 /// Represents the response to an Odoo [`DatabaseList`] call
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct DatabaseListResponse {
     pub databases: Vec<String>,
 }
+
+/// Load an action definition
+///
+/// This is used by the webclient to resolve an action (e.g. `ir.actions.act_window`)
+/// into its full definition, including the views, domain, and context.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::OdooClient;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client.web_action_load(42, None).send()?;
+///
+/// if let Some(action) = resp.action {
+///     println!("Views: {:#?}", action.get("views"));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// Reference: [web/controllers/action.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/web/controllers/action.py#L19-L45)
+#[odoo_web(path = "/web/action/load", name = "web_action_load", auth = true)]
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionLoad {
+    /// The action id
+    pub action_id: OdooId,
+
+    /// Extra context used when evaluating the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_context: Option<Map<String, Value>>,
+}
+
+/// Represents the response to an Odoo [`ActionLoad`] call
+///
+/// If the action doesn't exist, Odoo returns `false` (deserialized as `None`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ActionLoadResponse {
+    /// The action definition
+    #[serde(deserialize_with = "crate::serde_helpers::false_as_none")]
+    pub action: Option<Map<String, Value>>,
+}
+
+/// Call a button method the way the webclient does
+///
+/// Buttons in the web UI (e.g. "Confirm" or "Post") are sent to
+/// `/web/dataset/call_button` rather than `call_kw`. Odoo calls the method as
+/// usual, but only returns the result if it's an action (e.g. a wizard), and
+/// `false` otherwise. Some modules also check the request path to behave
+/// differently for button calls, so use this (rather than the `call_button()`
+/// client method, which uses `execute_kw`) when that matters.
+///
+/// The `context` can be set with `.with_context()` on the request, as with
+/// the ORM methods.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp = client
+///     .dataset_call_button("account.move", "action_post", vec![42], jmap! {})
+///     .with_context(jmap! {"validate_analytic": true})
+///     .send()?;
+///
+/// if resp.into_result().is_action() {
+///     println!("The button opened a wizard");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///<br />
+///
+/// Reference: [web/controllers/dataset.py](https://github.com/odoo/odoo/blob/b6e195ccb3a6c37b0d980af159e546bdc67b1e42/addons/web/controllers/dataset.py)
+#[odoo_web(path = "/web/dataset/call_button", name = "dataset_call_button")]
+#[derive(Debug, Clone, Serialize)]
+pub struct DatasetCallButton {
+    /// The Odoo model
+    pub model: String,
+
+    /// The button method (e.g. `action_post`)
+    pub method: String,
+
+    /// The positional arguments: the record ids, then any extra arguments
+    pub args: Vec<Value>,
+
+    /// The keyword arguments (including the `context`)
+    pub kwargs: Map<String, Value>,
+}
+
+// the kwargs are a top-level param, rather than the last element of `args`
+impl OdooKwargsMethod for DatasetCallButton {
+    fn inject_context(params: &mut Value, context: &Map<String, Value>) {
+        let Some(kwargs) = params.get_mut("kwargs").and_then(Value::as_object_mut) else {
+            return;
+        };
+        match kwargs.get_mut("context").and_then(Value::as_object_mut) {
+            Some(existing) => existing.extend(context.clone()),
+            None => {
+                kwargs.insert("context".into(), Value::Object(context.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "client")]
+impl<I: odoo_api::client::RequestImpl> odoo_api::client::OdooClient<odoo_api::client::Authed, I> {
+    /// Call a button method the way the webclient does
+    ///
+    /// See [`DatasetCallButton`] for more info.
+    pub fn dataset_call_button<ID: Into<crate::jsonrpc::OdooIds>>(
+        &self,
+        model: &str,
+        method: &str,
+        ids: ID,
+        kwargs: Map<String, Value>,
+    ) -> odoo_api::client::OdooRequest<'_, DatasetCallButton, I> {
+        let ids = ids.into().into_inner();
+        let call_button = DatasetCallButton {
+            model: model.into(),
+            method: method.into(),
+            args: vec![ids.into()],
+            kwargs,
+        };
+
+        let endpoint = self.build_endpoint(call_button.endpoint());
+        self.build_request(call_button, &endpoint)
+    }
+}
+
+#[cfg(feature = "registry")]
+odoo_api::registry::inventory::submit! {
+    odoo_api::registry::MethodInfo {
+        service: "web",
+        method: "/web/dataset/call_button",
+        name: "dataset_call_button",
+        auth: true,
+        args: &["model", "method", "ids", "kwargs"],
+        kwargs: &[],
+    }
+}
+
+/// The response to a [`DatasetCallButton`] request
+///
+/// This is the action returned by the button, or `false`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DatasetCallButtonResponse {
+    pub result: Value,
+}
+
+#[cfg(feature = "client")]
+impl DatasetCallButtonResponse {
+    /// Sniff the result, as `call_button()` does
+    ///
+    /// A button without an action gives [`Bool(false)`](crate::client::MethodCallResult::Bool).
+    pub fn into_result(self) -> odoo_api::client::MethodCallResult {
+        odoo_api::client::MethodCallResult::from_value(self.result)
+    }
+}
+
+/// Call a custom JSON-RPC endpoint
+///
+/// Odoo modules may add their own JSON routes (e.g. `/my_module/api/v1/sync`),
+/// which use the same JSON-RPC envelope as the `/web` endpoints. This type
+/// sends `params` as-is, and deserializes the `result` into `R`.
+///
+/// ## Example
+/// ```no_run
+/// # #[cfg(feature = "blocking")]
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use odoo_api::{OdooClient, jmap};
+/// # use serde_json::Value;
+/// # let client = OdooClient::new_reqwest_blocking("")?;
+/// # let client = client.authenticate_manual("", "", 1, "", None);
+/// let resp: Value = client.web_call(
+///     "/my_module/api/v1/sync",
+///     jmap!{
+///         "since": "2023-01-01 00:00:00"
+///     }
+/// ).send()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WebCall<P, R> {
+    /// The endpoint path (e.g. `/my_module/api/v1/sync`)
+    pub endpoint: String,
+
+    /// The request params
+    pub params: P,
+
+    _response: PhantomData<fn() -> R>,
+}
+
+// derived `Clone` would require `R: Clone`
+impl<P: Clone, R> Clone for WebCall<P, R> {
+    fn clone(&self) -> Self {
+        Self {
+            endpoint: self.endpoint.clone(),
+            params: self.params.clone(),
+            _response: PhantomData,
+        }
+    }
+}
+
+impl<P, R> WebCall<P, R> {
+    pub fn new(endpoint: &str, params: P) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            params,
+            _response: PhantomData,
+        }
+    }
+}
+
+// only the params are sent; the endpoint is part of the URL
+impl<P: Serialize, R> Serialize for WebCall<P, R> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.params.serialize(serializer)
+    }
+}
+
+impl<P, R> JsonRpcParams for WebCall<P, R>
+where
+    P: Debug + Serialize,
+    R: Debug + DeserializeOwned,
+{
+    type Container<T> = OdooWebContainer<Self>;
+    type Response = R;
+
+    fn build(self, id: JsonRpcId) -> JsonRpcRequest<Self> {
+        self._build(id)
+    }
+}
+
+impl<P, R> OdooWebMethod for WebCall<P, R>
+where
+    P: Debug + Serialize,
+    R: Debug + DeserializeOwned,
+{
+    fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+#[cfg(feature = "client")]
+impl<S, I> odoo_api::client::OdooClient<S, I>
+where
+    S: odoo_api::client::AuthState,
+    I: odoo_api::client::RequestImpl,
+{
+    /// Call a custom JSON-RPC endpoint
+    ///
+    /// See [`WebCall`] for more info.
+    pub fn web_call<R, P>(
+        &self,
+        endpoint: &str,
+        params: P,
+    ) -> odoo_api::client::OdooRequest<'_, WebCall<P, R>, I>
+    where
+        P: Debug + Serialize,
+        R: Debug + DeserializeOwned,
+    {
+        let web_call = WebCall::new(endpoint, params);
+
+        let endpoint = self.build_endpoint(web_call.endpoint());
+        self.build_request(web_call, &endpoint)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures;
+    use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::test::Result;
+    use serde_json::{from_value, json, to_value};
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn database_list() -> Result<()> {
+        let expected = fixtures::web::database_list_request();
+        let actual = to_value(DatabaseList {}.build(1000))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dataset_call_button() -> Result<()> {
+        let expected = fixtures::web::dataset_call_button_request();
+        let mut kwargs = Map::new();
+        kwargs.insert("context".into(), json!({"lang": "en_US"}));
+        let actual = to_value(
+            DatasetCallButton {
+                model: "account.move".into(),
+                method: "action_post".into(),
+                args: vec![json!([42])],
+                kwargs,
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn dataset_call_button_response() -> Result<()> {
+        use crate::client::MethodCallResult;
+
+        let payload = fixtures::web::dataset_call_button_response();
+        let response: JsonRpcResponse<DatasetCallButtonResponse> = from_value(payload)?;
+        let JsonRpcResponse::Success(data) = response else {
+            panic!("expected a successful response");
+        };
+        let MethodCallResult::Action(action) = data.result.into_result() else {
+            panic!("expected an action");
+        };
+        assert_eq!(action.res_model.as_deref(), Some("validate.account.move"));
+        assert_eq!(action.context["active_ids"], json!([42]));
+
+        // no action
+        let response: JsonRpcResponse<DatasetCallButtonResponse> =
+            from_value(json!({"jsonrpc": "2.0", "id": 1000, "result": false}))?;
+        let JsonRpcResponse::Success(data) = response else {
+            panic!("expected a successful response");
+        };
+        assert_eq!(data.result.into_result(), MethodCallResult::Bool(false));
+
+        Ok(())
+    }
+
+    /// The context is merged into the top-level `kwargs`
+    #[cfg(feature = "client")]
+    #[test]
+    fn dataset_call_button_context() -> Result<()> {
+        use crate::jmap;
+
+        let (client, _captured) = crate::client::odoo_request::test::capture_client();
+        let body = client
+            .dataset_call_button(
+                "account.move",
+                "action_post",
+                vec![42, 43],
+                jmap! {"context": {"lang": "en_US"}},
+            )
+            .with_context(jmap! {"validate_analytic": true})
+            .build_body()?;
+
+        assert_eq!(
+            body["params"],
+            json!({
+                "model": "account.move",
+                "method": "action_post",
+                "args": [[42, 43]],
+                "kwargs": {
+                    "context": {"lang": "en_US", "validate_analytic": true}
+                }
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn session_authenticate() -> Result<()> {
+        let expected = fixtures::web::session_authenticate_request();
+        let mut authenticate = SessionAuthenticate {
+            db: "some-database".into(),
+            login: "admin".into(),
+            password: "password".into(),
+        };
+        let actual = to_value(authenticate.clone().build(1000))?;
+        assert_eq!(actual, expected);
+
+        // an empty database is sent as `null`
+        authenticate.db = String::new();
+        let actual = to_value(authenticate.build(1000))?;
+        assert_eq!(actual["params"]["db"], json!(null));
+
+        Ok(())
+    }
+
+    #[test]
+    fn session_info() -> Result<()> {
+        let expected = fixtures::web::session_info_request();
+        let actual = to_value(SessionInfo {}.build(1000))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn version_info() -> Result<()> {
+        let expected = fixtures::web::version_info_request();
+        let actual = to_value(WebVersionInfo {}.build(1000))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    /// See [`crate::service::object::test::execute_response`] for more info
+    #[test]
+    fn version_info_response() -> Result<()> {
+        let payload = fixtures::web::version_info_response();
+
+        let response: JsonRpcResponse<WebVersionInfoResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.server_serie, "16.0");
+                assert_eq!(data.result.server_version_info.major, 16);
+                Ok(())
+            }
+        }
+    }
+
+    /// See [`crate::service::object::test::execute_response`] for more info
+    #[test]
+    fn database_list_response() -> Result<()> {
+        let payload = fixtures::web::database_list_response();
+
+        let response: JsonRpcResponse<DatabaseListResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(
+                    data.result.databases,
+                    vec!["old-database", "new-database", "database3"]
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn action_load() -> Result<()> {
+        let expected = fixtures::web::action_load_request();
+        let actual = to_value(
+            ActionLoad {
+                action_id: 42,
+                additional_context: Some(json!({"active_id": 7}).as_object().unwrap().clone()),
+            }
+            .build(1000),
+        )?;
+
+        assert_eq!(actual, expected);
+
+        // `additional_context` is omitted when not set
+        let actual = to_value(
+            ActionLoad {
+                action_id: 42,
+                additional_context: None,
+            }
+            .build(1000),
+        )?;
+        assert_eq!(actual["params"], json!({"action_id": 42}));
+
+        Ok(())
+    }
+
+    /// See [`crate::service::object::test::execute_response`] for more info
+    #[test]
+    fn action_load_response() -> Result<()> {
+        let payload = fixtures::web::action_load_response();
+
+        let response: JsonRpcResponse<ActionLoadResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                let action = data.result.action.expect("action");
+                assert_eq!(action["res_model"], json!("res.partner"));
+                assert_eq!(action["views"][1], json!([101, "tree"]));
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn action_load_response_missing() -> Result<()> {
+        let payload = fixtures::web::action_load_response_missing();
+
+        let response: JsonRpcResponse<ActionLoadResponse> = from_value(payload)?;
+        match response {
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+            JsonRpcResponse::Success(data) => {
+                assert!(data.result.action.is_none());
+                Ok(())
+            }
+        }
+    }
+
+    /// See [`crate::service::object::test::execute`] for more info
+    #[test]
+    fn web_call() -> Result<()> {
+        let expected = json!({
+            "jsonrpc": "2.0",
+            "method": "call",
+            "id": 1000,
+            "params": {
+                "since": "2023-01-01 00:00:00",
+                "limit": 10
+            }
+        });
+        let call: WebCall<_, Value> = WebCall::new(
+            "/my_module/api/v1/sync",
+            json!({"since": "2023-01-01 00:00:00", "limit": 10}),
+        );
+        assert_eq!(call.endpoint(), "/my_module/api/v1/sync");
+        let actual = to_value(call.build(1000))?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn web_call_client() -> std::result::Result<(), crate::client::error::ClosureError> {
+        use crate::client::OdooClient;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[derive(Debug, Deserialize)]
+        struct SyncResponse {
+            updated: Vec<OdooId>,
+        }
+
+        let captured = Rc::new(RefCell::new(Vec::new()));
+        let inner = captured.clone();
+        let client = OdooClient::new_closure_blocking(
+            "http://localhost:8069/",
+            move |url, data, session_id| {
+                inner
+                    .borrow_mut()
+                    .push((url.to_string(), data, session_id.map(String::from)));
+                Ok((
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {"updated": [4, 5]}}).to_string(),
+                    None,
+                ))
+            },
+        )
+        .authenticate_manual(
+            "some-database",
+            "admin",
+            2,
+            "password",
+            Some("abc123".into()),
+        );
+
+        let resp: SyncResponse = client
+            .web_call("/my_module/api/v1/sync", json!({"limit": 10}))
+            .send()?;
+        assert_eq!(resp.updated, vec![4, 5]);
+
+        let (url, body, session_id) = &captured.borrow()[0];
+        assert_eq!(url, "http://localhost:8069/my_module/api/v1/sync");
+        assert_eq!(body["params"], json!({"limit": 10}));
+        assert_eq!(session_id.as_deref(), Some("abc123"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+//! Typed Odoo `date` and `datetime` values
+//!
+//! Odoo sends dates as `"2023-01-16"`, and datetimes as naive `"2023-01-16 01:17:19"`
+//! strings (always in UTC). [`OdooDate`] and [`OdooDateTime`] parse those, and
+//! serialize back to the same format, so they can be used in both `read` responses
+//! and `create`/`write` values.
+//!
+//! Empty date fields are returned as `false`, so wrap them in an
+//! [`OdooOption`](crate::serde_helpers::OdooOption):
+//! ```
+//! use odoo_api::types::OdooDateTime;
+//! use odoo_api::serde_helpers::OdooOption;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Partner {
+//!     create_date: OdooDateTime,
+//!     date_closed: OdooOption<OdooDateTime>,
+//! }
+//!
+//! let partner: Partner = serde_json::from_str(r#"{
+//!     "create_date": "2023-01-16 01:17:19",
+//!     "date_closed": false
+//! }"#)?;
+//!
+//! assert_eq!(partner.create_date.year(), 2023);
+//! assert!(partner.date_closed.is_none());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! These are thin wrappers around the [`time`] crate types, and are only
+//! available with the `time` feature.
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+/// The format Odoo uses for `datetime` values
+const DATETIME_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+
+/// As [`DATETIME_FORMAT`], but accepting microseconds (sent by some versions)
+const DATETIME_PARSE_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second][optional [.[subsecond]]]");
+
+/// The format Odoo uses for `date` values
+const DATE_FORMAT: &[FormatItem<'static>] = format_description!("[year]-[month]-[day]");
+
+/// An Odoo `datetime` value, e.g. `"2023-01-16 01:17:19"`
+///
+/// Odoo stores datetimes in UTC, without an offset; see [`assume_utc`](Self::assume_utc).
+/// Microseconds (e.g. `"2023-01-16 01:17:19.123456"`) are accepted when parsing,
+/// but aren't serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OdooDateTime(pub PrimitiveDateTime);
+
+impl OdooDateTime {
+    /// Convert to an [`OffsetDateTime`] in UTC
+    pub fn assume_utc(self) -> OffsetDateTime {
+        self.0.assume_utc()
+    }
+}
+
+impl Deref for OdooDateTime {
+    type Target = PrimitiveDateTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<PrimitiveDateTime> for OdooDateTime {
+    fn from(value: PrimitiveDateTime) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OffsetDateTime> for OdooDateTime {
+    /// Convert to UTC, then drop the offset
+    fn from(value: OffsetDateTime) -> Self {
+        let value = value.to_offset(time::UtcOffset::UTC);
+        Self(PrimitiveDateTime::new(value.date(), value.time()))
+    }
+}
+
+impl FromStr for OdooDateTime {
+    type Err = time::error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PrimitiveDateTime::parse(s, DATETIME_PARSE_FORMAT).map(Self)
+    }
+}
+
+impl fmt::Display for OdooDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatted = self.0.format(DATETIME_FORMAT).map_err(|_| fmt::Error)?;
+        f.write_str(&formatted)
+    }
+}
+
+impl Serialize for OdooDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OdooDateTime {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de.deserialize_str(FromStrVisitor::new("an Odoo datetime string"))
+    }
+}
+
+/// An Odoo `date` value, e.g. `"2023-01-16"`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OdooDate(pub Date);
+
+impl Deref for OdooDate {
+    type Target = Date;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Date> for OdooDate {
+    fn from(value: Date) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for OdooDate {
+    type Err = time::error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Date::parse(s, DATE_FORMAT).map(Self)
+    }
+}
+
+impl fmt::Display for OdooDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatted = self.0.format(DATE_FORMAT).map_err(|_| fmt::Error)?;
+        f.write_str(&formatted)
+    }
+}
+
+impl Serialize for OdooDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OdooDate {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de.deserialize_str(FromStrVisitor::new("an Odoo date string"))
+    }
+}
+
+/// A visitor which parses a string with `T::from_str`
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::serde_helpers::OdooOption;
+    use serde_json::{from_value, json, to_value};
+    use time::macros::{date, datetime};
+
+    #[test]
+    fn datetime() -> serde_json::Result<()> {
+        let value: OdooDateTime = from_value(json!("2023-01-16 01:17:19"))?;
+        assert_eq!(value, OdooDateTime(datetime!(2023-01-16 01:17:19)));
+        assert_eq!(to_value(value)?, json!("2023-01-16 01:17:19"));
+        assert_eq!(value.assume_utc().unix_timestamp(), 1673831839);
+
+        assert!(from_value::<OdooDateTime>(json!("2023-01-16")).is_err());
+        assert!(from_value::<OdooDateTime>(json!("2023-01-16T01:17:19")).is_err());
+        assert!(from_value::<OdooDateTime>(json!(false)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn datetime_microseconds() -> serde_json::Result<()> {
+        let value: OdooDateTime = from_value(json!("2023-01-16 01:17:19.123456"))?;
+        assert_eq!(value, OdooDateTime(datetime!(2023-01-16 01:17:19.123456)));
+
+        // microseconds aren't sent back to Odoo
+        assert_eq!(to_value(value)?, json!("2023-01-16 01:17:19"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn date() -> serde_json::Result<()> {
+        let value: OdooDate = from_value(json!("2023-01-16"))?;
+        assert_eq!(value, OdooDate(date!(2023 - 01 - 16)));
+        assert_eq!(to_value(value)?, json!("2023-01-16"));
+
+        assert!(from_value::<OdooDate>(json!("2023-01-16 01:17:19")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn false_as_none() -> serde_json::Result<()> {
+        let value: OdooOption<OdooDateTime> = from_value(json!(false))?;
+        assert!(value.is_none());
+        assert_eq!(to_value(&value)?, json!(false));
+
+        let value: OdooOption<OdooDate> = from_value(json!("2023-01-16"))?;
+        assert_eq!(value.0, Some(OdooDate(date!(2023 - 01 - 16))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+//! The [`OdooVersion`] type
+//!
+//! This is a parsed, comparable version of the Odoo `server_version` string. It
+//! is mostly useful for runtime checks, e.g. if a method is only available in
+//! newer Odoo versions:
+//! ```
+//! use odoo_api::OdooVersion;
+//!
+//! let version: OdooVersion = "saas~16.3+e".parse()?;
+//! if version.at_least(16, 0) {
+//!     // use the newer API
+//! }
+//! # Ok::<(), odoo_api::version::OdooVersionParseError>(())
+//! ```
+
+use crate::service::common::VersionResponse;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A parsed Odoo server version
+///
+/// Versions are compared, ordered and hashed by `(major, minor)` only, so SaaS
+/// releases sort between the major versions (`16.0 < saas~16.3 < 17.0`), and
+/// the edition is ignored (`16.0+e == 16.0`).
+///
+/// Supported formats include:
+///  - `16.0`
+///  - `16.0+e`
+///  - `saas~16.3`
+///  - `saas~16.3+e`
+///  - `17.0a1` or `17.0rc1` (pre-releases; the suffix is ignored)
+#[derive(Debug, Clone, Copy)]
+pub struct OdooVersion {
+    /// The "major" version (e.g., `16`)
+    pub major: u32,
+
+    /// The "minor" version (e.g., `0`, or `3` for `saas~16.3`)
+    pub minor: u32,
+
+    /// Whether this is a SaaS release (e.g., `saas~16.3`)
+    pub saas: bool,
+
+    /// Whether the server is running Odoo Enterprise
+    pub is_enterprise: bool,
+}
+
+impl OdooVersion {
+    /// Check whether this version is at least `major.minor`
+    ///
+    /// The edition (Community vs Enterprise) is not considered here.
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    fn key(&self) -> (u32, u32) {
+        (self.major, self.minor)
+    }
+}
+
+impl PartialEq for OdooVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for OdooVersion {}
+
+impl PartialOrd for OdooVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OdooVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for OdooVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+/// An error parsing an [`OdooVersion`]
+#[derive(Debug, Error)]
+#[error("Invalid Odoo version string: {0:?}")]
+pub struct OdooVersionParseError(pub String);
+
+impl FromStr for OdooVersion {
+    type Err = OdooVersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || OdooVersionParseError(s.into());
+        let trimmed = s.trim();
+
+        // "+e" marks Enterprise; anything else after the "+" is ignored
+        let (version, is_enterprise) = match trimmed.split_once('+') {
+            Some((version, suffix)) => (version, suffix.starts_with('e')),
+            None => (trimmed, false),
+        };
+
+        // SaaS versions are prefixed with "saas~" (or "saas-" in older releases)
+        let (version, saas) = match version
+            .strip_prefix("saas~")
+            .or_else(|| version.strip_prefix("saas-"))
+        {
+            Some(version) => (version, true),
+            None => (version, false),
+        };
+
+        let (major, minor) = version.split_once('.').unwrap_or((version, "0"));
+        let major = major.parse().map_err(|_| err())?;
+
+        // pre-release versions have a suffix on the minor, e.g. "17.0a1"
+        let digits = minor
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(minor.len());
+        let minor = minor[..digits].parse().map_err(|_| err())?;
+
+        Ok(Self {
+            major,
+            minor,
+            saas,
+            is_enterprise,
+        })
+    }
+}
+
+impl fmt::Display for OdooVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.saas {
+            write!(f, "saas~")?;
+        }
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.is_enterprise {
+            write!(f, "+e")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&VersionResponse> for OdooVersion {
+    fn from(value: &VersionResponse) -> Self {
+        let info = &value.server_version_info;
+        Self {
+            major: info.major,
+            minor: info.minor,
+            saas: value.server_version.starts_with("saas"),
+            is_enterprise: info.is_enterprise(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::{from_value, json};
+
+    fn parse(s: &str) -> OdooVersion {
+        s.parse().unwrap()
+    }
+
+    /// `==` ignores `saas` and `is_enterprise`, so compare every field here
+    fn fields(v: OdooVersion) -> (u32, u32, bool, bool) {
+        (v.major, v.minor, v.saas, v.is_enterprise)
+    }
+
+    #[test]
+    fn parse_community() {
+        assert_eq!(fields(parse("14.0")), (14, 0, false, false));
+    }
+
+    #[test]
+    fn parse_enterprise() {
+        assert_eq!(fields(parse("16.0+e")), (16, 0, false, true));
+    }
+
+    #[test]
+    fn parse_saas() {
+        assert_eq!(fields(parse("saas~16.3")), (16, 3, true, false));
+        assert_eq!(fields(parse("saas~16.3+e")), (16, 3, true, true));
+        assert_eq!(fields(parse("saas-11.3")), fields(parse("saas~11.3")));
+    }
+
+    #[test]
+    fn parse_prerelease() {
+        assert_eq!(fields(parse("17.0a1")), fields(parse("17.0")));
+        assert_eq!(fields(parse("17.0rc1+e")), fields(parse("17.0+e")));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("".parse::<OdooVersion>().is_err());
+        assert!("master".parse::<OdooVersion>().is_err());
+        assert!("saas~".parse::<OdooVersion>().is_err());
+        assert!("16.x".parse::<OdooVersion>().is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(parse("16.0") < parse("saas~16.3"));
+        assert!(parse("saas~16.3") < parse("17.0"));
+        assert!(parse("9.0") < parse("10.0"));
+        assert!(parse("saas~16.3").at_least(16, 0));
+        assert!(!parse("saas~16.3").at_least(16, 4));
+        assert!(parse("16.0+e").at_least(16, 0));
+
+        // the edition doesn't affect the ordering
+        assert!(parse("16.0+e") < parse("saas~16.3"));
+        assert!(parse("saas~16.3+e") < parse("17.0"));
+        assert!(parse("16.0+e") <= parse("16.0") && parse("16.0") <= parse("16.0+e"));
+    }
+
+    #[test]
+    fn equality_ignores_edition() {
+        use std::collections::HashSet;
+
+        assert_eq!(parse("16.0+e"), parse("16.0"));
+        assert_eq!(parse("saas~16.3+e"), parse("saas~16.3"));
+        assert_ne!(parse("16.0"), parse("saas~16.3"));
+
+        let versions: HashSet<_> = ["16.0", "16.0+e", "saas~16.3", "saas~16.3+e"]
+            .into_iter()
+            .map(parse)
+            .collect();
+        assert_eq!(versions.len(), 2);
+    }
+
+    #[test]
+    fn display() {
+        for version in ["14.0", "16.0+e", "saas~16.3", "saas~16.3+e"] {
+            assert_eq!(parse(version).to_string(), version);
+        }
+    }
+
+    #[test]
+    fn from_version_response() -> serde_json::Result<()> {
+        let response: VersionResponse = from_value(json!({
+            "server_version": "saas~16.3+e",
+            "server_version_info": [16, 3, 0, "final", 0, "e"],
+            "server_serie": "saas~16.3",
+            "protocol_version": 1
+        }))?;
+
+        assert_eq!(
+            fields(OdooVersion::from(&response)),
+            fields(parse("saas~16.3+e"))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Server warnings embedded in successful responses
+//!
+//! Some Odoo methods report problems in their result, rather than raising an
+//! error. Two layouts are known:
+//!  - `onchange()` returns `{"value": {...}, "warning": {"title": ..., "message": ..., "type": ...}}`
+//!  - `load()` returns `{"ids": [...], "messages": [{"type": ..., "message": ..., ...}]}`
+//!
+//! The [`HasWarnings`] trait extracts these from a response. With the `client`
+//! feature, [`OdooClient::on_warning`](crate::client::OdooClient::on_warning)
+//! registers a hook which is called for every warning the client receives.
+//!
+//! ## Example
+//! ```
+//! use odoo_api::warning::HasWarnings;
+//! use serde_json::json;
+//!
+//! let result = json!({
+//!     "value": {"price_unit": 0.0},
+//!     "warning": {"title": "Warning", "message": "No price found", "type": "dialog"},
+//! });
+//!
+//! let warnings = result.warnings();
+//! assert_eq!(warnings[0].message, "No price found");
+//! ```
+
+use crate::service::object::{ExecuteKwResponse, ExecuteResponse};
+use serde_json::Value;
+
+/// A warning reported by the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// The warning title (`onchange()` only)
+    pub title: Option<String>,
+
+    /// The warning message
+    pub message: String,
+
+    /// The warning type; `dialog` or `notification` for `onchange()`, and
+    /// `error`, `warning` or `info` for `load()`
+    pub kind: Option<String>,
+}
+
+impl Warning {
+    /// Parse a warning dict, returning `None` if it has no message
+    ///
+    /// Odoo sends `false` for missing values, so unset and non-string fields
+    /// are treated as `None`.
+    fn from_value(value: &Value) -> Option<Self> {
+        let string = |key: &str| value.get(key).and_then(Value::as_str).map(String::from);
+        Some(Self {
+            title: string("title"),
+            message: string("message")?,
+            kind: string("type"),
+        })
+    }
+}
+
+/// A response which may carry server [`Warning`]s
+pub trait HasWarnings {
+    /// The warnings in this response (if any)
+    fn warnings(&self) -> Vec<Warning>;
+}
+
+/// Find the warnings in a raw result, in either of the known layouts
+impl HasWarnings for Value {
+    fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if let Some(warning) = self.get("warning").and_then(Warning::from_value) {
+            warnings.push(warning);
+        }
+        if let Some(messages) = self.get("messages").and_then(Value::as_array) {
+            warnings.extend(messages.iter().filter_map(Warning::from_value));
+        }
+        warnings
+    }
+}
+
+impl HasWarnings for ExecuteResponse {
+    fn warnings(&self) -> Vec<Warning> {
+        self.data.warnings()
+    }
+}
+
+impl HasWarnings for ExecuteKwResponse {
+    fn warnings(&self) -> Vec<Warning> {
+        self.data.warnings()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::{from_value, json};
+
+    #[test]
+    fn onchange() -> serde_json::Result<()> {
+        let response: ExecuteKwResponse = from_value(json!({
+            "value": {"price_unit": 0.0},
+            "warning": {
+                "title": "Warning for Acme",
+                "message": "This customer is on hold",
+                "type": "dialog"
+            }
+        }))?;
+
+        assert_eq!(
+            response.warnings(),
+            vec![Warning {
+                title: Some("Warning for Acme".into()),
+                message: "This customer is on hold".into(),
+                kind: Some("dialog".into()),
+            }]
+        );
+
+        // no warning
+        let response: ExecuteKwResponse =
+            from_value(json!({"value": {"price_unit": 1.0}, "warning": false}))?;
+        assert!(response.warnings().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn load() -> serde_json::Result<()> {
+        let response: ExecuteKwResponse = from_value(json!({
+            "ids": false,
+            "messages": [
+                {
+                    "type": "error",
+                    "message": "No matching record found for name 'Acme'",
+                    "record": 0,
+                    "rows": {"from": 0, "to": 0},
+                    "field": "partner_id"
+                },
+                {
+                    "type": "warning",
+                    "message": "Found multiple matches for value 'Bob'",
+                    "record": 1,
+                    "rows": {"from": 1, "to": 1},
+                    "field": "user_id"
+                }
+            ]
+        }))?;
+
+        let warnings = response.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].title, None);
+        assert_eq!(warnings[0].kind.as_deref(), Some("error"));
+        assert_eq!(
+            warnings[1].message,
+            "Found multiple matches for value 'Bob'"
+        );
+
+        // other results are ignored
+        let response: ExecuteKwResponse = from_value(json!([1, 2, 3]))?;
+        assert!(response.warnings().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,746 @@
+//! An alternate XML-RPC encoding for the JSON-RPC service methods
+//!
+//! Some hardened Odoo deployments disable `/jsonrpc`, but leave the XML-RPC
+//! endpoints (`/xmlrpc/2/common`, `/xmlrpc/2/object`, `/xmlrpc/2/db`) open.
+//! The service methods take the same positional args over both protocols, so
+//! this module re-encodes the JSON-RPC request bodies as XML-RPC `<methodCall>`s,
+//! and converts the `<methodResponse>` back into a JSON-RPC response. The usual
+//! typed response structs (and error handling) then work as normal.
+//!
+//! The `reqwest` impls do this automatically once the transport is enabled with
+//! `with_xmlrpc_transport()`:
+//! ```no_run
+//! # #[cfg(feature = "blocking")]
+//! # fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! use odoo_api::OdooClient;
+//!
+//! let mut client = OdooClient::new_reqwest_blocking("https://demo.odoo.com")?;
+//! client.with_xmlrpc_transport(true);
+//!
+//! // sent to `/xmlrpc/2/common`
+//! let version = client.common_version().send()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only requests to `/jsonrpc` are re-encoded; the "Web" methods (e.g.,
+//! `/web/session/authenticate`) have no XML-RPC equivalent, and are still sent
+//! as JSON-RPC. Closure-based impls can use [`encode_request`] and
+//! [`decode_response`] directly.
+//!
+//! XML-RPC faults are converted to a [`JsonRpcError`](crate::jsonrpc::response::JsonRpcError),
+//! based on the Odoo fault codes:
+//!  - `2`: `odoo.exceptions.UserError`
+//!  - `3`: `odoo.exceptions.AccessDenied`
+//!  - `4`: `odoo.exceptions.AccessError`
+//!  - Anything else: The exception name is parsed from the traceback
+//!
+//! Values are mapped as you'd expect. `dateTime.iso8601` and `base64` values
+//! are returned as strings, and `None` is sent as `<nil/>` (which Odoo accepts).
+
+use serde_json::{json, Map, Number, Value};
+use thiserror::Error;
+
+/// An error encoding an XML-RPC request, or parsing an XML-RPC response
+#[derive(Debug, Error)]
+pub enum XmlRpcError {
+    /// The JSON-RPC request body couldn't be re-encoded
+    ///
+    /// This is raised if the body doesn't have a `service`, `method`, and
+    /// `args` (i.e., it isn't a service method).
+    #[error("Unable to encode XML-RPC request: {0}")]
+    Encode(String),
+
+    /// The response wasn't valid XML-RPC
+    #[error("Invalid XML-RPC response at byte {position}: {message}")]
+    Parse { position: usize, message: String },
+}
+
+pub type XmlRpcResult<T> = std::result::Result<T, XmlRpcError>;
+
+/// Re-encode a JSON-RPC request `body` for the matching XML-RPC endpoint
+///
+/// Returns the XML-RPC URL and `<methodCall>` body, or `None` if `url` isn't
+/// a `/jsonrpc` endpoint (e.g., for the "Web" methods).
+pub fn encode_request(url: &str, body: &Value) -> XmlRpcResult<Option<(String, String)>> {
+    let base = match url.strip_suffix("/jsonrpc") {
+        Some(base) => base,
+        None => return Ok(None),
+    };
+
+    let params = &body["params"];
+    let field = |name: &str| {
+        params
+            .get(name)
+            .ok_or_else(|| XmlRpcError::Encode(format!("missing `params.{}`", name)))
+    };
+    let service = field("service")?
+        .as_str()
+        .ok_or_else(|| XmlRpcError::Encode("`params.service` isn't a string".into()))?;
+    let method = field("method")?
+        .as_str()
+        .ok_or_else(|| XmlRpcError::Encode("`params.method` isn't a string".into()))?;
+    let args = field("args")?
+        .as_array()
+        .ok_or_else(|| XmlRpcError::Encode("`params.args` isn't an array".into()))?;
+
+    Ok(Some((
+        format!("{}/xmlrpc/2/{}", base, service),
+        encode_call(method, args),
+    )))
+}
+
+/// Encode an XML-RPC `<methodCall>`
+pub fn encode_call(method: &str, params: &[Value]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\"?>\n<methodCall><methodName>");
+    escape_into(&mut out, method);
+    out.push_str("</methodName><params>");
+    for param in params {
+        out.push_str("<param>");
+        encode_value(&mut out, param);
+        out.push_str("</param>");
+    }
+    out.push_str("</params></methodCall>\n");
+    out
+}
+
+/// Encode `value` as an XML-RPC `<value>`
+fn encode_value(out: &mut String, value: &Value) {
+    out.push_str("<value>");
+    match value {
+        Value::Null => out.push_str("<nil/>"),
+        Value::Bool(value) => {
+            out.push_str(if *value {
+                "<boolean>1</boolean>"
+            } else {
+                "<boolean>0</boolean>"
+            });
+        }
+        Value::Number(number) => {
+            if let Some(int) = number.as_i64().and_then(|n| i32::try_from(n).ok()) {
+                out.push_str(&format!("<int>{}</int>", int));
+            } else if number.is_f64() {
+                out.push_str(&format!("<double>{}</double>", number));
+            } else {
+                out.push_str(&format!("<i8>{}</i8>", number));
+            }
+        }
+        Value::String(string) => {
+            out.push_str("<string>");
+            escape_into(out, string);
+            out.push_str("</string>");
+        }
+        Value::Array(array) => {
+            out.push_str("<array><data>");
+            for value in array {
+                encode_value(out, value);
+            }
+            out.push_str("</data></array>");
+        }
+        Value::Object(map) => {
+            out.push_str("<struct>");
+            for (name, value) in map {
+                out.push_str("<member><name>");
+                escape_into(out, name);
+                out.push_str("</name>");
+                encode_value(out, value);
+                out.push_str("</member>");
+            }
+            out.push_str("</struct>");
+        }
+    }
+    out.push_str("</value>");
+}
+
+fn escape_into(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Convert an XML-RPC `<methodResponse>` into a JSON-RPC response
+///
+/// The returned value is a full JSON-RPC response (with the request `id`), so
+/// it can be parsed like any other response. Faults are converted to a
+/// JSON-RPC `error`.
+pub fn decode_response(xml: &str, id: &Value) -> XmlRpcResult<Value> {
+    let mut parser = Parser::new(xml);
+    parser.skip_prolog();
+    parser.open("methodResponse")?;
+    let (tag, _) = parser.start_tag()?;
+    let response = match tag {
+        "params" => {
+            parser.open("param")?;
+            let value = parser.value()?;
+            parser.close("param")?;
+            parser.close("params")?;
+            json!({"jsonrpc": "2.0", "id": id, "result": value})
+        }
+        "fault" => {
+            let fault = parser.value()?;
+            parser.close("fault")?;
+            json!({"jsonrpc": "2.0", "id": id, "error": fault_error(&fault)})
+        }
+        tag => return Err(parser.error(format!("unexpected <{}>", tag))),
+    };
+    parser.close("methodResponse")?;
+
+    Ok(response)
+}
+
+/// Build a JSON-RPC `error` object from an Odoo XML-RPC fault
+fn fault_error(fault: &Value) -> Value {
+    let string = fault["faultString"].as_str().unwrap_or_default();
+    let (name, message) = match fault["faultCode"].as_i64() {
+        Some(2) => ("odoo.exceptions.UserError".to_string(), string.to_string()),
+        Some(3) => (
+            "odoo.exceptions.AccessDenied".to_string(),
+            string.to_string(),
+        ),
+        Some(4) => (
+            "odoo.exceptions.AccessError".to_string(),
+            string.to_string(),
+        ),
+        _ => parse_traceback(string),
+    };
+
+    json!({
+        "code": 200,
+        "message": "Odoo Server Error",
+        "data": {
+            "name": name,
+            "debug": string,
+            "message": message,
+            "arguments": [message],
+            "context": {}
+        }
+    })
+}
+
+/// Parse the exception name and message from a Python traceback
+///
+/// The exception is on the first unindented line after the last stack frame,
+/// e.g. `TypeError: search() missing 1 required positional argument: 'args'`.
+/// Builtin exceptions are prefixed with `builtins.`, to match JSON-RPC.
+fn parse_traceback(traceback: &str) -> (String, String) {
+    let lines: Vec<&str> = traceback.lines().collect();
+    let start = lines
+        .iter()
+        .rposition(|line| line.starts_with(' '))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let exception = lines[start.min(lines.len())..].join("\n");
+    match exception.split_once(": ") {
+        Some((name, message)) if !name.is_empty() && !name.contains(char::is_whitespace) => {
+            let name = if name.contains('.') {
+                name.to_string()
+            } else {
+                format!("builtins.{}", name)
+            };
+            (name, message.to_string())
+        }
+        _ => ("xmlrpc.client.Fault".to_string(), traceback.to_string()),
+    }
+}
+
+/// A minimal XML parser, covering the subset of XML used by XML-RPC
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self, message: String) -> XmlRpcError {
+        XmlRpcError::Parse {
+            position: self.position,
+            message,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skip the `<?xml ...?>` declaration (and any comments)
+    fn skip_prolog(&mut self) {
+        loop {
+            self.skip_whitespace();
+            let end = if self.rest().starts_with("<?") {
+                self.rest().find("?>").map(|i| i + 2)
+            } else if self.rest().starts_with("<!--") {
+                self.rest().find("-->").map(|i| i + 3)
+            } else {
+                None
+            };
+            match end {
+                Some(end) => self.position += end,
+                None => return,
+            }
+        }
+    }
+
+    /// Parse a start tag, returning its name and whether it's self-closing
+    fn start_tag(&mut self) -> XmlRpcResult<(&'a str, bool)> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        if !rest.starts_with('<') || rest.starts_with("</") {
+            return Err(self.error("expected a start tag".into()));
+        }
+        let end = rest
+            .find('>')
+            .ok_or_else(|| self.error("unterminated tag".into()))?;
+        let tag = &rest[1..end];
+        let (tag, empty) = match tag.strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        self.position += end + 1;
+        Ok((name, empty))
+    }
+
+    /// Parse the start tag `name`, returning whether it's self-closing
+    fn open(&mut self, name: &str) -> XmlRpcResult<bool> {
+        let position = self.position;
+        let (tag, empty) = self.start_tag()?;
+        if tag != name {
+            self.position = position;
+            return Err(self.error(format!("expected <{}>, found <{}>", name, tag)));
+        }
+        Ok(empty)
+    }
+
+    /// Parse the end tag `name`
+    fn close(&mut self, name: &str) -> XmlRpcResult<()> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let tag = rest
+            .strip_prefix("</")
+            .and_then(|rest| rest.split_once('>'))
+            .map(|(tag, _)| tag);
+        match tag {
+            Some(tag) if tag.trim_end() == name => {
+                self.position += tag.len() + 3;
+                Ok(())
+            }
+            _ => Err(self.error(format!("expected </{}>", name))),
+        }
+    }
+
+    /// Check whether the next tag is an end tag
+    fn at_end_tag(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().starts_with("</")
+    }
+
+    /// Parse (and unescape) the text up to the next tag
+    fn text(&mut self) -> XmlRpcResult<String> {
+        let rest = self.rest();
+        let end = rest.find('<').unwrap_or(rest.len());
+        let text = unescape(&rest[..end]).map_err(|message| self.error(message))?;
+        self.position += end;
+        Ok(text)
+    }
+
+    /// Parse the text content of the element `name`, which has already been opened
+    fn scalar(&mut self, name: &str, empty: bool) -> XmlRpcResult<String> {
+        if empty {
+            return Ok(String::new());
+        }
+        let text = self.text()?;
+        self.close(name)?;
+        Ok(text)
+    }
+
+    /// Parse a `<value>`
+    fn value(&mut self) -> XmlRpcResult<Value> {
+        if self.open("value")? {
+            return Ok(Value::String(String::new()));
+        }
+
+        // a bare `<value>text</value>` is a string
+        let text = self.text()?;
+        if self.rest().starts_with("</") {
+            self.close("value")?;
+            return Ok(Value::String(text));
+        }
+
+        let (tag, empty) = self.start_tag()?;
+        let value = match tag {
+            "nil" => {
+                self.scalar(tag, empty)?;
+                Value::Null
+            }
+            "string" | "dateTime.iso8601" | "base64" => Value::String(self.scalar(tag, empty)?),
+            "int" | "i1" | "i2" | "i4" | "i8" => {
+                let text = self.scalar(tag, empty)?;
+                let int: i64 = text
+                    .trim()
+                    .parse()
+                    .map_err(|_| self.error(format!("invalid <{}>: {:?}", tag, text)))?;
+                Value::Number(int.into())
+            }
+            "double" => {
+                let text = self.scalar(tag, empty)?;
+                let double: f64 = text
+                    .trim()
+                    .parse()
+                    .map_err(|_| self.error(format!("invalid <double>: {:?}", text)))?;
+                Number::from_f64(double)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null)
+            }
+            "boolean" => match self.scalar(tag, empty)?.trim() {
+                "1" => Value::Bool(true),
+                "0" => Value::Bool(false),
+                text => return Err(self.error(format!("invalid <boolean>: {:?}", text))),
+            },
+            "array" => {
+                let mut array = Vec::new();
+                if !empty {
+                    if !self.open("data")? {
+                        while !self.at_end_tag() {
+                            array.push(self.value()?);
+                        }
+                        self.close("data")?;
+                    }
+                    self.close("array")?;
+                }
+                Value::Array(array)
+            }
+            "struct" => {
+                let mut map = Map::new();
+                if !empty {
+                    while !self.at_end_tag() {
+                        self.open("member")?;
+                        let name = self.open("name")?;
+                        let name = self.scalar("name", name)?;
+                        let value = self.value()?;
+                        self.close("member")?;
+                        map.insert(name, value);
+                    }
+                    self.close("struct")?;
+                }
+                Value::Object(map)
+            }
+            tag => return Err(self.error(format!("unsupported value type <{}>", tag))),
+        };
+        self.close("value")?;
+
+        Ok(value)
+    }
+}
+
+/// Unescape the XML entities in `text`
+fn unescape(text: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or_else(|| "unterminated entity".to_string())?;
+        let entity = &rest[start + 1..start + end];
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("unknown entity &{};", entity))?,
+        };
+        out.push(c);
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::jsonrpc::{JsonRpcParams, JsonRpcResponse};
+    use crate::service::common::{Version, VersionResponse};
+    use crate::service::object::{ExecuteKw, ExecuteKwResponse};
+    use serde_json::{from_value, to_value};
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn execute_kw() -> Result<()> {
+        let body = to_value(
+            ExecuteKw {
+                database: "some-database".into(),
+                uid: 2,
+                password: "pass & word".into(),
+                model: "res.partner".into(),
+                method: "search_read".into(),
+                args: vec![json!([["active", "=", true]])],
+                kwargs: jmap! {
+                    "fields": ["name"],
+                    "limit": 80,
+                    "context": {"lang": null}
+                },
+            }
+            .build(1000),
+        )?;
+
+        let (url, xml) = encode_request("http://localhost:8069/jsonrpc", &body)?.unwrap();
+        assert_eq!(url, "http://localhost:8069/xmlrpc/2/object");
+        assert_eq!(
+            xml,
+            concat!(
+                "<?xml version=\"1.0\"?>\n",
+                "<methodCall><methodName>execute_kw</methodName><params>",
+                "<param><value><string>some-database</string></value></param>",
+                "<param><value><int>2</int></value></param>",
+                "<param><value><string>pass &amp; word</string></value></param>",
+                "<param><value><string>res.partner</string></value></param>",
+                "<param><value><string>search_read</string></value></param>",
+                "<param><value><array><data><value><array><data><value><array><data>",
+                "<value><string>active</string></value>",
+                "<value><string>=</string></value>",
+                "<value><boolean>1</boolean></value>",
+                "</data></array></value></data></array></value></data></array></value></param>",
+                "<param><value><struct>",
+                "<member><name>context</name><value><struct>",
+                "<member><name>lang</name><value><nil/></value></member>",
+                "</struct></value></member>",
+                "<member><name>fields</name><value><array><data>",
+                "<value><string>name</string></value>",
+                "</data></array></value></member>",
+                "<member><name>limit</name><value><int>80</int></value></member>",
+                "</struct></value></param>",
+                "</params></methodCall>\n",
+            )
+        );
+
+        let response = r#"<?xml version='1.0'?>
+<methodResponse>
+<params>
+<param>
+<value><array><data>
+<value><struct>
+<member>
+<name>id</name>
+<value><int>3</int></value>
+</member>
+<member>
+<name>name</name>
+<value><string>Mitchell Admin &amp; Co.</string></value>
+</member>
+<member>
+<name>credit_limit</name>
+<value><double>1500.5</double></value>
+</member>
+<member>
+<name>parent_id</name>
+<value><boolean>0</boolean></value>
+</member>
+<member>
+<name>category_id</name>
+<value><array><data>
+</data></array></value>
+</member>
+<member>
+<name>ref</name>
+<value>R-1</value>
+</member>
+</struct></value>
+</data></array></value>
+</param>
+</params>
+</methodResponse>
+"#;
+        let response: JsonRpcResponse<ExecuteKwResponse> =
+            from_value(decode_response(response, &body["id"])?)?;
+        match response {
+            JsonRpcResponse::Success(data) => assert_eq!(
+                data.result.data,
+                json!([{
+                    "id": 3,
+                    "name": "Mitchell Admin & Co.",
+                    "credit_limit": 1500.5,
+                    "parent_id": false,
+                    "category_id": [],
+                    "ref": "R-1"
+                }])
+            ),
+            JsonRpcResponse::Error(e) => return Err(e.error.into()),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn common_version() -> Result<()> {
+        let body = to_value(Version {}.build(1))?;
+        let (url, xml) = encode_request("http://localhost:8069/jsonrpc", &body)?.unwrap();
+        assert_eq!(url, "http://localhost:8069/xmlrpc/2/common");
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\"?>\n<methodCall><methodName>version</methodName><params></params></methodCall>\n"
+        );
+
+        let response = r#"<?xml version='1.0'?>
+<methodResponse>
+<params>
+<param>
+<value><struct>
+<member>
+<name>server_version</name>
+<value><string>16.0+e</string></value>
+</member>
+<member>
+<name>server_version_info</name>
+<value><array><data>
+<value><int>16</int></value>
+<value><int>0</int></value>
+<value><int>0</int></value>
+<value><string>final</string></value>
+<value><int>0</int></value>
+<value><string>e</string></value>
+</data></array></value>
+</member>
+<member>
+<name>server_serie</name>
+<value><string>16.0</string></value>
+</member>
+<member>
+<name>protocol_version</name>
+<value><int>1</int></value>
+</member>
+</struct></value>
+</param>
+</params>
+</methodResponse>
+"#;
+        let response: JsonRpcResponse<VersionResponse> =
+            from_value(decode_response(response, &body["id"])?)?;
+        match response {
+            JsonRpcResponse::Success(data) => {
+                assert_eq!(data.result.server_version, "16.0+e");
+                assert_eq!(data.result.server_serie, "16.0");
+                Ok(())
+            }
+            JsonRpcResponse::Error(e) => Err(e.error.into()),
+        }
+    }
+
+    #[test]
+    fn fault() -> Result<()> {
+        let response = r#"<?xml version='1.0'?>
+<methodResponse>
+<fault>
+<value><struct>
+<member>
+<name>faultCode</name>
+<value><int>3</int></value>
+</member>
+<member>
+<name>faultString</name>
+<value><string>Access Denied</string></value>
+</member>
+</struct></value>
+</fault>
+</methodResponse>
+"#;
+        let response: JsonRpcResponse<Value> = from_value(decode_response(response, &json!(1))?)?;
+        match response {
+            JsonRpcResponse::Error(e) => {
+                assert_eq!(e.error.data.name, "odoo.exceptions.AccessDenied");
+                assert_eq!(e.error.data.message, "Access Denied");
+            }
+            JsonRpcResponse::Success(_) => panic!("expected a fault"),
+        }
+
+        // application errors only have the traceback
+        let response = r#"<?xml version='1.0'?>
+<methodResponse>
+<fault>
+<value><struct>
+<member>
+<name>faultCode</name>
+<value><int>1</int></value>
+</member>
+<member>
+<name>faultString</name>
+<value><string>Traceback (most recent call last):
+  File "/usr/lib/python3/dist-packages/odoo/api.py", line 466, in call_kw
+    result = _call_kw_model(method, model, args, kwargs)
+TypeError: BaseModel.search() missing 1 required positional argument: 'args'
+</string></value>
+</member>
+</struct></value>
+</fault>
+</methodResponse>
+"#;
+        let response: JsonRpcResponse<Value> = from_value(decode_response(response, &json!(1))?)?;
+        match response {
+            JsonRpcResponse::Error(e) => {
+                assert_eq!(e.error.data.name, "builtins.TypeError");
+                assert_eq!(
+                    e.error.data.message,
+                    "BaseModel.search() missing 1 required positional argument: 'args'"
+                );
+                assert!(e.error.data.debug.starts_with("Traceback"));
+            }
+            JsonRpcResponse::Success(_) => panic!("expected a fault"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn web_methods() -> Result<()> {
+        // only `/jsonrpc` requests are re-encoded
+        let body = json!({"jsonrpc": "2.0", "method": "call", "id": 1, "params": {}});
+        assert!(encode_request("http://localhost:8069/web/session/authenticate", &body)?.is_none());
+
+        assert!(matches!(
+            encode_request("http://localhost:8069/jsonrpc", &body),
+            Err(XmlRpcError::Encode(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_response() {
+        for xml in [
+            "",
+            "<methodResponse><params><param><value><int>abc</int></value></param></params></methodResponse>",
+            "<methodResponse><params><param><value><int>1</int></param></params></methodResponse>",
+            "<methodResponse><unknown/></methodResponse>",
+        ] {
+            assert!(
+                matches!(
+                    decode_response(xml, &json!(1)),
+                    Err(XmlRpcError::Parse { .. })
+                ),
+                "{:?}",
+                xml
+            );
+        }
+    }
+}